@@ -0,0 +1,603 @@
+//! `ArrayType` derive for enums.
+
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+use syn::{DataEnum, DeriveInput, Error, Fields, Ident, Index, Member, Type};
+
+/// The maximum number of variants, bounded by the non-negative `i8` type ids
+/// of Arrow unions.
+const MAX_VARIANTS: usize = 128;
+
+/// The maximum number of fields of a variant, bounded by the tuples that
+/// implement `narrow::layout::struct_array::StructLayout`.
+const MAX_FIELDS: usize = 12;
+
+/// The union layout selected with the `narrow` attribute.
+#[derive(Clone, Copy)]
+enum Mode {
+    /// `#[narrow(dense)]`, the default.
+    Dense,
+    /// `#[narrow(sparse)]`.
+    Sparse,
+}
+
+/// Generates the union memory layout of an enum.
+pub(crate) fn derive(input: &DeriveInput, data: &DataEnum) -> Result<TokenStream, Error> {
+    let mode = mode(input)?;
+    if data.variants.is_empty() {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "`ArrayType` requires at least one variant",
+        ));
+    }
+    if data.variants.len() > MAX_VARIANTS {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "`ArrayType` supports at most 128 variants",
+        ));
+    }
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "`ArrayType` can not be derived for generic enums",
+        ));
+    }
+
+    let variants = data
+        .variants
+        .iter()
+        .map(|variant| {
+            let fields = match variant.fields {
+                Fields::Named(ref named) => named.named.iter().collect::<Vec<_>>(),
+                Fields::Unnamed(ref unnamed) => unnamed.unnamed.iter().collect(),
+                Fields::Unit => {
+                    return Err(Error::new_spanned(
+                        variant,
+                        "`ArrayType` does not support unit variants",
+                    ));
+                }
+            };
+            if fields.len() > MAX_FIELDS {
+                return Err(Error::new_spanned(
+                    variant,
+                    "`ArrayType` supports at most 12 fields per variant",
+                ));
+            }
+            let bindings = (0..fields.len())
+                .map(|index| format_ident!("field{index}"))
+                .collect::<Vec<_>>();
+            let members =
+                fields
+                    .iter()
+                    .zip(&bindings)
+                    .enumerate()
+                    .map(|(index, (field, binding))| {
+                        let member = field
+                            .ident
+                            .clone()
+                            .map_or_else(|| Member::Unnamed(Index::from(index)), Member::Named);
+                        quote!(#member: #binding)
+                    });
+            let ident = &variant.ident;
+            let enum_ident = &input.ident;
+            let types = fields.iter().map(|field| &field.ty);
+            Ok(Variant {
+                name: ident.clone(),
+                payload: if let [field] = *fields.as_slice() {
+                    field.ty.clone()
+                } else {
+                    syn::parse_quote!((#(#types),*))
+                },
+                pattern: quote!(#enum_ident::#ident { #(#members),* }),
+                value: if let [ref binding] = *bindings.as_slice() {
+                    quote!(#binding)
+                } else {
+                    quote!((#(#bindings),*))
+                },
+            })
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    Ok(Generator {
+        input,
+        mode,
+        params: (0..variants.len())
+            .map(|index| format_ident!("F{index}"))
+            .collect(),
+        variants,
+    }
+    .generate())
+}
+
+/// Returns the union layout selected by the `narrow` attributes of `input`.
+fn mode(input: &DeriveInput) -> Result<Mode, Error> {
+    let mut mode = Mode::Dense;
+    input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("narrow"))
+        .try_for_each(|attr| {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("dense") {
+                    mode = Mode::Dense;
+                    Ok(())
+                } else if meta.path.is_ident("sparse") {
+                    mode = Mode::Sparse;
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `dense` or `sparse`"))
+                }
+            })
+        })?;
+    Ok(mode)
+}
+
+/// A variant of the deriving enum.
+struct Variant {
+    /// Variant name.
+    name: Ident,
+    /// The stored type: the type of a single field, or a tuple of all field
+    /// types.
+    payload: Type,
+    /// Pattern binding the fields of the variant to `field0`, `field1`, ...
+    pattern: TokenStream,
+    /// Expression building the payload from the bindings of `pattern`.
+    value: TokenStream,
+}
+
+/// Shared state for the generated items.
+///
+/// Like the struct derive, the generated variants collection is generic over
+/// one child collection per variant (`F0`, `F1`, ...), so its bounds stay
+/// provable for a generic storage.
+struct Generator<'input> {
+    /// The deriving enum.
+    input: &'input DeriveInput,
+    /// The selected union layout.
+    mode: Mode,
+    /// The variants, in type id order.
+    variants: Vec<Variant>,
+    /// Child collection type parameters, one per variant.
+    params: Vec<Ident>,
+}
+
+impl Generator<'_> {
+    /// Returns the name of a generated item, prefixed with the enum name.
+    fn ident(&self, suffix: &str) -> Ident {
+        format_ident!("{}{}", self.input.ident, suffix)
+    }
+
+    /// Returns the type ids of the variants as literals.
+    fn type_ids(&self) -> Vec<Literal> {
+        (0..self.variants.len())
+            .map(|index| Literal::i8_unsuffixed(i8::try_from(index).expect("at most 128 variants")))
+            .collect()
+    }
+
+    /// Returns the indices of the children as literals.
+    fn indices(&self) -> Vec<Literal> {
+        (0..self.variants.len())
+            .map(Literal::usize_unsuffixed)
+            .collect()
+    }
+
+    /// Generates all items.
+    fn generate(&self) -> TokenStream {
+        let variants = self.variants_collection();
+        let view = self.view();
+        let layout = self.layout();
+        quote! {
+            #variants
+            #view
+            #layout
+        }
+    }
+
+    /// Generates the collection holding one child collection per variant.
+    #[expect(clippy::too_many_lines, reason = "one impl per trait")]
+    fn variants_collection(&self) -> TokenStream {
+        let ident = &self.input.ident;
+        let vis = &self.input.vis;
+        let variants_ident = self.ident("Variants");
+        let view_ident = self.ident("View");
+        let names = self
+            .variants
+            .iter()
+            .map(|variant| &variant.name)
+            .collect::<Vec<_>>();
+        let patterns = self
+            .variants
+            .iter()
+            .map(|variant| &variant.pattern)
+            .collect::<Vec<_>>();
+        let values = self
+            .variants
+            .iter()
+            .map(|variant| &variant.value)
+            .collect::<Vec<_>>();
+        let types = self
+            .variants
+            .iter()
+            .map(|variant| &variant.payload)
+            .collect::<Vec<_>>();
+        let params = &self.params;
+        let first_param = &params[0];
+        let type_ids = self.type_ids();
+        let indices = self.indices();
+        let count = self.variants.len();
+
+        let doc = format!(
+            "Child collections of the variants of [`{ident}`].\n\n\
+             Arrow stores an enum as a union with one child array per \
+             variant. The child at index `n` holds the values of the variant \
+             with type id `n`."
+        );
+        let variant_docs = names
+            .iter()
+            .map(|name| format!("Collection of the `{name}` variant."));
+        let first_type = types[0];
+        let rest_params = &params[1..];
+        let rest_types = &types[1..];
+        let realloc = quote! {
+            #first_param: ::narrow::collection::CollectionRealloc<Owned = #first_type>,
+            #(
+                #rest_params: ::narrow::collection::CollectionRealloc<
+                    Owned = #rest_types,
+                    Alloc = <#first_param as ::narrow::collection::CollectionAllocIn>::Alloc,
+                >,
+            )*
+        };
+
+        let sparse = match self.mode {
+            Mode::Dense => quote!(),
+            Mode::Sparse => quote! {
+                #[automatically_derived]
+                impl<#(#params),*> ::narrow::layout::union::SparseUnionVariants
+                    for #variants_ident<#(#params),*>
+                where
+                    #realloc
+                    #(<#params as ::narrow::collection::Collection>::Owned:
+                        ::core::default::Default,)*
+                {
+                    fn reserve(&mut self, additional: usize) {
+                        #(
+                            ::narrow::collection::CollectionRealloc::reserve(
+                                &mut self.#indices,
+                                additional,
+                            );
+                        )*
+                    }
+
+                    fn try_reserve(
+                        &mut self,
+                        additional: usize,
+                    ) -> ::core::result::Result<(), ::narrow::collection::AllocError> {
+                        #(
+                            ::narrow::collection::CollectionRealloc::try_reserve(
+                                &mut self.#indices,
+                                additional,
+                            )?;
+                        )*
+                        ::core::result::Result::Ok(())
+                    }
+
+                    fn push_default(&mut self, type_id: i8) {
+                        match type_id {
+                            #(
+                                #type_ids => ::core::iter::Extend::extend(
+                                    &mut self.#indices,
+                                    ::core::iter::once(
+                                        <#params as ::narrow::collection::Collection>::Owned::default(),
+                                    ),
+                                ),
+                            )*
+                            _ => {}
+                        }
+                    }
+
+                    fn try_push_default(
+                        &mut self,
+                        type_id: i8,
+                    ) -> ::core::result::Result<(), ::narrow::collection::AllocError> {
+                        match type_id {
+                            #(
+                                #type_ids => ::narrow::collection::CollectionRealloc::try_extend(
+                                    &mut self.#indices,
+                                    ::core::iter::once(
+                                        <#params as ::narrow::collection::Collection>::Owned::default(),
+                                    ),
+                                ),
+                            )*
+                            _ => ::core::result::Result::Ok(()),
+                        }
+                    }
+                }
+            },
+        };
+
+        quote! {
+            #[doc = #doc]
+            #vis struct #variants_ident<#(#params),*>(
+                #(
+                    #[doc = #variant_docs]
+                    #vis #params,
+                )*
+            );
+
+            #[automatically_derived]
+            impl<#(#params: ::core::fmt::Debug),*> ::core::fmt::Debug
+                for #variants_ident<#(#params),*>
+            {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.debug_tuple(stringify!(#variants_ident))
+                        #(.field(&self.#indices))*
+                        .finish()
+                }
+            }
+
+            #[automatically_derived]
+            impl<#(#params: ::core::clone::Clone),*> ::core::clone::Clone
+                for #variants_ident<#(#params),*>
+            {
+                fn clone(&self) -> Self {
+                    Self(#(::core::clone::Clone::clone(&self.#indices)),*)
+                }
+            }
+
+            #[automatically_derived]
+            impl<#(#params: ::core::default::Default),*> ::core::default::Default
+                for #variants_ident<#(#params),*>
+            {
+                fn default() -> Self {
+                    Self(#(<#params as ::core::default::Default>::default()),*)
+                }
+            }
+
+            #[automatically_derived]
+            impl<#(#params),*> ::narrow::layout::union::UnionVariants
+                for #variants_ident<#(#params),*>
+            where
+                #(#params: ::narrow::collection::Collection<Owned = #types>,)*
+            {
+                const VARIANTS: usize = #count;
+
+                type Owned = #ident;
+
+                type View<'collection>
+                    = #view_ident<'collection, #(#params),*>
+                where
+                    Self: 'collection;
+
+                fn type_id(item: &Self::Owned) -> i8 {
+                    match *item {
+                        #(#ident::#names { .. } => #type_ids,)*
+                    }
+                }
+
+                fn variant_len(&self, type_id: i8) -> ::core::option::Option<usize> {
+                    match type_id {
+                        #(
+                            #type_ids => ::core::option::Option::Some(
+                                ::narrow::length::Length::len(&self.#indices),
+                            ),
+                        )*
+                        _ => ::core::option::Option::None,
+                    }
+                }
+
+                fn variant_view(
+                    &self,
+                    type_id: i8,
+                    index: usize,
+                ) -> ::core::option::Option<Self::View<'_>> {
+                    match type_id {
+                        #(
+                            #type_ids => ::narrow::collection::Collection::view(&self.#indices, index)
+                                .map(#view_ident::#names),
+                        )*
+                        _ => ::core::option::Option::None,
+                    }
+                }
+            }
+
+            #[automatically_derived]
+            impl<#(#params),*> ::narrow::layout::union::UnionVariantsRealloc
+                for #variants_ident<#(#params),*>
+            where
+                #realloc
+            {
+                type Alloc = <#first_param as ::narrow::collection::CollectionAllocIn>::Alloc;
+
+                fn with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Self {
+                    Self(
+                        #(
+                            <#params as ::narrow::collection::CollectionAllocIn>::with_capacity_in(
+                                capacity,
+                                ::core::clone::Clone::clone(&alloc),
+                            ),
+                        )*
+                    )
+                }
+
+                fn try_with_capacity_in(
+                    capacity: usize,
+                    alloc: Self::Alloc,
+                ) -> ::core::result::Result<Self, ::narrow::collection::AllocError> {
+                    ::core::result::Result::Ok(Self(
+                        #(
+                            <#params as ::narrow::collection::CollectionAllocIn>::try_with_capacity_in(
+                                capacity,
+                                ::core::clone::Clone::clone(&alloc),
+                            )?,
+                        )*
+                    ))
+                }
+
+                fn allocator(&self) -> Self::Alloc {
+                    ::narrow::collection::CollectionRealloc::allocator(&self.0)
+                }
+
+                fn push(&mut self, item: Self::Owned) {
+                    match item {
+                        #(
+                            #patterns => ::core::iter::Extend::extend(
+                                &mut self.#indices,
+                                ::core::iter::once(#values),
+                            ),
+                        )*
+                    }
+                }
+
+                fn try_push(
+                    &mut self,
+                    item: Self::Owned,
+                ) -> ::core::result::Result<(), ::narrow::collection::AllocError> {
+                    match item {
+                        #(
+                            #patterns => ::narrow::collection::CollectionRealloc::try_extend(
+                                &mut self.#indices,
+                                ::core::iter::once(#values),
+                            ),
+                        )*
+                    }
+                }
+
+                fn truncate(&mut self, type_id: i8, len: usize) {
+                    match type_id {
+                        #(
+                            #type_ids => ::narrow::collection::CollectionRealloc::truncate(
+                                &mut self.#indices,
+                                len,
+                            ),
+                        )*
+                        _ => {}
+                    }
+                }
+            }
+
+            #sparse
+        }
+    }
+
+    /// Generates the borrowed item view.
+    fn view(&self) -> TokenStream {
+        let ident = &self.input.ident;
+        let vis = &self.input.vis;
+        let view_ident = self.ident("View");
+        let names = self
+            .variants
+            .iter()
+            .map(|variant| &variant.name)
+            .collect::<Vec<_>>();
+        let types = self
+            .variants
+            .iter()
+            .map(|variant| &variant.payload)
+            .collect::<Vec<_>>();
+        let patterns = self.variants.iter().map(|variant| &variant.pattern);
+        let values = self.variants.iter().map(|variant| &variant.value);
+        let params = &self.params;
+
+        let doc = format!(
+            "A borrowed [`{ident}`] item, holding a view of the value of its \
+             variant.\n\n\
+             Reading an item through this view does not materialize its fields."
+        );
+        let variant_docs = names.iter().map(|name| format!("View of a `{name}` item."));
+
+        quote! {
+            #[doc = #doc]
+            #vis enum #view_ident<
+                'collection,
+                #(#params: ::narrow::collection::Collection + 'collection),*
+            > {
+                #(
+                    #[doc = #variant_docs]
+                    #names(#params::View<'collection>),
+                )*
+            }
+
+            #[automatically_derived]
+            impl<'collection, #(#params: ::narrow::collection::Collection + 'collection),*>
+                ::core::clone::Clone for #view_ident<'collection, #(#params),*>
+            {
+                fn clone(&self) -> Self {
+                    *self
+                }
+            }
+
+            #[automatically_derived]
+            impl<'collection, #(#params: ::narrow::collection::Collection + 'collection),*>
+                ::core::marker::Copy for #view_ident<'collection, #(#params),*>
+            {
+            }
+
+            #[automatically_derived]
+            impl<'collection, #(#params: ::narrow::collection::Collection + 'collection),*>
+                ::core::fmt::Debug for #view_ident<'collection, #(#params),*>
+            where
+                #(#params::View<'collection>: ::core::fmt::Debug,)*
+            {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match *self {
+                        #(
+                            Self::#names(ref view) => {
+                                f.debug_tuple(stringify!(#names)).field(view).finish()
+                            }
+                        )*
+                    }
+                }
+            }
+
+            #[automatically_derived]
+            impl<'collection, #(#params),*> ::narrow::collection::owned::IntoOwned<#ident>
+                for #view_ident<'collection, #(#params),*>
+            where
+                #(#params: ::narrow::collection::Collection<Owned = #types> + 'collection,)*
+            {
+                fn into_owned(self) -> #ident {
+                    match self {
+                        #(
+                            Self::#names(view) => {
+                                let #values: #types =
+                                    ::narrow::collection::owned::IntoOwned::into_owned(view);
+                                #patterns
+                            }
+                        )*
+                    }
+                }
+            }
+        }
+    }
+
+    /// Generates the `UnionLayout`, `Layout` and `NullableLayout`
+    /// implementations.
+    fn layout(&self) -> TokenStream {
+        let ident = &self.input.ident;
+        let variants_ident = self.ident("Variants");
+        let types = self.variants.iter().map(|variant| &variant.payload);
+        let memory = match self.mode {
+            Mode::Dense => quote!(::narrow::layout::union::DenseUnion),
+            Mode::Sparse => quote!(::narrow::layout::union::SparseUnion),
+        };
+
+        quote! {
+            #[automatically_derived]
+            impl ::narrow::layout::union::UnionLayout for #ident {
+                type Variants<Storage: ::narrow::buffer::Buffer> =
+                    #variants_ident<#(<#types as ::narrow::layout::ArrayItem>::Memory<Storage>),*>;
+            }
+
+            #[automatically_derived]
+            impl ::narrow::layout::Layout for #ident {
+                type Memory<
+                    Nulls: ::narrow::nullability::Nullability,
+                    Storage: ::narrow::buffer::Buffer,
+                > = #memory<#ident, Nulls, Storage>;
+            }
+
+            #[automatically_derived]
+            impl ::narrow::layout::NullableLayout for #ident {}
+        }
+    }
+}
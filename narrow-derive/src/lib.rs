@@ -1 +1,141 @@
+//! Derive macros for [narrow](https://docs.rs/narrow).
+//!
+//! The generated code refers to `::narrow`, so the deriving crate must depend
+//! on `narrow` directly.
 
+// The goal of the list of lints here is to help reduce complexity and improve consistency
+#![deny(
+    // Rustc
+    missing_copy_implementations,
+    missing_debug_implementations,
+    missing_docs,
+    noop_method_call,
+    warnings,
+    unused,
+    // Clippy
+    clippy::all,
+    clippy::suspicious,
+    clippy::style,
+    clippy::complexity,
+    clippy::perf,
+    clippy::pedantic,
+    // clippy::restriction,
+    clippy::arithmetic_side_effects,
+    clippy::as_conversions,
+    clippy::as_underscore,
+    clippy::clone_on_ref_ptr,
+    clippy::decimal_literal_representation,
+    clippy::empty_structs_with_brackets,
+    clippy::get_unwrap,
+    clippy::if_then_some_else_none,
+    // clippy::missing_docs_in_private_items,
+    clippy::pattern_type_mismatch,
+    clippy::rest_pat_in_fully_bound_structs,
+    clippy::same_name_method,
+    clippy::self_named_module_files,
+    clippy::semicolon_outside_block,
+    clippy::shadow_reuse,
+    clippy::shadow_same,
+    clippy::shadow_unrelated,
+    clippy::str_to_string,
+    clippy::string_add,
+    clippy::tests_outside_test_module,
+    clippy::unneeded_field_pattern,
+    clippy::unseparated_literal_suffix,
+    clippy::unwrap_used,
+    // Rustdoc
+    rustdoc::all
+)]
+#![forbid(unsafe_code)]
+#![allow(clippy::module_name_repetitions)]
+
+use proc_macro::TokenStream;
+use syn::{Data, DeriveInput, Error, parse_macro_input};
+
+mod r#enum;
+mod r#struct;
+
+/// Derives `narrow::layout::Layout` and `narrow::layout::NullableLayout` for
+/// a struct with named fields or an enum.
+///
+/// Arrow stores a struct as one child array per field. The derive generates
+/// that columnar representation next to the row type, so `Array<Row>` and
+/// `Array<Option<Row>>` select it like any other layout:
+///
+/// ```text
+/// struct Row { a: u32, b: bool }
+///     -> RowFields { a: FixedSizePrimitive<u32>, b: Boolean }
+///     -> Struct<Row, Nulls>: optional validity around RowFields
+/// ```
+///
+/// Rows are read back through `RowView`, which borrows one view per field
+/// instead of materializing the row. Nullable rows require the struct to
+/// implement [`Default`], which fills the child arrays at null positions.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{array::Array, collection::Collection};
+/// use narrow_derive::ArrayType;
+///
+/// #[derive(ArrayType, Clone, Debug, Default, PartialEq)]
+/// struct Row {
+///     a: u32,
+///     b: Option<Vec<bool>>,
+/// }
+///
+/// let rows = [Row { a: 1, b: None }, Row { a: 2, b: Some(vec![true]) }];
+/// let array = rows.clone().into_iter().collect::<Array<Row>>();
+/// assert_eq!(array.view(1).map(|row| row.a), Some(2));
+/// assert_eq!(array.into_iter_owned().collect::<Vec<_>>(), rows);
+///
+/// let array = [Some(Row::default()), None].into_iter().collect::<Array<Option<Row>>>();
+/// assert_eq!(array.owned(1), Some(None));
+/// ```
+///
+/// Enums are stored as unions with one child array per variant. A variant
+/// with a single field stores that field, a variant with more fields stores
+/// them as a tuple:
+///
+/// ```text
+/// enum Event { Click { x: u32, y: u32 }, Key(String) }
+///     -> EventVariants(Struct<(u32, u32)>, Utf8)
+///     -> DenseUnion<Event, Nulls>: type ids, offsets and EventVariants
+/// ```
+///
+/// `#[narrow(sparse)]` selects the sparse union layout instead, which pads
+/// every child to the length of the array and requires every variant type to
+/// implement [`Default`]. Items are read back through `EventView`, with one
+/// variant per enum variant. Nullable enums require the enum to implement
+/// [`Default`], and unit variants are not supported.
+///
+/// ```
+/// use narrow::{array::Array, collection::Collection};
+/// use narrow_derive::ArrayType;
+///
+/// #[derive(ArrayType, Clone, Debug, PartialEq)]
+/// #[narrow(sparse)]
+/// enum Event {
+///     Click { x: u32, y: u32 },
+///     Key(String),
+/// }
+///
+/// let events = [Event::Key(String::from("a")), Event::Click { x: 1, y: 2 }];
+/// let array = events.clone().into_iter().collect::<Array<Event>>();
+/// assert!(matches!(array.view(0), Some(EventView::Key("a"))));
+/// assert_eq!(array.into_iter_owned().collect::<Vec<_>>(), events);
+/// ```
+#[proc_macro_derive(ArrayType, attributes(narrow))]
+pub fn derive_array_type(tokens: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(tokens as DeriveInput);
+    match input.data {
+        Data::Struct(ref data) => r#struct::derive(&input, data),
+        Data::Enum(ref data) => r#enum::derive(&input, data),
+        Data::Union(_) => Err(Error::new_spanned(
+            &input.ident,
+            "`ArrayType` can only be derived for structs and enums",
+        )),
+    }
+    .unwrap_or_else(Error::into_compile_error)
+    .into()
+}
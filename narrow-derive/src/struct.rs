@@ -0,0 +1,622 @@
+//! `ArrayType` derive for structs.
+
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+use syn::{DataStruct, DeriveInput, Error, Fields, Ident, Type, Visibility, ext::IdentExt};
+
+/// Generates the columnar memory layout of a struct with named fields.
+pub(crate) fn derive(input: &DeriveInput, data: &DataStruct) -> Result<TokenStream, Error> {
+    let Fields::Named(ref named) = data.fields else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "`ArrayType` can only be derived for structs with named fields",
+        ));
+    };
+    if let Some(attr) = input
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("narrow"))
+    {
+        return Err(Error::new_spanned(
+            attr,
+            "`narrow` attributes are only supported on enums",
+        ));
+    }
+    if named.named.is_empty() {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "`ArrayType` requires at least one field",
+        ));
+    }
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "`ArrayType` can not be derived for generic structs",
+        ));
+    }
+
+    Ok(Generator {
+        input,
+        names: named
+            .named
+            .iter()
+            .map(|field| field.ident.clone().expect("named field"))
+            .collect(),
+        types: named.named.iter().map(|field| field.ty.clone()).collect(),
+        visibilities: named.named.iter().map(|field| field.vis.clone()).collect(),
+        params: (0..named.named.len())
+            .map(|index| format_ident!("F{index}"))
+            .collect(),
+    }
+    .generate())
+}
+
+/// Shared state for the generated items.
+///
+/// The generated fields collection is generic over one child collection per
+/// field (`F0`, `F1`, ...). Bounds on these parameters stay abstract, whereas
+/// bounds on the concrete memory layouts of the field types would be
+/// normalized eagerly and could no longer be proven for a generic storage.
+struct Generator<'input> {
+    /// The deriving struct.
+    input: &'input DeriveInput,
+    /// Field names.
+    names: Vec<Ident>,
+    /// Field types.
+    types: Vec<Type>,
+    /// Field visibilities.
+    visibilities: Vec<Visibility>,
+    /// Child collection type parameters, one per field.
+    params: Vec<Ident>,
+}
+
+impl Generator<'_> {
+    /// Returns the name of a generated item, prefixed with the struct name.
+    fn ident(&self, suffix: &str) -> Ident {
+        format_ident!("{}{}", self.input.ident, suffix)
+    }
+
+    /// Generates all items.
+    fn generate(&self) -> TokenStream {
+        let fields = self.fields();
+        let view = self.view();
+        let into_iter = self.owned_iter();
+        let field_refs = self.field_refs();
+        let struct_fields = self.struct_fields();
+        let layout = self.layout();
+        quote! {
+            #fields
+            #view
+            #into_iter
+            #field_refs
+            #struct_fields
+            #layout
+        }
+    }
+
+    /// Generates the collection holding one child collection per field.
+    #[expect(clippy::too_many_lines, reason = "one impl per trait")]
+    fn fields(&self) -> TokenStream {
+        let ident = &self.input.ident;
+        let vis = &self.input.vis;
+        let fields_ident = self.ident("Fields");
+        let view_ident = self.ident("View");
+        let into_iter_ident = self.ident("IntoIter");
+        let names = &self.names;
+        let first = &names[0];
+        let types = &self.types;
+        let visibilities = &self.visibilities;
+        let params = &self.params;
+        let first_param = &params[0];
+
+        let doc = format!(
+            "Child collections of the fields of [`{ident}`].\n\n\
+             Arrow stores a struct as one child array per field, so every \
+             field of a row is appended to its own collection."
+        );
+        let field_docs = names
+            .iter()
+            .map(|name| format!("Collection of the `{name}` field."));
+        let first_type = &types[0];
+        let rest_params = &params[1..];
+        let rest_types = &types[1..];
+        let realloc = quote! {
+            #first_param: ::narrow::collection::CollectionRealloc<Owned = #first_type>,
+            #(
+                #rest_params: ::narrow::collection::CollectionRealloc<
+                    Owned = #rest_types,
+                    Alloc = <#first_param as ::narrow::collection::CollectionAllocIn>::Alloc,
+                >,
+            )*
+        };
+
+        quote! {
+            #[doc = #doc]
+            #vis struct #fields_ident<#(#params),*> {
+                #(
+                    #[doc = #field_docs]
+                    #visibilities #names: #params,
+                )*
+            }
+
+            #[automatically_derived]
+            impl<#(#params: ::core::fmt::Debug),*> ::core::fmt::Debug
+                for #fields_ident<#(#params),*>
+            {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.debug_struct(stringify!(#fields_ident))
+                        #(.field(stringify!(#names), &self.#names))*
+                        .finish()
+                }
+            }
+
+            #[automatically_derived]
+            impl<#(#params: ::core::clone::Clone),*> ::core::clone::Clone
+                for #fields_ident<#(#params),*>
+            {
+                fn clone(&self) -> Self {
+                    Self {
+                        #(#names: ::core::clone::Clone::clone(&self.#names),)*
+                    }
+                }
+            }
+
+            #[automatically_derived]
+            impl<#(#params: ::core::default::Default),*> ::core::default::Default
+                for #fields_ident<#(#params),*>
+            {
+                fn default() -> Self {
+                    Self {
+                        #(#names: ::core::default::Default::default(),)*
+                    }
+                }
+            }
+
+            #[automatically_derived]
+            impl<#(#params: ::narrow::collection::Collection),*> ::narrow::length::Length
+                for #fields_ident<#(#params),*>
+            {
+                fn len(&self) -> usize {
+                    ::narrow::length::Length::len(&self.#first)
+                }
+            }
+
+            #[automatically_derived]
+            impl<#(#params),*> ::narrow::collection::Collection for #fields_ident<#(#params),*>
+            where
+                #(#params: ::narrow::collection::Collection<Owned = #types>,)*
+            {
+                type View<'collection>
+                    = #view_ident<'collection, #(#params),*>
+                where
+                    Self: 'collection;
+
+                type Owned = #ident;
+
+                fn view(&self, index: usize) -> ::core::option::Option<Self::View<'_>> {
+                    ::core::option::Option::Some(#view_ident {
+                        #(#names: ::narrow::collection::Collection::view(&self.#names, index)?,)*
+                    })
+                }
+
+                type Iter<'collection>
+                    = ::core::iter::Map<
+                        ::core::iter::Zip<
+                            ::core::ops::Range<usize>,
+                            ::core::iter::RepeatN<&'collection Self>,
+                        >,
+                        fn((usize, &'collection Self)) -> Self::View<'collection>,
+                    >
+                where
+                    Self: 'collection;
+
+                fn iter_views(&self) -> Self::Iter<'_> {
+                    let len = ::narrow::length::Length::len(self);
+                    (0..len)
+                        .zip(::core::iter::repeat_n(self, len))
+                        .map(|(index, fields)| {
+                            ::narrow::collection::Collection::view(fields, index)
+                                .expect("index in bounds")
+                        })
+                }
+
+                type IntoIter = #into_iter_ident<#(#params),*>;
+
+                fn into_iter_owned(self) -> Self::IntoIter {
+                    #into_iter_ident {
+                        #(#names: ::narrow::collection::Collection::into_iter_owned(self.#names),)*
+                    }
+                }
+            }
+
+            #[automatically_derived]
+            impl<#(#params),*> ::core::iter::Extend<#ident> for #fields_ident<#(#params),*>
+            where
+                #(#params: ::core::iter::Extend<#types>,)*
+            {
+                fn extend<I: ::core::iter::IntoIterator<Item = #ident>>(&mut self, iter: I) {
+                    iter.into_iter().for_each(|#ident { #(#names),* }| {
+                        #(
+                            ::core::iter::Extend::extend(
+                                &mut self.#names,
+                                ::core::iter::once(#names),
+                            );
+                        )*
+                    });
+                }
+            }
+
+            #[automatically_derived]
+            impl<#(#params),*> ::core::iter::FromIterator<#ident> for #fields_ident<#(#params),*>
+            where
+                #realloc
+                <#first_param as ::narrow::collection::CollectionAllocIn>::Alloc:
+                    ::core::default::Default,
+            {
+                fn from_iter<I: ::core::iter::IntoIterator<Item = #ident>>(iter: I) -> Self {
+                    ::narrow::collection::CollectionAllocIn::from_iter_in(
+                        iter,
+                        ::core::default::Default::default(),
+                    )
+                }
+            }
+
+            #[automatically_derived]
+            impl<#(#params),*> ::narrow::collection::CollectionAllocIn
+                for #fields_ident<#(#params),*>
+            where
+                #realloc
+            {
+                type Alloc = <#first_param as ::narrow::collection::CollectionAllocIn>::Alloc;
+
+                fn with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Self {
+                    Self {
+                        #(
+                            #names: ::narrow::collection::CollectionAllocIn::with_capacity_in(
+                                capacity,
+                                ::core::clone::Clone::clone(&alloc),
+                            ),
+                        )*
+                    }
+                }
+
+                fn from_iter_in<I: ::core::iter::IntoIterator<Item = Self::Owned>>(
+                    iter: I,
+                    alloc: Self::Alloc,
+                ) -> Self {
+                    let items = iter.into_iter();
+                    let mut fields = Self::with_capacity_in(items.size_hint().0, alloc);
+                    ::core::iter::Extend::extend(&mut fields, items);
+                    fields
+                }
+
+                fn try_with_capacity_in(
+                    capacity: usize,
+                    alloc: Self::Alloc,
+                ) -> ::core::result::Result<Self, ::narrow::collection::AllocError> {
+                    ::core::result::Result::Ok(Self {
+                        #(
+                            #names: ::narrow::collection::CollectionAllocIn::try_with_capacity_in(
+                                capacity,
+                                ::core::clone::Clone::clone(&alloc),
+                            )?,
+                        )*
+                    })
+                }
+
+                fn try_from_iter_in<I: ::core::iter::IntoIterator<Item = Self::Owned>>(
+                    iter: I,
+                    alloc: Self::Alloc,
+                ) -> ::core::result::Result<Self, ::narrow::collection::AllocError> {
+                    let items = iter.into_iter();
+                    let mut fields = Self::try_with_capacity_in(items.size_hint().0, alloc)?;
+                    ::narrow::collection::CollectionRealloc::try_extend(&mut fields, items)?;
+                    ::core::result::Result::Ok(fields)
+                }
+            }
+
+            #[automatically_derived]
+            impl<#(#params),*> ::narrow::collection::CollectionRealloc
+                for #fields_ident<#(#params),*>
+            where
+                #realloc
+            {
+                fn allocator(&self) -> Self::Alloc {
+                    ::narrow::collection::CollectionRealloc::allocator(&self.#first)
+                }
+
+                fn try_reserve(
+                    &mut self,
+                    additional: usize,
+                ) -> ::core::result::Result<(), ::narrow::collection::AllocError> {
+                    #(
+                        ::narrow::collection::CollectionRealloc::try_reserve(
+                            &mut self.#names,
+                            additional,
+                        )?;
+                    )*
+                    ::core::result::Result::Ok(())
+                }
+
+                fn try_extend<I: ::core::iter::IntoIterator<Item = Self::Owned>>(
+                    &mut self,
+                    iter: I,
+                ) -> ::core::result::Result<(), ::narrow::collection::AllocError> {
+                    // Children are extended one row at a time, so a failed
+                    // reservation can leave them with different lengths.
+                    // Truncating restores the rows present before the call.
+                    let len = ::narrow::length::Length::len(self);
+                    iter.into_iter()
+                        .try_for_each(|#ident { #(#names),* }| {
+                            #(
+                                ::narrow::collection::CollectionRealloc::try_extend(
+                                    &mut self.#names,
+                                    ::core::iter::once(#names),
+                                )?;
+                            )*
+                            ::core::result::Result::Ok(())
+                        })
+                        .inspect_err(|_| {
+                            ::narrow::collection::CollectionRealloc::truncate(self, len);
+                        })
+                }
+
+                fn reserve(&mut self, additional: usize) {
+                    #(
+                        ::narrow::collection::CollectionRealloc::reserve(
+                            &mut self.#names,
+                            additional,
+                        );
+                    )*
+                }
+
+                fn truncate(&mut self, len: usize) {
+                    #(
+                        ::narrow::collection::CollectionRealloc::truncate(&mut self.#names, len);
+                    )*
+                }
+            }
+        }
+    }
+
+    /// Generates the borrowed row view.
+    fn view(&self) -> TokenStream {
+        let ident = &self.input.ident;
+        let vis = &self.input.vis;
+        let view_ident = self.ident("View");
+        let names = &self.names;
+        let types = &self.types;
+        let visibilities = &self.visibilities;
+        let params = &self.params;
+
+        let doc = format!(
+            "A borrowed [`{ident}`] item, holding one view per field.\n\n\
+             Reading a row through this view does not materialize its fields."
+        );
+        let field_docs = names
+            .iter()
+            .map(|name| format!("View of the `{name}` field."));
+
+        quote! {
+            #[doc = #doc]
+            #vis struct #view_ident<
+                'collection,
+                #(#params: ::narrow::collection::Collection + 'collection),*
+            > {
+                #(
+                    #[doc = #field_docs]
+                    #visibilities #names: #params::View<'collection>,
+                )*
+            }
+
+            #[automatically_derived]
+            impl<'collection, #(#params: ::narrow::collection::Collection + 'collection),*>
+                ::core::clone::Clone for #view_ident<'collection, #(#params),*>
+            {
+                fn clone(&self) -> Self {
+                    *self
+                }
+            }
+
+            #[automatically_derived]
+            impl<'collection, #(#params: ::narrow::collection::Collection + 'collection),*>
+                ::core::marker::Copy for #view_ident<'collection, #(#params),*>
+            {
+            }
+
+            #[automatically_derived]
+            impl<'collection, #(#params: ::narrow::collection::Collection + 'collection),*>
+                ::core::fmt::Debug for #view_ident<'collection, #(#params),*>
+            where
+                #(#params::View<'collection>: ::core::fmt::Debug,)*
+            {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.debug_struct(stringify!(#view_ident))
+                        #(.field(stringify!(#names), &self.#names))*
+                        .finish()
+                }
+            }
+
+            #[automatically_derived]
+            impl<'collection, #(#params),*> ::narrow::collection::owned::IntoOwned<#ident>
+                for #view_ident<'collection, #(#params),*>
+            where
+                #(#params: ::narrow::collection::Collection<Owned = #types> + 'collection,)*
+            {
+                fn into_owned(self) -> #ident {
+                    #ident {
+                        #(
+                            #names: ::narrow::collection::owned::IntoOwned::into_owned(
+                                self.#names,
+                            ),
+                        )*
+                    }
+                }
+            }
+        }
+    }
+
+    /// Generates the iterator over owned rows.
+    fn owned_iter(&self) -> TokenStream {
+        let ident = &self.input.ident;
+        let vis = &self.input.vis;
+        let into_iter_ident = self.ident("IntoIter");
+        let names = &self.names;
+        let first = &names[0];
+        let types = &self.types;
+        let params = &self.params;
+
+        let doc = format!("An iterator over owned [`{ident}`] items.");
+
+        quote! {
+            #[doc = #doc]
+            #vis struct #into_iter_ident<#(#params: ::narrow::collection::Collection),*> {
+                #(#names: #params::IntoIter,)*
+            }
+
+            #[automatically_derived]
+            impl<#(#params: ::narrow::collection::Collection),*> ::core::fmt::Debug
+                for #into_iter_ident<#(#params),*>
+            where
+                #(#params::IntoIter: ::core::fmt::Debug,)*
+            {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.debug_struct(stringify!(#into_iter_ident))
+                        #(.field(stringify!(#names), &self.#names))*
+                        .finish()
+                }
+            }
+
+            #[automatically_derived]
+            impl<#(#params),*> ::core::iter::Iterator for #into_iter_ident<#(#params),*>
+            where
+                #(#params: ::narrow::collection::Collection<Owned = #types>,)*
+            {
+                type Item = #ident;
+
+                fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                    ::core::option::Option::Some(#ident {
+                        #(#names: ::core::iter::Iterator::next(&mut self.#names)?,)*
+                    })
+                }
+
+                fn size_hint(&self) -> (usize, ::core::option::Option<usize>) {
+                    ::core::iter::Iterator::size_hint(&self.#first)
+                }
+            }
+
+            #[automatically_derived]
+            impl<#(#params),*> ::core::iter::ExactSizeIterator for #into_iter_ident<#(#params),*>
+            where
+                #(#params: ::narrow::collection::Collection<Owned = #types>,)*
+            {
+            }
+        }
+    }
+
+    /// Generates positional access to the child collection of every field.
+    fn field_refs(&self) -> TokenStream {
+        let fields_ident = self.ident("Fields");
+        let params = &self.params;
+
+        let impls = self
+            .names
+            .iter()
+            .zip(params)
+            .enumerate()
+            .map(|(index, (name, param))| {
+                let literal = Literal::usize_unsuffixed(index);
+                quote! {
+                    #[automatically_derived]
+                    impl<#(#params: ::narrow::collection::Collection),*>
+                        ::narrow::layout::struct_array::FieldRef<#literal>
+                        for #fields_ident<#(#params),*>
+                    {
+                        type Field = #param;
+
+                        fn field_ref(&self) -> &Self::Field {
+                            &self.#name
+                        }
+                    }
+                }
+            });
+        quote!(#(#impls)*)
+    }
+
+    /// Generates access to all child collections as a nested list of pairs.
+    fn struct_fields(&self) -> TokenStream {
+        let fields_ident = self.ident("Fields");
+        let names = &self.names;
+        let params = &self.params;
+
+        // Folding from the last field builds `(F0, (F1, ()))` inside out.
+        let nest = |items: Vec<TokenStream>| {
+            items
+                .into_iter()
+                .rev()
+                .fold(quote!(()), |tail, head| quote!((#head, #tail)))
+        };
+        let children = nest(params.iter().map(|param| quote!(#param)).collect());
+        let children_ref = nest(params.iter().map(|param| quote!(&'fields #param)).collect());
+        let refs = nest(names.iter().map(|name| quote!(&self.#name)).collect());
+        let values = nest(names.iter().map(|name| quote!(self.#name)).collect());
+        let pattern = nest(names.iter().map(|name| quote!(#name)).collect());
+
+        quote! {
+            #[automatically_derived]
+            impl<#(#params),*> ::narrow::layout::struct_array::StructFields
+                for #fields_ident<#(#params),*>
+            {
+                type Children = #children;
+
+                type ChildrenRef<'fields>
+                    = #children_ref
+                where
+                    Self: 'fields;
+
+                fn children_ref(&self) -> Self::ChildrenRef<'_> {
+                    #refs
+                }
+
+                fn into_children(self) -> Self::Children {
+                    #values
+                }
+
+                fn from_children(children: Self::Children) -> Self {
+                    let #pattern = children;
+                    Self { #(#names),* }
+                }
+            }
+        }
+    }
+
+    /// Generates the `StructLayout`, `Layout` and `NullableLayout`
+    /// implementations.
+    fn layout(&self) -> TokenStream {
+        let ident = &self.input.ident;
+        let fields_ident = self.ident("Fields");
+        let types = &self.types;
+        let field_names = self.names.iter().map(|name| name.unraw().to_string());
+
+        quote! {
+            #[automatically_derived]
+            impl ::narrow::layout::struct_array::StructLayout for #ident {
+                const FIELD_NAMES: &'static [&'static str] = &[#(#field_names),*];
+
+                type Fields<Storage: ::narrow::buffer::Buffer> =
+                    #fields_ident<#(<#types as ::narrow::layout::ArrayItem>::Memory<Storage>),*>;
+            }
+
+            #[automatically_derived]
+            impl ::narrow::layout::Layout for #ident {
+                type Memory<
+                    Nulls: ::narrow::nullability::Nullability,
+                    Storage: ::narrow::buffer::Buffer,
+                > = ::narrow::layout::struct_array::Struct<#ident, Nulls, Storage>;
+            }
+
+            #[automatically_derived]
+            impl ::narrow::layout::NullableLayout for #ident {}
+        }
+    }
+}
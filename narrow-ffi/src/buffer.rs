@@ -0,0 +1,160 @@
+//! Storage for buffers owned by an Arrow C Data producer.
+
+extern crate alloc;
+
+use alloc::rc::Rc;
+use core::{borrow::Borrow, fmt, iter::Map, ops::Deref, ptr::NonNull, slice};
+
+use narrow::{
+    buffer::Buffer,
+    collection::{Collection, slice::SliceIntoIter, view::AsView},
+    fixed_size::FixedSize,
+    length::Length,
+};
+
+use crate::ArrowArray;
+
+/// Storage for buffers that remain owned by a foreign Arrow C Data producer.
+///
+/// [`ImportOwned`](crate::ImportOwned) moves the producer's [`ArrowArray`]
+/// into a reference-counted handle shared by every imported buffer, including
+/// the buffers of child and dictionary arrays. The producer's release
+/// callback is invoked when the last of those buffers is dropped, so the
+/// imported array does not borrow the C structure.
+///
+/// The handle is not atomically reference-counted, and the Arrow C Data
+/// Interface does not require release callbacks to be callable from any
+/// thread, so these buffers cannot be sent to other threads.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{array::Array, collection::Collection};
+/// use narrow_ffi::{Export, ForeignBuffer, ImportOwned};
+///
+/// let (array, schema) = [1, 2].into_iter().collect::<Array<i32>>().export().unwrap();
+/// // SAFETY: The exported structures describe a valid `i32` array.
+/// let values = unsafe { Array::<i32, ForeignBuffer>::import_owned(array, &schema) }.unwrap();
+/// drop(schema);
+/// assert_eq!(values.into_iter_owned().collect::<Vec<_>>(), [1, 2]);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ForeignBuffer;
+impl Buffer for ForeignBuffer {
+    type For<T: FixedSize> = ForeignSlice<T>;
+}
+
+/// A contiguous buffer of `T` in memory retained by a foreign producer.
+///
+/// Cloning a [`ForeignSlice`] shares the handle to the producer's
+/// [`ArrowArray`] instead of copying the values.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{array::Array, buffer::BufferRef};
+/// use narrow_ffi::{Export, ForeignBuffer, ForeignSlice, ImportOwned};
+///
+/// let (array, schema) = [1, 2].into_iter().collect::<Array<i32>>().export().unwrap();
+/// // SAFETY: The exported structures describe a valid `i32` array.
+/// let values = unsafe { Array::<i32, ForeignBuffer>::import_owned(array, &schema) }.unwrap();
+/// let buffer: &ForeignSlice<i32> = values.buffer_ref().buffer_ref();
+/// assert_eq!(**buffer, [1, 2]);
+/// ```
+pub struct ForeignSlice<T> {
+    /// First value of the buffer.
+    pointer: NonNull<T>,
+    /// Number of values in the buffer.
+    len: usize,
+    /// Producer array that retains the buffer.
+    owner: Rc<ArrowArray>,
+}
+
+impl<T> ForeignSlice<T> {
+    /// Constructs a [`ForeignSlice`] of `len` values at `pointer`, retained
+    /// by `owner`.
+    ///
+    /// # Safety
+    ///
+    /// When `len` is non-zero, `pointer` must be non-null, aligned and valid
+    /// for `len` reads of initialized values that remain immutable until
+    /// `owner` is released.
+    pub(crate) unsafe fn new(owner: &Rc<ArrowArray>, pointer: *const T, len: usize) -> Self {
+        let data = if len == 0 {
+            NonNull::dangling()
+        } else {
+            NonNull::new(pointer.cast_mut()).expect("non-empty foreign buffer is not null")
+        };
+        Self {
+            pointer: data,
+            len,
+            owner: Rc::clone(owner),
+        }
+    }
+}
+
+impl<T> Deref for ForeignSlice<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: The constructor guarantees `len` readable values, or a
+        // dangling pointer for an empty slice, retained by `owner`.
+        unsafe { slice::from_raw_parts(self.pointer.as_ptr(), self.len) }
+    }
+}
+
+impl<T> Borrow<[T]> for ForeignSlice<T> {
+    fn borrow(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T> Clone for ForeignSlice<T> {
+    fn clone(&self) -> Self {
+        Self {
+            pointer: self.pointer,
+            len: self.len,
+            owner: Rc::clone(&self.owner),
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ForeignSlice<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> Length for ForeignSlice<T> {
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<T: for<'any> AsView<'any>> Collection for ForeignSlice<T> {
+    type View<'collection>
+        = <T as AsView<'collection>>::View
+    where
+        Self: 'collection;
+
+    type Owned = T;
+
+    fn view(&self, index: usize) -> Option<Self::View<'_>> {
+        self.get(index).map(AsView::as_view)
+    }
+
+    type Iter<'collection>
+        = Map<slice::Iter<'collection, T>, fn(&'collection T) -> <T as AsView<'collection>>::View>
+    where
+        Self: 'collection;
+
+    fn iter_views(&self) -> Self::Iter<'_> {
+        self.iter().map(AsView::as_view)
+    }
+
+    type IntoIter = SliceIntoIter<Self, T>;
+
+    fn into_iter_owned(self) -> Self::IntoIter {
+        self.into()
+    }
+}
@@ -0,0 +1,143 @@
+//! Export support for [`Binary`].
+//!
+//! Binary arrays have the offsets of a list but keep their bytes in a third
+//! buffer instead of a child array, so consumers see `binary` rather than
+//! `list<uint8>`.
+
+use core::{borrow::Borrow, ffi::c_void, ptr};
+
+use narrow::{
+    bitmap::ValidityBitmap,
+    buffer::{Buffer, BufferRef},
+    collection::ChildRef,
+    layout::binary::{Binary, BinaryValues},
+    nullability::{NonNullable, Nullable},
+};
+
+use crate::{ARROW_FLAG_NULLABLE, ArrowArray, ArrowListOffset, ArrowSchema};
+
+use super::{AlignedBitmaps, ArrowArrayLayout, validity_null_count};
+
+/// Returns the buffer pointers of `values` with the `validity` buffer
+/// pointer.
+fn binary_buffers<OffsetItem: ArrowListOffset, Storage: Buffer>(
+    validity: *const c_void,
+    values: &BinaryValues<OffsetItem, Storage>,
+) -> [*const c_void; 3] {
+    let offsets: &[OffsetItem] = values.buffer_ref().buffer_ref().borrow();
+    let data: &[u8] = values.buffer_ref().child_ref().borrow();
+    [validity, offsets.as_ptr().cast(), data.as_ptr().cast()]
+}
+
+impl<OffsetItem: ArrowListOffset, Storage: Buffer> ArrowArrayLayout
+    for Binary<NonNullable, OffsetItem, Storage>
+{
+    type Buffers = [*const c_void; 3];
+    type Children = [ArrowArray; 0];
+
+    fn schema() -> ArrowSchema {
+        ArrowSchema::childless(OffsetItem::BINARY_FORMAT, 0)
+    }
+
+    fn buffers(&self, _bitmaps: &mut AlignedBitmaps) -> Self::Buffers {
+        binary_buffers(ptr::null(), self.buffer_ref())
+    }
+}
+
+impl<OffsetItem: ArrowListOffset, Storage: Buffer> ArrowArrayLayout
+    for Binary<Nullable, OffsetItem, Storage>
+{
+    type Buffers = [*const c_void; 3];
+    type Children = [ArrowArray; 0];
+
+    fn schema() -> ArrowSchema {
+        ArrowSchema::childless(OffsetItem::BINARY_FORMAT, ARROW_FLAG_NULLABLE)
+    }
+
+    fn null_count(&self) -> i64 {
+        validity_null_count(self, self.buffer_ref())
+    }
+
+    fn buffers(&self, bitmaps: &mut AlignedBitmaps) -> Self::Buffers {
+        let validity = self.buffer_ref();
+        let validity_values = validity.bitmap_ref().map(|bitmap| bitmaps.pointer(bitmap));
+        binary_buffers(validity_values.unwrap_or(ptr::null()), validity.child_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::{vec, vec::Vec};
+    use core::{ffi::CStr, slice};
+
+    use narrow::{array::Array, buffer::BufferRef, collection::ChildRef, layout::binary::Bytes};
+
+    use crate::{ARROW_FLAG_NULLABLE, ArrowListOffset};
+
+    use super::super::Export;
+
+    #[test]
+    fn binary_format_strings_match_arrow() {
+        assert_eq!(<i32 as ArrowListOffset>::BINARY_FORMAT, c"z");
+        assert_eq!(<i64 as ArrowListOffset>::BINARY_FORMAT, c"Z");
+    }
+
+    #[test]
+    fn exports_binary_without_children() {
+        let narrow_array = [vec![1, 2], vec![], vec![3]]
+            .into_iter()
+            .map(Bytes::new)
+            .collect::<Array<Bytes>>();
+        let values = narrow_array.buffer_ref().buffer_ref().buffer_ref();
+        let offsets_data = values.buffer_ref().as_ptr();
+        let data = values.child_ref().as_ptr();
+
+        let (array, schema) = narrow_array.export().expect("export array");
+
+        assert_eq!((array.length, array.n_buffers, array.n_children), (3, 3, 0));
+        // SAFETY: The exported array owns a three-entry buffer pointer array.
+        let buffers = unsafe { slice::from_raw_parts(array.buffers, 3) };
+        assert!(buffers[0].is_null());
+        assert_eq!(buffers[1], offsets_data.cast());
+        assert_eq!(buffers[2], data.cast());
+        // SAFETY: The offsets buffer holds one offset more than the length.
+        let offsets = unsafe { slice::from_raw_parts(buffers[1].cast::<i32>(), 4) };
+        assert_eq!(offsets, [0, 2, 2, 3]);
+        // SAFETY: The exported schema has a live, null-terminated format string.
+        assert_eq!(unsafe { CStr::from_ptr(schema.format) }, c"z");
+        assert_eq!((schema.flags, schema.n_children), (0, 0));
+    }
+
+    #[test]
+    fn exports_nullable_large_binary_slice() {
+        let narrow_array = [Some(vec![1]), None, Some(vec![2, 3])]
+            .into_iter()
+            .map(|value| value.map(Bytes::<i64>::new))
+            .collect::<Array<Option<Bytes<i64>>>>();
+
+        let (array, schema) = narrow_array.slice(1, 2).export().expect("export array");
+
+        assert_eq!((array.length, array.offset, array.null_count), (2, 1, 1));
+        assert_eq!((array.n_buffers, array.n_children), (3, 0));
+        // SAFETY: The exported array owns a three-entry buffer pointer array.
+        let buffers = unsafe { slice::from_raw_parts(array.buffers, 3) };
+        // SAFETY: The offsets buffer holds one offset more than the three
+        // items in the backing buffers.
+        let offsets = unsafe { slice::from_raw_parts(buffers[1].cast::<i64>(), 4) };
+        assert_eq!(offsets, [0, 1, 1, 3]);
+        // SAFETY: The exported schema has a live, null-terminated format string.
+        assert_eq!(unsafe { CStr::from_ptr(schema.format) }, c"Z");
+        assert_eq!(schema.flags, ARROW_FLAG_NULLABLE);
+    }
+
+    #[test]
+    fn byte_lists_remain_lists() {
+        let narrow_array = [vec![1_u8]].into_iter().collect::<Array<Vec<u8>>>();
+        let (array, schema) = narrow_array.export().expect("export array");
+        assert_eq!((array.n_buffers, array.n_children), (2, 1));
+        // SAFETY: The exported schema has a live, null-terminated format string.
+        assert_eq!(unsafe { CStr::from_ptr(schema.format) }, c"+l");
+    }
+}
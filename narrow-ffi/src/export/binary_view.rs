@@ -0,0 +1,207 @@
+//! Export support for [`BinaryView`] and [`Utf8View`].
+
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+use core::{
+    borrow::Borrow,
+    ffi::{CStr, c_void},
+    ptr,
+};
+
+use narrow::{
+    bitmap::ValidityBitmap,
+    buffer::{Buffer, BufferRef},
+    collection::ChildRef,
+    layout::{
+        binary_view::{BinaryView, BinaryViews, Viewed},
+        utf8_view::Utf8View,
+    },
+    nullability::{NonNullable, Nullable},
+};
+
+use crate::{ArrowArray, ArrowSchema};
+
+use super::{AlignedBitmaps, ArrowArrayLayout, ArrowType, validity_null_count};
+
+impl ArrowType for Viewed<Vec<u8>> {
+    const FORMAT: &'static CStr = c"vz";
+}
+
+impl ArrowType for Viewed<String> {
+    const FORMAT: &'static CStr = c"vu";
+}
+
+/// Buffer pointers of a view layout.
+///
+/// The number of data buffers is only known at runtime, and the last buffer
+/// holds their sizes, which the export retains here.
+#[derive(Default)]
+pub(super) struct ViewBuffers {
+    /// Arrow C Data buffer pointers.
+    pointers: Vec<*const c_void>,
+    /// The variadic buffer sizes referenced by the last pointer.
+    sizes: Vec<i64>,
+}
+
+impl ViewBuffers {
+    /// Returns the buffers of `views` with the `validity` buffer pointer.
+    fn new<Storage: Buffer>(validity: *const c_void, views: &BinaryViews<Storage>) -> Self {
+        let data_buffers = views.data_buffers();
+        let sizes = data_buffers
+            .iter()
+            .map(|data| i64::try_from(data.borrow().len()).expect("data buffer length exceeds i64"))
+            .collect::<Vec<_>>();
+        let view_values: &[_] = views.buffer_ref().borrow();
+        let mut pointers = Vec::with_capacity(data_buffers.len().strict_add(3));
+        pointers.push(validity);
+        pointers.push(view_values.as_ptr().cast());
+        pointers.extend(
+            data_buffers
+                .iter()
+                .map(|data| data.borrow().as_ptr().cast()),
+        );
+        let mut buffers = Self { pointers, sizes };
+        let sizes_pointer = buffers.sizes.as_ptr().cast();
+        buffers.pointers.push(sizes_pointer);
+        buffers
+    }
+}
+
+impl AsRef<[*const c_void]> for ViewBuffers {
+    fn as_ref(&self) -> &[*const c_void] {
+        &self.pointers
+    }
+}
+
+impl AsMut<[*const c_void]> for ViewBuffers {
+    fn as_mut(&mut self) -> &mut [*const c_void] {
+        &mut self.pointers
+    }
+}
+
+impl<Storage: Buffer> ArrowArrayLayout for BinaryView<NonNullable, Storage> {
+    type Buffers = ViewBuffers;
+    type Children = [ArrowArray; 0];
+
+    fn schema() -> ArrowSchema {
+        ArrowSchema::flat::<Viewed<Vec<u8>>>()
+    }
+
+    fn buffers(&self, _bitmaps: &mut AlignedBitmaps) -> Self::Buffers {
+        ViewBuffers::new(ptr::null(), self.buffer_ref())
+    }
+}
+
+impl<Storage: Buffer> ArrowArrayLayout for BinaryView<Nullable, Storage> {
+    type Buffers = ViewBuffers;
+    type Children = [ArrowArray; 0];
+
+    fn schema() -> ArrowSchema {
+        ArrowSchema::flat::<Option<Viewed<Vec<u8>>>>()
+    }
+
+    fn null_count(&self) -> i64 {
+        validity_null_count(self, self.buffer_ref())
+    }
+
+    fn buffers(&self, bitmaps: &mut AlignedBitmaps) -> Self::Buffers {
+        let validity = self.buffer_ref();
+        let validity_values = validity.bitmap_ref().map(|bitmap| bitmaps.pointer(bitmap));
+        ViewBuffers::new(validity_values.unwrap_or(ptr::null()), validity.child_ref())
+    }
+}
+
+impl<Storage: Buffer> ArrowArrayLayout for Utf8View<NonNullable, Storage> {
+    type Buffers = ViewBuffers;
+    type Children = [ArrowArray; 0];
+
+    fn schema() -> ArrowSchema {
+        ArrowSchema::flat::<Viewed<String>>()
+    }
+
+    fn buffers(&self, _bitmaps: &mut AlignedBitmaps) -> Self::Buffers {
+        ViewBuffers::new(ptr::null(), self.buffer_ref().buffer_ref())
+    }
+}
+
+impl<Storage: Buffer> ArrowArrayLayout for Utf8View<Nullable, Storage> {
+    type Buffers = ViewBuffers;
+    type Children = [ArrowArray; 0];
+
+    fn schema() -> ArrowSchema {
+        ArrowSchema::flat::<Option<Viewed<String>>>()
+    }
+
+    fn null_count(&self) -> i64 {
+        validity_null_count(self, self.buffer_ref())
+    }
+
+    fn buffers(&self, bitmaps: &mut AlignedBitmaps) -> Self::Buffers {
+        let validity = self.buffer_ref();
+        let validity_values = validity.bitmap_ref().map(|bitmap| bitmaps.pointer(bitmap));
+        ViewBuffers::new(
+            validity_values.unwrap_or(ptr::null()),
+            validity.child_ref().buffer_ref(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::{string::String, vec::Vec};
+    use core::{ffi::CStr, slice};
+
+    use narrow::{array::Array, buffer::BufferRef, layout::binary_view::Viewed};
+
+    use crate::ARROW_FLAG_NULLABLE;
+
+    use super::super::Export;
+
+    #[test]
+    fn exports_variadic_buffers() {
+        let narrow_array = [b"short".to_vec(), b"longer than twelve bytes".to_vec()]
+            .into_iter()
+            .map(Viewed::new)
+            .collect::<Array<Viewed<Vec<u8>>>>();
+        let views = narrow_array.buffer_ref().buffer_ref();
+        let views_data = views.buffer_ref().as_ptr();
+        let data = views.data_buffers()[0].as_ptr();
+
+        let (array, schema) = narrow_array.export().expect("export array");
+
+        assert_eq!(array.length, 2);
+        assert_eq!(array.n_buffers, 4);
+        // SAFETY: The exported array owns a four-entry buffer pointer array.
+        let buffers = unsafe { slice::from_raw_parts(array.buffers, 4) };
+        assert!(buffers[0].is_null());
+        assert_eq!(buffers[1], views_data.cast());
+        assert_eq!(buffers[2], data.cast());
+        // SAFETY: The last buffer holds one size per data buffer.
+        let sizes = unsafe { slice::from_raw_parts(buffers[3].cast::<i64>(), 1) };
+        assert_eq!(sizes, [24]);
+        // SAFETY: The exported schema has a live, null-terminated format string.
+        assert_eq!(unsafe { CStr::from_ptr(schema.format) }, c"vz");
+        assert_eq!(schema.flags, 0);
+    }
+
+    #[test]
+    fn exports_nullable_utf8_view_slice() {
+        let narrow_array = [Some("a"), None, Some("b")]
+            .into_iter()
+            .map(|value| value.map(Viewed::<String>::from))
+            .collect::<Array<Option<Viewed<String>>>>();
+
+        let (array, schema) = narrow_array.slice(1, 2).export().expect("export array");
+
+        assert_eq!((array.length, array.offset), (2, 1));
+        assert_eq!(array.null_count, 1);
+        // Inline strings need no data buffers, only the empty sizes buffer.
+        assert_eq!(array.n_buffers, 3);
+        // SAFETY: The exported schema has a live, null-terminated format string.
+        assert_eq!(unsafe { CStr::from_ptr(schema.format) }, c"vu");
+        assert_eq!(schema.flags, ARROW_FLAG_NULLABLE);
+    }
+}
@@ -1,6 +1,6 @@
 //! Export support for [`Boolean`].
 
-use core::{borrow::Borrow, ffi::c_void, ptr};
+use core::{ffi::c_void, ptr};
 
 use narrow::{
     bitmap::ValidityBitmap,
@@ -12,7 +12,7 @@ use narrow::{
 
 use crate::{ArrowArray, ArrowSchema};
 
-use super::{ArrowArrayLayout, ArrowType};
+use super::{AlignedBitmaps, ArrowArrayLayout, ArrowType, validity_null_count};
 
 impl<Storage: Buffer> ArrowArrayLayout for Boolean<NonNullable, Storage> {
     type Buffers = [*const c_void; 2];
@@ -22,13 +22,8 @@ impl<Storage: Buffer> ArrowArrayLayout for Boolean<NonNullable, Storage> {
         ArrowSchema::flat::<bool>()
     }
 
-    fn offset(&self) -> usize {
-        self.buffer_ref().bit_offset()
-    }
-
-    fn buffers(&self) -> Self::Buffers {
-        let values: &[u8] = self.buffer_ref().buffer_ref().borrow();
-        [ptr::null(), values.as_ptr().cast()]
+    fn buffers(&self, bitmaps: &mut AlignedBitmaps) -> Self::Buffers {
+        [ptr::null(), bitmaps.pointer(self.buffer_ref())]
     }
 }
 
@@ -41,32 +36,15 @@ impl<Storage: Buffer> ArrowArrayLayout for Boolean<Nullable, Storage> {
     }
 
     fn null_count(&self) -> i64 {
-        i64::try_from(ValidityBitmap::null_count(self.buffer_ref()))
-            .expect("null count exceeds i64")
+        validity_null_count(self, self.buffer_ref())
     }
 
-    fn offset(&self) -> usize {
+    fn buffers(&self, bitmaps: &mut AlignedBitmaps) -> Self::Buffers {
         let validity = self.buffer_ref();
-        // Surface either bitmap offset so the exporter rejects unsupported offsets.
-        let validity_offset = validity
-            .bitmap_ref()
-            .map_or(0, narrow::bitmap::Bitmap::bit_offset);
-        if validity_offset != 0 {
-            return validity_offset;
-        }
-        validity.child_ref().bit_offset()
-    }
-
-    fn buffers(&self) -> Self::Buffers {
-        let validity = self.buffer_ref();
-        let validity_values = validity.bitmap_ref().map(|bitmap| {
-            let values: &[u8] = bitmap.buffer_ref().borrow();
-            values.as_ptr().cast()
-        });
-        let values: &[u8] = validity.child_ref().buffer_ref().borrow();
+        let validity_values = validity.bitmap_ref().map(|bitmap| bitmaps.pointer(bitmap));
         [
             validity_values.unwrap_or(ptr::null()),
-            values.as_ptr().cast(),
+            bitmaps.pointer(validity.child_ref()),
         ]
     }
 }
@@ -79,18 +57,19 @@ impl ArrowType for bool {
 mod tests {
     extern crate alloc;
 
-    use alloc::sync::Arc;
+    use alloc::{sync::Arc, vec::Vec};
     use core::{ffi::CStr, slice};
 
     use narrow::{
         array::Array,
         bitmap::Bitmap,
-        buffer::{ArcBuffer, ArrayBuffer},
+        buffer::{ArcBuffer, ArrayBuffer, SliceBuffer},
+        collection::Collection,
         layout::boolean::Boolean,
         validity::Validity,
     };
 
-    use crate::{ARROW_FLAG_NULLABLE, export::ExportError};
+    use crate::{ARROW_FLAG_NULLABLE, import::Import};
 
     use super::{super::Export, ArrowType};
 
@@ -182,35 +161,47 @@ mod tests {
     }
 
     #[test]
-    fn rejects_non_zero_nullable_boolean_offsets() {
+    fn realigns_nullable_boolean_value_bit_offset() {
         let offset_values =
             Bitmap::<ArrayBuffer<1>>::try_from_parts([0b0001_0100], 3, 2).expect("valid bitmap");
         let zero_validity =
-            Bitmap::<ArrayBuffer<1>>::try_from_parts([0b0000_0111], 3, 0).expect("valid bitmap");
+            Bitmap::<ArrayBuffer<1>>::try_from_parts([0b0000_0011], 3, 0).expect("valid bitmap");
         let offset_values_validity =
             Validity::try_from_parts(offset_values, zero_validity).expect("valid parts");
         let offset_values_array: Array<Option<bool>, ArrayBuffer<1>> =
             Array::from_buffer(Boolean::from_buffer(offset_values_validity));
+        let (array, schema) = offset_values_array.export().expect("export array");
+        assert_eq!(array.offset, 0);
+        assert_eq!(array.null_count, 1);
+        // SAFETY: The exported structures retain valid buffers for the
+        // lifetime of the imported array.
+        let imported: Array<Option<bool>, SliceBuffer<'_>> =
+            unsafe { Import::import(&array, &schema) }.expect("import array");
         assert_eq!(
-            offset_values_array
-                .export()
-                .expect_err("non-zero value offset"),
-            ExportError::NonZeroOffset { offset: 2 }
+            imported.into_iter_owned().collect::<Vec<_>>(),
+            [Some(true), Some(false), None]
         );
+    }
 
+    #[test]
+    fn realigns_nullable_boolean_validity_bit_offset() {
         let zero_values =
             Bitmap::<ArrayBuffer<1>>::try_from_parts([0b0000_0101], 3, 0).expect("valid bitmap");
         let offset_validity =
-            Bitmap::<ArrayBuffer<1>>::try_from_parts([0b0001_1100], 3, 2).expect("valid bitmap");
+            Bitmap::<ArrayBuffer<1>>::try_from_parts([0b0001_1000], 3, 2).expect("valid bitmap");
         let offset_validity_values =
             Validity::try_from_parts(zero_values, offset_validity).expect("valid parts");
         let offset_validity_array: Array<Option<bool>, ArrayBuffer<1>> =
             Array::from_buffer(Boolean::from_buffer(offset_validity_values));
+        let (array, schema) = offset_validity_array.export().expect("export array");
+        assert_eq!(array.null_count, 1);
+        // SAFETY: The exported structures retain valid buffers for the
+        // lifetime of the imported array.
+        let imported: Array<Option<bool>, SliceBuffer<'_>> =
+            unsafe { Import::import(&array, &schema) }.expect("import array");
         assert_eq!(
-            offset_validity_array
-                .export()
-                .expect_err("non-zero validity offset"),
-            ExportError::NonZeroOffset { offset: 2 }
+            imported.into_iter_owned().collect::<Vec<_>>(),
+            [None, Some(false), Some(true)]
         );
     }
 
@@ -231,13 +222,40 @@ mod tests {
     }
 
     #[test]
-    fn rejects_non_zero_boolean_offset() {
+    fn realigns_boolean_bit_offset() {
         let bitmap =
             Bitmap::<ArrayBuffer<1>>::try_from_parts([0b0001_0100], 3, 2).expect("valid bitmap");
-        let array: Array<bool, ArrayBuffer<1>> = Array::from_buffer(Boolean::from_buffer(bitmap));
+        let narrow_array: Array<bool, ArrayBuffer<1>> =
+            Array::from_buffer(Boolean::from_buffer(bitmap));
+
+        let (array, _schema) = narrow_array.export().expect("export array");
 
-        let error = array.export().expect_err("non-zero offset");
+        assert_eq!(array.offset, 0);
+        // SAFETY: The exported array owns a two-entry buffer pointer array.
+        let buffers = unsafe { slice::from_raw_parts(array.buffers, 2) };
+        // SAFETY: The value buffer points to a copy retained by the export.
+        assert_eq!(unsafe { *buffers[1].cast::<u8>() }, 0b0000_0101);
+    }
 
-        assert_eq!(error, ExportError::NonZeroOffset { offset: 2 });
+    #[test]
+    fn exports_byte_aligned_boolean_bit_offset_without_copying() {
+        let values = Arc::<[u8]>::from([0, 0b0000_0110]);
+        let data = values.as_ptr();
+        let bitmap = Bitmap::<ArcBuffer>::try_from_parts(values, 3, 8).expect("valid bitmap");
+        let narrow_array: Array<bool, ArcBuffer> = Array::from_buffer(Boolean::from_buffer(bitmap));
+
+        let (array, schema) = narrow_array.slice(1, 2).export().expect("export array");
+
+        assert_eq!(array.offset, 1);
+        assert_eq!(array.length, 2);
+        // SAFETY: The exported array owns a two-entry buffer pointer array.
+        let buffers = unsafe { slice::from_raw_parts(array.buffers, 2) };
+        // SAFETY: The value buffer holds two bytes retained by the export.
+        assert_eq!(buffers[1], unsafe { data.add(1) }.cast());
+        // SAFETY: The exported structures retain a valid value buffer for the
+        // lifetime of the imported array.
+        let imported: Array<bool, SliceBuffer<'_>> =
+            unsafe { Import::import(&array, &schema) }.expect("import array");
+        assert_eq!(imported.into_iter_owned().collect::<Vec<_>>(), [true, true]);
     }
 }
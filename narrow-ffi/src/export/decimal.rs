@@ -0,0 +1,204 @@
+//! Export support for the [`narrow::decimal`] types.
+//!
+//! Decimals are fixed-size primitives, so only their `d:P,S[,bits]` format
+//! strings are defined here. The bit width is left out for 128-bit decimals,
+//! which is the spelling Arrow uses by default, and imports accept it either
+//! way.
+
+use core::ffi::CStr;
+
+use narrow::decimal::{Decimal32, Decimal64, Decimal128, Decimal256};
+
+use super::ArrowType;
+
+/// The length of the format buffer, which fits `d:76,-128,256` and a NUL.
+const FORMAT_LEN: usize = 16;
+
+/// Writes `value` in decimal digits to `buffer` at `len`, returning the new
+/// length.
+const fn write_number(buffer: &mut [u8; FORMAT_LEN], len: usize, value: u16) -> usize {
+    let mut divisor = 1_u16;
+    while value.strict_div(divisor) >= 10 {
+        divisor = divisor.strict_mul(10);
+    }
+    let mut end = len;
+    while divisor > 0 {
+        let digit = value.strict_div(divisor).strict_rem(10).to_le_bytes()[0];
+        buffer[end] = b'0'.strict_add(digit);
+        end = end.strict_add(1);
+        divisor = divisor.strict_div(10);
+    }
+    end
+}
+
+/// Builds the NUL-terminated `d:P,S[,bits]` format string, leaving out the
+/// bit width when `bits` is zero.
+const fn format(precision: u8, scale: i8, bits: u16) -> [u8; FORMAT_LEN] {
+    let mut buffer = [0; FORMAT_LEN];
+    buffer[0] = b'd';
+    buffer[1] = b':';
+    let mut len = write_number(&mut buffer, 2, u16::from_le_bytes([precision, 0]));
+    buffer[len] = b',';
+    len = len.strict_add(1);
+    if scale < 0 {
+        buffer[len] = b'-';
+        len = len.strict_add(1);
+    }
+    len = write_number(
+        &mut buffer,
+        len,
+        u16::from_le_bytes([scale.unsigned_abs(), 0]),
+    );
+    if bits != 0 {
+        buffer[len] = b',';
+        write_number(&mut buffer, len.strict_add(1), bits);
+    }
+    buffer
+}
+
+/// The format string of a decimal with precision `P`, scale `S` and a bit
+/// width of `BITS`, or the default width when `BITS` is zero.
+struct DecimalFormat<const P: u8, const S: i8, const BITS: u16>;
+
+impl<const P: u8, const S: i8, const BITS: u16> DecimalFormat<P, S, BITS> {
+    /// The NUL-padded format string.
+    const BYTES: [u8; FORMAT_LEN] = format(P, S, BITS);
+    /// The format string.
+    const FORMAT: &'static CStr = match CStr::from_bytes_until_nul(&Self::BYTES) {
+        Ok(format) => format,
+        Err(_) => panic!("decimal format strings are NUL-terminated"),
+    };
+}
+
+impl<const P: u8, const S: i8> ArrowType for Decimal32<P, S> {
+    const FORMAT: &'static CStr = DecimalFormat::<P, S, 32>::FORMAT;
+}
+
+impl<const P: u8, const S: i8> ArrowType for Decimal64<P, S> {
+    const FORMAT: &'static CStr = DecimalFormat::<P, S, 64>::FORMAT;
+}
+
+/// Exported without a bit width, while an explicit `,128` is also accepted.
+impl<const P: u8, const S: i8> ArrowType for Decimal128<P, S> {
+    const FORMAT: &'static CStr = DecimalFormat::<P, S, 0>::FORMAT;
+
+    fn matches_format(format: &CStr) -> bool {
+        format == Self::FORMAT || format == DecimalFormat::<P, S, 128>::FORMAT
+    }
+}
+
+impl<const P: u8, const S: i8> ArrowType for Decimal256<P, S> {
+    const FORMAT: &'static CStr = DecimalFormat::<P, S, 256>::FORMAT;
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::string::ToString;
+    use core::{ffi::CStr, slice};
+
+    use narrow::{array::Array, buffer::SliceBuffer, collection::Collection};
+
+    use crate::{
+        ARROW_FLAG_NULLABLE,
+        import::{Import, ImportError},
+    };
+
+    use super::{super::Export, ArrowType, Decimal32, Decimal64, Decimal128, Decimal256};
+
+    #[test]
+    fn type_format_strings_match_arrow() {
+        assert_eq!(Decimal32::<9, -2>::FORMAT, c"d:9,-2,32");
+        assert_eq!(Decimal64::<18, 0>::FORMAT, c"d:18,0,64");
+        assert_eq!(Decimal128::<38, 10>::FORMAT, c"d:38,10");
+        assert_eq!(Decimal256::<76, -128>::FORMAT, c"d:76,-128,256");
+        assert_eq!(<Option<Decimal128<5, 2>>>::FORMAT, c"d:5,2");
+        assert_eq!(<Option<Decimal128<5, 2>>>::FLAGS, ARROW_FLAG_NULLABLE);
+
+        assert!(Decimal128::<5, 2>::matches_format(c"d:5,2,128"));
+        assert!(<Option<Decimal128<5, 2>>>::matches_format(c"d:5,2,128"));
+        assert!(!Decimal128::<5, 2>::matches_format(c"d:5,3"));
+        assert!(!Decimal256::<5, 2>::matches_format(c"d:5,2"));
+    }
+
+    #[test]
+    fn round_trips_decimal_arrays() {
+        let source = [
+            Some(Decimal128::<9, 2>::try_new(12_345).expect("in range")),
+            None,
+            Some(Decimal128::try_new(-1).expect("in range")),
+        ]
+        .into_iter()
+        .collect::<Array<Option<Decimal128<9, 2>>>>();
+        let (array, schema) = source.export().expect("export array");
+        // SAFETY: The exported schema has a live, null-terminated format string.
+        assert_eq!(unsafe { CStr::from_ptr(schema.format) }, c"d:9,2");
+        assert_eq!(array.null_count, 1);
+
+        // SAFETY: The exported structures retain valid buffers for the
+        // lifetime of the imported array.
+        let imported: Array<Option<Decimal128<9, 2>>, SliceBuffer<'_>> =
+            unsafe { Import::import(&array, &schema) }.expect("import array");
+        assert_eq!(
+            imported.owned(0).flatten().map(Decimal128::unscaled),
+            Some(12_345)
+        );
+        assert_eq!(imported.owned(1), Some(None));
+        assert_eq!(
+            imported.owned(2).flatten().map(Decimal128::unscaled),
+            Some(-1)
+        );
+
+        let wide = [-2_i128, i128::MAX]
+            .into_iter()
+            .map(|unscaled| Decimal256::<76, 0>::try_from_i128(unscaled).expect("in range"))
+            .collect::<Array<Decimal256<76, 0>>>();
+        let (wide_array, wide_schema) = wide.export().expect("export array");
+        // SAFETY: The exported array owns a two-entry buffer pointer array.
+        let buffers = unsafe { slice::from_raw_parts(wide_array.buffers, 2) };
+        // SAFETY: The values buffer holds two 32-byte decimals.
+        let bytes = unsafe { slice::from_raw_parts(buffers[1].cast::<u8>(), 64) };
+        assert_eq!(bytes[0], 0xfe);
+        assert!(bytes[1..32].iter().all(|&byte| byte == 0xff));
+
+        // SAFETY: The exported structures retain valid buffers for the
+        // lifetime of the imported array.
+        let wide_imported: Array<Decimal256<76, 0>, SliceBuffer<'_>> =
+            unsafe { Import::import(&wide_array, &wide_schema) }.expect("import array");
+        assert_eq!(
+            wide_imported.owned(0).map(Decimal256::to_le_bytes),
+            Some(
+                Decimal256::<76, 0>::try_from_i128(-2)
+                    .expect("in range")
+                    .to_le_bytes()
+            )
+        );
+        assert_eq!(
+            wide_imported.owned(1).map(|value| value.to_string()),
+            Some(i128::MAX.to_string())
+        );
+    }
+
+    #[test]
+    fn imports_explicit_bit_width_and_rejects_other_decimals() {
+        let source = [Decimal128::<9, 2>::try_new(1).expect("in range")]
+            .into_iter()
+            .collect::<Array<Decimal128<9, 2>>>();
+        let (array, mut schema) = source.export().expect("export array");
+
+        schema.format = c"d:9,2,128".as_ptr();
+        // SAFETY: The exported structures remain valid; only the format is
+        // changed to an equivalent spelling.
+        let imported: Array<Decimal128<9, 2>, SliceBuffer<'_>> =
+            unsafe { Import::import(&array, &schema) }.expect("import array");
+        assert_eq!(imported.owned(0).map(Decimal128::unscaled), Some(1));
+
+        // SAFETY: The exported structures remain valid.
+        let error = unsafe {
+            <Array<Decimal128<10, 2>, SliceBuffer<'_>> as Import>::import(&array, &schema)
+                .expect_err("precision mismatch")
+        };
+        assert_eq!(error, ImportError::UnexpectedFormat);
+    }
+}
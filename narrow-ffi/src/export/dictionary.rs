@@ -0,0 +1,214 @@
+//! Export support for [`DictionaryEncoded`].
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use core::{borrow::Borrow, ffi::c_void, ptr};
+
+use narrow::{
+    bitmap::ValidityBitmap,
+    buffer::{Buffer, BufferRef},
+    collection::ChildRef,
+    layout::dictionary::{DictionaryEncoded, DictionaryKey, DictionaryValue},
+    nullability::{NonNullable, Nullability, Nullable},
+};
+
+use crate::{ARROW_FLAG_NULLABLE, ArrowArray, ArrowSchema};
+
+use super::{
+    AlignedBitmaps, ArrowArrayLayout, ArrowType, ExportError, release_schema, validity_null_count,
+};
+
+impl<K, V, Storage> ArrowArrayLayout for DictionaryEncoded<K, V, NonNullable, Storage>
+where
+    K: DictionaryKey + ArrowType,
+    V: DictionaryValue + 'static,
+    Storage: Buffer,
+    V::Memory<Storage>: ArrowArrayLayout + 'static,
+{
+    type Buffers = [*const c_void; 2];
+    type Children = [ArrowArray; 0];
+
+    fn schema() -> ArrowSchema {
+        ArrowSchema::dictionary::<K, NonNullable>(<V::Memory<Storage>>::schema())
+    }
+
+    fn buffers(&self, _bitmaps: &mut AlignedBitmaps) -> Self::Buffers {
+        let keys: &[K] = self.buffer_ref().buffer_ref().borrow();
+        [ptr::null(), keys.as_ptr().cast()]
+    }
+
+    fn dictionary(&self) -> Result<Option<ArrowArray>, ExportError> {
+        self.buffer_ref().child_ref().child_array().map(Some)
+    }
+}
+
+impl<K, V, Storage> ArrowArrayLayout for DictionaryEncoded<K, V, Nullable, Storage>
+where
+    K: DictionaryKey + ArrowType,
+    V: DictionaryValue + 'static,
+    Storage: Buffer,
+    V::Memory<Storage>: ArrowArrayLayout + 'static,
+{
+    type Buffers = [*const c_void; 2];
+    type Children = [ArrowArray; 0];
+
+    fn schema() -> ArrowSchema {
+        ArrowSchema::dictionary::<K, Nullable>(<V::Memory<Storage>>::schema())
+    }
+
+    fn null_count(&self) -> i64 {
+        validity_null_count(self, self.buffer_ref())
+    }
+
+    fn buffers(&self, bitmaps: &mut AlignedBitmaps) -> Self::Buffers {
+        let validity = self.buffer_ref();
+        let validity_values = validity.bitmap_ref().map(|bitmap| bitmaps.pointer(bitmap));
+        let keys: &[K] = validity.child_ref().buffer_ref().borrow();
+        [validity_values.unwrap_or(ptr::null()), keys.as_ptr().cast()]
+    }
+
+    fn dictionary(&self) -> Result<Option<ArrowArray>, ExportError> {
+        self.buffer_ref()
+            .child_ref()
+            .child_ref()
+            .child_array()
+            .map(Some)
+    }
+}
+
+impl ArrowSchema {
+    /// Builds a dictionary-encoded schema and retains its value schema.
+    ///
+    /// The format describes the keys, and the dictionary schema describes the
+    /// values.
+    fn dictionary<K: ArrowType, Nulls: Nullability>(values: Self) -> Self {
+        let mut private = Box::new(DictionarySchemaData { dictionary: values });
+        let dictionary = ptr::from_mut(&mut private.dictionary);
+
+        let private_data = Box::into_raw(private).cast();
+        Self {
+            format: K::FORMAT.as_ptr(),
+            name: c"".as_ptr(),
+            metadata: ptr::null(),
+            flags: if Nulls::NULLABLE {
+                ARROW_FLAG_NULLABLE
+            } else {
+                0
+            },
+            n_children: 0,
+            children: ptr::null_mut(),
+            dictionary,
+            release: Some(release_schema::<DictionarySchemaData>),
+            private_data,
+        }
+    }
+}
+
+/// Data retained by `ArrowSchema::private_data` for a dictionary-encoded
+/// array.
+struct DictionarySchemaData {
+    /// Value schema owned by the export.
+    dictionary: ArrowSchema,
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::{sync::Arc, vec::Vec};
+    use core::{ffi::CStr, slice};
+
+    use narrow::{
+        array::Array,
+        buffer::ArcBuffer,
+        layout::{
+            dictionary::{Dictionary, DictionaryEncoded, DictionaryKeys},
+            fixed_size_primitive::FixedSizePrimitive,
+        },
+    };
+
+    use crate::ARROW_FLAG_NULLABLE;
+
+    use super::super::Export;
+
+    #[test]
+    fn exports_dictionary_without_copying_buffers() {
+        let key_storage = Arc::<[u16]>::from([1, 0, 1]);
+        let value_storage = Arc::<[i64]>::from([10, 20]);
+        let keys_weak = Arc::downgrade(&key_storage);
+        let values_weak = Arc::downgrade(&value_storage);
+        let keys_data = key_storage.as_ptr();
+        let values_data = value_storage.as_ptr();
+        let keys = DictionaryKeys::try_from_parts(
+            key_storage,
+            FixedSizePrimitive::from_buffer(value_storage),
+        )
+        .expect("valid keys");
+        let narrow_array: Array<Dictionary<u16, i64>, ArcBuffer> =
+            Array::from_buffer(DictionaryEncoded::from_buffer(keys));
+
+        let (array, schema) = narrow_array.export().expect("export array");
+
+        assert_eq!(array.length, 3);
+        assert_eq!(array.null_count, 0);
+        assert_eq!(array.n_buffers, 2);
+        assert_eq!(array.n_children, 0);
+        // SAFETY: The exported array owns a two-entry buffer pointer array.
+        let buffers = unsafe { slice::from_raw_parts(array.buffers, 2) };
+        assert!(buffers[0].is_null());
+        assert_eq!(buffers[1], keys_data.cast());
+        assert!(!array.dictionary.is_null());
+        // SAFETY: The dictionary array is retained by the parent.
+        let dictionary = unsafe { &*array.dictionary };
+        assert_eq!(dictionary.length, 2);
+        // SAFETY: The dictionary owns a two-entry buffer pointer array.
+        let dictionary_buffers = unsafe { slice::from_raw_parts(dictionary.buffers, 2) };
+        assert_eq!(dictionary_buffers[1], values_data.cast());
+
+        // SAFETY: The exported schema has a live, null-terminated format string.
+        assert_eq!(unsafe { CStr::from_ptr(schema.format) }, c"S");
+        assert_eq!(schema.flags, 0);
+        assert_eq!(schema.n_children, 0);
+        assert!(!schema.dictionary.is_null());
+        // SAFETY: The dictionary schema is retained by the parent.
+        let dictionary_schema = unsafe { &*schema.dictionary };
+        // SAFETY: The dictionary schema has a live, null-terminated format string.
+        assert_eq!(unsafe { CStr::from_ptr(dictionary_schema.format) }, c"l");
+        assert!(keys_weak.upgrade().is_some());
+        assert!(values_weak.upgrade().is_some());
+
+        drop(array);
+        assert!(keys_weak.upgrade().is_none());
+        assert!(values_weak.upgrade().is_none());
+        drop(schema);
+    }
+
+    #[test]
+    fn exports_nullable_dictionary_of_lists() {
+        let narrow_array = [Some(Dictionary::new(Vec::from([1_u8]))), None]
+            .into_iter()
+            .collect::<Array<Option<Dictionary<i8, Vec<u8>>>>>();
+
+        let (array, schema) = narrow_array.export().expect("export array");
+
+        assert_eq!(array.length, 2);
+        assert_eq!(array.null_count, 1);
+        // SAFETY: The exported array owns a two-entry buffer pointer array.
+        let buffers = unsafe { slice::from_raw_parts(array.buffers, 2) };
+        assert!(!buffers[0].is_null());
+        // SAFETY: The dictionary array is retained by the parent.
+        let dictionary = unsafe { &*array.dictionary };
+        // The null item is stored with the key of the default value.
+        assert_eq!(dictionary.length, 2);
+        assert_eq!(dictionary.n_children, 1);
+
+        // SAFETY: The exported schema has a live, null-terminated format string.
+        assert_eq!(unsafe { CStr::from_ptr(schema.format) }, c"c");
+        assert_eq!(schema.flags, ARROW_FLAG_NULLABLE);
+        // SAFETY: The dictionary schema is retained by the parent.
+        let dictionary_schema = unsafe { &*schema.dictionary };
+        // SAFETY: The dictionary schema has a live, null-terminated format string.
+        assert_eq!(unsafe { CStr::from_ptr(dictionary_schema.format) }, c"+l");
+    }
+}
@@ -0,0 +1,178 @@
+//! Export support for [`Extension`].
+//!
+//! Extension arrays are exported as their storage layout, with the name and
+//! metadata of the extension type added to the metadata of its schema.
+
+use core::ptr;
+
+use narrow::{
+    bitmap::ValidityBitmap,
+    buffer::{Buffer, BufferRef},
+    collection::ChildRef,
+    layout::{
+        Layout, NullableLayout,
+        extension::{Extension, ExtensionType},
+    },
+    nullability::{NonNullable, Nullable},
+};
+
+use crate::{
+    ARROW_FLAG_NULLABLE, ArrowArray, ArrowSchema,
+    metadata::{self, EXTENSION_METADATA, EXTENSION_NAME},
+};
+
+use super::{AlignedBitmaps, ArrowArrayLayout, ExportError, validity_null_count};
+
+/// The layout that stores items of the storage type of `T`.
+type StorageMemory<T, Storage> =
+    <<T as ExtensionType>::StorageType as Layout>::Memory<NonNullable, Storage>;
+
+/// Adds the name and metadata of `T` to the metadata of `schema`.
+fn describe<T: ExtensionType>(schema: ArrowSchema) -> ArrowSchema {
+    let metadata = metadata::encode([(EXTENSION_NAME, T::NAME), (EXTENSION_METADATA, T::METADATA)]);
+    schema.describe(None, metadata)
+}
+
+impl<T, Storage> ArrowArrayLayout for Extension<T, NonNullable, Storage>
+where
+    T: ExtensionType,
+    Storage: Buffer,
+    StorageMemory<T, Storage>: ArrowArrayLayout,
+{
+    type Buffers = <StorageMemory<T, Storage> as ArrowArrayLayout>::Buffers;
+    type Children = <StorageMemory<T, Storage> as ArrowArrayLayout>::Children;
+
+    fn schema() -> ArrowSchema {
+        describe::<T>(StorageMemory::<T, Storage>::schema())
+    }
+
+    fn buffers(&self, bitmaps: &mut AlignedBitmaps) -> Self::Buffers {
+        self.buffer_ref().buffer_ref().buffers(bitmaps)
+    }
+
+    fn children(&self) -> Result<Self::Children, ExportError> {
+        self.buffer_ref().buffer_ref().children()
+    }
+
+    fn dictionary(&self) -> Result<Option<ArrowArray>, ExportError> {
+        self.buffer_ref().buffer_ref().dictionary()
+    }
+}
+
+impl<T, Storage> ArrowArrayLayout for Extension<T, Nullable, Storage>
+where
+    T: ExtensionType,
+    T::StorageType: NullableLayout,
+    Storage: Buffer,
+    StorageMemory<T, Storage>: ArrowArrayLayout,
+{
+    type Buffers = <StorageMemory<T, Storage> as ArrowArrayLayout>::Buffers;
+    type Children = <StorageMemory<T, Storage> as ArrowArrayLayout>::Children;
+
+    fn schema() -> ArrowSchema {
+        let mut schema = StorageMemory::<T, Storage>::schema();
+        schema.flags |= ARROW_FLAG_NULLABLE;
+        describe::<T>(schema)
+    }
+
+    fn null_count(&self) -> i64 {
+        validity_null_count(self, self.buffer_ref())
+    }
+
+    fn buffers(&self, bitmaps: &mut AlignedBitmaps) -> Self::Buffers {
+        // Nullable storage layouts keep their validity bitmap in the first
+        // buffer, which their non-nullable export leaves null.
+        let validity = self.buffer_ref();
+        let mut buffers = validity.child_ref().buffer_ref().buffers(bitmaps);
+        if let Some(slot) = buffers.as_mut().first_mut() {
+            *slot = validity
+                .bitmap_ref()
+                .map_or(ptr::null(), |bitmap| bitmaps.pointer(bitmap));
+        }
+        buffers
+    }
+
+    fn children(&self) -> Result<Self::Children, ExportError> {
+        self.buffer_ref().child_ref().buffer_ref().children()
+    }
+
+    fn dictionary(&self) -> Result<Option<ArrowArray>, ExportError> {
+        self.buffer_ref().child_ref().buffer_ref().dictionary()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::{vec, vec::Vec};
+    use core::{ffi::CStr, slice};
+
+    use narrow::{
+        array::Array,
+        layout::extension::{Json, Uuid},
+    };
+
+    use crate::{ARROW_FLAG_NULLABLE, ArrowSchema, metadata::MetadataPairs};
+
+    use super::super::Export;
+
+    /// Returns the metadata pairs of an exported `schema`.
+    fn pairs(schema: &ArrowSchema) -> Vec<(&str, &str)> {
+        // SAFETY: Exported schemas have null or binary metadata.
+        unsafe { MetadataPairs::new(schema) }
+            .expect("pair count")
+            .collect::<Result<_, _>>()
+            .expect("valid pairs")
+    }
+
+    #[test]
+    fn exports_storage_with_extension_metadata() {
+        let uuid = Uuid::from_bytes([7; 16]);
+        let (array, schema) = [Some(uuid), None]
+            .into_iter()
+            .collect::<Array<Option<Uuid>>>()
+            .export()
+            .expect("export array");
+
+        assert_eq!((array.length, array.null_count, array.n_buffers), (2, 1, 2));
+        // SAFETY: The exported array owns a two-entry buffer pointer array,
+        // whose values buffer holds two 16-byte items.
+        let buffers = unsafe { slice::from_raw_parts(array.buffers, 2) };
+        // SAFETY: The values buffer is the second buffer and holds two 16-byte
+        // items, so its first 16 bytes are initialized `u8`s.
+        let values = unsafe { slice::from_raw_parts(buffers[1].cast::<u8>(), 16) };
+        assert_eq!(values, [7; 16]);
+
+        // SAFETY: The exported schema has a null-terminated format.
+        assert_eq!(unsafe { CStr::from_ptr(schema.format) }, c"w:16");
+        assert_eq!(schema.flags, ARROW_FLAG_NULLABLE);
+        assert_eq!(
+            pairs(&schema),
+            [
+                ("ARROW:extension:name", "arrow.uuid"),
+                ("ARROW:extension:metadata", "")
+            ]
+        );
+    }
+
+    #[test]
+    fn exports_nested_extension_items() {
+        let (_array, schema) = [vec![Json::from("{}")]]
+            .into_iter()
+            .collect::<Array<Vec<Json>>>()
+            .export()
+            .expect("export array");
+
+        assert!(schema.metadata.is_null());
+        assert_eq!(schema.n_children, 1);
+        // SAFETY: The exported schema has one live child schema.
+        let children = unsafe { slice::from_raw_parts(schema.children, 1) };
+        // SAFETY: The child pointer is non-null, aligned, and retained by
+        // `schema`, which outlives `item`.
+        let item = unsafe { &*children[0] };
+        // SAFETY: The child schema has a null-terminated format.
+        assert_eq!(unsafe { CStr::from_ptr(item.format) }, c"u");
+        assert_eq!(pairs(item)[0], ("ARROW:extension:name", "arrow.json"));
+    }
+}
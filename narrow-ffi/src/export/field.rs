@@ -0,0 +1,203 @@
+//! Export support for [`Field`] and [`Schema`] descriptions.
+//!
+//! Layouts export schemas without names. A field is attached afterwards by
+//! describing the exported schema with the name of the field, and with its
+//! metadata added to the metadata of the layout.
+
+use core::{mem, slice};
+use std::{collections::HashMap, ffi::CString};
+
+use narrow::{array::Array, buffer::Buffer, layout::ArrayItem, layout::struct_array::StructLayout};
+
+use crate::{
+    ARROW_FLAG_NULLABLE, ArrowArray, ArrowSchema, Field, Schema,
+    metadata::{self, MetadataPairs},
+};
+
+use super::{ArrowArrayLayout, Export, ExportError};
+
+/// Export a record batch through the Arrow C Data Interface.
+///
+/// A record batch is an array of structs, whose fields are the columns of the
+/// batch. The [`Schema`] names these columns and adds metadata to them and to
+/// the batch.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::array::Array;
+/// use narrow_ffi::{ExportError, ExportRecordBatch, Field, Schema};
+///
+/// let batch = [(1_u8, Some(true))].into_iter().collect::<Array<(u8, Option<bool>)>>();
+/// let schema = Schema::new(vec![Field::new("id", false), Field::new("flag", true)]);
+/// assert!(batch.clone().export_record_batch(&schema).is_ok());
+/// assert_eq!(
+///     batch.export_record_batch(&Schema::new(vec![Field::new("id", false)])).unwrap_err(),
+///     ExportError::FieldChildCountMismatch { fields: 1, children: 2 }
+/// );
+/// ```
+pub trait ExportRecordBatch: Export {
+    /// Consumes `self` and returns an [`ArrowArray`] and an [`ArrowSchema`]
+    /// described by `schema`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ExportError`] when the array cannot be exported, or when
+    /// `schema` does not match the exported columns.
+    fn export_record_batch(self, schema: &Schema)
+    -> Result<(ArrowArray, ArrowSchema), ExportError>;
+}
+
+impl<T, Storage> ExportRecordBatch for Array<T, Storage>
+where
+    T: ArrayItem + StructLayout,
+    Storage: Buffer,
+    T::Memory<Storage>: ArrowArrayLayout + 'static,
+{
+    fn export_record_batch(
+        self,
+        schema: &Schema,
+    ) -> Result<(ArrowArray, ArrowSchema), ExportError> {
+        self.export_field(&schema.to_field())
+    }
+}
+
+impl ArrowSchema {
+    /// Describes this schema with `field`, and its children with the children
+    /// of `field`.
+    pub(super) fn with_field(self, field: &Field) -> Result<Self, ExportError> {
+        let nullable = self.flags & ARROW_FLAG_NULLABLE != 0;
+        if field.nullable != nullable {
+            return Err(ExportError::NullabilityMismatch { nullable });
+        }
+        if !field.children.is_empty() {
+            let children = usize::try_from(self.n_children).expect("child count must fit in usize");
+            if field.children.len() != children {
+                return Err(ExportError::FieldChildCountMismatch {
+                    fields: field.children.len(),
+                    children,
+                });
+            }
+            // SAFETY: Exported schemas have a child pointer array with
+            // `n_children` entries.
+            let child_pointers = unsafe { slice::from_raw_parts(self.children, children) };
+            for (&pointer, child_field) in child_pointers.iter().zip(&field.children) {
+                // SAFETY: The child schemas are owned by this schema, which is
+                // not shared yet.
+                let child = unsafe { &mut *pointer };
+                *child = mem::take(child).with_field(child_field)?;
+            }
+        }
+
+        // SAFETY: Exported schemas have null or binary metadata.
+        let mut pairs = unsafe { MetadataPairs::new(&self) }
+            .and_then(Iterator::collect::<Result<HashMap<_, _>, _>>)
+            .expect("exported metadata is valid");
+        pairs.extend(
+            field
+                .metadata
+                .iter()
+                .map(|(key, value)| (key.as_str(), value.as_str())),
+        );
+        let encoded = metadata::encode(pairs);
+        let name = CString::new(field.name.as_str()).map_err(|_| ExportError::InvalidFieldName)?;
+        Ok(self.describe(Some(name), encoded))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::{ffi::CStr, slice};
+    use std::{borrow::ToOwned, collections::HashMap, string::String, vec, vec::Vec};
+
+    use narrow::{array::Array, layout::extension::Uuid};
+
+    use crate::{ArrowSchema, Field, export::Export};
+
+    use super::ExportError;
+
+    /// Returns the only child schema of an exported `schema`.
+    fn only_child(schema: &ArrowSchema) -> &ArrowSchema {
+        assert_eq!(schema.n_children, 1);
+        // SAFETY: The exported schema has one live child schema.
+        let children = unsafe { slice::from_raw_parts(schema.children, 1) };
+        // SAFETY: The child pointer is non-null and aligned, and the child schema
+        // is owned by `schema`, which outlives the returned reference.
+        unsafe { &*children[0] }
+    }
+
+    #[test]
+    fn names_nested_list_items() {
+        let values = [vec![[1_u8, 2]]]
+            .into_iter()
+            .collect::<Array<Vec<[u8; 2]>>>();
+        let field = Field::new("lists", false).with_children(vec![
+            Field::new("list", false).with_children(vec![Field::new("item", false)]),
+        ]);
+        let (_array, schema) = values.export_field(&field).expect("export array");
+
+        // SAFETY: The exported schema has a null-terminated name.
+        assert_eq!(unsafe { CStr::from_ptr(schema.name) }, c"lists");
+        assert!(schema.metadata.is_null());
+        let list = only_child(&schema);
+        // SAFETY: Child schemas with a field have a null-terminated name.
+        assert_eq!(unsafe { CStr::from_ptr(list.name) }, c"list");
+        let item = only_child(list);
+        // SAFETY: The item schema is a child with a field, so it has a
+        // null-terminated name owned by the export.
+        assert_eq!(unsafe { CStr::from_ptr(item.name) }, c"item");
+    }
+
+    #[test]
+    fn adds_to_layout_metadata() {
+        let values = [Uuid::default()].into_iter().collect::<Array<Uuid>>();
+        let field = Field::new("id", false)
+            .with_metadata(HashMap::from([("key".to_owned(), "value".to_owned())]));
+        let (_array, schema) = values.export_field(&field).expect("export array");
+
+        // SAFETY: The schema was exported above.
+        let imported = unsafe { Field::import(&schema) }.expect("import field");
+        assert_eq!(imported.name, "id");
+        assert_eq!(
+            imported.metadata,
+            HashMap::from([
+                ("key".to_owned(), "value".to_owned()),
+                ("ARROW:extension:name".to_owned(), "arrow.uuid".to_owned()),
+                ("ARROW:extension:metadata".to_owned(), String::new()),
+            ])
+        );
+    }
+
+    #[test]
+    fn rejects_mismatched_fields() {
+        let values = [vec![1_u8]].into_iter().collect::<Array<Vec<u8>>>();
+        assert_eq!(
+            values
+                .clone()
+                .export_field(&Field::new("a\0b", false))
+                .expect_err("nul byte"),
+            ExportError::InvalidFieldName
+        );
+        assert_eq!(
+            values
+                .clone()
+                .export_field(
+                    &Field::new("list", false).with_children(vec![Field::new("item", true)])
+                )
+                .expect_err("nullable item"),
+            ExportError::NullabilityMismatch { nullable: false }
+        );
+        assert_eq!(
+            values
+                .export_field(
+                    &Field::new("list", false)
+                        .with_children(vec![Field::default(), Field::default()])
+                )
+                .expect_err("two children"),
+            ExportError::FieldChildCountMismatch {
+                fields: 2,
+                children: 1
+            }
+        );
+    }
+}
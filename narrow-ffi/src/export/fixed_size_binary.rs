@@ -0,0 +1,136 @@
+//! Export support for fixed-size binary items.
+//!
+//! [`FixedSizeArray<u8, N>`] values are stored in a fixed-size primitive
+//! layout, which has the buffers of Arrow's fixed-size binary type. Only the
+//! `w:N` format string is defined here.
+
+use core::ffi::CStr;
+
+use narrow::fixed_size::FixedSizeArray;
+
+use crate::parse_width;
+
+use super::ArrowType;
+
+/// The length of the format buffer, which fits `w:` with any `usize` width
+/// and a NUL.
+const FORMAT_LEN: usize = 24;
+
+/// Builds the NUL-terminated `w:<width>` format string.
+const fn format(width: usize) -> [u8; FORMAT_LEN] {
+    let mut buffer = [0; FORMAT_LEN];
+    buffer[0] = b'w';
+    buffer[1] = b':';
+    let mut divisor = 1_usize;
+    while width.strict_div(divisor) >= 10 {
+        divisor = divisor.strict_mul(10);
+    }
+    let mut len = 2;
+    while divisor > 0 {
+        let digit = width.strict_div(divisor).strict_rem(10).to_le_bytes()[0];
+        buffer[len] = b'0'.strict_add(digit);
+        len = len.strict_add(1);
+        divisor = divisor.strict_div(10);
+    }
+    buffer
+}
+
+/// The format string of a fixed-size binary of `N` bytes.
+struct FixedSizeBinaryFormat<const N: usize>;
+
+impl<const N: usize> FixedSizeBinaryFormat<N> {
+    /// The NUL-padded format string.
+    const BYTES: [u8; FORMAT_LEN] = format(N);
+    /// The format string.
+    const FORMAT: &'static CStr = match CStr::from_bytes_until_nul(&Self::BYTES) {
+        Ok(format) => format,
+        Err(_) => panic!("fixed-size binary format strings are NUL-terminated"),
+    };
+}
+
+/// The byte width is parsed on import, like the width of a fixed-size list.
+impl<const N: usize> ArrowType for FixedSizeArray<u8, N> {
+    const FORMAT: &'static CStr = FixedSizeBinaryFormat::<N>::FORMAT;
+
+    fn matches_format(format: &CStr) -> bool {
+        parse_width(format, b"w:") == Some(N)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::vec::Vec;
+    use core::{ffi::CStr, slice};
+
+    use narrow::{array::Array, buffer::SliceBuffer, collection::Collection};
+
+    use crate::{
+        ARROW_FLAG_NULLABLE,
+        import::{Import, ImportError},
+    };
+
+    use super::{super::Export, ArrowType, FixedSizeArray};
+
+    #[test]
+    fn type_format_strings_match_arrow() {
+        assert_eq!(FixedSizeArray::<u8, 0>::FORMAT, c"w:0");
+        assert_eq!(FixedSizeArray::<u8, 16>::FORMAT, c"w:16");
+        assert_eq!(
+            FixedSizeArray::<u8, { usize::MAX }>::FORMAT.to_bytes(),
+            alloc::format!("w:{}", usize::MAX).as_bytes()
+        );
+        assert_eq!(<Option<FixedSizeArray<u8, 4>>>::FORMAT, c"w:4");
+        assert_eq!(<Option<FixedSizeArray<u8, 4>>>::FLAGS, ARROW_FLAG_NULLABLE);
+
+        assert!(FixedSizeArray::<u8, 4>::matches_format(c"w:04"));
+        assert!(!FixedSizeArray::<u8, 4>::matches_format(c"w:"));
+        assert!(!FixedSizeArray::<u8, 4>::matches_format(c"w:4x"));
+        assert!(!FixedSizeArray::<u8, 4>::matches_format(c"+w:4"));
+    }
+
+    #[test]
+    fn round_trips_fixed_size_binary() {
+        let source = [
+            Some(FixedSizeArray::from([1_u8, 2, 3])),
+            None,
+            Some(FixedSizeArray::from([4, 5, 6])),
+        ]
+        .into_iter()
+        .collect::<Array<Option<FixedSizeArray<u8, 3>>>>();
+        let (array, schema) = source.slice(1, 2).export().expect("export array");
+        // SAFETY: The exported schema has a live, null-terminated format string.
+        assert_eq!(unsafe { CStr::from_ptr(schema.format) }, c"w:3");
+        assert_eq!((array.offset, array.length, array.null_count), (1, 2, 1));
+        // SAFETY: The exported array owns a two-entry buffer pointer array.
+        let buffers = unsafe { slice::from_raw_parts(array.buffers, 2) };
+        // SAFETY: The values buffer holds three 3-byte values.
+        let bytes = unsafe { slice::from_raw_parts(buffers[1].cast::<u8>(), 9) };
+        assert_eq!(bytes[6..], [4, 5, 6]);
+
+        // SAFETY: The exported structures retain valid buffers for the
+        // lifetime of the imported array.
+        let imported: Array<Option<FixedSizeArray<u8, 3>>, SliceBuffer<'_>> =
+            unsafe { Import::import(&array, &schema) }.expect("import array");
+        assert_eq!(
+            imported.into_iter_owned().collect::<Vec<_>>(),
+            [None, Some(FixedSizeArray::from([4, 5, 6]))]
+        );
+    }
+
+    #[test]
+    fn rejects_mismatched_byte_width() {
+        let source = [FixedSizeArray::from([0_u8; 16])]
+            .into_iter()
+            .collect::<Array<FixedSizeArray<u8, 16>>>();
+        let (array, schema) = source.export().expect("export array");
+
+        // SAFETY: The exported structures remain valid.
+        let error = unsafe {
+            <Array<FixedSizeArray<u8, 8>, SliceBuffer<'_>> as Import>::import(&array, &schema)
+                .expect_err("width mismatch")
+        };
+        assert_eq!(error, ImportError::UnexpectedFormat);
+    }
+}
@@ -3,7 +3,7 @@
 extern crate alloc;
 
 use alloc::{boxed::Box, ffi::CString, format};
-use core::{borrow::Borrow, ffi::c_void, ptr};
+use core::{ffi::c_void, ptr};
 
 use narrow::{
     bitmap::ValidityBitmap,
@@ -15,7 +15,7 @@ use narrow::{
 
 use crate::{ARROW_FLAG_NULLABLE, ArrowArray, ArrowSchema};
 
-use super::{ArrowArrayLayout, ExportError, release_schema};
+use super::{AlignedBitmaps, ArrowArrayLayout, ExportError, release_schema, validity_null_count};
 
 impl<T, const N: usize, Storage> ArrowArrayLayout for FixedSizeList<T, N, NonNullable, Storage>
 where
@@ -30,7 +30,7 @@ where
         ArrowSchema::fixed_size_list::<N, NonNullable>(<T::Memory<Storage>>::schema())
     }
 
-    fn buffers(&self) -> Self::Buffers {
+    fn buffers(&self, _bitmaps: &mut AlignedBitmaps) -> Self::Buffers {
         [ptr::null()]
     }
 
@@ -53,21 +53,14 @@ where
     }
 
     fn null_count(&self) -> i64 {
-        i64::try_from(ValidityBitmap::null_count(self.buffer_ref()))
-            .expect("null count exceeds i64")
+        validity_null_count(self, self.buffer_ref())
     }
 
-    fn offset(&self) -> usize {
-        self.buffer_ref()
+    fn buffers(&self, bitmaps: &mut AlignedBitmaps) -> Self::Buffers {
+        let validity = self
+            .buffer_ref()
             .bitmap_ref()
-            .map_or(0, narrow::bitmap::Bitmap::bit_offset)
-    }
-
-    fn buffers(&self) -> Self::Buffers {
-        let validity = self.buffer_ref().bitmap_ref().map(|bitmap| {
-            let values: &[u8] = bitmap.buffer_ref().borrow();
-            values.as_ptr().cast()
-        });
+            .map(|bitmap| bitmaps.pointer(bitmap));
         [validity.unwrap_or(ptr::null())]
     }
 
@@ -122,21 +115,21 @@ struct FixedSizeListSchemaData {
 mod tests {
     extern crate alloc;
 
-    use alloc::sync::Arc;
+    use alloc::{sync::Arc, vec::Vec};
     use core::{ffi::CStr, slice};
 
     use narrow::{
         array::Array,
         bitmap::Bitmap,
-        buffer::{ArcBuffer, ArrayBuffer},
-        collection::flatten::Flatten,
+        buffer::{ArcBuffer, ArrayBuffer, SliceBuffer},
+        collection::{Collection, flatten::Flatten},
         layout::{fixed_size_list::FixedSizeList, fixed_size_primitive::FixedSizePrimitive},
         validity::Validity,
     };
 
-    use crate::ARROW_FLAG_NULLABLE;
+    use crate::{ARROW_FLAG_NULLABLE, import::Import};
 
-    use super::super::{Export, ExportError};
+    use super::super::Export;
 
     #[test]
     fn exports_fixed_size_list_child_without_copying_values() {
@@ -247,32 +240,71 @@ mod tests {
     }
 
     #[test]
-    fn rejects_non_zero_nullable_fixed_size_list_offset() {
+    fn realigns_nullable_fixed_size_list_validity_bit_offset() {
         let values_layout = FixedSizePrimitive::from_buffer([1, 2, 3, 4]);
         let flattened = Flatten::try_from_parts(values_layout).expect("valid fixed-size list");
-        let bitmap = Bitmap::<ArrayBuffer<4>>::try_from_parts([0b0000_1100, 0, 0, 0], 2, 2)
+        let bitmap = Bitmap::<ArrayBuffer<4>>::try_from_parts([0b0000_1000, 0, 0, 0], 2, 2)
             .expect("valid bitmap");
         let validity = Validity::try_from_parts(flattened, bitmap).expect("valid parts");
-        let array: Array<Option<[i32; 2]>, ArrayBuffer<4>> =
+        let narrow_array: Array<Option<[i32; 2]>, ArrayBuffer<4>> =
             Array::from_buffer(FixedSizeList::from_buffer(validity));
 
-        let error = array.export().expect_err("non-zero offset");
+        let (array, schema) = narrow_array.export().expect("export array");
 
-        assert_eq!(error, ExportError::NonZeroOffset { offset: 2 });
+        assert_eq!(array.offset, 0);
+        assert_eq!(array.null_count, 1);
+        // SAFETY: The exported structures retain the child values and the
+        // validity copy for the lifetime of the imported array.
+        let imported: Array<Option<[i32; 2]>, SliceBuffer<'_>> =
+            unsafe { Import::import(&array, &schema) }.expect("import array");
+        assert_eq!(
+            imported.into_iter_owned().collect::<Vec<_>>(),
+            [None, Some([3, 4])]
+        );
     }
 
     #[test]
-    fn rejects_non_zero_fixed_size_list_child_offset() {
+    fn realigns_fixed_size_list_child_validity_bit_offset() {
         let bitmap = Bitmap::<ArrayBuffer<4>>::try_from_parts([0b0011_1100, 0, 0, 0], 4, 2)
             .expect("valid bitmap");
         let validity = Validity::try_from_parts([1, 2, 3, 4], bitmap).expect("valid parts");
         let values_layout = FixedSizePrimitive::from_buffer(validity);
         let flattened = Flatten::try_from_parts(values_layout).expect("valid fixed-size list");
-        let array: Array<[Option<i32>; 2], ArrayBuffer<4>> =
+        let narrow_array: Array<[Option<i32>; 2], ArrayBuffer<4>> =
             Array::from_buffer(FixedSizeList::from_buffer(flattened));
 
-        let error = array.export().expect_err("non-zero child offset");
+        let (array, schema) = narrow_array.export().expect("export array");
+
+        // SAFETY: The exported structures retain the child values and the
+        // validity copy for the lifetime of the imported array.
+        let imported: Array<[Option<i32>; 2], SliceBuffer<'_>> =
+            unsafe { Import::import(&array, &schema) }.expect("import array");
+        assert_eq!(
+            imported.into_iter_owned().collect::<Vec<_>>(),
+            [[Some(1), Some(2)], [Some(3), Some(4)]]
+        );
+    }
 
-        assert_eq!(error, ExportError::NonZeroOffset { offset: 2 });
+    #[test]
+    fn exports_sliced_fixed_size_list_with_complete_child() {
+        let narrow_array = [[1_i32, 2], [3, 4], [5, 6]]
+            .into_iter()
+            .collect::<Array<[i32; 2]>>();
+
+        let (array, schema) = narrow_array.slice(1, 1).export().expect("export array");
+
+        assert_eq!(array.offset, 1);
+        assert_eq!(array.length, 1);
+        // SAFETY: The exported array owns a one-entry child pointer array.
+        let children = unsafe { slice::from_raw_parts(array.children, 1) };
+        // SAFETY: The child pointer refers to an array retained by the parent.
+        let child = unsafe { &*children[0] };
+        assert_eq!(child.offset, 0);
+        assert_eq!(child.length, 6);
+        // SAFETY: The exported structures retain the child values for the
+        // lifetime of the imported array.
+        let imported: Array<[i32; 2], SliceBuffer<'_>> =
+            unsafe { Import::import(&array, &schema) }.expect("import array");
+        assert_eq!(imported.into_iter_owned().collect::<Vec<_>>(), [[3, 4]]);
     }
 }
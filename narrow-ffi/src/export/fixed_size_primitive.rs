@@ -13,7 +13,7 @@ use narrow::{
 
 use crate::{ArrowArray, ArrowSchema};
 
-use super::{ArrowArrayLayout, ArrowType};
+use super::{AlignedBitmaps, ArrowArrayLayout, ArrowType, validity_null_count};
 
 impl<T, Storage> ArrowArrayLayout for FixedSizePrimitive<T, NonNullable, Storage>
 where
@@ -27,7 +27,7 @@ where
         ArrowSchema::flat::<T>()
     }
 
-    fn buffers(&self) -> Self::Buffers {
+    fn buffers(&self, _bitmaps: &mut AlignedBitmaps) -> Self::Buffers {
         let values: &[T] = self.buffer_ref().borrow();
         [ptr::null(), values.as_ptr().cast()]
     }
@@ -46,22 +46,12 @@ where
     }
 
     fn null_count(&self) -> i64 {
-        i64::try_from(ValidityBitmap::null_count(self.buffer_ref()))
-            .expect("null count exceeds i64")
+        validity_null_count(self, self.buffer_ref())
     }
 
-    fn offset(&self) -> usize {
-        self.buffer_ref()
-            .bitmap_ref()
-            .map_or(0, narrow::bitmap::Bitmap::bit_offset)
-    }
-
-    fn buffers(&self) -> Self::Buffers {
+    fn buffers(&self, bitmaps: &mut AlignedBitmaps) -> Self::Buffers {
         let validity = self.buffer_ref();
-        let validity_values = validity.bitmap_ref().map(|bitmap| {
-            let values: &[u8] = bitmap.buffer_ref().borrow();
-            values.as_ptr().cast()
-        });
+        let validity_values = validity.bitmap_ref().map(|bitmap| bitmaps.pointer(bitmap));
         let values: &[T] = validity.child_ref().borrow();
         [
             validity_values.unwrap_or(ptr::null()),
@@ -110,22 +100,30 @@ impl ArrowType for f64 {
     const FORMAT: &'static core::ffi::CStr = c"g";
 }
 
+/// Arrow has no format for `bf16`, so only `f16` is exported.
+#[cfg(feature = "half")]
+#[cfg_attr(docsrs, doc(cfg(feature = "half")))]
+impl ArrowType for narrow::fixed_size::f16 {
+    const FORMAT: &'static core::ffi::CStr = c"e";
+}
+
 #[cfg(test)]
 mod tests {
     extern crate alloc;
 
-    use alloc::sync::Arc;
+    use alloc::{sync::Arc, vec::Vec};
     use core::{ffi::CStr, slice};
 
     use narrow::{
         array::Array,
         bitmap::Bitmap,
-        buffer::{ArcBuffer, ArrayBuffer},
+        buffer::{ArcBuffer, ArrayBuffer, SliceBuffer},
+        collection::Collection,
         layout::fixed_size_primitive::FixedSizePrimitive,
         validity::Validity,
     };
 
-    use crate::{ARROW_FLAG_NULLABLE, export::ExportError};
+    use crate::{ARROW_FLAG_NULLABLE, import::Import};
 
     use super::{super::Export, ArrowType};
 
@@ -273,15 +271,69 @@ mod tests {
     }
 
     #[test]
-    fn rejects_non_zero_nullable_primitive_offset() {
+    fn realigns_nullable_primitive_validity_bit_offset() {
         let bitmap = Bitmap::<ArrayBuffer<3>>::try_from_parts([0b0001_0100, 0, 0], 3, 2)
             .expect("valid bitmap");
         let validity = Validity::try_from_parts([1, 0, 3], bitmap).expect("valid parts");
-        let array: Array<Option<i32>, ArrayBuffer<3>> =
+        let narrow_array: Array<Option<i32>, ArrayBuffer<3>> =
             Array::from_buffer(FixedSizePrimitive::from_buffer(validity));
 
-        let error = array.export().expect_err("non-zero offset");
+        let (array, schema) = narrow_array.export().expect("export array");
+
+        assert_eq!(array.offset, 0);
+        assert_eq!(array.null_count, 1);
+        // SAFETY: The exported array owns a two-entry buffer pointer array.
+        let buffers = unsafe { slice::from_raw_parts(array.buffers, 2) };
+        // SAFETY: The validity buffer points to a copy retained by the export.
+        assert_eq!(unsafe { *buffers[0].cast::<u8>() }, 0b0000_0101);
+        // SAFETY: The exported structures retain valid buffers for the
+        // lifetime of the imported array.
+        let imported: Array<Option<i32>, SliceBuffer<'_>> =
+            unsafe { Import::import(&array, &schema) }.expect("import array");
+        assert_eq!(
+            imported.into_iter_owned().collect::<Vec<_>>(),
+            [Some(1), None, Some(3)]
+        );
+    }
 
-        assert_eq!(error, ExportError::NonZeroOffset { offset: 2 });
+    #[test]
+    fn exports_sliced_primitive_without_copying_values() {
+        let values = Arc::<[i32]>::from([1, 2, 3, 4]);
+        let data = values.as_ptr();
+        let narrow_array: Array<i32, ArcBuffer> =
+            Array::from_buffer(FixedSizePrimitive::from_buffer(values));
+
+        let (array, schema) = narrow_array.slice(1, 2).export().expect("export array");
+
+        assert_eq!(array.offset, 1);
+        assert_eq!(array.length, 2);
+        // SAFETY: The exported array owns a two-entry buffer pointer array.
+        let buffers = unsafe { slice::from_raw_parts(array.buffers, 2) };
+        assert_eq!(buffers[1], data.cast());
+        // SAFETY: The exported structures retain a valid value buffer for the
+        // lifetime of the imported array.
+        let imported: Array<i32, SliceBuffer<'_>> =
+            unsafe { Import::import(&array, &schema) }.expect("import array");
+        assert_eq!(imported.into_iter_owned().collect::<Vec<_>>(), [2, 3]);
+    }
+
+    #[test]
+    fn counts_nulls_in_sliced_nullable_primitive() {
+        let narrow_array = [Some(1), None, Some(3), None]
+            .into_iter()
+            .collect::<Array<Option<i32>>>();
+
+        let (array, schema) = narrow_array.slice(1, 2).export().expect("export array");
+
+        assert_eq!(array.offset, 1);
+        assert_eq!(array.null_count, 1);
+        // SAFETY: The exported structures retain valid buffers for the
+        // lifetime of the imported array.
+        let imported: Array<Option<i32>, SliceBuffer<'_>> =
+            unsafe { Import::import(&array, &schema) }.expect("import array");
+        assert_eq!(
+            imported.into_iter_owned().collect::<Vec<_>>(),
+            [None, Some(3)]
+        );
     }
 }
@@ -0,0 +1,176 @@
+//! Export support for [`ListView`].
+
+use core::{borrow::Borrow, ffi::c_void, ptr};
+
+use narrow::{
+    bitmap::ValidityBitmap,
+    buffer::{Buffer, BufferRef},
+    collection::ChildRef,
+    layout::{ArrayItem, list_view::ListView},
+    nullability::{NonNullable, Nullable},
+};
+
+use crate::{ArrowArray, ArrowListOffset, ArrowSchema};
+
+use super::{AlignedBitmaps, ArrowArrayLayout, ExportError, validity_null_count};
+
+impl<T, OffsetItem, Storage> ArrowArrayLayout for ListView<T, NonNullable, OffsetItem, Storage>
+where
+    T: ArrayItem,
+    OffsetItem: ArrowListOffset,
+    Storage: Buffer,
+    T::Memory<Storage>: ArrowArrayLayout + 'static,
+{
+    type Buffers = [*const c_void; 3];
+    type Children = [ArrowArray; 1];
+
+    fn schema() -> ArrowSchema {
+        ArrowSchema::list::<NonNullable>(OffsetItem::VIEW_FORMAT, <T::Memory<Storage>>::schema())
+    }
+
+    fn buffers(&self, _bitmaps: &mut AlignedBitmaps) -> Self::Buffers {
+        let lists = self.buffer_ref();
+        let offsets: &[OffsetItem] = lists.buffer_ref().borrow();
+        let sizes: &[OffsetItem] = lists.sizes().borrow();
+        [ptr::null(), offsets.as_ptr().cast(), sizes.as_ptr().cast()]
+    }
+
+    fn children(&self) -> Result<Self::Children, ExportError> {
+        Ok([self.buffer_ref().child_ref().child_array()?])
+    }
+}
+
+impl<T, OffsetItem, Storage> ArrowArrayLayout for ListView<T, Nullable, OffsetItem, Storage>
+where
+    T: ArrayItem,
+    OffsetItem: ArrowListOffset,
+    Storage: Buffer,
+    T::Memory<Storage>: ArrowArrayLayout + 'static,
+{
+    type Buffers = [*const c_void; 3];
+    type Children = [ArrowArray; 1];
+
+    fn schema() -> ArrowSchema {
+        ArrowSchema::list::<Nullable>(OffsetItem::VIEW_FORMAT, <T::Memory<Storage>>::schema())
+    }
+
+    fn null_count(&self) -> i64 {
+        validity_null_count(self, self.buffer_ref())
+    }
+
+    fn buffers(&self, bitmaps: &mut AlignedBitmaps) -> Self::Buffers {
+        let validity = self.buffer_ref();
+        let validity_values = validity.bitmap_ref().map(|bitmap| bitmaps.pointer(bitmap));
+        let lists = validity.child_ref();
+        let offsets: &[OffsetItem] = lists.buffer_ref().borrow();
+        let sizes: &[OffsetItem] = lists.sizes().borrow();
+        [
+            validity_values.unwrap_or(ptr::null()),
+            offsets.as_ptr().cast(),
+            sizes.as_ptr().cast(),
+        ]
+    }
+
+    fn children(&self) -> Result<Self::Children, ExportError> {
+        Ok([self.buffer_ref().child_ref().child_ref().child_array()?])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::{sync::Arc, vec};
+    use core::{ffi::CStr, slice};
+
+    use narrow::{
+        array::Array,
+        buffer::ArcBuffer,
+        layout::{
+            fixed_size_primitive::FixedSizePrimitive,
+            list_view::{ListView, ListViews, ViewedList},
+        },
+    };
+
+    use crate::{ARROW_FLAG_NULLABLE, ArrowListOffset};
+
+    use super::super::Export;
+
+    #[test]
+    fn list_view_format_strings_match_arrow() {
+        assert_eq!(<i32 as ArrowListOffset>::VIEW_FORMAT, c"+vl");
+        assert_eq!(<i64 as ArrowListOffset>::VIEW_FORMAT, c"+vL");
+    }
+
+    #[test]
+    fn exports_list_view_without_copying_buffers() {
+        let value_storage = Arc::<[u16]>::from([1, 2, 3]);
+        let offset_storage = Arc::<[i32]>::from([1, 0]);
+        let size_storage = Arc::<[i32]>::from([2, 1]);
+        let values_weak = Arc::downgrade(&value_storage);
+        let values_data = value_storage.as_ptr();
+        let offsets_data = offset_storage.as_ptr();
+        let sizes_data = size_storage.as_ptr();
+        let lists = ListViews::try_from_parts(
+            FixedSizePrimitive::from_buffer(value_storage),
+            offset_storage,
+            size_storage,
+        )
+        .expect("valid list views");
+        let narrow_array: Array<ViewedList<u16>, ArcBuffer> =
+            Array::from_buffer(ListView::from_buffer(lists));
+
+        let (array, schema) = narrow_array.export().expect("export array");
+
+        assert_eq!((array.length, array.null_count), (2, 0));
+        assert_eq!((array.n_buffers, array.n_children), (3, 1));
+        // SAFETY: The exported array owns a three-entry buffer pointer array.
+        let buffers = unsafe { slice::from_raw_parts(array.buffers, 3) };
+        assert!(buffers[0].is_null());
+        assert_eq!(buffers[1], offsets_data.cast());
+        assert_eq!(buffers[2], sizes_data.cast());
+        // SAFETY: The exported array owns a one-entry child pointer array.
+        let children = unsafe { slice::from_raw_parts(array.children, 1) };
+        // SAFETY: The child array is retained by the parent.
+        let child = unsafe { &*children[0] };
+        assert_eq!(child.length, 3);
+        // SAFETY: The child owns a two-entry buffer pointer array.
+        let child_buffers = unsafe { slice::from_raw_parts(child.buffers, 2) };
+        assert_eq!(child_buffers[1], values_data.cast());
+
+        // SAFETY: The exported schema has a live, null-terminated format string.
+        assert_eq!(unsafe { CStr::from_ptr(schema.format) }, c"+vl");
+        assert_eq!((schema.flags, schema.n_children), (0, 1));
+
+        assert!(values_weak.upgrade().is_some());
+        drop(array);
+        assert!(values_weak.upgrade().is_none());
+        drop(schema);
+    }
+
+    #[test]
+    fn exports_nullable_large_list_view() {
+        let narrow_array = [Some(vec![1_i8]), None, Some(vec![2, 3])]
+            .into_iter()
+            .map(|list| list.map(ViewedList::<_, i64>::new))
+            .collect::<Array<Option<ViewedList<i8, i64>>>>();
+
+        let (array, schema) = narrow_array.export().expect("export array");
+
+        assert_eq!(array.null_count, 1);
+        // SAFETY: The exported array owns a three-entry buffer pointer array.
+        let buffers = unsafe { slice::from_raw_parts(array.buffers, 3) };
+        // SAFETY: The offsets and sizes buffers hold one value per list.
+        let offsets = unsafe { slice::from_raw_parts(buffers[1].cast::<i64>(), 3) };
+        // SAFETY: The sizes buffer is the third buffer, and holds one aligned
+        // `i64` per list for the whole unsliced array of three lists.
+        let sizes = unsafe { slice::from_raw_parts(buffers[2].cast::<i64>(), 3) };
+        assert_eq!(
+            (offsets, sizes),
+            ([0, 1, 1].as_slice(), [1, 0, 2].as_slice())
+        );
+        // SAFETY: The exported schema has a live, null-terminated format string.
+        assert_eq!(unsafe { CStr::from_ptr(schema.format) }, c"+vL");
+        assert_eq!(schema.flags, ARROW_FLAG_NULLABLE);
+    }
+}
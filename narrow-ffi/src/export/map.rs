@@ -0,0 +1,276 @@
+//! Export support for [`Map`].
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use core::{borrow::Borrow, ffi::c_void, ptr};
+
+use narrow::{
+    bitmap::ValidityBitmap,
+    buffer::{Buffer, BufferRef},
+    collection::ChildRef,
+    layout::{
+        ArrayItem, Layout, MemoryLayout,
+        map::{Map, MapEntries, MapItem},
+    },
+    length::Length,
+    nullability::{NonNullable, Nullability, Nullable},
+};
+
+use crate::{ARROW_FLAG_MAP_KEYS_SORTED, ArrowArray, ArrowSchema};
+
+use super::{
+    AlignedBitmaps, ArrowArrayLayout, ExportError, release_array, release_schema,
+    validity_null_count,
+};
+
+impl<K, V, Storage, U> ArrowArrayLayout for Map<K, V, NonNullable, Storage, U>
+where
+    K: Layout,
+    V: ArrayItem,
+    Storage: Buffer,
+    U: MapItem<K, V> + 'static,
+    K::Memory<NonNullable, Storage>: ArrowArrayLayout + 'static,
+    V::Memory<Storage>: ArrowArrayLayout + 'static,
+{
+    type Buffers = [*const c_void; 2];
+    type Children = [ArrowArray; 1];
+
+    fn schema() -> ArrowSchema {
+        ArrowSchema::map::<NonNullable, K, V, Storage, U>()
+    }
+
+    fn buffers(&self, _bitmaps: &mut AlignedBitmaps) -> Self::Buffers {
+        let offsets: &[i32] = self.buffer_ref().buffer_ref().borrow();
+        [ptr::null(), offsets.as_ptr().cast()]
+    }
+
+    fn children(&self) -> Result<Self::Children, ExportError> {
+        Ok([entries_array(self.buffer_ref().child_ref())?])
+    }
+}
+
+impl<K, V, Storage, U> ArrowArrayLayout for Map<K, V, Nullable, Storage, U>
+where
+    K: Layout,
+    V: ArrayItem,
+    Storage: Buffer,
+    U: MapItem<K, V> + 'static,
+    K::Memory<NonNullable, Storage>: ArrowArrayLayout + 'static,
+    V::Memory<Storage>: ArrowArrayLayout + 'static,
+{
+    type Buffers = [*const c_void; 2];
+    type Children = [ArrowArray; 1];
+
+    fn schema() -> ArrowSchema {
+        ArrowSchema::map::<Nullable, K, V, Storage, U>()
+    }
+
+    fn null_count(&self) -> i64 {
+        validity_null_count(self, self.buffer_ref())
+    }
+
+    fn buffers(&self, bitmaps: &mut AlignedBitmaps) -> Self::Buffers {
+        let validity = self.buffer_ref();
+        let validity_values = validity.bitmap_ref().map(|bitmap| bitmaps.pointer(bitmap));
+        let offsets: &[i32] = validity.child_ref().buffer_ref().borrow();
+        [
+            validity_values.unwrap_or(ptr::null()),
+            offsets.as_ptr().cast(),
+        ]
+    }
+
+    fn children(&self) -> Result<Self::Children, ExportError> {
+        Ok([entries_array(self.buffer_ref().child_ref().child_ref())?])
+    }
+}
+
+impl ArrowSchema {
+    /// Builds a map schema with the `entries` struct of its key and value
+    /// fields.
+    ///
+    /// Maps whose owned type iterates in key order set
+    /// [`ARROW_FLAG_MAP_KEYS_SORTED`].
+    fn map<Nulls, K, V, Storage, U>() -> Self
+    where
+        Nulls: Nullability,
+        K: Layout,
+        V: ArrayItem,
+        Storage: Buffer,
+        U: MapItem<K, V>,
+        K::Memory<NonNullable, Storage>: ArrowArrayLayout,
+        V::Memory<Storage>: ArrowArrayLayout,
+    {
+        let mut key = <K::Memory<NonNullable, Storage>>::schema();
+        key.name = c"key".as_ptr();
+        let mut value = <V::Memory<Storage>>::schema();
+        value.name = c"value".as_ptr();
+
+        let mut private = Box::new(EntriesSchemaData {
+            children: [key, value],
+            child_pointers: [ptr::null_mut(); 2],
+        });
+        private.child_pointers = private.children.each_mut().map(ptr::from_mut);
+        let children = private.child_pointers.as_mut_ptr();
+        let entries = Self {
+            format: c"+s".as_ptr(),
+            name: c"entries".as_ptr(),
+            metadata: ptr::null(),
+            flags: 0,
+            n_children: 2,
+            children,
+            dictionary: ptr::null_mut(),
+            release: Some(release_schema::<EntriesSchemaData>),
+            private_data: Box::into_raw(private).cast(),
+        };
+
+        let mut schema = Self::list::<Nulls>(c"+m", entries);
+        if U::KEYS_SORTED {
+            schema.flags |= ARROW_FLAG_MAP_KEYS_SORTED;
+        }
+        schema
+    }
+}
+
+/// Data retained by `ArrowSchema::private_data` for the entries of a map.
+struct EntriesSchemaData {
+    /// Key and value schemas owned by the export.
+    children: [ArrowSchema; 2],
+    /// Arrow C Data child pointers.
+    child_pointers: [*mut ArrowSchema; 2],
+}
+
+/// Builds the `entries` struct array of a map, borrowing the key and value
+/// storage retained by the map.
+///
+/// The entries are a non-nullable struct, so the array has no validity
+/// buffer.
+fn entries_array<K, V, Storage>(
+    entries: &MapEntries<K, V, Storage>,
+) -> Result<ArrowArray, ExportError>
+where
+    K: Layout,
+    V: ArrayItem,
+    Storage: Buffer,
+    K::Memory<NonNullable, Storage>: ArrowArrayLayout + 'static,
+    V::Memory<Storage>: ArrowArrayLayout + 'static,
+{
+    let length = i64::try_from(entries.len()).expect("array length exceeds i64");
+    let offset = i64::try_from(entries.offset()).expect("array offset exceeds i64");
+    let fields = entries.child_ref();
+
+    let mut private = Box::new(EntriesArrayData {
+        buffers: [ptr::null()],
+        children: [fields.0.child_array()?, fields.1.child_array()?],
+        child_pointers: [ptr::null_mut(); 2],
+    });
+    private.child_pointers = private.children.each_mut().map(ptr::from_mut);
+    let buffers = private.buffers.as_mut_ptr();
+    let children = private.child_pointers.as_mut_ptr();
+
+    Ok(ArrowArray {
+        length,
+        null_count: 0,
+        offset,
+        n_buffers: 1,
+        n_children: 2,
+        buffers,
+        children,
+        dictionary: ptr::null_mut(),
+        release: Some(release_array::<EntriesArrayData>),
+        private_data: Box::into_raw(private).cast(),
+    })
+}
+
+/// Data retained by `ArrowArray::private_data` for the entries of a map.
+struct EntriesArrayData {
+    /// Arrow C Data buffer pointers: only the absent validity buffer.
+    buffers: [*const c_void; 1],
+    /// Key and value arrays owned by the export.
+    children: [ArrowArray; 2],
+    /// Arrow C Data child pointers.
+    child_pointers: [*mut ArrowArray; 2],
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    extern crate std;
+
+    use alloc::{collections::BTreeMap, vec};
+    use core::{ffi::CStr, slice};
+    use std::collections::HashMap;
+
+    use narrow::{array::Array, layout::map::Entries};
+
+    use crate::{ARROW_FLAG_MAP_KEYS_SORTED, ARROW_FLAG_NULLABLE};
+
+    use super::super::Export;
+
+    #[test]
+    fn exports_map_entries_struct() {
+        let narrow_array = [BTreeMap::from([(2_u32, 2_u8), (1, 1)]), BTreeMap::new()]
+            .into_iter()
+            .collect::<Array<BTreeMap<u32, u8>>>();
+
+        let (array, schema) = narrow_array.export().expect("export array");
+
+        assert_eq!((array.length, array.n_buffers, array.n_children), (2, 2, 1));
+        // SAFETY: The exported array owns a two-entry buffer pointer array.
+        let buffers = unsafe { slice::from_raw_parts(array.buffers, 2) };
+        // SAFETY: The offsets buffer holds three values retained by the export.
+        let offsets = unsafe { slice::from_raw_parts(buffers[1].cast::<i32>(), 3) };
+        assert_eq!(offsets, [0, 2, 2]);
+        // SAFETY: The exported schema has a live, null-terminated format string.
+        assert_eq!(unsafe { CStr::from_ptr(schema.format) }, c"+m");
+        assert_eq!(schema.flags, ARROW_FLAG_MAP_KEYS_SORTED);
+
+        // SAFETY: The exported array owns a one-entry child pointer array.
+        let array_children = unsafe { slice::from_raw_parts(array.children, 1) };
+        // SAFETY: The child pointer refers to an array retained by the parent.
+        let entries_array = unsafe { &*array_children[0] };
+        assert_eq!((entries_array.length, entries_array.null_count), (2, 0));
+        assert_eq!((entries_array.n_buffers, entries_array.n_children), (1, 2));
+
+        // SAFETY: The exported schema owns a one-entry child pointer array.
+        let schema_children = unsafe { slice::from_raw_parts(schema.children, 1) };
+        // SAFETY: The child pointer refers to a schema retained by the parent.
+        let entries_schema = unsafe { &*schema_children[0] };
+        // SAFETY: The entries schema has a live, null-terminated name.
+        assert_eq!(unsafe { CStr::from_ptr(entries_schema.name) }, c"entries");
+        // SAFETY: The entries schema has a live, null-terminated format string.
+        assert_eq!(unsafe { CStr::from_ptr(entries_schema.format) }, c"+s");
+        assert_eq!(entries_schema.flags, 0);
+        // SAFETY: The entries schema owns a two-entry child pointer array.
+        let fields = unsafe { slice::from_raw_parts(entries_schema.children, 2) };
+        // SAFETY: The field schemas are retained by the entries schema.
+        let key = unsafe { &*fields[0] };
+        // SAFETY: The value schema is the second entry of the child pointer
+        // array, and is retained by the entries schema like the key schema.
+        let value = unsafe { &*fields[1] };
+        // SAFETY: The field schemas have live, null-terminated names.
+        assert_eq!(unsafe { CStr::from_ptr(key.name) }, c"key");
+        // SAFETY: The value schema has a field name owned by the export, which
+        // is null-terminated like the key name.
+        assert_eq!(unsafe { CStr::from_ptr(value.name) }, c"value");
+        // SAFETY: The key schema has a live, null-terminated format string.
+        assert_eq!(unsafe { CStr::from_ptr(key.format) }, c"I");
+        assert_eq!((key.flags, value.flags), (0, 0));
+    }
+
+    #[test]
+    fn exports_unsorted_nullable_maps() {
+        let narrow_array = [Some(HashMap::from([(1_u8, Some(true))])), None]
+            .into_iter()
+            .collect::<Array<Option<HashMap<u8, Option<bool>>>>>();
+        let (array, schema) = narrow_array.slice(1, 1).export().expect("export array");
+        assert_eq!((array.offset, array.length, array.null_count), (1, 1, 1));
+        assert_eq!(schema.flags, ARROW_FLAG_NULLABLE);
+
+        let entries = [Entries::new(vec![(1_u8, 2_u8), (1, 3)])]
+            .into_iter()
+            .collect::<Array<Entries<u8, u8>>>();
+        let (_, entries_schema) = entries.export().expect("export array");
+        assert_eq!(entries_schema.flags, 0);
+    }
+}
@@ -2,25 +2,70 @@
 
 extern crate alloc;
 
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{boxed::Box, ffi::CString, vec::Vec};
 use core::{
+    borrow::Borrow,
     ffi::{CStr, c_void},
     fmt, ptr,
 };
 
-use narrow::{array::Array, buffer::Buffer, layout::ArrayItem, length::Length};
+use narrow::{
+    array::Array,
+    bitmap::{Bitmap, ValidityBitmap},
+    buffer::{Buffer, BufferRef},
+    collection::Collection,
+    layout::{ArrayItem, MemoryLayout},
+};
 
-use crate::{ARROW_FLAG_NULLABLE, ArrowArray, ArrowSchema};
+#[cfg(feature = "std")]
+use crate::Field;
+use crate::{ARROW_FLAG_NULLABLE, ArrowArray, ArrowSchema, window_null_count};
 
+/// Export support for [`narrow::layout::binary::Binary`].
+mod binary;
+/// Export support for [`narrow::layout::binary_view::BinaryView`] and
+/// [`narrow::layout::utf8_view::Utf8View`].
+mod binary_view;
 /// Export support for [`narrow::layout::boolean::Boolean`].
 mod boolean;
+/// Export support for the [`narrow::decimal`] types.
+mod decimal;
+/// Export support for [`narrow::layout::dictionary::DictionaryEncoded`].
+mod dictionary;
+/// Export support for [`narrow::layout::extension::Extension`].
+mod extension;
+/// Export support for [`Field`] and [`Schema`](crate::Schema) descriptions.
+#[cfg(feature = "std")]
+mod field;
+#[cfg(feature = "std")]
+pub use field::ExportRecordBatch;
+/// Export support for fixed-size binary items.
+mod fixed_size_binary;
 /// Export support for [`narrow::layout::fixed_size_list::FixedSizeList`].
 mod fixed_size_list;
 /// Export support for [`narrow::layout::fixed_size_primitive::FixedSizePrimitive`].
 mod fixed_size_primitive;
+/// Export support for [`narrow::layout::list_view::ListView`].
+mod list_view;
+/// Export support for [`narrow::layout::map::Map`].
+mod map;
+/// Export support for [`narrow::layout::null::NullLayout`].
+mod null;
+/// Export support for [`narrow::layout::run_end_encoded::RunEndEncoded`].
+mod run_end_encoded;
+/// Export support for [`narrow::layout::struct_array::Struct`].
+mod struct_array;
+/// Export support for the [`narrow::temporal`] types.
+mod temporal;
+/// Export support for [`narrow::layout::utf8::Utf8`].
+mod utf8;
 /// Export support for [`narrow::layout::variable_size_list::VariableSizeList`].
 mod variable_size_list;
 
+/// Export support for the Arrow C Stream Interface.
+mod stream;
+pub use stream::ExportStream;
+
 /// A type with an [Arrow C Data format string].
 ///
 /// [Arrow C Data format string]: https://arrow.apache.org/docs/format/CDataInterface.html#data-type-description-format-strings
@@ -42,45 +87,74 @@ pub trait ArrowType {
     const FORMAT: &'static CStr;
     /// Arrow C Data schema flags.
     const FLAGS: i64 = 0;
+
+    /// Returns whether an imported format string describes this type.
+    ///
+    /// This compares with [`ArrowType::FORMAT`], unless the type has more
+    /// than one spelling.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow_ffi::ArrowType;
+    ///
+    /// assert!(i32::matches_format(c"i"));
+    /// assert!(!i32::matches_format(c"I"));
+    /// ```
+    #[must_use]
+    fn matches_format(format: &CStr) -> bool {
+        format == Self::FORMAT
+    }
 }
 
 impl<T: ArrowType> ArrowType for Option<T> {
     const FORMAT: &'static CStr = T::FORMAT;
     const FLAGS: i64 = T::FLAGS | ARROW_FLAG_NULLABLE;
+
+    fn matches_format(format: &CStr) -> bool {
+        T::matches_format(format)
+    }
 }
 
 /// Error returned when an [`Array`] cannot be exported.
 ///
 /// Export rejects representation details it cannot preserve faithfully instead
-/// of silently changing their meaning. The enum is non-exhaustive so further
+/// of silently changing their meaning. Every layout that currently implements
+/// [`Export`] can be represented, so the variants describe field descriptions
+/// that do not fit the exported schema. The enum is non-exhaustive so further
 /// unsupported Arrow conditions can be reported explicitly.
-///
-/// # Examples
-///
-/// ```
-/// use narrow::{array::Array, bitmap::Bitmap, layout::boolean::Boolean};
-/// use narrow_ffi::{Export, ExportError};
-///
-/// let bitmap = Bitmap::try_from_parts(vec![0], 1, 1).unwrap();
-/// let array = Array::<bool>::from_buffer(Boolean::from_buffer(bitmap));
-/// assert_eq!(array.export().unwrap_err(), ExportError::NonZeroOffset { offset: 1 });
-/// ```
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum ExportError {
-    /// The array has a non-zero offset, which is not currently supported.
-    NonZeroOffset {
-        /// Unsupported array offset.
-        offset: usize,
+    /// A field name contains a NUL byte, which a C string cannot represent.
+    InvalidFieldName,
+    /// The nullability of a field does not match its exported layout.
+    NullabilityMismatch {
+        /// Whether the exported layout is nullable.
+        nullable: bool,
+    },
+    /// A field describes a different number of children than its exported
+    /// schema has.
+    FieldChildCountMismatch {
+        /// Number of child fields.
+        fields: usize,
+        /// Number of child schemas.
+        children: usize,
     },
 }
 
 impl fmt::Display for ExportError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            Self::NonZeroOffset { offset } => {
-                write!(f, "array offset ({offset}) is not supported")
-            }
+            Self::InvalidFieldName => write!(f, "field name contains a NUL byte"),
+            Self::NullabilityMismatch { nullable } => write!(
+                f,
+                "field nullability does not match the exported layout (nullable: {nullable})"
+            ),
+            Self::FieldChildCountMismatch { fields, children } => write!(
+                f,
+                "field has {fields} children, but the exported schema has {children}"
+            ),
         }
     }
 }
@@ -89,7 +163,11 @@ impl core::error::Error for ExportError {}
 
 /// Export an [`Array`] through the Arrow C Data Interface.
 ///
-/// Only arrays with an offset of zero are currently supported.
+/// A sliced array exports its window through the `offset` and `length`
+/// fields of the [`ArrowArray`], so the foreign consumer shares the complete
+/// buffers. Bitmaps carry their own bit offset, which is folded into the
+/// buffer pointer when it is a whole number of bytes; other bitmaps are
+/// copied into a byte-aligned buffer retained by the export.
 ///
 /// Export consumes the array because the returned C handles must retain its
 /// storage after Rust leaves this call. That storage and the exposed pointer
@@ -99,20 +177,26 @@ impl core::error::Error for ExportError {}
 /// # Examples
 ///
 /// ```
-/// use narrow::array::Array;
-/// use narrow_ffi::Export;
+/// use narrow::{array::Array, buffer::SliceBuffer, collection::Collection};
+/// use narrow_ffi::{Export, Import};
 ///
 /// let values = [1, 2].into_iter().collect::<Array<i32>>();
 /// let (array, schema) = values.export().unwrap();
 /// assert!(!array.is_released() && !schema.is_released());
+///
+/// let sliced = [1, 2, 3].into_iter().collect::<Array<i32>>().slice(1, 2);
+/// let (array, schema) = sliced.export().unwrap();
+/// // SAFETY: The export retains its buffers until `array` is dropped.
+/// let imported = unsafe { Array::<i32, SliceBuffer>::import(&array, &schema) }.unwrap();
+/// assert_eq!(imported.into_iter_owned().collect::<Vec<_>>(), [2, 3]);
 /// ```
 pub trait Export {
     /// Consumes `self` and returns an [`ArrowArray`] and [`ArrowSchema`].
     ///
     /// # Errors
     ///
-    /// Returns [`ExportError::NonZeroOffset`] when the array has a non-zero
-    /// offset.
+    /// Returns an [`ExportError`] when the array cannot be represented
+    /// through the Arrow C Data Interface.
     ///
     /// # Examples
     ///
@@ -124,10 +208,46 @@ pub trait Export {
     /// assert!(values.export().is_ok());
     /// ```
     fn export(self) -> Result<(ArrowArray, ArrowSchema), ExportError>;
+
+    /// Consumes `self` and returns an [`ArrowArray`] and an [`ArrowSchema`]
+    /// described by `field`.
+    ///
+    /// The name of `field` is attached to the exported schema, and its
+    /// children to the child schemas. Its metadata is added to the metadata
+    /// of the layout, like the keys of an extension type, and replaces values
+    /// with the same key.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ExportError`] when the array cannot be exported, or when
+    /// `field` does not match the exported schema.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::array::Array;
+    /// use narrow_ffi::{Export, ExportError, Field};
+    ///
+    /// let values = [Some(1), None].into_iter().collect::<Array<Option<i32>>>();
+    /// assert!(values.clone().export_field(&Field::new("id", true)).is_ok());
+    /// assert_eq!(
+    ///     values.export_field(&Field::new("id", false)).unwrap_err(),
+    ///     ExportError::NullabilityMismatch { nullable: true }
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    fn export_field(self, field: &Field) -> Result<(ArrowArray, ArrowSchema), ExportError>
+    where
+        Self: Sized,
+    {
+        let (array, schema) = self.export()?;
+        Ok((array, schema.with_field(field)?))
+    }
 }
 
 /// A layout that describes its [`ArrowArray`] fields.
-trait ArrowArrayLayout: Length + Sized {
+trait ArrowArrayLayout: MemoryLayout + Sized {
     /// Buffer pointers exposed by the exported array.
     type Buffers: AsRef<[*const c_void]> + AsMut<[*const c_void]> + Default + 'static;
     /// Child arrays exposed by the exported array.
@@ -136,27 +256,24 @@ trait ArrowArrayLayout: Length + Sized {
     /// Builds the Arrow schema for this layout.
     fn schema() -> ArrowSchema;
 
-    /// Returns the number of null elements, or `-1` when unknown.
+    /// Returns the number of null elements in the layout's window, or `-1`
+    /// when unknown.
     fn null_count(&self) -> i64 {
         0
     }
 
-    /// Returns the item offset into the buffers.
-    fn offset(&self) -> usize {
-        0
-    }
-
-    /// Returns the array's buffer pointers.
-    fn buffers(&self) -> Self::Buffers;
+    /// Returns the array's buffer pointers, retaining bitmap copies in
+    /// `bitmaps`.
+    fn buffers(&self, bitmaps: &mut AlignedBitmaps) -> Self::Buffers;
 
     /// Returns the array's child pointers.
     fn children(&self) -> Result<Self::Children, ExportError> {
         Ok(Self::Children::default())
     }
 
-    /// Returns the dictionary array pointer.
-    fn dictionary(&self) -> *mut ArrowArray {
-        ptr::null_mut()
+    /// Returns the dictionary array, retained by the exported array.
+    fn dictionary(&self) -> Result<Option<ArrowArray>, ExportError> {
+        Ok(None)
     }
 
     /// Builds a child [`ArrowArray`] borrowing storage retained by its parent.
@@ -164,22 +281,21 @@ trait ArrowArrayLayout: Length + Sized {
     where
         Self: 'static,
     {
-        let offset = self.offset();
-        if offset != 0 {
-            return Err(ExportError::NonZeroOffset { offset });
-        }
-
         let length = i64::try_from(self.len()).expect("array length exceeds i64");
+        let offset = i64::try_from(self.offset()).expect("array offset exceeds i64");
         let null_count = self.null_count();
-        let dictionary = self.dictionary();
+        let mut bitmaps = AlignedBitmaps::default();
+        let buffers = self.buffers(&mut bitmaps);
         let mut private = Box::new(ArrayData::<(), Self>::new(
             (),
-            self.buffers(),
+            buffers,
+            bitmaps,
             self.children()?,
+            self.dictionary()?,
         ));
         private.set_child_pointers();
 
-        Ok(private.into_array(length, null_count, dictionary))
+        Ok(private.into_array(length, offset, null_count))
     }
 
     /// Builds an [`ArrowArray`] and [`ArrowSchema`] from this layout.
@@ -187,51 +303,97 @@ trait ArrowArrayLayout: Length + Sized {
     where
         Self: 'static,
     {
-        let offset = self.offset();
-        if offset != 0 {
-            return Err(ExportError::NonZeroOffset { offset });
-        }
-
         // Pin the layout before asking it for pointers into its storage.
         let mut private = Box::new(ArrayData::<Self, Self> {
             buffers: Self::Buffers::default(),
+            bitmaps: AlignedBitmaps::default(),
             children: Self::Children::default(),
             child_pointers: Vec::new(),
+            dictionary: None,
             owner: self,
         });
-        private.buffers = private.owner.buffers();
-        private.children = private.owner.children()?;
-        private.set_child_pointers();
+        let data = &mut *private;
+        data.buffers = data.owner.buffers(&mut data.bitmaps);
+        data.children = data.owner.children()?;
+        data.dictionary = data.owner.dictionary()?;
+        data.set_child_pointers();
 
         // Convert platform-sized layout metadata into the Arrow C ABI fields.
         let length = i64::try_from(private.owner.len()).expect("array length exceeds i64");
+        let offset = i64::try_from(private.owner.offset()).expect("array offset exceeds i64");
         let null_count = private.owner.null_count();
-        let dictionary = private.owner.dictionary();
-        let array = private.into_array(length, null_count, dictionary);
+        let array = private.into_array(length, offset, null_count);
 
         Ok((array, Self::schema()))
     }
 }
 
+/// Returns the Arrow null count of the window of a nullable `layout`.
+fn validity_null_count<Layout, Validity>(layout: &Layout, validity: &Validity) -> i64
+where
+    Layout: MemoryLayout,
+    Validity: ValidityBitmap,
+{
+    let null_count = window_null_count(validity, layout.offset(), layout.len());
+    i64::try_from(null_count).expect("null count exceeds i64")
+}
+
+/// Byte-aligned copies of exported bitmaps.
+///
+/// Arrow C Data bitmaps start at a byte boundary and share the array offset
+/// with the other buffers. A bitmap whose own bit offset is not a multiple of
+/// eight is therefore copied, and the copy is retained here by the export.
+#[derive(Default)]
+struct AlignedBitmaps(Vec<Vec<u8>>);
+
+impl AlignedBitmaps {
+    /// Returns a pointer to a buffer whose first bit is the first bit of
+    /// `bitmap`.
+    fn pointer<Storage: Buffer>(&mut self, bitmap: &Bitmap<Storage>) -> *const c_void {
+        let bit_offset = bitmap.bit_offset();
+        if bit_offset.is_multiple_of(8) {
+            let bytes: &[u8] = bitmap.buffer_ref().borrow();
+            bytes.split_at(bit_offset / 8).1.as_ptr().cast()
+        } else {
+            let (bytes, _, _) = bitmap.iter_views().collect::<Bitmap>().into_parts();
+            let pointer = bytes.as_ptr().cast();
+            self.0.push(bytes);
+            pointer
+        }
+    }
+}
+
 /// Data retained by `ArrowArray::private_data` for an array export.
 struct ArrayData<Owner, Layout: ArrowArrayLayout> {
     /// Arrow C Data buffer pointers.
     buffers: Layout::Buffers,
+    /// Bitmap copies referenced by `buffers`.
+    bitmaps: AlignedBitmaps,
     /// Child arrays owned by the export.
     children: Layout::Children,
     /// Arrow C Data child pointers.
     child_pointers: Vec<*mut ArrowArray>,
+    /// Dictionary array owned by the export.
+    dictionary: Option<ArrowArray>,
     /// Owner retained until the array is released.
     owner: Owner,
 }
 
 impl<Owner: 'static, Layout: ArrowArrayLayout + 'static> ArrayData<Owner, Layout> {
     /// Creates private data for an exported array.
-    fn new(owner: Owner, buffers: Layout::Buffers, children: Layout::Children) -> Self {
+    fn new(
+        owner: Owner,
+        buffers: Layout::Buffers,
+        bitmaps: AlignedBitmaps,
+        children: Layout::Children,
+        dictionary: Option<ArrowArray>,
+    ) -> Self {
         Self {
             buffers,
+            bitmaps,
             children,
             child_pointers: Vec::new(),
+            dictionary,
             owner,
         }
     }
@@ -247,12 +409,7 @@ impl<Owner: 'static, Layout: ArrowArrayLayout + 'static> ArrayData<Owner, Layout
     }
 
     /// Builds an [`ArrowArray`] backed by this private data.
-    fn into_array(
-        mut self: Box<Self>,
-        length: i64,
-        null_count: i64,
-        dictionary: *mut ArrowArray,
-    ) -> ArrowArray {
+    fn into_array(mut self: Box<Self>, length: i64, offset: i64, null_count: i64) -> ArrowArray {
         let n_buffers =
             i64::try_from(self.buffers.as_ref().len()).expect("buffer count exceeds i64");
         let n_children = i64::try_from(self.child_pointers.len()).expect("child count exceeds i64");
@@ -268,12 +425,16 @@ impl<Owner: 'static, Layout: ArrowArrayLayout + 'static> ArrayData<Owner, Layout
         } else {
             self.child_pointers.as_mut_ptr()
         };
+        let dictionary = self
+            .dictionary
+            .as_mut()
+            .map_or(ptr::null_mut(), ptr::from_mut);
         let private_data = Box::into_raw(self).cast();
 
         ArrowArray {
             length,
             null_count,
-            offset: 0,
+            offset,
             n_buffers,
             n_children,
             buffers: buffer_pointers,
@@ -299,11 +460,16 @@ where
 impl ArrowSchema {
     /// Builds a childless schema for an [`ArrowType`].
     fn flat<T: ArrowType>() -> Self {
+        Self::childless(T::FORMAT, T::FLAGS)
+    }
+
+    /// Builds a childless schema with `format` and `flags`.
+    fn childless(format: &'static CStr, flags: i64) -> Self {
         Self {
-            format: T::FORMAT.as_ptr(),
+            format: format.as_ptr(),
             name: c"".as_ptr(),
             metadata: ptr::null(),
-            flags: T::FLAGS,
+            flags,
             n_children: 0,
             children: ptr::null_mut(),
             dictionary: ptr::null_mut(),
@@ -311,6 +477,46 @@ impl ArrowSchema {
             private_data: ptr::null_mut(),
         }
     }
+
+    /// Returns this schema with `metadata` in the binary metadata format and,
+    /// if any, `name`.
+    ///
+    /// This schema is moved into the private data of the returned schema,
+    /// which shares its format, flags, children and dictionary.
+    fn describe(self, name: Option<CString>, metadata: Option<Vec<u8>>) -> Self {
+        let private = Box::new(DescribedSchemaData {
+            schema: self,
+            name,
+            metadata,
+        });
+        Self {
+            format: private.schema.format,
+            name: private
+                .name
+                .as_ref()
+                .map_or(private.schema.name, |owned| owned.as_ptr()),
+            metadata: private
+                .metadata
+                .as_ref()
+                .map_or(ptr::null(), |bytes| bytes.as_ptr().cast()),
+            flags: private.schema.flags,
+            n_children: private.schema.n_children,
+            children: private.schema.children,
+            dictionary: private.schema.dictionary,
+            release: Some(release_schema::<DescribedSchemaData>),
+            private_data: Box::into_raw(private).cast(),
+        }
+    }
+}
+
+/// Data retained by `ArrowSchema::private_data` for a described schema.
+struct DescribedSchemaData {
+    /// The described schema, released with this data.
+    schema: ArrowSchema,
+    /// Name of the described schema.
+    name: Option<CString>,
+    /// Binary metadata of the described schema.
+    metadata: Option<Vec<u8>>,
 }
 
 /// Releases private data retained by an [`ArrowArray`].
@@ -0,0 +1,91 @@
+//! Export support for [`NullLayout`].
+
+use core::ffi::{CStr, c_void};
+
+use narrow::{
+    buffer::Buffer,
+    layout::null::NullLayout,
+    length::Length,
+    nullability::{NonNullable, Nullable},
+};
+
+use crate::{ArrowArray, ArrowSchema};
+
+use super::{AlignedBitmaps, ArrowArrayLayout, ArrowType};
+
+impl<Storage: Buffer> ArrowArrayLayout for NullLayout<NonNullable, Storage> {
+    type Buffers = [*const c_void; 0];
+    type Children = [ArrowArray; 0];
+
+    fn schema() -> ArrowSchema {
+        ArrowSchema::flat::<()>()
+    }
+
+    fn buffers(&self, _bitmaps: &mut AlignedBitmaps) -> Self::Buffers {
+        []
+    }
+}
+
+/// Every item of a nullable null array is null.
+impl<Storage: Buffer> ArrowArrayLayout for NullLayout<Nullable, Storage> {
+    type Buffers = [*const c_void; 0];
+    type Children = [ArrowArray; 0];
+
+    fn schema() -> ArrowSchema {
+        ArrowSchema::flat::<Option<()>>()
+    }
+
+    fn null_count(&self) -> i64 {
+        i64::try_from(self.len()).expect("null count exceeds i64")
+    }
+
+    fn buffers(&self, _bitmaps: &mut AlignedBitmaps) -> Self::Buffers {
+        []
+    }
+}
+
+impl ArrowType for () {
+    const FORMAT: &'static CStr = c"n";
+}
+
+#[cfg(test)]
+mod tests {
+    use core::ffi::CStr;
+
+    use narrow::array::Array;
+
+    use crate::ARROW_FLAG_NULLABLE;
+
+    use super::{super::Export, ArrowType};
+
+    #[test]
+    fn type_format_string_matches_arrow() {
+        assert_eq!(<()>::FORMAT, c"n");
+    }
+
+    #[test]
+    fn exports_null_arrays_without_buffers() {
+        let units = [(), (), ()].into_iter().collect::<Array<()>>();
+        let (array, schema) = units.export().expect("export array");
+        assert_eq!((array.length, array.null_count), (3, 0));
+        assert_eq!((array.n_buffers, array.n_children), (0, 0));
+        // SAFETY: The exported schema has a live, null-terminated format string.
+        assert_eq!(unsafe { CStr::from_ptr(schema.format) }, c"n");
+        assert_eq!(schema.flags, 0);
+
+        let nulls = [None, None, None]
+            .into_iter()
+            .collect::<Array<Option<()>>>();
+        let (nulls_array, nulls_schema) = nulls.slice(1, 2).export().expect("export array");
+        assert_eq!(
+            (
+                nulls_array.offset,
+                nulls_array.length,
+                nulls_array.null_count
+            ),
+            (1, 2, 2)
+        );
+        assert_eq!(nulls_array.n_buffers, 0);
+        assert_eq!(nulls_schema.flags, ARROW_FLAG_NULLABLE);
+    }
+}
@@ -0,0 +1,208 @@
+//! Export support for [`RunEndEncoded`].
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use core::{borrow::Borrow, ffi::c_void, ptr};
+
+use narrow::{
+    buffer::{Buffer, BufferRef},
+    collection::ChildRef,
+    layout::{
+        ArrayItem,
+        fixed_size_primitive::FixedSizePrimitive,
+        run_end_encoded::{RunEnd, RunEndEncoded},
+    },
+};
+
+use crate::{ArrowArray, ArrowSchema};
+
+use super::{AlignedBitmaps, ArrayData, ArrowArrayLayout, ArrowType, ExportError, release_schema};
+
+impl<R, V, Storage> ArrowArrayLayout for RunEndEncoded<R, V, Storage>
+where
+    R: RunEnd + ArrowType,
+    V: ArrayItem,
+    Storage: Buffer,
+    V::Memory<Storage>: ArrowArrayLayout + 'static,
+{
+    type Buffers = [*const c_void; 0];
+    type Children = [ArrowArray; 2];
+
+    fn schema() -> ArrowSchema {
+        ArrowSchema::run_end_encoded(ArrowSchema::flat::<R>(), <V::Memory<Storage>>::schema())
+    }
+
+    fn buffers(&self, _bitmaps: &mut AlignedBitmaps) -> Self::Buffers {
+        []
+    }
+
+    fn children(&self) -> Result<Self::Children, ExportError> {
+        let runs = self.buffer_ref();
+        Ok([
+            run_ends_array(runs.buffer_ref().borrow()),
+            runs.child_ref().child_array()?,
+        ])
+    }
+}
+
+/// Builds the run ends child [`ArrowArray`], borrowing the run ends retained
+/// by its parent.
+///
+/// The window of a run-end encoded array applies to its logical items, so
+/// the run ends are always exported completely.
+fn run_ends_array<R: RunEnd + ArrowType>(run_ends: &[R]) -> ArrowArray {
+    let length = i64::try_from(run_ends.len()).expect("run end count exceeds i64");
+    let private = Box::new(ArrayData::<(), FixedSizePrimitive<R>>::new(
+        (),
+        [ptr::null(), run_ends.as_ptr().cast()],
+        AlignedBitmaps::default(),
+        [],
+        None,
+    ));
+    private.into_array(length, 0, 0)
+}
+
+impl ArrowSchema {
+    /// Builds a run-end encoded schema and retains its run ends and values
+    /// schemas.
+    fn run_end_encoded(mut run_ends: Self, mut values: Self) -> Self {
+        run_ends.name = c"run_ends".as_ptr();
+        values.name = c"values".as_ptr();
+        let mut private = Box::new(RunEndEncodedSchemaData {
+            children: [run_ends, values],
+            child_pointers: [ptr::null_mut(); 2],
+        });
+        private.child_pointers = [
+            ptr::from_mut(&mut private.children[0]),
+            ptr::from_mut(&mut private.children[1]),
+        ];
+
+        let children = private.child_pointers.as_mut_ptr();
+        let private_data = Box::into_raw(private).cast();
+        Self {
+            format: c"+r".as_ptr(),
+            name: c"".as_ptr(),
+            metadata: ptr::null(),
+            flags: 0,
+            n_children: 2,
+            children,
+            dictionary: ptr::null_mut(),
+            release: Some(release_schema::<RunEndEncodedSchemaData>),
+            private_data,
+        }
+    }
+}
+
+/// Data retained by `ArrowSchema::private_data` for a run-end encoded array.
+struct RunEndEncodedSchemaData {
+    /// Child schemas owned by the export.
+    children: [ArrowSchema; 2],
+    /// Arrow C Data child pointers.
+    child_pointers: [*mut ArrowSchema; 2],
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::sync::Arc;
+    use core::{ffi::CStr, slice};
+
+    use narrow::{
+        array::Array,
+        buffer::ArcBuffer,
+        layout::{
+            MemoryLayout,
+            fixed_size_primitive::FixedSizePrimitive,
+            run_end_encoded::{Run, RunEndEncoded, RunEnds},
+        },
+    };
+
+    use crate::{ARROW_FLAG_NULLABLE, ArrowSchema};
+
+    use super::super::Export;
+
+    /// Returns the format, name and flags of the child schema at `index`.
+    fn child_schema(schema: &ArrowSchema, index: usize) -> (&CStr, &CStr, i64) {
+        // SAFETY: The exported schema owns a two-entry child pointer array.
+        let children = unsafe { slice::from_raw_parts(schema.children, 2) };
+        // SAFETY: The child schemas are retained by the parent.
+        let child = unsafe { &*children[index] };
+        // SAFETY: The child schema has a live, null-terminated format string.
+        let format = unsafe { CStr::from_ptr(child.format) };
+        // SAFETY: The child schema has a live, null-terminated name string.
+        let name = unsafe { CStr::from_ptr(child.name) };
+        (format, name, child.flags)
+    }
+
+    #[test]
+    fn exports_run_end_encoded_without_copying_buffers() {
+        let run_end_storage = Arc::<[i32]>::from([2, 5]);
+        let value_storage = Arc::<[u64]>::from([10, 20]);
+        let run_ends_weak = Arc::downgrade(&run_end_storage);
+        let run_ends_data = run_end_storage.as_ptr();
+        let values_data = value_storage.as_ptr();
+        let runs = RunEnds::try_from_parts(
+            run_end_storage,
+            FixedSizePrimitive::from_buffer(value_storage),
+        )
+        .expect("valid run ends");
+        let narrow_array: Array<Run<i32, u64>, ArcBuffer> =
+            Array::from_buffer(RunEndEncoded::from_buffer(runs).slice(1, 3));
+
+        let (array, schema) = narrow_array.export().expect("export array");
+
+        assert_eq!(array.length, 3);
+        assert_eq!(array.offset, 1);
+        assert_eq!(array.null_count, 0);
+        assert_eq!(array.n_buffers, 0);
+        assert_eq!(array.n_children, 2);
+        // SAFETY: The exported array owns a two-entry child pointer array.
+        let children = unsafe { slice::from_raw_parts(array.children, 2) };
+        // SAFETY: The children are retained by the parent.
+        let run_ends = unsafe { &*children[0] };
+        // SAFETY: The values child is the second entry of the child pointer
+        // array, and is retained by the parent like the run ends child.
+        let values = unsafe { &*children[1] };
+        assert_eq!((run_ends.length, run_ends.offset), (2, 0));
+        assert_eq!((values.length, values.offset), (2, 0));
+        // SAFETY: The children own two-entry buffer pointer arrays.
+        let run_end_buffers = unsafe { slice::from_raw_parts(run_ends.buffers, 2) };
+        // SAFETY: The values child was exported as a primitive array, which owns
+        // a two-entry buffer pointer array retained by the parent.
+        let value_buffers = unsafe { slice::from_raw_parts(values.buffers, 2) };
+        assert_eq!(run_end_buffers[1], run_ends_data.cast());
+        assert_eq!(value_buffers[1], values_data.cast());
+
+        // SAFETY: The exported schema has a live, null-terminated format string.
+        assert_eq!(unsafe { CStr::from_ptr(schema.format) }, c"+r");
+        assert_eq!(schema.n_children, 2);
+        assert_eq!(child_schema(&schema, 0), (c"i", c"run_ends", 0));
+        assert_eq!(child_schema(&schema, 1), (c"L", c"values", 0));
+
+        assert!(run_ends_weak.upgrade().is_some());
+        drop(array);
+        assert!(run_ends_weak.upgrade().is_none());
+        drop(schema);
+    }
+
+    #[test]
+    fn exports_nullable_values() {
+        let narrow_array = [Some(1_i8), Some(1), None]
+            .into_iter()
+            .map(Run::new)
+            .collect::<Array<Run<i16, Option<i8>>>>();
+
+        let (array, schema) = narrow_array.export().expect("export array");
+
+        assert_eq!(array.null_count, 0);
+        // SAFETY: The exported array owns a two-entry child pointer array.
+        let children = unsafe { slice::from_raw_parts(array.children, 2) };
+        // SAFETY: The values child is retained by the parent.
+        assert_eq!(unsafe { (*children[1]).null_count }, 1);
+        assert_eq!(schema.flags, 0);
+        assert_eq!(child_schema(&schema, 0).0, c"s");
+        assert_eq!(child_schema(&schema, 1).2, ARROW_FLAG_NULLABLE);
+    }
+}
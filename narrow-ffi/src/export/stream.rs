@@ -0,0 +1,237 @@
+//! Export iterators of [`Array`] values through the Arrow C Stream Interface.
+
+extern crate alloc;
+
+use alloc::{boxed::Box, ffi::CString, string::ToString};
+use core::{
+    ffi::{CStr, c_char, c_int},
+    ptr,
+};
+
+use narrow::{array::Array, buffer::Buffer, layout::ArrayItem};
+
+use crate::{ArrowArray, ArrowArrayStream, ArrowSchema};
+
+use super::ArrowArrayLayout;
+
+/// `errno` value returned by the stream callbacks when an array cannot be
+/// exported.
+const EIO: c_int = 5;
+
+/// Export an iterator of [`Array`] values through the Arrow C Stream
+/// Interface.
+///
+/// The iterator is moved into the stream and advanced lazily, one array per
+/// `get_next` call, so batches are exported only when the consumer asks for
+/// them. Every array of the stream has the same item type, which determines
+/// the stream schema.
+///
+/// An array that cannot be exported ends the call with an error code, and
+/// its [`ExportError`](crate::ExportError) message is reported by
+/// `get_last_error`.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{array::Array, collection::Collection};
+/// use narrow_ffi::{ArrayStreamReader, ExportStream};
+///
+/// let batches = [
+///     [1, 2].into_iter().collect::<Array<i32>>(),
+///     [3].into_iter().collect::<Array<i32>>(),
+/// ];
+/// let stream = batches.export_stream();
+/// // SAFETY: The stream was exported with the same item type.
+/// let reader = unsafe { ArrayStreamReader::<i32>::try_new(stream) }.unwrap();
+/// let values = reader
+///     .map(|array| array.unwrap().into_iter_owned().collect::<Vec<_>>())
+///     .collect::<Vec<_>>();
+/// assert_eq!(values, [vec![1, 2], vec![3]]);
+/// ```
+pub trait ExportStream {
+    /// Consumes `self` and returns an [`ArrowArrayStream`] producing its
+    /// arrays.
+    fn export_stream(self) -> ArrowArrayStream;
+}
+
+impl<I, T, Storage> ExportStream for I
+where
+    I: IntoIterator<Item = Array<T, Storage>>,
+    I::IntoIter: 'static,
+    T: ArrayItem,
+    Storage: Buffer,
+    T::Memory<Storage>: ArrowArrayLayout + 'static,
+{
+    fn export_stream(self) -> ArrowArrayStream {
+        let private = Box::new(StreamData {
+            iter: self.into_iter(),
+            last_error: None,
+        });
+
+        ArrowArrayStream {
+            get_schema: Some(get_schema::<T::Memory<Storage>>),
+            get_next: Some(get_next::<I::IntoIter, T, Storage>),
+            get_last_error: Some(get_last_error::<I::IntoIter>),
+            release: Some(release_stream::<I::IntoIter>),
+            private_data: Box::into_raw(private).cast(),
+        }
+    }
+}
+
+/// Data retained by `ArrowArrayStream::private_data` for a stream export.
+struct StreamData<I> {
+    /// Remaining arrays of the stream.
+    iter: I,
+    /// Message of the last failed callback, returned by `get_last_error`.
+    last_error: Option<CString>,
+}
+
+/// Returns the private data of a live exported stream.
+///
+/// # Safety
+///
+/// `stream` must be a live stream exported with a `StreamData<I>`.
+unsafe fn stream_data<'stream, I>(stream: *mut ArrowArrayStream) -> &'stream mut StreamData<I> {
+    // SAFETY: The caller guarantees a live stream.
+    let private_data = unsafe { (*stream).private_data };
+    // SAFETY: The caller guarantees that `private_data` was created with
+    // `Box::into_raw` for this exact `StreamData<I>` type.
+    unsafe { &mut *private_data.cast::<StreamData<I>>() }
+}
+
+/// Writes the schema of an exported stream.
+unsafe extern "C" fn get_schema<Layout: ArrowArrayLayout>(
+    _stream: *mut ArrowArrayStream,
+    out: *mut ArrowSchema,
+) -> c_int {
+    // SAFETY: The Arrow C stream contract passes a pointer to writable
+    // storage for a schema, which is owned by the consumer afterwards.
+    unsafe { ptr::write(out, Layout::schema()) };
+    0
+}
+
+/// Writes the next array of an exported stream.
+unsafe extern "C" fn get_next<I, T, Storage>(
+    stream: *mut ArrowArrayStream,
+    out: *mut ArrowArray,
+) -> c_int
+where
+    I: Iterator<Item = Array<T, Storage>>,
+    T: ArrayItem,
+    Storage: Buffer,
+    T::Memory<Storage>: ArrowArrayLayout + 'static,
+{
+    // SAFETY: The Arrow C stream contract passes the live stream to its
+    // producer-provided callback.
+    let data = unsafe { stream_data::<I>(stream) };
+    let next = data.iter.next().map_or_else(
+        || Ok(ArrowArray::default()),
+        |array| {
+            array
+                .into_buffer()
+                .export()
+                .map(|(exported, _schema)| exported)
+        },
+    );
+    match next {
+        Ok(array) => {
+            data.last_error = None;
+            // SAFETY: The Arrow C stream contract passes a pointer to
+            // writable storage for an array, which is owned by the consumer
+            // afterwards.
+            unsafe { ptr::write(out, array) };
+            0
+        }
+        Err(error) => {
+            data.last_error = CString::new(error.to_string()).ok();
+            EIO
+        }
+    }
+}
+
+/// Returns the message of the last failed callback of an exported stream.
+unsafe extern "C" fn get_last_error<I>(stream: *mut ArrowArrayStream) -> *const c_char {
+    // SAFETY: The Arrow C stream contract passes the live stream to its
+    // producer-provided callback.
+    let data = unsafe { stream_data::<I>(stream) };
+    data.last_error.as_deref().map_or(ptr::null(), CStr::as_ptr)
+}
+
+/// Releases private data retained by an [`ArrowArrayStream`].
+unsafe extern "C" fn release_stream<I>(stream: *mut ArrowArrayStream) {
+    // SAFETY: The Arrow C stream contract passes the live stream to its
+    // producer-provided callback.
+    let stream_ref = unsafe { &mut *stream };
+    let private_data = stream_ref.private_data;
+    stream_ref.get_schema = None;
+    stream_ref.get_next = None;
+    stream_ref.get_last_error = None;
+    stream_ref.release = None;
+    stream_ref.private_data = ptr::null_mut();
+
+    // SAFETY: `private_data` was created with `Box::into_raw` for this exact
+    // `StreamData<I>` type and is released only once.
+    unsafe { drop(Box::from_raw(private_data.cast::<StreamData<I>>())) };
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::{sync::Arc, vec::Vec};
+    use core::ffi::CStr;
+
+    use narrow::{
+        array::Array, buffer::ArcBuffer, layout::fixed_size_primitive::FixedSizePrimitive,
+    };
+
+    use crate::{ARROW_FLAG_NULLABLE, ArrowArray, ArrowSchema, export::ExportStream};
+
+    #[test]
+    fn exports_stream_schema() {
+        let mut stream = Vec::<Array<Option<i64>>>::new().export_stream();
+        let mut schema = ArrowSchema::default();
+        let get_schema = stream.get_schema.expect("live stream");
+
+        // SAFETY: The stream is live and `schema` is writable.
+        assert_eq!(unsafe { get_schema(&raw mut stream, &raw mut schema) }, 0);
+        // SAFETY: Exported schemas have a static null-terminated format.
+        assert_eq!(unsafe { CStr::from_ptr(schema.format) }, c"l");
+        assert_eq!(schema.flags, ARROW_FLAG_NULLABLE);
+    }
+
+    #[test]
+    fn exports_arrays_until_released_end_marker() {
+        let batches = (1..=2).map(|length| (0..length).collect::<Array<i32>>());
+        let mut stream = batches.export_stream();
+        let get_next = stream.get_next.expect("live stream");
+        let get_last_error = stream.get_last_error.expect("live stream");
+
+        let mut lengths = Vec::new();
+        for _ in 0..4 {
+            let mut array = ArrowArray::default();
+            // SAFETY: The stream is live and `array` is writable.
+            assert_eq!(unsafe { get_next(&raw mut stream, &raw mut array) }, 0);
+            lengths.push((array.length, array.is_released()));
+        }
+        // SAFETY: The stream is live.
+        assert!(unsafe { get_last_error(&raw mut stream) }.is_null());
+        assert_eq!(lengths, [(1, false), (2, false), (0, true), (0, true)]);
+    }
+
+    #[test]
+    fn release_drops_remaining_arrays() {
+        let storage = Arc::<[i32]>::from([1, 2, 3]);
+        let weak = Arc::downgrade(&storage);
+        let array: Array<i32, ArcBuffer> =
+            Array::from_buffer(FixedSizePrimitive::from_buffer(storage));
+        let mut stream = [array].export_stream();
+        let release = stream.release.expect("live stream");
+
+        // SAFETY: The stream is live and released only once here.
+        unsafe { release(&raw mut stream) };
+        assert!(stream.is_released());
+        assert!(stream.private_data.is_null());
+        assert!(weak.upgrade().is_none());
+    }
+}
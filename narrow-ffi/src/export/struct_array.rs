@@ -0,0 +1,231 @@
+//! Export support for [`Struct`].
+
+extern crate alloc;
+
+use alloc::{boxed::Box, ffi::CString, vec::Vec};
+use core::{ffi::c_void, ptr};
+
+use narrow::{
+    bitmap::ValidityBitmap,
+    buffer::{Buffer, BufferRef},
+    collection::ChildRef,
+    layout::struct_array::{Struct, StructFields, StructLayout},
+    nullability::{NonNullable, Nullability, Nullable},
+};
+
+use crate::{ARROW_FLAG_NULLABLE, ArrowArray, ArrowSchema};
+
+use super::{AlignedBitmaps, ArrowArrayLayout, ExportError, release_schema, validity_null_count};
+
+/// The children of a struct, as a nested list of pairs.
+trait SchemaColumns {
+    /// Appends the schemas of these children to `schemas`.
+    fn push_schemas(schemas: &mut Vec<ArrowSchema>);
+}
+
+impl SchemaColumns for () {
+    fn push_schemas(_schemas: &mut Vec<ArrowSchema>) {}
+}
+
+impl<Head: ArrowArrayLayout, Tail: SchemaColumns> SchemaColumns for (Head, Tail) {
+    fn push_schemas(schemas: &mut Vec<ArrowSchema>) {
+        schemas.push(Head::schema());
+        Tail::push_schemas(schemas);
+    }
+}
+
+/// References to the children of a struct, as a nested list of pairs.
+trait ArrayColumns {
+    /// Appends the arrays of these children to `arrays`.
+    fn push_arrays(self, arrays: &mut Vec<ArrowArray>) -> Result<(), ExportError>;
+}
+
+impl ArrayColumns for () {
+    fn push_arrays(self, _arrays: &mut Vec<ArrowArray>) -> Result<(), ExportError> {
+        Ok(())
+    }
+}
+
+impl<Head: ArrowArrayLayout + 'static, Tail: ArrayColumns> ArrayColumns for (&Head, Tail) {
+    fn push_arrays(self, arrays: &mut Vec<ArrowArray>) -> Result<(), ExportError> {
+        arrays.push(self.0.child_array()?);
+        self.1.push_arrays(arrays)
+    }
+}
+
+/// Returns the child arrays of the `fields` of a struct.
+fn arrays<Fields>(fields: &Fields) -> Result<Vec<ArrowArray>, ExportError>
+where
+    Fields: StructFields,
+    for<'fields> Fields::ChildrenRef<'fields>: ArrayColumns,
+{
+    let mut arrays = Vec::new();
+    fields.children_ref().push_arrays(&mut arrays)?;
+    Ok(arrays)
+}
+
+impl<T, Storage> ArrowArrayLayout for Struct<T, NonNullable, Storage>
+where
+    T: StructLayout,
+    Storage: Buffer,
+    T::Fields<Storage>: 'static,
+    <T::Fields<Storage> as StructFields>::Children: SchemaColumns,
+    for<'fields> <T::Fields<Storage> as StructFields>::ChildrenRef<'fields>: ArrayColumns,
+{
+    type Buffers = [*const c_void; 1];
+    type Children = Vec<ArrowArray>;
+
+    fn schema() -> ArrowSchema {
+        ArrowSchema::struct_array::<T, Storage, NonNullable>()
+    }
+
+    fn buffers(&self, _bitmaps: &mut AlignedBitmaps) -> Self::Buffers {
+        [ptr::null()]
+    }
+
+    fn children(&self) -> Result<Self::Children, ExportError> {
+        arrays(self.child_ref())
+    }
+}
+
+impl<T, Storage> ArrowArrayLayout for Struct<T, Nullable, Storage>
+where
+    T: StructLayout,
+    Storage: Buffer,
+    T::Fields<Storage>: 'static,
+    <T::Fields<Storage> as StructFields>::Children: SchemaColumns,
+    for<'fields> <T::Fields<Storage> as StructFields>::ChildrenRef<'fields>: ArrayColumns,
+{
+    type Buffers = [*const c_void; 1];
+    type Children = Vec<ArrowArray>;
+
+    fn schema() -> ArrowSchema {
+        ArrowSchema::struct_array::<T, Storage, Nullable>()
+    }
+
+    fn null_count(&self) -> i64 {
+        validity_null_count(self, self.buffer_ref())
+    }
+
+    fn buffers(&self, bitmaps: &mut AlignedBitmaps) -> Self::Buffers {
+        let validity = self.buffer_ref();
+        [validity
+            .bitmap_ref()
+            .map_or(ptr::null(), |bitmap| bitmaps.pointer(bitmap))]
+    }
+
+    fn children(&self) -> Result<Self::Children, ExportError> {
+        arrays(self.child_ref())
+    }
+}
+
+impl ArrowSchema {
+    /// Builds a struct schema with one child per field of `T`, named after
+    /// the field.
+    fn struct_array<T, Storage, Nulls>() -> Self
+    where
+        T: StructLayout,
+        Storage: Buffer,
+        Nulls: Nullability,
+        <T::Fields<Storage> as StructFields>::Children: SchemaColumns,
+    {
+        let mut children = Vec::with_capacity(T::FIELD_NAMES.len());
+        <T::Fields<Storage> as StructFields>::Children::push_schemas(&mut children);
+        let names = T::FIELD_NAMES
+            .iter()
+            .map(|name| CString::new(*name).expect("field names do not contain NUL bytes"))
+            .collect::<Vec<_>>();
+        let mut private = Box::new(StructSchemaData {
+            children,
+            child_pointers: Vec::new(),
+            names,
+        });
+        let data = &mut *private;
+        for (child, name) in data.children.iter_mut().zip(&data.names) {
+            child.name = name.as_ptr();
+        }
+        data.child_pointers = data.children.iter_mut().map(ptr::from_mut).collect();
+
+        let n_children = i64::try_from(data.children.len()).expect("child count exceeds i64");
+        let child_pointers = data.child_pointers.as_mut_ptr();
+        Self {
+            format: c"+s".as_ptr(),
+            name: c"".as_ptr(),
+            metadata: ptr::null(),
+            flags: if Nulls::NULLABLE {
+                ARROW_FLAG_NULLABLE
+            } else {
+                0
+            },
+            n_children,
+            children: child_pointers,
+            dictionary: ptr::null_mut(),
+            release: Some(release_schema::<StructSchemaData>),
+            private_data: Box::into_raw(private).cast(),
+        }
+    }
+}
+
+/// Data retained by `ArrowSchema::private_data` for a struct.
+struct StructSchemaData {
+    /// Child schemas owned by the export.
+    children: Vec<ArrowSchema>,
+    /// Arrow C Data child pointers.
+    child_pointers: Vec<*mut ArrowSchema>,
+    /// Names of the child schemas.
+    names: Vec<CString>,
+}
+
+#[cfg(test)]
+mod tests {
+    use core::{ffi::CStr, slice};
+
+    use narrow::array::Array;
+
+    use crate::ARROW_FLAG_NULLABLE;
+
+    use super::super::Export;
+
+    #[test]
+    fn exports_struct_children() {
+        let narrow_array = [Some((1_u8, Some(true))), None, Some((3, None))]
+            .into_iter()
+            .collect::<Array<Option<(u8, Option<bool>)>>>();
+
+        let (array, schema) = narrow_array.slice(1, 2).export().expect("export array");
+
+        assert_eq!((array.length, array.offset, array.null_count), (2, 1, 1));
+        assert_eq!((array.n_buffers, array.n_children), (1, 2));
+        // SAFETY: The exported array owns a two-entry child pointer array.
+        let children = unsafe { slice::from_raw_parts(array.children, 2) };
+        // SAFETY: The child arrays are retained by their parent.
+        let first = unsafe { &*children[0] };
+        // SAFETY: The second child pointer is retained by the parent like the
+        // first, and points to an initialized, aligned `ArrowArray`.
+        let second = unsafe { &*children[1] };
+        assert_eq!((first.length, first.offset), (3, 0));
+        assert_eq!((second.length, second.null_count), (3, 2));
+
+        // SAFETY: The exported schema has a live, null-terminated format string.
+        assert_eq!(unsafe { CStr::from_ptr(schema.format) }, c"+s");
+        assert_eq!((schema.flags, schema.n_children), (ARROW_FLAG_NULLABLE, 2));
+        // SAFETY: The exported schema owns a two-entry child pointer array.
+        let child_schemas = unsafe { slice::from_raw_parts(schema.children, 2) };
+        // SAFETY: The child schemas are retained by their parent and have
+        // null-terminated formats and names.
+        let id = unsafe { &*child_schemas[0] };
+        // SAFETY: The second child schema pointer is retained by the parent like
+        // the first, and points to an initialized, aligned `ArrowSchema`.
+        let flag = unsafe { &*child_schemas[1] };
+        // SAFETY: The field schemas use the static, null-terminated format of
+        // their layout.
+        assert_eq!(unsafe { CStr::from_ptr(id.format) }, c"C");
+        // SAFETY: Unnamed struct fields are named after their index with a
+        // null-terminated string owned by the export.
+        assert_eq!(unsafe { CStr::from_ptr(id.name) }, c"0");
+        // SAFETY: The name of the second field is owned by the export like the
+        // name of the first.
+        assert_eq!(unsafe { CStr::from_ptr(flag.name) }, c"1");
+        assert_eq!(flag.flags, ARROW_FLAG_NULLABLE);
+    }
+}
@@ -0,0 +1,232 @@
+//! Export support for the [`narrow::temporal`] types.
+//!
+//! Temporal types are fixed-size primitives, so only their format strings are
+//! defined here. They carry the unit and the time zone, which makes the
+//! fixed-size primitive import check both.
+
+use core::{ffi::CStr, marker::PhantomData};
+
+use narrow::temporal::{
+    Date32, Date64, Duration, IntervalDayTime, IntervalMonthDayNano, IntervalYearMonth,
+    Microsecond, Millisecond, Nanosecond, Second, Time32, Time64, TimeZone, Timestamp,
+};
+
+use super::ArrowType;
+
+/// The length of the timestamp format buffer: `ts`, the unit, `:`, the time
+/// zone name and a NUL.
+const TIMESTAMP_FORMAT_LEN: usize = 64;
+
+/// Builds the NUL-terminated `ts<unit>:<time zone>` format string.
+const fn timestamp_format(unit: u8, time_zone: Option<&str>) -> [u8; TIMESTAMP_FORMAT_LEN] {
+    let mut buffer = [0; TIMESTAMP_FORMAT_LEN];
+    buffer[0] = b't';
+    buffer[1] = b's';
+    buffer[2] = unit;
+    buffer[3] = b':';
+    if let Some(name) = time_zone {
+        let bytes = name.as_bytes();
+        assert!(
+            bytes.len() < TIMESTAMP_FORMAT_LEN.strict_sub(4),
+            "time zone name is too long"
+        );
+        let mut index = 0;
+        while index < bytes.len() {
+            assert!(bytes[index] != 0, "time zone name contains a NUL byte");
+            buffer[index.strict_add(4)] = bytes[index];
+            index = index.strict_add(1);
+        }
+    }
+    buffer
+}
+
+/// The format string of a timestamp with unit code `UNIT` in time zone `Tz`.
+struct TimestampFormat<const UNIT: u8, Tz>(PhantomData<Tz>);
+
+impl<const UNIT: u8, Tz: TimeZone> TimestampFormat<UNIT, Tz> {
+    /// The NUL-padded format string.
+    const BYTES: [u8; TIMESTAMP_FORMAT_LEN] = timestamp_format(UNIT, Tz::NAME);
+    /// The format string.
+    const FORMAT: &'static CStr = match CStr::from_bytes_until_nul(&Self::BYTES) {
+        Ok(format) => format,
+        Err(_) => panic!("timestamp format strings are NUL-terminated"),
+    };
+}
+
+impl ArrowType for Date32 {
+    const FORMAT: &'static CStr = c"tdD";
+}
+
+impl ArrowType for Date64 {
+    const FORMAT: &'static CStr = c"tdm";
+}
+
+impl ArrowType for Time32<Second> {
+    const FORMAT: &'static CStr = c"tts";
+}
+
+impl ArrowType for Time32<Millisecond> {
+    const FORMAT: &'static CStr = c"ttm";
+}
+
+impl ArrowType for Time64<Microsecond> {
+    const FORMAT: &'static CStr = c"ttu";
+}
+
+impl ArrowType for Time64<Nanosecond> {
+    const FORMAT: &'static CStr = c"ttn";
+}
+
+impl<Tz: TimeZone> ArrowType for Timestamp<Second, Tz> {
+    const FORMAT: &'static CStr = TimestampFormat::<b's', Tz>::FORMAT;
+}
+
+impl<Tz: TimeZone> ArrowType for Timestamp<Millisecond, Tz> {
+    const FORMAT: &'static CStr = TimestampFormat::<b'm', Tz>::FORMAT;
+}
+
+impl<Tz: TimeZone> ArrowType for Timestamp<Microsecond, Tz> {
+    const FORMAT: &'static CStr = TimestampFormat::<b'u', Tz>::FORMAT;
+}
+
+impl<Tz: TimeZone> ArrowType for Timestamp<Nanosecond, Tz> {
+    const FORMAT: &'static CStr = TimestampFormat::<b'n', Tz>::FORMAT;
+}
+
+impl ArrowType for Duration<Second> {
+    const FORMAT: &'static CStr = c"tDs";
+}
+
+impl ArrowType for Duration<Millisecond> {
+    const FORMAT: &'static CStr = c"tDm";
+}
+
+impl ArrowType for Duration<Microsecond> {
+    const FORMAT: &'static CStr = c"tDu";
+}
+
+impl ArrowType for Duration<Nanosecond> {
+    const FORMAT: &'static CStr = c"tDn";
+}
+
+impl ArrowType for IntervalYearMonth {
+    const FORMAT: &'static CStr = c"tiM";
+}
+
+impl ArrowType for IntervalDayTime {
+    const FORMAT: &'static CStr = c"tiD";
+}
+
+impl ArrowType for IntervalMonthDayNano {
+    const FORMAT: &'static CStr = c"tin";
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::vec::Vec;
+    use core::{ffi::CStr, slice};
+
+    use narrow::{
+        array::Array,
+        buffer::SliceBuffer,
+        collection::Collection,
+        temporal::{Naive, Utc},
+    };
+
+    use crate::{
+        ARROW_FLAG_NULLABLE,
+        import::{Import, ImportError},
+    };
+
+    use super::{super::Export, *};
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    struct Amsterdam;
+
+    impl TimeZone for Amsterdam {
+        const NAME: Option<&'static str> = Some("Europe/Amsterdam");
+    }
+
+    #[test]
+    fn type_format_strings_match_arrow() {
+        assert_eq!(Date32::FORMAT, c"tdD");
+        assert_eq!(Date64::FORMAT, c"tdm");
+        assert_eq!(Time32::<Second>::FORMAT, c"tts");
+        assert_eq!(Time64::<Nanosecond>::FORMAT, c"ttn");
+        assert_eq!(Timestamp::<Second, Naive>::FORMAT, c"tss:");
+        assert_eq!(Timestamp::<Microsecond, Utc>::FORMAT, c"tsu:UTC");
+        assert_eq!(
+            Timestamp::<Nanosecond, Amsterdam>::FORMAT,
+            c"tsn:Europe/Amsterdam"
+        );
+        assert_eq!(Duration::<Millisecond>::FORMAT, c"tDm");
+        assert_eq!(IntervalYearMonth::FORMAT, c"tiM");
+        assert_eq!(IntervalDayTime::FORMAT, c"tiD");
+        assert_eq!(IntervalMonthDayNano::FORMAT, c"tin");
+        assert_eq!(<Option<Date32>>::FLAGS, ARROW_FLAG_NULLABLE);
+    }
+
+    #[test]
+    fn round_trips_timestamps() {
+        let source = [Some(Timestamp::<Millisecond, Utc>::new(1_000)), None]
+            .into_iter()
+            .collect::<Array<Option<Timestamp<Millisecond, Utc>>>>();
+        let (array, schema) = source.export().expect("export array");
+        // SAFETY: The exported schema has a live, null-terminated format string.
+        assert_eq!(unsafe { CStr::from_ptr(schema.format) }, c"tsm:UTC");
+
+        // SAFETY: The exported structures retain valid buffers for the
+        // lifetime of the imported array.
+        let imported: Array<Option<Timestamp<Millisecond, Utc>>, SliceBuffer<'_>> =
+            unsafe { Import::import(&array, &schema) }.expect("import array");
+        assert_eq!(
+            imported.into_iter_owned().collect::<Vec<_>>(),
+            [Some(Timestamp::new(1_000)), None]
+        );
+
+        // SAFETY: The exported structures remain valid.
+        let other_unit = unsafe {
+            <Array<Option<Timestamp<Microsecond, Utc>>, SliceBuffer<'_>> as Import>::import(
+                &array, &schema,
+            )
+            .expect_err("unit mismatch")
+        };
+        assert_eq!(other_unit, ImportError::UnexpectedFormat);
+        // SAFETY: The exported structures remain valid.
+        let other_time_zone = unsafe {
+            <Array<Option<Timestamp<Millisecond, Naive>>, SliceBuffer<'_>> as Import>::import(
+                &array, &schema,
+            )
+            .expect_err("time zone mismatch")
+        };
+        assert_eq!(other_time_zone, ImportError::UnexpectedFormat);
+    }
+
+    #[test]
+    fn round_trips_month_day_nano_intervals() {
+        let interval = IntervalMonthDayNano {
+            months: 1,
+            days: -2,
+            nanoseconds: 3,
+        };
+        let source = [interval]
+            .into_iter()
+            .collect::<Array<IntervalMonthDayNano>>();
+        let (array, schema) = source.export().expect("export array");
+        // SAFETY: The exported array owns a two-entry buffer pointer array.
+        let buffers = unsafe { slice::from_raw_parts(array.buffers, 2) };
+        // SAFETY: The values buffer holds one 16-byte interval.
+        let bytes = unsafe { slice::from_raw_parts(buffers[1].cast::<u8>(), 16) };
+        assert_eq!(bytes[..4], 1_i32.to_le_bytes());
+        assert_eq!(bytes[4..8], (-2_i32).to_le_bytes());
+        assert_eq!(bytes[8..], 3_i64.to_le_bytes());
+
+        // SAFETY: The exported structures retain valid buffers for the
+        // lifetime of the imported array.
+        let imported: Array<IntervalMonthDayNano, SliceBuffer<'_>> =
+            unsafe { Import::import(&array, &schema) }.expect("import array");
+        assert_eq!(imported.owned(0), Some(interval));
+    }
+}
@@ -0,0 +1,117 @@
+//! Export support for [`Utf8`].
+//!
+//! String arrays have the buffers of binary arrays, and only differ in their
+//! format.
+
+use core::{borrow::Borrow, ffi::c_void, ptr};
+
+use narrow::{
+    bitmap::ValidityBitmap,
+    buffer::{Buffer, BufferRef},
+    collection::ChildRef,
+    layout::utf8::{Utf8, Utf8Item, Utf8Values},
+    nullability::{NonNullable, Nullable},
+};
+
+use crate::{ARROW_FLAG_NULLABLE, ArrowArray, ArrowListOffset, ArrowSchema};
+
+use super::{AlignedBitmaps, ArrowArrayLayout, validity_null_count};
+
+/// Returns the buffer pointers of `values` with the `validity` buffer
+/// pointer.
+fn utf8_buffers<OffsetItem: ArrowListOffset, Storage: Buffer, U>(
+    validity: *const c_void,
+    values: &Utf8Values<OffsetItem, Storage, U>,
+) -> [*const c_void; 3] {
+    let offsets: &[OffsetItem] = values.buffer_ref().buffer_ref().borrow();
+    let data: &[u8] = values.buffer_ref().child_ref().borrow();
+    [validity, offsets.as_ptr().cast(), data.as_ptr().cast()]
+}
+
+impl<OffsetItem: ArrowListOffset, Storage: Buffer, U: Utf8Item> ArrowArrayLayout
+    for Utf8<NonNullable, OffsetItem, Storage, U>
+{
+    type Buffers = [*const c_void; 3];
+    type Children = [ArrowArray; 0];
+
+    fn schema() -> ArrowSchema {
+        ArrowSchema::childless(OffsetItem::UTF8_FORMAT, 0)
+    }
+
+    fn buffers(&self, _bitmaps: &mut AlignedBitmaps) -> Self::Buffers {
+        utf8_buffers(ptr::null(), self.buffer_ref())
+    }
+}
+
+impl<OffsetItem: ArrowListOffset, Storage: Buffer, U: Utf8Item> ArrowArrayLayout
+    for Utf8<Nullable, OffsetItem, Storage, U>
+{
+    type Buffers = [*const c_void; 3];
+    type Children = [ArrowArray; 0];
+
+    fn schema() -> ArrowSchema {
+        ArrowSchema::childless(OffsetItem::UTF8_FORMAT, ARROW_FLAG_NULLABLE)
+    }
+
+    fn null_count(&self) -> i64 {
+        validity_null_count(self, self.buffer_ref())
+    }
+
+    fn buffers(&self, bitmaps: &mut AlignedBitmaps) -> Self::Buffers {
+        let validity = self.buffer_ref();
+        let validity_values = validity.bitmap_ref().map(|bitmap| bitmaps.pointer(bitmap));
+        utf8_buffers(validity_values.unwrap_or(ptr::null()), validity.child_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::{borrow::ToOwned, boxed::Box, string::String};
+    use core::{ffi::CStr, slice};
+
+    use narrow::array::Array;
+
+    use crate::{ARROW_FLAG_NULLABLE, ArrowListOffset};
+
+    use super::super::Export;
+
+    #[test]
+    fn utf8_format_strings_match_arrow() {
+        assert_eq!(<i32 as ArrowListOffset>::UTF8_FORMAT, c"u");
+        assert_eq!(<i64 as ArrowListOffset>::UTF8_FORMAT, c"U");
+    }
+
+    #[test]
+    fn exports_utf8_buffers() {
+        let narrow_array = [Some("ab".to_owned()), None, Some("c".to_owned())]
+            .into_iter()
+            .collect::<Array<Option<String>>>();
+
+        let (array, schema) = narrow_array.slice(1, 2).export().expect("export array");
+
+        assert_eq!((array.length, array.offset, array.null_count), (2, 1, 1));
+        assert_eq!((array.n_buffers, array.n_children), (3, 0));
+        // SAFETY: The exported array owns a three-entry buffer pointer array.
+        let buffers = unsafe { slice::from_raw_parts(array.buffers, 3) };
+        // SAFETY: The offsets buffer holds one offset more than the three
+        // items in the backing buffers.
+        let offsets = unsafe { slice::from_raw_parts(buffers[1].cast::<i32>(), 4) };
+        assert_eq!(offsets, [0, 2, 2, 3]);
+        // SAFETY: The data buffer holds the bytes up to the last offset.
+        let data = unsafe { slice::from_raw_parts(buffers[2].cast::<u8>(), 3) };
+        assert_eq!(data, b"abc");
+        // SAFETY: The exported schema has a live, null-terminated format string.
+        assert_eq!(unsafe { CStr::from_ptr(schema.format) }, c"u");
+        assert_eq!(schema.flags, ARROW_FLAG_NULLABLE);
+
+        let (_boxed_array, boxed_schema) = [Box::<str>::from("a")]
+            .into_iter()
+            .collect::<Array<Box<str>>>()
+            .export()
+            .expect("export array");
+        // SAFETY: The exported schema has a live, null-terminated format string.
+        assert_eq!(unsafe { CStr::from_ptr(boxed_schema.format) }, c"u");
+    }
+}
@@ -3,7 +3,11 @@
 extern crate alloc;
 
 use alloc::boxed::Box;
-use core::{borrow::Borrow, ffi::c_void, ptr};
+use core::{
+    borrow::Borrow,
+    ffi::{CStr, c_void},
+    ptr,
+};
 
 use narrow::{
     bitmap::ValidityBitmap,
@@ -15,7 +19,7 @@ use narrow::{
 
 use crate::{ARROW_FLAG_NULLABLE, ArrowArray, ArrowListOffset, ArrowSchema};
 
-use super::{ArrowArrayLayout, ExportError, release_schema};
+use super::{AlignedBitmaps, ArrowArrayLayout, ExportError, release_schema, validity_null_count};
 
 impl<T, OffsetItem, Storage> ArrowArrayLayout
     for VariableSizeList<T, NonNullable, OffsetItem, Storage>
@@ -29,10 +33,10 @@ where
     type Children = [ArrowArray; 1];
 
     fn schema() -> ArrowSchema {
-        ArrowSchema::variable_size_list::<OffsetItem, NonNullable>(<T::Memory<Storage>>::schema())
+        ArrowSchema::list::<NonNullable>(OffsetItem::FORMAT, <T::Memory<Storage>>::schema())
     }
 
-    fn buffers(&self) -> Self::Buffers {
+    fn buffers(&self, _bitmaps: &mut AlignedBitmaps) -> Self::Buffers {
         let offsets: &[OffsetItem] = self.buffer_ref().buffer_ref().borrow();
         [ptr::null(), offsets.as_ptr().cast()]
     }
@@ -53,26 +57,16 @@ where
     type Children = [ArrowArray; 1];
 
     fn schema() -> ArrowSchema {
-        ArrowSchema::variable_size_list::<OffsetItem, Nullable>(<T::Memory<Storage>>::schema())
+        ArrowSchema::list::<Nullable>(OffsetItem::FORMAT, <T::Memory<Storage>>::schema())
     }
 
     fn null_count(&self) -> i64 {
-        i64::try_from(ValidityBitmap::null_count(self.buffer_ref()))
-            .expect("null count exceeds i64")
-    }
-
-    fn offset(&self) -> usize {
-        self.buffer_ref()
-            .bitmap_ref()
-            .map_or(0, narrow::bitmap::Bitmap::bit_offset)
+        validity_null_count(self, self.buffer_ref())
     }
 
-    fn buffers(&self) -> Self::Buffers {
+    fn buffers(&self, bitmaps: &mut AlignedBitmaps) -> Self::Buffers {
         let validity = self.buffer_ref();
-        let validity_values = validity.bitmap_ref().map(|bitmap| {
-            let values: &[u8] = bitmap.buffer_ref().borrow();
-            values.as_ptr().cast()
-        });
+        let validity_values = validity.bitmap_ref().map(|bitmap| bitmaps.pointer(bitmap));
         let offsets: &[OffsetItem] = validity.child_ref().buffer_ref().borrow();
         [
             validity_values.unwrap_or(ptr::null()),
@@ -86,9 +80,12 @@ where
 }
 
 impl ArrowSchema {
-    /// Builds a variable-size-list schema and retains its child schema.
-    fn variable_size_list<OffsetItem: ArrowListOffset, Nulls: Nullability>(child: Self) -> Self {
-        let mut private = Box::new(VariableSizeListSchemaData {
+    /// Builds a list schema with `format` and retains its child schema.
+    ///
+    /// Variable-size lists, list views and maps all have one child, and
+    /// differ only in their format.
+    pub(super) fn list<Nulls: Nullability>(format: &'static CStr, child: Self) -> Self {
+        let mut private = Box::new(ListSchemaData {
             children: [child],
             child_pointers: [ptr::null_mut()],
         });
@@ -97,7 +94,7 @@ impl ArrowSchema {
         let children = private.child_pointers.as_mut_ptr();
         let private_data = Box::into_raw(private).cast();
         Self {
-            format: OffsetItem::FORMAT.as_ptr(),
+            format: format.as_ptr(),
             name: c"".as_ptr(),
             metadata: ptr::null(),
             flags: if Nulls::NULLABLE {
@@ -108,14 +105,14 @@ impl ArrowSchema {
             n_children: 1,
             children,
             dictionary: ptr::null_mut(),
-            release: Some(release_schema::<VariableSizeListSchemaData>),
+            release: Some(release_schema::<ListSchemaData>),
             private_data,
         }
     }
 }
 
-/// Data retained by `ArrowSchema::private_data` for a variable-size list.
-struct VariableSizeListSchemaData {
+/// Data retained by `ArrowSchema::private_data` for a list.
+struct ListSchemaData {
     /// Child schemas owned by the export.
     children: [ArrowSchema; 1],
     /// Arrow C Data child pointers.
@@ -126,21 +123,22 @@ struct VariableSizeListSchemaData {
 mod tests {
     extern crate alloc;
 
-    use alloc::{sync::Arc, vec::Vec};
+    use alloc::{sync::Arc, vec, vec::Vec};
     use core::{ffi::CStr, slice};
 
     use narrow::{
         array::Array,
         bitmap::Bitmap,
-        buffer::{ArcBuffer, ArrayBuffer},
+        buffer::{ArcBuffer, ArrayBuffer, SliceBuffer},
+        collection::Collection,
         layout::{fixed_size_primitive::FixedSizePrimitive, variable_size_list::VariableSizeList},
         offset::Offsets,
         validity::Validity,
     };
 
-    use crate::{ARROW_FLAG_NULLABLE, ArrowListOffset};
+    use crate::{ARROW_FLAG_NULLABLE, ArrowListOffset, import::Import};
 
-    use super::super::{Export, ExportError};
+    use super::super::Export;
 
     #[test]
     fn list_format_strings_match_arrow() {
@@ -266,17 +264,51 @@ mod tests {
     }
 
     #[test]
-    fn rejects_non_zero_nullable_variable_size_list_offset() {
+    fn realigns_nullable_variable_size_list_validity_bit_offset() {
         let values_layout = FixedSizePrimitive::from_buffer([1, 2, 3]);
         let offsets = Offsets::try_from_parts(values_layout, [0, 2, 3]).expect("valid offsets");
-        let bitmap = Bitmap::<ArrayBuffer<3>>::try_from_parts([0b0000_1100, 0, 0], 2, 2)
+        let bitmap = Bitmap::<ArrayBuffer<3>>::try_from_parts([0b0000_0100, 0, 0], 2, 2)
             .expect("valid bitmap");
         let validity = Validity::try_from_parts(offsets, bitmap).expect("valid parts");
-        let array: Array<Option<Vec<i32>>, ArrayBuffer<3>> =
+        let narrow_array: Array<Option<Vec<i32>>, ArrayBuffer<3>> =
             Array::from_buffer(VariableSizeList::from_buffer(validity));
 
-        let error = array.export().expect_err("non-zero offset");
+        let (array, schema) = narrow_array.export().expect("export array");
 
-        assert_eq!(error, ExportError::NonZeroOffset { offset: 2 });
+        assert_eq!(array.offset, 0);
+        assert_eq!(array.null_count, 1);
+        // SAFETY: The exported structures retain the offsets, child values and
+        // validity copy for the lifetime of the imported array.
+        let imported: Array<Option<Vec<i32>>, SliceBuffer<'_>> =
+            unsafe { Import::import(&array, &schema) }.expect("import array");
+        assert_eq!(
+            imported.into_iter_owned().collect::<Vec<_>>(),
+            [Some(vec![1, 2]), None]
+        );
+    }
+
+    #[test]
+    fn exports_sliced_variable_size_list_without_copying_offsets() {
+        let narrow_array = [vec![1_i32], vec![2, 3], vec![], vec![4]]
+            .into_iter()
+            .collect::<Array<Vec<i32>>>();
+
+        let (array, schema) = narrow_array.slice(1, 2).export().expect("export array");
+
+        assert_eq!(array.offset, 1);
+        assert_eq!(array.length, 2);
+        // SAFETY: The exported array owns a two-entry buffer pointer array.
+        let buffers = unsafe { slice::from_raw_parts(array.buffers, 2) };
+        // SAFETY: The offsets buffer holds five offsets retained by the export.
+        let offsets = unsafe { slice::from_raw_parts(buffers[1].cast::<i32>(), 5) };
+        assert_eq!(offsets, [0, 1, 3, 3, 4]);
+        // SAFETY: The exported structures retain the offsets and child values
+        // for the lifetime of the imported array.
+        let imported: Array<Vec<i32>, SliceBuffer<'_>> =
+            unsafe { Import::import(&array, &schema) }.expect("import array");
+        assert_eq!(
+            imported.into_iter_owned().collect::<Vec<_>>(),
+            [vec![2, 3], vec![]]
+        );
     }
 }
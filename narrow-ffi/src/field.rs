@@ -0,0 +1,179 @@
+//! Names, nullability and metadata of exported and imported schemas.
+
+use std::{collections::HashMap, string::String, vec::Vec};
+
+/// A named field with key/value metadata.
+///
+/// The item type of an array determines its Arrow type, but not the name of
+/// the field it is stored in, or the metadata a consumer attaches to it. A
+/// [`Field`] describes these for an exported [`ArrowSchema`](crate::ArrowSchema)
+/// and, through its children, for the child schemas of nested types like
+/// lists and structs.
+///
+/// Fields without children leave the child schemas as they are exported.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use narrow::array::Array;
+/// use narrow_ffi::{Export, Field};
+///
+/// let values = [vec![1, 2], vec![3]].into_iter().collect::<Array<Vec<i32>>>();
+/// let field = Field::new("values", false)
+///     .with_metadata(HashMap::from([("unit".to_owned(), "m".to_owned())]))
+///     .with_children(vec![Field::new("item", false)]);
+/// let (_array, schema) = values.export_field(&field).unwrap();
+///
+/// // SAFETY: The schema was exported above.
+/// let imported = unsafe { Field::import(&schema) }.unwrap();
+/// assert_eq!(imported, field);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Field {
+    /// Name of the field.
+    pub name: String,
+    /// Whether the field may contain null items.
+    pub nullable: bool,
+    /// Key/value metadata of the field.
+    pub metadata: HashMap<String, String>,
+    /// Fields of the child schemas, in order.
+    pub children: Vec<Field>,
+}
+
+impl Field {
+    /// Constructs a field without metadata and children.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow_ffi::Field;
+    ///
+    /// let field = Field::new("id", false);
+    /// assert_eq!(field.name, "id");
+    /// assert!(field.metadata.is_empty());
+    /// ```
+    #[must_use]
+    pub fn new(name: impl Into<String>, nullable: bool) -> Self {
+        Self {
+            name: name.into(),
+            nullable,
+            metadata: HashMap::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Returns this field with `metadata`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    ///
+    /// use narrow_ffi::Field;
+    ///
+    /// let metadata = HashMap::from([("key".to_owned(), "value".to_owned())]);
+    /// let field = Field::new("id", false).with_metadata(metadata.clone());
+    /// assert_eq!(field.metadata, metadata);
+    /// ```
+    #[must_use]
+    pub fn with_metadata(self, metadata: HashMap<String, String>) -> Self {
+        Self { metadata, ..self }
+    }
+
+    /// Returns this field with the fields of its child schemas.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow_ffi::Field;
+    ///
+    /// let field = Field::new("list", true).with_children(vec![Field::new("item", false)]);
+    /// assert_eq!(field.children[0].name, "item");
+    /// ```
+    #[must_use]
+    pub fn with_children(self, children: Vec<Self>) -> Self {
+        Self { children, ..self }
+    }
+}
+
+/// The schema of a record batch.
+///
+/// Arrow C Data record batches are non-nullable struct arrays, with one child
+/// per column. The [`Field`]s describe the columns, and the schema metadata
+/// is attached to the struct itself.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use narrow::array::Array;
+/// use narrow_ffi::{ExportRecordBatch, Field, Schema};
+///
+/// let batch = [(1_u32, true)].into_iter().collect::<Array<(u32, bool)>>();
+/// let schema = Schema::new(vec![Field::new("id", false), Field::new("valid", false)])
+///     .with_metadata(HashMap::from([("source".to_owned(), "test".to_owned())]));
+/// let (_array, exported) = batch.export_record_batch(&schema).unwrap();
+///
+/// // SAFETY: The schema was exported above.
+/// let imported = unsafe { Schema::import(&exported) }.unwrap();
+/// assert_eq!(imported, schema);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Schema {
+    /// The fields of the columns, in order.
+    pub fields: Vec<Field>,
+    /// Key/value metadata of the record batch.
+    pub metadata: HashMap<String, String>,
+}
+
+impl Schema {
+    /// Constructs a schema without metadata.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow_ffi::{Field, Schema};
+    ///
+    /// let schema = Schema::new(vec![Field::new("id", false)]);
+    /// assert_eq!(schema.fields.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn new(fields: Vec<Field>) -> Self {
+        Self {
+            fields,
+            metadata: HashMap::new(),
+        }
+    }
+
+    /// Returns this schema with `metadata`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    ///
+    /// use narrow_ffi::Schema;
+    ///
+    /// let metadata = HashMap::from([("key".to_owned(), "value".to_owned())]);
+    /// let schema = Schema::default().with_metadata(metadata.clone());
+    /// assert_eq!(schema.metadata, metadata);
+    /// ```
+    #[must_use]
+    pub fn with_metadata(self, metadata: HashMap<String, String>) -> Self {
+        Self { metadata, ..self }
+    }
+
+    /// Returns the unnamed, non-nullable struct field of a record batch with
+    /// this schema.
+    pub(crate) fn to_field(&self) -> Field {
+        Field {
+            name: String::new(),
+            nullable: false,
+            metadata: self.metadata.clone(),
+            children: self.fields.clone(),
+        }
+    }
+}
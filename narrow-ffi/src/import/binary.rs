@@ -0,0 +1,207 @@
+//! Import support for [`Binary`].
+
+use core::{borrow::Borrow, ffi::CStr, mem, slice};
+
+use narrow::{
+    layout::binary::{Binary, BinaryValues},
+    offset::OffsetsError,
+};
+
+use crate::{ArrowArray, ArrowListOffset, ArrowSchema};
+
+use super::{ImportBuffer, ImportError, ImportLayout, ImportNullability, Owner};
+
+impl<'array, Nulls, OffsetItem, Storage> ImportLayout<'array> for Binary<Nulls, OffsetItem, Storage>
+where
+    Nulls: ImportNullability<'array>,
+    OffsetItem: ArrowListOffset,
+    Storage: ImportBuffer<'array>,
+{
+    type Storage = Storage;
+
+    const FLAGS: i64 = Nulls::FLAGS;
+    const BUFFERS: i64 = 3;
+    const CHILDREN: i64 = 0;
+
+    fn matches_format(format: &CStr) -> bool {
+        format == OffsetItem::BINARY_FORMAT
+    }
+
+    unsafe fn import_validated(
+        array: &'array ArrowArray,
+        _schema: &ArrowSchema,
+        owner: &Owner<'array, Self>,
+        length: usize,
+    ) -> Result<Self, ImportError> {
+        let offsets_length = length.checked_add(1).ok_or(ImportError::InvalidLength {
+            length: array.length,
+        })?;
+        // SAFETY: Common validation guarantees a three-entry buffer pointer
+        // array.
+        let buffers = unsafe { slice::from_raw_parts(array.buffers, 3) };
+        let offsets_pointer = buffers[1].cast::<OffsetItem>();
+        if offsets_pointer.is_null() {
+            return Err(ImportError::MissingOffsetsBuffer);
+        }
+        if !offsets_pointer.is_aligned() {
+            return Err(ImportError::MisalignedOffsetsBuffer {
+                alignment: mem::align_of::<OffsetItem>(),
+            });
+        }
+        // SAFETY: The caller guarantees the offsets buffer contains
+        // `offsets_length` aligned values that remain immutable for `'array`.
+        let offset_values =
+            unsafe { Storage::import_buffer(owner, offsets_pointer, offsets_length) };
+
+        // The data buffer has no length of its own: it ends at the last
+        // offset, which is validated again against the imported data.
+        let last = offset_values.borrow()[length];
+        let data_length: usize = last.try_into().map_err(|_| ImportError::InvalidOffsets {
+            error: OffsetsError::Negative { index: length },
+        })?;
+        let data_pointer = buffers[2].cast::<u8>();
+        if data_pointer.is_null() && data_length != 0 {
+            return Err(ImportError::MissingValuesBuffer);
+        }
+        // SAFETY: The caller guarantees the data buffer contains the bytes up
+        // to the last offset, which remain immutable for `'array`.
+        let data = unsafe { Storage::import_buffer(owner, data_pointer, data_length) };
+        let values = BinaryValues::try_from_parts(data, offset_values)
+            .map_err(|error| ImportError::InvalidOffsets { error })?;
+
+        // SAFETY: Common validation and the caller guarantee a valid optional
+        // validity buffer for the imported binary values.
+        let collection = unsafe { Nulls::wrap::<_, Storage>(array, owner, values) }?;
+        Ok(Self::from_buffer(collection))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::{vec, vec::Vec};
+    use core::{borrow::Borrow, ptr, slice};
+
+    use narrow::{
+        array::Array,
+        buffer::{BufferRef, SliceBuffer},
+        collection::{ChildRef, Collection},
+        layout::binary::Bytes,
+        offset::OffsetsError,
+    };
+
+    use crate::{
+        ForeignBuffer,
+        export::Export,
+        import::{Import, ImportError, ImportOwned},
+    };
+
+    #[test]
+    fn imports_binary_buffers_without_copying() {
+        let source = [vec![1, 2], vec![], vec![3]]
+            .into_iter()
+            .map(Bytes::new)
+            .collect::<Array<Bytes>>();
+        let (array, schema) = source.export().expect("export array");
+
+        // SAFETY: The exported structures retain valid offsets and data
+        // buffers for the lifetime of the imported array.
+        let imported: Array<Bytes, SliceBuffer<'_>> =
+            unsafe { Import::import(&array, &schema) }.expect("import array");
+
+        let values = imported.buffer_ref().buffer_ref().buffer_ref();
+        let offsets: &[i32] = values.buffer_ref().borrow();
+        let data: &[u8] = values.child_ref();
+        // SAFETY: The exported array owns a three-entry buffer pointer array.
+        let buffers = unsafe { slice::from_raw_parts(array.buffers, 3) };
+        assert_eq!(offsets.as_ptr().cast(), buffers[1]);
+        assert_eq!(data.as_ptr().cast(), buffers[2]);
+        assert_eq!(imported.view(0), Some([1, 2].as_slice()));
+        assert_eq!(
+            imported
+                .into_iter_owned()
+                .map(Vec::from)
+                .collect::<Vec<_>>(),
+            [vec![1, 2], vec![], vec![3]]
+        );
+    }
+
+    #[test]
+    fn round_trips_sliced_nullable_large_binary() {
+        let source = [Some(vec![1]), None, Some(vec![2, 3]), Some(vec![4])]
+            .into_iter()
+            .map(|value| value.map(Bytes::<i64>::new))
+            .collect::<Array<Option<Bytes<i64>>>>();
+        let (array, schema) = source.slice(1, 2).export().expect("export array");
+
+        // SAFETY: The exported structures describe a valid large binary array
+        // and are moved into the imported storage.
+        let imported: Array<Option<Bytes<i64>>, ForeignBuffer> =
+            unsafe { ImportOwned::import_owned(array, &schema) }.expect("import array");
+        assert_eq!(
+            imported
+                .iter_views()
+                .map(|value| value.map(<[u8]>::to_vec))
+                .collect::<Vec<_>>(),
+            [None, Some(vec![2, 3])]
+        );
+
+        let (large_array, large_schema) = [Some(Bytes::<i64>::new(vec![1]))]
+            .into_iter()
+            .collect::<Array<Option<Bytes<i64>>>>()
+            .export()
+            .expect("export array");
+        // SAFETY: The exported structures remain valid.
+        let other_width = unsafe {
+            <Array<Option<Bytes>, SliceBuffer<'_>> as Import>::import(&large_array, &large_schema)
+                .expect_err("offset width mismatch")
+        };
+        assert_eq!(other_width, ImportError::UnexpectedFormat);
+        // SAFETY: The exported structures remain valid.
+        let list = unsafe {
+            <Array<Option<Vec<u8>>, SliceBuffer<'_>> as Import>::import(&large_array, &large_schema)
+                .expect_err("binary is not a list")
+        };
+        assert_eq!(list, ImportError::UnexpectedFormat);
+    }
+
+    #[test]
+    fn rejects_invalid_offsets_and_missing_data() {
+        let source = [Bytes::new(vec![1, 2])]
+            .into_iter()
+            .collect::<Array<Bytes>>();
+        let (array, schema) = source.export().expect("export array");
+        // SAFETY: The exported array owns a three-entry buffer pointer array.
+        let buffers = unsafe { slice::from_raw_parts(array.buffers, 3) };
+        let offsets = buffers[1].cast::<i32>().cast_mut();
+        // SAFETY: The offsets buffer is owned by the export and not shared
+        // with an imported array.
+        unsafe { offsets.write(3) };
+
+        // SAFETY: The exported structures remain valid; only the first offset
+        // is changed to exercise validation.
+        let invalid_offsets = unsafe {
+            <Array<Bytes, SliceBuffer<'_>> as Import>::import(&array, &schema)
+                .expect_err("invalid offsets")
+        };
+        assert_eq!(
+            invalid_offsets,
+            ImportError::InvalidOffsets {
+                error: OffsetsError::NonMonotonic { index: 1 }
+            }
+        );
+
+        // SAFETY: The exported array owns a three-entry buffer pointer array.
+        let data = unsafe { array.buffers.add(2) };
+        // SAFETY: `data` points to the third entry of the buffer pointer array,
+        // which is owned by the export and not borrowed by any import.
+        unsafe { *data = ptr::null() };
+        // SAFETY: The missing data buffer is reported before it is read.
+        let missing_data = unsafe {
+            <Array<Bytes, SliceBuffer<'_>> as Import>::import(&array, &schema)
+                .expect_err("missing data")
+        };
+        assert_eq!(missing_data, ImportError::MissingValuesBuffer);
+    }
+}
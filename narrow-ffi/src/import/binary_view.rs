@@ -0,0 +1,302 @@
+//! Import support for [`BinaryView`] and [`Utf8View`].
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::{ffi::CStr, slice};
+
+use narrow::{
+    buffer::Buffer,
+    layout::{
+        binary_view::{BinaryView, BinaryViews, ByteView},
+        utf8_view::{Utf8View, Utf8Views},
+    },
+};
+
+use crate::{ArrowArray, ArrowSchema};
+
+use super::{ImportBuffer, ImportError, ImportLayout, ImportNullability, Owner};
+
+/// Imported views buffer and data buffers of a view array.
+type ViewParts<Storage> = (
+    <Storage as Buffer>::For<ByteView>,
+    Vec<<Storage as Buffer>::For<u8>>,
+);
+
+/// Imports the views buffer with `length` views and the variadic data
+/// buffers of a view array.
+///
+/// The data buffers are listed after the validity and views buffers, and
+/// their sizes are stored in the last buffer.
+///
+/// # Safety
+///
+/// Common fields must be validated, and the caller must uphold the
+/// requirements of [`Import::import`](super::Import::import) for the
+/// buffers.
+unsafe fn import_view_parts<'array, Storage: ImportBuffer<'array>>(
+    array: &'array ArrowArray,
+    owner: &Storage::Owner,
+    length: usize,
+) -> Result<ViewParts<Storage>, ImportError> {
+    let buffer_count = usize::try_from(array.n_buffers).expect("buffer count must fit in usize");
+    // SAFETY: Common validation guarantees a buffer pointer array with at
+    // least three entries, which the caller guarantees to be valid.
+    let buffers = unsafe { slice::from_raw_parts(array.buffers, buffer_count) };
+    let data_count = buffer_count.strict_sub(3);
+
+    let views_pointer = buffers[1].cast::<ByteView>();
+    if length != 0 && views_pointer.is_null() {
+        return Err(ImportError::MissingValuesBuffer);
+    }
+    // SAFETY: The caller guarantees the views buffer contains `length` views
+    // that remain immutable for `'array`. Views have no alignment
+    // requirement.
+    let views = unsafe { Storage::import_buffer(owner, views_pointer, length) };
+
+    let sizes_pointer = buffers[buffer_count.strict_sub(1)].cast::<i64>();
+    let sizes: &[i64] = if data_count == 0 {
+        &[]
+    } else if sizes_pointer.is_null() {
+        return Err(ImportError::MissingVariadicBufferSizes);
+    } else if !sizes_pointer.is_aligned() {
+        return Err(ImportError::MisalignedVariadicBufferSizes);
+    } else {
+        // SAFETY: The caller guarantees the sizes buffer contains one size
+        // per data buffer.
+        unsafe { slice::from_raw_parts(sizes_pointer, data_count) }
+    };
+
+    let data = buffers[2..buffer_count.strict_sub(1)]
+        .iter()
+        .zip(sizes)
+        .map(|(&pointer, &size)| {
+            let len = usize::try_from(size)
+                .map_err(|_| ImportError::InvalidVariadicBufferSize { size })?;
+            let data_pointer = pointer.cast::<u8>();
+            if len != 0 && data_pointer.is_null() {
+                return Err(ImportError::MissingValuesBuffer);
+            }
+            // SAFETY: The caller guarantees the data buffer contains `len`
+            // bytes that remain immutable for `'array`.
+            Ok(unsafe { Storage::import_buffer(owner, data_pointer, len) })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok((views, data))
+}
+
+impl<'array, Nulls, Storage> ImportLayout<'array> for BinaryView<Nulls, Storage>
+where
+    Nulls: ImportNullability<'array>,
+    Storage: ImportBuffer<'array>,
+{
+    type Storage = Storage;
+
+    const FLAGS: i64 = Nulls::FLAGS;
+    const BUFFERS: i64 = 3;
+    const VARIADIC_BUFFERS: bool = true;
+    const CHILDREN: i64 = 0;
+
+    fn matches_format(format: &CStr) -> bool {
+        format == c"vz"
+    }
+
+    unsafe fn import_validated(
+        array: &'array ArrowArray,
+        _schema: &ArrowSchema,
+        owner: &Owner<'array, Self>,
+        length: usize,
+    ) -> Result<Self, ImportError> {
+        // SAFETY: Common fields are validated and the caller upholds the
+        // Arrow C Data buffer requirements.
+        let (views, data) = unsafe { import_view_parts::<Storage>(array, owner, length) }?;
+        let values = BinaryViews::try_from_parts(views, data)
+            .map_err(|error| ImportError::InvalidBinaryViews { error })?;
+
+        // SAFETY: Common validation and the caller guarantee a valid optional
+        // validity buffer for the imported values.
+        let collection = unsafe { Nulls::wrap::<_, Storage>(array, owner, values) }?;
+        Ok(Self::from_buffer(collection))
+    }
+}
+
+impl<'array, Nulls, Storage> ImportLayout<'array> for Utf8View<Nulls, Storage>
+where
+    Nulls: ImportNullability<'array>,
+    Storage: ImportBuffer<'array>,
+{
+    type Storage = Storage;
+
+    const FLAGS: i64 = Nulls::FLAGS;
+    const BUFFERS: i64 = 3;
+    const VARIADIC_BUFFERS: bool = true;
+    const CHILDREN: i64 = 0;
+
+    fn matches_format(format: &CStr) -> bool {
+        format == c"vu"
+    }
+
+    unsafe fn import_validated(
+        array: &'array ArrowArray,
+        _schema: &ArrowSchema,
+        owner: &Owner<'array, Self>,
+        length: usize,
+    ) -> Result<Self, ImportError> {
+        // SAFETY: Common fields are validated and the caller upholds the
+        // Arrow C Data buffer requirements.
+        let (views, data) = unsafe { import_view_parts::<Storage>(array, owner, length) }?;
+        let values = Utf8Views::try_from_parts(views, data)
+            .map_err(|error| ImportError::InvalidUtf8Views { error })?;
+
+        // SAFETY: Common validation and the caller guarantee a valid optional
+        // validity buffer for the imported values.
+        let collection = unsafe { Nulls::wrap::<_, Storage>(array, owner, values) }?;
+        Ok(Self::from_buffer(collection))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::{string::String, vec::Vec};
+    use core::slice;
+
+    use narrow::{
+        array::Array,
+        buffer::{BufferRef, SliceBuffer},
+        collection::Collection,
+        layout::{
+            binary_view::{BinaryViewsError, Viewed},
+            utf8_view::Utf8ViewsError,
+        },
+    };
+
+    use crate::{
+        ForeignBuffer,
+        export::Export,
+        import::{Import, ImportError, ImportOwned},
+    };
+
+    #[test]
+    fn imports_binary_views_without_copying() {
+        let source = [b"short".to_vec(), b"longer than twelve bytes".to_vec()]
+            .into_iter()
+            .map(Viewed::new)
+            .collect::<Array<Viewed<Vec<u8>>>>();
+        let data = source.buffer_ref().buffer_ref().data_buffers()[0].as_ptr();
+        let (array, schema) = source.export().expect("export array");
+
+        // SAFETY: The exported structures remain live and retain their
+        // buffers for the lifetime of the imported array.
+        let imported: Array<Viewed<Vec<u8>>, SliceBuffer<'_>> =
+            unsafe { Import::import(&array, &schema) }.expect("import array");
+
+        let views = imported.buffer_ref().buffer_ref();
+        assert_eq!(views.data_buffers()[0].as_ptr(), data);
+        assert_eq!(
+            imported.iter_views().collect::<Vec<_>>(),
+            [&b"short"[..], b"longer than twelve bytes"]
+        );
+    }
+
+    #[test]
+    fn imports_sliced_nullable_utf8_views() {
+        let items = [
+            Some("a string that is not inlined"),
+            None,
+            Some("inline"),
+            Some("another string that is not inlined"),
+        ]
+        .map(|item| item.map(Viewed::<String>::from));
+        let source = items
+            .clone()
+            .into_iter()
+            .collect::<Array<Option<Viewed<String>>>>();
+        let (array, schema) = source.slice(1, 3).export().expect("export array");
+
+        // SAFETY: The exported array is moved into the imported buffers,
+        // which release it when they are dropped.
+        let imported =
+            unsafe { Array::<Option<Viewed<String>>, ForeignBuffer>::import_owned(array, &schema) }
+                .expect("import array");
+
+        assert_eq!(imported.into_iter_owned().collect::<Vec<_>>(), items[1..]);
+    }
+
+    #[test]
+    fn rejects_invalid_utf8() {
+        let source = [Viewed::<String>::from("a string that is not inlined")]
+            .into_iter()
+            .collect::<Array<Viewed<String>>>();
+        let (array, schema) = source.export().expect("export array");
+        // SAFETY: The exported array owns a four-entry buffer pointer array.
+        let buffers = unsafe { slice::from_raw_parts(array.buffers, 4) };
+        let mut invalid = *b"a string that is not inlined";
+        invalid[10] = 0xff;
+        let data_pointer = buffers.as_ptr().cast_mut().wrapping_add(2);
+        // SAFETY: The buffer pointer array is not shared with the imported
+        // array.
+        let original = unsafe { data_pointer.replace(invalid.as_ptr().cast()) };
+
+        // SAFETY: The replacement data outlives the import.
+        let error = unsafe {
+            <Array<Viewed<String>, SliceBuffer<'_>> as Import>::import(&array, &schema)
+                .expect_err("invalid utf-8")
+        };
+        // SAFETY: `data_pointer` still points into the buffer pointer array owned
+        // by the export, and the import that borrowed it has returned.
+        unsafe { data_pointer.write(original) };
+
+        assert_eq!(
+            error,
+            ImportError::InvalidUtf8Views {
+                error: Utf8ViewsError::InvalidUtf8 { index: 0 }
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_buffer_sizes() {
+        let source = [Viewed::new(b"longer than twelve bytes".to_vec())]
+            .into_iter()
+            .collect::<Array<Viewed<Vec<u8>>>>();
+        let (array, schema) = source.export().expect("export array");
+        // SAFETY: The exported array owns a four-entry buffer pointer array.
+        let buffers = unsafe { slice::from_raw_parts(array.buffers, 4) };
+        let sizes = buffers[3].cast::<i64>().cast_mut();
+        // SAFETY: The sizes buffer is owned by the export and not shared with
+        // the imported array.
+        let original = unsafe { sizes.replace(-1) };
+
+        // SAFETY: The exported structures remain valid; only the size is
+        // changed to exercise validation.
+        let negative = unsafe {
+            <Array<Viewed<Vec<u8>>, SliceBuffer<'_>> as Import>::import(&array, &schema)
+                .expect_err("negative size")
+        };
+        // SAFETY: `sizes` points to the aligned size of the only view in the
+        // sizes buffer owned by the export, and no import borrows it.
+        unsafe { sizes.write(4) };
+        // SAFETY: The exported structures remain valid; only the size is
+        // changed to exceed the data buffer.
+        let short = unsafe {
+            <Array<Viewed<Vec<u8>>, SliceBuffer<'_>> as Import>::import(&array, &schema)
+                .expect_err("short data buffer")
+        };
+        // SAFETY: `sizes` still points to the aligned size owned by the export,
+        // and the imports that read it have returned.
+        unsafe { sizes.write(original) };
+
+        assert_eq!(
+            negative,
+            ImportError::InvalidVariadicBufferSize { size: -1 }
+        );
+        assert_eq!(
+            short,
+            ImportError::InvalidBinaryViews {
+                error: BinaryViewsError::OutOfBounds { index: 0 }
+            }
+        );
+    }
+}
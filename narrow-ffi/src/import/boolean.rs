@@ -1,17 +1,20 @@
-//! Borrowed import support for [`Boolean`].
+//! Import support for [`Boolean`].
 
 use core::{ffi::CStr, slice};
 
-use narrow::{bitmap::Bitmap, buffer::SliceBuffer, layout::boolean::Boolean};
+use narrow::{bitmap::Bitmap, layout::boolean::Boolean};
 
 use crate::{ArrowArray, ArrowSchema, ArrowType};
 
-use super::{ImportError, ImportLayout, ImportNullability};
+use super::{ImportBuffer, ImportError, ImportLayout, ImportNullability, Owner};
 
-impl<'array, Nulls> ImportLayout<'array> for Boolean<Nulls, SliceBuffer<'array>>
+impl<'array, Nulls, Storage> ImportLayout<'array> for Boolean<Nulls, Storage>
 where
     Nulls: ImportNullability<'array>,
+    Storage: ImportBuffer<'array>,
 {
+    type Storage = Storage;
+
     const FLAGS: i64 = Nulls::FLAGS;
     const BUFFERS: i64 = 2;
     const CHILDREN: i64 = 0;
@@ -23,28 +26,25 @@ where
     unsafe fn import_validated(
         array: &'array ArrowArray,
         _schema: &ArrowSchema,
+        owner: &Owner<'array, Self>,
         length: usize,
     ) -> Result<Self, ImportError> {
         // SAFETY: The caller guarantees a valid two-entry buffer pointer array.
         let buffers = unsafe { slice::from_raw_parts(array.buffers, 2) };
         let byte_length = length.div_ceil(8);
-        let values = if byte_length == 0 {
-            &[]
-        } else {
-            let values = buffers[1].cast::<u8>();
-            if values.is_null() {
-                return Err(ImportError::MissingValuesBuffer);
-            }
-            // SAFETY: The caller guarantees the value buffer contains
-            // `byte_length` bytes that remain immutable for `'array`.
-            unsafe { slice::from_raw_parts(values, byte_length) }
-        };
+        let values_pointer = buffers[1].cast::<u8>();
+        if byte_length != 0 && values_pointer.is_null() {
+            return Err(ImportError::MissingValuesBuffer);
+        }
+        // SAFETY: The caller guarantees the value buffer contains
+        // `byte_length` bytes that remain immutable for `'array`.
+        let values = unsafe { Storage::import_buffer(owner, values_pointer, byte_length) };
         let bitmap = Bitmap::try_from_parts(values, length, 0)
             .expect("imported Boolean buffer contains the declared number of bits");
 
         // SAFETY: Common validation and the caller guarantee a valid optional
         // validity buffer for the imported values.
-        let collection = unsafe { Nulls::wrap(array, bitmap) }?;
+        let collection = unsafe { Nulls::wrap::<_, Storage>(array, owner, bitmap) }?;
         Ok(Self::from_buffer(collection))
     }
 }
@@ -0,0 +1,244 @@
+//! Import support for [`DictionaryEncoded`].
+
+use core::{ffi::CStr, mem, slice};
+
+use narrow::layout::dictionary::{
+    DictionaryEncoded, DictionaryKey, DictionaryKeys, DictionaryValue,
+};
+
+use crate::{ArrowArray, ArrowSchema, ArrowType};
+
+use super::{ImportBuffer, ImportError, ImportLayout, ImportNullability, Owner};
+
+impl<'array, K, V, Nulls, Storage> ImportLayout<'array> for DictionaryEncoded<K, V, Nulls, Storage>
+where
+    K: DictionaryKey + ArrowType,
+    V: DictionaryValue,
+    V::Memory<Storage>: ImportLayout<'array, Storage = Storage>,
+    Nulls: ImportNullability<'array>,
+    Storage: ImportBuffer<'array>,
+{
+    type Storage = Storage;
+
+    const FLAGS: i64 = Nulls::FLAGS;
+    const BUFFERS: i64 = 2;
+    const CHILDREN: i64 = 0;
+    const DICTIONARY: bool = true;
+
+    fn matches_format(format: &CStr) -> bool {
+        format == K::FORMAT
+    }
+
+    unsafe fn import_validated(
+        array: &'array ArrowArray,
+        schema: &ArrowSchema,
+        owner: &Owner<'array, Self>,
+        length: usize,
+    ) -> Result<Self, ImportError> {
+        // SAFETY: The caller guarantees a valid two-entry buffer pointer array.
+        let buffers = unsafe { slice::from_raw_parts(array.buffers, 2) };
+        let keys_pointer = buffers[1].cast::<K>();
+        if length != 0 {
+            if keys_pointer.is_null() {
+                return Err(ImportError::MissingValuesBuffer);
+            }
+            if !keys_pointer.is_aligned() {
+                return Err(ImportError::MisalignedValuesBuffer {
+                    alignment: mem::align_of::<K>(),
+                });
+            }
+        }
+        // SAFETY: The caller guarantees the key buffer contains `length`
+        // properly aligned keys that remain immutable for `'array`.
+        let keys = unsafe { Storage::import_buffer(owner, keys_pointer, length) };
+
+        // SAFETY: Common validation guarantees the dictionary pointers, and
+        // the caller upholds the Arrow C Data requirements for them.
+        let values = unsafe { Self::import_dictionary(array, schema, owner) }?;
+        let dictionary_keys = DictionaryKeys::try_from_parts(keys, values)
+            .map_err(|error| ImportError::InvalidDictionaryKeys { error })?;
+
+        // SAFETY: Common validation and the caller guarantee a valid optional
+        // validity buffer for the imported keys.
+        let collection = unsafe { Nulls::wrap::<_, Storage>(array, owner, dictionary_keys) }?;
+        Ok(Self::from_buffer(collection))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::{sync::Arc, vec, vec::Vec};
+    use core::{borrow::Borrow, ptr};
+
+    use narrow::{
+        array::Array,
+        buffer::{ArcBuffer, BufferRef, SliceBuffer},
+        collection::{ChildRef, Collection},
+        layout::{
+            dictionary::{Dictionary, DictionaryEncoded, DictionaryKeys, DictionaryKeysError},
+            fixed_size_primitive::FixedSizePrimitive,
+        },
+        length::Length,
+    };
+
+    use crate::{
+        export::Export,
+        import::{Import, ImportError},
+    };
+
+    #[test]
+    fn imports_dictionary_without_copying() {
+        let key_storage = Arc::<[u16]>::from([1, 0, 1]);
+        let value_storage = Arc::<[i64]>::from([10, 20]);
+        let keys_data = key_storage.as_ptr();
+        let values_data = value_storage.as_ptr();
+        let keys = DictionaryKeys::try_from_parts(
+            key_storage,
+            FixedSizePrimitive::from_buffer(value_storage),
+        )
+        .expect("valid keys");
+        let source: Array<Dictionary<u16, i64>, ArcBuffer> =
+            Array::from_buffer(DictionaryEncoded::from_buffer(keys));
+        let (array, schema) = source.export().expect("export array");
+
+        // SAFETY: The exported structures remain live and retain their keys
+        // and dictionary for the lifetime of the imported array.
+        let imported: Array<Dictionary<u16, i64>, SliceBuffer<'_>> =
+            unsafe { Import::import(&array, &schema) }.expect("import array");
+
+        let imported_keys = imported.buffer_ref().buffer_ref();
+        let key_values: &[u16] = imported_keys.buffer_ref();
+        let dictionary_values: &[i64] = imported_keys.child_ref().buffer_ref().borrow();
+        assert_eq!(key_values.as_ptr(), keys_data);
+        assert_eq!(dictionary_values.as_ptr(), values_data);
+        assert_eq!(imported.iter_views().collect::<Vec<_>>(), [20, 10, 20]);
+    }
+
+    #[test]
+    fn imports_nullable_dictionary_of_lists() {
+        let items = [
+            Some(Dictionary::new(vec![1_u8, 2])),
+            None,
+            Some(Dictionary::new(vec![])),
+            Some(Dictionary::new(vec![1, 2])),
+        ];
+        let source = items
+            .clone()
+            .into_iter()
+            .collect::<Array<Option<Dictionary<i32, Vec<u8>>>>>();
+        let (mut array, schema) = source.export().expect("export array");
+        array.null_count = -1;
+
+        // SAFETY: The exported structures retain valid keys, validity and
+        // dictionary buffers for the lifetime of the imported array.
+        let imported: Array<Option<Dictionary<i32, Vec<u8>>>, SliceBuffer<'_>> =
+            unsafe { Import::import(&array, &schema) }.expect("import array");
+
+        assert_eq!(
+            imported
+                .buffer_ref()
+                .buffer_ref()
+                .child_ref()
+                .child_ref()
+                .len(),
+            2
+        );
+        assert_eq!(imported.into_iter_owned().collect::<Vec<_>>(), items);
+    }
+
+    #[test]
+    fn imports_sliced_dictionary() {
+        let source = [
+            Some(Dictionary::new(1_i32)),
+            None,
+            Some(Dictionary::new(2)),
+            Some(Dictionary::new(1)),
+        ]
+        .into_iter()
+        .collect::<Array<Option<Dictionary<u8, i32>>>>();
+        let (array, schema) = source.slice(1, 2).export().expect("export array");
+        assert_eq!(array.offset, 1);
+        assert_eq!(array.null_count, 1);
+
+        // SAFETY: The exported structures retain valid keys, validity and
+        // dictionary buffers for the lifetime of the imported array.
+        let imported: Array<Option<Dictionary<u8, i32>>, SliceBuffer<'_>> =
+            unsafe { Import::import(&array, &schema) }.expect("import array");
+
+        assert_eq!(
+            imported.into_iter_owned().collect::<Vec<_>>(),
+            [None, Some(Dictionary::new(2))]
+        );
+    }
+
+    #[test]
+    fn rejects_missing_dictionary() {
+        let source = [Dictionary::new(1_i32)]
+            .into_iter()
+            .collect::<Array<Dictionary<u8, i32>>>();
+        let (mut array, schema) = source.export().expect("export array");
+        let dictionary = array.dictionary;
+        array.dictionary = ptr::null_mut();
+
+        // SAFETY: The exported structures remain valid; only the dictionary
+        // pointer is removed to exercise validation.
+        let error = unsafe {
+            <Array<Dictionary<u8, i32>, SliceBuffer<'_>> as Import>::import(&array, &schema)
+                .expect_err("missing dictionary")
+        };
+        array.dictionary = dictionary;
+
+        assert_eq!(error, ImportError::MissingDictionary);
+    }
+
+    #[test]
+    fn rejects_unexpected_dictionary() {
+        let source = [Dictionary::new(1_i32)]
+            .into_iter()
+            .collect::<Array<Dictionary<u8, i32>>>();
+        let (array, schema) = source.export().expect("export array");
+
+        // SAFETY: The exported structures remain valid; the key array is
+        // imported as plain values to exercise validation.
+        let error = unsafe {
+            <Array<u8, SliceBuffer<'_>> as Import>::import(&array, &schema)
+                .expect_err("unexpected dictionary")
+        };
+
+        assert_eq!(error, ImportError::UnexpectedDictionary);
+    }
+
+    #[test]
+    fn rejects_out_of_bounds_dictionary_keys() {
+        let source = [Dictionary::new(1_i32), Dictionary::new(2)]
+            .into_iter()
+            .collect::<Array<Dictionary<u8, i32>>>();
+        let (array, schema) = source.export().expect("export array");
+        let invalid_keys = [0_u8, 2];
+        // SAFETY: The exported array owns a writable two-entry pointer array;
+        // the second entry is the keys buffer.
+        let keys_slot = unsafe { array.buffers.add(1) };
+        // SAFETY: The slot is live and writable, and the replacement buffer
+        // remains live through the import call.
+        unsafe { *keys_slot = invalid_keys.as_ptr().cast() };
+
+        // SAFETY: Every referenced pointer remains valid and sufficiently
+        // sized; the malformed keys are validated by the importer.
+        let error = unsafe {
+            <Array<Dictionary<u8, i32>, SliceBuffer<'_>> as Import>::import(&array, &schema)
+                .expect_err("out of bounds key")
+        };
+
+        assert_eq!(
+            error,
+            ImportError::InvalidDictionaryKeys {
+                error: DictionaryKeysError::OutOfBounds {
+                    index: 1,
+                    values: 2
+                },
+            }
+        );
+    }
+}
@@ -0,0 +1,153 @@
+//! Import support for [`Extension`].
+//!
+//! Extension arrays are imported as their storage layout, after the
+//! `ARROW:extension:name` metadata of the schema is checked.
+
+use core::ffi::CStr;
+
+use narrow::{
+    layout::{
+        Layout,
+        extension::{Extension, ExtensionType, ExtensionValues},
+    },
+    nullability::NonNullable,
+};
+
+use crate::{ArrowArray, ArrowSchema, metadata::EXTENSION_NAME, metadata::MetadataPairs};
+
+use super::{ImportBuffer, ImportError, ImportLayout, ImportNullability, Owner};
+
+/// The layout that stores items of the storage type of `T`.
+type StorageMemory<T, Storage> =
+    <<T as ExtensionType>::StorageType as Layout>::Memory<NonNullable, Storage>;
+
+impl<'array, T, Nulls, Storage> ImportLayout<'array> for Extension<T, Nulls, Storage>
+where
+    T: ExtensionType,
+    Nulls: ImportNullability<'array>,
+    Storage: ImportBuffer<'array>,
+    StorageMemory<T, Storage>: ImportLayout<'array, Storage = Storage>,
+{
+    type Storage = Storage;
+
+    const FLAGS: i64 = Nulls::FLAGS;
+    const BUFFERS: i64 = StorageMemory::<T, Storage>::BUFFERS;
+    const VARIADIC_BUFFERS: bool = StorageMemory::<T, Storage>::VARIADIC_BUFFERS;
+    const CHILDREN: i64 = StorageMemory::<T, Storage>::CHILDREN;
+    const DICTIONARY: bool = StorageMemory::<T, Storage>::DICTIONARY;
+
+    fn matches_format(format: &CStr) -> bool {
+        StorageMemory::<T, Storage>::matches_format(format)
+    }
+
+    unsafe fn import_validated(
+        array: &'array ArrowArray,
+        schema: &ArrowSchema,
+        owner: &Owner<'array, Self>,
+        length: usize,
+    ) -> Result<Self, ImportError> {
+        // SAFETY: The caller guarantees null or valid binary metadata.
+        let mut pairs = unsafe { MetadataPairs::new(schema) }?;
+        let name = pairs.find_map(|pair| match pair {
+            Ok((EXTENSION_NAME, name)) => Some(Ok(name)),
+            Ok(_) => None,
+            Err(error) => Some(Err(error)),
+        });
+        if name.transpose()? != Some(T::NAME) {
+            return Err(ImportError::UnexpectedExtensionName);
+        }
+
+        // SAFETY: The storage layout has the same common fields, and the
+        // caller upholds the requirements of `Import::import`.
+        let storage =
+            unsafe { StorageMemory::<T, Storage>::import_validated(array, schema, owner, length) }?;
+
+        // SAFETY: Common validation and the caller guarantee a valid optional
+        // validity buffer for the imported storage.
+        let collection = unsafe {
+            Nulls::wrap::<_, Storage>(array, owner, ExtensionValues::from_buffer(storage))
+        }?;
+        Ok(Self::from_buffer(collection))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::{borrow::ToOwned, string::String, vec, vec::Vec};
+
+    use narrow::{
+        array::Array,
+        buffer::SliceBuffer,
+        collection::Collection,
+        fixed_size::FixedSizeArray,
+        layout::extension::{Json, Uuid},
+    };
+
+    use crate::{
+        ForeignBuffer,
+        export::Export,
+        import::{Import, ImportError, ImportOwned},
+    };
+
+    #[test]
+    fn round_trips_extension_arrays() {
+        let uuid = Uuid::from_bytes([3; 16]);
+        let (array, schema) = [None, Some(uuid), Some(Uuid::default())]
+            .into_iter()
+            .collect::<Array<Option<Uuid>>>()
+            .slice(1, 2)
+            .export()
+            .expect("export array");
+
+        // SAFETY: The exported structures describe a valid UUID array and
+        // are moved into the imported storage.
+        let imported: Array<Option<Uuid>, ForeignBuffer> =
+            unsafe { ImportOwned::import_owned(array, &schema) }.expect("import array");
+        assert_eq!(
+            imported.into_iter_owned().collect::<Vec<_>>(),
+            [Some(uuid), Some(Uuid::default())]
+        );
+
+        let (list_array, list_schema) = [vec![Json::from("1"), Json::from("[]")]]
+            .into_iter()
+            .collect::<Array<Vec<Json>>>()
+            .export()
+            .expect("export array");
+        // SAFETY: The exported structures remain valid while borrowed.
+        let lists: Array<Vec<Json>, SliceBuffer<'_>> =
+            unsafe { Import::import(&list_array, &list_schema) }.expect("import array");
+        assert_eq!(
+            lists.owned(0),
+            Some(vec![Json::from("1"), Json::from("[]")])
+        );
+    }
+
+    #[test]
+    fn checks_extension_names() {
+        let (array, schema) = [Json::from("{}")]
+            .into_iter()
+            .collect::<Array<Json>>()
+            .export()
+            .expect("export array");
+        // SAFETY: The exported structures remain valid while borrowed.
+        let storage =
+            unsafe { <Array<String, SliceBuffer<'_>> as Import>::import(&array, &schema) }
+                .expect("storage type ignores extension metadata");
+        assert_eq!(storage.owned(0), Some("{}".to_owned()));
+
+        let (plain_array, plain_schema) = [[1_u8; 16]]
+            .into_iter()
+            .map(FixedSizeArray::from)
+            .collect::<Array<FixedSizeArray<u8, 16>>>()
+            .export()
+            .expect("export array");
+        // SAFETY: The exported structures remain valid while borrowed.
+        let missing = unsafe {
+            <Array<Uuid, SliceBuffer<'_>> as Import>::import(&plain_array, &plain_schema)
+                .expect_err("missing extension name")
+        };
+        assert_eq!(missing, ImportError::UnexpectedExtensionName);
+    }
+}
@@ -0,0 +1,224 @@
+//! Import support for [`Field`] and [`Schema`] descriptions.
+
+use core::{ffi::CStr, slice};
+use std::{borrow::ToOwned, collections::HashMap, string::String, vec::Vec};
+
+use crate::{ARROW_FLAG_NULLABLE, ArrowSchema, Field, Schema, metadata::MetadataPairs};
+
+use super::ImportError;
+
+impl Field {
+    /// Imports the field described by an [`ArrowSchema`], including the
+    /// fields of its child schemas.
+    ///
+    /// A null name is imported as an empty name, and null metadata as empty
+    /// metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ImportError`] when the schema or one of its children is
+    /// released, a child schema is missing, a name is not valid UTF-8 or the
+    /// metadata is invalid.
+    ///
+    /// # Safety
+    ///
+    /// The name of `schema` must be null or a null-terminated string, its
+    /// metadata must be null or encoded in the Arrow C Data binary metadata
+    /// format, and its child pointers must be valid. The same applies to its
+    /// child schemas.
+    pub unsafe fn import(schema: &ArrowSchema) -> Result<Self, ImportError> {
+        if schema.is_released() {
+            return Err(ImportError::ReleasedSchema);
+        }
+        let name = if schema.name.is_null() {
+            String::new()
+        } else {
+            // SAFETY: The caller guarantees a null-terminated name.
+            unsafe { CStr::from_ptr(schema.name) }
+                .to_str()
+                .map_err(|_| ImportError::InvalidFieldName)?
+                .to_owned()
+        };
+        // SAFETY: The caller guarantees null or valid binary metadata.
+        let metadata = unsafe { MetadataPairs::new(schema) }?
+            .map(|pair| pair.map(|(key, value)| (key.to_owned(), value.to_owned())))
+            .collect::<Result<HashMap<_, _>, _>>()?;
+
+        let n_children =
+            usize::try_from(schema.n_children).map_err(|_| ImportError::MissingSchemaChildren)?;
+        let children = if n_children == 0 {
+            Vec::new()
+        } else {
+            if schema.children.is_null() {
+                return Err(ImportError::MissingSchemaChildren);
+            }
+            // SAFETY: The caller guarantees `n_children` child pointers.
+            let child_pointers = unsafe { slice::from_raw_parts(schema.children, n_children) };
+            child_pointers
+                .iter()
+                .map(|&pointer| {
+                    if pointer.is_null() {
+                        return Err(ImportError::MissingSchemaChildren);
+                    }
+                    // SAFETY: The caller guarantees valid child schemas.
+                    let child = unsafe { &*pointer };
+                    // SAFETY: The caller guarantees the same for the children.
+                    unsafe { Self::import(child) }
+                })
+                .collect::<Result<_, _>>()?
+        };
+
+        Ok(Self {
+            name,
+            nullable: schema.flags & ARROW_FLAG_NULLABLE != 0,
+            metadata,
+            children,
+        })
+    }
+}
+
+impl Schema {
+    /// Imports the schema of a record batch, described by a struct
+    /// [`ArrowSchema`].
+    ///
+    /// The child schemas describe the columns, and the metadata of the struct
+    /// is the metadata of the schema.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ImportError::UnexpectedFormat`] when `schema` is not a struct,
+    /// and the errors of [`Field::import`] otherwise.
+    ///
+    /// # Safety
+    ///
+    /// The format of `schema` must be null or a null-terminated string, and
+    /// the requirements of [`Field::import`] apply.
+    pub unsafe fn import(schema: &ArrowSchema) -> Result<Self, ImportError> {
+        if schema.is_released() {
+            return Err(ImportError::ReleasedSchema);
+        }
+        if schema.format.is_null() {
+            return Err(ImportError::MissingFormat);
+        }
+        // SAFETY: The caller guarantees a null-terminated format.
+        if unsafe { CStr::from_ptr(schema.format) } != c"+s" {
+            return Err(ImportError::UnexpectedFormat);
+        }
+        // SAFETY: The caller upholds the requirements of `Field::import`.
+        let field = unsafe { Field::import(schema) }?;
+        Ok(Self {
+            fields: field.children,
+            metadata: field.metadata,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::ToOwned, collections::HashMap, string::String, vec, vec::Vec};
+
+    use narrow::array::Array;
+
+    use crate::{
+        ArrowSchema, ExportRecordBatch, Field, Schema, export::Export, import::ImportError,
+    };
+
+    /// Releases a test schema that borrows its name and metadata.
+    unsafe extern "C" fn release_borrowed(schema: *mut ArrowSchema) {
+        // SAFETY: Called with the address of a live schema.
+        unsafe { (*schema).release = None };
+    }
+
+    #[test]
+    fn round_trips_nested_fields() {
+        let values = [Some(vec![[1_u16, 2]]), None]
+            .into_iter()
+            .collect::<Array<Option<Vec<[u16; 2]>>>>();
+        let field = Field::new("lists", true)
+            .with_metadata(HashMap::from([
+                ("a".to_owned(), String::new()),
+                ("ключ".to_owned(), "значение".to_owned()),
+            ]))
+            .with_children(vec![
+                Field::new("list", false)
+                    .with_metadata(HashMap::from([("size".to_owned(), "2".to_owned())]))
+                    .with_children(vec![Field::new("item", false)]),
+            ]);
+        let (_array, schema) = values.export_field(&field).expect("export array");
+
+        // SAFETY: The schema was exported above.
+        assert_eq!(unsafe { Field::import(&schema) }, Ok(field));
+    }
+
+    #[test]
+    fn imports_unnamed_children() {
+        let (_array, schema) = [vec![1_u8]]
+            .into_iter()
+            .collect::<Array<Vec<u8>>>()
+            .export()
+            .expect("export array");
+
+        // SAFETY: The schema was exported above.
+        let field = unsafe { Field::import(&schema) }.expect("import field");
+        assert_eq!(
+            field,
+            Field::default().with_children(vec![Field::default()])
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_schemas() {
+        let (_array, list_schema) = [vec![1_u8]]
+            .into_iter()
+            .collect::<Array<Vec<u8>>>()
+            .export()
+            .expect("export array");
+        // SAFETY: The schema was exported above.
+        let not_a_struct = unsafe { Schema::import(&list_schema) };
+        assert_eq!(not_a_struct, Err(ImportError::UnexpectedFormat));
+
+        let invalid_name = ArrowSchema {
+            name: c"\xff".as_ptr(),
+            release: Some(release_borrowed),
+            ..ArrowSchema::default()
+        };
+        // SAFETY: The schema has a null-terminated name and no metadata or
+        // children.
+        let invalid_name_error = unsafe { Field::import(&invalid_name) };
+        assert_eq!(invalid_name_error, Err(ImportError::InvalidFieldName));
+
+        let metadata = [1_i32, -1]
+            .iter()
+            .flat_map(|length| length.to_ne_bytes())
+            .collect::<Vec<_>>();
+        let negative_length = ArrowSchema {
+            metadata: metadata.as_ptr().cast(),
+            release: Some(release_borrowed),
+            ..ArrowSchema::default()
+        };
+        // SAFETY: The metadata ends at the negative length, which is rejected
+        // before anything after it is read.
+        let negative_length_error = unsafe { Field::import(&negative_length) };
+        assert_eq!(negative_length_error, Err(ImportError::InvalidMetadata));
+
+        // SAFETY: A default schema is released.
+        let released = unsafe { Field::import(&ArrowSchema::default()) };
+        assert_eq!(released, Err(ImportError::ReleasedSchema));
+    }
+
+    #[test]
+    fn round_trips_record_batch_schemas() {
+        let batch = [(1_u8, Some(true))]
+            .into_iter()
+            .collect::<Array<(u8, Option<bool>)>>();
+        let schema = Schema::new(vec![
+            Field::new("id", false),
+            Field::new("flag", true)
+                .with_metadata(HashMap::from([("k".to_owned(), "v".to_owned())])),
+        ]);
+        let (_array, exported) = batch.export_record_batch(&schema).expect("export batch");
+
+        // SAFETY: The schema was exported above.
+        assert_eq!(unsafe { Schema::import(&exported) }, Ok(schema));
+    }
+}
@@ -1,51 +1,39 @@
-//! Borrowed import support for [`FixedSizeList`].
+//! Import support for [`FixedSizeList`].
 
 use core::ffi::CStr;
 
 use narrow::{
-    buffer::SliceBuffer,
     collection::flatten::Flatten,
-    layout::{ArrayItem, fixed_size_list::FixedSizeList},
+    layout::{ArrayItem, MemoryLayout, fixed_size_list::FixedSizeList},
     length::Length,
 };
 
-use crate::{ArrowArray, ArrowSchema};
+use crate::{ArrowArray, ArrowSchema, parse_width};
 
-use super::{ImportError, ImportLayout, ImportNullability};
+use super::{ImportBuffer, ImportError, ImportLayout, ImportNullability, Owner};
 
-impl<'array, T, const N: usize, Nulls> ImportLayout<'array>
-    for FixedSizeList<T, N, Nulls, SliceBuffer<'array>>
+impl<'array, T, const N: usize, Nulls, Storage> ImportLayout<'array>
+    for FixedSizeList<T, N, Nulls, Storage>
 where
     T: ArrayItem,
-    T::Memory<SliceBuffer<'array>>: ImportLayout<'array>,
+    T::Memory<Storage>: ImportLayout<'array, Storage = Storage>,
     Nulls: ImportNullability<'array>,
+    Storage: ImportBuffer<'array>,
 {
+    type Storage = Storage;
+
     const FLAGS: i64 = Nulls::FLAGS;
     const BUFFERS: i64 = 1;
     const CHILDREN: i64 = 1;
 
     fn matches_format(format: &CStr) -> bool {
-        let Some(digits) = format.to_bytes().strip_prefix(b"+w:") else {
-            return false;
-        };
-        if digits.is_empty() {
-            return false;
-        }
-
-        // Parse the decimal width without allocating, rejecting non-digits
-        // and arithmetic overflow before comparing it with `N`.
-        digits.iter().try_fold(0_usize, |size, byte| {
-            let digit = (*byte).checked_sub(b'0')?;
-            if digit > 9 {
-                return None;
-            }
-            size.checked_mul(10)?.checked_add(usize::from(digit))
-        }) == Some(N)
+        parse_width(format, b"+w:") == Some(N)
     }
 
     unsafe fn import_validated(
         array: &'array ArrowArray,
         schema: &ArrowSchema,
+        owner: &Owner<'array, Self>,
         length: usize,
     ) -> Result<Self, ImportError> {
         if N == 0 {
@@ -54,22 +42,25 @@ where
 
         // SAFETY: Common parent fields are validated and the caller upholds
         // the Arrow C Data requirements for the retained child structures.
-        let child =
-            unsafe { Self::import_child::<T::Memory<SliceBuffer<'array>>>(array, schema, 0) }?;
+        let child = unsafe { Self::import_child::<T::Memory<Storage>>(array, schema, owner, 0) }?;
         let child_length = child.len();
-        if length.checked_mul(N) != Some(child_length) {
+        let Some(values) = length
+            .checked_mul(N)
+            .filter(|values| *values <= child_length)
+        else {
             return Err(ImportError::FixedSizeListLengthMismatch {
                 length,
                 child_length,
                 size: N,
             });
-        }
-        let flattened = Flatten::try_from_parts(child)
+        };
+        // Producers may keep child items past the end of a sliced parent.
+        let flattened = Flatten::try_from_parts(child.slice(0, values))
             .expect("validated fixed-size-list child length is a multiple of its width");
 
         // SAFETY: Common validation and the caller guarantee a valid optional
         // validity buffer for the imported lists.
-        let collection = unsafe { Nulls::wrap(array, flattened) }?;
+        let collection = unsafe { Nulls::wrap::<_, Storage>(array, owner, flattened) }?;
         Ok(Self::from_buffer(collection))
     }
 }
@@ -78,7 +69,7 @@ where
 mod tests {
     extern crate alloc;
 
-    use alloc::sync::Arc;
+    use alloc::{sync::Arc, vec::Vec};
     use core::{borrow::Borrow, ptr};
 
     use narrow::{
@@ -209,10 +200,12 @@ mod tests {
             .into_iter()
             .collect::<Array<[i32; 2]>>();
         let (mut array, schema) = source.export().expect("export array");
-        array.length = 1;
+        array.offset = 1;
+        array.length = 2;
 
-        // SAFETY: The exported structures and buffers remain valid; only the
-        // parent length is changed to exercise child length validation.
+        // SAFETY: The parent has no buffers to read, and the child remains
+        // valid; only the parent window is changed to exercise child length
+        // validation.
         let error = unsafe {
             <Array<[i32; 2], SliceBuffer<'_>> as Import>::import(&array, &schema)
                 .expect_err("mismatched fixed-size-list child length")
@@ -221,10 +214,27 @@ mod tests {
         assert_eq!(
             error,
             ImportError::FixedSizeListLengthMismatch {
-                length: 1,
+                length: 3,
                 child_length: 4,
                 size: 2,
             }
         );
     }
+
+    #[test]
+    fn imports_fixed_size_list_with_offset_and_longer_child() {
+        let source = [[1_i32, 2], [3, 4], [5, 6]]
+            .into_iter()
+            .collect::<Array<[i32; 2]>>();
+        let (mut array, schema) = source.export().expect("export array");
+        array.offset = 1;
+        array.length = 1;
+
+        // SAFETY: The exported child remains valid and contains more values
+        // than the parent window requires.
+        let imported: Array<[i32; 2], SliceBuffer<'_>> =
+            unsafe { Import::import(&array, &schema) }.expect("import array");
+
+        assert_eq!(imported.into_iter_owned().collect::<Vec<_>>(), [[3, 4]]);
+    }
 }
@@ -1,55 +1,55 @@
-//! Borrowed import support for [`FixedSizePrimitive`].
+//! Import support for [`FixedSizePrimitive`].
 
 use core::{ffi::CStr, mem, slice};
 
-use narrow::{
-    buffer::SliceBuffer, fixed_size::FixedSize, layout::fixed_size_primitive::FixedSizePrimitive,
-};
+use narrow::{fixed_size::FixedSize, layout::fixed_size_primitive::FixedSizePrimitive};
 
 use crate::{ArrowArray, ArrowSchema, ArrowType};
 
-use super::{ImportError, ImportLayout, ImportNullability};
+use super::{ImportBuffer, ImportError, ImportLayout, ImportNullability, Owner};
 
-impl<'array, T, Nulls> ImportLayout<'array> for FixedSizePrimitive<T, Nulls, SliceBuffer<'array>>
+impl<'array, T, Nulls, Storage> ImportLayout<'array> for FixedSizePrimitive<T, Nulls, Storage>
 where
     T: FixedSize + ArrowType,
     Nulls: ImportNullability<'array>,
+    Storage: ImportBuffer<'array>,
 {
+    type Storage = Storage;
+
     const FLAGS: i64 = Nulls::FLAGS;
     const BUFFERS: i64 = 2;
     const CHILDREN: i64 = 0;
 
     fn matches_format(format: &CStr) -> bool {
-        format == T::FORMAT
+        T::matches_format(format)
     }
 
     unsafe fn import_validated(
         array: &'array ArrowArray,
         _schema: &ArrowSchema,
+        owner: &Owner<'array, Self>,
         length: usize,
     ) -> Result<Self, ImportError> {
         // SAFETY: The caller guarantees a valid two-entry buffer pointer array.
         let buffers = unsafe { slice::from_raw_parts(array.buffers, 2) };
-        let values = if length == 0 {
-            &[]
-        } else {
-            let values = buffers[1].cast::<T>();
-            if values.is_null() {
+        let values_pointer = buffers[1].cast::<T>();
+        if length != 0 {
+            if values_pointer.is_null() {
                 return Err(ImportError::MissingValuesBuffer);
             }
-            if !values.is_aligned() {
+            if !values_pointer.is_aligned() {
                 return Err(ImportError::MisalignedValuesBuffer {
                     alignment: mem::align_of::<T>(),
                 });
             }
-            // SAFETY: The caller guarantees the value buffer contains `length`
-            // properly aligned values that remain immutable for `'array`.
-            unsafe { slice::from_raw_parts(values, length) }
-        };
+        }
+        // SAFETY: The caller guarantees the value buffer contains `length`
+        // properly aligned values that remain immutable for `'array`.
+        let values = unsafe { Storage::import_buffer(owner, values_pointer, length) };
 
         // SAFETY: Common validation and the caller guarantee a valid optional
         // validity buffer for the imported values.
-        let collection = unsafe { Nulls::wrap(array, values) }?;
+        let collection = unsafe { Nulls::wrap::<_, Storage>(array, owner, values) }?;
         Ok(Self::from_buffer(collection))
     }
 }
@@ -66,15 +66,15 @@ mod tests {
         bitmap::{Bitmap, ValidityBitmap},
         buffer::{ArcBuffer, BufferRef, SliceBuffer},
         collection::{ChildRef, Collection},
-        layout::fixed_size_primitive::FixedSizePrimitive,
+        layout::{MemoryLayout, fixed_size_primitive::FixedSizePrimitive},
         length::Length,
         validity::Validity,
     };
 
     use crate::{
-        ARROW_FLAG_NULLABLE,
+        ARROW_FLAG_NULLABLE, ForeignBuffer,
         export::Export,
-        import::{Import, ImportError},
+        import::{Import, ImportError, ImportOwned},
     };
 
     #[test]
@@ -104,6 +104,52 @@ mod tests {
         drop(schema);
     }
 
+    #[test]
+    fn releases_owned_import_after_last_buffer() {
+        let storage = Arc::<[i32]>::from([1, 2, 3]);
+        let weak = Arc::downgrade(&storage);
+        let data = storage.as_ptr();
+        let source: Array<i32, ArcBuffer> =
+            Array::from_buffer(FixedSizePrimitive::from_buffer(storage));
+        let (array, schema) = source.export().expect("export array");
+
+        // SAFETY: The exported structures describe a valid i32 array.
+        let imported: Array<i32, ForeignBuffer> =
+            unsafe { ImportOwned::import_owned(array, &schema) }.expect("import array");
+        drop(schema);
+        let sliced = imported.slice(1, 2);
+
+        let imported_values: &[i32] = imported.buffer_ref().buffer_ref().borrow();
+        assert_eq!(imported_values.as_ptr(), data);
+        drop(imported);
+        assert!(weak.upgrade().is_some());
+        assert_eq!(
+            sliced.into_iter_owned().collect::<alloc::vec::Vec<_>>(),
+            [2, 3]
+        );
+        assert!(weak.upgrade().is_none());
+    }
+
+    #[test]
+    fn releases_owned_import_on_error() {
+        let storage = Arc::<[i32]>::from([1]);
+        let weak = Arc::downgrade(&storage);
+        let source: Array<i32, ArcBuffer> =
+            Array::from_buffer(FixedSizePrimitive::from_buffer(storage));
+        let (array, mut schema) = source.export().expect("export array");
+        schema.format = c"l".as_ptr();
+
+        // SAFETY: The exported structures and value buffer remain valid; only
+        // the schema format is changed to exercise validation.
+        let error = unsafe {
+            <Array<i32, ForeignBuffer> as ImportOwned>::import_owned(array, &schema)
+                .expect_err("mismatched format")
+        };
+
+        assert_eq!(error, ImportError::UnexpectedFormat);
+        assert!(weak.upgrade().is_none());
+    }
+
     #[test]
     fn rejects_mismatched_primitive_format() {
         let source = [1_i32].into_iter().collect::<Array<i32>>();
@@ -121,19 +167,38 @@ mod tests {
     }
 
     #[test]
-    fn rejects_non_zero_primitive_offset() {
-        let source = [1_i32].into_iter().collect::<Array<i32>>();
+    fn imports_primitive_values_with_offset() {
+        let source = [1_i32, 2, 3].into_iter().collect::<Array<i32>>();
         let (mut array, schema) = source.export().expect("export array");
         array.offset = 1;
+        array.length = 2;
+
+        // SAFETY: The value buffer contains `offset + length` values that
+        // remain valid for the lifetime of the imported array.
+        let imported: Array<i32, SliceBuffer<'_>> =
+            unsafe { Import::import(&array, &schema) }.expect("import array");
+
+        assert_eq!(imported.buffer_ref().offset(), 1);
+        assert_eq!(
+            imported.into_iter_owned().collect::<alloc::vec::Vec<_>>(),
+            [2, 3]
+        );
+    }
+
+    #[test]
+    fn rejects_negative_primitive_offset() {
+        let source = [1_i32].into_iter().collect::<Array<i32>>();
+        let (mut array, schema) = source.export().expect("export array");
+        array.offset = -1;
 
         // SAFETY: The exported structures and value buffer remain valid; only
         // the array offset is changed to exercise validation.
         let error = unsafe {
             <Array<i32, SliceBuffer<'_>> as Import>::import(&array, &schema)
-                .expect_err("non-zero offset")
+                .expect_err("negative offset")
         };
 
-        assert_eq!(error, ImportError::NonZeroOffset { offset: 1 });
+        assert_eq!(error, ImportError::InvalidOffset { offset: -1 });
     }
 
     #[test]
@@ -290,6 +355,41 @@ mod tests {
         assert_eq!(error, ImportError::MissingValidityBuffer);
     }
 
+    #[test]
+    fn counts_nulls_in_nullable_primitive_window() {
+        let source = [Some(1_i32), None, Some(3)]
+            .into_iter()
+            .collect::<Array<Option<i32>>>();
+        let (mut array, schema) = source.export().expect("export array");
+        array.offset = 2;
+        array.length = 1;
+        array.null_count = 0;
+
+        // SAFETY: The exported buffers contain `offset + length` items that
+        // remain valid for the lifetime of the imported array.
+        let imported: Array<Option<i32>, SliceBuffer<'_>> =
+            unsafe { Import::import(&array, &schema) }.expect("import array");
+        assert_eq!(
+            imported.into_iter_owned().collect::<alloc::vec::Vec<_>>(),
+            [Some(3)]
+        );
+
+        array.null_count = 1;
+        // SAFETY: The exported buffers remain valid; only the null count is
+        // changed to exercise validation of the window.
+        let error = unsafe {
+            <Array<Option<i32>, SliceBuffer<'_>> as Import>::import(&array, &schema)
+                .expect_err("null outside of the window")
+        };
+        assert_eq!(
+            error,
+            ImportError::NullCountMismatch {
+                declared: 1,
+                actual: 0,
+            }
+        );
+    }
+
     #[test]
     fn rejects_nullable_primitive_null_count_mismatch() {
         let source = [Some(1_i32), None]
@@ -312,4 +412,37 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    #[cfg(feature = "half")]
+    fn imports_half_precision_values() {
+        use alloc::vec::Vec;
+        use core::ffi::CStr;
+
+        use narrow::fixed_size::f16;
+
+        let source = [0.5_f32, -1.0, 2.0]
+            .into_iter()
+            .collect::<Array<f32>>()
+            .to_f16();
+        let (array, schema) = source.export().expect("export array");
+        // SAFETY: The exported schema has a live, null-terminated format string.
+        assert_eq!(unsafe { CStr::from_ptr(schema.format) }, c"e");
+
+        // SAFETY: The exported structures retain a valid f16 buffer for the
+        // lifetime of the imported array.
+        let imported: Array<f16, SliceBuffer<'_>> =
+            unsafe { Import::import(&array, &schema) }.expect("import array");
+        assert_eq!(
+            imported.to_f32().into_iter_owned().collect::<Vec<_>>(),
+            [0.5, -1.0, 2.0]
+        );
+
+        // SAFETY: The exported structures remain valid.
+        let error = unsafe {
+            <Array<u16, SliceBuffer<'_>> as Import>::import(&array, &schema)
+                .expect_err("f16 is not u16")
+        };
+        assert_eq!(error, ImportError::UnexpectedFormat);
+    }
 }
@@ -0,0 +1,187 @@
+//! Import support for [`ListView`].
+
+use core::{ffi::CStr, mem, slice};
+
+use narrow::layout::{
+    ArrayItem,
+    list_view::{ListView, ListViews},
+};
+
+use crate::{ArrowArray, ArrowListOffset, ArrowSchema};
+
+use super::{ImportBuffer, ImportError, ImportLayout, ImportNullability, Owner};
+
+impl<'array, T, Nulls, OffsetItem, Storage> ImportLayout<'array>
+    for ListView<T, Nulls, OffsetItem, Storage>
+where
+    T: ArrayItem,
+    Nulls: ImportNullability<'array>,
+    OffsetItem: ArrowListOffset,
+    T::Memory<Storage>: ImportLayout<'array, Storage = Storage>,
+    Storage: ImportBuffer<'array>,
+{
+    type Storage = Storage;
+
+    const FLAGS: i64 = Nulls::FLAGS;
+    const BUFFERS: i64 = 3;
+    const CHILDREN: i64 = 1;
+
+    fn matches_format(format: &CStr) -> bool {
+        format == OffsetItem::VIEW_FORMAT
+    }
+
+    unsafe fn import_validated(
+        array: &'array ArrowArray,
+        schema: &ArrowSchema,
+        owner: &Owner<'array, Self>,
+        length: usize,
+    ) -> Result<Self, ImportError> {
+        // SAFETY: Common validation guarantees a three-entry buffer pointer
+        // array.
+        let buffers = unsafe { slice::from_raw_parts(array.buffers, 3) };
+        let offsets_pointer = buffers[1].cast::<OffsetItem>();
+        let sizes_pointer = buffers[2].cast::<OffsetItem>();
+        let alignment = mem::align_of::<OffsetItem>();
+        // Unlike offsets, list view offsets and sizes have one entry per
+        // list, so both buffers may be missing when there are no lists.
+        if length != 0 {
+            if offsets_pointer.is_null() {
+                return Err(ImportError::MissingOffsetsBuffer);
+            }
+            if !offsets_pointer.is_aligned() {
+                return Err(ImportError::MisalignedOffsetsBuffer { alignment });
+            }
+            if sizes_pointer.is_null() {
+                return Err(ImportError::MissingSizesBuffer);
+            }
+            if !sizes_pointer.is_aligned() {
+                return Err(ImportError::MisalignedSizesBuffer { alignment });
+            }
+        }
+        // SAFETY: The caller guarantees the offsets and sizes buffers contain
+        // `length` aligned values that remain immutable for `'array`.
+        let offsets = unsafe { Storage::import_buffer(owner, offsets_pointer, length) };
+        // SAFETY: The caller guarantees the same for the sizes buffer, whose
+        // pointer was checked for null and alignment above.
+        let sizes = unsafe { Storage::import_buffer(owner, sizes_pointer, length) };
+
+        // SAFETY: Common parent fields are validated and the caller upholds
+        // the Arrow C Data requirements for the retained child structures.
+        let child = unsafe { Self::import_child::<T::Memory<Storage>>(array, schema, owner, 0) }?;
+        let lists = ListViews::try_from_parts(child, offsets, sizes)
+            .map_err(|error| ImportError::InvalidListViews { error })?;
+
+        // SAFETY: Common validation and the caller guarantee a valid optional
+        // validity buffer for the imported lists.
+        let collection = unsafe { Nulls::wrap::<_, Storage>(array, owner, lists) }?;
+        Ok(Self::from_buffer(collection))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::{sync::Arc, vec, vec::Vec};
+    use core::{borrow::Borrow, slice};
+
+    use narrow::{
+        array::Array,
+        buffer::{ArcBuffer, BufferRef, SliceBuffer},
+        collection::Collection,
+        layout::{
+            fixed_size_primitive::FixedSizePrimitive,
+            list_view::{ListView, ListViews, ListViewsError, ViewedList},
+        },
+    };
+
+    use crate::{
+        ForeignBuffer,
+        export::Export,
+        import::{Import, ImportError, ImportOwned},
+    };
+
+    #[test]
+    fn imports_list_view_without_copying() {
+        let offset_storage = Arc::<[i32]>::from([2, 0, 1]);
+        let size_storage = Arc::<[i32]>::from([1, 2, 2]);
+        let offsets_data = offset_storage.as_ptr();
+        let sizes_data = size_storage.as_ptr();
+        let lists = ListViews::try_from_parts(
+            FixedSizePrimitive::from_buffer(Arc::<[u32]>::from([7, 8, 9])),
+            offset_storage,
+            size_storage,
+        )
+        .expect("valid list views");
+        let source: Array<ViewedList<u32>, ArcBuffer> =
+            Array::from_buffer(ListView::from_buffer(lists));
+        let (array, schema) = source.export().expect("export array");
+
+        // SAFETY: The exported structures remain live and retain their
+        // buffers and child for the lifetime of the imported array.
+        let imported: Array<ViewedList<u32>, SliceBuffer<'_>> =
+            unsafe { Import::import(&array, &schema) }.expect("import array");
+
+        let imported_lists = imported.buffer_ref().buffer_ref();
+        let offsets: &[i32] = imported_lists.buffer_ref();
+        let sizes: &[i32] = imported_lists.sizes().borrow();
+        assert_eq!(offsets.as_ptr(), offsets_data);
+        assert_eq!(sizes.as_ptr(), sizes_data);
+        assert_eq!(
+            imported.into_iter_owned().collect::<Vec<_>>(),
+            [vec![9], vec![7, 8], vec![8, 9]].map(ViewedList::new)
+        );
+    }
+
+    #[test]
+    fn imports_sliced_nullable_large_list_view() {
+        let items = [Some(vec![1_i16, 2]), None, Some(vec![3]), Some(vec![])]
+            .map(|list| list.map(ViewedList::<_, i64>::new));
+        let source = items
+            .clone()
+            .into_iter()
+            .collect::<Array<Option<ViewedList<i16, i64>>>>();
+        let (array, schema) = source.slice(1, 3).export().expect("export array");
+
+        // SAFETY: The exported array is moved into the imported buffers,
+        // which release it when they are dropped.
+        let imported = unsafe {
+            Array::<Option<ViewedList<i16, i64>>, ForeignBuffer>::import_owned(array, &schema)
+        }
+        .expect("import array");
+
+        assert_eq!(imported.into_iter_owned().collect::<Vec<_>>(), items[1..]);
+    }
+
+    #[test]
+    fn rejects_out_of_bounds_list_view() {
+        let source = [vec![1_u8, 2]]
+            .into_iter()
+            .map(ViewedList::new)
+            .collect::<Array<ViewedList<u8>>>();
+        let (array, schema) = source.export().expect("export array");
+        // SAFETY: The exported array owns a three-entry buffer pointer array.
+        let buffers = unsafe { slice::from_raw_parts(array.buffers, 3) };
+        let sizes_pointer = buffers.as_ptr().cast_mut().wrapping_add(2);
+        let sizes = [3_i32];
+        // SAFETY: The buffer pointer array is not shared with the imported
+        // array.
+        let original = unsafe { sizes_pointer.replace(sizes.as_ptr().cast()) };
+
+        // SAFETY: The replacement sizes outlive the import.
+        let error = unsafe {
+            <Array<ViewedList<u8>, SliceBuffer<'_>> as Import>::import(&array, &schema)
+                .expect_err("out of bounds list")
+        };
+        // SAFETY: `sizes_pointer` still points into the buffer pointer array
+        // owned by the export, and the import that borrowed it has returned.
+        unsafe { sizes_pointer.write(original) };
+
+        assert_eq!(
+            error,
+            ImportError::InvalidListViews {
+                error: ListViewsError::OutOfBounds { index: 0 }
+            }
+        );
+    }
+}
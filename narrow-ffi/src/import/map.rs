@@ -0,0 +1,301 @@
+//! Import support for [`Map`].
+//!
+//! The `entries` child of a map is imported as a two-field struct. Its field
+//! names are not checked, and keys must be non-nullable. The
+//! [`ARROW_FLAG_MAP_KEYS_SORTED`](crate::ARROW_FLAG_MAP_KEYS_SORTED) flag is
+//! accepted whether or not it is set: it describes the producer's data and
+//! does not change how the map is read.
+
+use core::{ffi::CStr, mem, slice};
+
+use narrow::{
+    layout::{
+        ArrayItem, Layout, MemoryLayout,
+        map::{Map, MapEntries, MapItem, MapValues},
+        struct_array::Struct,
+    },
+    length::Length,
+    nullability::NonNullable,
+};
+
+use crate::{ArrowArray, ArrowSchema};
+
+use super::{ImportBuffer, ImportError, ImportLayout, ImportNullability, Owner};
+
+impl<'array, K, V, Nulls, Storage, U> ImportLayout<'array> for Map<K, V, Nulls, Storage, U>
+where
+    K: Layout,
+    V: ArrayItem,
+    Nulls: ImportNullability<'array>,
+    U: MapItem<K, V>,
+    MapEntries<K, V, Storage>: ImportLayout<'array, Storage = Storage>,
+    Storage: ImportBuffer<'array>,
+{
+    type Storage = Storage;
+
+    const FLAGS: i64 = Nulls::FLAGS;
+    const BUFFERS: i64 = 2;
+    const CHILDREN: i64 = 1;
+
+    fn matches_format(format: &CStr) -> bool {
+        format == c"+m"
+    }
+
+    unsafe fn import_validated(
+        array: &'array ArrowArray,
+        schema: &ArrowSchema,
+        owner: &Owner<'array, Self>,
+        length: usize,
+    ) -> Result<Self, ImportError> {
+        let offsets_length = length.checked_add(1).ok_or(ImportError::InvalidLength {
+            length: array.length,
+        })?;
+        // SAFETY: Common validation guarantees a two-entry buffer pointer array.
+        let buffers = unsafe { slice::from_raw_parts(array.buffers, 2) };
+        let offsets_pointer = buffers[1].cast::<i32>();
+        if offsets_pointer.is_null() {
+            return Err(ImportError::MissingOffsetsBuffer);
+        }
+        if !offsets_pointer.is_aligned() {
+            return Err(ImportError::MisalignedOffsetsBuffer {
+                alignment: mem::align_of::<i32>(),
+            });
+        }
+        // SAFETY: The caller guarantees the offsets buffer contains
+        // `offsets_length` aligned values that remain immutable for `'array`.
+        let offsets = unsafe { Storage::import_buffer(owner, offsets_pointer, offsets_length) };
+
+        // SAFETY: Common parent fields are validated and the caller upholds
+        // the Arrow C Data requirements for the retained child structures.
+        let entries =
+            unsafe { Self::import_child::<MapEntries<K, V, Storage>>(array, schema, owner, 0) }?;
+        let maps = MapValues::try_from_parts(entries, offsets)
+            .map_err(|error| ImportError::InvalidOffsets { error })?;
+
+        // SAFETY: Common validation and the caller guarantee a valid optional
+        // validity buffer for the imported maps.
+        let collection = unsafe { Nulls::wrap::<_, Storage>(array, owner, maps) }?;
+        Ok(Self::from_buffer(collection))
+    }
+}
+
+impl<'array, K, V, Storage> ImportLayout<'array> for MapEntries<K, V, Storage>
+where
+    K: Layout,
+    V: ArrayItem,
+    K::Memory<NonNullable, Storage>: ImportLayout<'array, Storage = Storage>,
+    V::Memory<Storage>: ImportLayout<'array, Storage = Storage>,
+    Storage: ImportBuffer<'array>,
+{
+    type Storage = Storage;
+
+    const BUFFERS: i64 = 1;
+    const CHILDREN: i64 = 2;
+
+    fn matches_format(format: &CStr) -> bool {
+        format == c"+s"
+    }
+
+    unsafe fn import_validated(
+        array: &'array ArrowArray,
+        schema: &ArrowSchema,
+        owner: &Owner<'array, Self>,
+        length: usize,
+    ) -> Result<Self, ImportError> {
+        // SAFETY: Common parent fields are validated and the caller upholds
+        // the Arrow C Data requirements for the retained child structures.
+        let keys = unsafe {
+            Self::import_child::<K::Memory<NonNullable, Storage>>(array, schema, owner, 0)
+        }?;
+        // SAFETY: The entries struct has two children, validated by the common
+        // fields, and the caller upholds the Arrow C Data requirements for
+        // the values child as for the keys child.
+        let values = unsafe { Self::import_child::<V::Memory<Storage>>(array, schema, owner, 1) }?;
+
+        // Struct children are indexed by the parent offset, so both fields
+        // must cover the whole struct, and are cut to the same length.
+        let keys_length = keys.len();
+        let values_length = values.len();
+        if keys_length < length || values_length < length {
+            return Err(ImportError::MapEntriesLengthMismatch {
+                length,
+                keys_length,
+                values_length,
+            });
+        }
+        Ok(Struct::from_buffer((
+            keys.slice(0, length),
+            values.slice(0, length),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    extern crate std;
+
+    use alloc::{collections::BTreeMap, vec, vec::Vec};
+    use core::{borrow::Borrow, slice};
+    use std::collections::HashMap;
+
+    use narrow::{
+        array::Array,
+        buffer::{BufferRef, SliceBuffer},
+        collection::Collection,
+        layout::map::Entries,
+    };
+
+    use crate::{
+        ARROW_FLAG_MAP_KEYS_SORTED, ARROW_FLAG_NULLABLE, ForeignBuffer,
+        export::Export,
+        import::{Import, ImportError, ImportOwned},
+    };
+
+    #[test]
+    fn imports_map_without_copying() {
+        let source = [
+            BTreeMap::from([(1_u32, Some(true)), (2, None)]),
+            BTreeMap::new(),
+            BTreeMap::from([(3, Some(false))]),
+        ]
+        .into_iter()
+        .collect::<Array<BTreeMap<u32, Option<bool>>>>();
+        let offsets_data = source.buffer_ref().buffer_ref().buffer_ref().as_ptr();
+        let (array, schema) = source.export().expect("export array");
+        assert_eq!(schema.flags, ARROW_FLAG_MAP_KEYS_SORTED);
+
+        // SAFETY: The exported structures retain valid offsets and entries
+        // for the lifetime of the imported array.
+        let imported: Array<BTreeMap<u32, Option<bool>>, SliceBuffer<'_>> =
+            unsafe { Import::import(&array, &schema) }.expect("import array");
+
+        let offsets: &[i32] = imported.buffer_ref().buffer_ref().buffer_ref().borrow();
+        assert_eq!(offsets, [0, 2, 2, 3]);
+        assert_eq!(offsets.as_ptr(), offsets_data);
+        assert_eq!(
+            imported.owned(0),
+            Some(BTreeMap::from([(1, Some(true)), (2, None)]))
+        );
+        assert_eq!(imported.owned(1), Some(BTreeMap::new()));
+        assert_eq!(imported.owned(2), Some(BTreeMap::from([(3, Some(false))])));
+    }
+
+    #[test]
+    fn imports_sliced_nullable_unsorted_map() {
+        let source = [
+            Some(HashMap::from([(1_u8, 10_i64)])),
+            None,
+            Some(HashMap::from([(2, 20), (3, 30)])),
+        ]
+        .into_iter()
+        .collect::<Array<Option<HashMap<u8, i64>>>>();
+        let (array, schema) = source.slice(1, 2).export().expect("export array");
+        assert_eq!(schema.flags, ARROW_FLAG_NULLABLE);
+
+        // SAFETY: The exported structures describe a valid map array whose
+        // children are owned by the parent array.
+        let imported = unsafe {
+            Array::<Option<HashMap<u8, i64>>, ForeignBuffer>::import_owned(array, &schema)
+        }
+        .expect("import array");
+        drop(schema);
+
+        assert_eq!(
+            imported.into_iter_owned().collect::<Vec<_>>(),
+            [None, Some(HashMap::from([(2, 20), (3, 30)]))]
+        );
+    }
+
+    #[test]
+    fn imports_entries_regardless_of_sorted_keys_flag() {
+        let source = [Entries::new(vec![(2_u16, 1_u8), (1, 2)])]
+            .into_iter()
+            .collect::<Array<Entries<u16, u8>>>();
+        let (array, mut schema) = source.export().expect("export array");
+        schema.flags = ARROW_FLAG_MAP_KEYS_SORTED;
+
+        // SAFETY: The exported structures remain valid; only the sorted keys
+        // flag is changed.
+        let imported: Array<Entries<u16, u8>, SliceBuffer<'_>> =
+            unsafe { Import::import(&array, &schema) }.expect("import array");
+
+        assert_eq!(imported.owned(0), Some(Entries::new(vec![(2, 1), (1, 2)])));
+    }
+
+    #[test]
+    fn rejects_invalid_map_entries() {
+        let source = [BTreeMap::from([(1_u8, 2_u8)])]
+            .into_iter()
+            .collect::<Array<BTreeMap<u8, u8>>>();
+        let (array, schema) = source.export().expect("export array");
+        // SAFETY: The exported schema owns a one-entry child pointer array.
+        let entries = unsafe { slice::from_raw_parts(schema.children, 1) }[0];
+        // SAFETY: The entries schema is retained by the parent.
+        let entries_children = unsafe { (*entries).children };
+        // SAFETY: The entries schema owns a two-entry child pointer array.
+        let fields = unsafe { slice::from_raw_parts(entries_children, 2) };
+        let key = fields[0];
+
+        // SAFETY: The key schema is retained by the entries schema and not
+        // otherwise borrowed.
+        unsafe { (*key).flags = ARROW_FLAG_NULLABLE };
+        // SAFETY: The exported structures remain valid; only the key field
+        // flags are changed to exercise entries validation.
+        let nullable_keys = unsafe {
+            <Array<BTreeMap<u8, u8>, SliceBuffer<'_>> as Import>::import(&array, &schema)
+                .expect_err("nullable keys")
+        };
+        assert_eq!(
+            nullable_keys,
+            ImportError::UnexpectedFlags {
+                flags: ARROW_FLAG_NULLABLE
+            }
+        );
+
+        // SAFETY: `key` still points to the key schema retained by the entries
+        // schema, and the import that borrowed it has returned.
+        unsafe { (*key).flags = 0 };
+        // SAFETY: The entries schema is retained by the parent and not
+        // otherwise borrowed.
+        unsafe { (*entries).format = c"+l".as_ptr() };
+        // SAFETY: The exported structures remain valid; only the entries
+        // format is changed.
+        let list_entries = unsafe {
+            <Array<BTreeMap<u8, u8>, SliceBuffer<'_>> as Import>::import(&array, &schema)
+                .expect_err("entries are not a struct")
+        };
+        assert_eq!(list_entries, ImportError::UnexpectedFormat);
+    }
+
+    #[test]
+    fn rejects_short_map_entries_fields() {
+        let source = [BTreeMap::from([(1_u8, 2_u8), (3, 4)])]
+            .into_iter()
+            .collect::<Array<BTreeMap<u8, u8>>>();
+        let (array, schema) = source.export().expect("export array");
+        // SAFETY: The exported array owns a one-entry child pointer array.
+        let entries = unsafe { slice::from_raw_parts(array.children, 1) }[0];
+        // SAFETY: The entries array is retained by the parent.
+        let entries_children = unsafe { (*entries).children };
+        // SAFETY: The entries array owns a two-entry child pointer array.
+        let fields = unsafe { slice::from_raw_parts(entries_children, 2) };
+        // SAFETY: The value array is live and not otherwise borrowed.
+        unsafe { (*fields[1]).length = 1 };
+
+        // SAFETY: The exported structures remain valid; only the value
+        // length is shortened.
+        let error = unsafe {
+            <Array<BTreeMap<u8, u8>, SliceBuffer<'_>> as Import>::import(&array, &schema)
+                .expect_err("short values")
+        };
+        assert_eq!(
+            error,
+            ImportError::MapEntriesLengthMismatch {
+                length: 2,
+                keys_length: 2,
+                values_length: 1,
+            }
+        );
+    }
+}
@@ -1,19 +1,30 @@
-//! Borrow Arrow C Data Interface arrays as Narrow arrays.
+//! Import Arrow C Data Interface arrays as Narrow arrays.
 
+extern crate alloc;
+
+use alloc::rc::Rc;
 use core::{ffi::CStr, fmt, slice};
 
 use narrow::{
     array::Array,
-    bitmap::{Bitmap, ValidityBitmap},
-    buffer::SliceBuffer,
+    bitmap::Bitmap,
+    buffer::{Buffer, SliceBuffer},
     collection::Collection,
-    layout::ArrayItem,
+    fixed_size::FixedSize,
+    layout::{
+        ArrayItem, MemoryLayout, binary_view::BinaryViewsError, dictionary::DictionaryKeysError,
+        list_view::ListViewsError, run_end_encoded::RunEndsError, utf8::Utf8ValuesError,
+        utf8_view::Utf8ViewsError,
+    },
     nullability::{NonNullable, Nullability, Nullable},
     offset::OffsetsError,
     validity::Validity,
 };
 
-use crate::{ARROW_FLAG_NULLABLE, ArrowArray, ArrowSchema};
+use crate::{
+    ARROW_FLAG_NULLABLE, ArrowArray, ArrowSchema, ForeignBuffer, buffer::ForeignSlice,
+    window_null_count,
+};
 
 /// Borrowed import support for an Arrow C Data array.
 pub trait Import<'array>: Sized {
@@ -33,6 +44,95 @@ pub trait Import<'array>: Sized {
     unsafe fn import(array: &'array ArrowArray, schema: &ArrowSchema) -> Result<Self, ImportError>;
 }
 
+/// Owned import support for an Arrow C Data array.
+///
+/// Borrowing with [`Import`] leaves the caller responsible for keeping the
+/// [`ArrowArray`] alive and releasing it. An owned import instead moves the
+/// array into [`ForeignBuffer`] storage, which releases it when the imported
+/// buffers are dropped.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{array::Array, collection::Collection};
+/// use narrow_ffi::{Export, ForeignBuffer, ImportOwned};
+///
+/// let source = [Some(1), None].into_iter().collect::<Array<Option<i32>>>();
+/// let (array, schema) = source.export().unwrap();
+/// // SAFETY: The exported structures describe a valid nullable `i32` array.
+/// let values = unsafe { Array::<Option<i32>, ForeignBuffer>::import_owned(array, &schema) };
+/// assert_eq!(values.unwrap().into_iter_owned().collect::<Vec<_>>(), [Some(1), None]);
+/// ```
+pub trait ImportOwned: Sized {
+    /// Imports an [`ArrowArray`] and [`ArrowSchema`] without copying
+    /// buffers, taking over the release of `array`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ImportError`] when the structures do not describe the
+    /// expected Narrow array representation. The array is released before
+    /// the error is returned.
+    ///
+    /// # Safety
+    ///
+    /// Every non-null pointer read by the importer must be valid for the
+    /// required reads. Referenced buffers must remain immutable until `array`
+    /// is released and contain enough properly aligned elements for the
+    /// declared array length. Scalar metadata is validated by the importer.
+    unsafe fn import_owned(array: ArrowArray, schema: &ArrowSchema) -> Result<Self, ImportError>;
+}
+
+/// Storage for the buffers of an imported Arrow C Data array.
+trait ImportBuffer<'array>: Buffer {
+    /// Handle that keeps the imported buffers alive.
+    type Owner;
+
+    /// Returns the buffer of `len` values at `pointer`.
+    ///
+    /// # Safety
+    ///
+    /// When `len` is non-zero, `pointer` must be non-null, aligned and valid
+    /// for `len` reads of values that remain immutable for `'array` and
+    /// while `owner` is retained.
+    unsafe fn import_buffer<T: FixedSize>(
+        owner: &Self::Owner,
+        pointer: *const T,
+        len: usize,
+    ) -> Self::For<T>;
+}
+
+impl<'array> ImportBuffer<'array> for SliceBuffer<'array> {
+    type Owner = ();
+
+    unsafe fn import_buffer<T: FixedSize>(
+        _owner: &Self::Owner,
+        pointer: *const T,
+        len: usize,
+    ) -> Self::For<T> {
+        if len == 0 {
+            &[]
+        } else {
+            // SAFETY: The caller guarantees `len` aligned values that remain
+            // immutable for `'array`.
+            unsafe { slice::from_raw_parts(pointer, len) }
+        }
+    }
+}
+
+impl ImportBuffer<'_> for ForeignBuffer {
+    type Owner = Rc<ArrowArray>;
+
+    unsafe fn import_buffer<T: FixedSize>(
+        owner: &Self::Owner,
+        pointer: *const T,
+        len: usize,
+    ) -> Self::For<T> {
+        // SAFETY: The caller guarantees `len` aligned values that remain
+        // immutable while `owner` is retained.
+        unsafe { ForeignSlice::new(owner, pointer, len) }
+    }
+}
+
 /// Arrow import behavior for a [`Nullability`] type constructor.
 trait ImportNullability<'array>: Nullability {
     /// Arrow schema nullable flag for this nullability.
@@ -40,27 +140,34 @@ trait ImportNullability<'array>: Nullability {
 
     /// Wraps an imported collection with this nullability.
     ///
+    /// The collection contains the items before the array offset as well, so
+    /// its validity bitmap is imported from the start of the buffer.
+    ///
     /// # Safety
     ///
     /// Common fields must be validated, and the caller must uphold the
     /// requirements of [`Import::import`] for the validity buffer.
-    unsafe fn wrap<T>(
+    unsafe fn wrap<T, Storage>(
         array: &'array ArrowArray,
+        owner: &Storage::Owner,
         collection: T,
-    ) -> Result<Self::Collection<T, SliceBuffer<'array>>, ImportError>
+    ) -> Result<Self::Collection<T, Storage>, ImportError>
     where
-        T: Collection;
+        T: Collection,
+        Storage: ImportBuffer<'array>;
 }
 
 impl<'array> ImportNullability<'array> for NonNullable {
     const FLAGS: i64 = 0;
 
-    unsafe fn wrap<T>(
+    unsafe fn wrap<T, Storage>(
         _array: &'array ArrowArray,
+        _owner: &Storage::Owner,
         collection: T,
-    ) -> Result<Self::Collection<T, SliceBuffer<'array>>, ImportError>
+    ) -> Result<Self::Collection<T, Storage>, ImportError>
     where
         T: Collection,
+        Storage: ImportBuffer<'array>,
     {
         Ok(collection)
     }
@@ -69,12 +176,14 @@ impl<'array> ImportNullability<'array> for NonNullable {
 impl<'array> ImportNullability<'array> for Nullable {
     const FLAGS: i64 = ARROW_FLAG_NULLABLE;
 
-    unsafe fn wrap<T>(
+    unsafe fn wrap<T, Storage>(
         array: &'array ArrowArray,
+        owner: &Storage::Owner,
         collection: T,
-    ) -> Result<Self::Collection<T, SliceBuffer<'array>>, ImportError>
+    ) -> Result<Self::Collection<T, Storage>, ImportError>
     where
         T: Collection,
+        Storage: ImportBuffer<'array>,
     {
         // Common validation guarantees a non-null buffer pointer array.
         let buffers = usize::try_from(array.n_buffers).expect("buffer count must fit in usize");
@@ -95,14 +204,18 @@ impl<'array> ImportNullability<'array> for Nullable {
             let byte_length = length.div_ceil(8);
             // SAFETY: The caller guarantees the validity buffer contains
             // `byte_length` bytes that remain immutable for `'array`.
-            let values = unsafe { slice::from_raw_parts(validity_pointer, byte_length) };
+            let values = unsafe { Storage::import_buffer(owner, validity_pointer, byte_length) };
             let bitmap = Bitmap::try_from_parts(values, length, 0)
                 .expect("imported validity buffer contains the declared number of bits");
             Validity::try_from_parts(collection, bitmap).expect("validity lengths match")
         };
 
         if array.null_count >= 0 {
-            let actual = validity.null_count();
+            // Common validation guarantees a non-negative offset and length
+            // that end within the imported collection.
+            let offset = usize::try_from(array.offset).expect("offset must fit in usize");
+            let window = usize::try_from(array.length).expect("length must fit in usize");
+            let actual = window_null_count(&validity, offset, window);
             if usize::try_from(array.null_count) != Ok(actual) {
                 return Err(ImportError::NullCountMismatch {
                     declared: array.null_count,
@@ -116,25 +229,37 @@ impl<'array> ImportNullability<'array> for Nullable {
 }
 
 /// A memory layout that can borrow an Arrow C Data array.
-trait ImportLayout<'array>: Sized {
+trait ImportLayout<'array>: MemoryLayout + Sized {
+    /// Storage of the imported buffers.
+    type Storage: ImportBuffer<'array>;
+
     /// Expected Arrow schema nullable flag.
     const FLAGS: i64 = 0;
     /// Expected number of Arrow array buffers.
     const BUFFERS: i64;
+    /// Whether the Arrow array has a variable number of buffers in addition
+    /// to the expected ones.
+    const VARIADIC_BUFFERS: bool = false;
     /// Expected number of Arrow array and schema children.
     const CHILDREN: i64;
+    /// Whether the Arrow array and schema carry a dictionary.
+    const DICTIONARY: bool = false;
 
     /// Returns whether an Arrow format matches this memory layout.
     fn matches_format(format: &CStr) -> bool;
 
     /// Constructs the memory layout after its common fields are validated.
     ///
+    /// `length` is the number of items in the buffers, which includes the
+    /// items before the array offset.
+    ///
     /// # Safety
     ///
     /// The caller must uphold the requirements of [`Import::import`].
     unsafe fn import_validated(
         array: &'array ArrowArray,
         schema: &ArrowSchema,
+        owner: &Owner<'array, Self>,
         length: usize,
     ) -> Result<Self, ImportError>;
 
@@ -147,6 +272,7 @@ trait ImportLayout<'array>: Sized {
     unsafe fn import_layout(
         array: &'array ArrowArray,
         schema: &ArrowSchema,
+        owner: &Owner<'array, Self>,
     ) -> Result<Self, ImportError> {
         if array.is_released() {
             return Err(ImportError::ReleasedArray);
@@ -170,11 +296,15 @@ trait ImportLayout<'array>: Sized {
         let length = usize::try_from(array.length).map_err(|_| ImportError::InvalidLength {
             length: array.length,
         })?;
-        if array.offset != 0 {
-            return Err(ImportError::NonZeroOffset {
+        // Arrow applies the offset to every buffer, so the buffers contain
+        // `offset + length` items. They are imported completely and then
+        // sliced to the array window.
+        let offset = usize::try_from(array.offset)
+            .ok()
+            .filter(|offset| offset.checked_add(length).is_some())
+            .ok_or(ImportError::InvalidOffset {
                 offset: array.offset,
-            });
-        }
+            })?;
         // Arrow permits -1 when the null count has not been computed. Known
         // counts must fit the array, and non-nullable layouts cannot contain
         // known null items.
@@ -186,7 +316,12 @@ trait ImportLayout<'array>: Sized {
                 null_count: array.null_count,
             });
         }
-        if array.n_buffers != Self::BUFFERS {
+        let buffer_count_matches = if Self::VARIADIC_BUFFERS {
+            array.n_buffers >= Self::BUFFERS
+        } else {
+            array.n_buffers == Self::BUFFERS
+        };
+        if !buffer_count_matches {
             return Err(ImportError::UnexpectedBufferCount {
                 count: array.n_buffers,
             });
@@ -197,9 +332,12 @@ trait ImportLayout<'array>: Sized {
                 schema: schema.n_children,
             });
         }
-        if !array.dictionary.is_null() || !schema.dictionary.is_null() {
+        if !Self::DICTIONARY && (!array.dictionary.is_null() || !schema.dictionary.is_null()) {
             return Err(ImportError::UnexpectedDictionary);
         }
+        if Self::DICTIONARY && (array.dictionary.is_null() || schema.dictionary.is_null()) {
+            return Err(ImportError::MissingDictionary);
+        }
         if Self::BUFFERS != 0 && array.buffers.is_null() {
             return Err(ImportError::MissingBufferPointers);
         }
@@ -212,7 +350,9 @@ trait ImportLayout<'array>: Sized {
 
         // SAFETY: Common fields have been validated and the caller upholds the
         // Arrow C Data pointer and buffer requirements.
-        unsafe { Self::import_validated(array, schema, length) }
+        let layout =
+            unsafe { Self::import_validated(array, schema, owner, offset.strict_add(length)) }?;
+        Ok(layout.slice(offset, length))
     }
 
     /// Imports a child memory layout at `index`.
@@ -224,10 +364,11 @@ trait ImportLayout<'array>: Sized {
     unsafe fn import_child<Child>(
         array: &'array ArrowArray,
         schema: &ArrowSchema,
+        owner: &Owner<'array, Self>,
         index: usize,
     ) -> Result<Child, ImportError>
     where
-        Child: ImportLayout<'array>,
+        Child: ImportLayout<'array, Storage = Self::Storage>,
     {
         let children = usize::try_from(Self::CHILDREN).expect("child count must fit in usize");
         assert!(index < children, "child index is out of bounds");
@@ -256,19 +397,71 @@ trait ImportLayout<'array>: Sized {
 
         // SAFETY: The child structures are covered by the caller's Arrow C
         // Data guarantees and retained by their respective parents.
-        unsafe { Child::import_layout(child_array, child_schema) }
+        unsafe { Child::import_layout(child_array, child_schema, owner) }
+    }
+
+    /// Imports the dictionary values memory layout.
+    ///
+    /// # Safety
+    ///
+    /// Common parent fields must be validated, and the caller must uphold the
+    /// requirements of [`Import::import`] for the dictionary structures.
+    unsafe fn import_dictionary<Values>(
+        array: &'array ArrowArray,
+        schema: &ArrowSchema,
+        owner: &Owner<'array, Self>,
+    ) -> Result<Values, ImportError>
+    where
+        Values: ImportLayout<'array, Storage = Self::Storage>,
+    {
+        assert!(Self::DICTIONARY, "layout has no dictionary");
+
+        // SAFETY: Common validation guarantees non-null dictionary pointers,
+        // and the caller guarantees that the dictionary array is retained by
+        // the parent for `'array`.
+        let dictionary_array: &'array ArrowArray = unsafe { &*array.dictionary };
+        // SAFETY: The caller guarantees that the dictionary schema is valid
+        // while the parent schema is borrowed.
+        let dictionary_schema = unsafe { &*schema.dictionary };
+
+        // SAFETY: The dictionary structures are covered by the caller's Arrow
+        // C Data guarantees and retained by their respective parents.
+        unsafe { Values::import_layout(dictionary_array, dictionary_schema, owner) }
     }
 }
 
+/// Handle that keeps the buffers of an imported `Layout` alive.
+type Owner<'array, Layout> =
+    <<Layout as ImportLayout<'array>>::Storage as ImportBuffer<'array>>::Owner;
+
 impl<'array, T> Import<'array> for Array<T, SliceBuffer<'array>>
 where
     T: ArrayItem,
-    T::Memory<SliceBuffer<'array>>: ImportLayout<'array>,
+    T::Memory<SliceBuffer<'array>>: ImportLayout<'array, Storage = SliceBuffer<'array>>,
 {
     unsafe fn import(array: &'array ArrowArray, schema: &ArrowSchema) -> Result<Self, ImportError> {
         // SAFETY: The caller upholds the requirements of `Import::import`.
         let memory = unsafe {
-            <T::Memory<SliceBuffer<'array>> as ImportLayout>::import_layout(array, schema)
+            <T::Memory<SliceBuffer<'array>> as ImportLayout>::import_layout(array, schema, &())
+        }?;
+        Ok(Self::from_buffer(memory))
+    }
+}
+
+impl<T> ImportOwned for Array<T, ForeignBuffer>
+where
+    T: ArrayItem,
+    T::Memory<ForeignBuffer>: for<'array> ImportLayout<'array, Storage = ForeignBuffer>,
+{
+    unsafe fn import_owned(array: ArrowArray, schema: &ArrowSchema) -> Result<Self, ImportError> {
+        // Every imported buffer retains the array, so the producer's release
+        // callback runs once the last of them is dropped.
+        let owner = Rc::new(array);
+        // SAFETY: The caller upholds the requirements of
+        // `ImportOwned::import_owned`, and `owner` keeps the array and the
+        // children it owns alive for the imported buffers.
+        let memory = unsafe {
+            <T::Memory<ForeignBuffer> as ImportLayout>::import_layout(&owner, schema, &owner)
         }?;
         Ok(Self::from_buffer(memory))
     }
@@ -296,9 +489,10 @@ pub enum ImportError {
         /// Invalid array length supplied by the producer.
         length: i64,
     },
-    /// The Arrow array has an unsupported offset.
-    NonZeroOffset {
-        /// Unsupported offset supplied by the producer.
+    /// The Arrow array offset is negative, or the end of the array does not
+    /// fit in [`usize`].
+    InvalidOffset {
+        /// Invalid offset supplied by the producer.
         offset: i64,
     },
     /// The Arrow array null count does not match its length or imported layout.
@@ -329,20 +523,37 @@ pub enum ImportError {
     },
     /// The Arrow array or schema contains an unexpected dictionary.
     UnexpectedDictionary,
+    /// The Arrow array or schema of a dictionary-encoded array does not
+    /// contain its dictionary.
+    MissingDictionary,
+    /// A dictionary key does not address one of the dictionary values.
+    InvalidDictionaryKeys {
+        /// Dictionary key invariant that was violated.
+        error: DictionaryKeysError,
+    },
     /// The Arrow array does not contain its buffer pointer array.
     MissingBufferPointers,
     /// The Arrow array does not contain its child pointer array.
     MissingArrayChildren,
     /// The Arrow schema does not contain its child pointer array.
     MissingSchemaChildren,
+    /// The Arrow schema name is not valid UTF-8.
+    InvalidFieldName,
+    /// The Arrow schema metadata has a negative length, or contains keys or
+    /// values that are not valid UTF-8.
+    InvalidMetadata,
+    /// The `ARROW:extension:name` metadata of the Arrow schema is missing or
+    /// names another extension type.
+    UnexpectedExtensionName,
     /// The fixed-size-list width is not supported.
     UnsupportedFixedSizeListSize {
         /// Unsupported fixed-size-list width.
         size: usize,
     },
-    /// A fixed-size-list child length does not match its parent.
+    /// A fixed-size-list child is shorter than its parent requires.
     FixedSizeListLengthMismatch {
-        /// Number of items in the parent array.
+        /// Number of items in the parent array, including the items before
+        /// its offset.
         length: usize,
         /// Number of items in the child array.
         child_length: usize,
@@ -361,6 +572,28 @@ pub enum ImportError {
         /// Offset invariant that was violated.
         error: OffsetsError,
     },
+    /// A non-empty list view array does not contain a sizes buffer.
+    MissingSizesBuffer,
+    /// The sizes buffer is not aligned for its element type.
+    MisalignedSizesBuffer {
+        /// Required byte alignment of the size type.
+        alignment: usize,
+    },
+    /// The offsets and sizes of a list view array do not satisfy the Arrow
+    /// list view invariants.
+    InvalidListViews {
+        /// List view invariant that was violated.
+        error: ListViewsError,
+    },
+    /// A key or value child of a map is shorter than its entries struct.
+    MapEntriesLengthMismatch {
+        /// Number of entries, including the entries before the struct offset.
+        length: usize,
+        /// Number of items in the key child.
+        keys_length: usize,
+        /// Number of items in the value child.
+        values_length: usize,
+    },
     /// A non-empty array does not contain a value buffer.
     MissingValuesBuffer,
     /// The primitive value buffer is not aligned for its element type.
@@ -368,9 +601,53 @@ pub enum ImportError {
         /// Required byte alignment of the requested element type.
         alignment: usize,
     },
+    /// The run ends child of a run-end encoded array has a non-zero offset.
+    UnsupportedRunEndsOffset {
+        /// Offset of the run ends child.
+        offset: usize,
+    },
+    /// The run ends and values children of a run-end encoded array do not
+    /// satisfy the run-end encoding invariants.
+    InvalidRunEnds {
+        /// Run-end invariant that was violated.
+        error: RunEndsError,
+    },
+    /// The runs of a run-end encoded array end before the end of the array.
+    RunEndsLengthMismatch {
+        /// Number of items in the array, including the items before its
+        /// offset.
+        length: usize,
+        /// Number of items covered by the runs.
+        runs_length: usize,
+    },
+    /// The variadic buffer sizes of a view array are missing.
+    MissingVariadicBufferSizes,
+    /// The variadic buffer sizes buffer is not aligned for `i64`.
+    MisalignedVariadicBufferSizes,
+    /// A variadic buffer size is negative or does not fit in [`usize`].
+    InvalidVariadicBufferSize {
+        /// Invalid buffer size supplied by the producer.
+        size: i64,
+    },
+    /// The views of a binary view array reference invalid data.
+    InvalidBinaryViews {
+        /// View invariant that was violated.
+        error: BinaryViewsError,
+    },
+    /// The offsets or data of a UTF-8 array are invalid.
+    InvalidUtf8 {
+        /// Offset or UTF-8 invariant that was violated.
+        error: Utf8ValuesError,
+    },
+    /// The views of a UTF-8 view array reference invalid data or strings.
+    InvalidUtf8Views {
+        /// View or UTF-8 invariant that was violated.
+        error: Utf8ViewsError,
+    },
 }
 
 impl fmt::Display for ImportError {
+    #[expect(clippy::too_many_lines, reason = "one message per variant")]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             Self::ReleasedArray => write!(f, "Arrow array is already released"),
@@ -386,8 +663,8 @@ impl fmt::Display for ImportError {
             Self::InvalidLength { length } => {
                 write!(f, "Arrow array length ({length}) is invalid")
             }
-            Self::NonZeroOffset { offset } => {
-                write!(f, "Arrow array offset ({offset}) is not supported")
+            Self::InvalidOffset { offset } => {
+                write!(f, "Arrow array offset ({offset}) is invalid")
             }
             Self::UnexpectedNullCount { null_count } => {
                 write!(
@@ -412,9 +689,18 @@ impl fmt::Display for ImportError {
             Self::UnexpectedDictionary => {
                 write!(f, "Arrow dictionary is not supported for this array")
             }
+            Self::MissingDictionary => write!(f, "Arrow dictionary is missing"),
+            Self::InvalidDictionaryKeys { error } => {
+                write!(f, "invalid Arrow dictionary keys: {error}")
+            }
             Self::MissingBufferPointers => write!(f, "Arrow buffer pointers are missing"),
             Self::MissingArrayChildren => write!(f, "Arrow array child pointers are missing"),
             Self::MissingSchemaChildren => write!(f, "Arrow schema child pointers are missing"),
+            Self::InvalidFieldName => write!(f, "Arrow schema name is not valid UTF-8"),
+            Self::InvalidMetadata => write!(f, "Arrow schema metadata is invalid"),
+            Self::UnexpectedExtensionName => {
+                write!(f, "Arrow extension name does not match")
+            }
             Self::UnsupportedFixedSizeListSize { size } => {
                 write!(f, "fixed-size-list width ({size}) is not supported")
             }
@@ -424,7 +710,7 @@ impl fmt::Display for ImportError {
                 size,
             } => write!(
                 f,
-                "fixed-size-list length ({length}) with width ({size}) does not match child length ({child_length})"
+                "fixed-size-list length ({length}) with width ({size}) exceeds child length ({child_length})"
             ),
             Self::MissingOffsetsBuffer => write!(f, "Arrow offsets buffer is missing"),
             Self::MisalignedOffsetsBuffer { alignment } => write!(
@@ -432,11 +718,49 @@ impl fmt::Display for ImportError {
                 "Arrow offsets buffer does not have the required alignment ({alignment})"
             ),
             Self::InvalidOffsets { error } => write!(f, "invalid Arrow offsets: {error}"),
+            Self::MissingSizesBuffer => write!(f, "Arrow sizes buffer is missing"),
+            Self::MisalignedSizesBuffer { alignment } => write!(
+                f,
+                "Arrow sizes buffer does not have the required alignment ({alignment})"
+            ),
+            Self::InvalidListViews { error } => write!(f, "invalid Arrow list views: {error}"),
+            Self::MapEntriesLengthMismatch {
+                length,
+                keys_length,
+                values_length,
+            } => write!(
+                f,
+                "map entries length ({length}) exceeds the length of its keys ({keys_length}) or values ({values_length})"
+            ),
             Self::MissingValuesBuffer => write!(f, "Arrow value buffer is missing"),
             Self::MisalignedValuesBuffer { alignment } => write!(
                 f,
                 "Arrow value buffer does not have the required alignment ({alignment})"
             ),
+            Self::UnsupportedRunEndsOffset { offset } => {
+                write!(f, "Arrow run ends offset ({offset}) is not supported")
+            }
+            Self::InvalidRunEnds { error } => write!(f, "invalid Arrow run ends: {error}"),
+            Self::RunEndsLengthMismatch {
+                length,
+                runs_length,
+            } => write!(
+                f,
+                "run-end encoded length ({length}) exceeds the length of its runs ({runs_length})"
+            ),
+            Self::MissingVariadicBufferSizes => {
+                write!(f, "Arrow variadic buffer sizes are missing")
+            }
+            Self::MisalignedVariadicBufferSizes => write!(
+                f,
+                "Arrow variadic buffer sizes do not have the required alignment"
+            ),
+            Self::InvalidVariadicBufferSize { size } => {
+                write!(f, "Arrow variadic buffer size ({size}) is invalid")
+            }
+            Self::InvalidBinaryViews { error } => write!(f, "invalid Arrow binary views: {error}"),
+            Self::InvalidUtf8 { error } => write!(f, "invalid Arrow utf-8 array: {error}"),
+            Self::InvalidUtf8Views { error } => write!(f, "invalid Arrow utf-8 views: {error}"),
         }
     }
 }
@@ -445,16 +769,48 @@ impl core::error::Error for ImportError {
     fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         match *self {
             Self::InvalidOffsets { ref error } => Some(error),
+            Self::InvalidListViews { ref error } => Some(error),
+            Self::InvalidDictionaryKeys { ref error } => Some(error),
+            Self::InvalidRunEnds { ref error } => Some(error),
+            Self::InvalidBinaryViews { ref error } => Some(error),
+            Self::InvalidUtf8 { ref error } => Some(error),
+            Self::InvalidUtf8Views { ref error } => Some(error),
             _ => None,
         }
     }
 }
 
-/// Borrowed import support for Boolean arrays.
+/// Import support for binary arrays.
+mod binary;
+/// Import support for binary view and UTF-8 view arrays.
+mod binary_view;
+/// Import support for Boolean arrays.
 mod boolean;
-/// Borrowed import support for fixed-size-list arrays.
+/// Import support for dictionary-encoded arrays.
+mod dictionary;
+/// Import support for extension type arrays.
+mod extension;
+/// Import support for [`Field`](crate::Field) and [`Schema`](crate::Schema)
+/// descriptions.
+#[cfg(feature = "std")]
+mod field;
+/// Import support for fixed-size-list arrays.
 mod fixed_size_list;
-/// Borrowed import support for fixed-size primitive arrays.
+/// Import support for fixed-size primitive arrays.
 mod fixed_size_primitive;
-/// Borrowed import support for variable-size-list arrays.
+/// Import support for list view arrays.
+mod list_view;
+/// Import support for map arrays.
+mod map;
+/// Import support for null arrays.
+mod null;
+/// Import support for run-end encoded arrays.
+mod run_end_encoded;
+/// Import support for UTF-8 arrays.
+mod utf8;
+/// Import support for variable-size-list arrays.
 mod variable_size_list;
+
+/// Import support for the Arrow C Stream Interface.
+mod stream;
+pub use stream::{ArrayStreamReader, StreamError};
@@ -0,0 +1,126 @@
+//! Import support for [`NullLayout`].
+
+use core::ffi::CStr;
+
+use narrow::{collection::Collection, layout::null::NullLayout};
+
+use crate::{ArrowArray, ArrowSchema};
+
+use super::{ImportBuffer, ImportError, ImportLayout, ImportNullability, Owner};
+
+impl<'array, Nulls, Storage> ImportLayout<'array> for NullLayout<Nulls, Storage>
+where
+    Nulls: ImportNullability<'array>,
+    Storage: ImportBuffer<'array>,
+    Self: Collection,
+{
+    type Storage = Storage;
+
+    const FLAGS: i64 = Nulls::FLAGS;
+    const BUFFERS: i64 = 0;
+    const CHILDREN: i64 = 0;
+
+    fn matches_format(format: &CStr) -> bool {
+        format == c"n"
+    }
+
+    unsafe fn import_validated(
+        array: &'array ArrowArray,
+        _schema: &ArrowSchema,
+        _owner: &Owner<'array, Self>,
+        length: usize,
+    ) -> Result<Self, ImportError> {
+        // Every item of a null array is null, but producers that derive the
+        // null count from the absent validity buffer report zero.
+        if Nulls::NULLABLE
+            && !matches!(array.null_count, -1 | 0)
+            && array.null_count != array.length
+        {
+            return Err(ImportError::NullCountMismatch {
+                declared: array.null_count,
+                actual: usize::try_from(array.length).expect("length must fit in usize"),
+            });
+        }
+        Ok(Self::new(length))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use narrow::{array::Array, buffer::SliceBuffer, collection::Collection, length::Length};
+
+    use crate::{
+        ARROW_FLAG_NULLABLE, ForeignBuffer,
+        export::Export,
+        import::{Import, ImportError, ImportOwned},
+    };
+
+    #[test]
+    fn imports_null_arrays() {
+        let units = [(), ()].into_iter().collect::<Array<()>>();
+        let (array, schema) = units.export().expect("export array");
+        // SAFETY: The exported structures are valid and have no buffers.
+        let imported: Array<(), SliceBuffer<'_>> =
+            unsafe { Import::import(&array, &schema) }.expect("import array");
+        assert_eq!(imported.len(), 2);
+
+        let nulls = [None, None, None]
+            .into_iter()
+            .collect::<Array<Option<()>>>();
+        let (nulls_array, nulls_schema) = nulls.slice(1, 2).export().expect("export array");
+        // SAFETY: The exported structures are valid and have no buffers.
+        let owned =
+            unsafe { Array::<Option<()>, ForeignBuffer>::import_owned(nulls_array, &nulls_schema) }
+                .expect("import array");
+        assert_eq!(owned.len(), 2);
+        assert_eq!(owned.owned(1), Some(None));
+    }
+
+    #[test]
+    fn accepts_zero_null_count() {
+        let nulls = [None, None].into_iter().collect::<Array<Option<()>>>();
+        let (mut array, schema) = nulls.export().expect("export array");
+        array.null_count = 0;
+        // SAFETY: The exported structures are valid and have no buffers.
+        let imported: Array<Option<()>, SliceBuffer<'_>> =
+            unsafe { Import::import(&array, &schema) }.expect("import array");
+        assert_eq!(imported.len(), 2);
+    }
+
+    #[test]
+    fn rejects_partial_null_count() {
+        let nulls = [None, None].into_iter().collect::<Array<Option<()>>>();
+        let (mut array, schema) = nulls.export().expect("export array");
+        array.null_count = 1;
+        // SAFETY: The exported structures are valid and have no buffers.
+        let error = unsafe {
+            <Array<Option<()>, SliceBuffer<'_>> as Import>::import(&array, &schema)
+                .expect_err("partial null count")
+        };
+        assert_eq!(
+            error,
+            ImportError::NullCountMismatch {
+                declared: 1,
+                actual: 2
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_mismatched_nullability() {
+        let units = [()].into_iter().collect::<Array<()>>();
+        let (array, mut schema) = units.export().expect("export array");
+        schema.flags = ARROW_FLAG_NULLABLE;
+        // SAFETY: The exported structures are valid and have no buffers.
+        let error = unsafe {
+            <Array<(), SliceBuffer<'_>> as Import>::import(&array, &schema)
+                .expect_err("nullable schema")
+        };
+        assert_eq!(
+            error,
+            ImportError::UnexpectedFlags {
+                flags: ARROW_FLAG_NULLABLE
+            }
+        );
+    }
+}
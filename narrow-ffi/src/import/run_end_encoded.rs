@@ -0,0 +1,203 @@
+//! Import support for [`RunEndEncoded`].
+
+use core::ffi::CStr;
+
+use narrow::{
+    layout::{
+        ArrayItem, MemoryLayout,
+        fixed_size_primitive::FixedSizePrimitive,
+        run_end_encoded::{RunEnd, RunEndEncoded, RunEnds},
+    },
+    length::Length,
+    nullability::NonNullable,
+};
+
+use crate::{ArrowArray, ArrowSchema, ArrowType};
+
+use super::{ImportBuffer, ImportError, ImportLayout, Owner};
+
+impl<'array, R, V, Storage> ImportLayout<'array> for RunEndEncoded<R, V, Storage>
+where
+    R: RunEnd + ArrowType,
+    V: ArrayItem,
+    V::Memory<Storage>: ImportLayout<'array, Storage = Storage>,
+    FixedSizePrimitive<R, NonNullable, Storage>: ImportLayout<'array, Storage = Storage>,
+    Storage: ImportBuffer<'array>,
+{
+    type Storage = Storage;
+
+    const BUFFERS: i64 = 0;
+    const CHILDREN: i64 = 2;
+
+    fn matches_format(format: &CStr) -> bool {
+        format == c"+r"
+    }
+
+    unsafe fn import_validated(
+        array: &'array ArrowArray,
+        schema: &ArrowSchema,
+        owner: &Owner<'array, Self>,
+        length: usize,
+    ) -> Result<Self, ImportError> {
+        // SAFETY: Common parent fields are validated and the caller upholds
+        // the Arrow C Data requirements for the retained child structures.
+        let run_ends = unsafe {
+            Self::import_child::<FixedSizePrimitive<R, NonNullable, Storage>>(
+                array, schema, owner, 0,
+            )
+        }?;
+        // The run ends are positions of logical items, which the parent
+        // offset already windows.
+        let run_ends_offset = run_ends.offset();
+        if run_ends_offset != 0 {
+            return Err(ImportError::UnsupportedRunEndsOffset {
+                offset: run_ends_offset,
+            });
+        }
+        // SAFETY: The parent has two children, validated by the common fields, and
+        // the caller upholds the Arrow C Data requirements for the values
+        // child as for the run ends child.
+        let values = unsafe { Self::import_child::<V::Memory<Storage>>(array, schema, owner, 1) }?;
+
+        let runs = RunEnds::try_from_parts(run_ends.into_buffer(), values)
+            .map_err(|error| ImportError::InvalidRunEnds { error })?;
+        let runs_length = runs.len();
+        if runs_length < length {
+            return Err(ImportError::RunEndsLengthMismatch {
+                length,
+                runs_length,
+            });
+        }
+        Ok(Self::from_buffer(runs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::{sync::Arc, vec::Vec};
+    use core::{borrow::Borrow, slice};
+
+    use narrow::{
+        array::Array,
+        buffer::{ArcBuffer, BufferRef, SliceBuffer},
+        collection::{ChildRef, Collection},
+        layout::{
+            fixed_size_primitive::FixedSizePrimitive,
+            run_end_encoded::{Run, RunEndEncoded, RunEnds, RunEndsError},
+        },
+    };
+
+    use crate::{
+        ForeignBuffer,
+        export::Export,
+        import::{Import, ImportError, ImportOwned},
+    };
+
+    #[test]
+    fn imports_run_end_encoded_without_copying() {
+        let run_end_storage = Arc::<[i64]>::from([1, 3]);
+        let value_storage = Arc::<[u16]>::from([7, 8]);
+        let run_ends_data = run_end_storage.as_ptr();
+        let values_data = value_storage.as_ptr();
+        let runs = RunEnds::try_from_parts(
+            run_end_storage,
+            FixedSizePrimitive::from_buffer(value_storage),
+        )
+        .expect("valid run ends");
+        let source: Array<Run<i64, u16>, ArcBuffer> =
+            Array::from_buffer(RunEndEncoded::from_buffer(runs));
+        let (array, schema) = source.export().expect("export array");
+
+        // SAFETY: The exported structures remain live and retain their run
+        // ends and values for the lifetime of the imported array.
+        let imported: Array<Run<i64, u16>, SliceBuffer<'_>> =
+            unsafe { Import::import(&array, &schema) }.expect("import array");
+
+        let imported_runs = imported.buffer_ref().buffer_ref();
+        let run_ends: &[i64] = imported_runs.buffer_ref();
+        let values: &[u16] = imported_runs.child_ref().buffer_ref().borrow();
+        assert_eq!(run_ends.as_ptr(), run_ends_data);
+        assert_eq!(values.as_ptr(), values_data);
+        assert_eq!(
+            imported.iter_views().map(|view| *view).collect::<Vec<_>>(),
+            [7, 8, 8]
+        );
+    }
+
+    #[test]
+    fn imports_sliced_nullable_values() {
+        let items = [Some(1_i32), Some(1), None, None, Some(2)].map(Run::new);
+        let source = items.into_iter().collect::<Array<Run<i16, Option<i32>>>>();
+        let (array, schema) = source.slice(1, 3).export().expect("export array");
+
+        // SAFETY: The exported array is moved into the imported buffers,
+        // which release it when they are dropped.
+        let imported =
+            unsafe { Array::<Run<i16, Option<i32>>, ForeignBuffer>::import_owned(array, &schema) }
+                .expect("import array");
+
+        assert_eq!(imported.into_iter_owned().collect::<Vec<_>>(), items[1..4]);
+    }
+
+    #[test]
+    fn rejects_invalid_run_ends() {
+        let source = [1_u8, 2]
+            .into_iter()
+            .map(Run::new)
+            .collect::<Array<Run<i32, u8>>>();
+        let (array, schema) = source.export().expect("export array");
+        // SAFETY: The exported array owns a two-entry child pointer array.
+        let children = unsafe { slice::from_raw_parts(array.children, 2) };
+        // SAFETY: The run ends child is retained by the parent.
+        let run_ends = unsafe { &*children[0] };
+        let descending = [2_i32, 1];
+        let values_pointer = run_ends.buffers.wrapping_add(1);
+        // SAFETY: The run ends child owns a two-entry buffer pointer array,
+        // which is not shared with the imported array.
+        let original = unsafe { values_pointer.replace(descending.as_ptr().cast()) };
+
+        // SAFETY: The replacement run ends outlive the import.
+        let error = unsafe {
+            <Array<Run<i32, u8>, SliceBuffer<'_>> as Import>::import(&array, &schema)
+                .expect_err("descending run ends")
+        };
+        // SAFETY: `values_pointer` still points into the buffer pointer array of
+        // the run ends child, and the import that borrowed it has returned.
+        unsafe { values_pointer.write(original) };
+
+        assert_eq!(
+            error,
+            ImportError::InvalidRunEnds {
+                error: RunEndsError::NotIncreasing { index: 1 }
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_short_run_ends() {
+        let source = [1_u8, 2]
+            .into_iter()
+            .map(Run::new)
+            .collect::<Array<Run<i32, u8>>>();
+        let (mut array, schema) = source.export().expect("export array");
+        array.length = 3;
+
+        // SAFETY: The exported structures remain valid; only the parent
+        // length is changed to exercise validation.
+        let error = unsafe {
+            <Array<Run<i32, u8>, SliceBuffer<'_>> as Import>::import(&array, &schema)
+                .expect_err("short run ends")
+        };
+        array.length = 2;
+
+        assert_eq!(
+            error,
+            ImportError::RunEndsLengthMismatch {
+                length: 3,
+                runs_length: 2
+            }
+        );
+    }
+}
@@ -0,0 +1,338 @@
+//! Import Arrow C Stream Interface streams as iterators of Narrow arrays.
+
+extern crate alloc;
+
+use alloc::string::String;
+use core::{
+    ffi::{CStr, c_int},
+    fmt,
+    iter::FusedIterator,
+    marker::PhantomData,
+    mem,
+};
+
+use narrow::{array::Array, layout::ArrayItem};
+
+use crate::{ArrowArray, ArrowArrayStream, ArrowSchema, ForeignBuffer};
+
+use super::{ImportError, ImportOwned};
+
+/// An iterator over the arrays of an imported [`ArrowArrayStream`].
+///
+/// The stream schema is requested once when the reader is constructed, and
+/// every array returned by `get_next` is imported with [`ImportOwned`], so
+/// the arrays remain valid after the stream is released.
+///
+/// The reader releases the stream when the producer signals the end of the
+/// stream or reports an error, and the iterator is fused from then on. A
+/// failed callback is returned as [`StreamError::Callback`] with the message
+/// from `get_last_error`.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{array::Array, collection::Collection};
+/// use narrow_ffi::{ArrayStreamReader, ExportStream};
+///
+/// let stream = [[Some(1), None].into_iter().collect::<Array<Option<u8>>>()].export_stream();
+/// // SAFETY: The stream was exported with the same item type.
+/// let mut reader = unsafe { ArrayStreamReader::<Option<u8>>::try_new(stream) }.unwrap();
+/// let array = reader.next().unwrap().unwrap();
+/// assert_eq!(array.into_iter_owned().collect::<Vec<_>>(), [Some(1), None]);
+/// assert!(reader.next().is_none());
+/// ```
+#[derive(Debug)]
+pub struct ArrayStreamReader<T> {
+    /// The imported stream, released once it is exhausted.
+    stream: ArrowArrayStream,
+    /// The schema shared by every array of the stream.
+    schema: ArrowSchema,
+    /// The item type of the imported arrays.
+    _item: PhantomData<fn() -> T>,
+}
+
+impl<T> ArrayStreamReader<T> {
+    /// Takes over `stream` and requests its schema.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StreamError`] when the stream is released or its schema
+    /// cannot be requested. The stream is released before the error is
+    /// returned.
+    ///
+    /// # Safety
+    ///
+    /// `stream` must follow the Arrow C Stream Interface, and every array it
+    /// produces must uphold the requirements of
+    /// [`ImportOwned::import_owned`].
+    pub unsafe fn try_new(mut stream: ArrowArrayStream) -> Result<Self, StreamError> {
+        let get_schema = stream
+            .get_schema
+            .filter(|_| !stream.is_released())
+            .ok_or(StreamError::ReleasedStream)?;
+        let mut schema = ArrowSchema::default();
+        // SAFETY: The caller guarantees a live stream, and `schema` is valid
+        // storage for the schema written by the producer.
+        let code = unsafe { get_schema(&raw mut stream, &raw mut schema) };
+        if code != 0 {
+            // SAFETY: The caller guarantees a live stream.
+            return Err(unsafe { StreamError::callback(&mut stream, code) });
+        }
+        Ok(Self {
+            stream,
+            schema,
+            _item: PhantomData,
+        })
+    }
+
+    /// Returns the schema shared by the arrays of the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::array::Array;
+    /// use narrow_ffi::{ArrayStreamReader, ExportStream};
+    ///
+    /// let stream = Vec::<Array<i32>>::new().export_stream();
+    /// // SAFETY: The stream was exported with the same item type.
+    /// let reader = unsafe { ArrayStreamReader::<i32>::try_new(stream) }.unwrap();
+    /// assert!(!reader.schema().is_released());
+    /// ```
+    #[must_use]
+    pub fn schema(&self) -> &ArrowSchema {
+        &self.schema
+    }
+}
+
+impl<T: ArrayItem> Iterator for ArrayStreamReader<T>
+where
+    Array<T, ForeignBuffer>: ImportOwned,
+{
+    type Item = Result<Array<T, ForeignBuffer>, StreamError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let get_next = self
+            .stream
+            .get_next
+            .filter(|_| !self.stream.is_released())?;
+        let mut array = ArrowArray::default();
+        // SAFETY: `try_new` guarantees a live stream, and `array` is valid
+        // storage for the array written by the producer.
+        let code = unsafe { get_next(&raw mut self.stream, &raw mut array) };
+        if code != 0 {
+            // SAFETY: `try_new` guarantees a live stream.
+            let error = unsafe { StreamError::callback(&mut self.stream, code) };
+            drop(mem::take(&mut self.stream));
+            return Some(Err(error));
+        }
+        if array.is_released() {
+            drop(mem::take(&mut self.stream));
+            return None;
+        }
+        // SAFETY: `try_new` guarantees that the arrays produced by the stream
+        // uphold the requirements of `ImportOwned::import_owned`.
+        Some(unsafe { ImportOwned::import_owned(array, &self.schema) }.map_err(StreamError::Import))
+    }
+}
+
+impl<T: ArrayItem> FusedIterator for ArrayStreamReader<T> where Array<T, ForeignBuffer>: ImportOwned {}
+
+/// Error returned when an Arrow C stream cannot be read as Narrow arrays.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum StreamError {
+    /// The Arrow stream was already released.
+    ReleasedStream,
+    /// A stream callback returned an error code.
+    Callback {
+        /// The `errno`-compatible error code.
+        code: c_int,
+        /// The message returned by `get_last_error`, if any.
+        message: Option<String>,
+    },
+    /// An array of the stream could not be imported.
+    Import(ImportError),
+}
+
+impl StreamError {
+    /// Builds a [`StreamError::Callback`] for `code` with the last error
+    /// message of `stream`.
+    ///
+    /// # Safety
+    ///
+    /// `stream` must be live and follow the Arrow C Stream Interface.
+    unsafe fn callback(stream: &mut ArrowArrayStream, code: c_int) -> Self {
+        let message = stream.get_last_error.and_then(|get_last_error| {
+            // SAFETY: The caller guarantees a live stream.
+            let pointer = unsafe { get_last_error(stream) };
+            (!pointer.is_null()).then(|| {
+                // SAFETY: A non-null last error is a null-terminated string
+                // that remains valid until the next stream callback.
+                unsafe { CStr::from_ptr(pointer) }
+                    .to_string_lossy()
+                    .into_owned()
+            })
+        });
+        Self::Callback { code, message }
+    }
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::ReleasedStream => write!(f, "Arrow stream is already released"),
+            Self::Callback {
+                code,
+                message: Some(ref message),
+            } => write!(f, "Arrow stream callback failed ({code}): {message}"),
+            Self::Callback {
+                code,
+                message: None,
+            } => write!(f, "Arrow stream callback failed ({code})"),
+            Self::Import(ref error) => write!(f, "invalid Arrow stream array: {error}"),
+        }
+    }
+}
+
+impl core::error::Error for StreamError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match *self {
+            Self::Import(ref error) => Some(error),
+            Self::ReleasedStream | Self::Callback { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::{borrow::ToOwned, string::ToString, sync::Arc, vec, vec::Vec};
+    use core::{
+        ffi::{c_char, c_int},
+        ptr,
+    };
+
+    use narrow::{
+        array::Array, buffer::ArcBuffer, collection::Collection,
+        layout::fixed_size_primitive::FixedSizePrimitive,
+    };
+
+    use crate::{ArrowArray, ArrowArrayStream, ArrowSchema, export::ExportStream};
+
+    use super::{ArrayStreamReader, ImportError, StreamError};
+
+    unsafe extern "C" fn get_schema(
+        _stream: *mut ArrowArrayStream,
+        _out: *mut ArrowSchema,
+    ) -> c_int {
+        0
+    }
+
+    unsafe extern "C" fn fail_next(_stream: *mut ArrowArrayStream, _out: *mut ArrowArray) -> c_int {
+        22
+    }
+
+    unsafe extern "C" fn get_last_error(_stream: *mut ArrowArrayStream) -> *const c_char {
+        c"producer failed".as_ptr()
+    }
+
+    unsafe extern "C" fn release_stream(stream: *mut ArrowArrayStream) {
+        // SAFETY: The callback receives the live stream from its `Drop` impl.
+        unsafe { (*stream).release = None };
+    }
+
+    #[test]
+    fn reads_exported_stream() {
+        let batches = [
+            [Some(1_i64), None]
+                .into_iter()
+                .collect::<Array<Option<i64>>>(),
+            Array::default(),
+            [Some(3)].into_iter().collect(),
+        ];
+        // SAFETY: The stream was exported with the same item type.
+        let reader = unsafe { ArrayStreamReader::<Option<i64>>::try_new(batches.export_stream()) }
+            .expect("live stream");
+
+        let values = reader
+            .map(|array| {
+                array
+                    .expect("valid array")
+                    .into_iter_owned()
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(values, [vec![Some(1), None], vec![], vec![Some(3)]]);
+    }
+
+    #[test]
+    fn arrays_outlive_the_stream() {
+        let storage = Arc::<[i32]>::from([1, 2, 3]);
+        let weak = Arc::downgrade(&storage);
+        let array: Array<i32, ArcBuffer> =
+            Array::from_buffer(FixedSizePrimitive::from_buffer(storage));
+        // SAFETY: The stream was exported with the same item type.
+        let mut reader = unsafe { ArrayStreamReader::<i32>::try_new([array].export_stream()) }
+            .expect("live stream");
+
+        let imported = reader.next().expect("array").expect("valid array");
+        assert!(reader.next().is_none());
+        assert!(reader.stream.is_released());
+        drop(reader);
+        assert!(weak.upgrade().is_some());
+        assert_eq!(imported.into_iter_owned().collect::<Vec<_>>(), [1, 2, 3]);
+        assert!(weak.upgrade().is_none());
+    }
+
+    #[test]
+    fn reports_mismatched_arrays() {
+        let stream = [[1_i32].into_iter().collect::<Array<i32>>()].export_stream();
+        // SAFETY: The stream produces valid arrays; only their type differs
+        // from the requested item type.
+        let mut reader = unsafe { ArrayStreamReader::<i64>::try_new(stream) }.expect("live stream");
+
+        let error = reader
+            .next()
+            .expect("error")
+            .expect_err("mismatched format");
+        assert_eq!(error, StreamError::Import(ImportError::UnexpectedFormat));
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn reports_producer_errors() {
+        let stream = ArrowArrayStream {
+            get_schema: Some(get_schema),
+            get_next: Some(fail_next),
+            get_last_error: Some(get_last_error),
+            release: Some(release_stream),
+            private_data: ptr::null_mut(),
+        };
+        // SAFETY: The stream callbacks follow the Arrow C Stream Interface.
+        let mut reader = unsafe { ArrayStreamReader::<i32>::try_new(stream) }.expect("live stream");
+
+        let error = reader.next().expect("error").expect_err("failed callback");
+        assert_eq!(
+            error,
+            StreamError::Callback {
+                code: 22,
+                message: Some("producer failed".to_owned()),
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "Arrow stream callback failed (22): producer failed"
+        );
+        assert!(reader.stream.is_released());
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn rejects_released_stream() {
+        // SAFETY: A released stream is never called.
+        let error = unsafe { ArrayStreamReader::<i32>::try_new(ArrowArrayStream::default()) }
+            .expect_err("released stream");
+        assert_eq!(error, StreamError::ReleasedStream);
+    }
+}
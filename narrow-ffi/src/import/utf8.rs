@@ -0,0 +1,139 @@
+//! Import support for [`Utf8`].
+
+use core::{borrow::Borrow, ffi::CStr, mem, slice};
+
+use narrow::{
+    layout::utf8::{Utf8, Utf8Item, Utf8Values},
+    offset::OffsetsError,
+};
+
+use crate::{ArrowArray, ArrowListOffset, ArrowSchema};
+
+use super::{ImportBuffer, ImportError, ImportLayout, ImportNullability, Owner};
+
+impl<'array, Nulls, OffsetItem, Storage, U> ImportLayout<'array>
+    for Utf8<Nulls, OffsetItem, Storage, U>
+where
+    Nulls: ImportNullability<'array>,
+    OffsetItem: ArrowListOffset,
+    Storage: ImportBuffer<'array>,
+    U: Utf8Item,
+{
+    type Storage = Storage;
+
+    const FLAGS: i64 = Nulls::FLAGS;
+    const BUFFERS: i64 = 3;
+    const CHILDREN: i64 = 0;
+
+    fn matches_format(format: &CStr) -> bool {
+        format == OffsetItem::UTF8_FORMAT
+    }
+
+    unsafe fn import_validated(
+        array: &'array ArrowArray,
+        _schema: &ArrowSchema,
+        owner: &Owner<'array, Self>,
+        length: usize,
+    ) -> Result<Self, ImportError> {
+        let offsets_length = length.checked_add(1).ok_or(ImportError::InvalidLength {
+            length: array.length,
+        })?;
+        // SAFETY: Common validation guarantees a three-entry buffer pointer
+        // array.
+        let buffers = unsafe { slice::from_raw_parts(array.buffers, 3) };
+        let offsets_pointer = buffers[1].cast::<OffsetItem>();
+        if offsets_pointer.is_null() {
+            return Err(ImportError::MissingOffsetsBuffer);
+        }
+        if !offsets_pointer.is_aligned() {
+            return Err(ImportError::MisalignedOffsetsBuffer {
+                alignment: mem::align_of::<OffsetItem>(),
+            });
+        }
+        // SAFETY: The caller guarantees the offsets buffer contains
+        // `offsets_length` aligned values that remain immutable for `'array`.
+        let offset_values =
+            unsafe { Storage::import_buffer(owner, offsets_pointer, offsets_length) };
+
+        // The data buffer has no length of its own: it ends at the last
+        // offset, which is validated again against the imported data.
+        let last = offset_values.borrow()[length];
+        let data_length: usize = last.try_into().map_err(|_| ImportError::InvalidOffsets {
+            error: OffsetsError::Negative { index: length },
+        })?;
+        let data_pointer = buffers[2].cast::<u8>();
+        if data_pointer.is_null() && data_length != 0 {
+            return Err(ImportError::MissingValuesBuffer);
+        }
+        // SAFETY: The caller guarantees the data buffer contains the bytes up
+        // to the last offset, which remain immutable for `'array`.
+        let data = unsafe { Storage::import_buffer(owner, data_pointer, data_length) };
+        let values = Utf8Values::try_from_parts(data, offset_values)
+            .map_err(|error| ImportError::InvalidUtf8 { error })?;
+
+        // SAFETY: Common validation and the caller guarantee a valid optional
+        // validity buffer for the imported string values.
+        let collection = unsafe { Nulls::wrap::<_, Storage>(array, owner, values) }?;
+        Ok(Self::from_buffer(collection))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::{borrow::ToOwned, string::String, vec::Vec};
+    use core::slice;
+
+    use narrow::{
+        array::Array, buffer::SliceBuffer, collection::Collection, layout::utf8::Utf8ValuesError,
+    };
+
+    use crate::{
+        ForeignBuffer,
+        export::Export,
+        import::{Import, ImportError, ImportOwned},
+    };
+
+    #[test]
+    fn round_trips_sliced_nullable_utf8() {
+        let source = [Some("a".to_owned()), None, Some("bc".to_owned())]
+            .into_iter()
+            .collect::<Array<Option<String>>>();
+        let (array, schema) = source.slice(1, 2).export().expect("export array");
+
+        // SAFETY: The exported structures describe a valid string array and
+        // are moved into the imported storage.
+        let imported: Array<Option<String>, ForeignBuffer> =
+            unsafe { ImportOwned::import_owned(array, &schema) }.expect("import array");
+        assert_eq!(
+            imported.into_iter_owned().collect::<Vec<_>>(),
+            [None, Some("bc".to_owned())]
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_utf8() {
+        let source = ["ab".to_owned()].into_iter().collect::<Array<String>>();
+        let (array, schema) = source.export().expect("export array");
+        // SAFETY: The exported array owns a three-entry buffer pointer array.
+        let buffers = unsafe { slice::from_raw_parts(array.buffers, 3) };
+        let data = buffers[2].cast::<u8>().cast_mut();
+        // SAFETY: The data buffer is owned by the export and not shared with
+        // an imported array.
+        unsafe { data.write(0xff) };
+
+        // SAFETY: The exported structures remain valid; only the data is
+        // changed to exercise validation.
+        let error = unsafe {
+            <Array<String, SliceBuffer<'_>> as Import>::import(&array, &schema)
+                .expect_err("invalid utf-8")
+        };
+        assert_eq!(
+            error,
+            ImportError::InvalidUtf8 {
+                error: Utf8ValuesError::InvalidUtf8 { index: 0 }
+            }
+        );
+    }
+}
@@ -1,25 +1,27 @@
-//! Borrowed import support for [`VariableSizeList`].
+//! Import support for [`VariableSizeList`].
 
 use core::{ffi::CStr, mem, slice};
 
 use narrow::{
-    buffer::SliceBuffer,
     layout::{ArrayItem, variable_size_list::VariableSizeList},
     offset::Offsets,
 };
 
 use crate::{ArrowArray, ArrowListOffset, ArrowSchema};
 
-use super::{ImportError, ImportLayout, ImportNullability};
+use super::{ImportBuffer, ImportError, ImportLayout, ImportNullability, Owner};
 
-impl<'array, T, Nulls, OffsetItem> ImportLayout<'array>
-    for VariableSizeList<T, Nulls, OffsetItem, SliceBuffer<'array>>
+impl<'array, T, Nulls, OffsetItem, Storage> ImportLayout<'array>
+    for VariableSizeList<T, Nulls, OffsetItem, Storage>
 where
     T: ArrayItem,
     Nulls: ImportNullability<'array>,
     OffsetItem: ArrowListOffset,
-    T::Memory<SliceBuffer<'array>>: ImportLayout<'array>,
+    T::Memory<Storage>: ImportLayout<'array, Storage = Storage>,
+    Storage: ImportBuffer<'array>,
 {
+    type Storage = Storage;
+
     const FLAGS: i64 = Nulls::FLAGS;
     const BUFFERS: i64 = 2;
     const CHILDREN: i64 = 1;
@@ -31,6 +33,7 @@ where
     unsafe fn import_validated(
         array: &'array ArrowArray,
         schema: &ArrowSchema,
+        owner: &Owner<'array, Self>,
         length: usize,
     ) -> Result<Self, ImportError> {
         let offsets_length = length.checked_add(1).ok_or(ImportError::InvalidLength {
@@ -49,18 +52,18 @@ where
         }
         // SAFETY: The caller guarantees the offsets buffer contains
         // `offsets_length` aligned values that remain immutable for `'array`.
-        let offset_values = unsafe { slice::from_raw_parts(offsets_pointer, offsets_length) };
+        let offset_values =
+            unsafe { Storage::import_buffer(owner, offsets_pointer, offsets_length) };
 
         // SAFETY: Common parent fields are validated and the caller upholds
         // the Arrow C Data requirements for the retained child structures.
-        let child =
-            unsafe { Self::import_child::<T::Memory<SliceBuffer<'array>>>(array, schema, 0) }?;
+        let child = unsafe { Self::import_child::<T::Memory<Storage>>(array, schema, owner, 0) }?;
         let offsets = Offsets::try_from_parts(child, offset_values)
             .map_err(|error| ImportError::InvalidOffsets { error })?;
 
         // SAFETY: Common validation and the caller guarantee a valid optional
         // validity buffer for the imported offsets collection.
-        let collection = unsafe { Nulls::wrap(array, offsets) }?;
+        let collection = unsafe { Nulls::wrap::<_, Storage>(array, owner, offsets) }?;
         Ok(Self::from_buffer(collection))
     }
 }
@@ -83,8 +86,9 @@ mod tests {
     };
 
     use crate::{
+        ForeignBuffer,
         export::Export,
-        import::{Import, ImportError},
+        import::{Import, ImportError, ImportOwned},
     };
 
     #[test]
@@ -128,6 +132,30 @@ mod tests {
         drop(schema);
     }
 
+    #[test]
+    fn owned_import_retains_child_buffers() {
+        let value_storage = Arc::<[i32]>::from([1, 2, 3]);
+        let values_weak = Arc::downgrade(&value_storage);
+        let values = FixedSizePrimitive::from_buffer(value_storage);
+        let offsets =
+            Offsets::try_from_parts(values, Arc::<[i32]>::from([0, 2, 3])).expect("valid offsets");
+        let source: Array<Vec<i32>, ArcBuffer> =
+            Array::from_buffer(VariableSizeList::from_buffer(offsets));
+        let (array, schema) = source.export().expect("export array");
+
+        // SAFETY: The exported structures describe a valid list array whose
+        // child is owned by the parent array.
+        let imported: Array<Vec<i32>, ForeignBuffer> =
+            unsafe { ImportOwned::import_owned(array, &schema) }.expect("import array");
+        drop(schema);
+        let child = imported.buffer_ref().buffer_ref().child_ref().clone();
+        drop(imported);
+
+        assert!(values_weak.upgrade().is_some());
+        assert_eq!(child.into_iter_owned().collect::<Vec<_>>(), [1, 2, 3]);
+        assert!(values_weak.upgrade().is_none());
+    }
+
     #[test]
     fn imports_nullable_variable_size_list_buffers_without_copying() {
         let value_storage = Arc::<[i32]>::from([1, 2, 3]);
@@ -184,6 +212,45 @@ mod tests {
         assert_eq!(imported.owned(1), Some(Some(vec![3])));
     }
 
+    #[test]
+    fn imports_variable_size_list_with_non_zero_first_offset() {
+        let values = FixedSizePrimitive::from_buffer(vec![0, 1, 2, 3]);
+        let offsets = Offsets::try_from_parts(values, vec![1, 3, 4]).expect("valid offsets");
+        let source: Array<Vec<i32>> = Array::from_buffer(VariableSizeList::from_buffer(offsets));
+        let (array, schema) = source.export().expect("export array");
+
+        // SAFETY: The exported structures retain valid offsets and child
+        // buffers for the lifetime of the imported array.
+        let imported: Array<Vec<i32>, SliceBuffer<'_>> =
+            unsafe { Import::import(&array, &schema) }.expect("import array");
+
+        assert_eq!(
+            imported.into_iter_owned().collect::<Vec<_>>(),
+            [vec![1, 2], vec![3]]
+        );
+    }
+
+    #[test]
+    fn imports_variable_size_list_with_offset() {
+        let source = [Some(vec![1_i32]), None, Some(vec![2, 3])]
+            .into_iter()
+            .collect::<Array<Option<Vec<i32>>>>();
+        let (mut array, schema) = source.export().expect("export array");
+        array.offset = 1;
+        array.length = 2;
+        array.null_count = 1;
+
+        // SAFETY: The exported buffers contain `offset + length` items that
+        // remain valid for the lifetime of the imported array.
+        let imported: Array<Option<Vec<i32>>, SliceBuffer<'_>> =
+            unsafe { Import::import(&array, &schema) }.expect("import array");
+
+        assert_eq!(
+            imported.into_iter_owned().collect::<Vec<_>>(),
+            [None, Some(vec![2, 3])]
+        );
+    }
+
     #[test]
     fn rejects_mismatched_variable_size_list_format() {
         let source = [vec![1_i32]].into_iter().collect::<Array<Vec<i32>>>();
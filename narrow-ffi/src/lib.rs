@@ -1,10 +1,13 @@
-//! Arrow C Data Interface support for [`Array`](narrow::array::Array).
+//! Arrow C Data and C Stream Interface support for [`Array`](narrow::array::Array).
 //!
-//! See [The Arrow C data interface] specification.
+//! See [The Arrow C data interface] and [The Arrow C stream interface]
+//! specifications.
 //!
 //! [The Arrow C data interface]: https://arrow.apache.org/docs/format/CDataInterface.html
+//! [The Arrow C stream interface]: https://arrow.apache.org/docs/format/CStreamInterface.html
 
 #![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
 // The goal of the list of lints here is to help reduce complexity and improve consistency
 #![deny(
     // Rustc
@@ -62,29 +65,94 @@
 )]
 
 use core::{
-    ffi::{CStr, c_char, c_void},
+    ffi::{CStr, c_char, c_int, c_void},
     ptr,
 };
 
-use narrow::offset::Offset;
+use narrow::{bitmap::ValidityBitmap, collection::Collection, offset::Offset};
 
+#[cfg(feature = "std")]
+extern crate std;
+
+mod buffer;
+pub use buffer::{ForeignBuffer, ForeignSlice};
 mod export;
-pub use export::{ArrowType, Export, ExportError};
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use export::ExportRecordBatch;
+pub use export::{ArrowType, Export, ExportError, ExportStream};
+#[cfg(feature = "std")]
+mod field;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use field::{Field, Schema};
 mod import;
-pub use import::{Import, ImportError};
+pub use import::{ArrayStreamReader, Import, ImportError, ImportOwned, StreamError};
+mod metadata;
 
-/// An Arrow list offset with a C Data format string.
+/// An Arrow list offset with C Data format strings.
+///
+/// Binary and string arrays use the same offsets as lists, so their format is
+/// selected by the offset width as well.
 trait ArrowListOffset: Offset {
     /// Arrow C Data format for a list using this offset width.
     const FORMAT: &'static CStr;
+    /// Arrow C Data format for a list view using this offset width.
+    const VIEW_FORMAT: &'static CStr;
+    /// Arrow C Data format for binary data using this offset width.
+    const BINARY_FORMAT: &'static CStr;
+    /// Arrow C Data format for UTF-8 strings using this offset width.
+    const UTF8_FORMAT: &'static CStr;
 }
 
 impl ArrowListOffset for i32 {
     const FORMAT: &'static CStr = c"+l";
+    const VIEW_FORMAT: &'static CStr = c"+vl";
+    const BINARY_FORMAT: &'static CStr = c"z";
+    const UTF8_FORMAT: &'static CStr = c"u";
 }
 
 impl ArrowListOffset for i64 {
     const FORMAT: &'static CStr = c"+L";
+    const VIEW_FORMAT: &'static CStr = c"+vL";
+    const BINARY_FORMAT: &'static CStr = c"Z";
+    const UTF8_FORMAT: &'static CStr = c"U";
+}
+
+/// Returns the number of nulls among the `len` items of `validity` starting
+/// at `offset`.
+///
+/// Arrow null counts describe the array window, while a sliced array shares
+/// its validity bitmap with the items outside of that window.
+fn window_null_count<V: ValidityBitmap>(validity: &V, offset: usize, len: usize) -> usize {
+    match validity.bitmap_ref() {
+        None => 0,
+        Some(_) if offset == 0 && len == validity.len() => validity.null_count(),
+        Some(bitmap) => bitmap
+            .iter_views()
+            .skip(offset)
+            .take(len)
+            .filter(|valid| !*valid)
+            .count(),
+    }
+}
+
+/// Returns the width in a `<prefix><width>` format string, such as `+w:4`.
+///
+/// The decimal width is parsed without allocating, rejecting non-digits and
+/// arithmetic overflow.
+fn parse_width(format: &CStr, prefix: &[u8]) -> Option<usize> {
+    let digits = format.to_bytes().strip_prefix(prefix)?;
+    if digits.is_empty() {
+        return None;
+    }
+    digits.iter().try_fold(0_usize, |width, byte| {
+        let digit = (*byte).checked_sub(b'0')?;
+        if digit > 9 {
+            return None;
+        }
+        width.checked_mul(10)?.checked_add(usize::from(digit))
+    })
 }
 
 /// Dictionary values are ordered.
@@ -215,13 +283,66 @@ impl Drop for ArrowArray {
     }
 }
 
+/// The Arrow C Stream Interface structure.
+///
+/// A stream produces a sequence of [`ArrowArray`] values that share one
+/// [`ArrowSchema`].
+#[repr(C)]
+#[derive(Debug)]
+pub struct ArrowArrayStream {
+    /// Producer callback that writes the stream schema, returning zero or an
+    /// `errno`-compatible error code.
+    get_schema: Option<unsafe extern "C" fn(*mut Self, *mut ArrowSchema) -> c_int>,
+    /// Producer callback that writes the next array, or a released array at
+    /// the end of the stream, returning zero or an `errno`-compatible error
+    /// code.
+    get_next: Option<unsafe extern "C" fn(*mut Self, *mut ArrowArray) -> c_int>,
+    /// Producer callback that returns a null-terminated description of the
+    /// last error, or null when there is none.
+    get_last_error: Option<unsafe extern "C" fn(*mut Self) -> *const c_char>,
+    /// Producer callback that releases the stream; [`None`] marks it released.
+    release: Option<unsafe extern "C" fn(*mut Self)>,
+    /// Optional opaque producer-owned data used by the callbacks.
+    private_data: *mut c_void,
+}
+
+impl ArrowArrayStream {
+    /// Returns whether this stream has been released.
+    #[must_use]
+    pub const fn is_released(&self) -> bool {
+        self.release.is_none()
+    }
+}
+
+impl Default for ArrowArrayStream {
+    fn default() -> Self {
+        Self {
+            get_schema: None,
+            get_next: None,
+            get_last_error: None,
+            release: None,
+            private_data: ptr::null_mut(),
+        }
+    }
+}
+
+impl Drop for ArrowArrayStream {
+    fn drop(&mut self) {
+        if let Some(release) = self.release {
+            // SAFETY: A live Arrow C stream owns a producer-provided callback
+            // that accepts the address of the stream being released.
+            unsafe { release(self) };
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[cfg(target_pointer_width = "64")]
     use core::mem::{align_of, offset_of, size_of};
     use core::sync::atomic::{AtomicUsize, Ordering};
 
-    use super::{ArrowArray, ArrowSchema};
+    use super::{ArrowArray, ArrowArrayStream, ArrowSchema};
 
     static ARRAY_RELEASES: AtomicUsize = AtomicUsize::new(0);
     static SCHEMA_RELEASES: AtomicUsize = AtomicUsize::new(0);
@@ -242,6 +363,7 @@ mod tests {
     fn empty_structures_are_released() {
         assert!(ArrowArray::default().is_released());
         assert!(ArrowSchema::default().is_released());
+        assert!(ArrowArrayStream::default().is_released());
     }
 
     #[cfg(target_pointer_width = "64")]
@@ -271,6 +393,14 @@ mod tests {
         assert_eq!(offset_of!(ArrowArray, dictionary), 56);
         assert_eq!(offset_of!(ArrowArray, release), 64);
         assert_eq!(offset_of!(ArrowArray, private_data), 72);
+
+        assert_eq!(align_of::<ArrowArrayStream>(), 8);
+        assert_eq!(size_of::<ArrowArrayStream>(), 40);
+        assert_eq!(offset_of!(ArrowArrayStream, get_schema), 0);
+        assert_eq!(offset_of!(ArrowArrayStream, get_next), 8);
+        assert_eq!(offset_of!(ArrowArrayStream, get_last_error), 16);
+        assert_eq!(offset_of!(ArrowArrayStream, release), 24);
+        assert_eq!(offset_of!(ArrowArrayStream, private_data), 32);
     }
 
     #[test]
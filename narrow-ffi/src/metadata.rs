@@ -0,0 +1,212 @@
+//! The Arrow C Data binary metadata format.
+//!
+//! Metadata is a sequence of key/value pairs. The number of pairs is followed
+//! by every key and value, each prefixed with its length in bytes. Lengths
+//! are native-endian `i32`s, which are not necessarily aligned.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::{marker::PhantomData, mem, slice, str};
+
+use crate::{ArrowSchema, ImportError};
+
+/// The metadata key of the name of an extension type.
+pub(crate) const EXTENSION_NAME: &str = "ARROW:extension:name";
+
+/// The metadata key of the serialized metadata of an extension type.
+pub(crate) const EXTENSION_METADATA: &str = "ARROW:extension:metadata";
+
+/// Appends a metadata length as a native-endian `i32`.
+fn push_length(buffer: &mut Vec<u8>, len: usize) {
+    let length = i32::try_from(len).expect("metadata length exceeds i32");
+    buffer.extend_from_slice(&length.to_ne_bytes());
+}
+
+/// Encodes key/value `pairs` in the binary metadata format, or returns
+/// [`None`] when there are no pairs.
+pub(crate) fn encode<'pairs, I>(pairs: I) -> Option<Vec<u8>>
+where
+    I: IntoIterator<Item = (&'pairs str, &'pairs str)>,
+    I::IntoIter: ExactSizeIterator,
+{
+    let iter = pairs.into_iter();
+    if iter.len() == 0 {
+        return None;
+    }
+    let mut buffer = Vec::new();
+    push_length(&mut buffer, iter.len());
+    for (key, value) in iter {
+        push_length(&mut buffer, key.len());
+        buffer.extend_from_slice(key.as_bytes());
+        push_length(&mut buffer, value.len());
+        buffer.extend_from_slice(value.as_bytes());
+    }
+    Some(buffer)
+}
+
+/// The key/value pairs of the metadata of an [`ArrowSchema`].
+///
+/// Iteration stops after the first invalid pair.
+pub(crate) struct MetadataPairs<'schema> {
+    /// Start of the next length.
+    cursor: *const u8,
+    /// Number of pairs that are not read yet.
+    remaining: usize,
+    /// The schema that owns the metadata.
+    schema: PhantomData<&'schema ArrowSchema>,
+}
+
+impl<'schema> MetadataPairs<'schema> {
+    /// Returns the pairs of the metadata of `schema`, where null metadata has
+    /// no pairs.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ImportError::InvalidMetadata`] when the number of pairs is
+    /// negative.
+    ///
+    /// # Safety
+    ///
+    /// The metadata of `schema` must be null or encoded in the binary metadata
+    /// format.
+    pub(crate) unsafe fn new(schema: &'schema ArrowSchema) -> Result<Self, ImportError> {
+        let mut pairs = Self {
+            cursor: schema.metadata.cast(),
+            remaining: 0,
+            schema: PhantomData,
+        };
+        if !pairs.cursor.is_null() {
+            // SAFETY: The caller guarantees metadata starting with the number
+            // of pairs.
+            pairs.remaining = unsafe { pairs.read_length() }?;
+        }
+        Ok(pairs)
+    }
+
+    /// Reads a length and advances past it.
+    ///
+    /// # Safety
+    ///
+    /// The cursor must be valid for reads of four bytes.
+    unsafe fn read_length(&mut self) -> Result<usize, ImportError> {
+        // SAFETY: The caller guarantees four readable bytes, which are not
+        // necessarily aligned.
+        let length = unsafe { self.cursor.cast::<i32>().read_unaligned() };
+        // SAFETY: The bytes read above are part of the same allocation.
+        self.cursor = unsafe { self.cursor.add(mem::size_of::<i32>()) };
+        usize::try_from(length).map_err(|_| ImportError::InvalidMetadata)
+    }
+
+    /// Reads a length-prefixed UTF-8 string and advances past it.
+    ///
+    /// # Safety
+    ///
+    /// The cursor must point to a length followed by that many bytes, which
+    /// remain valid for `'schema`.
+    unsafe fn read_str(&mut self) -> Result<&'schema str, ImportError> {
+        // SAFETY: The caller guarantees a readable length.
+        let length = unsafe { self.read_length() }?;
+        // SAFETY: The caller guarantees `length` bytes after the length that
+        // remain valid for `'schema`.
+        let bytes = unsafe { slice::from_raw_parts(self.cursor, length) };
+        // SAFETY: The bytes read above are part of the same allocation.
+        self.cursor = unsafe { self.cursor.add(length) };
+        str::from_utf8(bytes).map_err(|_| ImportError::InvalidMetadata)
+    }
+}
+
+impl<'schema> Iterator for MetadataPairs<'schema> {
+    type Item = Result<(&'schema str, &'schema str), ImportError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.remaining = self.remaining.checked_sub(1)?;
+        // SAFETY: `MetadataPairs::new` guarantees metadata with `remaining`
+        // more keys and values.
+        let key = unsafe { self.read_str() };
+        // SAFETY: Every key is followed by its value, so `MetadataPairs::new`
+        // also guarantees the value of this key.
+        let pair = key.and_then(|name| unsafe { self.read_str() }.map(|value| (name, value)));
+        if pair.is_err() {
+            self.remaining = 0;
+        }
+        Some(pair)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::{vec, vec::Vec};
+
+    use crate::{ArrowSchema, ImportError};
+
+    use super::{MetadataPairs, encode};
+
+    #[test]
+    fn encodes_pairs() {
+        assert_eq!(encode([]), None);
+        let expected = [1_i32, 3]
+            .iter()
+            .flat_map(|length| length.to_ne_bytes())
+            .chain(*b"key")
+            .chain(5_i32.to_ne_bytes())
+            .chain(*b"value")
+            .collect::<Vec<_>>();
+        assert_eq!(encode([("key", "value")]), Some(expected));
+    }
+
+    #[test]
+    fn decodes_pairs() {
+        let metadata = encode([("a", ""), ("ключ", "значение")]).expect("two pairs");
+        let schema = ArrowSchema {
+            metadata: metadata.as_ptr().cast(),
+            ..ArrowSchema::default()
+        };
+        // SAFETY: The metadata was encoded above.
+        let pairs = unsafe { MetadataPairs::new(&schema) }
+            .expect("pair count")
+            .collect::<Result<Vec<_>, _>>();
+        assert_eq!(pairs, Ok(vec![("a", ""), ("ключ", "значение")]));
+
+        let unset = ArrowSchema::default();
+        // SAFETY: A default schema has no metadata.
+        let empty = unsafe { MetadataPairs::new(&unset) }.expect("no metadata");
+        assert_eq!(empty.count(), 0);
+    }
+
+    #[test]
+    fn rejects_invalid_pairs() {
+        let negative = [1_i32, -1]
+            .iter()
+            .flat_map(|length| length.to_ne_bytes())
+            .collect::<Vec<_>>();
+        let schema = ArrowSchema {
+            metadata: negative.as_ptr().cast(),
+            ..ArrowSchema::default()
+        };
+        // SAFETY: The metadata ends at the negative length, which is rejected
+        // before anything after it is read.
+        let mut pairs = unsafe { MetadataPairs::new(&schema) }.expect("pair count");
+        assert_eq!(pairs.next(), Some(Err(ImportError::InvalidMetadata)));
+        assert_eq!(pairs.next(), None);
+
+        let invalid_utf8 = [1_i32, 1]
+            .iter()
+            .flat_map(|length| length.to_ne_bytes())
+            .chain([0xff])
+            .chain(0_i32.to_ne_bytes())
+            .collect::<Vec<_>>();
+        let other_schema = ArrowSchema {
+            metadata: invalid_utf8.as_ptr().cast(),
+            ..ArrowSchema::default()
+        };
+        // SAFETY: The metadata holds one pair with a one-byte key and an
+        // empty value.
+        let invalid = unsafe { MetadataPairs::new(&other_schema) }
+            .expect("pair count")
+            .next();
+        assert_eq!(invalid, Some(Err(ImportError::InvalidMetadata)));
+    }
+}
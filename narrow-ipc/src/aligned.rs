@@ -0,0 +1,562 @@
+//! Storage for buffers with 64-byte alignment and padding.
+
+use std::{
+    alloc::{self, Layout},
+    borrow::{Borrow, BorrowMut},
+    fmt,
+    iter::Map,
+    marker::PhantomData,
+    mem,
+    ops::{Deref, DerefMut},
+    ptr::{self, NonNull},
+    slice,
+};
+
+use narrow::{
+    buffer::Buffer,
+    collection::{
+        AllocError, Collection, CollectionAllocIn, CollectionRealloc, slice::SliceIntoIter,
+        view::AsView,
+    },
+    fixed_size::FixedSize,
+    length::Length,
+};
+
+/// Alignment and padding of [`AlignedVec`] allocations in bytes.
+const ALIGNMENT: usize = 64;
+
+/// A type with the alignment of [`AlignedVec`] allocations, used for the
+/// dangling pointer of empty vectors.
+#[repr(C, align(64))]
+struct Aligned;
+
+/// Storage for buffers that are aligned and padded to 64 bytes.
+///
+/// The Arrow specification recommends 64-byte alignment and padding, so the
+/// values of a buffer can be processed with aligned SIMD loads of a whole
+/// cache line, including the last one. Arrays built with this storage
+/// satisfy that without copying, and can grow like arrays built with
+/// [`VecBuffer`](narrow::buffer::VecBuffer).
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{array::Array, buffer::BufferRef, collection::Collection};
+/// use narrow_ipc::{AlignedBuffer, AlignedVec};
+///
+/// let mut array = [1_u32, 2].into_iter().collect::<Array<u32, AlignedBuffer>>();
+/// array.extend([3]);
+/// let values: &AlignedVec<u32> = array.buffer_ref().buffer_ref();
+/// assert!(values.as_ptr().cast::<[u8; 64]>().is_aligned());
+/// assert_eq!(values.padded_bytes().len(), 64);
+/// assert_eq!(array.into_iter_owned().collect::<Vec<_>>(), [1, 2, 3]);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AlignedBuffer;
+impl Buffer for AlignedBuffer {
+    type For<T: FixedSize> = AlignedVec<T>;
+}
+
+/// A growable buffer of `T` that is aligned and padded to 64 bytes.
+///
+/// The allocation is a multiple of 64 bytes, and every byte after the
+/// values is zero, so [`AlignedVec::padded_bytes`] can be read in whole
+/// 64-byte blocks.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::collection::CollectionRealloc;
+/// use narrow_ipc::AlignedVec;
+///
+/// let mut values = [1_u8, 2, 3].into_iter().collect::<AlignedVec<u8>>();
+/// CollectionRealloc::truncate(&mut values, 1);
+/// assert_eq!(*values, [1]);
+/// assert_eq!(values.padded_bytes()[..3], [1, 0, 0]);
+/// ```
+pub struct AlignedVec<T: FixedSize> {
+    /// Start of the allocation.
+    ptr: NonNull<T>,
+    /// Number of values.
+    len: usize,
+    /// Number of values that fit in the allocation.
+    capacity: usize,
+    /// Type of the values.
+    _values: PhantomData<T>,
+}
+
+// SAFETY: An `AlignedVec` owns its values, like a `Vec`.
+unsafe impl<T: FixedSize + Send> Send for AlignedVec<T> {}
+
+// SAFETY: An `AlignedVec` owns its values, like a `Vec`.
+unsafe impl<T: FixedSize + Sync> Sync for AlignedVec<T> {}
+
+impl<T: FixedSize> AlignedVec<T> {
+    /// Constructs a new, empty [`AlignedVec`] without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow_ipc::AlignedVec;
+    ///
+    /// let values = AlignedVec::<u16>::new();
+    /// assert!(values.is_empty());
+    /// assert_eq!(values.capacity(), 0);
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            ptr: NonNull::<Aligned>::dangling().cast(),
+            len: 0,
+            capacity: if mem::size_of::<T>() == 0 {
+                usize::MAX
+            } else {
+                0
+            },
+            _values: PhantomData,
+        }
+    }
+
+    /// Returns the number of values that fit in the allocation.
+    ///
+    /// The capacity includes the padding of the allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::collection::CollectionAlloc;
+    /// use narrow_ipc::AlignedVec;
+    ///
+    /// let values = <AlignedVec<u32> as CollectionAlloc>::with_capacity(1);
+    /// assert_eq!(values.capacity(), 16);
+    /// ```
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the bytes of the values followed by their zero padding, up to
+    /// the next multiple of 64 bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow_ipc::AlignedVec;
+    ///
+    /// let values = [1_u16].into_iter().collect::<AlignedVec<u16>>();
+    /// assert_eq!(values.padded_bytes().len(), 64);
+    /// assert!(values.padded_bytes()[2..].iter().all(|byte| *byte == 0));
+    /// ```
+    #[must_use]
+    pub fn padded_bytes(&self) -> &[u8] {
+        let len = if self.is_allocated() {
+            mem::size_of_val::<[T]>(self).next_multiple_of(ALIGNMENT)
+        } else {
+            0
+        };
+        // SAFETY: The allocation is a multiple of 64 bytes that contains the
+        // values, and its bytes are initialized: `FixedSize` guarantees that
+        // the values have no padding, and the rest of the allocation is
+        // zeroed when it is allocated and when values are removed.
+        unsafe { slice::from_raw_parts(self.ptr.as_ptr().cast::<u8>(), len) }
+    }
+
+    /// Appends `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the new capacity overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow_ipc::AlignedVec;
+    ///
+    /// let mut values = AlignedVec::new();
+    /// values.push(1_i64);
+    /// assert_eq!(*values, [1]);
+    /// ```
+    pub fn push(&mut self, value: T) {
+        if self.len == self.capacity {
+            self.grow_or_abort(1);
+        }
+        let slot = self.ptr.as_ptr().wrapping_add(self.len);
+        // SAFETY: The allocation has room for at least `len + 1` values.
+        unsafe { slot.write(value) };
+        self.len = self.len.strict_add(1);
+    }
+
+    /// Returns whether the values are stored in an allocation.
+    fn is_allocated(&self) -> bool {
+        mem::size_of::<T>() != 0 && self.capacity != 0
+    }
+
+    /// Returns the layout of an allocation for at least `capacity` values.
+    ///
+    /// The capacity of an allocation, which is its size divided by the size
+    /// of `T`, has the same layout.
+    fn layout(capacity: usize) -> Option<Layout> {
+        let size = capacity
+            .checked_mul(mem::size_of::<T>())?
+            .checked_next_multiple_of(ALIGNMENT)?;
+        Layout::from_size_align(size, ALIGNMENT).ok()
+    }
+
+    /// Grows the allocation to fit at least `additional` more values.
+    ///
+    /// Returns the layout that could not be allocated on failure, or
+    /// [`None`] when the capacity overflows.
+    fn grow(&mut self, additional: usize) -> Result<(), Option<Layout>> {
+        let required = self.len.checked_add(additional).ok_or(None)?;
+        if required <= self.capacity {
+            return Ok(());
+        }
+        let capacity = required.max(self.capacity.saturating_mul(2));
+        let layout = Self::layout(capacity).ok_or(None)?;
+        let ptr = if self.is_allocated() {
+            let current = Self::layout(self.capacity).expect("layout of the allocation");
+            // SAFETY: The allocation was made with `current`, and the new
+            // size is a non-zero multiple of the alignment.
+            let grown = unsafe { alloc::realloc(self.ptr.as_ptr().cast(), current, layout.size()) };
+            if !grown.is_null() {
+                let added = layout.size().strict_sub(current.size());
+                let tail = grown.wrapping_add(current.size());
+                // SAFETY: The added bytes are within the new allocation.
+                unsafe { ptr::write_bytes(tail, 0, added) };
+            }
+            grown
+        } else {
+            // SAFETY: The layout has a non-zero size, because `T` is not
+            // zero-sized when the capacity is zero.
+            unsafe { alloc::alloc_zeroed(layout) }
+        };
+        self.ptr = NonNull::new(ptr.cast()).ok_or(Some(layout))?;
+        self.capacity = layout.size().strict_div(mem::size_of::<T>());
+        Ok(())
+    }
+
+    /// Grows the allocation to fit at least `additional` more values, and
+    /// aborts when it cannot be allocated.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the new capacity overflows.
+    fn grow_or_abort(&mut self, additional: usize) {
+        match self.grow(additional) {
+            Ok(()) => {}
+            Err(Some(layout)) => alloc::handle_alloc_error(layout),
+            Err(None) => panic!("capacity overflow"),
+        }
+    }
+}
+
+impl<T: FixedSize> Drop for AlignedVec<T> {
+    fn drop(&mut self) {
+        if self.is_allocated() {
+            let layout = Self::layout(self.capacity).expect("layout of the allocation");
+            // SAFETY: The allocation was made with the layout of its
+            // capacity.
+            unsafe { alloc::dealloc(self.ptr.as_ptr().cast(), layout) };
+        }
+    }
+}
+
+impl<T: FixedSize> Default for AlignedVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: FixedSize> Deref for AlignedVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: The pointer is aligned and the first `len` values are
+        // initialized.
+        unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<T: FixedSize> DerefMut for AlignedVec<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY: The pointer is aligned and the first `len` values are
+        // initialized and uniquely borrowed.
+        unsafe { slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<T: FixedSize> Borrow<[T]> for AlignedVec<T> {
+    fn borrow(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T: FixedSize> BorrowMut<[T]> for AlignedVec<T> {
+    fn borrow_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T: FixedSize> Clone for AlignedVec<T> {
+    fn clone(&self) -> Self {
+        self.iter().copied().collect()
+    }
+}
+
+impl<T: FixedSize + fmt::Debug> fmt::Debug for AlignedVec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: FixedSize + PartialEq> PartialEq for AlignedVec<T> {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<T: FixedSize + Eq> Eq for AlignedVec<T> {}
+
+impl<T: FixedSize> Extend<T> for AlignedVec<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let items = iter.into_iter();
+        self.grow_or_abort(items.size_hint().0);
+        items.for_each(|item| self.push(item));
+    }
+}
+
+impl<T: FixedSize> FromIterator<T> for AlignedVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut values = Self::new();
+        values.extend(iter);
+        values
+    }
+}
+
+impl<T: FixedSize> Length for AlignedVec<T> {
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<T: FixedSize + for<'any> AsView<'any>> Collection for AlignedVec<T> {
+    type View<'collection>
+        = <T as AsView<'collection>>::View
+    where
+        Self: 'collection;
+
+    type Owned = T;
+
+    fn view(&self, index: usize) -> Option<Self::View<'_>> {
+        self.get(index).map(AsView::as_view)
+    }
+
+    type Iter<'collection>
+        = Map<slice::Iter<'collection, T>, fn(&'collection T) -> <T as AsView<'collection>>::View>
+    where
+        Self: 'collection;
+
+    fn iter_views(&self) -> Self::Iter<'_> {
+        self.iter().map(AsView::as_view)
+    }
+
+    type IntoIter = SliceIntoIter<Self, T>;
+
+    fn into_iter_owned(self) -> Self::IntoIter {
+        self.into()
+    }
+}
+
+impl<T: FixedSize + for<'any> AsView<'any>> CollectionAllocIn for AlignedVec<T> {
+    type Alloc = ();
+
+    fn with_capacity_in(capacity: usize, (): Self::Alloc) -> Self {
+        let mut values = Self::new();
+        values.grow_or_abort(capacity);
+        values
+    }
+
+    fn from_iter_in<I: IntoIterator<Item = Self::Owned>>(iter: I, (): Self::Alloc) -> Self {
+        iter.into_iter().collect()
+    }
+
+    fn try_with_capacity_in(capacity: usize, (): Self::Alloc) -> Result<Self, AllocError> {
+        let mut values = Self::new();
+        values.grow(capacity).map_err(|_| AllocError)?;
+        Ok(values)
+    }
+
+    fn try_from_iter_in<I: IntoIterator<Item = Self::Owned>>(
+        iter: I,
+        (): Self::Alloc,
+    ) -> Result<Self, AllocError> {
+        let mut values = Self::new();
+        values.try_extend(iter)?;
+        Ok(values)
+    }
+}
+
+impl<T: FixedSize + for<'any> AsView<'any>> CollectionRealloc for AlignedVec<T> {
+    fn allocator(&self) -> Self::Alloc {}
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+        self.grow(additional).map_err(|_| AllocError)
+    }
+
+    fn try_extend<I: IntoIterator<Item = Self::Owned>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), AllocError> {
+        let len = self.len;
+        let items = iter.into_iter();
+        self.grow(items.size_hint().0).map_err(|_| AllocError)?;
+        for item in items {
+            if self.len == self.capacity && self.grow(1).is_err() {
+                CollectionRealloc::truncate(self, len);
+                return Err(AllocError);
+            }
+            self.push(item);
+        }
+        Ok(())
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.grow_or_abort(additional);
+    }
+
+    /// Shortens this buffer to `len` values and zeroes the removed values,
+    /// so the padding stays zeroed.
+    fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            let removed = self.len.strict_sub(len);
+            let start = self.ptr.as_ptr().wrapping_add(len);
+            // SAFETY: The removed values are within the allocation and
+            // aligned for `T`. They are no longer values, so the zeroed bytes
+            // are only read as padding bytes.
+            unsafe { ptr::write_bytes(start, 0, removed) };
+            self.len = len;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use narrow::{
+        array::Array, buffer::BufferRef, collection::CollectionAlloc, fixed_size::FixedSizeArray,
+    };
+
+    use crate::{StreamReader, StreamWriter};
+
+    use super::*;
+
+    fn assert_aligned<T: FixedSize>(values: &AlignedVec<T>) {
+        assert!(values.as_ptr().cast::<Aligned>().is_aligned());
+        assert!(values.padded_bytes().len().is_multiple_of(ALIGNMENT));
+        assert!(
+            values.padded_bytes()[mem::size_of_val::<[T]>(values)..]
+                .iter()
+                .all(|byte| *byte == 0)
+        );
+    }
+
+    #[test]
+    fn growth_keeps_alignment_and_padding() {
+        let mut values = AlignedVec::<u8>::new();
+        assert_aligned(&values);
+        for value in 1..=200 {
+            values.push(value);
+            assert_aligned(&values);
+        }
+        assert_eq!(values.padded_bytes().len(), 256);
+        CollectionRealloc::truncate(&mut values, 65);
+        assert_aligned(&values);
+        assert_eq!(values.padded_bytes().len(), 128);
+        values.extend([1; 10]);
+        assert_eq!(
+            values[60..],
+            [61, 62, 63, 64, 65, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1]
+        );
+        assert_aligned(&values);
+    }
+
+    #[test]
+    fn wide_values() {
+        let values = (0..5)
+            .map(|value| FixedSizeArray::from([value; 25]))
+            .collect::<AlignedVec<FixedSizeArray<u8, 25>>>();
+        assert_aligned(&values);
+        assert_eq!(values.capacity(), 5);
+        assert_eq!(values.clone(), values);
+        let wider = values
+            .iter()
+            .chain(&*values)
+            .copied()
+            .collect::<AlignedVec<_>>();
+        assert_aligned(&wider);
+        assert_eq!(wider.len(), 10);
+    }
+
+    #[test]
+    fn zero_sized_values() {
+        let mut values = AlignedVec::<FixedSizeArray<u32, 0>>::new();
+        values.extend([FixedSizeArray::from([]); 3]);
+        assert_eq!(values.len(), 3);
+        assert_eq!(values.capacity(), usize::MAX);
+        assert!(values.padded_bytes().is_empty());
+    }
+
+    #[test]
+    fn capacity_overflow() {
+        let mut values = [1_u64, 2].into_iter().collect::<AlignedVec<_>>();
+        assert_eq!(
+            <AlignedVec<u64> as CollectionAllocIn>::try_with_capacity_in(usize::MAX, ()).err(),
+            Some(AllocError)
+        );
+        assert_eq!(
+            CollectionRealloc::try_reserve(&mut values, usize::MAX),
+            Err(AllocError)
+        );
+        assert_eq!(
+            CollectionRealloc::try_extend(&mut values, std::iter::repeat_n(3, usize::MAX)),
+            Err(AllocError)
+        );
+        assert_eq!(*values, [1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity overflow")]
+    fn reserve_capacity_overflow_panics() {
+        let mut values = <AlignedVec<u32> as CollectionAlloc>::with_capacity(1);
+        CollectionRealloc::reserve(&mut values, usize::MAX);
+    }
+
+    #[test]
+    fn arrays_write_to_ipc_streams() {
+        type Row = (u16, Option<String>, Vec<Option<i64>>);
+        let rows: [Row; 3] = [
+            (1, Some("a".to_owned()), vec![Some(1), None]),
+            (2, None, vec![]),
+            (3, Some("bc".to_owned()), vec![Some(3)]),
+        ];
+        let mut array = rows[..2]
+            .iter()
+            .cloned()
+            .collect::<Array<Row, AlignedBuffer>>();
+        array.extend([rows[2].clone()]);
+        assert_aligned(array.buffer_ref().buffer_ref().0.buffer_ref());
+
+        let mut writer = StreamWriter::<_, Row>::try_new(Vec::new()).expect("write schema");
+        writer.write(&array).expect("write batch");
+        let bytes = writer.finish().expect("finish stream");
+        let batches = StreamReader::<_, Row>::try_new(bytes.as_slice())
+            .expect("read schema")
+            .map(|batch| {
+                batch
+                    .expect("read batch")
+                    .into_iter_owned()
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(batches, [rows]);
+    }
+}
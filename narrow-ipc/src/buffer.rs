@@ -0,0 +1,281 @@
+//! Storage for buffers in IPC message bodies.
+
+use std::{
+    borrow::Borrow,
+    fmt,
+    io::{self, Read},
+    iter::{self, Map},
+    marker::PhantomData,
+    mem,
+    ops::Deref,
+    slice,
+    sync::Arc,
+};
+
+use narrow::{
+    buffer::Buffer,
+    collection::{Collection, slice::SliceIntoIter, view::AsView},
+    fixed_size::FixedSize,
+    length::Length,
+};
+
+/// Storage for buffers that share the body of an IPC message.
+///
+/// Reading a message body allocates it once, aligned to 64 bytes. Every
+/// column buffer in it is then an [`IpcSlice`] that shares the body instead
+/// of copying its values. The body is released when the last array that
+/// references it is dropped.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{array::Array, collection::Collection};
+/// use narrow_ipc::{IpcBuffer, StreamReader, StreamWriter};
+///
+/// let mut writer = StreamWriter::<_, (u32,)>::try_new(Vec::new()).unwrap();
+/// writer.write(&[(1,), (2,)].into_iter().collect::<Array<_>>()).unwrap();
+/// let bytes = writer.finish().unwrap();
+///
+/// let mut reader = StreamReader::<_, (u32,)>::try_new(bytes.as_slice()).unwrap();
+/// let batch: Array<(u32,), IpcBuffer> = reader.next().unwrap().unwrap();
+/// assert_eq!(batch.into_iter_owned().collect::<Vec<_>>(), [(1,), (2,)]);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct IpcBuffer;
+impl Buffer for IpcBuffer {
+    type For<T: FixedSize> = IpcSlice<T>;
+}
+
+/// A block of a message body, which aligns the body to 64 bytes.
+#[derive(Clone, Copy)]
+#[repr(C, align(64))]
+struct Block([u8; 64]);
+
+/// The body of an IPC message, shared by the buffers read from it.
+#[derive(Clone)]
+pub struct MessageBody {
+    /// Blocks that contain the body.
+    blocks: Arc<[Block]>,
+    /// Number of bytes in the body.
+    len: usize,
+}
+
+impl MessageBody {
+    /// Reads a body of `len` bytes.
+    pub(crate) fn read<R: Read>(reader: &mut R, len: usize) -> io::Result<Self> {
+        let block_size = mem::size_of::<Block>();
+        let mut blocks =
+            iter::repeat_n(Block([0; 64]), len.div_ceil(block_size)).collect::<Arc<_>>();
+        let blocks_mut = Arc::get_mut(&mut blocks).expect("new body is not shared");
+        // SAFETY: Blocks are arrays of bytes without padding, so the blocks
+        // are `size_of::<Block>() * blocks_mut.len()` initialized bytes that
+        // are borrowed mutably for the lifetime of `bytes`.
+        let bytes = unsafe {
+            slice::from_raw_parts_mut(
+                blocks_mut.as_mut_ptr().cast::<u8>(),
+                block_size.strict_mul(blocks_mut.len()),
+            )
+        };
+        reader.read_exact(&mut bytes[..len])?;
+        Ok(Self { blocks, len })
+    }
+
+    /// Returns a body containing a copy of `bytes`.
+    pub(crate) fn copy_from(bytes: &[u8]) -> Self {
+        Self::read(&mut &*bytes, bytes.len()).expect("reading from a slice of its own length")
+    }
+
+    /// Returns the bytes of the body.
+    pub(crate) fn bytes(&self) -> &[u8] {
+        // SAFETY: Blocks are arrays of bytes without padding, and the body
+        // was initialized with at least `len` bytes.
+        unsafe { slice::from_raw_parts(self.blocks.as_ptr().cast::<u8>(), self.len) }
+    }
+}
+
+impl fmt::Debug for MessageBody {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MessageBody")
+            .field("len", &self.len)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A buffer of `T` in the body of an IPC message.
+///
+/// Cloning an [`IpcSlice`] shares the body instead of copying the values.
+/// A buffer that is not aligned for `T` in the body, which the IPC format
+/// permits for types wider than 8 bytes, is copied into a body of its own.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{array::Array, buffer::BufferRef};
+/// use narrow_ipc::{IpcBuffer, IpcSlice, StreamReader, StreamWriter};
+///
+/// let mut writer = StreamWriter::<_, (i64,)>::try_new(Vec::new()).unwrap();
+/// writer.write(&[(1,), (2,)].into_iter().collect::<Array<_>>()).unwrap();
+/// let bytes = writer.finish().unwrap();
+///
+/// let mut reader = StreamReader::<_, (i64,)>::try_new(bytes.as_slice()).unwrap();
+/// let batch = reader.next().unwrap().unwrap();
+/// let (column,) = batch.buffer_ref().buffer_ref();
+/// let values: &IpcSlice<i64> = column.buffer_ref();
+/// assert_eq!(**values, [1, 2]);
+/// ```
+pub struct IpcSlice<T> {
+    /// Body that contains the buffer.
+    body: MessageBody,
+    /// Byte offset of the first value in the body.
+    offset: usize,
+    /// Number of values in the buffer.
+    len: usize,
+    /// Type of the values.
+    _values: PhantomData<T>,
+}
+
+impl<T: FixedSize> IpcSlice<T> {
+    /// Constructs an [`IpcSlice`] of `len` values at byte `offset` in `body`.
+    ///
+    /// The bytes are reinterpreted as values without validation, which
+    /// [`FixedSize`] permits.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the values do not fit in the body.
+    pub(crate) fn new(body: &MessageBody, offset: usize, len: usize) -> Self {
+        let end = len
+            .checked_mul(mem::size_of::<T>())
+            .and_then(|bytes| bytes.checked_add(offset))
+            .filter(|end| *end <= body.len)
+            .expect("buffer is within the message body");
+        if offset.is_multiple_of(mem::align_of::<T>()) {
+            Self {
+                body: body.clone(),
+                offset,
+                len,
+                _values: PhantomData,
+            }
+        } else {
+            Self {
+                body: MessageBody::copy_from(&body.bytes()[offset..end]),
+                offset: 0,
+                len,
+                _values: PhantomData,
+            }
+        }
+    }
+}
+
+impl<T: FixedSize> Deref for IpcSlice<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        let values = self.body.bytes()[self.offset..].as_ptr().cast::<T>();
+        // SAFETY: The constructor guarantees `len` values at an aligned
+        // offset in the initialized body, and `FixedSize` guarantees that any
+        // initialized bytes are a valid `T`.
+        unsafe { slice::from_raw_parts(values, self.len) }
+    }
+}
+
+impl<T: FixedSize> Borrow<[T]> for IpcSlice<T> {
+    fn borrow(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T> Clone for IpcSlice<T> {
+    fn clone(&self) -> Self {
+        Self {
+            body: self.body.clone(),
+            offset: self.offset,
+            len: self.len,
+            _values: PhantomData,
+        }
+    }
+}
+
+impl<T: FixedSize + fmt::Debug> fmt::Debug for IpcSlice<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> Length for IpcSlice<T> {
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<T: FixedSize + for<'any> AsView<'any>> Collection for IpcSlice<T> {
+    type View<'collection>
+        = <T as AsView<'collection>>::View
+    where
+        Self: 'collection;
+
+    type Owned = T;
+
+    fn view(&self, index: usize) -> Option<Self::View<'_>> {
+        self.get(index).map(AsView::as_view)
+    }
+
+    type Iter<'collection>
+        = Map<slice::Iter<'collection, T>, fn(&'collection T) -> <T as AsView<'collection>>::View>
+    where
+        Self: 'collection;
+
+    fn iter_views(&self) -> Self::Iter<'_> {
+        self.iter().map(AsView::as_view)
+    }
+
+    type IntoIter = SliceIntoIter<Self, T>;
+
+    fn into_iter_owned(self) -> Self::IntoIter {
+        self.into()
+    }
+}
+
+/// Returns the bytes of fixed-size `values`, in native byte order.
+pub(crate) fn bytes_of<T: FixedSize>(values: &[T]) -> &[u8] {
+    // SAFETY: `FixedSize` guarantees that `T` has no padding, so every byte
+    // of `values` is initialized.
+    unsafe { slice::from_raw_parts(values.as_ptr().cast::<u8>(), mem::size_of_val(values)) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aligned_buffers_share_the_body() {
+        let bytes = [1_u32, 2, 3].map(u32::to_ne_bytes).concat();
+        let body = MessageBody::read(&mut bytes.as_slice(), bytes.len()).expect("read body");
+        let values = IpcSlice::<u32>::new(&body, 4, 2);
+        assert_eq!(*values, [2, 3]);
+        assert!(Arc::ptr_eq(&values.body.blocks, &body.blocks));
+    }
+
+    #[test]
+    fn misaligned_buffers_are_copied() {
+        let bytes = [0, 1_u16, 2, 0].map(u16::to_ne_bytes).concat();
+        let body = MessageBody::read(&mut bytes.as_slice(), bytes.len()).expect("read body");
+        let values = IpcSlice::<u32>::new(&body, 2, 1);
+        assert_eq!(
+            values.first().map(|value| value.to_ne_bytes()),
+            Some(
+                [1_u16.to_ne_bytes(), 2_u16.to_ne_bytes()]
+                    .concat()
+                    .try_into()
+                    .expect("value fits")
+            )
+        );
+        assert!(!Arc::ptr_eq(&values.body.blocks, &body.blocks));
+    }
+
+    #[test]
+    fn empty_body() {
+        let body = MessageBody::read(&mut [].as_slice(), 0).expect("read body");
+        assert!(IpcSlice::<u64>::new(&body, 0, 0).is_empty());
+    }
+}
@@ -0,0 +1,993 @@
+//! Flatbuffer tables of the Arrow IPC format.
+//!
+//! These are hand-written counterparts of the code `flatc` generates from
+//! `Message.fbs`, `Schema.fbs` and `File.fbs`, limited to the tables and fields this crate
+//! reads or writes. Tables are only constructed by [`root`], after the
+//! verifier checked every field that is accessed, so the accessors can follow
+//! offsets without further bounds checks.
+
+use flatbuffers::{
+    FlatBufferBuilder, Follow, ForwardsUOffset, InvalidFlatbuffer, Push, SimpleToVerifyInSlice,
+    Table, TableFinishedWIPOffset, UnionWIPOffset, VOffsetT, Vector, Verifiable, Verifier,
+    VerifierOptions, WIPOffset,
+};
+
+/// Marker that precedes the length of every encapsulated message.
+pub(crate) const CONTINUATION: [u8; 4] = [0xFF; 4];
+
+/// Alignment of message metadata and bodies in a stream.
+pub(crate) const ALIGNMENT: usize = 8;
+
+/// Magic bytes at the start and end of an IPC file.
+pub(crate) const MAGIC: [u8; 6] = *b"ARROW1";
+
+/// `MetadataVersion::V4`, the oldest version with the current buffer layout.
+pub(crate) const METADATA_VERSION_V4: i16 = 3;
+/// `MetadataVersion::V5`, written by this crate.
+pub(crate) const METADATA_VERSION_V5: i16 = 4;
+
+/// `MessageHeader::Schema`.
+pub(crate) const HEADER_SCHEMA: u8 = 1;
+/// `MessageHeader::DictionaryBatch`.
+pub(crate) const HEADER_DICTIONARY_BATCH: u8 = 2;
+/// `MessageHeader::RecordBatch`.
+pub(crate) const HEADER_RECORD_BATCH: u8 = 3;
+
+/// `Endianness::Little`.
+pub(crate) const ENDIANNESS_LITTLE: i16 = 0;
+/// `Endianness::Big`.
+pub(crate) const ENDIANNESS_BIG: i16 = 1;
+/// The endianness of this platform.
+pub(crate) const ENDIANNESS_NATIVE: i16 = if cfg!(target_endian = "big") {
+    ENDIANNESS_BIG
+} else {
+    ENDIANNESS_LITTLE
+};
+
+/// `Type::Int`.
+pub(crate) const TYPE_INT: u8 = 2;
+/// `Type::FloatingPoint`.
+pub(crate) const TYPE_FLOATING_POINT: u8 = 3;
+/// `Type::Utf8`.
+pub(crate) const TYPE_UTF8: u8 = 5;
+/// `Type::Bool`.
+pub(crate) const TYPE_BOOL: u8 = 6;
+/// `Type::List`.
+pub(crate) const TYPE_LIST: u8 = 12;
+/// `Type::Struct_`.
+pub(crate) const TYPE_STRUCT: u8 = 13;
+/// `Type::FixedSizeList`.
+pub(crate) const TYPE_FIXED_SIZE_LIST: u8 = 16;
+/// `Type::LargeUtf8`.
+pub(crate) const TYPE_LARGE_UTF8: u8 = 20;
+/// `Type::LargeList`.
+pub(crate) const TYPE_LARGE_LIST: u8 = 21;
+
+/// `Precision::HALF`.
+pub(crate) const PRECISION_HALF: i16 = 0;
+/// `Precision::SINGLE`.
+pub(crate) const PRECISION_SINGLE: i16 = 1;
+/// `Precision::DOUBLE`.
+pub(crate) const PRECISION_DOUBLE: i16 = 2;
+
+/// Verifies `bytes` as a flatbuffer with a root table `T`.
+pub(crate) fn root<'buf, T>(bytes: &'buf [u8]) -> Result<T, InvalidFlatbuffer>
+where
+    T: Follow<'buf, Inner = T> + Verifiable,
+{
+    let options = VerifierOptions::default();
+    let mut verifier = Verifier::new(&options, bytes);
+    <ForwardsUOffset<T>>::run_verifier(&mut verifier, 0)?;
+    // SAFETY: The buffer was verified to contain a root `T`.
+    Ok(unsafe { <ForwardsUOffset<T>>::follow(bytes, 0) })
+}
+
+/// Declares a verified table wrapper.
+macro_rules! table {
+    ($(#[$attr:meta])* $name:ident) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug)]
+        pub(crate) struct $name<'buf>(Table<'buf>);
+
+        impl<'buf> Follow<'buf> for $name<'buf> {
+            type Inner = Self;
+
+            unsafe fn follow(buf: &'buf [u8], loc: usize) -> Self {
+                // SAFETY: The caller guarantees a table at `loc`.
+                Self(unsafe { Table::new(buf, loc) })
+            }
+        }
+    };
+}
+
+/// Reads the field at `slot` of a verified table.
+macro_rules! field {
+    ($table:expr, $ty:ty, $slot:expr, $default:expr) => {
+        // SAFETY: The table was verified, including the field at `slot`.
+        unsafe { $table.get::<$ty>($slot, $default) }
+    };
+}
+
+table!(
+    /// A table whose fields are never accessed.
+    #[expect(dead_code, reason = "unused union variants are only verified")]
+    Opaque
+);
+
+impl Verifiable for Opaque<'_> {
+    fn run_verifier(
+        verifier: &mut Verifier<'_, '_>,
+        position: usize,
+    ) -> Result<(), InvalidFlatbuffer> {
+        verifier.visit_table(position)?.finish();
+        Ok(())
+    }
+}
+
+table!(
+    /// An encapsulated IPC message.
+    Message
+);
+
+impl<'buf> Message<'buf> {
+    /// Slot of the metadata version.
+    pub(crate) const VT_VERSION: VOffsetT = 4;
+    /// Slot of the header type.
+    pub(crate) const VT_HEADER_TYPE: VOffsetT = 6;
+    /// Slot of the header table.
+    pub(crate) const VT_HEADER: VOffsetT = 8;
+    /// Slot of the body length.
+    pub(crate) const VT_BODY_LENGTH: VOffsetT = 10;
+
+    /// Returns the metadata version.
+    pub(crate) fn version(&self) -> i16 {
+        field!(self.0, i16, Self::VT_VERSION, Some(0)).unwrap_or_default()
+    }
+
+    /// Returns the type of the header.
+    pub(crate) fn header_type(&self) -> u8 {
+        field!(self.0, u8, Self::VT_HEADER_TYPE, Some(0)).unwrap_or_default()
+    }
+
+    /// Returns the number of body bytes following the metadata.
+    pub(crate) fn body_length(&self) -> i64 {
+        field!(self.0, i64, Self::VT_BODY_LENGTH, Some(0)).unwrap_or_default()
+    }
+
+    /// Returns the header table.
+    fn header(&self) -> Option<Table<'buf>> {
+        field!(self.0, ForwardsUOffset<Table<'buf>>, Self::VT_HEADER, None)
+    }
+
+    /// Returns the header if it is a schema.
+    pub(crate) fn header_as_schema(&self) -> Option<Schema<'buf>> {
+        (self.header_type() == HEADER_SCHEMA)
+            .then(|| self.header().map(Schema))
+            .flatten()
+    }
+
+    /// Returns the header if it is a dictionary batch.
+    pub(crate) fn header_as_dictionary_batch(&self) -> Option<DictionaryBatch<'buf>> {
+        (self.header_type() == HEADER_DICTIONARY_BATCH)
+            .then(|| self.header().map(DictionaryBatch))
+            .flatten()
+    }
+
+    /// Returns the header if it is a record batch.
+    pub(crate) fn header_as_record_batch(&self) -> Option<RecordBatch<'buf>> {
+        (self.header_type() == HEADER_RECORD_BATCH)
+            .then(|| self.header().map(RecordBatch))
+            .flatten()
+    }
+
+    /// Builds a message table.
+    pub(crate) fn create(
+        fbb: &mut FlatBufferBuilder<'_>,
+        header_type: u8,
+        header: WIPOffset<UnionWIPOffset>,
+        body_length: i64,
+    ) -> WIPOffset<TableFinishedWIPOffset> {
+        let start = fbb.start_table();
+        fbb.push_slot::<i64>(Self::VT_BODY_LENGTH, body_length, 0);
+        fbb.push_slot_always(Self::VT_HEADER, header);
+        fbb.push_slot::<i16>(Self::VT_VERSION, METADATA_VERSION_V5, 0);
+        fbb.push_slot::<u8>(Self::VT_HEADER_TYPE, header_type, 0);
+        fbb.end_table(start)
+    }
+}
+
+impl Verifiable for Message<'_> {
+    fn run_verifier(
+        verifier: &mut Verifier<'_, '_>,
+        position: usize,
+    ) -> Result<(), InvalidFlatbuffer> {
+        verifier
+            .visit_table(position)?
+            .visit_field::<i16>("version", Self::VT_VERSION, false)?
+            .visit_union::<u8, _>(
+                "header_type",
+                Self::VT_HEADER_TYPE,
+                "header",
+                Self::VT_HEADER,
+                false,
+                |key, v, pos| match key {
+                    HEADER_SCHEMA => {
+                        v.verify_union_variant::<ForwardsUOffset<Schema<'_>>>("Schema", pos)
+                    }
+                    HEADER_DICTIONARY_BATCH => v
+                        .verify_union_variant::<ForwardsUOffset<DictionaryBatch<'_>>>(
+                            "DictionaryBatch",
+                            pos,
+                        ),
+                    HEADER_RECORD_BATCH => v
+                        .verify_union_variant::<ForwardsUOffset<RecordBatch<'_>>>(
+                            "RecordBatch",
+                            pos,
+                        ),
+                    _ => v.verify_union_variant::<ForwardsUOffset<Opaque<'_>>>("Opaque", pos),
+                },
+            )?
+            .visit_field::<i64>("bodyLength", Self::VT_BODY_LENGTH, false)?
+            .finish();
+        Ok(())
+    }
+}
+
+table!(
+    /// The schema of a stream.
+    Schema
+);
+
+impl<'buf> Schema<'buf> {
+    /// Slot of the endianness.
+    pub(crate) const VT_ENDIANNESS: VOffsetT = 4;
+    /// Slot of the fields.
+    pub(crate) const VT_FIELDS: VOffsetT = 6;
+
+    /// Returns the endianness of the buffers.
+    pub(crate) fn endianness(&self) -> i16 {
+        field!(self.0, i16, Self::VT_ENDIANNESS, Some(0)).unwrap_or_default()
+    }
+
+    /// Returns the top-level fields.
+    pub(crate) fn fields(&self) -> Option<Vector<'buf, ForwardsUOffset<Field<'buf>>>> {
+        field!(
+            self.0,
+            ForwardsUOffset<Vector<'buf, ForwardsUOffset<Field<'buf>>>>,
+            Self::VT_FIELDS,
+            None
+        )
+    }
+
+    /// Builds a schema table.
+    pub(crate) fn create(
+        fbb: &mut FlatBufferBuilder<'_>,
+        endianness: i16,
+        fields: &[WIPOffset<TableFinishedWIPOffset>],
+    ) -> WIPOffset<TableFinishedWIPOffset> {
+        let field_vector = fbb.create_vector(fields);
+        let start = fbb.start_table();
+        fbb.push_slot_always(Self::VT_FIELDS, field_vector);
+        fbb.push_slot::<i16>(Self::VT_ENDIANNESS, endianness, ENDIANNESS_LITTLE);
+        fbb.end_table(start)
+    }
+}
+
+impl Verifiable for Schema<'_> {
+    fn run_verifier(
+        verifier: &mut Verifier<'_, '_>,
+        position: usize,
+    ) -> Result<(), InvalidFlatbuffer> {
+        verifier
+            .visit_table(position)?
+            .visit_field::<i16>("endianness", Self::VT_ENDIANNESS, false)?
+            .visit_field::<ForwardsUOffset<Vector<'_, ForwardsUOffset<Field<'_>>>>>(
+                "fields",
+                Self::VT_FIELDS,
+                false,
+            )?
+            .finish();
+        Ok(())
+    }
+}
+
+table!(
+    /// A field of a schema.
+    Field
+);
+
+impl<'buf> Field<'buf> {
+    /// Slot of the name.
+    pub(crate) const VT_NAME: VOffsetT = 4;
+    /// Slot of the nullable flag.
+    pub(crate) const VT_NULLABLE: VOffsetT = 6;
+    /// Slot of the type discriminant.
+    pub(crate) const VT_TYPE_TYPE: VOffsetT = 8;
+    /// Slot of the type table.
+    pub(crate) const VT_TYPE: VOffsetT = 10;
+    /// Slot of the dictionary encoding.
+    pub(crate) const VT_DICTIONARY: VOffsetT = 12;
+    /// Slot of the child fields.
+    pub(crate) const VT_CHILDREN: VOffsetT = 14;
+
+    /// Returns the name.
+    pub(crate) fn name(&self) -> Option<&'buf str> {
+        field!(self.0, ForwardsUOffset<&str>, Self::VT_NAME, None)
+    }
+
+    /// Returns whether the field is nullable.
+    pub(crate) fn nullable(&self) -> bool {
+        field!(self.0, bool, Self::VT_NULLABLE, Some(false)).unwrap_or_default()
+    }
+
+    /// Returns the type discriminant.
+    pub(crate) fn type_type(&self) -> u8 {
+        field!(self.0, u8, Self::VT_TYPE_TYPE, Some(0)).unwrap_or_default()
+    }
+
+    /// Returns the type table.
+    fn type_table(&self) -> Option<Table<'buf>> {
+        field!(self.0, ForwardsUOffset<Table<'buf>>, Self::VT_TYPE, None)
+    }
+
+    /// Returns the type if it is an integer.
+    pub(crate) fn type_as_int(&self) -> Option<Int<'buf>> {
+        (self.type_type() == TYPE_INT)
+            .then(|| self.type_table().map(Int))
+            .flatten()
+    }
+
+    /// Returns the type if it is a floating point number.
+    pub(crate) fn type_as_floating_point(&self) -> Option<FloatingPoint<'buf>> {
+        (self.type_type() == TYPE_FLOATING_POINT)
+            .then(|| self.type_table().map(FloatingPoint))
+            .flatten()
+    }
+
+    /// Returns the type if it is a fixed-size list.
+    pub(crate) fn type_as_fixed_size_list(&self) -> Option<FixedSizeList<'buf>> {
+        (self.type_type() == TYPE_FIXED_SIZE_LIST)
+            .then(|| self.type_table().map(FixedSizeList))
+            .flatten()
+    }
+
+    /// Returns the dictionary encoding.
+    pub(crate) fn dictionary(&self) -> Option<DictionaryEncoding<'buf>> {
+        field!(
+            self.0,
+            ForwardsUOffset<DictionaryEncoding<'buf>>,
+            Self::VT_DICTIONARY,
+            None
+        )
+    }
+
+    /// Returns the child fields.
+    pub(crate) fn children(&self) -> Option<Vector<'buf, ForwardsUOffset<Field<'buf>>>> {
+        field!(
+            self.0,
+            ForwardsUOffset<Vector<'buf, ForwardsUOffset<Field<'buf>>>>,
+            Self::VT_CHILDREN,
+            None
+        )
+    }
+
+    /// Builds a field table.
+    pub(crate) fn create(
+        fbb: &mut FlatBufferBuilder<'_>,
+        name: &str,
+        nullable: bool,
+        type_type: u8,
+        type_table: WIPOffset<UnionWIPOffset>,
+        dictionary: Option<WIPOffset<TableFinishedWIPOffset>>,
+        children: &[WIPOffset<TableFinishedWIPOffset>],
+    ) -> WIPOffset<TableFinishedWIPOffset> {
+        let name_string = fbb.create_string(name);
+        let child_vector = fbb.create_vector(children);
+        let start = fbb.start_table();
+        fbb.push_slot_always(Self::VT_NAME, name_string);
+        fbb.push_slot_always(Self::VT_TYPE, type_table);
+        if let Some(encoding) = dictionary {
+            fbb.push_slot_always(Self::VT_DICTIONARY, encoding);
+        }
+        fbb.push_slot_always(Self::VT_CHILDREN, child_vector);
+        fbb.push_slot::<u8>(Self::VT_TYPE_TYPE, type_type, 0);
+        fbb.push_slot::<bool>(Self::VT_NULLABLE, nullable, false);
+        fbb.end_table(start)
+    }
+}
+
+impl Verifiable for Field<'_> {
+    fn run_verifier(
+        verifier: &mut Verifier<'_, '_>,
+        position: usize,
+    ) -> Result<(), InvalidFlatbuffer> {
+        verifier
+            .visit_table(position)?
+            .visit_field::<ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+            .visit_field::<bool>("nullable", Self::VT_NULLABLE, false)?
+            .visit_union::<u8, _>(
+                "type_type",
+                Self::VT_TYPE_TYPE,
+                "type",
+                Self::VT_TYPE,
+                false,
+                |key, v, pos| match key {
+                    TYPE_INT => v.verify_union_variant::<ForwardsUOffset<Int<'_>>>("Int", pos),
+                    TYPE_FLOATING_POINT => v
+                        .verify_union_variant::<ForwardsUOffset<FloatingPoint<'_>>>(
+                            "FloatingPoint",
+                            pos,
+                        ),
+                    TYPE_FIXED_SIZE_LIST => v
+                        .verify_union_variant::<ForwardsUOffset<FixedSizeList<'_>>>(
+                            "FixedSizeList",
+                            pos,
+                        ),
+                    _ => v.verify_union_variant::<ForwardsUOffset<Opaque<'_>>>("Opaque", pos),
+                },
+            )?
+            .visit_field::<ForwardsUOffset<DictionaryEncoding<'_>>>(
+                "dictionary",
+                Self::VT_DICTIONARY,
+                false,
+            )?
+            .visit_field::<ForwardsUOffset<Vector<'_, ForwardsUOffset<Field<'_>>>>>(
+                "children",
+                Self::VT_CHILDREN,
+                false,
+            )?
+            .finish();
+        Ok(())
+    }
+}
+
+table!(
+    /// The dictionary encoding of a field.
+    DictionaryEncoding
+);
+
+impl<'buf> DictionaryEncoding<'buf> {
+    /// Slot of the dictionary id.
+    pub(crate) const VT_ID: VOffsetT = 4;
+    /// Slot of the index type.
+    pub(crate) const VT_INDEX_TYPE: VOffsetT = 6;
+    /// Slot of the ordered flag.
+    pub(crate) const VT_IS_ORDERED: VOffsetT = 8;
+
+    /// Returns the dictionary id.
+    pub(crate) fn id(&self) -> i64 {
+        field!(self.0, i64, Self::VT_ID, Some(0)).unwrap_or_default()
+    }
+
+    /// Returns the index type, which defaults to a signed 32-bit integer.
+    pub(crate) fn index_type(&self) -> Option<Int<'buf>> {
+        field!(
+            self.0,
+            ForwardsUOffset<Int<'buf>>,
+            Self::VT_INDEX_TYPE,
+            None
+        )
+    }
+
+    /// Returns whether the dictionary values are ordered.
+    pub(crate) fn is_ordered(&self) -> bool {
+        field!(self.0, bool, Self::VT_IS_ORDERED, Some(false)).unwrap_or_default()
+    }
+
+    /// Builds a dictionary encoding table.
+    pub(crate) fn create(
+        fbb: &mut FlatBufferBuilder<'_>,
+        id: i64,
+        index_type: WIPOffset<TableFinishedWIPOffset>,
+        is_ordered: bool,
+    ) -> WIPOffset<TableFinishedWIPOffset> {
+        let start = fbb.start_table();
+        fbb.push_slot::<i64>(Self::VT_ID, id, 0);
+        fbb.push_slot_always(Self::VT_INDEX_TYPE, index_type);
+        fbb.push_slot::<bool>(Self::VT_IS_ORDERED, is_ordered, false);
+        fbb.end_table(start)
+    }
+}
+
+impl Verifiable for DictionaryEncoding<'_> {
+    fn run_verifier(
+        verifier: &mut Verifier<'_, '_>,
+        position: usize,
+    ) -> Result<(), InvalidFlatbuffer> {
+        verifier
+            .visit_table(position)?
+            .visit_field::<i64>("id", Self::VT_ID, false)?
+            .visit_field::<ForwardsUOffset<Int<'_>>>("indexType", Self::VT_INDEX_TYPE, false)?
+            .visit_field::<bool>("isOrdered", Self::VT_IS_ORDERED, false)?
+            .finish();
+        Ok(())
+    }
+}
+
+table!(
+    /// An integer type.
+    Int
+);
+
+impl Int<'_> {
+    /// Slot of the bit width.
+    pub(crate) const VT_BIT_WIDTH: VOffsetT = 4;
+    /// Slot of the signed flag.
+    pub(crate) const VT_IS_SIGNED: VOffsetT = 6;
+
+    /// Returns the bit width.
+    pub(crate) fn bit_width(&self) -> i32 {
+        field!(self.0, i32, Self::VT_BIT_WIDTH, Some(0)).unwrap_or_default()
+    }
+
+    /// Returns whether the integer is signed.
+    pub(crate) fn is_signed(&self) -> bool {
+        field!(self.0, bool, Self::VT_IS_SIGNED, Some(false)).unwrap_or_default()
+    }
+
+    /// Builds an integer type table.
+    pub(crate) fn create(
+        fbb: &mut FlatBufferBuilder<'_>,
+        bit_width: i32,
+        is_signed: bool,
+    ) -> WIPOffset<TableFinishedWIPOffset> {
+        let start = fbb.start_table();
+        fbb.push_slot::<i32>(Self::VT_BIT_WIDTH, bit_width, 0);
+        fbb.push_slot::<bool>(Self::VT_IS_SIGNED, is_signed, false);
+        fbb.end_table(start)
+    }
+}
+
+impl Verifiable for Int<'_> {
+    fn run_verifier(
+        verifier: &mut Verifier<'_, '_>,
+        position: usize,
+    ) -> Result<(), InvalidFlatbuffer> {
+        verifier
+            .visit_table(position)?
+            .visit_field::<i32>("bitWidth", Self::VT_BIT_WIDTH, false)?
+            .visit_field::<bool>("is_signed", Self::VT_IS_SIGNED, false)?
+            .finish();
+        Ok(())
+    }
+}
+
+table!(
+    /// A floating point type.
+    FloatingPoint
+);
+
+impl FloatingPoint<'_> {
+    /// Slot of the precision.
+    pub(crate) const VT_PRECISION: VOffsetT = 4;
+
+    /// Returns the precision.
+    pub(crate) fn precision(&self) -> i16 {
+        field!(self.0, i16, Self::VT_PRECISION, Some(0)).unwrap_or_default()
+    }
+
+    /// Builds a floating point type table.
+    pub(crate) fn create(
+        fbb: &mut FlatBufferBuilder<'_>,
+        precision: i16,
+    ) -> WIPOffset<TableFinishedWIPOffset> {
+        let start = fbb.start_table();
+        fbb.push_slot::<i16>(Self::VT_PRECISION, precision, PRECISION_HALF);
+        fbb.end_table(start)
+    }
+}
+
+impl Verifiable for FloatingPoint<'_> {
+    fn run_verifier(
+        verifier: &mut Verifier<'_, '_>,
+        position: usize,
+    ) -> Result<(), InvalidFlatbuffer> {
+        verifier
+            .visit_table(position)?
+            .visit_field::<i16>("precision", Self::VT_PRECISION, false)?
+            .finish();
+        Ok(())
+    }
+}
+
+table!(
+    /// A fixed-size list type.
+    FixedSizeList
+);
+
+impl FixedSizeList<'_> {
+    /// Slot of the list size.
+    pub(crate) const VT_LIST_SIZE: VOffsetT = 4;
+
+    /// Returns the number of items per list.
+    pub(crate) fn list_size(&self) -> i32 {
+        field!(self.0, i32, Self::VT_LIST_SIZE, Some(0)).unwrap_or_default()
+    }
+
+    /// Builds a fixed-size list type table.
+    pub(crate) fn create(
+        fbb: &mut FlatBufferBuilder<'_>,
+        list_size: i32,
+    ) -> WIPOffset<TableFinishedWIPOffset> {
+        let start = fbb.start_table();
+        fbb.push_slot::<i32>(Self::VT_LIST_SIZE, list_size, 0);
+        fbb.end_table(start)
+    }
+}
+
+impl Verifiable for FixedSizeList<'_> {
+    fn run_verifier(
+        verifier: &mut Verifier<'_, '_>,
+        position: usize,
+    ) -> Result<(), InvalidFlatbuffer> {
+        verifier
+            .visit_table(position)?
+            .visit_field::<i32>("listSize", Self::VT_LIST_SIZE, false)?
+            .finish();
+        Ok(())
+    }
+}
+
+/// Builds a type table without fields, like `Bool` or `Utf8`.
+pub(crate) fn create_empty_table(
+    fbb: &mut FlatBufferBuilder<'_>,
+) -> WIPOffset<TableFinishedWIPOffset> {
+    let start = fbb.start_table();
+    fbb.end_table(start)
+}
+
+table!(
+    /// The metadata of a record batch.
+    RecordBatch
+);
+
+impl<'buf> RecordBatch<'buf> {
+    /// Slot of the number of rows.
+    pub(crate) const VT_LENGTH: VOffsetT = 4;
+    /// Slot of the field nodes.
+    pub(crate) const VT_NODES: VOffsetT = 6;
+    /// Slot of the buffers.
+    pub(crate) const VT_BUFFERS: VOffsetT = 8;
+    /// Slot of the body compression.
+    pub(crate) const VT_COMPRESSION: VOffsetT = 10;
+
+    /// Returns the number of rows.
+    pub(crate) fn length(&self) -> i64 {
+        field!(self.0, i64, Self::VT_LENGTH, Some(0)).unwrap_or_default()
+    }
+
+    /// Returns the field nodes, in pre-order of the schema fields.
+    pub(crate) fn nodes(&self) -> Option<Vector<'buf, FieldNode>> {
+        field!(
+            self.0,
+            ForwardsUOffset<Vector<'buf, FieldNode>>,
+            Self::VT_NODES,
+            None
+        )
+    }
+
+    /// Returns the body regions of the buffers, in pre-order of the schema
+    /// fields.
+    pub(crate) fn buffers(&self) -> Option<Vector<'buf, BufferRegion>> {
+        field!(
+            self.0,
+            ForwardsUOffset<Vector<'buf, BufferRegion>>,
+            Self::VT_BUFFERS,
+            None
+        )
+    }
+
+    /// Returns whether the body is compressed.
+    pub(crate) fn is_compressed(&self) -> bool {
+        field!(
+            self.0,
+            ForwardsUOffset<Opaque<'buf>>,
+            Self::VT_COMPRESSION,
+            None
+        )
+        .is_some()
+    }
+
+    /// Builds a record batch table.
+    pub(crate) fn create(
+        fbb: &mut FlatBufferBuilder<'_>,
+        length: i64,
+        nodes: &[FieldNode],
+        buffers: &[BufferRegion],
+    ) -> WIPOffset<TableFinishedWIPOffset> {
+        let node_vector = fbb.create_vector(nodes);
+        let buffer_vector = fbb.create_vector(buffers);
+        let start = fbb.start_table();
+        fbb.push_slot::<i64>(Self::VT_LENGTH, length, 0);
+        fbb.push_slot_always(Self::VT_NODES, node_vector);
+        fbb.push_slot_always(Self::VT_BUFFERS, buffer_vector);
+        fbb.end_table(start)
+    }
+}
+
+impl Verifiable for RecordBatch<'_> {
+    fn run_verifier(
+        verifier: &mut Verifier<'_, '_>,
+        position: usize,
+    ) -> Result<(), InvalidFlatbuffer> {
+        verifier
+            .visit_table(position)?
+            .visit_field::<i64>("length", Self::VT_LENGTH, false)?
+            .visit_field::<ForwardsUOffset<Vector<'_, FieldNode>>>("nodes", Self::VT_NODES, false)?
+            .visit_field::<ForwardsUOffset<Vector<'_, BufferRegion>>>(
+                "buffers",
+                Self::VT_BUFFERS,
+                false,
+            )?
+            .visit_field::<ForwardsUOffset<Opaque<'_>>>("compression", Self::VT_COMPRESSION, false)?
+            .finish();
+        Ok(())
+    }
+}
+
+table!(
+    /// The metadata of a dictionary batch.
+    DictionaryBatch
+);
+
+impl<'buf> DictionaryBatch<'buf> {
+    /// Slot of the dictionary id.
+    pub(crate) const VT_ID: VOffsetT = 4;
+    /// Slot of the dictionary values.
+    pub(crate) const VT_DATA: VOffsetT = 6;
+    /// Slot of the delta flag.
+    pub(crate) const VT_IS_DELTA: VOffsetT = 8;
+
+    /// Returns the dictionary id.
+    pub(crate) fn id(&self) -> i64 {
+        field!(self.0, i64, Self::VT_ID, Some(0)).unwrap_or_default()
+    }
+
+    /// Returns the dictionary values as a single-column record batch.
+    pub(crate) fn data(&self) -> Option<RecordBatch<'buf>> {
+        field!(
+            self.0,
+            ForwardsUOffset<RecordBatch<'buf>>,
+            Self::VT_DATA,
+            None
+        )
+    }
+
+    /// Returns whether the values extend the existing dictionary.
+    pub(crate) fn is_delta(&self) -> bool {
+        field!(self.0, bool, Self::VT_IS_DELTA, Some(false)).unwrap_or_default()
+    }
+
+    /// Builds a dictionary batch table.
+    pub(crate) fn create(
+        fbb: &mut FlatBufferBuilder<'_>,
+        id: i64,
+        data: WIPOffset<TableFinishedWIPOffset>,
+    ) -> WIPOffset<TableFinishedWIPOffset> {
+        let start = fbb.start_table();
+        fbb.push_slot::<i64>(Self::VT_ID, id, 0);
+        fbb.push_slot_always(Self::VT_DATA, data);
+        fbb.end_table(start)
+    }
+}
+
+impl Verifiable for DictionaryBatch<'_> {
+    fn run_verifier(
+        verifier: &mut Verifier<'_, '_>,
+        position: usize,
+    ) -> Result<(), InvalidFlatbuffer> {
+        verifier
+            .visit_table(position)?
+            .visit_field::<i64>("id", Self::VT_ID, false)?
+            .visit_field::<ForwardsUOffset<RecordBatch<'_>>>("data", Self::VT_DATA, false)?
+            .visit_field::<bool>("isDelta", Self::VT_IS_DELTA, false)?
+            .finish();
+        Ok(())
+    }
+}
+
+/// Reads the little-endian `i64` at `loc` of a verified buffer.
+fn read_i64(buf: &[u8], loc: usize) -> i64 {
+    let bytes = buf[loc..loc.strict_add(8)]
+        .try_into()
+        .expect("slice of eight bytes");
+    i64::from_le_bytes(bytes)
+}
+
+/// Declares a flatbuffer struct of two little-endian `i64` fields.
+macro_rules! pair_struct {
+    ($(#[$attr:meta])* $name:ident { $(#[$first_attr:meta])* $first:ident, $(#[$second_attr:meta])* $second:ident }) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub(crate) struct $name {
+            $(#[$first_attr])*
+            pub(crate) $first: i64,
+            $(#[$second_attr])*
+            pub(crate) $second: i64,
+        }
+
+        impl Follow<'_> for $name {
+            type Inner = Self;
+
+            unsafe fn follow(buf: &[u8], loc: usize) -> Self {
+                Self {
+                    $first: read_i64(buf, loc),
+                    $second: read_i64(buf, loc.strict_add(8)),
+                }
+            }
+        }
+
+        impl Push for $name {
+            type Output = Self;
+
+            unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+                dst[..8].copy_from_slice(&self.$first.to_le_bytes());
+                dst[8..16].copy_from_slice(&self.$second.to_le_bytes());
+            }
+        }
+
+        impl Verifiable for $name {
+            fn run_verifier(verifier: &mut Verifier<'_, '_>, position: usize) -> Result<(), InvalidFlatbuffer> {
+                verifier.in_buffer::<Self>(position)
+            }
+        }
+
+        impl SimpleToVerifyInSlice for $name {}
+    };
+}
+
+pair_struct!(
+    /// The length and null count of an array in a record batch.
+    FieldNode {
+        /// Number of items.
+        length,
+        /// Number of null items.
+        null_count
+    }
+);
+
+pair_struct!(
+    /// The location of a buffer in a message body, the `Buffer` struct of
+    /// the format.
+    BufferRegion {
+        /// Byte offset from the start of the body.
+        offset,
+        /// Number of bytes, excluding padding.
+        length
+    }
+);
+
+/// The location of an encapsulated message in an IPC file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Block {
+    /// Byte offset of the message from the start of the file.
+    pub(crate) offset: i64,
+    /// Number of metadata bytes, including the prefix and padding.
+    pub(crate) meta_data_length: i32,
+    /// Number of body bytes.
+    pub(crate) body_length: i64,
+}
+
+impl Follow<'_> for Block {
+    type Inner = Self;
+
+    unsafe fn follow(buf: &[u8], loc: usize) -> Self {
+        let meta_data_length = buf[loc.strict_add(8)..loc.strict_add(12)]
+            .try_into()
+            .expect("slice of four bytes");
+        Self {
+            offset: read_i64(buf, loc),
+            meta_data_length: i32::from_le_bytes(meta_data_length),
+            body_length: read_i64(buf, loc.strict_add(16)),
+        }
+    }
+}
+
+impl Push for Block {
+    type Output = Self;
+
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        dst[..8].copy_from_slice(&self.offset.to_le_bytes());
+        dst[8..12].copy_from_slice(&self.meta_data_length.to_le_bytes());
+        dst[12..16].fill(0);
+        dst[16..24].copy_from_slice(&self.body_length.to_le_bytes());
+    }
+}
+
+impl Verifiable for Block {
+    fn run_verifier(
+        verifier: &mut Verifier<'_, '_>,
+        position: usize,
+    ) -> Result<(), InvalidFlatbuffer> {
+        verifier.in_buffer::<Self>(position)
+    }
+}
+
+impl SimpleToVerifyInSlice for Block {}
+
+table!(
+    /// The footer of an IPC file.
+    Footer
+);
+
+impl<'buf> Footer<'buf> {
+    /// Slot of the metadata version.
+    pub(crate) const VT_VERSION: VOffsetT = 4;
+    /// Slot of the schema.
+    pub(crate) const VT_SCHEMA: VOffsetT = 6;
+    /// Slot of the dictionary batch blocks.
+    pub(crate) const VT_DICTIONARIES: VOffsetT = 8;
+    /// Slot of the record batch blocks.
+    pub(crate) const VT_RECORD_BATCHES: VOffsetT = 10;
+
+    /// Returns the metadata version.
+    pub(crate) fn version(&self) -> i16 {
+        field!(self.0, i16, Self::VT_VERSION, Some(0)).unwrap_or_default()
+    }
+
+    /// Returns the schema of the file.
+    pub(crate) fn schema(&self) -> Option<Schema<'buf>> {
+        field!(self.0, ForwardsUOffset<Schema<'buf>>, Self::VT_SCHEMA, None)
+    }
+
+    /// Returns the blocks of the dictionary batches.
+    pub(crate) fn dictionaries(&self) -> Option<Vector<'buf, Block>> {
+        field!(
+            self.0,
+            ForwardsUOffset<Vector<'buf, Block>>,
+            Self::VT_DICTIONARIES,
+            None
+        )
+    }
+
+    /// Returns the blocks of the record batches.
+    pub(crate) fn record_batches(&self) -> Option<Vector<'buf, Block>> {
+        field!(
+            self.0,
+            ForwardsUOffset<Vector<'buf, Block>>,
+            Self::VT_RECORD_BATCHES,
+            None
+        )
+    }
+
+    /// Builds a footer table.
+    pub(crate) fn create(
+        fbb: &mut FlatBufferBuilder<'_>,
+        schema: WIPOffset<TableFinishedWIPOffset>,
+        dictionaries: &[Block],
+        record_batches: &[Block],
+    ) -> WIPOffset<TableFinishedWIPOffset> {
+        let dictionary_vector = fbb.create_vector(dictionaries);
+        let record_batch_vector = fbb.create_vector(record_batches);
+        let start = fbb.start_table();
+        fbb.push_slot_always(Self::VT_SCHEMA, schema);
+        fbb.push_slot_always(Self::VT_DICTIONARIES, dictionary_vector);
+        fbb.push_slot_always(Self::VT_RECORD_BATCHES, record_batch_vector);
+        fbb.push_slot::<i16>(Self::VT_VERSION, METADATA_VERSION_V5, 0);
+        fbb.end_table(start)
+    }
+}
+
+impl Verifiable for Footer<'_> {
+    fn run_verifier(
+        verifier: &mut Verifier<'_, '_>,
+        position: usize,
+    ) -> Result<(), InvalidFlatbuffer> {
+        verifier
+            .visit_table(position)?
+            .visit_field::<i16>("version", Self::VT_VERSION, false)?
+            .visit_field::<ForwardsUOffset<Schema<'_>>>("schema", Self::VT_SCHEMA, false)?
+            .visit_field::<ForwardsUOffset<Vector<'_, Block>>>(
+                "dictionaries",
+                Self::VT_DICTIONARIES,
+                false,
+            )?
+            .visit_field::<ForwardsUOffset<Vector<'_, Block>>>(
+                "recordBatches",
+                Self::VT_RECORD_BATCHES,
+                false,
+            )?
+            .finish();
+        Ok(())
+    }
+}
@@ -0,0 +1,85 @@
+//! Arrow IPC format support for [`Array`](narrow::array::Array).
+//!
+//! See the [Arrow IPC format] specification. Arrays are written as record
+//! batches of the streaming or file format, with dictionary batches for
+//! dictionary-encoded columns, and read back with buffers that share the
+//! message bodies. The footer of the file format allows reading any record
+//! batch without reading the ones before it, and on Unix a file can be
+//! memory-mapped with [`Mmap`] to view its columns without copying them.
+//!
+//! Arrays built with [`AlignedBuffer`] storage have the 64-byte alignment and
+//! padding that the specification recommends for buffers.
+//!
+//! [Arrow IPC format]: https://arrow.apache.org/docs/format/Columnar.html#serialization-and-interprocess-communication-ipc
+
+// The goal of the list of lints here is to help reduce complexity and improve consistency
+#![deny(
+    // Rustc
+    missing_copy_implementations,
+    missing_debug_implementations,
+    missing_docs,
+    noop_method_call,
+    unsafe_op_in_unsafe_fn,
+    warnings,
+    unused,
+    // Clippy
+    clippy::all,
+    clippy::suspicious,
+    clippy::style,
+    clippy::complexity,
+    clippy::perf,
+    clippy::pedantic,
+    // clippy::restriction,
+    clippy::arithmetic_side_effects,
+    clippy::as_conversions,
+    clippy::as_underscore,
+    clippy::clone_on_ref_ptr,
+    clippy::decimal_literal_representation,
+    clippy::empty_structs_with_brackets,
+    clippy::get_unwrap,
+    clippy::if_then_some_else_none,
+    // clippy::missing_docs_in_private_items,
+    clippy::multiple_unsafe_ops_per_block,
+    clippy::pattern_type_mismatch,
+    clippy::rest_pat_in_fully_bound_structs,
+    clippy::same_name_method,
+    clippy::self_named_module_files,
+    clippy::semicolon_outside_block,
+    clippy::shadow_reuse,
+    clippy::shadow_same,
+    clippy::shadow_unrelated,
+    clippy::str_to_string,
+    clippy::string_add,
+    clippy::tests_outside_test_module,
+    clippy::undocumented_unsafe_blocks,
+    clippy::unnecessary_safety_comment,
+    clippy::unnecessary_safety_doc,
+    clippy::unnecessary_self_imports,
+    clippy::unneeded_field_pattern,
+    clippy::unseparated_literal_suffix,
+    clippy::unwrap_used,
+    // Rustdoc
+    rustdoc::all
+)]
+#![allow(
+    clippy::into_iter_without_iter,
+    clippy::iter_not_returning_iterator,
+    clippy::module_name_repetitions,
+    clippy::pub_use
+)]
+
+mod aligned;
+pub use aligned::{AlignedBuffer, AlignedVec};
+mod buffer;
+pub use buffer::{IpcBuffer, IpcSlice};
+mod format;
+#[cfg(unix)]
+mod mmap;
+#[cfg(unix)]
+pub use mmap::{Mmap, MmapBuffer, MmapError, MmapSlice};
+mod read;
+pub use read::{FileReader, FileSource, ReadError, StreamReader};
+mod schema;
+pub use schema::{DataType, DictionaryEncoding, Field, IntType, Precision, PrimitiveType, Schema};
+mod write;
+pub use write::{FileWriter, StreamWriter, WriteError};
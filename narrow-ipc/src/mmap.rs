@@ -0,0 +1,542 @@
+//! Storage for buffers in memory-mapped files.
+
+use std::{
+    borrow::Borrow,
+    fmt,
+    fs::File,
+    iter::Map,
+    marker::PhantomData,
+    mem,
+    ops::Deref,
+    os::fd::AsRawFd,
+    ptr::{self, NonNull},
+    slice,
+    sync::Arc,
+};
+
+use narrow::{
+    buffer::Buffer,
+    collection::{Collection, slice::SliceIntoIter, view::AsView},
+    fixed_size::FixedSize,
+    length::Length,
+};
+
+use crate::buffer::MessageBody;
+
+/// A read-only mapping of a file.
+struct Mapping {
+    /// Start of the mapping.
+    ptr: NonNull<u8>,
+    /// Number of mapped bytes.
+    len: usize,
+}
+
+// SAFETY: The mapping is read-only, so sharing it between threads cannot
+// cause data races.
+unsafe impl Send for Mapping {}
+
+// SAFETY: The mapping is read-only, so sharing it between threads cannot
+// cause data races.
+unsafe impl Sync for Mapping {}
+
+impl Drop for Mapping {
+    fn drop(&mut self) {
+        if self.len != 0 {
+            // SAFETY: The mapping was created with this address and length,
+            // and no references to it outlive the last `Mmap`.
+            unsafe {
+                libc::munmap(self.ptr.as_ptr().cast(), self.len);
+            }
+        }
+    }
+}
+
+/// A shared, read-only window into a memory-mapped file.
+///
+/// Cloning an [`Mmap`] shares the mapping, which is unmapped when the last
+/// window and the last [`MmapSlice`] into it are dropped. The window
+/// dereferences to its bytes, which the operating system loads on access,
+/// so mapping a file does not read it.
+///
+/// # Examples
+///
+/// ```
+/// use std::{fs::File, io::Write};
+///
+/// use narrow_ipc::Mmap;
+///
+/// let path = std::env::temp_dir().join("narrow-ipc-mmap-doc.bin");
+/// File::create(&path).unwrap().write_all(b"narrow").unwrap();
+///
+/// // SAFETY: The file is not modified while it is mapped.
+/// let map = unsafe { Mmap::map(&File::open(&path).unwrap()) }.unwrap();
+/// assert_eq!(&*map, b"narrow");
+/// # std::fs::remove_file(path).unwrap();
+/// ```
+#[derive(Clone)]
+pub struct Mmap {
+    /// The shared mapping.
+    mapping: Arc<Mapping>,
+    /// Byte offset of the window in the mapping.
+    offset: usize,
+    /// Number of bytes in the window.
+    len: usize,
+}
+
+impl Mmap {
+    /// Maps the contents of `file` read-only.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`io::Error`](std::io::Error) when the file cannot be
+    /// mapped.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated while it is mapped, by
+    /// this or any other process, because the mapped bytes are exposed as
+    /// immutable slices.
+    pub unsafe fn map(file: &File) -> std::io::Result<Self> {
+        let len = usize::try_from(file.metadata()?.len())
+            .map_err(|_| std::io::Error::from(std::io::ErrorKind::OutOfMemory))?;
+        let ptr = if len == 0 {
+            // Empty mappings are not permitted, and not needed. The dangling
+            // pointer is aligned for every fixed-size type.
+            NonNull::<u128>::dangling().cast()
+        } else {
+            // SAFETY: A new private read-only mapping does not alias any
+            // existing memory.
+            let address = unsafe {
+                libc::mmap(
+                    ptr::null_mut(),
+                    len,
+                    libc::PROT_READ,
+                    libc::MAP_PRIVATE,
+                    file.as_raw_fd(),
+                    0,
+                )
+            };
+            if address == libc::MAP_FAILED {
+                return Err(std::io::Error::last_os_error());
+            }
+            NonNull::new(address.cast()).ok_or_else(std::io::Error::last_os_error)?
+        };
+        Ok(Self {
+            mapping: Arc::new(Mapping { ptr, len }),
+            offset: 0,
+            len,
+        })
+    }
+
+    /// Returns the window of `len` bytes at `offset` in this window, or
+    /// [`None`] when it is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{fs::File, io::Write};
+    ///
+    /// use narrow_ipc::Mmap;
+    ///
+    /// let path = std::env::temp_dir().join("narrow-ipc-mmap-window-doc.bin");
+    /// File::create(&path).unwrap().write_all(b"narrow").unwrap();
+    ///
+    /// // SAFETY: The file is not modified while it is mapped.
+    /// let map = unsafe { Mmap::map(&File::open(&path).unwrap()) }.unwrap();
+    /// assert_eq!(map.window(1, 3).as_deref(), Some(&b"arr"[..]));
+    /// assert!(map.window(4, 3).is_none());
+    /// # std::fs::remove_file(path).unwrap();
+    /// ```
+    #[must_use]
+    pub fn window(&self, offset: usize, len: usize) -> Option<Self> {
+        offset
+            .checked_add(len)
+            .filter(|end| *end <= self.len)
+            .map(|_| Self {
+                mapping: Arc::clone(&self.mapping),
+                offset: self.offset.strict_add(offset),
+                len,
+            })
+    }
+}
+
+impl Deref for Mmap {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        let start = self.mapping.ptr.as_ptr().wrapping_add(self.offset);
+        // SAFETY: The window is within the mapping, which is readable and
+        // not modified while it is mapped.
+        unsafe { slice::from_raw_parts(start, self.len) }
+    }
+}
+
+impl fmt::Debug for Mmap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Mmap")
+            .field("offset", &self.offset)
+            .field("len", &self.len)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Error returned when an [`MmapSlice`] cannot view a window of a mapping.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MmapError {
+    /// The values do not fit in the window.
+    OutOfBounds {
+        /// Byte offset of the values.
+        offset: usize,
+        /// Number of values.
+        len: usize,
+        /// Number of bytes in the window.
+        window_len: usize,
+    },
+    /// The values are not aligned for their type.
+    Misaligned {
+        /// Byte offset of the values.
+        offset: usize,
+        /// Required alignment.
+        align: usize,
+    },
+}
+
+impl fmt::Display for MmapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::OutOfBounds {
+                offset,
+                len,
+                window_len,
+            } => write!(
+                f,
+                "{len} values at offset {offset} are out of bounds for a window of {window_len} bytes"
+            ),
+            Self::Misaligned { offset, align } => {
+                write!(f, "offset {offset} is not aligned to {align} bytes")
+            }
+        }
+    }
+}
+
+impl core::error::Error for MmapError {}
+
+/// Storage for buffers that view a memory-mapped file.
+///
+/// Reading an Arrow IPC file from an [`Mmap`] with a
+/// [`FileReader`](crate::FileReader) yields arrays whose buffers are
+/// [`MmapSlice`]s into the mapping, so columns of any size are viewed
+/// without reading them into the heap.
+///
+/// # Examples
+///
+/// ```
+/// use std::fs::File;
+///
+/// use narrow::{array::Array, collection::Collection};
+/// use narrow_ipc::{FileReader, FileWriter, Mmap, MmapBuffer};
+///
+/// let path = std::env::temp_dir().join("narrow-ipc-mmap-buffer-doc.arrow");
+/// let mut writer = FileWriter::<_, (u64,)>::try_new(File::create(&path).unwrap()).unwrap();
+/// writer.write(&[(1,), (2,)].into_iter().collect::<Array<_>>()).unwrap();
+/// writer.finish().unwrap();
+///
+/// // SAFETY: The file is not modified while it is mapped.
+/// let map = unsafe { Mmap::map(&File::open(&path).unwrap()) }.unwrap();
+/// let mut reader = FileReader::<_, (u64,)>::try_new(map).unwrap();
+/// let batch: Array<(u64,), MmapBuffer> = reader.batch(0).unwrap();
+/// assert_eq!(batch.into_iter_owned().collect::<Vec<_>>(), [(1,), (2,)]);
+/// # std::fs::remove_file(path).unwrap();
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MmapBuffer;
+impl Buffer for MmapBuffer {
+    type For<T: FixedSize> = MmapSlice<T>;
+}
+
+/// The memory that backs an [`MmapSlice`].
+#[derive(Clone)]
+enum Backing {
+    /// A window of a mapped file.
+    Mapped(Mmap),
+    /// A copy of values that are not aligned in the mapping.
+    Copied(MessageBody),
+}
+
+impl Backing {
+    /// Returns the bytes of the backing memory.
+    fn bytes(&self) -> &[u8] {
+        match *self {
+            Self::Mapped(ref map) => map,
+            Self::Copied(ref body) => body.bytes(),
+        }
+    }
+}
+
+/// A buffer of `T` in a memory-mapped file.
+///
+/// Cloning an [`MmapSlice`] shares the mapping instead of copying the
+/// values.
+///
+/// # Examples
+///
+/// ```
+/// use std::{fs::File, io::Write};
+///
+/// use narrow_ipc::{Mmap, MmapError, MmapSlice};
+///
+/// let path = std::env::temp_dir().join("narrow-ipc-mmap-slice-doc.bin");
+/// let bytes = [1_u32, 2, 3].map(u32::to_ne_bytes).concat();
+/// File::create(&path).unwrap().write_all(&bytes).unwrap();
+///
+/// // SAFETY: The file is not modified while it is mapped.
+/// let map = unsafe { Mmap::map(&File::open(&path).unwrap()) }.unwrap();
+/// let values = MmapSlice::<u32>::try_new(&map, 4, 2).unwrap();
+/// assert_eq!(*values, [2, 3]);
+/// assert!(matches!(
+///     MmapSlice::<u32>::try_new(&map, 2, 1),
+///     Err(MmapError::Misaligned { offset: 2, align: 4 })
+/// ));
+/// # std::fs::remove_file(path).unwrap();
+/// ```
+pub struct MmapSlice<T> {
+    /// Memory that contains the buffer.
+    backing: Backing,
+    /// Byte offset of the first value in the backing memory.
+    offset: usize,
+    /// Number of values in the buffer.
+    len: usize,
+    /// Type of the values.
+    _values: PhantomData<T>,
+}
+
+impl<T: FixedSize> MmapSlice<T> {
+    /// Constructs an [`MmapSlice`] of `len` values at byte `offset` in
+    /// `map`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`MmapError`] when the values do not fit in `map`, or when
+    /// they are not aligned for `T`.
+    ///
+    /// The mapped bytes are reinterpreted as values without validation. This
+    /// is sound because [`FixedSize`] is sealed to types for which any
+    /// initialized bytes are a valid value.
+    pub fn try_new(map: &Mmap, offset: usize, len: usize) -> Result<Self, MmapError> {
+        len.checked_mul(mem::size_of::<T>())
+            .and_then(|bytes| bytes.checked_add(offset))
+            .filter(|end| *end <= map.len())
+            .ok_or(MmapError::OutOfBounds {
+                offset,
+                len,
+                window_len: map.len(),
+            })?;
+        let align = mem::align_of::<T>();
+        if !map.as_ptr().wrapping_add(offset).cast::<T>().is_aligned() {
+            return Err(MmapError::Misaligned { offset, align });
+        }
+        Ok(Self {
+            backing: Backing::Mapped(map.clone()),
+            offset,
+            len,
+            _values: PhantomData,
+        })
+    }
+
+    /// Constructs an [`MmapSlice`] of `len` values at byte `offset` in
+    /// `map`, copying them to the heap when they are not aligned for `T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the values do not fit in `map`.
+    pub(crate) fn new(map: &Mmap, offset: usize, len: usize) -> Self {
+        match Self::try_new(map, offset, len) {
+            Ok(values) => values,
+            Err(MmapError::Misaligned { .. }) => {
+                let end = offset.strict_add(len.strict_mul(mem::size_of::<T>()));
+                Self::copy_from(&map[offset..end], len)
+            }
+            Err(error) => panic!("buffer is within the message body: {error}"),
+        }
+    }
+
+    /// Constructs an [`MmapSlice`] of `len` values from a copy of `bytes`.
+    pub(crate) fn copy_from(bytes: &[u8], len: usize) -> Self {
+        Self {
+            backing: Backing::Copied(MessageBody::copy_from(bytes)),
+            offset: 0,
+            len,
+            _values: PhantomData,
+        }
+    }
+}
+
+impl<T: FixedSize> Deref for MmapSlice<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        let values = self.backing.bytes()[self.offset..].as_ptr().cast::<T>();
+        // SAFETY: The constructors guarantee `len` values at an aligned
+        // offset in the initialized backing memory, and `FixedSize` guarantees
+        // that any initialized bytes are a valid `T`.
+        unsafe { slice::from_raw_parts(values, self.len) }
+    }
+}
+
+impl<T: FixedSize> Borrow<[T]> for MmapSlice<T> {
+    fn borrow(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T> Clone for MmapSlice<T> {
+    fn clone(&self) -> Self {
+        Self {
+            backing: self.backing.clone(),
+            offset: self.offset,
+            len: self.len,
+            _values: PhantomData,
+        }
+    }
+}
+
+impl<T: FixedSize + fmt::Debug> fmt::Debug for MmapSlice<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> Length for MmapSlice<T> {
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<T: FixedSize + for<'any> AsView<'any>> Collection for MmapSlice<T> {
+    type View<'collection>
+        = <T as AsView<'collection>>::View
+    where
+        Self: 'collection;
+
+    type Owned = T;
+
+    fn view(&self, index: usize) -> Option<Self::View<'_>> {
+        self.get(index).map(AsView::as_view)
+    }
+
+    type Iter<'collection>
+        = Map<slice::Iter<'collection, T>, fn(&'collection T) -> <T as AsView<'collection>>::View>
+    where
+        Self: 'collection;
+
+    fn iter_views(&self) -> Self::Iter<'_> {
+        self.iter().map(AsView::as_view)
+    }
+
+    type IntoIter = SliceIntoIter<Self, T>;
+
+    fn into_iter_owned(self) -> Self::IntoIter {
+        self.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Write, path::PathBuf};
+
+    use super::*;
+
+    /// A temporary file that is removed when dropped.
+    struct TempFile(PathBuf);
+
+    impl TempFile {
+        fn new(name: &str, bytes: &[u8]) -> Self {
+            let path =
+                std::env::temp_dir().join(format!("narrow-ipc-{}-{name}", std::process::id()));
+            File::create(&path)
+                .and_then(|mut file| file.write_all(bytes))
+                .expect("write file");
+            Self(path)
+        }
+
+        fn map(&self) -> Mmap {
+            let file = File::open(&self.0).expect("open file");
+            // SAFETY: Test files are not modified while they are mapped.
+            unsafe { Mmap::map(&file) }.expect("map file")
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    #[test]
+    fn slices_share_the_mapping() {
+        let file = TempFile::new("share", &[1_u64, 2, 3].map(u64::to_ne_bytes).concat());
+        let map = file.map();
+        let values = MmapSlice::<u64>::try_new(&map, 8, 2).expect("aligned window");
+        assert_eq!(*values, [2, 3]);
+        assert_eq!(values.as_ptr().cast::<u8>(), map[8..].as_ptr());
+        drop(map);
+        assert_eq!(values.clone().into_iter_owned().collect::<Vec<_>>(), [2, 3]);
+    }
+
+    #[test]
+    fn checks() {
+        let file = TempFile::new("checks", &[0; 16]);
+        let map = file.map();
+        assert_eq!(
+            MmapSlice::<u32>::try_new(&map, 8, 3).err(),
+            Some(MmapError::OutOfBounds {
+                offset: 8,
+                len: 3,
+                window_len: 16
+            })
+        );
+        assert_eq!(
+            MmapSlice::<u64>::try_new(&map, usize::MAX, 1).err(),
+            Some(MmapError::OutOfBounds {
+                offset: usize::MAX,
+                len: 1,
+                window_len: 16
+            })
+        );
+        assert_eq!(
+            MmapSlice::<u16>::try_new(&map, 3, 1).err(),
+            Some(MmapError::Misaligned {
+                offset: 3,
+                align: 2
+            })
+        );
+        let window = map.window(4, 8).expect("window");
+        assert!(MmapSlice::<u32>::try_new(&window, 0, 2).is_ok());
+        assert!(MmapSlice::<u64>::try_new(&window, 0, 1).is_err());
+    }
+
+    #[test]
+    fn misaligned_values_are_copied() {
+        let file = TempFile::new("copy", &[0, 1, 0, 2, 0]);
+        let map = file.map();
+        let values = MmapSlice::<u16>::new(&map, 1, 2);
+        assert_eq!(
+            *values,
+            [u16::from_ne_bytes([1, 0]), u16::from_ne_bytes([2, 0])]
+        );
+        assert!(matches!(values.backing, Backing::Copied(_)));
+    }
+
+    #[test]
+    fn empty_files() {
+        let file = TempFile::new("empty", &[]);
+        let map = file.map();
+        assert!(map.is_empty());
+        assert!(
+            MmapSlice::<u64>::try_new(&map, 0, 0)
+                .expect("empty window")
+                .is_empty()
+        );
+    }
+}
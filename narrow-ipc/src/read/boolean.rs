@@ -0,0 +1,24 @@
+//! Read support for [`Boolean`].
+
+use narrow::layout::boolean::Boolean;
+
+use crate::{DataType, Field};
+
+use super::{BatchReader, ReadBuffer, ReadError, ReadLayout, ReadNullability};
+
+impl<Nulls, Storage> ReadLayout<Storage> for Boolean<Nulls, Storage>
+where
+    Nulls: ReadNullability,
+    Storage: ReadBuffer,
+{
+    fn matches(field: &Field) -> bool {
+        field.data_type == DataType::Boolean && field.dictionary.is_none()
+    }
+
+    fn read(_field: &Field, reader: &mut BatchReader<'_, Storage>) -> Result<Self, ReadError> {
+        let (len, null_count) = reader.node()?;
+        let validity = reader.validity(len)?;
+        let values = reader.bits(len)?;
+        Nulls::wrap(values, validity, null_count).map(Self::from_buffer)
+    }
+}
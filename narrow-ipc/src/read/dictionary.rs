@@ -0,0 +1,44 @@
+//! Read support for [`DictionaryEncoded`].
+
+use narrow::layout::dictionary::{
+    DictionaryEncoded, DictionaryKey, DictionaryKeys, DictionaryValue,
+};
+
+use crate::{Field, schema::IndexType};
+
+use super::{BatchReader, ReadBuffer, ReadError, ReadLayout, ReadNullability};
+
+impl<K, V, Nulls, Storage> ReadLayout<Storage> for DictionaryEncoded<K, V, Nulls, Storage>
+where
+    K: DictionaryKey + IndexType,
+    V: DictionaryValue,
+    Nulls: ReadNullability,
+    Storage: ReadBuffer,
+    V::Memory<Storage>: ReadLayout<Storage>,
+{
+    fn matches(field: &Field) -> bool {
+        field.dictionary.is_some_and(|dictionary| {
+            dictionary.index_type == K::INDEX_TYPE
+                && <V::Memory<Storage>>::matches(&values_field(field))
+        })
+    }
+
+    fn read(field: &Field, reader: &mut BatchReader<'_, Storage>) -> Result<Self, ReadError> {
+        let id = field.dictionary.expect("field is dictionary-encoded").id;
+        let (len, null_count) = reader.node()?;
+        let validity = reader.validity(len)?;
+        let keys = reader.buffer::<K>(len)?;
+        let values = reader.dictionary::<V::Memory<Storage>>(id, &values_field(field))?;
+        let dictionary_keys = DictionaryKeys::try_from_parts(keys, values)
+            .map_err(|error| ReadError::InvalidDictionaryKeys { error })?;
+        Nulls::wrap(dictionary_keys, validity, null_count).map(Self::from_buffer)
+    }
+}
+
+/// Returns the field of the dictionary values of a dictionary-encoded `field`.
+fn values_field(field: &Field) -> Field {
+    Field {
+        dictionary: None,
+        ..field.clone()
+    }
+}
@@ -0,0 +1,563 @@
+//! Read support for the Arrow IPC file format.
+
+use std::{
+    io::{self, Read, Seek, SeekFrom},
+    marker::PhantomData,
+    mem,
+};
+
+use narrow::{array::Array, layout::ArrayItem};
+
+use crate::{
+    IpcBuffer, Schema,
+    buffer::MessageBody,
+    format::{self, Block, Footer},
+};
+
+use super::{
+    BatchReader, Dictionaries, EncapsulatedMessage, ReadBatch, ReadBuffer, ReadError, batch_length,
+    invalid_flatbuffer, read_metadata_length,
+};
+
+/// Random access to the bytes of an Arrow IPC file.
+///
+/// The source determines the storage of the arrays read from the file: a
+/// [`Read`] and [`Seek`] source, like a [`File`](std::fs::File), copies each
+/// message body into an [`IpcBuffer`], while a source that already holds
+/// the file in memory can share it with the arrays instead.
+pub trait FileSource {
+    /// Storage of the buffers read from the file.
+    type Storage: ReadBuffer;
+
+    /// Returns the number of bytes in the file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`io::Error`] when the length cannot be determined.
+    fn file_len(&mut self) -> io::Result<u64>;
+
+    /// Reads exactly `buf.len()` bytes starting at `offset`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`io::Error`] when the bytes cannot be read.
+    fn read_exact_at(&mut self, offset: u64, buf: &mut [u8]) -> io::Result<()>;
+
+    /// Returns the message body of `len` bytes starting at `offset`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`io::Error`] when the body cannot be read.
+    fn read_body(
+        &mut self,
+        offset: u64,
+        len: usize,
+    ) -> io::Result<<Self::Storage as ReadBuffer>::Body>;
+}
+
+impl<R: Read + Seek> FileSource for R {
+    type Storage = IpcBuffer;
+
+    fn file_len(&mut self) -> io::Result<u64> {
+        self.seek(SeekFrom::End(0))
+    }
+
+    fn read_exact_at(&mut self, offset: u64, buf: &mut [u8]) -> io::Result<()> {
+        self.seek(SeekFrom::Start(offset))?;
+        self.read_exact(buf)
+    }
+
+    fn read_body(&mut self, offset: u64, len: usize) -> io::Result<MessageBody> {
+        self.seek(SeekFrom::Start(offset))?;
+        MessageBody::read(self, len)
+    }
+}
+
+#[cfg(unix)]
+impl FileSource for crate::Mmap {
+    type Storage = crate::MmapBuffer;
+
+    fn file_len(&mut self) -> io::Result<u64> {
+        u64::try_from(self.len()).map_err(io::Error::other)
+    }
+
+    fn read_exact_at(&mut self, offset: u64, buf: &mut [u8]) -> io::Result<()> {
+        let window = self
+            .window(to_usize(offset)?, buf.len())
+            .ok_or(io::ErrorKind::UnexpectedEof)?;
+        buf.copy_from_slice(&window);
+        Ok(())
+    }
+
+    fn read_body(&mut self, offset: u64, len: usize) -> io::Result<crate::Mmap> {
+        Ok(self
+            .window(to_usize(offset)?, len)
+            .ok_or(io::ErrorKind::UnexpectedEof)?)
+    }
+}
+
+/// Converts a file offset to a memory offset.
+#[cfg(unix)]
+fn to_usize(offset: u64) -> io::Result<usize> {
+    usize::try_from(offset).map_err(|_| io::Error::from(io::ErrorKind::UnexpectedEof))
+}
+
+/// Byte offset of the first message, after the magic bytes and padding.
+const MESSAGES_OFFSET: u64 = 8;
+
+/// Number of bytes after the footer: its length and the magic bytes.
+const TRAILER_LEN: usize = mem::size_of::<i32>() + format::MAGIC.len();
+
+/// A reader of [`Array`]s of `T` from the Arrow IPC file format.
+///
+/// The footer of the file is read when the reader is constructed, together
+/// with the dictionaries. Record batches are then read on request, in any
+/// order, by seeking to the block the footer lists for them, so a batch is
+/// read without reading the batches before it.
+///
+/// The schema of the file must match the fields of the struct `T`, as for a
+/// [`StreamReader`](crate::StreamReader).
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Cursor;
+///
+/// use narrow::{array::Array, collection::Collection};
+/// use narrow_ipc::{FileReader, FileWriter};
+///
+/// let mut writer = FileWriter::<_, (u32, String)>::try_new(Vec::new()).unwrap();
+/// for id in 0..3 {
+///     let batch = [(id, id.to_string())].into_iter().collect::<Array<_>>();
+///     writer.write(&batch).unwrap();
+/// }
+/// let bytes = writer.finish().unwrap();
+///
+/// let mut reader = FileReader::<_, (u32, String)>::try_new(Cursor::new(bytes)).unwrap();
+/// assert_eq!(reader.num_batches(), 3);
+/// let batch = reader.batch(2).unwrap();
+/// assert_eq!(batch.into_iter_owned().collect::<Vec<_>>(), [(2, "2".to_owned())]);
+/// ```
+#[derive(Debug)]
+pub struct FileReader<S: FileSource, T> {
+    /// Source of the file.
+    source: S,
+    /// Schema of the file.
+    schema: Schema,
+    /// Dictionaries of the file.
+    dictionaries: Dictionaries<<S::Storage as ReadBuffer>::Body>,
+    /// Blocks of the record batches.
+    record_batches: Vec<Block>,
+    /// Byte offset of the footer, which ends the messages.
+    footer_offset: u64,
+    /// Type of the rows.
+    _rows: PhantomData<fn() -> T>,
+}
+
+impl<S: FileSource, T: ArrayItem> FileReader<S, T>
+where
+    T::Memory<S::Storage>: ReadBatch<S::Storage>,
+{
+    /// Constructs a [`FileReader`] and reads the footer and dictionaries of
+    /// the file.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ReadError`] when the footer or dictionaries cannot be
+    /// read, or when the schema does not match `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    ///
+    /// use narrow_ipc::{FileReader, ReadError};
+    ///
+    /// assert!(matches!(
+    ///     FileReader::<_, (u8,)>::try_new(Cursor::new(b"ARROW2")),
+    ///     Err(ReadError::InvalidMagic)
+    /// ));
+    /// ```
+    pub fn try_new(mut source: S) -> Result<Self, ReadError> {
+        let file_len = source.file_len()?;
+        let trailer_offset = u64::try_from(TRAILER_LEN)
+            .ok()
+            .and_then(|len| file_len.checked_sub(len))
+            .filter(|offset| *offset >= MESSAGES_OFFSET)
+            .ok_or(ReadError::InvalidMagic)?;
+        let mut magic = [0; format::MAGIC.len()];
+        source.read_exact_at(0, &mut magic)?;
+        let mut trailer = [0; TRAILER_LEN];
+        source.read_exact_at(trailer_offset, &mut trailer)?;
+        let [first, second, third, fourth, trailing_magic @ ..] = trailer;
+        if magic != format::MAGIC || trailing_magic != format::MAGIC {
+            return Err(ReadError::InvalidMagic);
+        }
+
+        let length = i32::from_le_bytes([first, second, third, fourth]);
+        let footer_range = usize::try_from(length).ok().and_then(|len| {
+            let offset = trailer_offset.checked_sub(u64::try_from(len).ok()?)?;
+            (offset >= MESSAGES_OFFSET).then_some((offset, len))
+        });
+        let Some((footer_offset, footer_len)) = footer_range else {
+            return Err(ReadError::InvalidMessageLength {
+                length: i64::from(length),
+            });
+        };
+        let mut footer_bytes = vec![0; footer_len];
+        source.read_exact_at(footer_offset, &mut footer_bytes)?;
+        let footer = format::root::<Footer<'_>>(&footer_bytes)
+            .map_err(|error| invalid_flatbuffer(&error))?;
+        let version = footer.version();
+        if version < format::METADATA_VERSION_V4 {
+            return Err(ReadError::UnsupportedVersion { version });
+        }
+
+        let table = footer.schema().ok_or(ReadError::MissingSchema)?;
+        if table.endianness() != format::ENDIANNESS_NATIVE {
+            return Err(ReadError::NonNativeEndianness);
+        }
+        let schema = Schema::from_table(table)?;
+        if !<T::Memory<S::Storage>>::matches_schema(&schema) {
+            return Err(ReadError::SchemaMismatch);
+        }
+
+        let mut reader = Self {
+            source,
+            schema,
+            dictionaries: Dictionaries::default(),
+            record_batches: footer
+                .record_batches()
+                .map(|blocks| blocks.iter().collect())
+                .unwrap_or_default(),
+            footer_offset,
+            _rows: PhantomData,
+        };
+        for block in footer.dictionaries().into_iter().flatten() {
+            let message = reader.read_block(block, format::HEADER_DICTIONARY_BATCH)?;
+            reader.dictionaries.insert(message)?;
+        }
+        Ok(reader)
+    }
+
+    /// Returns the schema of the file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    ///
+    /// use narrow_ipc::{FileReader, FileWriter};
+    ///
+    /// let bytes = FileWriter::<_, (u8, u16)>::try_new(Vec::new()).unwrap().finish().unwrap();
+    /// let reader = FileReader::<_, (u8, u16)>::try_new(Cursor::new(bytes)).unwrap();
+    /// assert_eq!(reader.schema().fields.len(), 2);
+    /// ```
+    pub fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    /// Returns the number of record batches in the file.
+    pub fn num_batches(&self) -> usize {
+        self.record_batches.len()
+    }
+
+    /// Reads the record batch at `index`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ReadError`] when `index` is out of bounds, or when the
+    /// record batch cannot be read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    ///
+    /// use narrow_ipc::{FileReader, FileWriter, ReadError};
+    ///
+    /// let bytes = FileWriter::<_, (u8,)>::try_new(Vec::new()).unwrap().finish().unwrap();
+    /// let mut reader = FileReader::<_, (u8,)>::try_new(Cursor::new(bytes)).unwrap();
+    /// assert!(matches!(
+    ///     reader.batch(0),
+    ///     Err(ReadError::BatchIndexOutOfBounds { index: 0, len: 0 })
+    /// ));
+    /// ```
+    pub fn batch(&mut self, index: usize) -> Result<Array<T, S::Storage>, ReadError> {
+        let block = *self
+            .record_batches
+            .get(index)
+            .ok_or(ReadError::BatchIndexOutOfBounds {
+                index,
+                len: self.record_batches.len(),
+            })?;
+        let message = self.read_block(block, format::HEADER_RECORD_BATCH)?;
+        let batch =
+            message
+                .message()
+                .header_as_record_batch()
+                .ok_or(ReadError::InvalidMetadata {
+                    reason: "record batch message has no header".to_owned(),
+                })?;
+        let length = batch_length(batch)?;
+        let mut reader = BatchReader::try_new(batch, message.body(), &self.dictionaries)?;
+        let memory = <T::Memory<S::Storage>>::read_batch(&self.schema, length, &mut reader)?;
+        Ok(Array::from_buffer(memory))
+    }
+
+    /// Reads the message of `block`, which must have a header of
+    /// `header_type`.
+    fn read_block(
+        &mut self,
+        block: Block,
+        header_type: u8,
+    ) -> Result<EncapsulatedMessage<<S::Storage as ReadBuffer>::Body>, ReadError> {
+        let invalid = ReadError::InvalidBlock {
+            offset: block.offset,
+            meta_data_length: block.meta_data_length,
+            body_length: block.body_length,
+        };
+        let (Ok(offset), Ok(meta_data_length), Ok(body_length)) = (
+            u64::try_from(block.offset),
+            usize::try_from(block.meta_data_length),
+            usize::try_from(block.body_length),
+        ) else {
+            return Err(invalid);
+        };
+        let bounds = u64::try_from(meta_data_length)
+            .ok()
+            .and_then(|len| offset.checked_add(len))
+            .and_then(|start| Some((start, start.checked_add(u64::try_from(body_length).ok()?)?)))
+            .filter(|&(_, end)| offset >= MESSAGES_OFFSET && end <= self.footer_offset);
+        let Some((body_offset, _)) = bounds else {
+            return Err(invalid);
+        };
+
+        let mut bytes = vec![0; meta_data_length];
+        self.source.read_exact_at(offset, &mut bytes)?;
+        let mut prefixed = bytes.as_slice();
+        let flatbuffer_len = read_metadata_length(&mut prefixed)?
+            .filter(|len| *len <= prefixed.len())
+            .ok_or(invalid)?;
+        let metadata = prefixed[..flatbuffer_len].to_vec();
+        let message = EncapsulatedMessage::try_new(metadata, ())?;
+        let header = message.message();
+        if header.header_type() != header_type {
+            return Err(ReadError::UnexpectedMessage {
+                header_type: header.header_type(),
+            });
+        }
+        let length = header.body_length();
+        if usize::try_from(length).ok() != Some(body_length) {
+            return Err(ReadError::InvalidMessageLength { length });
+        }
+        let body = self.source.read_body(body_offset, body_length)?;
+        Ok(message.with_body(body))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Cursor, sync::Arc};
+
+    use arrow_array::{
+        ArrayRef, DictionaryArray, Int32Array, RecordBatch, StringArray, types::UInt16Type,
+    };
+    use narrow::{ArrayType, collection::Collection, layout::dictionary::Dictionary};
+
+    use crate::FileWriter;
+
+    use super::*;
+
+    #[derive(ArrayType, Clone, Debug, Default, PartialEq)]
+    struct Row {
+        id: u32,
+        name: Option<String>,
+        tags: Vec<u16>,
+        kind: Dictionary<u8, String>,
+    }
+
+    fn rows(count: u32) -> Vec<Row> {
+        (0..count)
+            .map(|id| Row {
+                id,
+                name: id.is_multiple_of(2).then(|| id.to_string()),
+                tags: vec![
+                    u16::try_from(id).expect("value fits");
+                    usize::try_from(id % 3).expect("value fits")
+                ],
+                kind: Dictionary::new(
+                    ["a", "b", "c"][usize::try_from(id % 3).expect("value fits")].to_owned(),
+                ),
+            })
+            .collect()
+    }
+
+    fn write(batches: &[&[Row]]) -> Vec<u8> {
+        let mut writer = FileWriter::<_, Row>::try_new(Vec::new()).expect("write schema");
+        for batch in batches {
+            writer
+                .write(&batch.iter().cloned().collect::<Array<Row>>())
+                .expect("write batch");
+        }
+        writer.finish().expect("finish file")
+    }
+
+    #[test]
+    fn random_access() {
+        let expected = rows(30);
+        let bytes = write(&expected.chunks(3).collect::<Vec<_>>());
+        let mut reader = FileReader::<_, Row>::try_new(Cursor::new(bytes)).expect("read footer");
+        assert_eq!(reader.num_batches(), 10);
+        for index in [9, 0, 4, 4] {
+            let batch = reader.batch(index).expect("read batch");
+            assert_eq!(
+                batch.into_iter_owned().collect::<Vec<_>>(),
+                expected.chunks(3).nth(index).expect("chunk")
+            );
+        }
+        assert!(matches!(
+            reader.batch(10),
+            Err(ReadError::BatchIndexOutOfBounds { index: 10, len: 10 })
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn reads_memory_mapped_files() {
+        use narrow::buffer::BufferRef;
+
+        let expected = rows(12);
+        let bytes = write(&expected.chunks(6).collect::<Vec<_>>());
+        let path =
+            std::env::temp_dir().join(format!("narrow-ipc-{}-file.arrow", std::process::id()));
+        std::fs::write(&path, &bytes).expect("write file");
+        let file = std::fs::File::open(&path).expect("open file");
+        // SAFETY: The file is not modified while it is mapped.
+        let map = unsafe { crate::Mmap::map(&file) }.expect("map file");
+        std::fs::remove_file(&path).expect("remove file");
+
+        let mut reader = FileReader::<_, Row>::try_new(map.clone()).expect("read footer");
+        let batch = reader.batch(1).expect("read batch");
+        let ids: &crate::MmapSlice<u32> = batch.buffer_ref().buffer_ref().id.buffer_ref();
+        assert!(map.as_ptr_range().contains(&ids.as_ptr().cast()));
+        assert_eq!(batch.into_iter_owned().collect::<Vec<_>>(), &expected[6..]);
+    }
+
+    #[test]
+    fn invalid_magic() {
+        let bytes = write(&[&rows(2)]);
+        let mut leading = bytes.clone();
+        leading[5] = b'2';
+        assert!(matches!(
+            FileReader::<_, Row>::try_new(Cursor::new(leading)),
+            Err(ReadError::InvalidMagic)
+        ));
+        let trailing = &bytes[..bytes.len().strict_sub(1)];
+        assert!(matches!(
+            FileReader::<_, Row>::try_new(Cursor::new(trailing)),
+            Err(ReadError::InvalidMagic)
+        ));
+        let stream = crate::StreamWriter::<_, Row>::try_new(Vec::new())
+            .expect("write schema")
+            .finish()
+            .expect("finish stream");
+        assert!(matches!(
+            FileReader::<_, Row>::try_new(Cursor::new(stream)),
+            Err(ReadError::InvalidMagic)
+        ));
+    }
+
+    #[test]
+    fn invalid_footer_length() {
+        let mut bytes = write(&[&rows(2)]);
+        let at = bytes.len().strict_sub(10);
+        bytes[at..at.strict_add(4)].copy_from_slice(&i32::MAX.to_le_bytes());
+        assert!(matches!(
+            FileReader::<_, Row>::try_new(Cursor::new(bytes)),
+            Err(ReadError::InvalidMessageLength { .. })
+        ));
+    }
+
+    #[test]
+    fn schema_mismatch() {
+        let bytes = write(&[]);
+        assert!(matches!(
+            FileReader::<_, (u32,)>::try_new(Cursor::new(bytes)),
+            Err(ReadError::SchemaMismatch)
+        ));
+    }
+
+    #[test]
+    fn arrow_reads_written_files() {
+        let expected = rows(10);
+        let bytes = write(&[&expected[..6], &expected[6..]]);
+        let reader =
+            arrow_ipc::reader::FileReader::try_new(Cursor::new(bytes), None).expect("read footer");
+        let batches = reader.collect::<Result<Vec<_>, _>>().expect("read batches");
+        assert_eq!(
+            batches
+                .iter()
+                .map(RecordBatch::num_rows)
+                .collect::<Vec<_>>(),
+            [6, 4]
+        );
+        let ids = batches[1]
+            .column(0)
+            .as_any()
+            .downcast_ref::<arrow_array::UInt32Array>()
+            .expect("id column");
+        assert_eq!(ids.values(), &[6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn reads_arrow_ipc_files() {
+        let columns: Vec<(&str, ArrayRef)> = vec![
+            ("id", Arc::new(Int32Array::from(vec![1, 2, 3]))),
+            (
+                "kind",
+                Arc::new(
+                    vec![Some("x"), Some("y"), Some("x")]
+                        .into_iter()
+                        .collect::<DictionaryArray<UInt16Type>>(),
+                ),
+            ),
+            (
+                "note",
+                Arc::new(StringArray::from(vec![None, Some("n"), None])),
+            ),
+        ];
+        let record_batch = RecordBatch::try_from_iter(columns).expect("record batch");
+        let mut writer = arrow_ipc::writer::FileWriter::try_new(Vec::new(), &record_batch.schema())
+            .expect("write schema");
+        writer.write(&record_batch).expect("write batch");
+        writer
+            .write(&record_batch.slice(2, 1))
+            .expect("write batch");
+        writer.finish().expect("finish file");
+        let bytes = writer.into_inner().expect("file bytes");
+
+        let mut reader = FileReader::<_, (i32, Dictionary<u16, String>, Option<String>)>::try_new(
+            Cursor::new(bytes),
+        )
+        .expect("read footer");
+        assert_eq!(reader.num_batches(), 2);
+        assert_eq!(
+            reader
+                .batch(1)
+                .expect("read batch")
+                .into_iter_owned()
+                .collect::<Vec<_>>(),
+            [(3, Dictionary::new("x".to_owned()), None)]
+        );
+        assert_eq!(
+            reader
+                .batch(0)
+                .expect("read batch")
+                .into_iter_owned()
+                .map(|(id, _, note)| (id, note))
+                .collect::<Vec<_>>(),
+            [(1, None), (2, Some("n".to_owned())), (3, None)]
+        );
+    }
+}
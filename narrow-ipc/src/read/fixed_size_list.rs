@@ -0,0 +1,50 @@
+//! Read support for [`FixedSizeList`].
+
+use narrow::{
+    collection::flatten::Flatten,
+    layout::{ArrayItem, MemoryLayout, fixed_size_list::FixedSizeList},
+    length::Length,
+};
+
+use crate::{DataType, Field};
+
+use super::{BatchReader, ReadBuffer, ReadError, ReadLayout, ReadNullability};
+
+impl<T, const N: usize, Nulls, Storage> ReadLayout<Storage> for FixedSizeList<T, N, Nulls, Storage>
+where
+    T: ArrayItem,
+    Nulls: ReadNullability,
+    Storage: ReadBuffer,
+    T::Memory<Storage>: ReadLayout<Storage>,
+{
+    fn matches(field: &Field) -> bool {
+        // Lists of zero items cannot be flattened, so they are not read.
+        match field.data_type {
+            DataType::FixedSizeList(ref child, size) => {
+                N != 0
+                    && size == N
+                    && field.dictionary.is_none()
+                    && <T::Memory<Storage>>::matches(child)
+            }
+            _ => false,
+        }
+    }
+
+    fn read(field: &Field, reader: &mut BatchReader<'_, Storage>) -> Result<Self, ReadError> {
+        let DataType::FixedSizeList(ref child, _) = field.data_type else {
+            unreachable!("field is a fixed-size list");
+        };
+        let (len, null_count) = reader.node()?;
+        let validity = reader.validity(len)?;
+        let values = <T::Memory<Storage>>::read(child, reader)?;
+        let expected = len.strict_mul(N);
+        let actual = values.len();
+        if actual < expected {
+            return Err(ReadError::LengthMismatch { expected, actual });
+        }
+        // Writers may keep child items past the end of a sliced list array.
+        let flattened = Flatten::try_from_parts(values.slice(0, expected))
+            .expect("child length is a multiple of the list size");
+        Nulls::wrap(flattened, validity, null_count).map(Self::from_buffer)
+    }
+}
@@ -0,0 +1,25 @@
+//! Read support for [`FixedSizePrimitive`].
+
+use narrow::{fixed_size::FixedSize, layout::fixed_size_primitive::FixedSizePrimitive};
+
+use crate::{Field, PrimitiveType};
+
+use super::{BatchReader, ReadBuffer, ReadError, ReadLayout, ReadNullability};
+
+impl<T, Nulls, Storage> ReadLayout<Storage> for FixedSizePrimitive<T, Nulls, Storage>
+where
+    T: FixedSize + PrimitiveType,
+    Nulls: ReadNullability,
+    Storage: ReadBuffer,
+{
+    fn matches(field: &Field) -> bool {
+        field.data_type == T::DATA_TYPE && field.dictionary.is_none()
+    }
+
+    fn read(_field: &Field, reader: &mut BatchReader<'_, Storage>) -> Result<Self, ReadError> {
+        let (len, null_count) = reader.node()?;
+        let validity = reader.validity(len)?;
+        let values = reader.buffer::<T>(len)?;
+        Nulls::wrap(values, validity, null_count).map(Self::from_buffer)
+    }
+}
@@ -0,0 +1,726 @@
+//! Read Arrow IPC messages as [`Array`](narrow::array::Array) values.
+
+use std::{
+    collections::HashMap,
+    fmt,
+    io::{self, Read},
+    mem, vec,
+};
+
+use narrow::{
+    bitmap::{Bitmap, ValidityBitmap},
+    buffer::Buffer,
+    collection::Collection,
+    fixed_size::FixedSize,
+    layout::{dictionary::DictionaryKeysError, utf8::Utf8ValuesError},
+    length::Length,
+    nullability::{NonNullable, Nullability, Nullable},
+    offset::{Offset, OffsetsError},
+    validity::Validity,
+};
+
+use crate::{
+    Field, IpcBuffer, IpcSlice, Schema,
+    buffer::{MessageBody, bytes_of},
+    format::{self, BufferRegion, FieldNode, Message, RecordBatch},
+};
+
+/// Read support for [`narrow::layout::boolean::Boolean`].
+mod boolean;
+/// Read support for [`narrow::layout::dictionary::DictionaryEncoded`].
+mod dictionary;
+/// Read support for [`narrow::layout::fixed_size_list::FixedSizeList`].
+mod fixed_size_list;
+/// Read support for [`narrow::layout::fixed_size_primitive::FixedSizePrimitive`].
+mod fixed_size_primitive;
+/// Read support for [`narrow::layout::struct_array::Struct`].
+mod struct_array;
+/// Read support for [`narrow::layout::utf8::Utf8`].
+mod utf8;
+/// Read support for [`narrow::layout::variable_size_list::VariableSizeList`].
+mod variable_size_list;
+
+/// Read support for the Arrow IPC file format.
+mod file;
+pub use file::{FileReader, FileSource};
+/// Read support for the Arrow IPC streaming format.
+mod stream;
+pub use stream::StreamReader;
+
+/// Error returned when an Arrow IPC stream cannot be read.
+///
+/// Every length, offset and count in a message is validated before a buffer
+/// is borrowed, so malformed input is reported instead of causing a panic or
+/// an out-of-bounds access.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ReadError {
+    /// The underlying reader failed.
+    Io(io::Error),
+    /// The stream ended before its schema, or the file footer has none.
+    MissingSchema,
+    /// The file does not start and end with the `ARROW1` magic bytes.
+    InvalidMagic,
+    /// A block of the file footer is not within the file.
+    InvalidBlock {
+        /// Byte offset supplied by the writer.
+        offset: i64,
+        /// Metadata length supplied by the writer.
+        meta_data_length: i32,
+        /// Body length supplied by the writer.
+        body_length: i64,
+    },
+    /// The requested record batch is not in the file.
+    BatchIndexOutOfBounds {
+        /// Requested record batch.
+        index: usize,
+        /// Number of record batches in the file.
+        len: usize,
+    },
+    /// The message metadata is not a valid flatbuffer or is inconsistent.
+    InvalidMetadata {
+        /// Description of the problem.
+        reason: String,
+    },
+    /// The length of a message or its body is negative.
+    InvalidMessageLength {
+        /// Length supplied by the writer.
+        length: i64,
+    },
+    /// The metadata version is older than `V4`.
+    UnsupportedVersion {
+        /// Metadata version supplied by the writer.
+        version: i16,
+    },
+    /// A message of this type is not expected at this position.
+    UnexpectedMessage {
+        /// Type of the message header.
+        header_type: u8,
+    },
+    /// The schema contains a type that is not supported.
+    UnsupportedType {
+        /// Discriminant of the type in the IPC format.
+        type_id: u8,
+    },
+    /// The schema does not match the requested Narrow array type.
+    SchemaMismatch,
+    /// The buffers are not in the byte order of this platform.
+    NonNativeEndianness,
+    /// The message body is compressed.
+    Compressed,
+    /// A dictionary batch extends a dictionary instead of replacing it.
+    DeltaDictionary {
+        /// Identifier of the dictionary.
+        id: i64,
+    },
+    /// A record batch references a dictionary that was not read.
+    MissingDictionary {
+        /// Identifier of the dictionary.
+        id: i64,
+    },
+    /// A record batch has fewer field nodes than its schema requires.
+    MissingFieldNode,
+    /// A record batch has fewer buffers than its schema requires.
+    MissingBuffer,
+    /// A field node has a negative length or null count, or more nulls than
+    /// items.
+    InvalidFieldNode {
+        /// Length supplied by the writer.
+        length: i64,
+        /// Null count supplied by the writer.
+        null_count: i64,
+    },
+    /// A buffer is not within the message body.
+    InvalidBuffer {
+        /// Byte offset supplied by the writer.
+        offset: i64,
+        /// Byte length supplied by the writer.
+        length: i64,
+    },
+    /// A buffer is shorter than its field node requires.
+    BufferTooSmall {
+        /// Byte length of the buffer.
+        length: usize,
+        /// Required byte length.
+        required: usize,
+    },
+    /// A child array or column has a different length than required.
+    LengthMismatch {
+        /// Required length.
+        expected: usize,
+        /// Length of the child array or column.
+        actual: usize,
+    },
+    /// A non-nullable field contains null items.
+    UnexpectedNulls {
+        /// Null count supplied by the writer.
+        null_count: usize,
+    },
+    /// A null count does not match the validity bitmap.
+    NullCountMismatch {
+        /// Null count supplied by the writer.
+        declared: usize,
+        /// Number of nulls in the validity bitmap.
+        actual: usize,
+    },
+    /// A field with null items does not have a validity buffer.
+    MissingValidityBuffer,
+    /// An offsets buffer does not satisfy the Arrow offset invariants.
+    InvalidOffsets {
+        /// Offset invariant that was violated.
+        error: OffsetsError,
+    },
+    /// A string is not valid UTF-8.
+    InvalidUtf8 {
+        /// UTF-8 invariant that was violated.
+        error: Utf8ValuesError,
+    },
+    /// A dictionary key does not address one of the dictionary values.
+    InvalidDictionaryKeys {
+        /// Dictionary key invariant that was violated.
+        error: DictionaryKeysError,
+    },
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Io(ref error) => write!(f, "failed to read Arrow IPC stream: {error}"),
+            Self::MissingSchema => write!(f, "Arrow IPC schema is missing"),
+            Self::InvalidMagic => write!(f, "Arrow IPC file magic bytes are missing"),
+            Self::InvalidBlock {
+                offset,
+                meta_data_length,
+                body_length,
+            } => write!(
+                f,
+                "Arrow IPC file block at offset {offset} with metadata length \
+                 {meta_data_length} and body length {body_length} is not within the file"
+            ),
+            Self::BatchIndexOutOfBounds { index, len } => write!(
+                f,
+                "record batch index ({index}) is out of bounds for {len} record batches"
+            ),
+            Self::InvalidMetadata { ref reason } => {
+                write!(f, "Arrow IPC message metadata is invalid: {reason}")
+            }
+            Self::InvalidMessageLength { length } => {
+                write!(f, "Arrow IPC message length ({length}) is invalid")
+            }
+            Self::UnsupportedVersion { version } => {
+                write!(f, "Arrow IPC metadata version ({version}) is not supported")
+            }
+            Self::UnexpectedMessage { header_type } => {
+                write!(f, "Arrow IPC message type ({header_type}) is unexpected")
+            }
+            Self::UnsupportedType { type_id } => {
+                write!(f, "Arrow IPC field type ({type_id}) is not supported")
+            }
+            Self::SchemaMismatch => write!(f, "Arrow IPC schema does not match"),
+            Self::NonNativeEndianness => {
+                write!(
+                    f,
+                    "Arrow IPC stream endianness does not match this platform"
+                )
+            }
+            Self::Compressed => write!(f, "compressed Arrow IPC bodies are not supported"),
+            Self::DeltaDictionary { id } => {
+                write!(
+                    f,
+                    "delta dictionary batch for dictionary {id} is not supported"
+                )
+            }
+            Self::MissingDictionary { id } => write!(f, "dictionary {id} is missing"),
+            Self::MissingFieldNode => write!(f, "Arrow IPC record batch field node is missing"),
+            Self::MissingBuffer => write!(f, "Arrow IPC record batch buffer is missing"),
+            Self::InvalidFieldNode { length, null_count } => write!(
+                f,
+                "Arrow IPC field node length ({length}) or null count ({null_count}) is invalid"
+            ),
+            Self::InvalidBuffer { offset, length } => write!(
+                f,
+                "Arrow IPC buffer at offset {offset} with length {length} is not within the body"
+            ),
+            Self::BufferTooSmall { length, required } => write!(
+                f,
+                "Arrow IPC buffer length ({length}) is smaller than required ({required})"
+            ),
+            Self::LengthMismatch { expected, actual } => {
+                write!(f, "array length ({actual}) does not match ({expected})")
+            }
+            Self::UnexpectedNulls { null_count } => {
+                write!(f, "non-nullable field contains {null_count} null items")
+            }
+            Self::NullCountMismatch { declared, actual } => write!(
+                f,
+                "Arrow IPC null count ({declared}) does not match validity bitmap ({actual})"
+            ),
+            Self::MissingValidityBuffer => write!(f, "Arrow IPC validity buffer is missing"),
+            Self::InvalidOffsets { error } => write!(f, "invalid offsets: {error}"),
+            Self::InvalidUtf8 { error } => write!(f, "invalid UTF-8 values: {error}"),
+            Self::InvalidDictionaryKeys { error } => write!(f, "invalid dictionary keys: {error}"),
+        }
+    }
+}
+
+impl core::error::Error for ReadError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match *self {
+            Self::Io(ref error) => Some(error),
+            Self::InvalidOffsets { ref error } => Some(error),
+            Self::InvalidUtf8 { ref error } => Some(error),
+            Self::InvalidDictionaryKeys { ref error } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ReadError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+/// Storage for buffers read from message bodies.
+pub trait ReadBuffer: Buffer {
+    /// A message body.
+    type Body: fmt::Debug;
+
+    /// Returns the number of bytes in `body`.
+    fn body_len(body: &Self::Body) -> usize;
+
+    /// Returns the buffer of `len` values at byte `offset` in `body`.
+    ///
+    /// The values must be within the body. Values that are not aligned for
+    /// `T` are copied.
+    fn read_buffer<T: FixedSize>(body: &Self::Body, offset: usize, len: usize) -> Self::For<T>;
+
+    /// Returns a buffer containing a copy of `values`.
+    fn copy_buffer<T: FixedSize>(values: &[T]) -> Self::For<T>;
+}
+
+impl ReadBuffer for IpcBuffer {
+    type Body = MessageBody;
+
+    fn body_len(body: &Self::Body) -> usize {
+        body.bytes().len()
+    }
+
+    fn read_buffer<T: FixedSize>(body: &Self::Body, offset: usize, len: usize) -> Self::For<T> {
+        IpcSlice::new(body, offset, len)
+    }
+
+    fn copy_buffer<T: FixedSize>(values: &[T]) -> Self::For<T> {
+        IpcSlice::new(&MessageBody::copy_from(bytes_of(values)), 0, values.len())
+    }
+}
+
+#[cfg(unix)]
+impl ReadBuffer for crate::MmapBuffer {
+    type Body = crate::Mmap;
+
+    fn body_len(body: &Self::Body) -> usize {
+        body.len()
+    }
+
+    fn read_buffer<T: FixedSize>(body: &Self::Body, offset: usize, len: usize) -> Self::For<T> {
+        crate::MmapSlice::new(body, offset, len)
+    }
+
+    fn copy_buffer<T: FixedSize>(values: &[T]) -> Self::For<T> {
+        crate::MmapSlice::copy_from(bytes_of(values), values.len())
+    }
+}
+
+/// An encapsulated message: verified metadata and its body.
+#[derive(Debug)]
+pub(crate) struct EncapsulatedMessage<Body> {
+    /// The `Message` flatbuffer.
+    metadata: Vec<u8>,
+    /// The message body.
+    body: Body,
+}
+
+impl<Body> EncapsulatedMessage<Body> {
+    /// Verifies `metadata` and constructs an encapsulated message.
+    pub(crate) fn try_new(metadata: Vec<u8>, body: Body) -> Result<Self, ReadError> {
+        let message =
+            format::root::<Message<'_>>(&metadata).map_err(|error| invalid_flatbuffer(&error))?;
+        let version = message.version();
+        if version < format::METADATA_VERSION_V4 {
+            return Err(ReadError::UnsupportedVersion { version });
+        }
+        Ok(Self { metadata, body })
+    }
+
+    /// Replaces the message body.
+    pub(crate) fn with_body<Other>(self, body: Other) -> EncapsulatedMessage<Other> {
+        EncapsulatedMessage {
+            metadata: self.metadata,
+            body,
+        }
+    }
+
+    /// Returns the message metadata.
+    pub(crate) fn message(&self) -> Message<'_> {
+        format::root(&self.metadata).expect("message metadata was verified")
+    }
+
+    /// Returns the message body.
+    pub(crate) fn body(&self) -> &Body {
+        &self.body
+    }
+}
+
+/// Returns the error for metadata that is not a valid flatbuffer.
+fn invalid_flatbuffer(error: &flatbuffers::InvalidFlatbuffer) -> ReadError {
+    ReadError::InvalidMetadata {
+        reason: error.to_string(),
+    }
+}
+
+/// Reads the metadata length prefix of an encapsulated message, returning
+/// [`None`] at the end of the stream.
+///
+/// Streams end with an end-of-stream marker, or without one at a message
+/// boundary. Messages of writers before Arrow 0.15 start without a
+/// continuation marker.
+fn read_metadata_length<R: Read>(reader: &mut R) -> Result<Option<usize>, ReadError> {
+    let mut prefix = [0; 4];
+    let mut filled = 0;
+    while filled < prefix.len() {
+        match reader.read(&mut prefix[filled..]) {
+            Ok(0) if filled == 0 => return Ok(None),
+            Ok(0) => return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into()),
+            Ok(read) => filled = filled.strict_add(read),
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+            Err(error) => return Err(error.into()),
+        }
+    }
+    if prefix == format::CONTINUATION {
+        reader.read_exact(&mut prefix)?;
+    }
+    let length = i32::from_le_bytes(prefix);
+    match usize::try_from(length) {
+        Ok(0) => Ok(None),
+        Ok(metadata_length) => Ok(Some(metadata_length)),
+        Err(_) => Err(ReadError::InvalidMessageLength {
+            length: i64::from(length),
+        }),
+    }
+}
+
+/// Reads the next encapsulated message of a stream, returning [`None`] at the
+/// end of the stream.
+pub(crate) fn read_message<R: Read>(
+    reader: &mut R,
+) -> Result<Option<EncapsulatedMessage<MessageBody>>, ReadError> {
+    let Some(metadata_length) = read_metadata_length(reader)? else {
+        return Ok(None);
+    };
+    let mut metadata = vec![0; metadata_length];
+    reader.read_exact(&mut metadata)?;
+    let message = EncapsulatedMessage::try_new(metadata, ())?;
+    let length = message.message().body_length();
+    let body_length =
+        usize::try_from(length).map_err(|_| ReadError::InvalidMessageLength { length })?;
+    let body = MessageBody::read(reader, body_length)?;
+    Ok(Some(message.with_body(body)))
+}
+
+/// The dictionary batches of a stream, by identifier.
+///
+/// The values are decoded when a record batch references them, because only
+/// the requested array type determines their memory layout.
+#[derive(Debug)]
+pub(crate) struct Dictionaries<Body>(HashMap<i64, EncapsulatedMessage<Body>>);
+
+impl<Body> Default for Dictionaries<Body> {
+    fn default() -> Self {
+        Self(HashMap::new())
+    }
+}
+
+impl<Body> Dictionaries<Body> {
+    /// Stores a dictionary batch message, replacing an earlier dictionary
+    /// with the same identifier.
+    pub(crate) fn insert(&mut self, message: EncapsulatedMessage<Body>) -> Result<(), ReadError> {
+        let batch = message
+            .message()
+            .header_as_dictionary_batch()
+            .expect("message is a dictionary batch");
+        let id = batch.id();
+        if batch.is_delta() {
+            return Err(ReadError::DeltaDictionary { id });
+        }
+        if batch.data().is_none() {
+            return Err(ReadError::InvalidMetadata {
+                reason: format!("dictionary batch {id} has no data"),
+            });
+        }
+        self.0.insert(id, message);
+        Ok(())
+    }
+}
+
+/// Sequential access to the field nodes and buffers of a record batch.
+#[derive(Debug)]
+pub struct BatchReader<'batch, Storage: ReadBuffer> {
+    /// The message body.
+    body: &'batch Storage::Body,
+    /// Remaining field nodes.
+    nodes: vec::IntoIter<FieldNode>,
+    /// Remaining buffers.
+    buffers: vec::IntoIter<BufferRegion>,
+    /// Dictionaries referenced by the batch.
+    dictionaries: &'batch Dictionaries<Storage::Body>,
+}
+
+impl<'batch, Storage: ReadBuffer> BatchReader<'batch, Storage> {
+    /// Constructs a reader of the field nodes and buffers of `batch` in
+    /// `body`.
+    pub(crate) fn try_new(
+        batch: RecordBatch<'_>,
+        body: &'batch Storage::Body,
+        dictionaries: &'batch Dictionaries<Storage::Body>,
+    ) -> Result<Self, ReadError> {
+        if batch.is_compressed() {
+            return Err(ReadError::Compressed);
+        }
+        Ok(Self {
+            body,
+            nodes: batch
+                .nodes()
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>()
+                .into_iter(),
+            buffers: batch
+                .buffers()
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>()
+                .into_iter(),
+            dictionaries,
+        })
+    }
+
+    /// Returns the length and null count of the next field node.
+    fn node(&mut self) -> Result<(usize, usize), ReadError> {
+        let node = self.nodes.next().ok_or(ReadError::MissingFieldNode)?;
+        match (
+            usize::try_from(node.length),
+            usize::try_from(node.null_count),
+        ) {
+            (Ok(length), Ok(null_count)) if null_count <= length => Ok((length, null_count)),
+            _ => Err(ReadError::InvalidFieldNode {
+                length: node.length,
+                null_count: node.null_count,
+            }),
+        }
+    }
+
+    /// Returns the byte offset and length of the next buffer.
+    fn region(&mut self) -> Result<(usize, usize), ReadError> {
+        let region = self.buffers.next().ok_or(ReadError::MissingBuffer)?;
+        let body = Storage::body_len(self.body);
+        match (
+            usize::try_from(region.offset),
+            usize::try_from(region.length),
+        ) {
+            (Ok(offset), Ok(length))
+                if offset.checked_add(length).is_some_and(|end| end <= body) =>
+            {
+                Ok((offset, length))
+            }
+            _ => Err(ReadError::InvalidBuffer {
+                offset: region.offset,
+                length: region.length,
+            }),
+        }
+    }
+
+    /// Returns the next buffer, which contains at least `len` values.
+    fn buffer<T: FixedSize>(&mut self, len: usize) -> Result<Storage::For<T>, ReadError> {
+        let (offset, length) = self.region()?;
+        let required = len.saturating_mul(mem::size_of::<T>());
+        if length < required {
+            return Err(ReadError::BufferTooSmall { length, required });
+        }
+        Ok(Storage::read_buffer(self.body, offset, len))
+    }
+
+    /// Returns all bytes of the next buffer.
+    ///
+    /// The offsets of a variable-size layout determine how many of them are
+    /// used, so writers may pad the buffer or keep bytes of a sliced array.
+    fn bytes(&mut self) -> Result<Storage::For<u8>, ReadError> {
+        let (offset, length) = self.region()?;
+        Ok(Storage::read_buffer(self.body, offset, length))
+    }
+
+    /// Returns the next buffer as a bitmap of `len` bits.
+    fn bits(&mut self, len: usize) -> Result<Bitmap<Storage>, ReadError> {
+        let bytes = self.buffer::<u8>(len.div_ceil(8))?;
+        Ok(Bitmap::try_from_parts(bytes, len, 0).expect("buffer contains the required bits"))
+    }
+
+    /// Returns the next buffer as an optional validity bitmap of `len` bits.
+    ///
+    /// Writers may omit the validity buffer of a field without nulls.
+    fn validity(&mut self, len: usize) -> Result<Option<Bitmap<Storage>>, ReadError> {
+        match self.buffers.as_slice().first() {
+            Some(region) if region.length == 0 => {
+                self.region()?;
+                Ok(None)
+            }
+            _ => self.bits(len).map(Some),
+        }
+    }
+
+    /// Returns the next buffer as the `len + 1` offsets of `len` items.
+    ///
+    /// Writers may omit the offsets of an empty array.
+    fn offsets<OffsetItem: Offset>(
+        &mut self,
+        len: usize,
+    ) -> Result<Storage::For<OffsetItem>, ReadError> {
+        match self.buffers.as_slice().first() {
+            Some(region) if len == 0 && region.length == 0 => {
+                self.region()?;
+                Ok(Storage::copy_buffer(&[OffsetItem::default()]))
+            }
+            _ => self.buffer(len.strict_add(1)),
+        }
+    }
+
+    /// Reads the values of dictionary `id` as a `Layout` described by `field`.
+    fn dictionary<Layout: ReadLayout<Storage>>(
+        &self,
+        id: i64,
+        field: &Field,
+    ) -> Result<Layout, ReadError> {
+        let message = self
+            .dictionaries
+            .0
+            .get(&id)
+            .ok_or(ReadError::MissingDictionary { id })?;
+        let batch = message
+            .message()
+            .header_as_dictionary_batch()
+            .and_then(|batch| batch.data())
+            .expect("stored dictionary batches have data");
+        let length = batch_length(batch)?;
+        let mut reader = BatchReader::try_new(batch, &message.body, self.dictionaries)?;
+        let values = Layout::read(field, &mut reader)?;
+        check_length(length, values.len())?;
+        Ok(values)
+    }
+}
+
+/// Returns the number of rows of `batch`.
+pub(crate) fn batch_length(batch: RecordBatch<'_>) -> Result<usize, ReadError> {
+    let length = batch.length();
+    usize::try_from(length).map_err(|_| ReadError::InvalidFieldNode {
+        length,
+        null_count: 0,
+    })
+}
+
+/// Returns an error when a child array or column does not have the
+/// `expected` length.
+fn check_length(expected: usize, actual: usize) -> Result<(), ReadError> {
+    if expected == actual {
+        Ok(())
+    } else {
+        Err(ReadError::LengthMismatch { expected, actual })
+    }
+}
+
+/// Read behavior for a [`Nullability`] type constructor.
+pub trait ReadNullability: Nullability {
+    /// Wraps a read collection with this nullability.
+    fn wrap<T, Storage>(
+        collection: T,
+        validity: Option<Bitmap<Storage>>,
+        null_count: usize,
+    ) -> Result<Self::Collection<T, Storage>, ReadError>
+    where
+        T: Collection,
+        Storage: Buffer;
+}
+
+impl ReadNullability for NonNullable {
+    fn wrap<T, Storage>(
+        collection: T,
+        _validity: Option<Bitmap<Storage>>,
+        null_count: usize,
+    ) -> Result<T, ReadError>
+    where
+        T: Collection,
+        Storage: Buffer,
+    {
+        if null_count == 0 {
+            Ok(collection)
+        } else {
+            Err(ReadError::UnexpectedNulls { null_count })
+        }
+    }
+}
+
+impl ReadNullability for Nullable {
+    fn wrap<T, Storage>(
+        collection: T,
+        validity: Option<Bitmap<Storage>>,
+        null_count: usize,
+    ) -> Result<Validity<T, Storage>, ReadError>
+    where
+        T: Collection,
+        Storage: Buffer,
+    {
+        match validity {
+            Some(bitmap) => {
+                let nullable = Validity::try_from_parts(collection, bitmap)
+                    .expect("validity bitmap has the length of the field node");
+                let actual = nullable.null_count();
+                if actual == null_count {
+                    Ok(nullable)
+                } else {
+                    Err(ReadError::NullCountMismatch {
+                        declared: null_count,
+                        actual,
+                    })
+                }
+            }
+            None if null_count == 0 => Ok(Validity::from_collection(collection)),
+            None => Err(ReadError::MissingValidityBuffer),
+        }
+    }
+}
+
+/// A memory layout that can be read from a record batch.
+pub trait ReadLayout<Storage: ReadBuffer>: Length + Sized {
+    /// Returns whether `field` describes this memory layout.
+    ///
+    /// Nullability is not compared: a non-nullable layout reads a nullable
+    /// field without nulls, and a nullable layout reads any field.
+    fn matches(field: &Field) -> bool;
+
+    /// Reads the field nodes and buffers of this layout, described by a
+    /// matching `field`.
+    fn read(field: &Field, reader: &mut BatchReader<'_, Storage>) -> Result<Self, ReadError>;
+}
+
+/// A record batch layout that can be read.
+pub trait ReadBatch<Storage: ReadBuffer>: Sized {
+    /// Returns whether `schema` describes this record batch layout.
+    fn matches_schema(schema: &Schema) -> bool;
+
+    /// Reads a record batch of `length` rows described by a matching
+    /// `schema`.
+    fn read_batch(
+        schema: &Schema,
+        length: usize,
+        reader: &mut BatchReader<'_, Storage>,
+    ) -> Result<Self, ReadError>;
+}
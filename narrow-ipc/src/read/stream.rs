@@ -0,0 +1,457 @@
+//! Read support for the Arrow IPC streaming format.
+
+use std::{io::Read, marker::PhantomData};
+
+use narrow::{array::Array, layout::ArrayItem};
+
+use crate::{IpcBuffer, Schema, buffer::MessageBody, format};
+
+use super::{
+    BatchReader, Dictionaries, EncapsulatedMessage, ReadBatch, ReadError, batch_length,
+    read_message,
+};
+
+/// A reader of [`Array`]s of `T` from the Arrow IPC streaming format.
+///
+/// The schema of the stream must match the fields of the struct `T`: the
+/// number, order and types of the columns are compared, but not their names.
+/// A nullable layout reads a non-nullable column and a non-nullable layout
+/// reads a nullable column without nulls.
+///
+/// Each record batch is read as an array whose buffers share the message
+/// body, so reading a batch allocates once for the body and never copies
+/// aligned values.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{array::Array, collection::Collection};
+/// use narrow_ipc::{StreamReader, StreamWriter};
+///
+/// let mut writer = StreamWriter::<_, (u16, Vec<bool>)>::try_new(Vec::new()).unwrap();
+/// writer.write(&[(1, vec![true])].into_iter().collect::<Array<_>>()).unwrap();
+/// writer.write(&[(2, vec![])].into_iter().collect::<Array<_>>()).unwrap();
+/// let bytes = writer.finish().unwrap();
+///
+/// let reader = StreamReader::<_, (u16, Vec<bool>)>::try_new(bytes.as_slice()).unwrap();
+/// let rows = reader
+///     .map(|batch| batch.unwrap().into_iter_owned())
+///     .flatten()
+///     .collect::<Vec<_>>();
+/// assert_eq!(rows, [(1, vec![true]), (2, vec![])]);
+/// ```
+#[derive(Debug)]
+pub struct StreamReader<R: Read, T> {
+    /// Reader of the stream.
+    reader: R,
+    /// Schema of the stream.
+    schema: Schema,
+    /// Dictionaries read so far.
+    dictionaries: Dictionaries<MessageBody>,
+    /// Whether the end of the stream or an error was reached.
+    finished: bool,
+    /// Type of the rows.
+    _rows: PhantomData<fn() -> T>,
+}
+
+impl<R: Read, T: ArrayItem> StreamReader<R, T>
+where
+    T::Memory<IpcBuffer>: ReadBatch<IpcBuffer>,
+{
+    /// Constructs a [`StreamReader`] and reads the schema from `reader`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ReadError`] when the schema cannot be read, or when it
+    /// does not match `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow_ipc::{ReadError, StreamReader, StreamWriter};
+    ///
+    /// let bytes = StreamWriter::<_, (u8,)>::try_new(Vec::new()).unwrap().finish().unwrap();
+    /// assert!(matches!(
+    ///     StreamReader::<_, (String,)>::try_new(bytes.as_slice()),
+    ///     Err(ReadError::SchemaMismatch)
+    /// ));
+    /// ```
+    pub fn try_new(mut reader: R) -> Result<Self, ReadError> {
+        let message = read_message(&mut reader)?.ok_or(ReadError::MissingSchema)?;
+        let header = message.message();
+        let table = header
+            .header_as_schema()
+            .ok_or(ReadError::UnexpectedMessage {
+                header_type: header.header_type(),
+            })?;
+        if table.endianness() != format::ENDIANNESS_NATIVE {
+            return Err(ReadError::NonNativeEndianness);
+        }
+        let schema = Schema::from_table(table)?;
+        if !<T::Memory<IpcBuffer>>::matches_schema(&schema) {
+            return Err(ReadError::SchemaMismatch);
+        }
+        Ok(Self {
+            reader,
+            schema,
+            dictionaries: Dictionaries::default(),
+            finished: false,
+            _rows: PhantomData,
+        })
+    }
+
+    /// Returns the schema of the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow_ipc::{StreamReader, StreamWriter};
+    ///
+    /// let bytes = StreamWriter::<_, (u8, u16)>::try_new(Vec::new()).unwrap().finish().unwrap();
+    /// let reader = StreamReader::<_, (u8, u16)>::try_new(bytes.as_slice()).unwrap();
+    /// assert_eq!(reader.schema().fields.len(), 2);
+    /// ```
+    pub fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    /// Reads messages up to and including the next record batch.
+    fn next_batch(&mut self) -> Result<Option<Array<T, IpcBuffer>>, ReadError> {
+        while let Some(message) = read_message(&mut self.reader)? {
+            match message.message().header_type() {
+                format::HEADER_DICTIONARY_BATCH => self.dictionaries.insert(message)?,
+                format::HEADER_RECORD_BATCH => return self.record_batch(&message).map(Some),
+                header_type => return Err(ReadError::UnexpectedMessage { header_type }),
+            }
+        }
+        Ok(None)
+    }
+
+    /// Decodes a record batch message.
+    fn record_batch(
+        &self,
+        message: &EncapsulatedMessage<MessageBody>,
+    ) -> Result<Array<T, IpcBuffer>, ReadError> {
+        let batch =
+            message
+                .message()
+                .header_as_record_batch()
+                .ok_or(ReadError::InvalidMetadata {
+                    reason: "record batch message has no header".to_owned(),
+                })?;
+        let length = batch_length(batch)?;
+        let mut reader = BatchReader::try_new(batch, message.body(), &self.dictionaries)?;
+        let memory = <T::Memory<IpcBuffer>>::read_batch(&self.schema, length, &mut reader)?;
+        Ok(Array::from_buffer(memory))
+    }
+}
+
+impl<R: Read, T: ArrayItem> Iterator for StreamReader<R, T>
+where
+    T::Memory<IpcBuffer>: ReadBatch<IpcBuffer>,
+{
+    type Item = Result<Array<T, IpcBuffer>, ReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let batch = self.next_batch();
+        self.finished = !matches!(batch, Ok(Some(_)));
+        batch.transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_array::{
+        ArrayRef, BooleanArray, DictionaryArray, FixedSizeListArray, Float64Array, ListArray,
+        RecordBatch, StringArray, StructArray, UInt8Array,
+        types::{Int8Type, Int32Type},
+    };
+    use arrow_schema::{DataType, Field};
+    use narrow::{ArrayType, collection::Collection, layout::dictionary::Dictionary};
+
+    use crate::StreamWriter;
+
+    use super::*;
+
+    #[derive(ArrayType, Clone, Debug, Default, PartialEq)]
+    struct Inner {
+        flag: bool,
+        values: Option<Vec<Option<i16>>>,
+    }
+
+    #[derive(ArrayType, Clone, Debug, Default, PartialEq)]
+    struct Row {
+        id: u32,
+        score: f64,
+        name: String,
+        note: Option<String>,
+        pair: [i32; 2],
+        inner: Option<Inner>,
+        color: Dictionary<u8, String>,
+        level: Option<Dictionary<i16, Vec<u64>>>,
+    }
+
+    fn rows(count: u32) -> Vec<Row> {
+        (0..count)
+            .map(|id| Row {
+                id,
+                score: f64::from(id) / 2.0,
+                name: "x".repeat(usize::try_from(id).expect("value fits")),
+                note: (!id.is_multiple_of(3)).then(|| format!("note {id}")),
+                pair: [i32::try_from(id).expect("value fits"), -1],
+                inner: (id % 4 != 1).then(|| Inner {
+                    flag: id.is_multiple_of(2),
+                    values: (id % 5 != 2).then(|| vec![Some(1), None, Some(3)]),
+                }),
+                color: Dictionary::new(
+                    ["red", "green"][usize::try_from(id % 2).expect("value fits")].to_owned(),
+                ),
+                level: id
+                    .is_multiple_of(2)
+                    .then(|| Dictionary::new(vec![u64::from(id % 3)])),
+            })
+            .collect()
+    }
+
+    fn write<T>(rows: &[T]) -> Vec<u8>
+    where
+        T: ArrayItem + Clone,
+        T::Memory<narrow::buffer::VecBuffer>:
+            crate::write::BatchSchema + for<'any> crate::write::WriteBatch<'any>,
+        Array<T>: FromIterator<T>,
+    {
+        let mut writer = StreamWriter::<_, T>::try_new(Vec::new()).expect("write schema");
+        writer
+            .write(&rows.iter().cloned().collect::<Array<T>>())
+            .expect("write batch");
+        writer.finish().expect("finish stream")
+    }
+
+    #[test]
+    fn round_trip() {
+        let expected = rows(20);
+        let mut writer = StreamWriter::<_, Row>::try_new(Vec::new()).expect("write schema");
+        for chunk in expected.chunks(7) {
+            writer
+                .write(&chunk.iter().cloned().collect::<Array<Row>>())
+                .expect("write batch");
+        }
+        let bytes = writer.finish().expect("finish stream");
+
+        let reader = StreamReader::<_, Row>::try_new(bytes.as_slice()).expect("read schema");
+        let batches = reader.collect::<Result<Vec<_>, _>>().expect("read batches");
+        assert_eq!(batches.len(), 3);
+        let actual = batches
+            .into_iter()
+            .flat_map(Collection::into_iter_owned)
+            .collect::<Vec<_>>();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn round_trip_slices() {
+        let expected = rows(20);
+        let array = expected.iter().cloned().collect::<Array<Row>>();
+        let mut writer = StreamWriter::<_, Row>::try_new(Vec::new()).expect("write schema");
+        writer.write(&array.slice(3, 9)).expect("write batch");
+        writer.write(&array.slice(19, 1)).expect("write batch");
+        writer.write(&array.slice(20, 0)).expect("write batch");
+        let bytes = writer.finish().expect("finish stream");
+
+        let reader = StreamReader::<_, Row>::try_new(bytes.as_slice()).expect("read schema");
+        let lens = reader
+            .map(|batch| {
+                batch
+                    .expect("read batch")
+                    .into_iter_owned()
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(lens, [&expected[3..12], &expected[19..], &[]]);
+    }
+
+    #[test]
+    fn nullable_layouts_read_non_nullable_columns() {
+        let bytes = write(&[(1_u8, "a".to_owned())]);
+        let mut reader = StreamReader::<_, (Option<u8>, Option<String>)>::try_new(bytes.as_slice())
+            .expect("read schema");
+        assert_eq!(
+            reader
+                .next()
+                .expect("batch")
+                .expect("read batch")
+                .into_iter_owned()
+                .collect::<Vec<_>>(),
+            [(Some(1), Some("a".to_owned()))]
+        );
+    }
+
+    #[test]
+    fn non_nullable_layouts_reject_nulls() {
+        let bytes = write(&[(Some(1_u8),), (None,)]);
+        let mut reader = StreamReader::<_, (u8,)>::try_new(bytes.as_slice()).expect("read schema");
+        assert!(matches!(
+            reader.next(),
+            Some(Err(ReadError::UnexpectedNulls { null_count: 1 }))
+        ));
+        assert!(reader.next().is_none());
+
+        let valid = write(&[(Some(1_u8),)]);
+        let mut valid_reader =
+            StreamReader::<_, (u8,)>::try_new(valid.as_slice()).expect("read schema");
+        assert_eq!(
+            valid_reader
+                .next()
+                .expect("batch")
+                .expect("read batch")
+                .into_iter_owned()
+                .collect::<Vec<_>>(),
+            [(1,)]
+        );
+    }
+
+    #[test]
+    fn schema_mismatch() {
+        let bytes = write(&[(1_u8, 2_u16)]);
+        assert!(matches!(
+            StreamReader::<_, (u8,)>::try_new(bytes.as_slice()),
+            Err(ReadError::SchemaMismatch)
+        ));
+        assert!(matches!(
+            StreamReader::<_, (u8, i16)>::try_new(bytes.as_slice()),
+            Err(ReadError::SchemaMismatch)
+        ));
+        assert!(matches!(
+            StreamReader::<_, (u8, Dictionary<u8, u16>)>::try_new(bytes.as_slice()),
+            Err(ReadError::SchemaMismatch)
+        ));
+    }
+
+    #[test]
+    fn missing_schema() {
+        assert!(matches!(
+            StreamReader::<_, (u8,)>::try_new([].as_slice()),
+            Err(ReadError::MissingSchema)
+        ));
+    }
+
+    #[test]
+    fn truncated_stream() {
+        let bytes = write(&[(1_u64,), (2,)]);
+        // Drop the end-of-stream marker and the last bytes of the body.
+        let truncated = &bytes[..bytes.len().strict_sub(12)];
+        let mut reader = StreamReader::<_, (u64,)>::try_new(truncated).expect("read schema");
+        assert!(matches!(reader.next(), Some(Err(ReadError::Io(_)))));
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn streams_without_end_of_stream_marker() {
+        let bytes = write(&[(1_u64,)]);
+        let unterminated = &bytes[..bytes.len().strict_sub(8)];
+        let reader = StreamReader::<_, (u64,)>::try_new(unterminated).expect("read schema");
+        assert_eq!(reader.count(), 1);
+    }
+
+    #[test]
+    fn reads_arrow_ipc_streams() {
+        type Columns = (
+            u8,
+            Option<f64>,
+            String,
+            Option<Vec<Option<i32>>>,
+            [i32; 2],
+            (bool,),
+            Option<Dictionary<i8, String>>,
+        );
+
+        let flags: ArrayRef = Arc::new(BooleanArray::from(vec![true, false, true]));
+        let inner = StructArray::from(vec![(
+            Arc::new(Field::new("flag", DataType::Boolean, false)),
+            flags,
+        )]);
+        let columns: Vec<(&str, ArrayRef)> = vec![
+            ("id", Arc::new(UInt8Array::from(vec![1, 2, 3]))),
+            (
+                "score",
+                Arc::new(Float64Array::from(vec![Some(0.5), None, Some(1.5)])),
+            ),
+            ("name", Arc::new(StringArray::from(vec!["a", "bc", ""]))),
+            (
+                "list",
+                Arc::new(ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+                    Some(vec![Some(1), None]),
+                    None,
+                    Some(vec![]),
+                ])),
+            ),
+            (
+                "pair",
+                Arc::new(FixedSizeListArray::from_iter_primitive::<Int32Type, _, _>(
+                    vec![
+                        Some(vec![Some(1), Some(2)]),
+                        Some(vec![Some(3), Some(4)]),
+                        Some(vec![Some(5), Some(6)]),
+                    ],
+                    2,
+                )),
+            ),
+            ("inner", Arc::new(inner)),
+            (
+                "color",
+                Arc::new(
+                    vec![Some("red"), None, Some("red")]
+                        .into_iter()
+                        .collect::<DictionaryArray<Int8Type>>(),
+                ),
+            ),
+        ];
+        let record_batch = RecordBatch::try_from_iter(columns).expect("record batch");
+        let mut writer =
+            arrow_ipc::writer::StreamWriter::try_new(Vec::new(), &record_batch.schema())
+                .expect("write schema");
+        writer.write(&record_batch).expect("write batch");
+        writer
+            .write(&record_batch.slice(1, 2))
+            .expect("write batch");
+        writer.finish().expect("finish stream");
+        let bytes = writer.into_inner().expect("stream bytes");
+
+        let reader = StreamReader::<_, Columns>::try_new(bytes.as_slice()).expect("read schema");
+        let batches = reader
+            .map(|batch| {
+                batch
+                    .expect("read batch")
+                    .into_iter_owned()
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let expected: [Columns; 3] = [
+            (
+                1,
+                Some(0.5),
+                "a".to_owned(),
+                Some(vec![Some(1), None]),
+                [1, 2],
+                (true,),
+                Some(Dictionary::new("red".to_owned())),
+            ),
+            (2, None, "bc".to_owned(), None, [3, 4], (false,), None),
+            (
+                3,
+                Some(1.5),
+                String::new(),
+                Some(vec![]),
+                [5, 6],
+                (true,),
+                Some(Dictionary::new("red".to_owned())),
+            ),
+        ];
+        assert_eq!(batches, [&expected[..], &expected[1..]]);
+    }
+}
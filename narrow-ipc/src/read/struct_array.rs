@@ -0,0 +1,128 @@
+//! Read support for [`Struct`].
+
+use narrow::{
+    layout::{
+        MemoryLayout,
+        struct_array::{Struct, StructFields, StructLayout},
+    },
+    nullability::NonNullable,
+};
+
+use crate::{DataType, Field, Schema};
+
+use super::{BatchReader, ReadBatch, ReadBuffer, ReadError, ReadLayout, ReadNullability};
+
+/// The children of a struct, as a nested list of pairs.
+pub trait ReadColumns<Storage: ReadBuffer>: Sized {
+    /// Returns whether `fields` describe these children.
+    fn matches_fields(fields: &[Field]) -> bool;
+
+    /// Reads the first `len` items of every child, described by matching
+    /// `fields`.
+    fn read_columns(
+        fields: &[Field],
+        len: usize,
+        reader: &mut BatchReader<'_, Storage>,
+    ) -> Result<Self, ReadError>;
+}
+
+impl<Storage: ReadBuffer> ReadColumns<Storage> for () {
+    fn matches_fields(fields: &[Field]) -> bool {
+        fields.is_empty()
+    }
+
+    fn read_columns(
+        _fields: &[Field],
+        _len: usize,
+        _reader: &mut BatchReader<'_, Storage>,
+    ) -> Result<Self, ReadError> {
+        Ok(())
+    }
+}
+
+impl<Storage, Head, Tail> ReadColumns<Storage> for (Head, Tail)
+where
+    Storage: ReadBuffer,
+    Head: ReadLayout<Storage> + MemoryLayout,
+    Tail: ReadColumns<Storage>,
+{
+    fn matches_fields(fields: &[Field]) -> bool {
+        fields
+            .split_first()
+            .is_some_and(|(field, rest)| Head::matches(field) && Tail::matches_fields(rest))
+    }
+
+    fn read_columns(
+        fields: &[Field],
+        len: usize,
+        reader: &mut BatchReader<'_, Storage>,
+    ) -> Result<Self, ReadError> {
+        let (field, rest) = fields.split_first().expect("fields match the children");
+        let child = Head::read(field, reader)?;
+        let actual = child.len();
+        if actual < len {
+            return Err(ReadError::LengthMismatch {
+                expected: len,
+                actual,
+            });
+        }
+        // Writers may keep child items past the end of a sliced struct array.
+        Ok((child.slice(0, len), Tail::read_columns(rest, len, reader)?))
+    }
+}
+
+impl<T, Nulls, Storage> ReadLayout<Storage> for Struct<T, Nulls, Storage>
+where
+    T: StructLayout,
+    Nulls: ReadNullability,
+    Storage: ReadBuffer,
+    <T::Fields<Storage> as StructFields>::Children: ReadColumns<Storage>,
+{
+    fn matches(field: &Field) -> bool {
+        match field.data_type {
+            DataType::Struct(ref fields) => {
+                field.dictionary.is_none()
+                    && <T::Fields<Storage> as StructFields>::Children::matches_fields(fields)
+            }
+            _ => false,
+        }
+    }
+
+    fn read(field: &Field, reader: &mut BatchReader<'_, Storage>) -> Result<Self, ReadError> {
+        let (len, null_count) = reader.node()?;
+        let validity = reader.validity(len)?;
+        let children = <T::Fields<Storage> as StructFields>::Children::read_columns(
+            field.children(),
+            len,
+            reader,
+        )?;
+        let fields = T::Fields::<Storage>::from_children(children);
+        Nulls::wrap(fields, validity, null_count).map(Self::from_buffer)
+    }
+}
+
+impl<T, Storage> ReadBatch<Storage> for Struct<T, NonNullable, Storage>
+where
+    T: StructLayout,
+    Storage: ReadBuffer,
+    <T::Fields<Storage> as StructFields>::Children: ReadColumns<Storage>,
+{
+    fn matches_schema(schema: &Schema) -> bool {
+        <T::Fields<Storage> as StructFields>::Children::matches_fields(&schema.fields)
+    }
+
+    fn read_batch(
+        schema: &Schema,
+        length: usize,
+        reader: &mut BatchReader<'_, Storage>,
+    ) -> Result<Self, ReadError> {
+        let children = <T::Fields<Storage> as StructFields>::Children::read_columns(
+            &schema.fields,
+            length,
+            reader,
+        )?;
+        Ok(Self::from_buffer(T::Fields::<Storage>::from_children(
+            children,
+        )))
+    }
+}
@@ -0,0 +1,29 @@
+//! Read support for [`Utf8`].
+
+use narrow::layout::utf8::{Utf8, Utf8Item, Utf8Values};
+
+use crate::{Field, schema::ListOffset};
+
+use super::{BatchReader, ReadBuffer, ReadError, ReadLayout, ReadNullability};
+
+impl<Nulls, OffsetItem, Storage, U> ReadLayout<Storage> for Utf8<Nulls, OffsetItem, Storage, U>
+where
+    Nulls: ReadNullability,
+    OffsetItem: ListOffset,
+    Storage: ReadBuffer,
+    U: Utf8Item,
+{
+    fn matches(field: &Field) -> bool {
+        field.data_type == OffsetItem::UTF8 && field.dictionary.is_none()
+    }
+
+    fn read(_field: &Field, reader: &mut BatchReader<'_, Storage>) -> Result<Self, ReadError> {
+        let (len, null_count) = reader.node()?;
+        let validity = reader.validity(len)?;
+        let offsets = reader.offsets::<OffsetItem>(len)?;
+        let data = reader.bytes()?;
+        let values = Utf8Values::try_from_parts(data, offsets)
+            .map_err(|error| ReadError::InvalidUtf8 { error })?;
+        Nulls::wrap(values, validity, null_count).map(Self::from_buffer)
+    }
+}
@@ -0,0 +1,36 @@
+//! Read support for [`VariableSizeList`].
+
+use narrow::{
+    layout::{ArrayItem, variable_size_list::VariableSizeList},
+    offset::Offsets,
+};
+
+use crate::{Field, schema::ListOffset};
+
+use super::{BatchReader, ReadBuffer, ReadError, ReadLayout, ReadNullability};
+
+impl<T, Nulls, OffsetItem, Storage> ReadLayout<Storage>
+    for VariableSizeList<T, Nulls, OffsetItem, Storage>
+where
+    T: ArrayItem,
+    Nulls: ReadNullability,
+    OffsetItem: ListOffset,
+    Storage: ReadBuffer,
+    T::Memory<Storage>: ReadLayout<Storage>,
+{
+    fn matches(field: &Field) -> bool {
+        field.dictionary.is_none()
+            && OffsetItem::list_child(&field.data_type).is_some_and(<T::Memory<Storage>>::matches)
+    }
+
+    fn read(field: &Field, reader: &mut BatchReader<'_, Storage>) -> Result<Self, ReadError> {
+        let child = OffsetItem::list_child(&field.data_type).expect("field is a list");
+        let (len, null_count) = reader.node()?;
+        let validity = reader.validity(len)?;
+        let offsets = reader.offsets::<OffsetItem>(len)?;
+        let values = <T::Memory<Storage>>::read(child, reader)?;
+        let lists = Offsets::try_from_parts(values, offsets)
+            .map_err(|error| ReadError::InvalidOffsets { error })?;
+        Nulls::wrap(lists, validity, null_count).map(Self::from_buffer)
+    }
+}
@@ -0,0 +1,421 @@
+//! Schemas of Arrow IPC streams.
+
+use flatbuffers::{FlatBufferBuilder, TableFinishedWIPOffset, WIPOffset};
+use narrow::offset::Offset;
+
+use crate::{ReadError, format};
+
+/// The schema of an Arrow IPC stream.
+///
+/// A stream starts with its schema, which describes one [`Field`] per column
+/// of every following record batch.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::array::Array;
+/// use narrow_ipc::{DataType, StreamReader, StreamWriter};
+///
+/// let mut writer = StreamWriter::<_, (u8, bool)>::try_new(Vec::new()).unwrap();
+/// writer.write(&[(1, true)].into_iter().collect::<Array<_>>()).unwrap();
+/// let bytes = writer.finish().unwrap();
+///
+/// let reader = StreamReader::<_, (u8, bool)>::try_new(bytes.as_slice()).unwrap();
+/// let fields = &reader.schema().fields;
+/// assert_eq!(fields[1].name, "1");
+/// assert_eq!(fields[1].data_type, DataType::Boolean);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Schema {
+    /// The top-level fields, one per column.
+    pub fields: Vec<Field>,
+}
+
+/// A named and typed field of a [`Schema`].
+///
+/// A dictionary-encoded field has the type of its dictionary values, and
+/// describes its keys with a [`DictionaryEncoding`], as in the IPC format.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Field {
+    /// Name of the field.
+    pub name: String,
+    /// Whether the field may contain null items.
+    pub nullable: bool,
+    /// Type of the items, or of the dictionary values.
+    pub data_type: DataType,
+    /// Dictionary encoding of the field.
+    pub dictionary: Option<DictionaryEncoding>,
+}
+
+/// The logical type of a [`Field`].
+///
+/// Only the types of the memory layouts that can be written and read by this
+/// crate are described. Reading a stream with another type fails with
+/// [`ReadError::UnsupportedType`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DataType {
+    /// Booleans, stored as a bitmap.
+    Boolean,
+    /// Integers.
+    Int(IntType),
+    /// Floating point numbers.
+    FloatingPoint(Precision),
+    /// UTF-8 strings with 32-bit offsets.
+    Utf8,
+    /// UTF-8 strings with 64-bit offsets.
+    LargeUtf8,
+    /// Variable-size lists with 32-bit offsets.
+    List(Box<Field>),
+    /// Variable-size lists with 64-bit offsets.
+    LargeList(Box<Field>),
+    /// Lists with the given number of items.
+    FixedSizeList(Box<Field>, usize),
+    /// Structs with one child per field.
+    Struct(Vec<Field>),
+}
+
+/// The width and signedness of an integer type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IntType {
+    /// Width in bits: 8, 16, 32 or 64.
+    pub bit_width: u8,
+    /// Whether the integer is signed.
+    pub signed: bool,
+}
+
+/// The precision of a floating point type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Precision {
+    /// 16-bit floating point numbers.
+    Half,
+    /// 32-bit floating point numbers.
+    Single,
+    /// 64-bit floating point numbers.
+    Double,
+}
+
+/// The dictionary encoding of a [`Field`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct DictionaryEncoding {
+    /// Identifier of the dictionary, unique within a stream.
+    pub id: i64,
+    /// Type of the keys.
+    pub index_type: IntType,
+    /// Whether the dictionary values are ordered.
+    pub ordered: bool,
+}
+
+/// A fixed-size primitive type with an Arrow [`DataType`].
+///
+/// # Examples
+///
+/// ```
+/// use narrow_ipc::{DataType, IntType, PrimitiveType};
+///
+/// assert_eq!(
+///     u16::DATA_TYPE,
+///     DataType::Int(IntType { bit_width: 16, signed: false })
+/// );
+/// ```
+pub trait PrimitiveType {
+    /// Arrow type of the values.
+    const DATA_TYPE: DataType;
+}
+
+/// An integer type that can be used for dictionary keys.
+pub trait IndexType {
+    /// Arrow type of the keys.
+    const INDEX_TYPE: IntType;
+}
+
+/// Implements [`PrimitiveType`] and [`IndexType`] for integer types.
+macro_rules! int_type {
+    ($($ty:ty => $bit_width:literal, $signed:literal;)+) => {
+        $(
+            impl IndexType for $ty {
+                const INDEX_TYPE: IntType = IntType {
+                    bit_width: $bit_width,
+                    signed: $signed,
+                };
+            }
+
+            impl PrimitiveType for $ty {
+                const DATA_TYPE: DataType = DataType::Int(<$ty>::INDEX_TYPE);
+            }
+        )+
+    };
+}
+
+int_type! {
+    i8 => 8, true;
+    i16 => 16, true;
+    i32 => 32, true;
+    i64 => 64, true;
+    u8 => 8, false;
+    u16 => 16, false;
+    u32 => 32, false;
+    u64 => 64, false;
+}
+
+impl PrimitiveType for f32 {
+    const DATA_TYPE: DataType = DataType::FloatingPoint(Precision::Single);
+}
+
+impl PrimitiveType for f64 {
+    const DATA_TYPE: DataType = DataType::FloatingPoint(Precision::Double);
+}
+
+/// A list offset type, which selects between the regular and large variants
+/// of the variable-size types.
+pub trait ListOffset: Offset {
+    /// Type of strings with these offsets.
+    const UTF8: DataType;
+
+    /// Returns the type of lists of `child` with these offsets.
+    fn list(child: Field) -> DataType;
+
+    /// Returns the child of `data_type` if it is a list with these offsets.
+    fn list_child(data_type: &DataType) -> Option<&Field>;
+}
+
+impl ListOffset for i32 {
+    const UTF8: DataType = DataType::Utf8;
+
+    fn list(child: Field) -> DataType {
+        DataType::List(Box::new(child))
+    }
+
+    fn list_child(data_type: &DataType) -> Option<&Field> {
+        match *data_type {
+            DataType::List(ref child) => Some(child),
+            _ => None,
+        }
+    }
+}
+
+impl ListOffset for i64 {
+    const UTF8: DataType = DataType::LargeUtf8;
+
+    fn list(child: Field) -> DataType {
+        DataType::LargeList(Box::new(child))
+    }
+
+    fn list_child(data_type: &DataType) -> Option<&Field> {
+        match *data_type {
+            DataType::LargeList(ref child) => Some(child),
+            _ => None,
+        }
+    }
+}
+
+impl Schema {
+    /// Decodes a schema from its flatbuffer table.
+    pub(crate) fn from_table(schema: format::Schema<'_>) -> Result<Self, ReadError> {
+        let fields = schema
+            .fields()
+            .into_iter()
+            .flatten()
+            .map(Field::from_table)
+            .collect::<Result<_, _>>()?;
+        Ok(Self { fields })
+    }
+
+    /// Encodes this schema as a flatbuffer table.
+    pub(crate) fn to_table(
+        &self,
+        fbb: &mut FlatBufferBuilder<'_>,
+    ) -> WIPOffset<TableFinishedWIPOffset> {
+        let fields = self
+            .fields
+            .iter()
+            .map(|field| field.to_table(fbb))
+            .collect::<Vec<_>>();
+        format::Schema::create(fbb, format::ENDIANNESS_NATIVE, &fields)
+    }
+}
+
+impl Field {
+    /// Constructs a field without a dictionary encoding.
+    pub(crate) fn new(name: impl Into<String>, nullable: bool, data_type: DataType) -> Self {
+        Self {
+            name: name.into(),
+            nullable,
+            data_type,
+            dictionary: None,
+        }
+    }
+
+    /// Returns the child fields of this field's type.
+    pub(crate) fn children(&self) -> &[Self] {
+        match self.data_type {
+            DataType::List(ref child)
+            | DataType::LargeList(ref child)
+            | DataType::FixedSizeList(ref child, _) => core::slice::from_ref(child),
+            DataType::Struct(ref children) => children,
+            DataType::Boolean
+            | DataType::Int(_)
+            | DataType::FloatingPoint(_)
+            | DataType::Utf8
+            | DataType::LargeUtf8 => &[],
+        }
+    }
+
+    /// Decodes a field from its flatbuffer table.
+    fn from_table(field: format::Field<'_>) -> Result<Self, ReadError> {
+        let children = field
+            .children()
+            .into_iter()
+            .flatten()
+            .map(Self::from_table)
+            .collect::<Result<Vec<_>, _>>()?;
+        let data_type = match field.type_type() {
+            format::TYPE_BOOL => DataType::Boolean,
+            format::TYPE_INT => DataType::Int(IntType::from_table(
+                field.type_as_int().ok_or_else(missing_type)?,
+            )?),
+            format::TYPE_FLOATING_POINT => {
+                let floating_point = field.type_as_floating_point().ok_or_else(missing_type)?;
+                DataType::FloatingPoint(match floating_point.precision() {
+                    format::PRECISION_HALF => Precision::Half,
+                    format::PRECISION_SINGLE => Precision::Single,
+                    format::PRECISION_DOUBLE => Precision::Double,
+                    precision => {
+                        return Err(ReadError::InvalidMetadata {
+                            reason: format!("unknown floating point precision {precision}"),
+                        });
+                    }
+                })
+            }
+            format::TYPE_UTF8 => DataType::Utf8,
+            format::TYPE_LARGE_UTF8 => DataType::LargeUtf8,
+            format::TYPE_LIST => DataType::List(single_child(children)?),
+            format::TYPE_LARGE_LIST => DataType::LargeList(single_child(children)?),
+            format::TYPE_FIXED_SIZE_LIST => {
+                let list_size = field
+                    .type_as_fixed_size_list()
+                    .ok_or_else(missing_type)?
+                    .list_size();
+                let size = usize::try_from(list_size).map_err(|_| ReadError::InvalidMetadata {
+                    reason: format!("negative fixed-size list size {list_size}"),
+                })?;
+                DataType::FixedSizeList(single_child(children)?, size)
+            }
+            format::TYPE_STRUCT => DataType::Struct(children),
+            type_id => return Err(ReadError::UnsupportedType { type_id }),
+        };
+        let dictionary = field
+            .dictionary()
+            .map(|dictionary| {
+                Ok::<_, ReadError>(DictionaryEncoding {
+                    id: dictionary.id(),
+                    // The IPC format defaults to signed 32-bit keys.
+                    index_type: dictionary.index_type().map_or(
+                        Ok(IntType {
+                            bit_width: 32,
+                            signed: true,
+                        }),
+                        IntType::from_table,
+                    )?,
+                    ordered: dictionary.is_ordered(),
+                })
+            })
+            .transpose()?;
+        Ok(Self {
+            name: field.name().unwrap_or_default().to_owned(),
+            nullable: field.nullable(),
+            data_type,
+            dictionary,
+        })
+    }
+
+    /// Encodes this field as a flatbuffer table.
+    fn to_table(&self, fbb: &mut FlatBufferBuilder<'_>) -> WIPOffset<TableFinishedWIPOffset> {
+        let children = self
+            .children()
+            .iter()
+            .map(|child| child.to_table(fbb))
+            .collect::<Vec<_>>();
+        let (type_type, type_table) = match self.data_type {
+            DataType::Boolean => (format::TYPE_BOOL, format::create_empty_table(fbb)),
+            DataType::Int(int) => (format::TYPE_INT, int.to_table(fbb)),
+            DataType::FloatingPoint(precision) => {
+                let precision_id = match precision {
+                    Precision::Half => format::PRECISION_HALF,
+                    Precision::Single => format::PRECISION_SINGLE,
+                    Precision::Double => format::PRECISION_DOUBLE,
+                };
+                (
+                    format::TYPE_FLOATING_POINT,
+                    format::FloatingPoint::create(fbb, precision_id),
+                )
+            }
+            DataType::Utf8 => (format::TYPE_UTF8, format::create_empty_table(fbb)),
+            DataType::LargeUtf8 => (format::TYPE_LARGE_UTF8, format::create_empty_table(fbb)),
+            DataType::List(_) => (format::TYPE_LIST, format::create_empty_table(fbb)),
+            DataType::LargeList(_) => (format::TYPE_LARGE_LIST, format::create_empty_table(fbb)),
+            DataType::FixedSizeList(_, size) => {
+                let list_size = i32::try_from(size).expect("fixed-size list size exceeds i32");
+                (
+                    format::TYPE_FIXED_SIZE_LIST,
+                    format::FixedSizeList::create(fbb, list_size),
+                )
+            }
+            DataType::Struct(_) => (format::TYPE_STRUCT, format::create_empty_table(fbb)),
+        };
+        let dictionary = self.dictionary.map(|dictionary| {
+            let index_type = dictionary.index_type.to_table(fbb);
+            format::DictionaryEncoding::create(fbb, dictionary.id, index_type, dictionary.ordered)
+        });
+        format::Field::create(
+            fbb,
+            &self.name,
+            self.nullable,
+            type_type,
+            type_table.as_union_value(),
+            dictionary,
+            &children,
+        )
+    }
+}
+
+impl IntType {
+    /// Decodes an integer type from its flatbuffer table.
+    fn from_table(int: format::Int<'_>) -> Result<Self, ReadError> {
+        let bit_width = int.bit_width();
+        match bit_width {
+            8 | 16 | 32 | 64 => Ok(Self {
+                bit_width: u8::try_from(bit_width).expect("valid bit width fits in u8"),
+                signed: int.is_signed(),
+            }),
+            _ => Err(ReadError::InvalidMetadata {
+                reason: format!("unsupported integer bit width {bit_width}"),
+            }),
+        }
+    }
+
+    /// Encodes this integer type as a flatbuffer table.
+    fn to_table(self, fbb: &mut FlatBufferBuilder<'_>) -> WIPOffset<TableFinishedWIPOffset> {
+        format::Int::create(fbb, i32::from(self.bit_width), self.signed)
+    }
+}
+
+/// Returns the only child field of a list field.
+fn single_child(children: Vec<Field>) -> Result<Box<Field>, ReadError> {
+    let [child]: [Field; 1] = children
+        .try_into()
+        .map_err(|_| ReadError::InvalidMetadata {
+            reason: "list field requires exactly one child".to_owned(),
+        })?;
+    Ok(Box::new(child))
+}
+
+/// Returns the error for a field without its type table.
+fn missing_type() -> ReadError {
+    ReadError::InvalidMetadata {
+        reason: "field type is missing".to_owned(),
+    }
+}
@@ -0,0 +1,33 @@
+//! Write support for [`Boolean`].
+
+use narrow::{
+    buffer::{Buffer, BufferRef},
+    layout::{MemoryLayout, boolean::Boolean},
+};
+
+use crate::{DataType, Field};
+
+use super::{Body, DictionaryIds, WriteLayout, WriteNullability, WriteSchema};
+
+impl<Nulls, Storage> WriteSchema for Boolean<Nulls, Storage>
+where
+    Nulls: WriteNullability,
+    Storage: Buffer,
+{
+    fn field(name: String, _ids: &mut DictionaryIds) -> Field {
+        Field::new(name, Nulls::NULLABLE, DataType::Boolean)
+    }
+}
+
+impl<'layout, Nulls, Storage> WriteLayout<'layout> for Boolean<Nulls, Storage>
+where
+    Nulls: WriteNullability,
+    Storage: Buffer,
+{
+    fn write_body(&'layout self, offset: usize, len: usize, body: &mut Body<'layout>) {
+        let start = self.offset().strict_add(offset);
+        let (values, bitmap) = Nulls::parts(self.buffer_ref());
+        body.validity(bitmap, start, len);
+        body.bits(values, start, len);
+    }
+}
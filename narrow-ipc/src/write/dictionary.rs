@@ -0,0 +1,75 @@
+//! Write support for [`DictionaryEncoded`].
+
+use std::borrow::Borrow;
+
+use narrow::{
+    buffer::{Buffer, BufferRef},
+    collection::ChildRef,
+    layout::{
+        MemoryLayout,
+        dictionary::{DictionaryEncoded, DictionaryKey, DictionaryValue},
+    },
+    length::Length,
+};
+
+use crate::{DictionaryEncoding, Field, schema::IndexType};
+
+use super::{Body, DictionaryBody, DictionaryIds, WriteLayout, WriteNullability, WriteSchema};
+
+impl<K, V, Nulls, Storage> WriteSchema for DictionaryEncoded<K, V, Nulls, Storage>
+where
+    K: DictionaryKey + IndexType,
+    V: DictionaryValue,
+    Nulls: WriteNullability,
+    Storage: Buffer,
+    V::Memory<Storage>: WriteSchema,
+{
+    fn field(name: String, ids: &mut DictionaryIds) -> Field {
+        let id = ids.next_id();
+        let values = <V::Memory<Storage>>::field(name, ids);
+        Field {
+            nullable: Nulls::NULLABLE,
+            dictionary: Some(DictionaryEncoding {
+                id,
+                index_type: K::INDEX_TYPE,
+                ordered: false,
+            }),
+            ..values
+        }
+    }
+}
+
+impl<'layout, K, V, Nulls, Storage> WriteLayout<'layout> for DictionaryEncoded<K, V, Nulls, Storage>
+where
+    K: DictionaryKey + IndexType,
+    V: DictionaryValue,
+    Nulls: WriteNullability,
+    Storage: Buffer,
+    V::Memory<Storage>: WriteLayout<'layout>,
+{
+    fn write_body(&'layout self, offset: usize, len: usize, body: &mut Body<'layout>) {
+        let start = self.offset().strict_add(offset);
+        let (dictionary_keys, bitmap) = Nulls::parts(self.buffer_ref());
+        body.validity(bitmap, start, len);
+        let keys: &[K] = dictionary_keys.buffer_ref().borrow();
+        body.values(&keys[start..start.strict_add(len)]);
+    }
+
+    fn write_dictionaries(
+        &'layout self,
+        ids: &mut DictionaryIds,
+        dictionaries: &mut Vec<DictionaryBody<'layout>>,
+    ) {
+        let id = ids.next_id();
+        let (keys, _) = Nulls::parts(self.buffer_ref());
+        let values = keys.child_ref();
+        values.write_dictionaries(ids, dictionaries);
+        let mut body = Body::default();
+        values.write_body(0, values.len(), &mut body);
+        dictionaries.push(DictionaryBody {
+            id,
+            length: values.len(),
+            body,
+        });
+    }
+}
@@ -0,0 +1,277 @@
+//! Write support for the Arrow IPC file format.
+
+use std::{collections::HashMap, io::Write, marker::PhantomData};
+
+use flatbuffers::FlatBufferBuilder;
+use narrow::{
+    array::Array,
+    buffer::{BufferRef, VecBuffer},
+    layout::ArrayItem,
+    length::Length,
+};
+
+use crate::{
+    Schema,
+    format::{self, Block, Footer},
+};
+
+use super::{
+    BatchSchema, Body, PADDING, WriteBatch, WriteError, write_dictionary, write_end_of_stream,
+    write_record_batch, write_schema,
+};
+
+/// A writer of [`Array`]s of `T` in the Arrow IPC file format.
+///
+/// The messages are those of a [`StreamWriter`](crate::StreamWriter),
+/// enclosed by magic bytes and followed by a footer with the schema and the
+/// location of every dictionary and record batch, which lets a
+/// [`FileReader`](crate::FileReader) read the batches in any order.
+///
+/// Dictionaries cannot be replaced in the file format: each dictionary is
+/// written with the first batch, and later batches must reference the same
+/// dictionary values.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Cursor;
+///
+/// use narrow::{array::Array, collection::Collection};
+/// use narrow_ipc::{FileReader, FileWriter};
+///
+/// let mut writer = FileWriter::<_, (u8, Option<bool>)>::try_new(Vec::new()).unwrap();
+/// writer.write(&[(1, Some(true))].into_iter().collect::<Array<_>>()).unwrap();
+/// writer.write(&[(2, None)].into_iter().collect::<Array<_>>()).unwrap();
+/// let bytes = writer.finish().unwrap();
+/// assert!(bytes.starts_with(b"ARROW1") && bytes.ends_with(b"ARROW1"));
+///
+/// let mut reader = FileReader::<_, (u8, Option<bool>)>::try_new(Cursor::new(bytes)).unwrap();
+/// let batch = reader.batch(1).unwrap();
+/// assert_eq!(batch.into_iter_owned().collect::<Vec<_>>(), [(2, None)]);
+/// ```
+#[derive(Debug)]
+pub struct FileWriter<W: Write, T> {
+    /// Writer of the file.
+    writer: W,
+    /// Number of bytes written so far.
+    position: usize,
+    /// Schema of the file.
+    schema: Schema,
+    /// Blocks of the dictionary batches.
+    dictionaries: Vec<Block>,
+    /// Blocks of the record batches.
+    record_batches: Vec<Block>,
+    /// Written dictionary batch messages, by identifier.
+    written: HashMap<i64, Vec<u8>>,
+    /// Type of the rows.
+    _rows: PhantomData<fn(T)>,
+}
+
+impl<W: Write, T: ArrayItem> FileWriter<W, T> {
+    /// Constructs a [`FileWriter`] and writes the magic bytes and the schema
+    /// of `T` to `writer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`WriteError`] when the schema cannot be written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow_ipc::FileWriter;
+    ///
+    /// let writer = FileWriter::<_, (u8,)>::try_new(Vec::new()).unwrap();
+    /// assert!(writer.finish().unwrap().starts_with(b"ARROW1"));
+    /// ```
+    pub fn try_new(mut writer: W) -> Result<Self, WriteError>
+    where
+        T::Memory<VecBuffer>: BatchSchema,
+    {
+        let schema = <T::Memory<VecBuffer>>::schema();
+        let padding = format::MAGIC
+            .len()
+            .next_multiple_of(format::ALIGNMENT)
+            .strict_sub(format::MAGIC.len());
+        writer.write_all(&format::MAGIC)?;
+        writer.write_all(&PADDING[..padding])?;
+        let (metadata_length, body_length) = write_schema(&mut writer, &schema)?;
+        let position = format::MAGIC
+            .len()
+            .strict_add(padding)
+            .strict_add(metadata_length)
+            .strict_add(body_length);
+        Ok(Self {
+            writer,
+            position,
+            schema,
+            dictionaries: Vec::new(),
+            record_batches: Vec::new(),
+            written: HashMap::new(),
+            _rows: PhantomData,
+        })
+    }
+
+    /// Writes `batch` as a record batch, preceded by the dictionaries that
+    /// were not written before.
+    ///
+    /// Only the items of a sliced array are written.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`WriteError`] when the batch cannot be written, or when it
+    /// references different dictionary values than an earlier batch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{array::Array, collection::Collection, layout::dictionary::Dictionary};
+    /// use narrow_ipc::{FileWriter, WriteError};
+    ///
+    /// let mut writer = FileWriter::<_, (Dictionary<u8, String>,)>::try_new(Vec::new()).unwrap();
+    /// let batch = [(Dictionary::new("a".to_owned()),)].into_iter().collect::<Array<_>>();
+    /// writer.write(&batch).unwrap();
+    /// writer.write(&batch).unwrap();
+    /// let other = [(Dictionary::new("b".to_owned()),)].into_iter().collect::<Array<_>>();
+    /// assert!(matches!(
+    ///     writer.write(&other),
+    ///     Err(WriteError::DictionaryReplacement { id: 0 })
+    /// ));
+    /// ```
+    pub fn write<'layout, Storage>(
+        &mut self,
+        batch: &'layout Array<T, Storage>,
+    ) -> Result<(), WriteError>
+    where
+        Storage: narrow::buffer::Buffer,
+        T::Memory<Storage>: WriteBatch<'layout>,
+    {
+        let layout = batch.buffer_ref();
+        let mut dictionaries = Vec::new();
+        layout.write_batch_dictionaries(&mut dictionaries);
+        let mut pending = Vec::new();
+        for dictionary in &dictionaries {
+            let mut message = Vec::new();
+            let lengths = write_dictionary(&mut message, dictionary)?;
+            match self.written.get(&dictionary.id) {
+                Some(written) if *written == message => {}
+                Some(_) => {
+                    return Err(WriteError::DictionaryReplacement { id: dictionary.id });
+                }
+                None => pending.push((dictionary.id, message, lengths)),
+            }
+        }
+
+        // Dictionaries are only written once the batch is known to be valid,
+        // so a rejected batch leaves the file unchanged.
+        for (id, message, (metadata_length, body_length)) in pending {
+            self.writer.write_all(&message)?;
+            let block = self.block(metadata_length, body_length);
+            self.dictionaries.push(block);
+            self.written.insert(id, message);
+        }
+
+        let mut body = Body::default();
+        layout.write_batch(&mut body);
+        let (metadata_length, body_length) =
+            write_record_batch(&mut self.writer, layout.len(), &body)?;
+        let block = self.block(metadata_length, body_length);
+        self.record_batches.push(block);
+        Ok(())
+    }
+
+    /// Returns the block of a message written at the current position and
+    /// advances the position past it.
+    fn block(&mut self, metadata_length: usize, body_length: usize) -> Block {
+        let block = Block {
+            offset: i64::try_from(self.position).expect("file length exceeds i64"),
+            meta_data_length: i32::try_from(metadata_length).expect("message metadata exceeds i32"),
+            body_length: i64::try_from(body_length).expect("body length exceeds i64"),
+        };
+        self.position = self
+            .position
+            .strict_add(metadata_length)
+            .strict_add(body_length);
+        block
+    }
+
+    /// Writes the end-of-stream marker, the footer and the trailing magic
+    /// bytes, and returns the underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`WriteError`] when the footer cannot be written.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the footer exceeds 2 GiB.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow_ipc::FileWriter;
+    ///
+    /// let bytes = FileWriter::<_, (u8,)>::try_new(Vec::new()).unwrap().finish().unwrap();
+    /// assert!(bytes.ends_with(b"ARROW1"));
+    /// ```
+    pub fn finish(mut self) -> Result<W, WriteError> {
+        write_end_of_stream(&mut self.writer)?;
+        let mut fbb = FlatBufferBuilder::new();
+        let schema = self.schema.to_table(&mut fbb);
+        let footer = Footer::create(&mut fbb, schema, &self.dictionaries, &self.record_batches);
+        fbb.finish(footer, None);
+        let bytes = fbb.finished_data();
+        let length = i32::try_from(bytes.len()).expect("footer exceeds i32");
+        self.writer.write_all(bytes)?;
+        self.writer.write_all(&length.to_le_bytes())?;
+        self.writer.write_all(&format::MAGIC)?;
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use narrow::layout::dictionary::Dictionary;
+
+    use super::*;
+
+    type Columns = (u64, Dictionary<u8, String>);
+
+    fn batch(values: &[(u64, &str)]) -> Array<Columns> {
+        values
+            .iter()
+            .map(|&(id, kind)| (id, Dictionary::new(kind.to_owned())))
+            .collect()
+    }
+
+    #[test]
+    fn blocks_locate_messages() {
+        let mut writer = FileWriter::<_, Columns>::try_new(Vec::new()).expect("write schema");
+        writer.write(&batch(&[(1, "a")])).expect("write batch");
+        writer.write(&batch(&[(2, "a")])).expect("write batch");
+        let dictionaries = writer.dictionaries.clone();
+        let record_batches = writer.record_batches.clone();
+        let bytes = writer.finish().expect("finish file");
+
+        assert_eq!(dictionaries.len(), 1);
+        assert_eq!(record_batches.len(), 2);
+        for block in dictionaries.iter().chain(&record_batches) {
+            let offset = usize::try_from(block.offset).expect("offset fits");
+            assert!(offset.is_multiple_of(format::ALIGNMENT));
+            assert_eq!(bytes[offset..offset.strict_add(4)], format::CONTINUATION);
+        }
+    }
+
+    #[test]
+    fn rejected_batches_are_not_written() {
+        let mut writer = FileWriter::<_, Columns>::try_new(Vec::new()).expect("write schema");
+        writer.write(&batch(&[(1, "a")])).expect("write batch");
+        let position = writer.position;
+        assert!(matches!(
+            writer.write(&batch(&[(2, "b")])),
+            Err(WriteError::DictionaryReplacement { id: 0 })
+        ));
+        assert_eq!(writer.position, position);
+        assert_eq!(writer.record_batches.len(), 1);
+    }
+}
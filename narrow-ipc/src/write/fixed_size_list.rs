@@ -0,0 +1,55 @@
+//! Write support for [`FixedSizeList`].
+
+use narrow::{
+    buffer::{Buffer, BufferRef},
+    collection::ChildRef,
+    layout::{ArrayItem, MemoryLayout, fixed_size_list::FixedSizeList},
+};
+
+use crate::{DataType, Field};
+
+use super::{Body, DictionaryBody, DictionaryIds, WriteLayout, WriteNullability, WriteSchema};
+
+impl<T, const N: usize, Nulls, Storage> WriteSchema for FixedSizeList<T, N, Nulls, Storage>
+where
+    T: ArrayItem,
+    Nulls: WriteNullability,
+    Storage: Buffer,
+    T::Memory<Storage>: WriteSchema,
+{
+    fn field(name: String, ids: &mut DictionaryIds) -> Field {
+        let child = <T::Memory<Storage>>::field("item".to_owned(), ids);
+        Field::new(
+            name,
+            Nulls::NULLABLE,
+            DataType::FixedSizeList(Box::new(child), N),
+        )
+    }
+}
+
+impl<'layout, T, const N: usize, Nulls, Storage> WriteLayout<'layout>
+    for FixedSizeList<T, N, Nulls, Storage>
+where
+    T: ArrayItem,
+    Nulls: WriteNullability,
+    Storage: Buffer,
+    T::Memory<Storage>: WriteLayout<'layout>,
+{
+    fn write_body(&'layout self, offset: usize, len: usize, body: &mut Body<'layout>) {
+        let start = self.offset().strict_add(offset);
+        let (values, bitmap) = Nulls::parts(self.buffer_ref());
+        body.validity(bitmap, start, len);
+        values
+            .child_ref()
+            .write_body(start.strict_mul(N), len.strict_mul(N), body);
+    }
+
+    fn write_dictionaries(
+        &'layout self,
+        ids: &mut DictionaryIds,
+        dictionaries: &mut Vec<DictionaryBody<'layout>>,
+    ) {
+        let (values, _) = Nulls::parts(self.buffer_ref());
+        values.child_ref().write_dictionaries(ids, dictionaries);
+    }
+}
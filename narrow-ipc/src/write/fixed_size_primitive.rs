@@ -0,0 +1,39 @@
+//! Write support for [`FixedSizePrimitive`].
+
+use std::borrow::Borrow;
+
+use narrow::{
+    buffer::{Buffer, BufferRef},
+    fixed_size::FixedSize,
+    layout::{MemoryLayout, fixed_size_primitive::FixedSizePrimitive},
+};
+
+use crate::{Field, PrimitiveType};
+
+use super::{Body, DictionaryIds, WriteLayout, WriteNullability, WriteSchema};
+
+impl<T, Nulls, Storage> WriteSchema for FixedSizePrimitive<T, Nulls, Storage>
+where
+    T: FixedSize + PrimitiveType,
+    Nulls: WriteNullability,
+    Storage: Buffer,
+{
+    fn field(name: String, _ids: &mut DictionaryIds) -> Field {
+        Field::new(name, Nulls::NULLABLE, T::DATA_TYPE)
+    }
+}
+
+impl<'layout, T, Nulls, Storage> WriteLayout<'layout> for FixedSizePrimitive<T, Nulls, Storage>
+where
+    T: FixedSize + PrimitiveType,
+    Nulls: WriteNullability,
+    Storage: Buffer,
+{
+    fn write_body(&'layout self, offset: usize, len: usize, body: &mut Body<'layout>) {
+        let start = self.offset().strict_add(offset);
+        let (buffer, bitmap) = Nulls::parts(self.buffer_ref());
+        body.validity(bitmap, start, len);
+        let values: &[T] = buffer.borrow();
+        body.values(&values[start..start.strict_add(len)]);
+    }
+}
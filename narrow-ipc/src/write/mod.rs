@@ -0,0 +1,415 @@
+//! Write [`Array`](narrow::array::Array) values as Arrow IPC messages.
+
+use std::{
+    borrow::{Borrow, Cow},
+    fmt, io,
+    ops::Range,
+};
+
+use flatbuffers::FlatBufferBuilder;
+use narrow::{
+    bitmap::{Bitmap, ValidityBitmap},
+    buffer::{Buffer, BufferRef},
+    collection::{ChildRef, Collection},
+    fixed_size::FixedSize,
+    nullability::{NonNullable, Nullability, Nullable},
+    offset::Offset,
+    validity::Validity,
+};
+
+use crate::{
+    Field, Schema,
+    buffer::bytes_of,
+    format::{self, BufferRegion, FieldNode},
+};
+
+/// Write support for [`narrow::layout::boolean::Boolean`].
+mod boolean;
+/// Write support for [`narrow::layout::dictionary::DictionaryEncoded`].
+mod dictionary;
+/// Write support for [`narrow::layout::fixed_size_list::FixedSizeList`].
+mod fixed_size_list;
+/// Write support for [`narrow::layout::fixed_size_primitive::FixedSizePrimitive`].
+mod fixed_size_primitive;
+/// Write support for [`narrow::layout::struct_array::Struct`].
+mod struct_array;
+/// Write support for [`narrow::layout::utf8::Utf8`].
+mod utf8;
+/// Write support for [`narrow::layout::variable_size_list::VariableSizeList`].
+mod variable_size_list;
+
+/// Write support for the Arrow IPC file format.
+mod file;
+pub use file::FileWriter;
+/// Write support for the Arrow IPC streaming format.
+mod stream;
+pub use stream::StreamWriter;
+
+/// Error returned when an Arrow IPC stream or file cannot be written.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum WriteError {
+    /// The underlying writer failed.
+    Io(io::Error),
+    /// A batch references a different dictionary than an earlier batch of
+    /// the same file.
+    ///
+    /// The file format does not support replacing dictionaries.
+    DictionaryReplacement {
+        /// Identifier of the dictionary.
+        id: i64,
+    },
+}
+
+impl fmt::Display for WriteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Io(ref error) => write!(f, "failed to write Arrow IPC stream: {error}"),
+            Self::DictionaryReplacement { id } => write!(
+                f,
+                "dictionary {id} cannot be replaced in the Arrow IPC file format"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for WriteError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match *self {
+            Self::Io(ref error) => Some(error),
+            Self::DictionaryReplacement { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for WriteError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+/// Identifiers of the dictionaries of a schema.
+///
+/// Identifiers are assigned in pre-order of the schema fields, both when the
+/// schema is described and when the dictionaries of a batch are written, so
+/// both traversals agree without storing the identifiers.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DictionaryIds(i64);
+
+impl DictionaryIds {
+    /// Returns the identifier of the next dictionary.
+    fn next_id(&mut self) -> i64 {
+        let id = self.0;
+        self.0 = id.strict_add(1);
+        id
+    }
+}
+
+/// A memory layout with an Arrow schema [`Field`].
+pub trait WriteSchema {
+    /// Returns the field of this layout named `name`.
+    fn field(name: String, ids: &mut DictionaryIds) -> Field;
+}
+
+/// A memory layout that can be written to a message body.
+///
+/// The lifetime is that of the borrowed layout, so buffers can be written
+/// without copying them.
+pub trait WriteLayout<'layout>: WriteSchema {
+    /// Writes the field nodes and buffers of the `len` items starting at
+    /// `offset` of this layout.
+    fn write_body(&'layout self, offset: usize, len: usize, body: &mut Body<'layout>);
+
+    /// Writes the dictionaries referenced by this layout as bodies of
+    /// single-column record batches, after the dictionaries they reference.
+    fn write_dictionaries(
+        &'layout self,
+        ids: &mut DictionaryIds,
+        dictionaries: &mut Vec<DictionaryBody<'layout>>,
+    ) {
+        let _ = (ids, dictionaries);
+    }
+}
+
+/// Write behavior for a [`Nullability`] type constructor.
+pub trait WriteNullability: Nullability {
+    /// Returns the collection and the optional validity bitmap of `collection`.
+    fn parts<T, Storage>(
+        collection: &Self::Collection<T, Storage>,
+    ) -> (&T, Option<&Bitmap<Storage>>)
+    where
+        T: Collection,
+        Storage: Buffer;
+}
+
+impl WriteNullability for NonNullable {
+    fn parts<T, Storage>(collection: &T) -> (&T, Option<&Bitmap<Storage>>)
+    where
+        T: Collection,
+        Storage: Buffer,
+    {
+        (collection, None)
+    }
+}
+
+impl WriteNullability for Nullable {
+    fn parts<T, Storage>(collection: &Validity<T, Storage>) -> (&T, Option<&Bitmap<Storage>>)
+    where
+        T: Collection,
+        Storage: Buffer,
+    {
+        (collection.child_ref(), collection.bitmap_ref())
+    }
+}
+
+/// The field nodes and buffers of a message body.
+///
+/// Buffers are borrowed from the written layouts where possible. Each is
+/// padded to a multiple of 8 bytes, so the next buffer starts aligned.
+#[derive(Debug, Default)]
+pub struct Body<'layout> {
+    /// Field nodes in pre-order.
+    nodes: Vec<FieldNode>,
+    /// Regions of the buffers in pre-order.
+    buffers: Vec<BufferRegion>,
+    /// Buffer contents and padding.
+    chunks: Vec<Cow<'layout, [u8]>>,
+    /// Number of bytes in the body.
+    len: usize,
+}
+
+/// Zeros used to pad buffers.
+const PADDING: [u8; format::ALIGNMENT] = [0; format::ALIGNMENT];
+
+impl<'layout> Body<'layout> {
+    /// Adds a field node.
+    fn node(&mut self, length: usize, null_count: usize) {
+        self.nodes.push(FieldNode {
+            length: i64::try_from(length).expect("array length exceeds i64"),
+            null_count: i64::try_from(null_count).expect("null count exceeds i64"),
+        });
+    }
+
+    /// Adds a buffer.
+    fn buffer(&mut self, bytes: Cow<'layout, [u8]>) {
+        let len = bytes.len();
+        self.buffers.push(BufferRegion {
+            offset: i64::try_from(self.len).expect("body length exceeds i64"),
+            length: i64::try_from(len).expect("buffer length exceeds i64"),
+        });
+        let padding = len.next_multiple_of(format::ALIGNMENT).strict_sub(len);
+        self.len = self.len.strict_add(len).strict_add(padding);
+        if len != 0 {
+            self.chunks.push(bytes);
+        }
+        if padding != 0 {
+            self.chunks.push(Cow::Borrowed(&PADDING[..padding]));
+        }
+    }
+
+    /// Adds a buffer of fixed-size values.
+    fn values<T: FixedSize>(&mut self, values: &'layout [T]) {
+        self.buffer(Cow::Borrowed(bytes_of(values)));
+    }
+
+    /// Adds the `len + 1` offsets starting at `offset`, rebased to start at
+    /// zero, and returns the range of the items they reference.
+    fn offsets<OffsetItem: Offset>(
+        &mut self,
+        offsets: &'layout [OffsetItem],
+        offset: usize,
+        len: usize,
+    ) -> Range<usize> {
+        let window = &offsets[offset..=offset.strict_add(len)];
+        let first = window[0].as_usize();
+        let last = window[len].as_usize();
+        if first == 0 {
+            self.values(window);
+        } else {
+            let rebased = window
+                .iter()
+                .map(|item| {
+                    OffsetItem::try_from(item.as_usize().strict_sub(first))
+                        .expect("rebased offset is smaller than the offset")
+                })
+                .collect::<Vec<_>>();
+            self.buffer(Cow::Owned(bytes_of(&rebased).to_vec()));
+        }
+        first..last
+    }
+
+    /// Adds the `len` bits starting at `offset` of `bitmap` as a buffer.
+    fn bits<Storage: Buffer>(
+        &mut self,
+        bitmap: &'layout Bitmap<Storage>,
+        offset: usize,
+        len: usize,
+    ) {
+        let start = bitmap.bit_offset().strict_add(offset);
+        if start.is_multiple_of(8) {
+            let bytes: &[u8] = bitmap.buffer_ref().borrow();
+            let end = start.strict_add(len).div_ceil(8);
+            self.buffer(Cow::Borrowed(&bytes[start / 8..end]));
+        } else {
+            let (bytes, _, _) = bitmap
+                .iter_views()
+                .skip(offset)
+                .take(len)
+                .collect::<Bitmap>()
+                .into_parts();
+            self.buffer(Cow::Owned(bytes));
+        }
+    }
+
+    /// Adds the field node and validity buffer of the `len` items starting at
+    /// `offset` of a layout with an optional validity `bitmap`.
+    ///
+    /// The validity buffer is omitted when none of the items is null.
+    fn validity<Storage: Buffer>(
+        &mut self,
+        bitmap: Option<&'layout Bitmap<Storage>>,
+        offset: usize,
+        len: usize,
+    ) {
+        let null_count = bitmap.map_or(0, |validity| {
+            validity
+                .iter_views()
+                .skip(offset)
+                .take(len)
+                .filter(|valid| !*valid)
+                .count()
+        });
+        self.node(len, null_count);
+        match bitmap {
+            Some(validity) if null_count != 0 => self.bits(validity, offset, len),
+            _ => self.buffer(Cow::Borrowed(&[])),
+        }
+    }
+}
+
+/// The body of a dictionary batch.
+#[derive(Debug)]
+pub struct DictionaryBody<'layout> {
+    /// Identifier of the dictionary.
+    id: i64,
+    /// Number of dictionary values.
+    length: usize,
+    /// The values as a single-column record batch.
+    body: Body<'layout>,
+}
+
+/// A record batch layout with a [`Schema`].
+pub trait BatchSchema {
+    /// Returns the schema of this record batch.
+    fn schema() -> Schema;
+}
+
+/// A record batch: a non-nullable struct whose fields are the columns.
+pub trait WriteBatch<'layout>: BatchSchema + Collection {
+    /// Writes the columns of this record batch to a message body.
+    fn write_batch(&'layout self, body: &mut Body<'layout>);
+
+    /// Writes the dictionaries referenced by the columns of this record batch.
+    fn write_batch_dictionaries(&'layout self, dictionaries: &mut Vec<DictionaryBody<'layout>>);
+}
+
+/// Writes an encapsulated message with `header` and `body`, returning the
+/// number of metadata bytes, including the prefix and padding, and the
+/// number of body bytes.
+pub(crate) fn write_message<W, F>(
+    writer: &mut W,
+    header_type: u8,
+    header: F,
+    body: &Body<'_>,
+) -> Result<(usize, usize), WriteError>
+where
+    W: io::Write,
+    F: for<'fbb> FnOnce(
+        &mut FlatBufferBuilder<'fbb>,
+    ) -> flatbuffers::WIPOffset<flatbuffers::TableFinishedWIPOffset>,
+{
+    let mut fbb = FlatBufferBuilder::new();
+    let header_table = header(&mut fbb);
+    let body_length = i64::try_from(body.len).expect("body length exceeds i64");
+    let message = format::Message::create(
+        &mut fbb,
+        header_type,
+        header_table.as_union_value(),
+        body_length,
+    );
+    fbb.finish(message, None);
+    let metadata = fbb.finished_data();
+
+    // The continuation marker and length prefix take 8 bytes, so padding the
+    // metadata to a multiple of 8 keeps the body aligned.
+    let padded = metadata.len().next_multiple_of(format::ALIGNMENT);
+    let prefix = i32::try_from(padded).expect("message metadata exceeds i32");
+    writer.write_all(&format::CONTINUATION)?;
+    writer.write_all(&prefix.to_le_bytes())?;
+    writer.write_all(metadata)?;
+    writer.write_all(&PADDING[..padded.strict_sub(metadata.len())])?;
+    for chunk in &body.chunks {
+        writer.write_all(chunk)?;
+    }
+    Ok((padded.strict_add(8), body.len))
+}
+
+/// Writes the header of a record batch with `length` rows and `body`.
+pub(crate) fn record_batch(
+    fbb: &mut FlatBufferBuilder<'_>,
+    length: usize,
+    body: &Body<'_>,
+) -> flatbuffers::WIPOffset<flatbuffers::TableFinishedWIPOffset> {
+    let rows = i64::try_from(length).expect("batch length exceeds i64");
+    format::RecordBatch::create(fbb, rows, &body.nodes, &body.buffers)
+}
+
+/// Writes the schema message of `schema`.
+pub(crate) fn write_schema<W: io::Write>(
+    writer: &mut W,
+    schema: &Schema,
+) -> Result<(usize, usize), WriteError> {
+    write_message(
+        writer,
+        format::HEADER_SCHEMA,
+        |fbb| schema.to_table(fbb),
+        &Body::default(),
+    )
+}
+
+/// Writes the dictionary batch message of `dictionary`.
+pub(crate) fn write_dictionary<W: io::Write>(
+    writer: &mut W,
+    dictionary: &DictionaryBody<'_>,
+) -> Result<(usize, usize), WriteError> {
+    write_message(
+        writer,
+        format::HEADER_DICTIONARY_BATCH,
+        |fbb| {
+            let data = record_batch(fbb, dictionary.length, &dictionary.body);
+            format::DictionaryBatch::create(fbb, dictionary.id, data)
+        },
+        &dictionary.body,
+    )
+}
+
+/// Writes the record batch message of `length` rows and `body`.
+pub(crate) fn write_record_batch<W: io::Write>(
+    writer: &mut W,
+    length: usize,
+    body: &Body<'_>,
+) -> Result<(usize, usize), WriteError> {
+    write_message(
+        writer,
+        format::HEADER_RECORD_BATCH,
+        |fbb| record_batch(fbb, length, body),
+        body,
+    )
+}
+
+/// Writes the end-of-stream marker, returning its number of bytes.
+pub(crate) fn write_end_of_stream<W: io::Write>(writer: &mut W) -> Result<usize, WriteError> {
+    writer.write_all(&format::CONTINUATION)?;
+    writer.write_all(&0_i32.to_le_bytes())?;
+    Ok(format::CONTINUATION.len().strict_add(4))
+}
@@ -0,0 +1,340 @@
+//! Write support for the Arrow IPC streaming format.
+
+use std::{io::Write, marker::PhantomData};
+
+use narrow::{
+    array::Array,
+    buffer::{BufferRef, VecBuffer},
+    layout::ArrayItem,
+    length::Length,
+};
+
+use super::{
+    BatchSchema, Body, WriteBatch, WriteError, write_dictionary, write_end_of_stream,
+    write_record_batch, write_schema,
+};
+
+/// A writer of [`Array`]s of `T` in the Arrow IPC streaming format.
+///
+/// The fields of the struct `T` are the columns of the stream. Each array is
+/// written as a record batch, preceded by a dictionary batch for every
+/// dictionary-encoded column, which replaces the dictionary of the previous
+/// batch. Buffers are written from the array's storage without copying them
+/// into an intermediate buffer.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{array::Array, collection::Collection};
+/// use narrow_ipc::{StreamReader, StreamWriter};
+///
+/// let mut writer = StreamWriter::<_, (u8, Option<String>)>::try_new(Vec::new()).unwrap();
+/// let batch = [(1, Some("a".to_owned())), (2, None)].into_iter().collect::<Array<_>>();
+/// writer.write(&batch).unwrap();
+/// let bytes = writer.finish().unwrap();
+///
+/// let mut reader = StreamReader::<_, (u8, Option<String>)>::try_new(bytes.as_slice()).unwrap();
+/// let batch = reader.next().unwrap().unwrap();
+/// assert_eq!(
+///     batch.into_iter_owned().collect::<Vec<_>>(),
+///     [(1, Some("a".to_owned())), (2, None)]
+/// );
+/// ```
+#[derive(Debug)]
+pub struct StreamWriter<W: Write, T> {
+    /// Writer of the stream.
+    writer: W,
+    /// Type of the rows.
+    _rows: PhantomData<fn(T)>,
+}
+
+impl<W: Write, T: ArrayItem> StreamWriter<W, T> {
+    /// Constructs a [`StreamWriter`] and writes the schema of `T` to `writer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`WriteError`] when the schema cannot be written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow_ipc::StreamWriter;
+    ///
+    /// let writer = StreamWriter::<_, (u8,)>::try_new(Vec::new()).unwrap();
+    /// assert!(!writer.finish().unwrap().is_empty());
+    /// ```
+    pub fn try_new(mut writer: W) -> Result<Self, WriteError>
+    where
+        T::Memory<VecBuffer>: BatchSchema,
+    {
+        write_schema(&mut writer, &<T::Memory<VecBuffer>>::schema())?;
+        Ok(Self {
+            writer,
+            _rows: PhantomData,
+        })
+    }
+
+    /// Writes `batch` as a record batch, preceded by its dictionaries.
+    ///
+    /// Only the items of a sliced array are written.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`WriteError`] when the batch cannot be written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{array::Array, collection::Collection};
+    /// use narrow_ipc::{StreamReader, StreamWriter};
+    ///
+    /// let mut writer = StreamWriter::<_, (u8,)>::try_new(Vec::new()).unwrap();
+    /// let batch = [(1,), (2,), (3,)].into_iter().collect::<Array<_>>();
+    /// writer.write(&batch.slice(1, 2)).unwrap();
+    /// let bytes = writer.finish().unwrap();
+    ///
+    /// let mut reader = StreamReader::<_, (u8,)>::try_new(bytes.as_slice()).unwrap();
+    /// let batch = reader.next().unwrap().unwrap();
+    /// assert_eq!(batch.into_iter_owned().collect::<Vec<_>>(), [(2,), (3,)]);
+    /// ```
+    pub fn write<'layout, Storage>(
+        &mut self,
+        batch: &'layout Array<T, Storage>,
+    ) -> Result<(), WriteError>
+    where
+        Storage: narrow::buffer::Buffer,
+        T::Memory<Storage>: WriteBatch<'layout>,
+    {
+        let layout = batch.buffer_ref();
+        let mut dictionaries = Vec::new();
+        layout.write_batch_dictionaries(&mut dictionaries);
+        for dictionary in &dictionaries {
+            write_dictionary(&mut self.writer, dictionary)?;
+        }
+
+        let mut body = Body::default();
+        layout.write_batch(&mut body);
+        write_record_batch(&mut self.writer, layout.len(), &body)?;
+        Ok(())
+    }
+
+    /// Writes the end-of-stream marker and returns the underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`WriteError`] when the marker cannot be written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow_ipc::StreamWriter;
+    ///
+    /// let bytes = StreamWriter::<_, (u8,)>::try_new(Vec::new()).unwrap().finish().unwrap();
+    /// assert!(bytes.ends_with(&[0xFF, 0xFF, 0xFF, 0xFF, 0, 0, 0, 0]));
+    /// ```
+    pub fn finish(mut self) -> Result<W, WriteError> {
+        write_end_of_stream(&mut self.writer)?;
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::{
+        Array as _, BooleanArray, DictionaryArray, FixedSizeListArray, Float32Array, Int64Array,
+        ListArray, RecordBatch, StringArray, StructArray, UInt16Array,
+        cast::AsArray,
+        types::{Float32Type, Int32Type, Int64Type, UInt8Type, UInt16Type},
+    };
+    use arrow_schema::DataType;
+    use narrow::{ArrayType, layout::dictionary::Dictionary};
+
+    use crate::format;
+
+    use super::*;
+
+    #[derive(ArrayType, Clone, Debug, Default, PartialEq)]
+    struct Point {
+        x: i64,
+        valid: bool,
+    }
+
+    #[derive(ArrayType, Clone, Debug, Default, PartialEq)]
+    struct Row {
+        id: u16,
+        ratio: Option<f32>,
+        name: Option<String>,
+        tags: Vec<Option<i32>>,
+        pair: [u16; 2],
+        point: Option<Point>,
+        kind: Option<Dictionary<u8, String>>,
+    }
+
+    fn row(id: u16) -> Row {
+        Row {
+            id,
+            ratio: id.is_multiple_of(2).then_some(f32::from(id)),
+            name: (!id.is_multiple_of(3)).then(|| id.to_string()),
+            tags: vec![Some(i32::from(id)), None],
+            pair: [id, id.strict_add(1)],
+            point: (id != 4).then_some(Point {
+                x: i64::from(id).strict_neg(),
+                valid: !id.is_multiple_of(2),
+            }),
+            kind: (id != 2)
+                .then(|| Dictionary::new(["a", "b", "c"][usize::from(id % 3)].to_owned())),
+        }
+    }
+
+    #[test]
+    fn arrow_reads_written_streams() {
+        let array = (0..10).map(row).collect::<Array<Row>>();
+        let mut writer = StreamWriter::<_, Row>::try_new(Vec::new()).expect("write schema");
+        writer.write(&array).expect("write batch");
+        writer.write(&array.slice(3, 5)).expect("write batch");
+        let bytes = writer.finish().expect("finish stream");
+
+        let reader =
+            arrow_ipc::reader::StreamReader::try_new(bytes.as_slice(), None).expect("read schema");
+        let names = reader
+            .schema()
+            .fields()
+            .iter()
+            .map(|field| field.name().clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            ["id", "ratio", "name", "tags", "pair", "point", "kind"]
+        );
+
+        let batches = reader.collect::<Result<Vec<_>, _>>().expect("read batches");
+        assert_eq!(batches.len(), 2);
+        for (batch, ids) in batches.iter().zip([0_u16..10, 3..8]) {
+            let rows = ids.map(row).collect::<Vec<_>>();
+            assert_eq!(
+                batch.column(0).as_primitive::<UInt16Type>(),
+                &UInt16Array::from(rows.iter().map(|row| row.id).collect::<Vec<_>>())
+            );
+            assert_eq!(
+                batch.column(1).as_primitive::<Float32Type>(),
+                &Float32Array::from(rows.iter().map(|row| row.ratio).collect::<Vec<_>>())
+            );
+            assert_eq!(
+                batch.column(2).as_string::<i32>(),
+                &rows
+                    .iter()
+                    .map(|row| row.name.clone())
+                    .collect::<StringArray>()
+            );
+            assert_eq!(
+                batch.column(3).as_list::<i32>(),
+                &ListArray::from_iter_primitive::<Int32Type, _, _>(
+                    rows.iter().map(|row| Some(row.tags.clone()))
+                )
+            );
+            let pairs = batch
+                .column(4)
+                .as_any()
+                .downcast_ref::<FixedSizeListArray>()
+                .expect("column type");
+            assert_eq!(pairs.value_length(), 2);
+            assert_eq!(
+                pairs.values().as_primitive::<UInt16Type>(),
+                &UInt16Array::from(rows.iter().flat_map(|row| row.pair).collect::<Vec<_>>())
+            );
+            assert_nested_columns(batch, &rows);
+        }
+    }
+
+    /// Asserts that the struct and dictionary columns of `batch` contain
+    /// `rows`.
+    fn assert_nested_columns(batch: &RecordBatch, rows: &[Row]) {
+        let points = batch
+            .column(5)
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .expect("column type");
+        assert_eq!(
+            (0..points.len())
+                .map(|index| points.is_valid(index))
+                .collect::<Vec<_>>(),
+            rows.iter()
+                .map(|row| row.point.is_some())
+                .collect::<Vec<_>>()
+        );
+        let valid_points = rows
+            .iter()
+            .map(|row| row.point.clone().unwrap_or_default())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            points.column(0).as_primitive::<Int64Type>(),
+            &Int64Array::from(valid_points.iter().map(|point| point.x).collect::<Vec<_>>())
+        );
+        assert_eq!(
+            points.column(1).as_boolean(),
+            &BooleanArray::from(
+                valid_points
+                    .iter()
+                    .map(|point| point.valid)
+                    .collect::<Vec<_>>()
+            )
+        );
+        let kinds = batch
+            .column(6)
+            .as_any()
+            .downcast_ref::<DictionaryArray<UInt8Type>>()
+            .expect("column type");
+        assert_eq!(kinds.value_type(), DataType::Utf8);
+        let values = kinds
+            .downcast_dict::<StringArray>()
+            .expect("dictionary value type");
+        assert_eq!(
+            values
+                .into_iter()
+                .map(|value| value.map(str::to_owned))
+                .collect::<Vec<_>>(),
+            rows.iter()
+                .map(|row| row.kind.clone().map(Dictionary::into_inner))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn bodies_are_aligned() {
+        let array = [(1_u8, "abc".to_owned())].into_iter().collect::<Array<_>>();
+        let mut writer =
+            StreamWriter::<_, (u8, String)>::try_new(Vec::new()).expect("write schema");
+        writer.write(&array).expect("write batch");
+        let bytes = writer.finish().expect("finish stream");
+
+        // Every message starts with the continuation marker at an 8-byte
+        // aligned position, followed by padded metadata and body.
+        let mut position = 0;
+        while position < bytes.len() {
+            assert!(position.is_multiple_of(8));
+            assert_eq!(
+                bytes[position..position.strict_add(4)],
+                format::CONTINUATION
+            );
+            let length = i32::from_le_bytes(
+                bytes[position.strict_add(4)..position.strict_add(8)]
+                    .try_into()
+                    .expect("value fits"),
+            );
+            if length == 0 {
+                break;
+            }
+            let metadata = &bytes[position.strict_add(8)..];
+            let message = format::root::<format::Message<'_>>(
+                &metadata[..usize::try_from(length).expect("value fits")],
+            )
+            .expect("message metadata");
+            position = position
+                .strict_add(8)
+                .strict_add(usize::try_from(length).expect("value fits"))
+                .strict_add(usize::try_from(message.body_length()).expect("value fits"));
+        }
+        assert_eq!(position.strict_add(8), bytes.len());
+    }
+}
@@ -0,0 +1,170 @@
+//! Write support for [`Struct`].
+
+use narrow::{
+    buffer::{Buffer, BufferRef},
+    layout::{
+        MemoryLayout,
+        struct_array::{Struct, StructFields, StructLayout},
+    },
+    length::Length,
+    nullability::NonNullable,
+};
+
+use crate::{DataType, Field, Schema};
+
+use super::{
+    BatchSchema, Body, DictionaryBody, DictionaryIds, WriteBatch, WriteLayout, WriteNullability,
+    WriteSchema,
+};
+
+/// The fields of the children of a struct, as a nested list of pairs.
+pub trait SchemaColumns {
+    /// Appends the fields named `names` of these children to `fields`.
+    fn push_fields(names: &[&str], ids: &mut DictionaryIds, fields: &mut Vec<Field>);
+}
+
+impl SchemaColumns for () {
+    fn push_fields(_names: &[&str], _ids: &mut DictionaryIds, _fields: &mut Vec<Field>) {}
+}
+
+impl<Head: WriteSchema, Tail: SchemaColumns> SchemaColumns for (Head, Tail) {
+    fn push_fields(names: &[&str], ids: &mut DictionaryIds, fields: &mut Vec<Field>) {
+        let (name, rest) = names.split_first().expect("one name per struct field");
+        fields.push(Head::field((*name).to_owned(), ids));
+        Tail::push_fields(rest, ids, fields);
+    }
+}
+
+/// References to the children of a struct, as a nested list of pairs.
+pub trait WriteColumns<'layout> {
+    /// Writes the `len` items starting at `offset` of every child.
+    fn write_columns(self, offset: usize, len: usize, body: &mut Body<'layout>);
+
+    /// Writes the dictionaries referenced by the children.
+    fn write_column_dictionaries(
+        self,
+        ids: &mut DictionaryIds,
+        dictionaries: &mut Vec<DictionaryBody<'layout>>,
+    );
+}
+
+impl WriteColumns<'_> for () {
+    fn write_columns(self, _offset: usize, _len: usize, _body: &mut Body<'_>) {}
+
+    fn write_column_dictionaries(
+        self,
+        _ids: &mut DictionaryIds,
+        _dictionaries: &mut Vec<DictionaryBody<'_>>,
+    ) {
+    }
+}
+
+impl<'layout, Head, Tail> WriteColumns<'layout> for (&'layout Head, Tail)
+where
+    Head: WriteLayout<'layout>,
+    Tail: WriteColumns<'layout>,
+{
+    fn write_columns(self, offset: usize, len: usize, body: &mut Body<'layout>) {
+        self.0.write_body(offset, len, body);
+        self.1.write_columns(offset, len, body);
+    }
+
+    fn write_column_dictionaries(
+        self,
+        ids: &mut DictionaryIds,
+        dictionaries: &mut Vec<DictionaryBody<'layout>>,
+    ) {
+        self.0.write_dictionaries(ids, dictionaries);
+        self.1.write_column_dictionaries(ids, dictionaries);
+    }
+}
+
+/// Returns the fields of the children of a struct layout `T`.
+fn fields<T, Storage>(ids: &mut DictionaryIds) -> Vec<Field>
+where
+    T: StructLayout,
+    Storage: Buffer,
+    <T::Fields<Storage> as StructFields>::Children: SchemaColumns,
+{
+    let mut fields = Vec::with_capacity(T::FIELD_NAMES.len());
+    <T::Fields<Storage> as StructFields>::Children::push_fields(T::FIELD_NAMES, ids, &mut fields);
+    fields
+}
+
+impl<T, Nulls, Storage> WriteSchema for Struct<T, Nulls, Storage>
+where
+    T: StructLayout,
+    Nulls: WriteNullability,
+    Storage: Buffer,
+    <T::Fields<Storage> as StructFields>::Children: SchemaColumns,
+{
+    fn field(name: String, ids: &mut DictionaryIds) -> Field {
+        Field::new(
+            name,
+            Nulls::NULLABLE,
+            DataType::Struct(fields::<T, Storage>(ids)),
+        )
+    }
+}
+
+impl<'layout, T, Nulls, Storage> WriteLayout<'layout> for Struct<T, Nulls, Storage>
+where
+    T: StructLayout,
+    Nulls: WriteNullability,
+    Storage: Buffer,
+    T::Fields<Storage>: 'layout,
+    <T::Fields<Storage> as StructFields>::Children: SchemaColumns,
+    <T::Fields<Storage> as StructFields>::ChildrenRef<'layout>: WriteColumns<'layout>,
+{
+    fn write_body(&'layout self, offset: usize, len: usize, body: &mut Body<'layout>) {
+        let start = self.offset().strict_add(offset);
+        let (fields, bitmap) = Nulls::parts(self.buffer_ref());
+        body.validity(bitmap, start, len);
+        fields.children_ref().write_columns(start, len, body);
+    }
+
+    fn write_dictionaries(
+        &'layout self,
+        ids: &mut DictionaryIds,
+        dictionaries: &mut Vec<DictionaryBody<'layout>>,
+    ) {
+        let (fields, _) = Nulls::parts(self.buffer_ref());
+        fields
+            .children_ref()
+            .write_column_dictionaries(ids, dictionaries);
+    }
+}
+
+impl<T, Storage> BatchSchema for Struct<T, NonNullable, Storage>
+where
+    T: StructLayout,
+    Storage: Buffer,
+    <T::Fields<Storage> as StructFields>::Children: SchemaColumns,
+{
+    fn schema() -> Schema {
+        Schema {
+            fields: fields::<T, Storage>(&mut DictionaryIds::default()),
+        }
+    }
+}
+
+impl<'layout, T, Storage> WriteBatch<'layout> for Struct<T, NonNullable, Storage>
+where
+    T: StructLayout,
+    Storage: Buffer,
+    T::Fields<Storage>: 'layout,
+    <T::Fields<Storage> as StructFields>::Children: SchemaColumns,
+    <T::Fields<Storage> as StructFields>::ChildrenRef<'layout>: WriteColumns<'layout>,
+{
+    fn write_batch(&'layout self, body: &mut Body<'layout>) {
+        self.buffer_ref()
+            .children_ref()
+            .write_columns(self.offset(), self.len(), body);
+    }
+
+    fn write_batch_dictionaries(&'layout self, dictionaries: &mut Vec<DictionaryBody<'layout>>) {
+        self.buffer_ref()
+            .children_ref()
+            .write_column_dictionaries(&mut DictionaryIds::default(), dictionaries);
+    }
+}
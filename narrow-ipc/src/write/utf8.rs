@@ -0,0 +1,47 @@
+//! Write support for [`Utf8`].
+
+use std::borrow::Borrow;
+
+use narrow::{
+    buffer::{Buffer, BufferRef},
+    collection::ChildRef,
+    layout::{
+        MemoryLayout,
+        utf8::{Utf8, Utf8Item},
+    },
+};
+
+use crate::{Field, schema::ListOffset};
+
+use super::{Body, DictionaryIds, WriteLayout, WriteNullability, WriteSchema};
+
+impl<Nulls, OffsetItem, Storage, U> WriteSchema for Utf8<Nulls, OffsetItem, Storage, U>
+where
+    Nulls: WriteNullability,
+    OffsetItem: ListOffset,
+    Storage: Buffer,
+    U: Utf8Item,
+{
+    fn field(name: String, _ids: &mut DictionaryIds) -> Field {
+        Field::new(name, Nulls::NULLABLE, OffsetItem::UTF8)
+    }
+}
+
+impl<'layout, Nulls, OffsetItem, Storage, U> WriteLayout<'layout>
+    for Utf8<Nulls, OffsetItem, Storage, U>
+where
+    Nulls: WriteNullability,
+    OffsetItem: ListOffset,
+    Storage: Buffer,
+    U: Utf8Item,
+{
+    fn write_body(&'layout self, offset: usize, len: usize, body: &mut Body<'layout>) {
+        let start = self.offset().strict_add(offset);
+        let (values, bitmap) = Nulls::parts(self.buffer_ref());
+        body.validity(bitmap, start, len);
+        let offsets = values.buffer_ref();
+        let data = body.offsets(offsets.buffer_ref().borrow(), start, len);
+        let bytes: &[u8] = offsets.child_ref().borrow();
+        body.values(&bytes[data]);
+    }
+}
@@ -0,0 +1,56 @@
+//! Write support for [`VariableSizeList`].
+
+use std::borrow::Borrow;
+
+use narrow::{
+    buffer::{Buffer, BufferRef},
+    collection::ChildRef,
+    layout::{ArrayItem, MemoryLayout, variable_size_list::VariableSizeList},
+};
+
+use crate::{Field, schema::ListOffset};
+
+use super::{Body, DictionaryBody, DictionaryIds, WriteLayout, WriteNullability, WriteSchema};
+
+impl<T, Nulls, OffsetItem, Storage> WriteSchema for VariableSizeList<T, Nulls, OffsetItem, Storage>
+where
+    T: ArrayItem,
+    Nulls: WriteNullability,
+    OffsetItem: ListOffset,
+    Storage: Buffer,
+    T::Memory<Storage>: WriteSchema,
+{
+    fn field(name: String, ids: &mut DictionaryIds) -> Field {
+        let child = <T::Memory<Storage>>::field("item".to_owned(), ids);
+        Field::new(name, Nulls::NULLABLE, OffsetItem::list(child))
+    }
+}
+
+impl<'layout, T, Nulls, OffsetItem, Storage> WriteLayout<'layout>
+    for VariableSizeList<T, Nulls, OffsetItem, Storage>
+where
+    T: ArrayItem,
+    Nulls: WriteNullability,
+    OffsetItem: ListOffset,
+    Storage: Buffer,
+    T::Memory<Storage>: WriteLayout<'layout>,
+{
+    fn write_body(&'layout self, offset: usize, len: usize, body: &mut Body<'layout>) {
+        let start = self.offset().strict_add(offset);
+        let (values, bitmap) = Nulls::parts(self.buffer_ref());
+        body.validity(bitmap, start, len);
+        let items = body.offsets(values.buffer_ref().borrow(), start, len);
+        values
+            .child_ref()
+            .write_body(items.start, items.len(), body);
+    }
+
+    fn write_dictionaries(
+        &'layout self,
+        ids: &mut DictionaryIds,
+        dictionaries: &mut Vec<DictionaryBody<'layout>>,
+    ) {
+        let (values, _) = Nulls::parts(self.buffer_ref());
+        values.child_ref().write_dictionaries(ids, dictionaries);
+    }
+}
@@ -1,11 +1,30 @@
 //! Sequences of values with known length all having the same type.
 
+#[cfg(feature = "half")]
+extern crate alloc;
+
+#[cfg(feature = "half")]
+use alloc::vec;
+#[cfg(feature = "half")]
+use core::borrow::Borrow;
 use core::fmt::Debug;
 
+#[cfg(feature = "half")]
+use half::slice::HalfFloatSliceExt;
+
+#[cfg(feature = "half")]
+use crate::{
+    bitmap::ValidityBitmap,
+    collection::ChildRef,
+    fixed_size::{FixedSize, bf16, f16},
+    layout::fixed_size_primitive::FixedSizePrimitive,
+    nullability::Nullable,
+    validity::Validity,
+};
 use crate::{
     buffer::{Buffer, BufferRef, VecBuffer},
     collection::{AllocError, Collection, CollectionAllocIn, CollectionRealloc},
-    layout::ArrayItem,
+    layout::{ArrayItem, MemoryLayout},
     length::Length,
 };
 
@@ -67,6 +86,251 @@ impl<T: ArrayItem, Storage: Buffer> Array<T, Storage> {
     pub fn into_buffer(self) -> T::Memory<Storage> {
         self.0
     }
+
+    /// Returns an [`Array`] with `len` items starting at `offset` in this
+    /// array.
+    ///
+    /// The slice shares the backing buffers of this array. With
+    /// reference-counted storage such as [`ArcBuffer`](crate::buffer::ArcBuffer)
+    /// this is a cheap handle to the same memory, so arrays can be chunked
+    /// without copying their values.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `offset + len` exceeds the length of this array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{array::Array, buffer::ArcBuffer, collection::Collection};
+    ///
+    /// let values = [1, 2, 3, 4].into_iter().collect::<Array<i32, ArcBuffer>>();
+    /// let page = values.slice(2, 2);
+    /// assert_eq!(page.into_iter_owned().collect::<Vec<_>>(), [3, 4]);
+    /// ```
+    #[must_use]
+    pub fn slice(&self, offset: usize, len: usize) -> Self
+    where
+        T::Memory<Storage>: Clone,
+    {
+        Self(self.0.clone().slice(offset, len))
+    }
+}
+
+#[cfg(feature = "half")]
+#[cfg_attr(docsrs, doc(cfg(feature = "half")))]
+impl<Storage: Buffer> Array<f16, Storage> {
+    /// Converts the values of this array to [`f32`].
+    ///
+    /// The whole array is converted at once, which uses the hardware
+    /// conversion instructions of the target where available.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{array::Array, collection::Collection, fixed_size::f16};
+    ///
+    /// let values = [f16::from_f32(0.5), f16::ONE]
+    ///     .into_iter()
+    ///     .collect::<Array<f16>>();
+    /// assert_eq!(values.to_f32().into_iter_owned().collect::<Vec<_>>(), [0.5, 1.0]);
+    /// ```
+    #[must_use]
+    pub fn to_f32(&self) -> Array<f32> {
+        let values = self.0.values();
+        let mut converted = vec![0.0; values.len()];
+        values.convert_to_f32_slice(&mut converted);
+        Array(FixedSizePrimitive::from_buffer(converted))
+    }
+}
+
+#[cfg(feature = "half")]
+#[cfg_attr(docsrs, doc(cfg(feature = "half")))]
+impl<Storage: Buffer> Array<bf16, Storage> {
+    /// Converts the values of this array to [`f32`].
+    ///
+    /// Every [`bf16`] is exactly representable as an [`f32`], so this is
+    /// lossless.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{array::Array, collection::Collection, fixed_size::bf16};
+    ///
+    /// let values = [bf16::from_f32(-2.0)].into_iter().collect::<Array<bf16>>();
+    /// assert_eq!(values.to_f32().owned(0), Some(-2.0));
+    /// ```
+    #[must_use]
+    pub fn to_f32(&self) -> Array<f32> {
+        let values = self.0.values();
+        let mut converted = vec![0.0; values.len()];
+        values.convert_to_f32_slice(&mut converted);
+        Array(FixedSizePrimitive::from_buffer(converted))
+    }
+}
+
+#[cfg(feature = "half")]
+#[cfg_attr(docsrs, doc(cfg(feature = "half")))]
+impl<Storage: Buffer> Array<f32, Storage> {
+    /// Converts the values of this array to [`f16`](struct@f16), rounding to the nearest
+    /// representable value.
+    ///
+    /// Values beyond the range of [`f16`](struct@f16) become infinite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{array::Array, collection::Collection, fixed_size::f16};
+    ///
+    /// let values = [0.25_f32, 1e6].into_iter().collect::<Array<f32>>();
+    /// let halves = values.to_f16();
+    /// assert_eq!(halves.owned(0), Some(f16::from_f32(0.25)));
+    /// assert_eq!(halves.owned(1), Some(f16::INFINITY));
+    /// ```
+    #[must_use]
+    pub fn to_f16(&self) -> Array<f16> {
+        let values = self.0.values();
+        let mut converted = vec![f16::ZERO; values.len()];
+        converted.convert_from_f32_slice(values);
+        Array(FixedSizePrimitive::from_buffer(converted))
+    }
+
+    /// Converts the values of this array to [`bf16`], rounding to the
+    /// nearest representable value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{array::Array, collection::Collection, fixed_size::bf16};
+    ///
+    /// let values = [1.0_f32, 3.0].into_iter().collect::<Array<f32>>();
+    /// assert_eq!(values.to_bf16().owned(1), Some(bf16::from_f32(3.0)));
+    /// ```
+    #[must_use]
+    pub fn to_bf16(&self) -> Array<bf16> {
+        let values = self.0.values();
+        let mut converted = vec![bf16::ZERO; values.len()];
+        converted.convert_from_f32_slice(values);
+        Array(FixedSizePrimitive::from_buffer(converted))
+    }
+}
+
+#[cfg(feature = "half")]
+#[cfg_attr(docsrs, doc(cfg(feature = "half")))]
+impl<Storage: Buffer> Array<Option<f16>, Storage> {
+    /// Converts the values of this array to [`f32`], keeping their validity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{array::Array, collection::Collection, fixed_size::f16};
+    ///
+    /// let values = [Some(f16::ONE), None].into_iter().collect::<Array<Option<f16>>>();
+    /// assert_eq!(values.to_f32().into_iter_owned().collect::<Vec<_>>(), [Some(1.0), None]);
+    /// ```
+    #[must_use]
+    pub fn to_f32(&self) -> Array<Option<f32>> {
+        convert_nullable(&self.0, |values, converted| {
+            values.convert_to_f32_slice(converted);
+        })
+    }
+}
+
+#[cfg(feature = "half")]
+#[cfg_attr(docsrs, doc(cfg(feature = "half")))]
+impl<Storage: Buffer> Array<Option<bf16>, Storage> {
+    /// Converts the values of this array to [`f32`], keeping their validity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{array::Array, collection::Collection, fixed_size::bf16};
+    ///
+    /// let values = [None, Some(bf16::from_f32(-2.0))]
+    ///     .into_iter()
+    ///     .collect::<Array<Option<bf16>>>();
+    /// assert_eq!(values.to_f32().owned(1), Some(Some(-2.0)));
+    /// ```
+    #[must_use]
+    pub fn to_f32(&self) -> Array<Option<f32>> {
+        convert_nullable(&self.0, |values, converted| {
+            values.convert_to_f32_slice(converted);
+        })
+    }
+}
+
+#[cfg(feature = "half")]
+#[cfg_attr(docsrs, doc(cfg(feature = "half")))]
+impl<Storage: Buffer> Array<Option<f32>, Storage> {
+    /// Converts the values of this array to [`f16`](struct@f16), keeping their
+    /// validity.
+    ///
+    /// Valid values are rounded as by [`Array::<f32>::to_f16`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{array::Array, collection::Collection, fixed_size::f16};
+    ///
+    /// let values = [Some(0.25_f32), None].into_iter().collect::<Array<Option<f32>>>();
+    /// let halves = values.to_f16();
+    /// assert_eq!(halves.owned(0), Some(Some(f16::from_f32(0.25))));
+    /// assert_eq!(halves.owned(1), Some(None));
+    /// ```
+    #[must_use]
+    pub fn to_f16(&self) -> Array<Option<f16>> {
+        convert_nullable(&self.0, |values, converted: &mut [f16]| {
+            converted.convert_from_f32_slice(values);
+        })
+    }
+
+    /// Converts the values of this array to [`bf16`], keeping their validity.
+    ///
+    /// Valid values are rounded as by [`Array::<f32>::to_bf16`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{array::Array, collection::Collection, fixed_size::bf16};
+    ///
+    /// let values = [None, Some(3.0_f32)].into_iter().collect::<Array<Option<f32>>>();
+    /// assert_eq!(values.to_bf16().owned(1), Some(Some(bf16::from_f32(3.0))));
+    /// ```
+    #[must_use]
+    pub fn to_bf16(&self) -> Array<Option<bf16>> {
+        convert_nullable(&self.0, |values, converted: &mut [bf16]| {
+            converted.convert_from_f32_slice(values);
+        })
+    }
+}
+
+/// Converts the values of a nullable `values` layout with `convert`, keeping
+/// their validity.
+///
+/// The values of null items are converted as well, so the whole slice is
+/// converted at once.
+#[cfg(feature = "half")]
+fn convert_nullable<T: FixedSize, U: FixedSize + Default, Storage: Buffer>(
+    values: &FixedSizePrimitive<T, Nullable, Storage>,
+    convert: impl FnOnce(&[T], &mut [U]),
+) -> Array<Option<U>> {
+    let range = values.offset()..values.offset().strict_add(values.len());
+    let buffer = values.buffer_ref();
+    let mut converted = vec![U::default(); range.len()];
+    convert(&buffer.child_ref().borrow()[range.clone()], &mut converted);
+    let validity = match buffer.bitmap_ref() {
+        Some(bitmap) => Validity::try_from_parts(
+            converted,
+            bitmap
+                .iter_views()
+                .skip(range.start)
+                .take(range.len())
+                .collect(),
+        )
+        .expect("validity lengths match"),
+        None => Validity::from_collection(converted),
+    };
+    Array(FixedSizePrimitive::from_buffer(validity))
 }
 
 impl<T: ArrayItem, Storage: Buffer> BufferRef for Array<T, Storage> {
@@ -214,9 +478,18 @@ where
 mod tests {
     extern crate alloc;
 
-    use alloc::vec;
+    use alloc::{boxed::Box, string::String, sync::Arc, vec, vec::Vec};
 
-    use crate::{collection::tests::round_trip, fixed_size::FixedSizeArray};
+    use crate::{
+        buffer::ArcBuffer,
+        collection::{ChildRef, tests::round_trip},
+        fixed_size::FixedSizeArray,
+        layout::{
+            dictionary::Dictionary, fixed_size_primitive::FixedSizePrimitive,
+            variable_size_list::VariableSizeList,
+        },
+        offset::Offsets,
+    };
 
     use super::*;
 
@@ -262,10 +535,7 @@ mod tests {
 
         let mut array = Array::<i32>::default();
         extend(&mut array, [1, 2, 3, 4]);
-        assert_eq!(
-            array.into_iter_owned().collect::<alloc::vec::Vec<_>>(),
-            [1, 2, 3, 4]
-        );
+        assert_eq!(array.into_iter_owned().collect::<Vec<_>>(), [1, 2, 3, 4]);
     }
 
     #[test]
@@ -294,6 +564,18 @@ mod tests {
         round_trip::<Array<_>, _>([vec![1_u8, 2, 3, 4], vec![5, 6, 7, 8]]);
         round_trip::<Array<_>, _>([Some(vec![1_u8, 2, 3, 4]), None]);
 
+        // Dictionary
+        round_trip::<Array<_>, _>(
+            [b"a".to_vec(), b"bc".to_vec(), b"a".to_vec()].map(Dictionary::<u16, _>::new),
+        );
+        round_trip::<Array<_>, _>([Some(Dictionary::<i8, _>::new(String::from("a"))), None]);
+
+        // Utf8
+        round_trip::<Array<_>, _>([String::from("a"), String::from("bcd")]);
+        round_trip::<Array<_>, _>([Some(String::from("a")), None]);
+        round_trip::<Array<Box<str>>, _>([Box::from("a"), Box::from("")]);
+        round_trip::<Array<_>, _>([vec![Some(String::from("a")), None], vec![]]);
+
         // Variable size list
         round_trip::<Array<_>, _>([vec![1, 2, 3, 4], vec![5, 6, 7, 8]]);
         round_trip::<Array<_>, _>([Some(vec![1, 2, 3, 4]), None]);
@@ -315,4 +597,116 @@ mod tests {
         round_trip::<Array<_>, _>([vec![Some([1, 2]), None], vec![None, Some([7, 8])]]);
         round_trip::<Array<_>, _>([Some(vec![Some([1, 2]), None]), None]);
     }
+
+    #[test]
+    fn slice() {
+        let lists = [Some(vec![1, 2]), None, Some(vec![3]), Some(vec![4, 5])]
+            .into_iter()
+            .collect::<Array<Option<Vec<i32>>>>();
+        let list_slice = lists.slice(1, 3);
+        assert_eq!(list_slice.len(), 3);
+        assert_eq!(list_slice.owned(0), Some(None));
+        assert_eq!(list_slice.owned(3), None);
+        assert_eq!(
+            list_slice.slice(1, 2).into_iter_owned().collect::<Vec<_>>(),
+            [Some(vec![3]), Some(vec![4, 5])]
+        );
+
+        let strings = ["a", "bc", "", "def"]
+            .map(String::from)
+            .into_iter()
+            .collect::<Array<String>>();
+        assert_eq!(
+            strings.slice(1, 2).iter_views().collect::<Vec<_>>(),
+            ["bc", ""]
+        );
+
+        let structs = [(1_u8, Some(true)), (2, None), (3, Some(false))]
+            .into_iter()
+            .collect::<Array<(u8, Option<bool>)>>();
+        assert_eq!(
+            structs.slice(2, 1).into_iter_owned().collect::<Vec<_>>(),
+            [(3, Some(false))]
+        );
+
+        let empty = lists.slice(4, 0);
+        assert!(empty.is_empty());
+        assert_eq!(empty.into_iter_owned().count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "slice at offset 3 with length 2 exceeds length 4")]
+    fn slice_out_of_bounds() {
+        let array = [1, 2, 3, 4].into_iter().collect::<Array<i32>>();
+        let _slice = array.slice(3, 2);
+    }
+
+    #[test]
+    fn slice_shares_storage() {
+        let values = Arc::<[u8]>::from([1, 2, 3, 4, 5]);
+        let offsets = Offsets::try_from_parts(
+            FixedSizePrimitive::from_buffer(Arc::clone(&values)),
+            Arc::<[i32]>::from([0, 2, 2, 5]),
+        )
+        .expect("valid offsets");
+        let array =
+            Array::<Vec<u8>, ArcBuffer>::from_buffer(VariableSizeList::from_buffer(offsets));
+        let slice = array.slice(1, 2);
+        assert_eq!(slice.buffer_ref().offset(), 1);
+        assert!(Arc::ptr_eq(
+            slice.buffer_ref().buffer_ref().child_ref().buffer_ref(),
+            &values
+        ));
+        assert_eq!(
+            slice.into_iter_owned().collect::<Vec<_>>(),
+            [vec![], vec![3, 4, 5]]
+        );
+    }
+
+    #[test]
+    fn extend_slice() {
+        let array = [1, 2, 3, 4].into_iter().collect::<Array<i32>>();
+        let mut slice = array.slice(1, 1);
+        slice.extend([5]);
+        assert_eq!(slice.into_iter_owned().collect::<Vec<_>>(), [2, 5]);
+    }
+
+    #[test]
+    #[cfg(feature = "half")]
+    fn half_conversions_respect_slices() {
+        let values = [1.0_f32, 0.5, -2.0, 65_504.0]
+            .into_iter()
+            .collect::<Array<f32>>()
+            .slice(1, 2);
+        let halves = values.to_f16();
+        assert_eq!(
+            halves.to_f32().into_iter_owned().collect::<Vec<_>>(),
+            [0.5, -2.0]
+        );
+        let bf16_values = values.to_bf16().slice(1, 1);
+        assert_eq!(
+            bf16_values.to_f32().into_iter_owned().collect::<Vec<_>>(),
+            [-2.0]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "half")]
+    fn nullable_half_conversions_keep_validity() {
+        let values = [Some(1.0_f32), None, Some(0.5), None, Some(-2.0)]
+            .into_iter()
+            .collect::<Array<Option<f32>>>()
+            .slice(1, 4);
+        let halves = values.to_f16();
+        assert_eq!(halves.buffer_ref().buffer_ref().null_count(), 2);
+        assert_eq!(
+            halves.to_f32().into_iter_owned().collect::<Vec<_>>(),
+            [None, Some(0.5), None, Some(-2.0)]
+        );
+        let bf16_values = values.to_bf16().slice(1, 3);
+        assert_eq!(
+            bf16_values.to_f32().into_iter_owned().collect::<Vec<_>>(),
+            [Some(0.5), None, Some(-2.0)]
+        );
+    }
 }
@@ -8,9 +8,11 @@ pub mod array;
 pub mod r#box;
 pub mod rc;
 pub mod slice;
+pub mod tuple;
 pub mod vec;
 
 pub mod flatten;
+pub mod window;
 
 use core::fmt;
 
@@ -426,7 +428,7 @@ pub(crate) mod tests {
 
     pub(crate) fn round_trip<
         C: for<'any> CollectionAlloc<Owned = T, View<'any>: Debug>,
-        T: for<'this, 'other> AsView<'this, View: Debug> + Clone + Default + Debug + PartialEq,
+        T: for<'this, 'other> AsView<'this, View: Debug> + Clone + Debug + PartialEq,
     >(
         items: impl IntoIterator<Item = T>,
     ) {
@@ -25,7 +25,8 @@ pub trait IntoOwned<Owned> {
     /// ```
     /// use narrow::collection::owned::IntoOwned;
     ///
-    /// assert_eq!((&[1, 2][..]).into_owned(), vec![1, 2]);
+    /// let owned: Vec<_> = (&[1, 2][..]).into_owned();
+    /// assert_eq!(owned, vec![1, 2]);
     /// ```
     fn into_owned(self) -> Owned;
 }
@@ -36,6 +37,10 @@ impl IntoOwned<bool> for bool {
     }
 }
 
+impl IntoOwned<()> for () {
+    fn into_owned(self) {}
+}
+
 #[diagnostic::do_not_recommend]
 impl<T: FixedSize> IntoOwned<T> for T {
     fn into_owned(self) -> T {
@@ -55,8 +60,38 @@ impl<T: Clone> IntoOwned<Vec<T>> for &[T] {
     }
 }
 
+impl<T: for<'any> From<&'any str>> IntoOwned<T> for &str {
+    fn into_owned(self) -> T {
+        self.into()
+    }
+}
+
 impl<T: IntoOwned<U>, U> IntoOwned<Option<U>> for Option<T> {
     fn into_owned(self) -> Option<U> {
         self.map(IntoOwned::into_owned)
     }
 }
+
+/// Implements [`IntoOwned`] for tuples of views.
+macro_rules! tuple {
+    ($($index:tt $View:ident $Owned:ident),+) => {
+        impl<$($View: IntoOwned<$Owned>, $Owned),+> IntoOwned<($($Owned,)+)> for ($($View,)+) {
+            fn into_owned(self) -> ($($Owned,)+) {
+                ($(self.$index.into_owned(),)+)
+            }
+        }
+    };
+}
+
+tuple!(0 V0 T0);
+tuple!(0 V0 T0, 1 V1 T1);
+tuple!(0 V0 T0, 1 V1 T1, 2 V2 T2);
+tuple!(0 V0 T0, 1 V1 T1, 2 V2 T2, 3 V3 T3);
+tuple!(0 V0 T0, 1 V1 T1, 2 V2 T2, 3 V3 T3, 4 V4 T4);
+tuple!(0 V0 T0, 1 V1 T1, 2 V2 T2, 3 V3 T3, 4 V4 T4, 5 V5 T5);
+tuple!(0 V0 T0, 1 V1 T1, 2 V2 T2, 3 V3 T3, 4 V4 T4, 5 V5 T5, 6 V6 T6);
+tuple!(0 V0 T0, 1 V1 T1, 2 V2 T2, 3 V3 T3, 4 V4 T4, 5 V5 T5, 6 V6 T6, 7 V7 T7);
+tuple!(0 V0 T0, 1 V1 T1, 2 V2 T2, 3 V3 T3, 4 V4 T4, 5 V5 T5, 6 V6 T6, 7 V7 T7, 8 V8 T8);
+tuple!(0 V0 T0, 1 V1 T1, 2 V2 T2, 3 V3 T3, 4 V4 T4, 5 V5 T5, 6 V6 T6, 7 V7 T7, 8 V8 T8, 9 V9 T9);
+tuple!(0 V0 T0, 1 V1 T1, 2 V2 T2, 3 V3 T3, 4 V4 T4, 5 V5 T5, 6 V6 T6, 7 V7 T7, 8 V8 T8, 9 V9 T9, 10 V10 T10);
+tuple!(0 V0 T0, 1 V1 T1, 2 V2 T2, 3 V3 T3, 4 V4 T4, 5 V5 T5, 6 V6 T6, 7 V7 T7, 8 V8 T8, 9 V9 T9, 10 V10 T10, 11 V11 T11);
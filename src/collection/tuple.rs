@@ -0,0 +1,244 @@
+//! Tuples of equally long collections.
+//!
+//! A tuple `(A, B)` of collections is a collection of `(A::Owned, B::Owned)`
+//! items: the `n`-th item combines the `n`-th item of every collection. This
+//! is the struct-of-arrays form Arrow uses for the fields of a struct array.
+//!
+//! # Examples
+//!
+//! ```
+//! use narrow::collection::Collection;
+//!
+//! let values = [(1, true), (2, false)]
+//!     .into_iter()
+//!     .collect::<(Vec<u8>, Vec<bool>)>();
+//! assert_eq!(values.0, [1, 2]);
+//! assert_eq!(values.view(1), Some((2, false)));
+//! ```
+
+use core::{
+    iter::{self, Map, RepeatN, Zip},
+    ops::Range,
+};
+
+use crate::{
+    collection::{AllocError, Collection, CollectionAllocIn, CollectionRealloc},
+    length::Length,
+};
+
+/// Iterator over owned items of a tuple of collections.
+///
+/// Yields one tuple per index by advancing the iterators of all collections
+/// in lockstep.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::collection::Collection;
+///
+/// let values = (vec![1, 2], vec![true, false]);
+/// let mut iter = values.into_iter_owned();
+/// assert_eq!(iter.len(), 2);
+/// assert_eq!(iter.next(), Some((1, true)));
+/// ```
+#[derive(Clone, Debug)]
+pub struct TupleIntoIter<T>(T);
+
+/// Implements the collection traits for a tuple of collections.
+///
+/// The first collection determines the length and the allocator of the tuple.
+macro_rules! tuple {
+    ($first_index:tt $First:ident $(, $index:tt $Rest:ident)*) => {
+        impl<$First: Collection, $($Rest: Collection),*> Length for ($First, $($Rest,)*) {
+            fn len(&self) -> usize {
+                self.$first_index.len()
+            }
+        }
+
+        impl<$First: Collection, $($Rest: Collection),*> Collection for ($First, $($Rest,)*) {
+            type View<'collection>
+                = ($First::View<'collection>, $($Rest::View<'collection>,)*)
+            where
+                Self: 'collection;
+
+            type Owned = ($First::Owned, $($Rest::Owned,)*);
+
+            fn view(&self, index: usize) -> Option<Self::View<'_>> {
+                Some((
+                    self.$first_index.view(index)?,
+                    $(self.$index.view(index)?,)*
+                ))
+            }
+
+            type Iter<'collection>
+                = Map<
+                Zip<Range<usize>, RepeatN<&'collection Self>>,
+                fn((usize, &'collection Self)) -> Self::View<'collection>,
+            >
+            where
+                Self: 'collection;
+
+            fn iter_views(&self) -> Self::Iter<'_> {
+                (0..self.len())
+                    .zip(iter::repeat_n(self, self.len()))
+                    .map(|(index, collection)| collection.view(index).expect("out of bounds"))
+            }
+
+            type IntoIter = TupleIntoIter<($First::IntoIter, $($Rest::IntoIter,)*)>;
+
+            fn into_iter_owned(self) -> Self::IntoIter {
+                TupleIntoIter((
+                    self.$first_index.into_iter_owned(),
+                    $(self.$index.into_iter_owned(),)*
+                ))
+            }
+        }
+
+        impl<$First: CollectionRealloc, $($Rest: CollectionRealloc<Alloc = $First::Alloc>),*>
+            CollectionAllocIn for ($First, $($Rest,)*)
+        {
+            type Alloc = $First::Alloc;
+
+            fn with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Self {
+                (
+                    $First::with_capacity_in(capacity, alloc.clone()),
+                    $($Rest::with_capacity_in(capacity, alloc.clone()),)*
+                )
+            }
+
+            fn from_iter_in<I: IntoIterator<Item = Self::Owned>>(
+                iter: I,
+                alloc: Self::Alloc,
+            ) -> Self {
+                let items = iter.into_iter();
+                let mut collection = Self::with_capacity_in(items.size_hint().0, alloc);
+                collection.extend(items);
+                collection
+            }
+
+            fn try_with_capacity_in(
+                capacity: usize,
+                alloc: Self::Alloc,
+            ) -> Result<Self, AllocError> {
+                Ok((
+                    $First::try_with_capacity_in(capacity, alloc.clone())?,
+                    $($Rest::try_with_capacity_in(capacity, alloc.clone())?,)*
+                ))
+            }
+
+            fn try_from_iter_in<I: IntoIterator<Item = Self::Owned>>(
+                iter: I,
+                alloc: Self::Alloc,
+            ) -> Result<Self, AllocError> {
+                let items = iter.into_iter();
+                let mut collection = Self::try_with_capacity_in(items.size_hint().0, alloc)?;
+                collection.try_extend(items)?;
+                Ok(collection)
+            }
+        }
+
+        impl<$First: CollectionRealloc, $($Rest: CollectionRealloc<Alloc = $First::Alloc>),*>
+            CollectionRealloc for ($First, $($Rest,)*)
+        {
+            fn allocator(&self) -> Self::Alloc {
+                self.$first_index.allocator()
+            }
+
+            fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+                self.$first_index.try_reserve(additional)?;
+                $(self.$index.try_reserve(additional)?;)*
+                Ok(())
+            }
+
+            fn try_extend<I: IntoIterator<Item = Self::Owned>>(
+                &mut self,
+                iter: I,
+            ) -> Result<(), AllocError> {
+                // The collections are extended one item at a time, so a failed
+                // reservation can leave them with different lengths. Truncating
+                // restores the items present before this call.
+                let len = self.len();
+                iter.into_iter()
+                    .try_for_each(|item| {
+                        self.$first_index.try_extend(iter::once(item.$first_index))?;
+                        $(self.$index.try_extend(iter::once(item.$index))?;)*
+                        Ok(())
+                    })
+                    .inspect_err(|_| self.truncate(len))
+            }
+
+            fn reserve(&mut self, additional: usize) {
+                self.$first_index.reserve(additional);
+                $(self.$index.reserve(additional);)*
+            }
+
+            fn truncate(&mut self, len: usize) {
+                self.$first_index.truncate(len);
+                $(self.$index.truncate(len);)*
+            }
+        }
+
+        impl<$First: ExactSizeIterator, $($Rest: ExactSizeIterator),*> Iterator
+            for TupleIntoIter<($First, $($Rest,)*)>
+        {
+            type Item = ($First::Item, $($Rest::Item,)*);
+
+            fn next(&mut self) -> Option<Self::Item> {
+                Some((
+                    self.0.$first_index.next()?,
+                    $(self.0.$index.next()?,)*
+                ))
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.0.$first_index.size_hint()
+            }
+        }
+
+        impl<$First: ExactSizeIterator, $($Rest: ExactSizeIterator),*> ExactSizeIterator
+            for TupleIntoIter<($First, $($Rest,)*)>
+        {
+        }
+    };
+}
+
+tuple!(0 C0);
+tuple!(0 C0, 1 C1);
+tuple!(0 C0, 1 C1, 2 C2);
+tuple!(0 C0, 1 C1, 2 C2, 3 C3);
+tuple!(0 C0, 1 C1, 2 C2, 3 C3, 4 C4);
+tuple!(0 C0, 1 C1, 2 C2, 3 C3, 4 C4, 5 C5);
+tuple!(0 C0, 1 C1, 2 C2, 3 C3, 4 C4, 5 C5, 6 C6);
+tuple!(0 C0, 1 C1, 2 C2, 3 C3, 4 C4, 5 C5, 6 C6, 7 C7);
+tuple!(0 C0, 1 C1, 2 C2, 3 C3, 4 C4, 5 C5, 6 C6, 7 C7, 8 C8);
+tuple!(0 C0, 1 C1, 2 C2, 3 C3, 4 C4, 5 C5, 6 C6, 7 C7, 8 C8, 9 C9);
+tuple!(0 C0, 1 C1, 2 C2, 3 C3, 4 C4, 5 C5, 6 C6, 7 C7, 8 C8, 9 C9, 10 C10);
+tuple!(0 C0, 1 C1, 2 C2, 3 C3, 4 C4, 5 C5, 6 C6, 7 C7, 8 C8, 9 C9, 10 C10, 11 C11);
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::{vec, vec::Vec};
+
+    use crate::collection::tests::round_trip;
+
+    use super::*;
+
+    #[test]
+    fn collection() {
+        round_trip::<(Vec<_>,), _>([(1,), (2,)]);
+        round_trip::<(Vec<_>, Vec<_>), _>([(1, true), (2, false)]);
+        round_trip::<(Vec<_>, Vec<_>, Vec<_>), _>([(1, Some(2), vec![3]), (4, None, vec![])]);
+    }
+
+    #[test]
+    fn try_extend() {
+        let mut values = <(Vec<u8>, Vec<u16>)>::default();
+        CollectionRealloc::try_extend(&mut values, [(1, 2), (3, 4)]).expect("reserved");
+        assert_eq!(values, (vec![1, 3], vec![2, 4]));
+        CollectionRealloc::truncate(&mut values, 1);
+        assert_eq!(values.owned(0), Some((1, 2)));
+        assert_eq!(values.len(), 1);
+    }
+}
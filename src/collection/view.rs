@@ -1,7 +1,7 @@
 extern crate alloc;
 
 use crate::{collection::owned::IntoOwned, fixed_size::FixedSize};
-use alloc::vec::Vec;
+use alloc::{boxed::Box, string::String, vec::Vec};
 
 /// Convert items into views.
 ///
@@ -43,6 +43,12 @@ impl<'a> AsView<'a> for bool {
     }
 }
 
+impl<'a> AsView<'a> for () {
+    type View = ();
+
+    fn as_view(&'a self) {}
+}
+
 #[diagnostic::do_not_recommend]
 impl<'a, T: FixedSize> AsView<'a> for T {
     type View = T;
@@ -69,6 +75,48 @@ impl<'collection, T: Clone + 'collection> AsView<'collection> for Vec<T> {
     }
 }
 
+impl<'collection> AsView<'collection> for String {
+    type View = &'collection str;
+
+    fn as_view(&'collection self) -> Self::View {
+        self
+    }
+}
+
+impl<'collection> AsView<'collection> for Box<str> {
+    type View = &'collection str;
+
+    fn as_view(&'collection self) -> Self::View {
+        self
+    }
+}
+
+/// Implements [`AsView`] for tuples, viewing every element.
+macro_rules! tuple {
+    ($($index:tt $Type:ident),+) => {
+        impl<'collection, $($Type: AsView<'collection>),+> AsView<'collection> for ($($Type,)+) {
+            type View = ($($Type::View,)+);
+
+            fn as_view(&'collection self) -> Self::View {
+                ($(self.$index.as_view(),)+)
+            }
+        }
+    };
+}
+
+tuple!(0 T0);
+tuple!(0 T0, 1 T1);
+tuple!(0 T0, 1 T1, 2 T2);
+tuple!(0 T0, 1 T1, 2 T2, 3 T3);
+tuple!(0 T0, 1 T1, 2 T2, 3 T3, 4 T4);
+tuple!(0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5);
+tuple!(0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6);
+tuple!(0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7);
+tuple!(0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8);
+tuple!(0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8, 9 T9);
+tuple!(0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8, 9 T9, 10 T10);
+tuple!(0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8, 9 T9, 10 T10, 11 T11);
+
 #[cfg(test)]
 mod tests {
     extern crate alloc;
@@ -0,0 +1,399 @@
+//! A contiguous window into an inner collection.
+
+use core::{
+    fmt,
+    iter::{Skip, Take},
+};
+
+use crate::{
+    buffer::BufferRef,
+    collection::{AllocError, Collection, CollectionAllocIn, CollectionRealloc},
+    length::Length,
+};
+
+/// A window of `len` items starting at `offset` in an inner collection.
+///
+/// Reference-counted storage cannot be subsliced without losing the handle
+/// that keeps it alive, so slicing an Arrow array keeps the whole backing
+/// collection and records the logical window next to it. This mirrors the
+/// `offset` and `length` fields of the Arrow C data interface:
+///
+/// ```text
+/// collection  [a, b, c, d, e]
+/// window             [c, d]    offset 2, len 2
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{collection::{Collection, window::Window}, length::Length};
+///
+/// let values = Window::new(vec![1, 2, 3, 4]).slice(1, 2);
+/// assert_eq!(values.len(), 2);
+/// assert_eq!(values.offset(), 1);
+/// assert_eq!(values.iter_views().collect::<Vec<_>>(), [2, 3]);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Window<C: Collection> {
+    /// The complete inner collection.
+    collection: C,
+    /// The index of the first item of the window.
+    offset: usize,
+    /// The number of items in the window.
+    len: usize,
+}
+
+/// Error returned by [`Window::try_from_parts`].
+///
+/// A window is validated once when it is constructed, so indexing can map
+/// window positions to inner positions without further bounds arithmetic.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::collection::window::{Window, WindowError};
+///
+/// let error = Window::try_from_parts(vec![1, 2], 1, 2).unwrap_err();
+/// assert_eq!(error, WindowError::OutOfBounds { offset: 1, len: 2, available: 2 });
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WindowError {
+    /// The window extends past the end of the inner collection.
+    OutOfBounds {
+        /// The requested window offset.
+        offset: usize,
+        /// The requested window length.
+        len: usize,
+        /// The length of the inner collection.
+        available: usize,
+    },
+}
+
+impl fmt::Display for WindowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::OutOfBounds {
+                offset,
+                len,
+                available,
+            } => write!(
+                f,
+                "window at offset {offset} with length {len} exceeds collection length {available}"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for WindowError {}
+
+impl<C: Collection> Window<C> {
+    /// Constructs a [`Window`] covering all items of `collection`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{collection::window::Window, length::Length};
+    ///
+    /// let values = Window::new(vec![1, 2]);
+    /// assert_eq!(values.offset(), 0);
+    /// assert_eq!(values.len(), 2);
+    /// ```
+    #[must_use]
+    pub fn new(collection: C) -> Self {
+        let len = collection.len();
+        Self {
+            collection,
+            offset: 0,
+            len,
+        }
+    }
+
+    /// Constructs a [`Window`] of `len` items starting at `offset` in
+    /// `collection`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`WindowError`] when the window does not fit in
+    /// `collection`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::collection::{Collection, window::Window};
+    ///
+    /// let values = Window::try_from_parts(vec![1, 2, 3], 1, 2).unwrap();
+    /// assert_eq!(values.owned(0), Some(2));
+    /// ```
+    pub fn try_from_parts(collection: C, offset: usize, len: usize) -> Result<Self, WindowError> {
+        let available = collection.len();
+        if offset.checked_add(len).is_some_and(|end| end <= available) {
+            Ok(Self {
+                collection,
+                offset,
+                len,
+            })
+        } else {
+            Err(WindowError::OutOfBounds {
+                offset,
+                len,
+                available,
+            })
+        }
+    }
+
+    /// Returns the inner collection, offset and length of this [`Window`].
+    ///
+    /// This is the inverse of [`Window::try_from_parts`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::collection::window::Window;
+    ///
+    /// let values = Window::try_from_parts(vec![1, 2, 3], 1, 2).unwrap();
+    /// assert_eq!(values.into_parts(), (vec![1, 2, 3], 1, 2));
+    /// ```
+    #[must_use]
+    pub fn into_parts(self) -> (C, usize, usize) {
+        (self.collection, self.offset, self.len)
+    }
+
+    /// Returns the index of the first item of this [`Window`] in the inner
+    /// collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::collection::window::Window;
+    ///
+    /// assert_eq!(Window::new(vec![1, 2]).slice(1, 1).offset(), 1);
+    /// ```
+    #[must_use]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns a [`Window`] of `len` items starting at `offset` in this
+    /// window, sharing the inner collection.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `offset + len` exceeds the length of this window.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::collection::{Collection, window::Window};
+    ///
+    /// let values = Window::new(vec![1, 2, 3, 4]).slice(1, 3).slice(1, 1);
+    /// assert_eq!(values.offset(), 2);
+    /// assert_eq!(values.owned(0), Some(3));
+    /// ```
+    #[must_use]
+    pub fn slice(self, offset: usize, len: usize) -> Self {
+        assert!(
+            offset.checked_add(len).is_some_and(|end| end <= self.len),
+            "slice at offset {offset} with length {len} exceeds length {}",
+            self.len
+        );
+        Self {
+            collection: self.collection,
+            offset: self.offset.strict_add(offset),
+            len,
+        }
+    }
+
+    /// Returns the inner index one past the last item of this window.
+    fn end(&self) -> usize {
+        self.offset.strict_add(self.len)
+    }
+}
+
+impl<C: Collection> BufferRef for Window<C> {
+    type Buffer = C;
+
+    fn buffer_ref(&self) -> &Self::Buffer {
+        &self.collection
+    }
+}
+
+impl<C: Collection + Default> Default for Window<C> {
+    fn default() -> Self {
+        Self::new(C::default())
+    }
+}
+
+impl<C: Collection> Length for Window<C> {
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<C: Collection + FromIterator<U>, U> FromIterator<U> for Window<C> {
+    fn from_iter<I: IntoIterator<Item = U>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+/// Items beyond the end of the window are dropped before extending, so the
+/// new items directly follow the window.
+impl<C: CollectionRealloc + Extend<U>, U> Extend<U> for Window<C> {
+    fn extend<I: IntoIterator<Item = U>>(&mut self, iter: I) {
+        self.collection.truncate(self.end());
+        self.collection.extend(iter);
+        self.len = self.collection.len().strict_sub(self.offset);
+    }
+}
+
+impl<C: Collection> Collection for Window<C> {
+    type View<'collection>
+        = C::View<'collection>
+    where
+        Self: 'collection;
+
+    type Owned = C::Owned;
+
+    fn view(&self, index: usize) -> Option<Self::View<'_>> {
+        (index < self.len)
+            .then(|| self.collection.view(self.offset.strict_add(index)))
+            .flatten()
+    }
+
+    type Iter<'collection>
+        = Take<Skip<C::Iter<'collection>>>
+    where
+        Self: 'collection;
+
+    fn iter_views(&self) -> Self::Iter<'_> {
+        self.collection
+            .iter_views()
+            .skip(self.offset)
+            .take(self.len)
+    }
+
+    type IntoIter = Take<Skip<C::IntoIter>>;
+
+    fn into_iter_owned(self) -> Self::IntoIter {
+        self.collection
+            .into_iter_owned()
+            .skip(self.offset)
+            .take(self.len)
+    }
+}
+
+impl<C: CollectionAllocIn> CollectionAllocIn for Window<C> {
+    type Alloc = C::Alloc;
+
+    fn with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Self {
+        Self::new(C::with_capacity_in(capacity, alloc))
+    }
+
+    fn from_iter_in<I: IntoIterator<Item = Self::Owned>>(iter: I, alloc: Self::Alloc) -> Self {
+        Self::new(C::from_iter_in(iter, alloc))
+    }
+
+    fn try_with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Result<Self, AllocError> {
+        C::try_with_capacity_in(capacity, alloc).map(Self::new)
+    }
+
+    fn try_from_iter_in<I: IntoIterator<Item = Self::Owned>>(
+        iter: I,
+        alloc: Self::Alloc,
+    ) -> Result<Self, AllocError> {
+        C::try_from_iter_in(iter, alloc).map(Self::new)
+    }
+}
+
+impl<C: CollectionRealloc> CollectionRealloc for Window<C> {
+    fn allocator(&self) -> Self::Alloc {
+        self.collection.allocator()
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+        self.collection.try_reserve(additional)
+    }
+
+    fn try_extend<I: IntoIterator<Item = Self::Owned>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), AllocError> {
+        self.collection.truncate(self.end());
+        self.collection.try_extend(iter)?;
+        self.len = self.collection.len().strict_sub(self.offset);
+        Ok(())
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.collection.reserve(additional);
+    }
+
+    fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            self.collection.truncate(self.offset.strict_add(len));
+            self.len = len;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::{vec, vec::Vec};
+
+    use crate::collection::tests::round_trip;
+
+    use super::*;
+
+    #[test]
+    fn slices() {
+        let window = Window::new(vec![1, 2, 3, 4, 5]).slice(1, 3);
+        assert_eq!(window.len(), 3);
+        assert_eq!(window.view(0), Some(2));
+        assert_eq!(window.view(2), Some(4));
+        assert_eq!(window.view(3), None);
+        assert_eq!(window.iter_views().collect::<Vec<_>>(), [2, 3, 4]);
+        assert_eq!(window.into_iter_owned().collect::<Vec<_>>(), [2, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "slice at offset 2 with length 2 exceeds length 3")]
+    fn slice_out_of_bounds() {
+        let _window = Window::new(vec![1, 2, 3]).slice(2, 2);
+    }
+
+    #[test]
+    fn try_from_parts_out_of_bounds() {
+        assert_eq!(
+            Window::try_from_parts(vec![1], usize::MAX, 2).expect_err("overflowing window"),
+            WindowError::OutOfBounds {
+                offset: usize::MAX,
+                len: 2,
+                available: 1
+            }
+        );
+    }
+
+    #[test]
+    fn extend_drops_items_after_window() {
+        let mut window = Window::new(vec![1, 2, 3, 4]).slice(1, 1);
+        window.extend([5, 6]);
+        assert_eq!(window.iter_views().collect::<Vec<_>>(), [2, 5, 6]);
+        assert_eq!(window.into_parts(), (vec![1, 2, 5, 6], 1, 3));
+    }
+
+    #[test]
+    fn truncate() {
+        let mut window = Window::new(vec![1, 2, 3, 4]).slice(1, 2);
+        window.truncate(3);
+        assert_eq!(window.len(), 2);
+        window.truncate(1);
+        assert_eq!(window.into_parts(), (vec![1, 2], 1, 1));
+    }
+
+    #[test]
+    fn collection() {
+        round_trip::<Window<Vec<_>>, _>([1, 2, 3, 4]);
+        round_trip::<Window<Vec<_>>, _>([Some(1), None]);
+    }
+}
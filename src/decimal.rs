@@ -0,0 +1,631 @@
+//! Fixed-point decimal numbers.
+//!
+//! Arrow stores a decimal as an unscaled two's complement integer of 32, 64,
+//! 128 or 256 bits. The precision (the maximum number of significant digits)
+//! and the scale (the number of digits after the decimal point) belong to the
+//! type, so they are const generic parameters here:
+//!
+//! ```text
+//! Decimal128<7, 2>: unscaled 123_45 -> 123.45
+//! Decimal128<7, -2>: unscaled 123   -> 12300
+//! ```
+//!
+//! The decimal types are [`FixedSize`], so arrays of them use the
+//! fixed-size primitive layout.
+//!
+//! # Examples
+//!
+//! ```
+//! use narrow::{array::Array, collection::Collection, decimal::Decimal128};
+//!
+//! let prices = ["12.50", "0.99"]
+//!     .into_iter()
+//!     .map(|price| price.parse::<Decimal128<9, 2>>())
+//!     .collect::<Result<Array<Decimal128<9, 2>>, _>>()
+//!     .unwrap();
+//! assert_eq!(prices.owned(0).map(Decimal128::unscaled), Some(1250));
+//! assert_eq!(prices.owned(1).unwrap().to_string(), "0.99");
+//! ```
+
+use core::{
+    cmp::Ordering,
+    error, fmt,
+    str::{self, FromStr},
+};
+
+use crate::fixed_size::FixedSize;
+
+/// Error returned when a decimal cannot be constructed.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::decimal::{Decimal32, DecimalError};
+///
+/// assert_eq!(
+///     Decimal32::<3, 0>::try_new(1000),
+///     Err(DecimalError::PrecisionExceeded { precision: 3 })
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DecimalError {
+    /// The value has more significant digits than the precision allows.
+    PrecisionExceeded {
+        /// Precision of the decimal type.
+        precision: u8,
+    },
+    /// The value has non-zero digits below the scale of the decimal type.
+    ScaleExceeded {
+        /// Scale of the decimal type.
+        scale: i8,
+    },
+    /// The string is not a decimal number.
+    Invalid,
+}
+
+impl fmt::Display for DecimalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::PrecisionExceeded { precision } => {
+                write!(f, "decimal value exceeds precision ({precision})")
+            }
+            Self::ScaleExceeded { scale } => {
+                write!(f, "decimal value has digits beyond scale ({scale})")
+            }
+            Self::Invalid => write!(f, "invalid decimal number"),
+        }
+    }
+}
+
+impl error::Error for DecimalError {}
+
+/// Implements a decimal type backed by a native signed integer.
+macro_rules! decimal {
+    ($(#[$attr:meta])* $name:ident, $unscaled:ty, $max_precision:literal) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[repr(transparent)]
+        pub struct $name<const P: u8, const S: i8>($unscaled);
+
+        impl<const P: u8, const S: i8> $name<P, S> {
+            /// The largest supported precision.
+            pub const MAX_PRECISION: u8 = $max_precision;
+
+            /// The precision of this type, checked when the type is used.
+            ///
+            /// # Examples
+            ///
+            /// ```compile_fail
+            #[doc = concat!("use narrow::decimal::", stringify!($name), ";")]
+            ///
+            /// // The scale cannot exceed the precision.
+            #[doc = concat!("let _ = ", stringify!($name), "::<2, 3>::try_new(0);")]
+            /// ```
+            pub const PRECISION: u8 = {
+                assert!(P >= 1 && P <= $max_precision, "decimal precision is out of range");
+                assert!(S <= 0 || S.unsigned_abs() <= P, "decimal scale exceeds its precision");
+                P
+            };
+
+            /// The scale of this type.
+            pub const SCALE: i8 = S;
+
+            #[doc = concat!("Constructs a [`", stringify!($name), "`] from its unscaled value.")]
+            ///
+            /// # Errors
+            ///
+            /// Returns [`DecimalError::PrecisionExceeded`] when `unscaled`
+            /// has more than `P` digits.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use narrow::decimal::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let value = ", stringify!($name), "::<5, 2>::try_new(-123_45).unwrap();")]
+            /// assert_eq!(value.to_string(), "-123.45");
+            #[doc = concat!("assert!(", stringify!($name), "::<5, 2>::try_new(100_000).is_err());")]
+            /// ```
+            pub fn try_new(unscaled: $unscaled) -> Result<Self, DecimalError> {
+                let magnitude = Magnitude::from_u128(u128::from(unscaled.unsigned_abs()));
+                magnitude.check_precision(Self::PRECISION)?;
+                Ok(Self(unscaled))
+            }
+
+            /// Returns the unscaled value of this decimal.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use narrow::decimal::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let value: ", stringify!($name), "<4, 1> = \"12.5\".parse().unwrap();")]
+            /// assert_eq!(value.unscaled(), 125);
+            /// ```
+            #[must_use]
+            pub const fn unscaled(self) -> $unscaled {
+                self.0
+            }
+        }
+
+        impl<const P: u8, const S: i8> FixedSize for $name<P, S> {}
+
+        impl<const P: u8, const S: i8> fmt::Display for $name<P, S> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let magnitude = Magnitude::from_u128(u128::from(self.0.unsigned_abs()));
+                magnitude.fmt(self.0 >= 0, S, f)
+            }
+        }
+
+        impl<const P: u8, const S: i8> FromStr for $name<P, S> {
+            type Err = DecimalError;
+
+            fn from_str(input: &str) -> Result<Self, Self::Err> {
+                let (negative, magnitude) = Magnitude::parse(input, Self::PRECISION, S)?;
+                let unsigned = <$unscaled>::try_from(
+                    magnitude.to_u128().expect("precision is checked"),
+                )
+                .expect("precision is checked");
+                Ok(Self(if negative { unsigned.strict_neg() } else { unsigned }))
+            }
+        }
+    };
+}
+
+decimal!(
+    /// A decimal with precision `P` (at most 9) and scale `S`, stored in 32
+    /// bits.
+    Decimal32,
+    i32,
+    9
+);
+decimal!(
+    /// A decimal with precision `P` (at most 18) and scale `S`, stored in 64
+    /// bits.
+    Decimal64,
+    i64,
+    18
+);
+decimal!(
+    /// A decimal with precision `P` (at most 38) and scale `S`, stored in 128
+    /// bits.
+    Decimal128,
+    i128,
+    38
+);
+
+/// A decimal with precision `P` (at most 76) and scale `S`, stored in 256
+/// bits.
+///
+/// Rust has no 256-bit integer, so the unscaled value is exchanged as
+/// little-endian two's complement bytes, or constructed from an [`i128`].
+///
+/// # Examples
+///
+/// ```
+/// use narrow::decimal::Decimal256;
+///
+/// let value: Decimal256<76, 0> = "-1".parse().unwrap();
+/// assert_eq!(value.to_le_bytes(), [u8::MAX; 32]);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct Decimal256<const P: u8, const S: i8>([u64; 4]);
+
+impl<const P: u8, const S: i8> Decimal256<P, S> {
+    /// The largest supported precision.
+    pub const MAX_PRECISION: u8 = 76;
+
+    /// The precision of this type, checked when the type is used.
+    ///
+    /// # Examples
+    ///
+    /// ```compile_fail
+    /// use narrow::decimal::Decimal256;
+    ///
+    /// // Precision is at most 76.
+    /// let _ = Decimal256::<77, 0>::try_from_i128(0);
+    /// ```
+    pub const PRECISION: u8 = {
+        assert!(P >= 1 && P <= 76, "decimal precision is out of range");
+        assert!(
+            S <= 0 || S.unsigned_abs() <= P,
+            "decimal scale exceeds its precision"
+        );
+        P
+    };
+
+    /// The scale of this type.
+    pub const SCALE: i8 = S;
+
+    /// Constructs a [`Decimal256`] from an unscaled value that fits in an
+    /// [`i128`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DecimalError::PrecisionExceeded`] when `unscaled` has more
+    /// than `P` digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::decimal::Decimal256;
+    ///
+    /// let value = Decimal256::<40, 3>::try_from_i128(-1_500).unwrap();
+    /// assert_eq!(value.to_string(), "-1.500");
+    /// ```
+    pub fn try_from_i128(unscaled: i128) -> Result<Self, DecimalError> {
+        let magnitude = Magnitude::from_u128(unscaled.unsigned_abs());
+        magnitude.check_precision(Self::PRECISION)?;
+        Ok(Self::from_parts(unscaled < 0, magnitude))
+    }
+
+    /// Constructs a [`Decimal256`] from the little-endian two's complement
+    /// bytes of its unscaled value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DecimalError::PrecisionExceeded`] when the unscaled value has
+    /// more than `P` digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::decimal::Decimal256;
+    ///
+    /// let mut bytes = [0; 32];
+    /// bytes[0] = 42;
+    /// let value = Decimal256::<2, 1>::try_from_le_bytes(bytes).unwrap();
+    /// assert_eq!(value.to_string(), "4.2");
+    /// ```
+    pub fn try_from_le_bytes(bytes: [u8; 32]) -> Result<Self, DecimalError> {
+        let mut limbs = [0; 4];
+        for (limb, chunk) in limbs.iter_mut().zip(bytes.as_chunks::<8>().0) {
+            *limb = u64::from_le_bytes(*chunk);
+        }
+        let value = Self(limbs);
+        let (_, magnitude) = value.to_parts();
+        magnitude.check_precision(Self::PRECISION)?;
+        Ok(value)
+    }
+
+    /// Returns the little-endian two's complement bytes of the unscaled value
+    /// of this decimal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::decimal::Decimal256;
+    ///
+    /// let value = Decimal256::<3, 0>::try_from_i128(258).unwrap();
+    /// assert_eq!(value.to_le_bytes()[..2], [2, 1]);
+    /// ```
+    #[must_use]
+    pub fn to_le_bytes(self) -> [u8; 32] {
+        let mut bytes = [0; 32];
+        for (chunk, limb) in bytes.as_chunks_mut::<8>().0.iter_mut().zip(self.0) {
+            *chunk = limb.to_le_bytes();
+        }
+        bytes
+    }
+
+    /// Returns whether this decimal is negative.
+    fn is_negative(self) -> bool {
+        self.0[3].leading_zeros() == 0
+    }
+
+    /// Constructs a decimal from the sign and magnitude of its unscaled value.
+    fn from_parts(negative: bool, magnitude: Magnitude) -> Self {
+        Self(
+            if negative {
+                magnitude.negate()
+            } else {
+                magnitude
+            }
+            .0,
+        )
+    }
+
+    /// Returns the sign and magnitude of the unscaled value.
+    fn to_parts(self) -> (bool, Magnitude) {
+        let negative = self.is_negative();
+        let limbs = Magnitude(self.0);
+        (negative, if negative { limbs.negate() } else { limbs })
+    }
+}
+
+impl<const P: u8, const S: i8> FixedSize for Decimal256<P, S> {}
+
+impl<const P: u8, const S: i8> PartialOrd for Decimal256<P, S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const P: u8, const S: i8> Ord for Decimal256<P, S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .is_negative()
+            .cmp(&self.is_negative())
+            .then_with(|| self.0.iter().rev().cmp(other.0.iter().rev()))
+    }
+}
+
+impl<const P: u8, const S: i8> fmt::Display for Decimal256<P, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (negative, magnitude) = self.to_parts();
+        magnitude.fmt(!negative, S, f)
+    }
+}
+
+impl<const P: u8, const S: i8> FromStr for Decimal256<P, S> {
+    type Err = DecimalError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (negative, magnitude) = Magnitude::parse(input, Self::PRECISION, S)?;
+        Ok(Self::from_parts(negative, magnitude))
+    }
+}
+
+/// The magnitude of an unscaled decimal value, as little-endian 64-bit limbs.
+///
+/// Every decimal width fits, so parsing, formatting and precision checks are
+/// implemented once for all of them.
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Magnitude([u64; 4]);
+
+impl Magnitude {
+    /// Constructs a magnitude from a [`u128`].
+    fn from_u128(value: u128) -> Self {
+        let (low, high) = split(value);
+        Self([low, high, 0, 0])
+    }
+
+    /// Returns this magnitude as a [`u128`], if it fits.
+    fn to_u128(self) -> Option<u128> {
+        let [low, high, rest @ ..] = self.0;
+        (rest == [0; 2]).then(|| join(low, high))
+    }
+
+    /// Returns whether this magnitude is zero.
+    fn is_zero(self) -> bool {
+        self.0 == [0; 4]
+    }
+
+    /// Returns `self * factor + addend`, or `None` on overflow.
+    fn checked_mul_add(self, factor: u64, addend: u64) -> Option<Self> {
+        let mut carry = addend;
+        let mut limbs = [0; 4];
+        for (limb, value) in limbs.iter_mut().zip(self.0) {
+            let product = u128::from(value)
+                .strict_mul(u128::from(factor))
+                .strict_add(u128::from(carry));
+            (*limb, carry) = split(product);
+        }
+        (carry == 0).then_some(Self(limbs))
+    }
+
+    /// Returns the quotient and remainder of the division by `divisor`.
+    fn div_rem(self, divisor: u64) -> (Self, u64) {
+        let mut remainder = 0;
+        let mut limbs = [0; 4];
+        for (limb, value) in limbs.iter_mut().zip(self.0).rev() {
+            let dividend = join(value, remainder);
+            // The remainder is less than the divisor, so the quotient of
+            // each step fits in a limb.
+            (*limb, _) = split(dividend.strict_div(u128::from(divisor)));
+            (remainder, _) = split(dividend.strict_rem(u128::from(divisor)));
+        }
+        (Self(limbs), remainder)
+    }
+
+    /// Returns the two's complement negation of these limbs.
+    fn negate(self) -> Self {
+        let mut carry = true;
+        Self(self.0.map(|limb| {
+            let (sum, overflow) = (!limb).overflowing_add(u64::from(carry));
+            carry = overflow;
+            sum
+        }))
+    }
+
+    /// Checks that this magnitude has at most `precision` digits.
+    fn check_precision(self, precision: u8) -> Result<(), DecimalError> {
+        let limit = (0..precision)
+            .try_fold(Self::from_u128(1), |limit, _| limit.checked_mul_add(10, 0))
+            .expect("10^76 fits in 256 bits");
+        if self.cmp(&limit).is_lt() {
+            Ok(())
+        } else {
+            Err(DecimalError::PrecisionExceeded { precision })
+        }
+    }
+
+    /// Parses the sign and magnitude of the unscaled value of a decimal
+    /// number with the given precision and scale.
+    ///
+    /// Digits below the scale are accepted only when they are zero, so
+    /// parsing never rounds.
+    fn parse(input: &str, precision: u8, scale: i8) -> Result<(bool, Self), DecimalError> {
+        let (negative, unsigned) = match input.strip_prefix('-') {
+            Some(unsigned) => (true, unsigned),
+            None => (false, input.strip_prefix('+').unwrap_or(input)),
+        };
+        let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+        if (integer.is_empty() && fraction.is_empty())
+            || !integer
+                .bytes()
+                .chain(fraction.bytes())
+                .all(|byte| byte.is_ascii_digit())
+        {
+            return Err(DecimalError::Invalid);
+        }
+
+        let fraction_digits = usize::from(scale.max(0).unsigned_abs());
+        let (kept, dropped) = fraction.split_at(fraction.len().min(fraction_digits));
+        if dropped.bytes().any(|byte| byte != b'0') {
+            return Err(DecimalError::ScaleExceeded { scale });
+        }
+        let padding = fraction_digits.strict_sub(kept.len());
+        let mut magnitude = integer
+            .bytes()
+            .chain(kept.bytes())
+            .map(|byte| u64::from(byte.strict_sub(b'0')))
+            .chain((0..padding).map(|_| 0))
+            .try_fold(Self([0; 4]), |magnitude, digit| {
+                magnitude.checked_mul_add(10, digit)
+            })
+            .ok_or(DecimalError::PrecisionExceeded { precision })?;
+        for _ in 0..scale.min(0).unsigned_abs() {
+            let (quotient, remainder) = magnitude.div_rem(10);
+            if remainder != 0 {
+                return Err(DecimalError::ScaleExceeded { scale });
+            }
+            magnitude = quotient;
+        }
+
+        magnitude.check_precision(precision)?;
+        Ok((negative && !magnitude.is_zero(), magnitude))
+    }
+
+    /// Formats the decimal number with this magnitude and the given sign and
+    /// scale.
+    fn fmt(self, non_negative: bool, scale: i8, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // At most 77 digits, a decimal point and 128 zeros for the most
+        // negative scale.
+        let mut buffer = [b'0'; 256];
+        let fraction_digits = usize::from(scale.max(0).unsigned_abs());
+        let mut start = buffer
+            .len()
+            .strict_sub(usize::from(scale.min(0).unsigned_abs()));
+        let mut rest = self;
+        let mut written = 0;
+        while !rest.is_zero() || written <= fraction_digits {
+            if fraction_digits != 0 && written == fraction_digits {
+                start = start.strict_sub(1);
+                buffer[start] = b'.';
+            }
+            let (quotient, digit) = rest.div_rem(10);
+            start = start.strict_sub(1);
+            buffer[start] = b'0'.strict_add(u8::try_from(digit).expect("digit is less than ten"));
+            rest = quotient;
+            written = written.strict_add(1);
+        }
+        let digits = str::from_utf8(&buffer[start..]).expect("decimal digits are ASCII");
+        f.pad_integral(non_negative, "", digits)
+    }
+}
+
+impl PartialOrd for Magnitude {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Magnitude {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.iter().rev().cmp(other.0.iter().rev())
+    }
+}
+
+/// Splits a [`u128`] into its low and high 64 bits.
+fn split(value: u128) -> (u64, u64) {
+    let low = u64::try_from(value & u128::from(u64::MAX)).expect("masked to 64 bits");
+    let high = u64::try_from(value.strict_shr(64)).expect("shifted to 64 bits");
+    (low, high)
+}
+
+/// Joins the low and high 64 bits of a [`u128`].
+fn join(low: u64, high: u64) -> u128 {
+    u128::from(high).strict_shl(64) | u128::from(low)
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::{format, string::ToString};
+
+    use super::*;
+
+    #[test]
+    fn checked_construction() {
+        assert_eq!(
+            Decimal32::<9, 2>::try_new(999_999_999).map(Decimal32::unscaled),
+            Ok(999_999_999)
+        );
+        assert_eq!(
+            Decimal32::<9, 2>::try_new(-1_000_000_000),
+            Err(DecimalError::PrecisionExceeded { precision: 9 })
+        );
+        assert!(Decimal64::<18, 0>::try_new(i64::MAX).is_err());
+        assert!(Decimal128::<38, 0>::try_new(i128::MIN).is_err());
+        assert!(Decimal256::<38, 0>::try_from_i128(10_i128.pow(38)).is_err());
+        assert!(Decimal256::<39, 0>::try_from_i128(10_i128.pow(38)).is_ok());
+        assert!(Decimal256::<76, 0>::try_from_le_bytes([u8::MAX; 32]).is_ok());
+        let mut too_large = [u8::MAX; 32];
+        too_large[31] = 0x7f;
+        assert_eq!(
+            Decimal256::<76, 0>::try_from_le_bytes(too_large),
+            Err(DecimalError::PrecisionExceeded { precision: 76 })
+        );
+    }
+
+    #[test]
+    fn display() {
+        let cents = |unscaled| Decimal64::<10, 2>::try_new(unscaled).expect("valid decimal");
+        assert_eq!(cents(12_345).to_string(), "123.45");
+        assert_eq!(cents(-5).to_string(), "-0.05");
+        assert_eq!(cents(0).to_string(), "0.00");
+        assert_eq!(format!("{:>8}", cents(-100)), "   -1.00");
+        let hundreds = Decimal128::<5, -2>::try_new(-123).expect("valid decimal");
+        assert_eq!(hundreds.to_string(), "-12300");
+        let nines = format!("-999999.{}", "9".repeat(70));
+        let wide = nines.parse::<Decimal256<76, 70>>().expect("valid decimal");
+        assert_eq!(wide.to_string(), nines);
+    }
+
+    #[test]
+    fn parse() {
+        let parse = str::parse::<Decimal32<6, 2>>;
+        assert_eq!(parse("1234.5").map(Decimal32::unscaled), Ok(123_450));
+        assert_eq!(parse("+.5").map(Decimal32::unscaled), Ok(50));
+        assert_eq!(parse("-7.").map(Decimal32::unscaled), Ok(-700));
+        assert_eq!(parse("-0.000").map(Decimal32::unscaled), Ok(0));
+        assert_eq!(
+            parse("0.125"),
+            Err(DecimalError::ScaleExceeded { scale: 2 })
+        );
+        assert_eq!(
+            parse("10000"),
+            Err(DecimalError::PrecisionExceeded { precision: 6 })
+        );
+        for invalid in ["", "-", ".", "1.2.3", "1e3", "--1", " 1"] {
+            assert_eq!(parse(invalid), Err(DecimalError::Invalid), "{invalid:?}");
+        }
+        assert_eq!(
+            "12300".parse::<Decimal64<3, -2>>().map(Decimal64::unscaled),
+            Ok(123)
+        );
+        assert_eq!(
+            "12345".parse::<Decimal64<3, -2>>(),
+            Err(DecimalError::ScaleExceeded { scale: -2 })
+        );
+        assert_eq!(
+            "1".repeat(80).parse::<Decimal256<76, 0>>(),
+            Err(DecimalError::PrecisionExceeded { precision: 76 })
+        );
+    }
+
+    #[test]
+    fn ordering() {
+        let values = ["-2", "-1.5", "0", "0.25", "3"]
+            .map(|value| value.parse::<Decimal256<10, 2>>().expect("valid decimal"));
+        assert!(values.is_sorted());
+        assert_eq!(
+            values.map(|value| value.to_string()),
+            ["-2.00", "-1.50", "0.00", "0.25", "3.00"]
+        );
+    }
+}
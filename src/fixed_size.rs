@@ -8,6 +8,13 @@ use core::{mem, ops::Deref};
 /// contiguous, fixed-width value buffers. Sealing prevents unsupported
 /// representations from entering layouts that rely on this guarantee.
 ///
+/// Every fixed-size type is plain data: it has no padding bytes, and any
+/// initialized bytes of its size are a valid value. Buffers of fixed-size
+/// values can therefore be reinterpreted as bytes, and bytes with the right
+/// alignment as fixed-size values, without undefined behavior. Value
+/// invariants that are not needed for soundness, like the precision of a
+/// decimal, are not guaranteed for reinterpreted bytes.
+///
 /// # Examples
 ///
 /// ```
@@ -15,6 +22,19 @@ use core::{mem, ops::Deref};
 ///
 /// assert_eq!(u32::SIZE, 4);
 /// ```
+///
+/// Types outside this crate cannot implement it, so types with padding are
+/// rejected:
+///
+/// ```compile_fail
+/// use narrow::fixed_size::FixedSize;
+///
+/// #[derive(Clone, Copy)]
+/// #[repr(C)]
+/// struct Padded(u8, u32);
+///
+/// impl FixedSize for Padded {}
+/// ```
 pub trait FixedSize: Copy + sealed::Sealed + 'static {
     /// The size of this type in bytes.
     const SIZE: usize = mem::size_of::<Self>();
@@ -37,6 +57,31 @@ impl FixedSize for isize {}
 impl FixedSize for f32 {}
 impl FixedSize for f64 {}
 
+/// Half-precision floating point types of the [`half`] crate.
+///
+/// Rust has no stable 16-bit float, so these are re-exported to name the item
+/// types of half-precision arrays, such as embeddings produced in `f16`.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::fixed_size::{FixedSize, bf16, f16};
+///
+/// assert_eq!(f16::SIZE, 2);
+/// assert_eq!(bf16::from_f32(1.5).to_f32(), 1.5);
+/// ```
+#[cfg(feature = "half")]
+#[cfg_attr(docsrs, doc(cfg(feature = "half")))]
+pub use half::{bf16, f16};
+
+#[cfg(feature = "half")]
+#[cfg_attr(docsrs, doc(cfg(feature = "half")))]
+impl FixedSize for f16 {}
+
+#[cfg(feature = "half")]
+#[cfg_attr(docsrs, doc(cfg(feature = "half")))]
+impl FixedSize for bf16 {}
+
 /// An array with `N` `FixedSize` items per item.
 ///
 /// Just using [T; N] causes overlapping impls.
@@ -58,6 +103,7 @@ impl FixedSize for f64 {}
 /// assert_eq!(*value, [1, 2]);
 /// ```
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
 pub struct FixedSizeArray<T: FixedSize, const N: usize>([T; N]);
 
 impl<T: FixedSize, const N: usize> Default for FixedSizeArray<T, N>
@@ -85,7 +131,64 @@ impl<T: FixedSize, const N: usize> Deref for FixedSizeArray<T, N> {
 
 impl<T: FixedSize, const N: usize> FixedSize for FixedSizeArray<T, N> {}
 
+/// Private module for [`sealed::Sealed`] trait.
+///
+/// Every implementation is listed here, so the types that are reinterpreted
+/// as bytes can be audited in one place.
 mod sealed {
+    use crate::{
+        decimal::{Decimal32, Decimal64, Decimal128, Decimal256},
+        temporal::{
+            Date32, Date64, Duration, IntervalDayTime, IntervalMonthDayNano, IntervalYearMonth,
+            Time32, Time32Unit, Time64, Time64Unit, TimeUnit, TimeZone, Timestamp,
+        },
+    };
+
+    /// Used to seal [`super::FixedSize`].
     pub trait Sealed {}
-    impl<T: super::FixedSize> Sealed for T {}
+
+    impl Sealed for u8 {}
+    impl Sealed for u16 {}
+    impl Sealed for u32 {}
+    impl Sealed for u64 {}
+    impl Sealed for u128 {}
+    impl Sealed for usize {}
+
+    impl Sealed for i8 {}
+    impl Sealed for i16 {}
+    impl Sealed for i32 {}
+    impl Sealed for i64 {}
+    impl Sealed for i128 {}
+    impl Sealed for isize {}
+
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+
+    #[cfg(feature = "half")]
+    impl Sealed for super::f16 {}
+    #[cfg(feature = "half")]
+    impl Sealed for super::bf16 {}
+
+    /// `#[repr(transparent)]` over `[T; N]`, which has no padding between
+    /// items.
+    impl<T: super::FixedSize, const N: usize> Sealed for super::FixedSizeArray<T, N> {}
+
+    /// `#[repr(transparent)]` over their unscaled integers.
+    impl<const P: u8, const S: i8> Sealed for Decimal32<P, S> {}
+    impl<const P: u8, const S: i8> Sealed for Decimal64<P, S> {}
+    impl<const P: u8, const S: i8> Sealed for Decimal128<P, S> {}
+    impl<const P: u8, const S: i8> Sealed for Decimal256<P, S> {}
+
+    /// `#[repr(transparent)]` over their integers.
+    impl Sealed for Date32 {}
+    impl Sealed for Date64 {}
+    impl<Unit: Time32Unit> Sealed for Time32<Unit> {}
+    impl<Unit: Time64Unit> Sealed for Time64<Unit> {}
+    impl<Unit: TimeUnit, Tz: TimeZone> Sealed for Timestamp<Unit, Tz> {}
+    impl<Unit: TimeUnit> Sealed for Duration<Unit> {}
+    impl Sealed for IntervalYearMonth {}
+
+    /// `#[repr(C)]` with fields that are naturally aligned without padding.
+    impl Sealed for IntervalDayTime {}
+    impl Sealed for IntervalMonthDayNano {}
 }
@@ -0,0 +1,654 @@
+//! Variable-size binary items.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::{
+    borrow::Borrow,
+    fmt::{self, Debug},
+    iter::{self, Map, RepeatN, Zip},
+    marker::PhantomData,
+    ops::{Deref, Range},
+};
+
+use crate::{
+    buffer::{Buffer, BufferRef, VecBuffer},
+    collection::{
+        AllocError, ChildRef, Collection, CollectionAllocIn, CollectionRealloc, owned::IntoOwned,
+        view::AsView, window::Window,
+    },
+    layout::MemoryLayout,
+    length::Length,
+    nullability::{NonNullable, Nullability},
+    offset::{Offset, OffsetIntoIter, Offsets, OffsetsError},
+};
+
+/// An opaque sequence of bytes, stored with offsets of type `OffsetItem`.
+///
+/// `Vec<u8>` is a list of `u8` items, which Arrow stores as a list with a
+/// `u8` child array. Binary data has the same offsets and data buffers, but
+/// it is a distinct Arrow type without children. Wrapping the bytes selects
+/// the [`Binary`] layout, and `OffsetItem` selects the standard (`i32`) or
+/// large (`i64`) format:
+///
+/// ```text
+/// Vec<u8>       VariableSizeList<u8>
+/// Bytes         Binary
+/// Bytes<i64>    LargeBinary
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{array::Array, collection::Collection, layout::binary::Bytes};
+///
+/// let values = [Bytes::from(b"ab".as_slice()), Bytes::new(vec![])]
+///     .into_iter()
+///     .collect::<Array<Bytes>>();
+/// assert_eq!(values.view(0), Some(b"ab".as_slice()));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Bytes<OffsetItem: Offset = i32>(Vec<u8>, PhantomData<OffsetItem>);
+
+impl<OffsetItem: Offset> Bytes<OffsetItem> {
+    /// Wraps `bytes` for storage in a [`Binary`] layout.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::layout::binary::Bytes;
+    ///
+    /// let bytes = Bytes::<i64>::new(vec![1, 2]);
+    /// assert_eq!(*bytes, [1, 2]);
+    /// ```
+    #[must_use]
+    pub const fn new(bytes: Vec<u8>) -> Self {
+        Self(bytes, PhantomData)
+    }
+
+    /// Returns the wrapped bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::layout::binary::Bytes;
+    ///
+    /// let bytes: Bytes = Bytes::from(b"a".as_slice());
+    /// assert_eq!(bytes.into_inner(), b"a");
+    /// ```
+    #[must_use]
+    pub fn into_inner(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+impl<OffsetItem: Offset> Deref for Bytes<OffsetItem> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<OffsetItem: Offset> From<Vec<u8>> for Bytes<OffsetItem> {
+    fn from(value: Vec<u8>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<OffsetItem: Offset> From<&[u8]> for Bytes<OffsetItem> {
+    fn from(value: &[u8]) -> Self {
+        Self::new(value.to_vec())
+    }
+}
+
+impl<OffsetItem: Offset> From<Bytes<OffsetItem>> for Vec<u8> {
+    fn from(value: Bytes<OffsetItem>) -> Self {
+        value.0
+    }
+}
+
+impl<OffsetItem: Offset> IntoOwned<Bytes<OffsetItem>> for &[u8] {
+    fn into_owned(self) -> Bytes<OffsetItem> {
+        Bytes::new(self.to_vec())
+    }
+}
+
+impl<'collection, OffsetItem: Offset> AsView<'collection> for Bytes<OffsetItem> {
+    type View = &'collection [u8];
+
+    fn as_view(&'collection self) -> Self::View {
+        &self.0
+    }
+}
+
+/// Offsets into binary data.
+///
+/// These are the buffers of a [`Binary`] layout without outer nullability.
+/// Views are slices of the data buffer:
+///
+/// ```text
+/// offsets: [0, 2, 2, 5]
+/// data:    b"hiabc"
+/// items:   [b"hi", b"", b"abc"]
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{collection::Collection, layout::binary::{BinaryValues, Bytes}};
+///
+/// let values = [Bytes::from(b"hi".as_slice())].into_iter().collect::<BinaryValues>();
+/// assert_eq!(values.view(0), Some(b"hi".as_slice()));
+/// ```
+pub struct BinaryValues<OffsetItem: Offset = i32, Storage: Buffer = VecBuffer>(
+    Offsets<Storage::For<u8>, OffsetItem, Storage, Vec<u8>>,
+);
+
+impl<OffsetItem: Offset, Storage: Buffer> BinaryValues<OffsetItem, Storage> {
+    /// Constructs [`BinaryValues`] from a `data` buffer and its `offsets`
+    /// buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`OffsetsError`] when the offsets are invalid (see
+    /// [`Offsets::try_from_parts`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{collection::Collection, layout::binary::BinaryValues};
+    ///
+    /// let values = BinaryValues::<i32>::try_from_parts(b"hiabc".to_vec(), vec![0, 2, 5]).unwrap();
+    /// assert_eq!(values.view(1), Some(b"abc".as_slice()));
+    /// ```
+    pub fn try_from_parts(
+        data: Storage::For<u8>,
+        offsets: Storage::For<OffsetItem>,
+    ) -> Result<Self, OffsetsError> {
+        Offsets::try_from_parts(data, offsets).map(Self)
+    }
+
+    /// Returns the data buffer and offsets buffer of these [`BinaryValues`].
+    ///
+    /// This is the inverse of [`BinaryValues::try_from_parts`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::layout::binary::BinaryValues;
+    ///
+    /// let values = BinaryValues::<i32>::try_from_parts(b"hi".to_vec(), vec![0, 2]).unwrap();
+    /// assert_eq!(values.into_parts(), (b"hi".to_vec(), vec![0, 2]));
+    /// ```
+    #[must_use]
+    pub fn into_parts(self) -> (Storage::For<u8>, Storage::For<OffsetItem>) {
+        self.0.into_parts()
+    }
+}
+
+impl<OffsetItem: Offset, Storage: Buffer> BufferRef for BinaryValues<OffsetItem, Storage> {
+    type Buffer = Offsets<Storage::For<u8>, OffsetItem, Storage, Vec<u8>>;
+
+    fn buffer_ref(&self) -> &Self::Buffer {
+        &self.0
+    }
+}
+
+impl<OffsetItem: Offset, Storage: Buffer> Debug for BinaryValues<OffsetItem, Storage>
+where
+    Offsets<Storage::For<u8>, OffsetItem, Storage, Vec<u8>>: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("BinaryValues").field(&self.0).finish()
+    }
+}
+
+impl<OffsetItem: Offset, Storage: Buffer> Clone for BinaryValues<OffsetItem, Storage>
+where
+    Offsets<Storage::For<u8>, OffsetItem, Storage, Vec<u8>>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<OffsetItem: Offset, Storage: Buffer> Default for BinaryValues<OffsetItem, Storage>
+where
+    Offsets<Storage::For<u8>, OffsetItem, Storage, Vec<u8>>: Default,
+{
+    fn default() -> Self {
+        Self(Offsets::default())
+    }
+}
+
+impl<OffsetItem: Offset, Storage: Buffer> Extend<Bytes<OffsetItem>>
+    for BinaryValues<OffsetItem, Storage>
+where
+    Offsets<Storage::For<u8>, OffsetItem, Storage, Vec<u8>>: Extend<Vec<u8>>,
+{
+    fn extend<I: IntoIterator<Item = Bytes<OffsetItem>>>(&mut self, iter: I) {
+        self.0.extend(iter.into_iter().map(Bytes::into_inner));
+    }
+}
+
+impl<OffsetItem: Offset, Storage: Buffer> FromIterator<Bytes<OffsetItem>>
+    for BinaryValues<OffsetItem, Storage>
+where
+    Offsets<Storage::For<u8>, OffsetItem, Storage, Vec<u8>>: FromIterator<Vec<u8>>,
+{
+    fn from_iter<I: IntoIterator<Item = Bytes<OffsetItem>>>(iter: I) -> Self {
+        Self(iter.into_iter().map(Bytes::into_inner).collect())
+    }
+}
+
+impl<OffsetItem: Offset, Storage: Buffer> Length for BinaryValues<OffsetItem, Storage> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<OffsetItem: Offset, Storage: Buffer> Collection for BinaryValues<OffsetItem, Storage> {
+    type View<'collection>
+        = &'collection [u8]
+    where
+        Self: 'collection;
+
+    type Owned = Bytes<OffsetItem>;
+
+    fn view(&self, index: usize) -> Option<Self::View<'_>> {
+        let offsets = self.0.buffer_ref().borrow();
+        let start = offsets.get(index)?.as_usize();
+        let end = offsets.get(index.checked_add(1)?)?.as_usize();
+        self.0.child_ref().borrow().get(start..end)
+    }
+
+    type Iter<'collection>
+        = Map<
+        Zip<Range<usize>, RepeatN<&'collection Self>>,
+        fn((usize, &'collection Self)) -> Self::View<'collection>,
+    >
+    where
+        Self: 'collection;
+
+    fn iter_views(&self) -> Self::Iter<'_> {
+        let len = self.len();
+        (0..len)
+            .zip(iter::repeat_n(self, len))
+            .map(|(index, values)| values.view(index).expect("index in range"))
+    }
+
+    type IntoIter = Map<
+        OffsetIntoIter<Storage::For<u8>, OffsetItem, Storage, Vec<u8>>,
+        fn(Vec<u8>) -> Bytes<OffsetItem>,
+    >;
+
+    fn into_iter_owned(self) -> Self::IntoIter {
+        self.0.into_iter_owned().map(Bytes::new)
+    }
+}
+
+impl<OffsetItem: Offset, Storage: Buffer> CollectionAllocIn for BinaryValues<OffsetItem, Storage>
+where
+    Offsets<Storage::For<u8>, OffsetItem, Storage, Vec<u8>>: CollectionRealloc<Owned = Vec<u8>>,
+{
+    type Alloc =
+        <Offsets<Storage::For<u8>, OffsetItem, Storage, Vec<u8>> as CollectionAllocIn>::Alloc;
+
+    fn with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Self {
+        Self(Offsets::with_capacity_in(capacity, alloc))
+    }
+
+    fn from_iter_in<I: IntoIterator<Item = Self::Owned>>(iter: I, alloc: Self::Alloc) -> Self {
+        Self(Offsets::from_iter_in(
+            iter.into_iter().map(Bytes::into_inner),
+            alloc,
+        ))
+    }
+
+    fn try_with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Result<Self, AllocError> {
+        Offsets::try_with_capacity_in(capacity, alloc).map(Self)
+    }
+
+    fn try_from_iter_in<I: IntoIterator<Item = Self::Owned>>(
+        iter: I,
+        alloc: Self::Alloc,
+    ) -> Result<Self, AllocError> {
+        Offsets::try_from_iter_in(iter.into_iter().map(Bytes::into_inner), alloc).map(Self)
+    }
+}
+
+impl<OffsetItem: Offset, Storage: Buffer> CollectionRealloc for BinaryValues<OffsetItem, Storage>
+where
+    Offsets<Storage::For<u8>, OffsetItem, Storage, Vec<u8>>: CollectionRealloc<Owned = Vec<u8>>,
+{
+    fn allocator(&self) -> Self::Alloc {
+        self.0.allocator()
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+        self.0.try_reserve(additional)
+    }
+
+    fn try_extend<I: IntoIterator<Item = Self::Owned>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), AllocError> {
+        self.0.try_extend(iter.into_iter().map(Bytes::into_inner))
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.0.truncate(len);
+    }
+}
+
+/// A collection of variable-size binary items.
+///
+/// <https://arrow.apache.org/docs/format/Columnar.html#variable-size-binary-layout>
+///
+/// This is the Arrow `Binary` layout: [`BinaryValues`] with outer nullability.
+/// `OffsetItem` selects the standard (`i32`) or large (`i64`, see
+/// [`LargeBinary`]) Arrow binary format.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{
+///     collection::Collection,
+///     layout::binary::{Binary, Bytes},
+///     nullability::Nullable,
+/// };
+///
+/// let values = [Some(Bytes::from(b"a".as_slice())), None]
+///     .into_iter()
+///     .collect::<Binary<Nullable>>();
+/// assert_eq!(values.view(0), Some(Some(b"a".as_slice())));
+/// assert_eq!(values.view(1), Some(None));
+/// ```
+pub struct Binary<
+    Nulls: Nullability = NonNullable,
+    OffsetItem: Offset = i32,
+    Storage: Buffer = VecBuffer,
+>(Window<Nulls::Collection<BinaryValues<OffsetItem, Storage>, Storage>>);
+
+/// A collection of variable-size binary items with 64-bit offsets.
+///
+/// This is the Arrow `LargeBinary` layout, for binary data that can exceed
+/// `i32::MAX` bytes.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{collection::Collection, layout::binary::{Bytes, LargeBinary}};
+///
+/// let values = [Bytes::from(b"a".as_slice())].into_iter().collect::<LargeBinary>();
+/// assert_eq!(values.view(0), Some(b"a".as_slice()));
+/// ```
+pub type LargeBinary<Nulls = NonNullable, Storage = VecBuffer> = Binary<Nulls, i64, Storage>;
+
+impl<Nulls: Nullability, OffsetItem: Offset, Storage: Buffer> MemoryLayout
+    for Binary<Nulls, OffsetItem, Storage>
+{
+    fn slice(self, offset: usize, len: usize) -> Self {
+        Self(self.0.slice(offset, len))
+    }
+
+    fn offset(&self) -> usize {
+        self.0.offset()
+    }
+}
+
+impl<Nulls: Nullability, OffsetItem: Offset, Storage: Buffer> Binary<Nulls, OffsetItem, Storage> {
+    /// Constructs a [`Binary`] from its backing collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{
+    ///     collection::Collection,
+    ///     layout::binary::{Binary, BinaryValues},
+    ///     nullability::NonNullable,
+    /// };
+    ///
+    /// let values = BinaryValues::<i32>::try_from_parts(b"hi".to_vec(), vec![0, 2]).unwrap();
+    /// let values = Binary::<NonNullable>::from_buffer(values);
+    /// assert_eq!(values.view(0), Some(b"hi".as_slice()));
+    /// ```
+    #[must_use]
+    pub fn from_buffer(
+        buffer: Nulls::Collection<BinaryValues<OffsetItem, Storage>, Storage>,
+    ) -> Self {
+        Self(Window::new(buffer))
+    }
+
+    /// Returns the backing collection of this [`Binary`].
+    ///
+    /// This is the inverse of [`Binary::from_buffer`]. The backing collection
+    /// of a slice still contains the items outside of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{layout::binary::{Binary, Bytes}, length::Length};
+    ///
+    /// let values = [Bytes::new(vec![1])].into_iter().collect::<Binary>();
+    /// assert_eq!(values.into_buffer().len(), 1);
+    /// ```
+    #[must_use]
+    pub fn into_buffer(self) -> Nulls::Collection<BinaryValues<OffsetItem, Storage>, Storage> {
+        self.0.into_parts().0
+    }
+}
+
+impl<Nulls: Nullability, OffsetItem: Offset, Storage: Buffer> BufferRef
+    for Binary<Nulls, OffsetItem, Storage>
+{
+    type Buffer = Nulls::Collection<BinaryValues<OffsetItem, Storage>, Storage>;
+
+    fn buffer_ref(&self) -> &Self::Buffer {
+        self.0.buffer_ref()
+    }
+}
+
+impl<Nulls: Nullability, OffsetItem: Offset, Storage: Buffer> Debug
+    for Binary<Nulls, OffsetItem, Storage>
+where
+    Nulls::Collection<BinaryValues<OffsetItem, Storage>, Storage>: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Binary").field(&self.0).finish()
+    }
+}
+
+impl<Nulls: Nullability, OffsetItem: Offset, Storage: Buffer> Clone
+    for Binary<Nulls, OffsetItem, Storage>
+where
+    Nulls::Collection<BinaryValues<OffsetItem, Storage>, Storage>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<Nulls: Nullability, OffsetItem: Offset, Storage: Buffer> Default
+    for Binary<Nulls, OffsetItem, Storage>
+where
+    Nulls::Collection<BinaryValues<OffsetItem, Storage>, Storage>: Default,
+{
+    fn default() -> Self {
+        Self(Window::default())
+    }
+}
+
+impl<Nulls: Nullability, OffsetItem: Offset, Storage: Buffer> Extend<Nulls::Item<Bytes<OffsetItem>>>
+    for Binary<Nulls, OffsetItem, Storage>
+where
+    Window<Nulls::Collection<BinaryValues<OffsetItem, Storage>, Storage>>:
+        Extend<Nulls::Item<Bytes<OffsetItem>>>,
+{
+    fn extend<I: IntoIterator<Item = Nulls::Item<Bytes<OffsetItem>>>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl<Nulls: Nullability, OffsetItem: Offset, Storage: Buffer>
+    FromIterator<Nulls::Item<Bytes<OffsetItem>>> for Binary<Nulls, OffsetItem, Storage>
+where
+    Nulls::Collection<BinaryValues<OffsetItem, Storage>, Storage>:
+        FromIterator<Nulls::Item<Bytes<OffsetItem>>>,
+{
+    fn from_iter<I: IntoIterator<Item = Nulls::Item<Bytes<OffsetItem>>>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<Nulls: Nullability, OffsetItem: Offset, Storage: Buffer> Length
+    for Binary<Nulls, OffsetItem, Storage>
+{
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<Nulls: Nullability, OffsetItem: Offset, Storage: Buffer> Collection
+    for Binary<Nulls, OffsetItem, Storage>
+{
+    type View<'collection>
+        = <Nulls::Collection<BinaryValues<OffsetItem, Storage>, Storage> as Collection>::View<
+        'collection,
+    >
+    where
+        Self: 'collection;
+
+    type Owned = Nulls::Item<Bytes<OffsetItem>>;
+
+    fn view(&self, index: usize) -> Option<Self::View<'_>> {
+        self.0.view(index)
+    }
+
+    type Iter<'collection>
+        =
+        <Window<Nulls::Collection<BinaryValues<OffsetItem, Storage>, Storage>> as Collection>::Iter<
+            'collection,
+        >
+    where
+        Self: 'collection;
+
+    fn iter_views(&self) -> Self::Iter<'_> {
+        self.0.iter_views()
+    }
+
+    type IntoIter =
+        <Window<Nulls::Collection<BinaryValues<OffsetItem, Storage>, Storage>> as Collection>::IntoIter;
+
+    fn into_iter_owned(self) -> Self::IntoIter {
+        self.0.into_iter_owned()
+    }
+}
+
+impl<Nulls: Nullability, OffsetItem: Offset, Storage: Buffer> CollectionAllocIn
+    for Binary<Nulls, OffsetItem, Storage>
+where
+    Nulls::Collection<BinaryValues<OffsetItem, Storage>, Storage>: CollectionAllocIn,
+{
+    type Alloc =
+        <Nulls::Collection<BinaryValues<OffsetItem, Storage>, Storage> as CollectionAllocIn>::Alloc;
+
+    fn with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Self {
+        Self(Window::with_capacity_in(capacity, alloc))
+    }
+
+    fn from_iter_in<I: IntoIterator<Item = Self::Owned>>(iter: I, alloc: Self::Alloc) -> Self {
+        Self(Window::from_iter_in(iter, alloc))
+    }
+
+    fn try_with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Result<Self, AllocError> {
+        Window::try_with_capacity_in(capacity, alloc).map(Self)
+    }
+
+    fn try_from_iter_in<I: IntoIterator<Item = Self::Owned>>(
+        iter: I,
+        alloc: Self::Alloc,
+    ) -> Result<Self, AllocError> {
+        Window::try_from_iter_in(iter, alloc).map(Self)
+    }
+}
+
+impl<Nulls: Nullability, OffsetItem: Offset, Storage: Buffer> CollectionRealloc
+    for Binary<Nulls, OffsetItem, Storage>
+where
+    Nulls::Collection<BinaryValues<OffsetItem, Storage>, Storage>: CollectionRealloc,
+{
+    fn allocator(&self) -> Self::Alloc {
+        self.0.allocator()
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+        self.0.try_reserve(additional)
+    }
+
+    fn try_extend<I: IntoIterator<Item = Self::Owned>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), AllocError> {
+        self.0.try_extend(iter)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.0.truncate(len);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use crate::{collection::tests::round_trip, nullability::Nullable};
+
+    use super::*;
+
+    #[test]
+    fn try_from_parts() {
+        let values =
+            BinaryValues::<i64>::try_from_parts(vec![1, 2, 3], vec![0, 1, 3]).expect("valid");
+        assert_eq!(values.view(0), Some([1].as_slice()));
+        assert_eq!(values.view(1), Some([2, 3].as_slice()));
+        assert_eq!(values.view(2), None);
+
+        let error =
+            BinaryValues::<i32>::try_from_parts(vec![1], vec![0, 2]).expect_err("out of bounds");
+        assert_eq!(error, OffsetsError::OutOfBounds { last: 2, data: 1 });
+    }
+
+    #[test]
+    fn collection() {
+        round_trip::<Binary, _>([vec![], vec![1], vec![2, 3]].map(Bytes::new));
+        round_trip::<Binary<Nullable>, _>([Some(Bytes::new(vec![1])), None]);
+        round_trip::<LargeBinary, _>([vec![1, 2], vec![]].map(Bytes::new));
+        round_trip::<LargeBinary<Nullable>, _>([None, Some(Bytes::default())]);
+    }
+
+    #[test]
+    fn extend_and_slice() {
+        let mut values = [Bytes::new(vec![1])].into_iter().collect::<Binary>();
+        values.extend([Bytes::new(vec![2, 3])]);
+        CollectionRealloc::try_extend(&mut values, [Bytes::new(vec![4])]).expect("reserved");
+        let slice = values.slice(1, 2);
+        assert_eq!(
+            slice.iter_views().collect::<Vec<_>>(),
+            [[2, 3].as_slice(), [4].as_slice()]
+        );
+        assert_eq!(
+            slice.into_iter_owned().map(Vec::from).collect::<Vec<_>>(),
+            [vec![2, 3], vec![4]]
+        );
+    }
+}
@@ -0,0 +1,1008 @@
+//! Binary data stored in views.
+
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+use core::{
+    borrow::Borrow,
+    fmt::{self, Debug},
+    iter::{self, Map, RepeatN, Zip},
+    ops::{Deref, Range},
+};
+
+use crate::{
+    buffer::{Buffer, BufferRef, VecBuffer},
+    collection::{
+        AllocError, Collection, CollectionAllocIn, CollectionRealloc, owned::IntoOwned,
+        view::AsView, window::Window,
+    },
+    fixed_size::FixedSizeArray,
+    layout::MemoryLayout,
+    length::Length,
+    nullability::{NonNullable, Nullability},
+};
+
+/// A value of type `T` stored in a view layout.
+///
+/// Arrow stores binary and string data either with offsets into one data
+/// buffer or with views. Both represent the same items, so selecting views is
+/// a storage decision, like dictionary encoding. Wrapping the value selects
+/// the view layout:
+///
+/// ```text
+/// Viewed<Vec<u8>>  BinaryView
+/// Viewed<String>   Utf8View
+/// ```
+///
+/// Views are the plain `&[u8]` and `&str` slices of the items.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{array::Array, collection::Collection, layout::binary_view::Viewed};
+///
+/// let values = ["short", "longer than twelve bytes"]
+///     .into_iter()
+///     .map(|value| Viewed::new(String::from(value)))
+///     .collect::<Array<Viewed<String>>>();
+/// assert_eq!(values.view(1), Some("longer than twelve bytes"));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Viewed<T>(T);
+
+impl<T> Viewed<T> {
+    /// Wraps `value` for storage in a view layout.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::layout::binary_view::Viewed;
+    ///
+    /// let value = Viewed::new(vec![1_u8]);
+    /// assert_eq!(*value, [1]);
+    /// ```
+    #[must_use]
+    pub const fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Returns the wrapped value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::layout::binary_view::Viewed;
+    ///
+    /// let value = Viewed::new(String::from("a"));
+    /// assert_eq!(value.into_inner(), "a");
+    /// ```
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Viewed<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<&str> for Viewed<String> {
+    fn from(value: &str) -> Self {
+        Self(String::from(value))
+    }
+}
+
+impl IntoOwned<Viewed<Vec<u8>>> for &[u8] {
+    fn into_owned(self) -> Viewed<Vec<u8>> {
+        Viewed(self.to_vec())
+    }
+}
+
+impl<'collection> AsView<'collection> for Viewed<Vec<u8>> {
+    type View = &'collection [u8];
+
+    fn as_view(&'collection self) -> Self::View {
+        &self.0
+    }
+}
+
+impl<'collection> AsView<'collection> for Viewed<String> {
+    type View = &'collection str;
+
+    fn as_view(&'collection self) -> Self::View {
+        &self.0
+    }
+}
+
+/// The 16-byte view of one item in [`BinaryViews`].
+///
+/// Every view starts with the item length as a little-endian `i32`. Items of
+/// at most 12 bytes are stored inline, padded with zeros. Longer items store
+/// their first four bytes, the index of the data buffer that holds them and
+/// their offset in that buffer:
+///
+/// ```text
+/// inline:    | length | data (12 bytes)                   |
+/// reference: | length | prefix | buffer index | offset    |
+/// ```
+pub type ByteView = FixedSizeArray<u8, 16>;
+
+/// The maximum length of an item stored inline in its [`ByteView`].
+const INLINE_LEN: usize = 12;
+
+/// Returns the little-endian `i32` field at `start` of `view`.
+fn field(view: &ByteView, start: usize) -> i32 {
+    let bytes = view[start..start.strict_add(4)]
+        .try_into()
+        .expect("four bytes");
+    i32::from_le_bytes(bytes)
+}
+
+/// Returns the [`ByteView`] of an item of at most [`INLINE_LEN`] bytes.
+fn inline_view(bytes: &[u8]) -> ByteView {
+    let mut view = [0; 16];
+    view[..4].copy_from_slice(&encode(bytes.len()));
+    view[4..4_usize.strict_add(bytes.len())].copy_from_slice(bytes);
+    ByteView::from(view)
+}
+
+/// Returns the [`ByteView`] of an item stored at `offset` in data buffer
+/// `buffer`.
+fn reference_view(bytes: &[u8], buffer: usize, offset: usize) -> ByteView {
+    let mut view = [0; 16];
+    view[..4].copy_from_slice(&encode(bytes.len()));
+    view[4..8].copy_from_slice(&bytes[..4]);
+    view[8..12].copy_from_slice(&encode(buffer));
+    view[12..].copy_from_slice(&encode(offset));
+    ByteView::from(view)
+}
+
+/// Returns the little-endian `i32` encoding of a validated length, index or
+/// offset.
+fn encode(value: usize) -> [u8; 4] {
+    i32::try_from(value)
+        .expect("view field fits in i32")
+        .to_le_bytes()
+}
+
+/// Views of binary items.
+///
+/// <https://arrow.apache.org/docs/format/Columnar.html#variable-size-binary-view-layout>
+///
+/// Every item has a fixed-size [`ByteView`]. Short items live in the view
+/// itself and longer items are referenced in one of a variable number of data
+/// buffers:
+///
+/// ```text
+/// items:   [b"hi", b"a much longer item"]
+/// views:   [(2, b"hi"), (18, b"a mu", 0, 0)]
+/// buffers: [b"a much longer item"]
+/// ```
+///
+/// Unlike offsets, views do not depend on their neighbours. Slicing, filtering
+/// or reordering items only touches the views, and comparing the inline prefix
+/// often avoids reading the data buffers at all.
+///
+/// Extending stores items of at most 12 bytes inline and appends longer items
+/// to the last data buffer, starting a new one when its offsets would no
+/// longer fit in an `i32`.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{collection::Collection, layout::binary_view::{BinaryViews, Viewed}};
+///
+/// let values = [b"hi".to_vec(), b"a much longer item".to_vec()]
+///     .into_iter()
+///     .map(Viewed::new)
+///     .collect::<BinaryViews>();
+/// assert_eq!(values.view(0), Some(&b"hi"[..]));
+/// assert_eq!(values.data_buffers(), [b"a much longer item".to_vec()]);
+/// ```
+pub struct BinaryViews<Storage: Buffer = VecBuffer> {
+    /// The view of every item.
+    views: Storage::For<ByteView>,
+    /// The data buffers referenced by the views.
+    buffers: Vec<Storage::For<u8>>,
+}
+
+/// Error returned by [`BinaryViews::try_from_parts`].
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{
+///     buffer::VecBuffer,
+///     layout::binary_view::{BinaryViews, BinaryViewsError, ByteView},
+/// };
+///
+/// let mut view = [0; 16];
+/// view[0] = 13;
+/// let views = vec![ByteView::from(view)];
+/// let error = BinaryViews::<VecBuffer>::try_from_parts(views, vec![]).unwrap_err();
+/// assert_eq!(error, BinaryViewsError::MissingBuffer { index: 0, buffer: 0 });
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BinaryViewsError {
+    /// The view at `index` has a negative length.
+    NegativeLength {
+        /// The index of the invalid view.
+        index: usize,
+    },
+    /// The view at `index` references a data buffer that does not exist.
+    MissingBuffer {
+        /// The index of the invalid view.
+        index: usize,
+        /// The referenced data buffer.
+        buffer: i32,
+    },
+    /// The view at `index` references bytes outside of its data buffer.
+    OutOfBounds {
+        /// The index of the invalid view.
+        index: usize,
+    },
+    /// The prefix of the view at `index` does not match its data.
+    PrefixMismatch {
+        /// The index of the invalid view.
+        index: usize,
+    },
+}
+
+impl fmt::Display for BinaryViewsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::NegativeLength { index } => {
+                write!(f, "view at index {index} has a negative length")
+            }
+            Self::MissingBuffer { index, buffer } => write!(
+                f,
+                "view at index {index} references missing data buffer {buffer}"
+            ),
+            Self::OutOfBounds { index } => {
+                write!(
+                    f,
+                    "view at index {index} is out of bounds of its data buffer"
+                )
+            }
+            Self::PrefixMismatch { index } => {
+                write!(f, "prefix of view at index {index} does not match its data")
+            }
+        }
+    }
+}
+
+impl core::error::Error for BinaryViewsError {}
+
+impl<Storage: Buffer> BinaryViews<Storage> {
+    /// Constructs [`BinaryViews`] from a `views` buffer and the data
+    /// `buffers` they reference.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BinaryViewsError`] when a view has a negative length, or
+    /// when a view that is not inline references bytes outside of the data
+    /// buffers or its prefix does not match them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{
+    ///     collection::Collection,
+    ///     layout::binary_view::{BinaryViews, ByteView},
+    /// };
+    ///
+    /// let mut view = [0; 16];
+    /// view[0] = 1;
+    /// view[4] = b'a';
+    /// let values: BinaryViews =
+    ///     BinaryViews::try_from_parts(vec![ByteView::from(view)], vec![]).unwrap();
+    /// assert_eq!(values.view(0), Some(&b"a"[..]));
+    /// ```
+    pub fn try_from_parts(
+        views: Storage::For<ByteView>,
+        buffers: Vec<Storage::For<u8>>,
+    ) -> Result<Self, BinaryViewsError> {
+        let values = Self { views, buffers };
+        values
+            .views
+            .borrow()
+            .iter()
+            .enumerate()
+            .try_for_each(|(index, view)| values.resolve(index, view).map(|_| ()))?;
+        Ok(values)
+    }
+
+    /// Returns the views buffer and data buffers of these [`BinaryViews`].
+    ///
+    /// This is the inverse of [`BinaryViews::try_from_parts`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::layout::binary_view::{BinaryViews, Viewed};
+    ///
+    /// let values = [Viewed::new(b"a".to_vec())].into_iter().collect::<BinaryViews>();
+    /// let (views, buffers) = values.into_parts();
+    /// assert_eq!((views.len(), buffers.len()), (1, 0));
+    /// ```
+    #[must_use]
+    pub fn into_parts(self) -> (Storage::For<ByteView>, Vec<Storage::For<u8>>) {
+        (self.views, self.buffers)
+    }
+
+    /// Returns the data buffers referenced by the views.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::layout::binary_view::{BinaryViews, Viewed};
+    ///
+    /// let values = [Viewed::new(b"inline".to_vec())].into_iter().collect::<BinaryViews>();
+    /// assert!(values.data_buffers().is_empty());
+    /// ```
+    #[must_use]
+    pub fn data_buffers(&self) -> &[Storage::For<u8>] {
+        &self.buffers
+    }
+
+    /// Returns the bytes of the item at `index` with `view`.
+    fn resolve<'collection>(
+        &'collection self,
+        index: usize,
+        view: &'collection ByteView,
+    ) -> Result<&'collection [u8], BinaryViewsError> {
+        let len = usize::try_from(field(view, 0))
+            .map_err(|_| BinaryViewsError::NegativeLength { index })?;
+        if len <= INLINE_LEN {
+            return Ok(&view[4..4_usize.strict_add(len)]);
+        }
+        let buffer = field(view, 8);
+        let data = usize::try_from(buffer)
+            .ok()
+            .and_then(|position| self.buffers.get(position))
+            .ok_or(BinaryViewsError::MissingBuffer { index, buffer })?
+            .borrow();
+        let bytes = usize::try_from(field(view, 12))
+            .ok()
+            .and_then(|start| data.get(start..start.checked_add(len)?))
+            .ok_or(BinaryViewsError::OutOfBounds { index })?;
+        if bytes[..4] == view[4..8] {
+            Ok(bytes)
+        } else {
+            Err(BinaryViewsError::PrefixMismatch { index })
+        }
+    }
+}
+
+impl<Storage: Buffer> BinaryViews<Storage>
+where
+    Storage::For<ByteView>: CollectionRealloc,
+    Storage::For<u8>:
+        CollectionRealloc<Alloc = <Storage::For<ByteView> as CollectionAllocIn>::Alloc>,
+{
+    /// Appends the view of `bytes`, storing them in the last data buffer when
+    /// they cannot be inlined.
+    fn try_push(
+        &mut self,
+        bytes: Vec<u8>,
+        push_data: impl FnOnce(&mut Storage::For<u8>, Vec<u8>) -> Result<(), AllocError>,
+        push_view: impl FnOnce(&mut Storage::For<ByteView>, ByteView) -> Result<(), AllocError>,
+    ) -> Result<(), AllocError> {
+        let len = bytes.len();
+        let max = usize::try_from(i32::MAX).expect("i32::MAX fits in usize");
+        if len > max {
+            return Err(AllocError);
+        }
+        if len <= INLINE_LEN {
+            return push_view(&mut self.views, inline_view(&bytes));
+        }
+        // Offsets in a data buffer are `i32`, so a full buffer is left as is.
+        let fits = self
+            .buffers
+            .last()
+            .is_some_and(|data| data.len().checked_add(len).is_some_and(|end| end <= max));
+        if !fits {
+            let data = Storage::For::<u8>::try_with_capacity_in(len, self.views.allocator())?;
+            self.buffers.push(data);
+        }
+        let buffer = self.buffers.len().strict_sub(1);
+        let data = self.buffers.last_mut().expect("data buffer");
+        let offset = data.len();
+        let view = reference_view(&bytes, buffer, offset);
+        push_data(data, bytes)?;
+        push_view(&mut self.views, view)
+    }
+}
+
+impl<Storage: Buffer> BufferRef for BinaryViews<Storage> {
+    type Buffer = Storage::For<ByteView>;
+
+    fn buffer_ref(&self) -> &Self::Buffer {
+        &self.views
+    }
+}
+
+impl<Storage: Buffer> Debug for BinaryViews<Storage>
+where
+    Storage::For<ByteView>: Debug,
+    Storage::For<u8>: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BinaryViews")
+            .field("views", &self.views)
+            .field("buffers", &self.buffers)
+            .finish()
+    }
+}
+
+impl<Storage: Buffer> Clone for BinaryViews<Storage>
+where
+    Storage::For<ByteView>: Clone,
+    Storage::For<u8>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            views: self.views.clone(),
+            buffers: self.buffers.clone(),
+        }
+    }
+}
+
+impl<Storage: Buffer> Default for BinaryViews<Storage>
+where
+    Storage::For<ByteView>: Default,
+{
+    fn default() -> Self {
+        Self {
+            views: Storage::For::<ByteView>::default(),
+            buffers: Vec::new(),
+        }
+    }
+}
+
+impl<Storage: Buffer> Extend<Viewed<Vec<u8>>> for BinaryViews<Storage>
+where
+    Storage::For<ByteView>: CollectionRealloc,
+    Storage::For<u8>:
+        CollectionRealloc<Alloc = <Storage::For<ByteView> as CollectionAllocIn>::Alloc>,
+{
+    fn extend<I: IntoIterator<Item = Viewed<Vec<u8>>>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|Viewed(bytes)| {
+            self.try_push(
+                bytes,
+                |data, new| {
+                    data.extend(new);
+                    Ok(())
+                },
+                |views, view| {
+                    views.extend(iter::once(view));
+                    Ok(())
+                },
+            )
+            .expect("item length fits in i32");
+        });
+    }
+}
+
+impl<Storage: Buffer> FromIterator<Viewed<Vec<u8>>> for BinaryViews<Storage>
+where
+    Self: Default + Extend<Viewed<Vec<u8>>>,
+{
+    fn from_iter<I: IntoIterator<Item = Viewed<Vec<u8>>>>(iter: I) -> Self {
+        let mut values = Self::default();
+        values.extend(iter);
+        values
+    }
+}
+
+impl<Storage: Buffer> Length for BinaryViews<Storage> {
+    fn len(&self) -> usize {
+        self.views.len()
+    }
+}
+
+impl<Storage: Buffer> Collection for BinaryViews<Storage> {
+    type View<'collection>
+        = &'collection [u8]
+    where
+        Self: 'collection;
+
+    type Owned = Viewed<Vec<u8>>;
+
+    fn view(&self, index: usize) -> Option<Self::View<'_>> {
+        self.views
+            .borrow()
+            .get(index)
+            .map(|view| self.resolve(index, view).expect("validated view"))
+    }
+
+    type Iter<'collection>
+        = Map<
+        Zip<Range<usize>, RepeatN<&'collection Self>>,
+        fn((usize, &'collection Self)) -> Self::View<'collection>,
+    >
+    where
+        Self: 'collection;
+
+    fn iter_views(&self) -> Self::Iter<'_> {
+        let len = self.len();
+        (0..len)
+            .zip(iter::repeat_n(self, len))
+            .map(|(index, values)| values.view(index).expect("index in range"))
+    }
+
+    type IntoIter = BinaryViewsIntoIter<Storage>;
+
+    fn into_iter_owned(self) -> Self::IntoIter {
+        BinaryViewsIntoIter {
+            indices: 0..self.len(),
+            values: self,
+        }
+    }
+}
+
+impl<Storage: Buffer> CollectionAllocIn for BinaryViews<Storage>
+where
+    Storage::For<ByteView>: CollectionRealloc,
+    Storage::For<u8>:
+        CollectionRealloc<Alloc = <Storage::For<ByteView> as CollectionAllocIn>::Alloc>,
+{
+    type Alloc = <Storage::For<ByteView> as CollectionAllocIn>::Alloc;
+
+    /// Only the views are allocated: the size of the data buffers depends on
+    /// the lengths of the items.
+    fn with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Self {
+        Self {
+            views: Storage::For::<ByteView>::with_capacity_in(capacity, alloc),
+            buffers: Vec::new(),
+        }
+    }
+
+    fn from_iter_in<I: IntoIterator<Item = Self::Owned>>(iter: I, alloc: Self::Alloc) -> Self {
+        let items = iter.into_iter();
+        let mut values = Self::with_capacity_in(items.size_hint().0, alloc);
+        values.extend(items);
+        values
+    }
+
+    fn try_with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Result<Self, AllocError> {
+        Ok(Self {
+            views: Storage::For::<ByteView>::try_with_capacity_in(capacity, alloc)?,
+            buffers: Vec::new(),
+        })
+    }
+
+    fn try_from_iter_in<I: IntoIterator<Item = Self::Owned>>(
+        iter: I,
+        alloc: Self::Alloc,
+    ) -> Result<Self, AllocError> {
+        let items = iter.into_iter();
+        let mut values = Self::try_with_capacity_in(items.size_hint().0, alloc)?;
+        values.try_extend(items)?;
+        Ok(values)
+    }
+}
+
+impl<Storage: Buffer> CollectionRealloc for BinaryViews<Storage>
+where
+    Storage::For<ByteView>: CollectionRealloc,
+    Storage::For<u8>:
+        CollectionRealloc<Alloc = <Storage::For<ByteView> as CollectionAllocIn>::Alloc>,
+{
+    fn allocator(&self) -> Self::Alloc {
+        self.views.allocator()
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+        self.views.try_reserve(additional)
+    }
+
+    fn try_extend<I: IntoIterator<Item = Self::Owned>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), AllocError> {
+        let len = self.len();
+        iter.into_iter()
+            .try_for_each(|Viewed(bytes)| {
+                self.try_push(bytes, CollectionRealloc::try_extend, |views, view| {
+                    views.try_extend(iter::once(view))
+                })
+            })
+            .inspect_err(|_| self.truncate(len))
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.views.reserve(additional);
+    }
+
+    /// Drops the views after `len`. The data buffers are kept, because other
+    /// views may still reference them.
+    fn truncate(&mut self, len: usize) {
+        self.views.truncate(len);
+    }
+}
+
+/// Iterator over owned items of [`BinaryViews`].
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{collection::Collection, layout::binary_view::{BinaryViews, Viewed}};
+///
+/// let values = [Viewed::new(b"a".to_vec())].into_iter().collect::<BinaryViews>();
+/// let mut iter = values.into_iter_owned();
+/// assert_eq!(iter.len(), 1);
+/// assert_eq!(iter.next(), Some(Viewed::new(b"a".to_vec())));
+/// ```
+pub struct BinaryViewsIntoIter<Storage: Buffer> {
+    /// The indices of the remaining items.
+    indices: Range<usize>,
+    /// The iterated views.
+    values: BinaryViews<Storage>,
+}
+
+impl<Storage: Buffer> Debug for BinaryViewsIntoIter<Storage>
+where
+    BinaryViews<Storage>: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BinaryViewsIntoIter")
+            .field("indices", &self.indices)
+            .field("values", &self.values)
+            .finish()
+    }
+}
+
+impl<Storage: Buffer> Iterator for BinaryViewsIntoIter<Storage> {
+    type Item = Viewed<Vec<u8>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.indices.next()?;
+        self.values.view(index).map(IntoOwned::into_owned)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let index = self.indices.nth(n)?;
+        self.values.view(index).map(IntoOwned::into_owned)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+impl<Storage: Buffer> ExactSizeIterator for BinaryViewsIntoIter<Storage> {}
+
+/// A collection of binary items stored in views.
+///
+/// This is the Arrow `BinaryView` layout: [`BinaryViews`] with outer
+/// nullability.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{
+///     collection::Collection,
+///     layout::binary_view::{BinaryView, Viewed},
+///     nullability::Nullable,
+/// };
+///
+/// let values = [Some(Viewed::new(b"a".to_vec())), None]
+///     .into_iter()
+///     .collect::<BinaryView<Nullable>>();
+/// assert_eq!(values.view(0), Some(Some(&b"a"[..])));
+/// assert_eq!(values.view(1), Some(None));
+/// ```
+pub struct BinaryView<Nulls: Nullability = NonNullable, Storage: Buffer = VecBuffer>(
+    Window<Nulls::Collection<BinaryViews<Storage>, Storage>>,
+);
+
+impl<Nulls: Nullability, Storage: Buffer> MemoryLayout for BinaryView<Nulls, Storage> {
+    fn slice(self, offset: usize, len: usize) -> Self {
+        Self(self.0.slice(offset, len))
+    }
+
+    fn offset(&self) -> usize {
+        self.0.offset()
+    }
+}
+
+impl<Nulls: Nullability, Storage: Buffer> BinaryView<Nulls, Storage> {
+    /// Constructs a [`BinaryView`] from its backing collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{
+    ///     collection::Collection,
+    ///     layout::binary_view::{BinaryView, BinaryViews, Viewed},
+    ///     nullability::NonNullable,
+    /// };
+    ///
+    /// let values = [Viewed::new(b"a".to_vec())].into_iter().collect::<BinaryViews>();
+    /// let values = BinaryView::<NonNullable>::from_buffer(values);
+    /// assert_eq!(values.view(0), Some(&b"a"[..]));
+    /// ```
+    #[must_use]
+    pub fn from_buffer(buffer: Nulls::Collection<BinaryViews<Storage>, Storage>) -> Self {
+        Self(Window::new(buffer))
+    }
+
+    /// Returns the backing collection of this [`BinaryView`].
+    ///
+    /// This is the inverse of [`BinaryView::from_buffer`]. The backing
+    /// collection of a slice still contains the items outside of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{layout::binary_view::{BinaryView, Viewed}, length::Length};
+    ///
+    /// let values = [Viewed::new(b"a".to_vec())].into_iter().collect::<BinaryView>();
+    /// assert_eq!(values.into_buffer().len(), 1);
+    /// ```
+    #[must_use]
+    pub fn into_buffer(self) -> Nulls::Collection<BinaryViews<Storage>, Storage> {
+        self.0.into_parts().0
+    }
+}
+
+impl<Nulls: Nullability, Storage: Buffer> BufferRef for BinaryView<Nulls, Storage> {
+    type Buffer = Nulls::Collection<BinaryViews<Storage>, Storage>;
+
+    fn buffer_ref(&self) -> &Self::Buffer {
+        self.0.buffer_ref()
+    }
+}
+
+impl<Nulls: Nullability, Storage: Buffer> Debug for BinaryView<Nulls, Storage>
+where
+    Nulls::Collection<BinaryViews<Storage>, Storage>: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("BinaryView").field(&self.0).finish()
+    }
+}
+
+impl<Nulls: Nullability, Storage: Buffer> Clone for BinaryView<Nulls, Storage>
+where
+    Nulls::Collection<BinaryViews<Storage>, Storage>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<Nulls: Nullability, Storage: Buffer> Default for BinaryView<Nulls, Storage>
+where
+    Nulls::Collection<BinaryViews<Storage>, Storage>: Default,
+{
+    fn default() -> Self {
+        Self(Window::default())
+    }
+}
+
+impl<Nulls: Nullability, Storage: Buffer> Extend<Nulls::Item<Viewed<Vec<u8>>>>
+    for BinaryView<Nulls, Storage>
+where
+    Window<Nulls::Collection<BinaryViews<Storage>, Storage>>: Extend<Nulls::Item<Viewed<Vec<u8>>>>,
+{
+    fn extend<I: IntoIterator<Item = Nulls::Item<Viewed<Vec<u8>>>>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl<Nulls: Nullability, Storage: Buffer> FromIterator<Nulls::Item<Viewed<Vec<u8>>>>
+    for BinaryView<Nulls, Storage>
+where
+    Nulls::Collection<BinaryViews<Storage>, Storage>: FromIterator<Nulls::Item<Viewed<Vec<u8>>>>,
+{
+    fn from_iter<I: IntoIterator<Item = Nulls::Item<Viewed<Vec<u8>>>>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<Nulls: Nullability, Storage: Buffer> Length for BinaryView<Nulls, Storage> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<Nulls: Nullability, Storage: Buffer> Collection for BinaryView<Nulls, Storage> {
+    type View<'collection>
+        = <Nulls::Collection<BinaryViews<Storage>, Storage> as Collection>::View<'collection>
+    where
+        Self: 'collection;
+
+    type Owned = Nulls::Item<Viewed<Vec<u8>>>;
+
+    fn view(&self, index: usize) -> Option<Self::View<'_>> {
+        self.0.view(index)
+    }
+
+    type Iter<'collection>
+        =
+        <Window<Nulls::Collection<BinaryViews<Storage>, Storage>> as Collection>::Iter<'collection>
+    where
+        Self: 'collection;
+
+    fn iter_views(&self) -> Self::Iter<'_> {
+        self.0.iter_views()
+    }
+
+    type IntoIter =
+        <Window<Nulls::Collection<BinaryViews<Storage>, Storage>> as Collection>::IntoIter;
+
+    fn into_iter_owned(self) -> Self::IntoIter {
+        self.0.into_iter_owned()
+    }
+}
+
+impl<Nulls: Nullability, Storage: Buffer> CollectionAllocIn for BinaryView<Nulls, Storage>
+where
+    Nulls::Collection<BinaryViews<Storage>, Storage>: CollectionAllocIn,
+{
+    type Alloc = <Nulls::Collection<BinaryViews<Storage>, Storage> as CollectionAllocIn>::Alloc;
+
+    fn with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Self {
+        Self(Window::with_capacity_in(capacity, alloc))
+    }
+
+    fn from_iter_in<I: IntoIterator<Item = Self::Owned>>(iter: I, alloc: Self::Alloc) -> Self {
+        Self(Window::from_iter_in(iter, alloc))
+    }
+
+    fn try_with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Result<Self, AllocError> {
+        Window::try_with_capacity_in(capacity, alloc).map(Self)
+    }
+
+    fn try_from_iter_in<I: IntoIterator<Item = Self::Owned>>(
+        iter: I,
+        alloc: Self::Alloc,
+    ) -> Result<Self, AllocError> {
+        Window::try_from_iter_in(iter, alloc).map(Self)
+    }
+}
+
+impl<Nulls: Nullability, Storage: Buffer> CollectionRealloc for BinaryView<Nulls, Storage>
+where
+    Nulls::Collection<BinaryViews<Storage>, Storage>: CollectionRealloc,
+{
+    fn allocator(&self) -> Self::Alloc {
+        self.0.allocator()
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+        self.0.try_reserve(additional)
+    }
+
+    fn try_extend<I: IntoIterator<Item = Self::Owned>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), AllocError> {
+        self.0.try_extend(iter)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.0.truncate(len);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::vec;
+
+    use crate::{collection::tests::round_trip, nullability::Nullable};
+
+    use super::*;
+
+    fn items<const N: usize>(values: [&[u8]; N]) -> [Viewed<Vec<u8>>; N] {
+        values.map(IntoOwned::into_owned)
+    }
+
+    #[test]
+    fn inlines_short_items() {
+        let values = items([b"", b"twelve bytes", b"thirteen byte", b"a"])
+            .into_iter()
+            .collect::<BinaryViews>();
+        assert_eq!(values.data_buffers(), [b"thirteen byte".to_vec()]);
+        let views = values.buffer_ref();
+        assert_eq!(views[1][..4], 12_i32.to_le_bytes());
+        assert_eq!(views[1][4..], *b"twelve bytes");
+        assert_eq!(views[2][4..8], *b"thir");
+        assert_eq!(views[2][8..], [0; 8]);
+        assert_eq!(views[3][5..], [0; 11]);
+        assert_eq!(
+            values.iter_views().collect::<Vec<_>>(),
+            [&b""[..], b"twelve bytes", b"thirteen byte", b"a"]
+        );
+    }
+
+    #[test]
+    fn try_from_parts() {
+        let values = items([b"a", b"out of line item"])
+            .into_iter()
+            .collect::<BinaryViews>();
+        let (views, buffers) = values.into_parts();
+        let restored =
+            BinaryViews::<VecBuffer>::try_from_parts(views.clone(), buffers).expect("valid");
+        assert_eq!(restored.view(1), Some(&b"out of line item"[..]));
+
+        assert_eq!(
+            BinaryViews::<VecBuffer>::try_from_parts(views.clone(), vec![])
+                .expect_err("missing buffer"),
+            BinaryViewsError::MissingBuffer {
+                index: 1,
+                buffer: 0
+            }
+        );
+        assert_eq!(
+            BinaryViews::<VecBuffer>::try_from_parts(views.clone(), vec![b"out of line".to_vec()])
+                .expect_err("short buffer"),
+            BinaryViewsError::OutOfBounds { index: 1 }
+        );
+        assert_eq!(
+            BinaryViews::<VecBuffer>::try_from_parts(views, vec![b"Out of line item".to_vec()])
+                .expect_err("prefix"),
+            BinaryViewsError::PrefixMismatch { index: 1 }
+        );
+        assert_eq!(
+            BinaryViews::<VecBuffer>::try_from_parts(vec![ByteView::from([0xff; 16])], vec![])
+                .expect_err("negative length"),
+            BinaryViewsError::NegativeLength { index: 0 }
+        );
+    }
+
+    #[test]
+    fn from_buffer() {
+        let values = items([b"a", b"bc"]).into_iter().collect::<BinaryView>();
+        let restored = BinaryView::<NonNullable>::from_buffer(values.into_buffer());
+        assert_eq!(restored.len(), 2);
+        assert_eq!(restored.owned(1), Some(Viewed::new(b"bc".to_vec())));
+    }
+
+    #[test]
+    fn collection() {
+        round_trip::<BinaryView, _>(items([b"", b"a", b"a value of some length"]));
+        round_trip::<BinaryView<Nullable>, _>([
+            Some(Viewed::new(b"a value of some length".to_vec())),
+            None,
+            Some(Viewed::new(vec![])),
+        ]);
+    }
+
+    #[test]
+    fn try_extend_and_truncate() {
+        let mut values = items([b"a"]).into_iter().collect::<BinaryView>();
+        CollectionRealloc::try_extend(&mut values, items([b"a value of some length", b"b"]))
+            .expect("reserved");
+        assert_eq!(
+            values.owned(1),
+            Some(Viewed::new(b"a value of some length".to_vec()))
+        );
+        CollectionRealloc::truncate(&mut values, 1);
+        values.extend(items([b"another long value"]));
+        assert_eq!(
+            values.iter_views().collect::<Vec<_>>(),
+            [&b"a"[..], b"another long value"]
+        );
+        // The truncated item stays in the data buffer, after which the new
+        // item is appended.
+        let views = values.buffer_ref();
+        assert_eq!(
+            views.data_buffers(),
+            [b"a value of some lengthanother long value".to_vec()]
+        );
+        assert_eq!(views.buffer_ref()[1][12..], 22_i32.to_le_bytes());
+    }
+}
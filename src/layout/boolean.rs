@@ -3,7 +3,7 @@ use core::fmt::Debug;
 use crate::{
     bitmap::Bitmap,
     buffer::{Buffer, BufferRef, VecBuffer},
-    collection::{AllocError, Collection, CollectionAllocIn, CollectionRealloc},
+    collection::{AllocError, Collection, CollectionAllocIn, CollectionRealloc, window::Window},
     layout::MemoryLayout,
     length::Length,
     nullability::{NonNullable, Nullability},
@@ -33,10 +33,18 @@ use crate::{
 /// assert_eq!(values.owned(1), Some(None));
 /// ```
 pub struct Boolean<Nulls: Nullability = NonNullable, Storage: Buffer = VecBuffer>(
-    Nulls::Collection<Bitmap<Storage>, Storage>,
+    Window<Nulls::Collection<Bitmap<Storage>, Storage>>,
 );
 
-impl<Nulls: Nullability, Storage: Buffer> MemoryLayout for Boolean<Nulls, Storage> {}
+impl<Nulls: Nullability, Storage: Buffer> MemoryLayout for Boolean<Nulls, Storage> {
+    fn slice(self, offset: usize, len: usize) -> Self {
+        Self(self.0.slice(offset, len))
+    }
+
+    fn offset(&self) -> usize {
+        self.0.offset()
+    }
+}
 
 impl<Nulls: Nullability, Storage: Buffer> Boolean<Nulls, Storage> {
     /// Constructs a [`Boolean`] from its backing collection.
@@ -52,12 +60,13 @@ impl<Nulls: Nullability, Storage: Buffer> Boolean<Nulls, Storage> {
     /// ```
     #[must_use]
     pub fn from_buffer(buffer: Nulls::Collection<Bitmap<Storage>, Storage>) -> Self {
-        Self(buffer)
+        Self(Window::new(buffer))
     }
 
     /// Returns the backing collection of this [`Boolean`].
     ///
-    /// This is the inverse of [`Boolean::from_buffer`].
+    /// This is the inverse of [`Boolean::from_buffer`]. The backing
+    /// collection of a slice still contains the items outside of it.
     ///
     /// # Examples
     ///
@@ -69,7 +78,7 @@ impl<Nulls: Nullability, Storage: Buffer> Boolean<Nulls, Storage> {
     /// ```
     #[must_use]
     pub fn into_buffer(self) -> Nulls::Collection<Bitmap<Storage>, Storage> {
-        self.0
+        self.0.into_parts().0
     }
 }
 
@@ -77,7 +86,7 @@ impl<Nulls: Nullability, Storage: Buffer> BufferRef for Boolean<Nulls, Storage>
     type Buffer = Nulls::Collection<Bitmap<Storage>, Storage>;
 
     fn buffer_ref(&self) -> &Self::Buffer {
-        &self.0
+        self.0.buffer_ref()
     }
 }
 
@@ -90,12 +99,21 @@ where
     }
 }
 
+impl<Nulls: Nullability, Storage: Buffer> Clone for Boolean<Nulls, Storage>
+where
+    Nulls::Collection<Bitmap<Storage>, Storage>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
 impl<Nulls: Nullability, Storage: Buffer> Default for Boolean<Nulls, Storage>
 where
     Nulls::Collection<Bitmap<Storage>, Storage>: Default,
 {
     fn default() -> Self {
-        Self(Default::default())
+        Self(Window::default())
     }
 }
 
@@ -117,7 +135,7 @@ where
 
 impl<Nulls: Nullability, Storage: Buffer> Extend<Nulls::Item<bool>> for Boolean<Nulls, Storage>
 where
-    Nulls::Collection<Bitmap<Storage>, Storage>: Extend<Nulls::Item<bool>>,
+    Window<Nulls::Collection<Bitmap<Storage>, Storage>>: Extend<Nulls::Item<bool>>,
 {
     fn extend<I: IntoIterator<Item = Nulls::Item<bool>>>(&mut self, iter: I) {
         self.0.extend(iter);
@@ -137,7 +155,7 @@ impl<Nulls: Nullability, Storage: Buffer> Collection for Boolean<Nulls, Storage>
     }
 
     type Iter<'collection>
-        = <Nulls::Collection<Bitmap<Storage>, Storage> as Collection>::Iter<'collection>
+        = <Window<Nulls::Collection<Bitmap<Storage>, Storage>> as Collection>::Iter<'collection>
     where
         Self: 'collection;
 
@@ -145,7 +163,7 @@ impl<Nulls: Nullability, Storage: Buffer> Collection for Boolean<Nulls, Storage>
         self.0.iter_views()
     }
 
-    type IntoIter = <Nulls::Collection<Bitmap<Storage>, Storage> as Collection>::IntoIter;
+    type IntoIter = <Window<Nulls::Collection<Bitmap<Storage>, Storage>> as Collection>::IntoIter;
 
     fn into_iter_owned(self) -> Self::IntoIter {
         self.0.into_iter_owned()
@@ -159,22 +177,22 @@ where
     type Alloc = <Nulls::Collection<Bitmap<Storage>, Storage> as CollectionAllocIn>::Alloc;
 
     fn with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Self {
-        Self(Nulls::Collection::with_capacity_in(capacity, alloc))
+        Self(Window::with_capacity_in(capacity, alloc))
     }
 
     fn from_iter_in<I: IntoIterator<Item = Self::Owned>>(iter: I, alloc: Self::Alloc) -> Self {
-        Self(Nulls::Collection::from_iter_in(iter, alloc))
+        Self(Window::from_iter_in(iter, alloc))
     }
 
     fn try_with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Result<Self, AllocError> {
-        Nulls::Collection::try_with_capacity_in(capacity, alloc).map(Self)
+        Window::try_with_capacity_in(capacity, alloc).map(Self)
     }
 
     fn try_from_iter_in<I: IntoIterator<Item = Self::Owned>>(
         iter: I,
         alloc: Self::Alloc,
     ) -> Result<Self, AllocError> {
-        Nulls::Collection::try_from_iter_in(iter, alloc).map(Self)
+        Window::try_from_iter_in(iter, alloc).map(Self)
     }
 }
 
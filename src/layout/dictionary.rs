@@ -0,0 +1,1294 @@
+//! Dictionary-encoded values.
+
+extern crate alloc;
+
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::{
+    borrow::Borrow,
+    fmt::{self, Debug},
+    hash::{Hash, Hasher},
+    iter::{self, Map, RepeatN, Zip},
+    marker::PhantomData,
+    ops::{Deref, Range},
+};
+
+use hashbrown::HashTable;
+
+use crate::{
+    buffer::{Buffer, BufferRef, VecBuffer},
+    collection::{
+        AllocError, ChildRef, Collection, CollectionAllocIn, CollectionRealloc, owned::IntoOwned,
+        view::AsView, window::Window,
+    },
+    fixed_size::FixedSize,
+    layout::{ArrayItem, MemoryLayout, binary::Bytes, utf8::LargeString},
+    length::Length,
+    nullability::{NonNullable, Nullability},
+    offset::Offset,
+};
+
+/// Integer types that can index the values of a dictionary.
+///
+/// Arrow restricts dictionary keys to signed and unsigned integers of up to
+/// 64 bits. The conversions to and from [`usize`] map keys to positions in
+/// the dictionary values.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::layout::dictionary::DictionaryKey;
+///
+/// fn assert_key<K: DictionaryKey>() {}
+/// assert_key::<u16>();
+/// assert_key::<i32>();
+/// ```
+pub trait DictionaryKey: FixedSize + TryFrom<usize> + TryInto<usize> + sealed::Sealed {}
+
+impl DictionaryKey for u8 {}
+impl DictionaryKey for u16 {}
+impl DictionaryKey for u32 {}
+impl DictionaryKey for u64 {}
+impl DictionaryKey for i8 {}
+impl DictionaryKey for i16 {}
+impl DictionaryKey for i32 {}
+impl DictionaryKey for i64 {}
+
+/// Private module for [`sealed::Sealed`] trait.
+mod sealed {
+    /// Used to seal [`super::DictionaryKey`].
+    pub trait Sealed {}
+
+    /// Prevent downstream implementations of [`super::DictionaryKey`].
+    impl<T> Sealed for T where T: super::DictionaryKey {}
+}
+
+/// Values that can be stored in the dictionary of a [`DictionaryEncoded`]
+/// layout.
+///
+/// Extending a dictionary looks up every value by hashing its view and
+/// comparing it to the views of the dictionary values, so values are never
+/// cloned to index them. Views of variable-size values borrow slices of the
+/// dictionary values:
+///
+/// ```text
+/// Dictionary<u16, Vec<u8>>  owned item
+/// &[u8]                     view
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use narrow::layout::dictionary::DictionaryValue;
+///
+/// let value = String::from("a");
+/// assert_eq!(value.as_value_view(), "a");
+/// ```
+pub trait DictionaryValue: ArrayItem {
+    /// The view of a dictionary value.
+    type View<'value>: Copy + Eq + Hash + IntoOwned<Self> + 'value;
+
+    /// Returns the view of this value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::layout::dictionary::DictionaryValue;
+    ///
+    /// assert_eq!(vec![1_u8, 2].as_value_view(), [1, 2]);
+    /// ```
+    fn as_value_view(&self) -> Self::View<'_>;
+
+    /// Returns the view of the dictionary value at `index` of `values`, or
+    /// [`None`] if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{
+    ///     buffer::VecBuffer,
+    ///     layout::{dictionary::DictionaryValue, variable_size_list::VariableSizeList},
+    /// };
+    ///
+    /// let values = [vec![1], vec![2, 3]].into_iter().collect::<VariableSizeList<u8>>();
+    /// let view = Vec::<u8>::value_view::<VecBuffer>(&values, 1);
+    /// assert_eq!(view, Some([2, 3].as_slice()));
+    /// ```
+    fn value_view<Storage: Buffer>(
+        values: &Self::Memory<Storage>,
+        index: usize,
+    ) -> Option<Self::View<'_>>;
+}
+
+/// Implements [`DictionaryValue`] for a value type, generic over `$Generic`,
+/// and its nullable variant, whose layouts view values as `$View`.
+macro_rules! dictionary_value {
+    ($Type:ty, $View:ty $(, $Generic:ident: $Bound:path)?) => {
+        impl<$($Generic: $Bound)?> DictionaryValue for $Type {
+            type View<'value> = $View;
+
+            fn as_value_view(&self) -> Self::View<'_> {
+                self
+            }
+
+            fn value_view<Storage: Buffer>(
+                values: &Self::Memory<Storage>,
+                index: usize,
+            ) -> Option<Self::View<'_>> {
+                values.view(index)
+            }
+        }
+
+        impl<$($Generic: $Bound)?> DictionaryValue for Option<$Type> {
+            type View<'value> = Option<$View>;
+
+            fn as_value_view(&self) -> Self::View<'_> {
+                self.as_deref()
+            }
+
+            fn value_view<Storage: Buffer>(
+                values: &Self::Memory<Storage>,
+                index: usize,
+            ) -> Option<Self::View<'_>> {
+                values.view(index)
+            }
+        }
+    };
+}
+
+dictionary_value!(String, &'value str);
+dictionary_value!(Box<str>, &'value str);
+dictionary_value!(LargeString, &'value str);
+dictionary_value!(Bytes<OffsetItem>, &'value [u8], OffsetItem: Offset);
+
+impl<T: FixedSize + Eq + Hash> DictionaryValue for T {
+    type View<'value> = T;
+
+    fn as_value_view(&self) -> T {
+        *self
+    }
+
+    fn value_view<Storage: Buffer>(values: &Self::Memory<Storage>, index: usize) -> Option<T> {
+        values.owned(index)
+    }
+}
+
+impl<T: FixedSize + Eq + Hash> DictionaryValue for Option<T> {
+    type View<'value> = Self;
+
+    fn as_value_view(&self) -> Self {
+        *self
+    }
+
+    fn value_view<Storage: Buffer>(values: &Self::Memory<Storage>, index: usize) -> Option<Self> {
+        values.owned(index)
+    }
+}
+
+impl DictionaryValue for bool {
+    type View<'value> = Self;
+
+    fn as_value_view(&self) -> Self {
+        *self
+    }
+
+    fn value_view<Storage: Buffer>(values: &Self::Memory<Storage>, index: usize) -> Option<Self> {
+        values.owned(index)
+    }
+}
+
+impl DictionaryValue for Option<bool> {
+    type View<'value> = Self;
+
+    fn as_value_view(&self) -> Self {
+        *self
+    }
+
+    fn value_view<Storage: Buffer>(values: &Self::Memory<Storage>, index: usize) -> Option<Self> {
+        values.owned(index)
+    }
+}
+
+/// Lists of fixed-size values are stored as Arrow lists, and viewed as slices
+/// of their flat child.
+impl<T: FixedSize + Eq + Hash> DictionaryValue for Vec<T> {
+    type View<'value> = &'value [T];
+
+    fn as_value_view(&self) -> Self::View<'_> {
+        self
+    }
+
+    fn value_view<Storage: Buffer>(
+        values: &Self::Memory<Storage>,
+        index: usize,
+    ) -> Option<Self::View<'_>> {
+        values.view(index).map(|view| view.as_slice())
+    }
+}
+
+impl<T: FixedSize + Eq + Hash> DictionaryValue for Option<Vec<T>> {
+    type View<'value> = Option<&'value [T]>;
+
+    fn as_value_view(&self) -> Self::View<'_> {
+        self.as_deref()
+    }
+
+    fn value_view<Storage: Buffer>(
+        values: &Self::Memory<Storage>,
+        index: usize,
+    ) -> Option<Self::View<'_>> {
+        values
+            .view(index)
+            .map(|view| view.map(|list| list.as_slice()))
+    }
+}
+
+/// A value of type `V` stored with dictionary encoding, using keys of type
+/// `K`.
+///
+/// Dictionary encoding is a storage decision, not a different logical value.
+/// Wrapping the value selects the [`DictionaryEncoded`] layout. Views resolve
+/// the key to the [view](DictionaryValue::View) of the dictionary value:
+///
+/// ```text
+/// Dictionary<u16, String>  owned item
+/// &str                     view
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{array::Array, collection::Collection, layout::dictionary::Dictionary};
+///
+/// let values = ["ab", "c", "ab"]
+///     .into_iter()
+///     .map(|value| Dictionary::new(value.to_owned()))
+///     .collect::<Array<Dictionary<u16, String>>>();
+/// assert_eq!(values.view(2), Some("ab"));
+/// assert_eq!(values.owned(1).unwrap().into_inner(), "c");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Dictionary<K: DictionaryKey, V>(V, PhantomData<K>);
+
+impl<K: DictionaryKey, V> Dictionary<K, V> {
+    /// Wraps `value` for dictionary encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::layout::dictionary::Dictionary;
+    ///
+    /// let value = Dictionary::<u8, _>::new(1);
+    /// assert_eq!(*value, 1);
+    /// ```
+    #[must_use]
+    pub const fn new(value: V) -> Self {
+        Self(value, PhantomData)
+    }
+
+    /// Returns the wrapped value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::layout::dictionary::Dictionary;
+    ///
+    /// let value = Dictionary::<u8, _>::new(vec![1]);
+    /// assert_eq!(value.into_inner(), [1]);
+    /// ```
+    #[must_use]
+    pub fn into_inner(self) -> V {
+        self.0
+    }
+}
+
+impl<K: DictionaryKey, V> Deref for Dictionary<K, V> {
+    type Target = V;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<K: DictionaryKey, V: DictionaryValue> IntoOwned<Dictionary<K, V>> for V::View<'_> {
+    fn into_owned(self) -> Dictionary<K, V> {
+        Dictionary::new(self.into_owned())
+    }
+}
+
+impl<'collection, K: DictionaryKey, V: DictionaryValue> AsView<'collection> for Dictionary<K, V> {
+    type View = V::View<'collection>;
+
+    fn as_view(&'collection self) -> Self::View {
+        self.0.as_value_view()
+    }
+}
+
+/// Keys into a dictionary of values.
+///
+/// <https://arrow.apache.org/docs/format/Columnar.html#dictionary-encoded-layout>
+///
+/// Every distinct value is stored once in the dictionary, and items are keys
+/// that index it. Columns with few distinct values shrink to one small
+/// integer per item:
+///
+/// ```text
+/// items:      ["a", "b", "a", "a"]
+/// keys:       [0, 1, 0, 0]
+/// dictionary: ["a", "b"]
+/// ```
+///
+/// Extending the keys looks up each value in the dictionary and only appends
+/// values that are not present yet. The lookup hashes the
+/// [views](DictionaryValue::View) of the values; dictionary values are indexed
+/// lazily, so dictionaries constructed from parts and clones are deduplicated
+/// against as well. The number of distinct values is limited by the range of
+/// `K`.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{buffer::BufferRef, collection::{ChildRef, Collection}, length::Length};
+/// use narrow::layout::dictionary::{Dictionary, DictionaryKeys};
+///
+/// let keys = [1, 2, 1].into_iter().map(Dictionary::new).collect::<DictionaryKeys<u8, u32>>();
+/// assert_eq!(keys.buffer_ref(), &[0, 1, 0]);
+/// assert_eq!(keys.child_ref().len(), 2);
+/// assert_eq!(keys.view(2), Some(1));
+/// ```
+pub struct DictionaryKeys<K: DictionaryKey, V: DictionaryValue, Storage: Buffer = VecBuffer> {
+    /// The keys of the items.
+    keys: Storage::For<K>,
+    /// The dictionary values.
+    values: V::Memory<Storage>,
+    /// Lookup from the hashes of the dictionary values to their keys.
+    index: HashTable<(u64, K)>,
+    /// The number of dictionary values added to the index.
+    indexed: usize,
+}
+
+/// Error returned by [`DictionaryKeys::try_from_parts`] and
+/// [`DictionaryKeys::try_push`].
+///
+/// # Examples
+///
+/// ```
+/// use narrow::layout::dictionary::{DictionaryKeys, DictionaryKeysError};
+///
+/// let values = [1, 2].into_iter().collect();
+/// let error = DictionaryKeys::<u8, u32>::try_from_parts(vec![0, 2], values).unwrap_err();
+/// assert_eq!(error, DictionaryKeysError::OutOfBounds { index: 1, values: 2 });
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DictionaryKeysError {
+    /// The key at `index` does not address one of the dictionary values.
+    OutOfBounds {
+        /// The index of the invalid key.
+        index: usize,
+        /// The number of dictionary values.
+        values: usize,
+    },
+    /// The key type cannot address another dictionary value.
+    KeyOverflow {
+        /// The number of dictionary values.
+        values: usize,
+    },
+}
+
+impl fmt::Display for DictionaryKeysError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::OutOfBounds { index, values } => write!(
+                f,
+                "key at index {index} is out of bounds for a dictionary of {values} values"
+            ),
+            Self::KeyOverflow { values } => write!(
+                f,
+                "key type cannot address more than {values} dictionary values"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for DictionaryKeysError {}
+
+impl<K: DictionaryKey, V: DictionaryValue, Storage: Buffer> DictionaryKeys<K, V, Storage> {
+    /// Constructs [`DictionaryKeys`] from a `keys` buffer and the dictionary
+    /// `values`.
+    ///
+    /// The values are not required to be distinct.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DictionaryKeysError`] when a key is out of bounds of the
+    /// dictionary values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{collection::Collection, layout::dictionary::DictionaryKeys};
+    ///
+    /// let values = [3, 4].into_iter().collect();
+    /// let keys = DictionaryKeys::<i8, u32>::try_from_parts(vec![1, 0], values).unwrap();
+    /// assert_eq!(keys.view(0), Some(4));
+    /// ```
+    pub fn try_from_parts(
+        keys: Storage::For<K>,
+        values: V::Memory<Storage>,
+    ) -> Result<Self, DictionaryKeysError> {
+        let len = values.len();
+        keys.borrow()
+            .iter()
+            .enumerate()
+            .try_for_each(|(index, &key)| match key.try_into() {
+                Ok(position) if position < len => Ok(()),
+                Ok(_) | Err(_) => Err(DictionaryKeysError::OutOfBounds { index, values: len }),
+            })?;
+        Ok(Self {
+            keys,
+            values,
+            index: HashTable::new(),
+            indexed: 0,
+        })
+    }
+
+    /// Returns the keys buffer and the dictionary values of these
+    /// [`DictionaryKeys`].
+    ///
+    /// This is the inverse of [`DictionaryKeys::try_from_parts`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::layout::dictionary::{Dictionary, DictionaryKeys};
+    ///
+    /// let keys = [5, 5].into_iter().map(Dictionary::new).collect::<DictionaryKeys<u8, u32>>();
+    /// let (keys, values) = keys.into_parts();
+    /// assert_eq!((keys, values.into_buffer()), (vec![0, 0], vec![5]));
+    /// ```
+    #[must_use]
+    pub fn into_parts(self) -> (Storage::For<K>, V::Memory<Storage>) {
+        (self.keys, self.values)
+    }
+}
+
+impl<K: DictionaryKey, V: DictionaryValue, Storage: Buffer> DictionaryKeys<K, V, Storage> {
+    /// Appends `item` and returns its key, adding its value to the dictionary
+    /// values if it is not present yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DictionaryKeysError::KeyOverflow`] when `K` cannot address
+    /// another dictionary value. The keys are unchanged on failure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::layout::dictionary::{Dictionary, DictionaryKeys, DictionaryKeysError};
+    ///
+    /// let mut keys = (0..256).map(Dictionary::new).collect::<DictionaryKeys<u8, u32>>();
+    /// assert_eq!(keys.try_push(Dictionary::new(3)), Ok(3));
+    /// assert_eq!(
+    ///     keys.try_push(Dictionary::new(256)),
+    ///     Err(DictionaryKeysError::KeyOverflow { values: 256 })
+    /// );
+    /// ```
+    pub fn try_push(&mut self, item: Dictionary<K, V>) -> Result<K, DictionaryKeysError>
+    where
+        Storage::For<K>: Extend<K>,
+        V::Memory<Storage>: Extend<V>,
+    {
+        let Dictionary(value, _) = item;
+        let (key, new) = self.lookup(&value)?;
+        if let Some(hash) = new {
+            self.values.extend(iter::once(value));
+            self.insert(hash, key);
+        }
+        self.keys.extend(iter::once(key));
+        Ok(key)
+    }
+
+    /// Returns the key of `value`, and its hash when it is not one of the
+    /// dictionary values yet.
+    ///
+    /// Returns [`DictionaryKeysError::KeyOverflow`] when `value` is new and `K`
+    /// cannot address another dictionary value.
+    fn lookup(&mut self, value: &V) -> Result<(K, Option<u64>), DictionaryKeysError> {
+        // Values added through `try_from_parts` or a failed extension are not
+        // indexed yet. Keys of earlier duplicates take precedence.
+        while let Some(existing) = V::value_view(&self.values, self.indexed) {
+            let hash = hash_view(existing);
+            if let Ok(key) = K::try_from(self.indexed)
+                && self.find(existing).is_none()
+            {
+                self.index
+                    .insert_unique(hash, (hash, key), |&(entry, _)| entry);
+            }
+            self.indexed = self.indexed.strict_add(1);
+        }
+        let view = value.as_value_view();
+        if let Some(key) = self.find(view) {
+            return Ok((key, None));
+        }
+        let values = self.values.len();
+        let key = K::try_from(values).map_err(|_| DictionaryKeysError::KeyOverflow { values })?;
+        Ok((key, Some(hash_view(view))))
+    }
+
+    /// Returns the key of the dictionary value with the given `view`.
+    fn find<'values>(&'values self, view: V::View<'values>) -> Option<K> {
+        self.index
+            .find(hash_view(view), |&(_, key)| {
+                key.try_into()
+                    .ok()
+                    .and_then(|position| V::value_view(&self.values, position))
+                    == Some(view)
+            })
+            .map(|&(_, key)| key)
+    }
+
+    /// Adds the last dictionary value, with the given `hash`, to the index.
+    fn insert(&mut self, hash: u64, key: K) {
+        self.index
+            .insert_unique(hash, (hash, key), |&(entry, _)| entry);
+        self.indexed = self.indexed.strict_add(1);
+    }
+}
+
+/// Returns the hash of a dictionary value `view`.
+///
+/// Uses FNV-1a, which needs no random state, so that lookups are available
+/// without `std`.
+fn hash_view(view: impl Hash) -> u64 {
+    /// FNV-1a hasher.
+    struct Fnv(u64);
+
+    impl Hasher for Fnv {
+        fn finish(&self) -> u64 {
+            self.0
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            for &byte in bytes {
+                self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3);
+            }
+        }
+    }
+
+    let mut hasher = Fnv(0xcbf2_9ce4_8422_2325);
+    view.hash(&mut hasher);
+    hasher.finish()
+}
+
+impl<K: DictionaryKey, V: DictionaryValue, Storage: Buffer> BufferRef
+    for DictionaryKeys<K, V, Storage>
+{
+    type Buffer = Storage::For<K>;
+
+    fn buffer_ref(&self) -> &Self::Buffer {
+        &self.keys
+    }
+}
+
+impl<K: DictionaryKey, V: DictionaryValue, Storage: Buffer> ChildRef
+    for DictionaryKeys<K, V, Storage>
+{
+    type Child = V::Memory<Storage>;
+
+    fn child_ref(&self) -> &Self::Child {
+        &self.values
+    }
+}
+
+impl<K: DictionaryKey, V: DictionaryValue, Storage: Buffer> Debug for DictionaryKeys<K, V, Storage>
+where
+    Storage::For<K>: Debug,
+    V::Memory<Storage>: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DictionaryKeys")
+            .field("keys", &self.keys)
+            .field("values", &self.values)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<K: DictionaryKey, V: DictionaryValue, Storage: Buffer> Clone for DictionaryKeys<K, V, Storage>
+where
+    Storage::For<K>: Clone,
+    V::Memory<Storage>: Clone,
+{
+    fn clone(&self) -> Self {
+        // The index is rebuilt when the clone is extended, so clones do not
+        // copy every distinct value.
+        Self {
+            keys: self.keys.clone(),
+            values: self.values.clone(),
+            index: HashTable::new(),
+            indexed: 0,
+        }
+    }
+}
+
+impl<K: DictionaryKey, V: DictionaryValue, Storage: Buffer> Default
+    for DictionaryKeys<K, V, Storage>
+where
+    Storage::For<K>: Default,
+    V::Memory<Storage>: Default,
+{
+    fn default() -> Self {
+        Self {
+            keys: Storage::For::<K>::default(),
+            values: V::Memory::<Storage>::default(),
+            index: HashTable::new(),
+            indexed: 0,
+        }
+    }
+}
+
+impl<K: DictionaryKey, V: DictionaryValue, Storage: Buffer> Extend<Dictionary<K, V>>
+    for DictionaryKeys<K, V, Storage>
+where
+    Storage::For<K>: Extend<K>,
+    V::Memory<Storage>: Extend<V>,
+{
+    /// Extends the keys with the items of `iter`.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `K` cannot address another dictionary value. Use
+    /// [`DictionaryKeys::try_push`] to handle this case.
+    fn extend<I: IntoIterator<Item = Dictionary<K, V>>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|item| {
+            if let Err(error) = self.try_push(item) {
+                panic!("{error}");
+            }
+        });
+    }
+}
+
+impl<K: DictionaryKey, V: DictionaryValue, Storage: Buffer> FromIterator<Dictionary<K, V>>
+    for DictionaryKeys<K, V, Storage>
+where
+    Self: Default + Extend<Dictionary<K, V>>,
+{
+    /// Collects the items of `iter` into new keys.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `K` cannot address every distinct value of `iter`.
+    fn from_iter<I: IntoIterator<Item = Dictionary<K, V>>>(iter: I) -> Self {
+        let mut keys = Self::default();
+        keys.extend(iter);
+        keys
+    }
+}
+
+impl<K: DictionaryKey, V: DictionaryValue, Storage: Buffer> Length
+    for DictionaryKeys<K, V, Storage>
+{
+    fn len(&self) -> usize {
+        self.keys.borrow().len()
+    }
+}
+
+impl<K: DictionaryKey, V: DictionaryValue, Storage: Buffer> Collection
+    for DictionaryKeys<K, V, Storage>
+{
+    type View<'collection>
+        = V::View<'collection>
+    where
+        Self: 'collection;
+
+    type Owned = Dictionary<K, V>;
+
+    fn view(&self, index: usize) -> Option<Self::View<'_>> {
+        let key = *self.keys.borrow().get(index)?;
+        V::value_view(&self.values, key.try_into().ok()?)
+    }
+
+    type Iter<'collection>
+        = Map<
+        Zip<Range<usize>, RepeatN<&'collection Self>>,
+        fn((usize, &'collection Self)) -> Self::View<'collection>,
+    >
+    where
+        Self: 'collection;
+
+    fn iter_views(&self) -> Self::Iter<'_> {
+        let len = self.len();
+        (0..len)
+            .zip(iter::repeat_n(self, len))
+            .map(|(index, keys)| keys.view(index).expect("key in bounds"))
+    }
+
+    type IntoIter = DictionaryIntoIter<K, V, Storage>;
+
+    fn into_iter_owned(self) -> Self::IntoIter {
+        DictionaryIntoIter {
+            range: 0..self.len(),
+            keys: self,
+        }
+    }
+}
+
+impl<K: DictionaryKey, V: DictionaryValue, Storage: Buffer> CollectionAllocIn
+    for DictionaryKeys<K, V, Storage>
+where
+    Storage::For<K>: CollectionRealloc,
+    V::Memory<Storage>: CollectionRealloc<Alloc = <Storage::For<K> as CollectionAllocIn>::Alloc>,
+{
+    type Alloc = <Storage::For<K> as CollectionAllocIn>::Alloc;
+
+    fn with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Self {
+        Self {
+            keys: Storage::For::<K>::with_capacity_in(capacity, alloc.clone()),
+            values: V::Memory::<Storage>::with_capacity_in(0, alloc),
+            index: HashTable::new(),
+            indexed: 0,
+        }
+    }
+
+    fn from_iter_in<I: IntoIterator<Item = Self::Owned>>(iter: I, alloc: Self::Alloc) -> Self {
+        let items = iter.into_iter();
+        let mut keys = Self::with_capacity_in(items.size_hint().0, alloc);
+        keys.extend(items);
+        keys
+    }
+
+    fn try_with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Result<Self, AllocError> {
+        Ok(Self {
+            keys: Storage::For::<K>::try_with_capacity_in(capacity, alloc.clone())?,
+            values: V::Memory::<Storage>::try_with_capacity_in(0, alloc)?,
+            index: HashTable::new(),
+            indexed: 0,
+        })
+    }
+
+    fn try_from_iter_in<I: IntoIterator<Item = Self::Owned>>(
+        iter: I,
+        alloc: Self::Alloc,
+    ) -> Result<Self, AllocError> {
+        let items = iter.into_iter();
+        let mut keys = Self::try_with_capacity_in(items.size_hint().0, alloc)?;
+        keys.try_extend(items)?;
+        Ok(keys)
+    }
+}
+
+impl<K: DictionaryKey, V: DictionaryValue, Storage: Buffer> CollectionRealloc
+    for DictionaryKeys<K, V, Storage>
+where
+    Storage::For<K>: CollectionRealloc,
+    V::Memory<Storage>: CollectionRealloc<Alloc = <Storage::For<K> as CollectionAllocIn>::Alloc>,
+{
+    fn allocator(&self) -> Self::Alloc {
+        self.keys.allocator()
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+        self.keys.try_reserve(additional)
+    }
+
+    /// Tries to extend the keys with the items of `iter`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AllocError`] when storage cannot be reserved, or when `K`
+    /// cannot address another dictionary value. The keys and the dictionary
+    /// values are unchanged on failure. Use [`DictionaryKeys::try_push`] to
+    /// tell key overflows apart.
+    fn try_extend<I: IntoIterator<Item = Self::Owned>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), AllocError> {
+        let len = self.len();
+        let distinct = self.values.len();
+        iter.into_iter()
+            .try_for_each(|Dictionary(value, _)| {
+                let (key, new) = self.lookup(&value).map_err(|_| AllocError)?;
+                if let Some(hash) = new {
+                    self.values.try_extend(iter::once(value))?;
+                    self.insert(hash, key);
+                }
+                self.keys.try_extend(iter::once(key))
+            })
+            .inspect_err(|_| {
+                self.truncate(len);
+                self.values.truncate(distinct);
+                self.index.retain(|&mut (_, key)| {
+                    key.try_into().is_ok_and(|position| position < distinct)
+                });
+                self.indexed = self.indexed.min(distinct);
+            })
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.keys.reserve(additional);
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.keys.truncate(len);
+    }
+}
+
+/// Iterator over owned items of [`DictionaryKeys`].
+///
+/// Every item is materialized from the dictionary value its key refers to.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{collection::Collection, layout::dictionary::{Dictionary, DictionaryKeys}};
+///
+/// let keys = [1, 1].into_iter().map(Dictionary::new).collect::<DictionaryKeys<u8, u32>>();
+/// let mut iter = keys.into_iter_owned();
+/// assert_eq!(iter.len(), 2);
+/// assert_eq!(iter.next(), Some(Dictionary::new(1)));
+/// ```
+pub struct DictionaryIntoIter<K: DictionaryKey, V: DictionaryValue, Storage: Buffer> {
+    /// The remaining indices.
+    range: Range<usize>,
+    /// The iterated keys.
+    keys: DictionaryKeys<K, V, Storage>,
+}
+
+impl<K: DictionaryKey, V: DictionaryValue, Storage: Buffer> Debug
+    for DictionaryIntoIter<K, V, Storage>
+where
+    DictionaryKeys<K, V, Storage>: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DictionaryIntoIter")
+            .field("range", &self.range)
+            .field("keys", &self.keys)
+            .finish()
+    }
+}
+
+impl<K: DictionaryKey, V: DictionaryValue, Storage: Buffer> Iterator
+    for DictionaryIntoIter<K, V, Storage>
+{
+    type Item = Dictionary<K, V>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range.next().and_then(|index| self.keys.owned(index))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl<K: DictionaryKey, V: DictionaryValue, Storage: Buffer> ExactSizeIterator
+    for DictionaryIntoIter<K, V, Storage>
+{
+}
+
+/// A collection of dictionary-encoded values.
+///
+/// This is the Arrow dictionary-encoded layout: [`DictionaryKeys`] with outer
+/// nullability. Validity applies to the keys; null items are stored with the
+/// key of `V::default()`.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{collection::Collection, layout::dictionary::{Dictionary, DictionaryEncoded}};
+/// use narrow::nullability::Nullable;
+///
+/// let values = [Some(Dictionary::new(1)), None]
+///     .into_iter()
+///     .collect::<DictionaryEncoded<u8, u32, Nullable>>();
+/// assert_eq!(values.owned(0), Some(Some(Dictionary::new(1))));
+/// assert_eq!(values.owned(1), Some(None));
+/// ```
+pub struct DictionaryEncoded<
+    K: DictionaryKey,
+    V: DictionaryValue,
+    Nulls: Nullability = NonNullable,
+    Storage: Buffer = VecBuffer,
+>(Window<Nulls::Collection<DictionaryKeys<K, V, Storage>, Storage>>);
+
+impl<K: DictionaryKey, V: DictionaryValue, Nulls: Nullability, Storage: Buffer> MemoryLayout
+    for DictionaryEncoded<K, V, Nulls, Storage>
+{
+    fn slice(self, offset: usize, len: usize) -> Self {
+        Self(self.0.slice(offset, len))
+    }
+
+    fn offset(&self) -> usize {
+        self.0.offset()
+    }
+}
+
+impl<K: DictionaryKey, V: DictionaryValue, Nulls: Nullability, Storage: Buffer>
+    DictionaryEncoded<K, V, Nulls, Storage>
+{
+    /// Constructs a [`DictionaryEncoded`] from its backing collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{
+    ///     collection::Collection,
+    ///     layout::dictionary::{Dictionary, DictionaryEncoded, DictionaryKeys},
+    ///     nullability::NonNullable,
+    /// };
+    ///
+    /// let keys = DictionaryKeys::<u8, u32>::try_from_parts(vec![0, 0], [7].into_iter().collect());
+    /// let values = DictionaryEncoded::<_, _, NonNullable>::from_buffer(keys.unwrap());
+    /// assert_eq!(values.owned(1), Some(Dictionary::new(7)));
+    /// ```
+    #[must_use]
+    pub fn from_buffer(buffer: Nulls::Collection<DictionaryKeys<K, V, Storage>, Storage>) -> Self {
+        Self(Window::new(buffer))
+    }
+
+    /// Returns the backing collection of this [`DictionaryEncoded`].
+    ///
+    /// This is the inverse of [`DictionaryEncoded::from_buffer`]. The backing
+    /// collection of a slice still contains the items outside of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{layout::dictionary::{Dictionary, DictionaryEncoded}, length::Length};
+    ///
+    /// let values = [Dictionary::new(1)].into_iter().collect::<DictionaryEncoded<u8, u32>>();
+    /// assert_eq!(values.into_buffer().len(), 1);
+    /// ```
+    #[must_use]
+    pub fn into_buffer(self) -> Nulls::Collection<DictionaryKeys<K, V, Storage>, Storage> {
+        self.0.into_parts().0
+    }
+}
+
+impl<K: DictionaryKey, V: DictionaryValue, Nulls: Nullability, Storage: Buffer> BufferRef
+    for DictionaryEncoded<K, V, Nulls, Storage>
+{
+    type Buffer = Nulls::Collection<DictionaryKeys<K, V, Storage>, Storage>;
+
+    fn buffer_ref(&self) -> &Self::Buffer {
+        self.0.buffer_ref()
+    }
+}
+
+impl<K: DictionaryKey, V: DictionaryValue, Nulls: Nullability, Storage: Buffer> Debug
+    for DictionaryEncoded<K, V, Nulls, Storage>
+where
+    Nulls::Collection<DictionaryKeys<K, V, Storage>, Storage>: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("DictionaryEncoded").field(&self.0).finish()
+    }
+}
+
+impl<K: DictionaryKey, V: DictionaryValue, Nulls: Nullability, Storage: Buffer> Clone
+    for DictionaryEncoded<K, V, Nulls, Storage>
+where
+    Nulls::Collection<DictionaryKeys<K, V, Storage>, Storage>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<K: DictionaryKey, V: DictionaryValue, Nulls: Nullability, Storage: Buffer> Default
+    for DictionaryEncoded<K, V, Nulls, Storage>
+where
+    Nulls::Collection<DictionaryKeys<K, V, Storage>, Storage>: Default,
+{
+    fn default() -> Self {
+        Self(Window::default())
+    }
+}
+
+impl<K: DictionaryKey, V: DictionaryValue, Nulls: Nullability, Storage: Buffer>
+    Extend<Nulls::Item<Dictionary<K, V>>> for DictionaryEncoded<K, V, Nulls, Storage>
+where
+    Window<Nulls::Collection<DictionaryKeys<K, V, Storage>, Storage>>:
+        Extend<Nulls::Item<Dictionary<K, V>>>,
+{
+    fn extend<I: IntoIterator<Item = Nulls::Item<Dictionary<K, V>>>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl<K: DictionaryKey, V: DictionaryValue, Nulls: Nullability, Storage: Buffer>
+    FromIterator<Nulls::Item<Dictionary<K, V>>> for DictionaryEncoded<K, V, Nulls, Storage>
+where
+    Nulls::Collection<DictionaryKeys<K, V, Storage>, Storage>:
+        FromIterator<Nulls::Item<Dictionary<K, V>>>,
+{
+    fn from_iter<I: IntoIterator<Item = Nulls::Item<Dictionary<K, V>>>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<K: DictionaryKey, V: DictionaryValue, Nulls: Nullability, Storage: Buffer> Length
+    for DictionaryEncoded<K, V, Nulls, Storage>
+{
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<K: DictionaryKey, V: DictionaryValue, Nulls: Nullability, Storage: Buffer> Collection
+    for DictionaryEncoded<K, V, Nulls, Storage>
+{
+    type View<'collection>
+        =
+        <Nulls::Collection<DictionaryKeys<K, V, Storage>, Storage> as Collection>::View<'collection>
+    where
+        Self: 'collection;
+
+    type Owned = Nulls::Item<Dictionary<K, V>>;
+
+    fn view(&self, index: usize) -> Option<Self::View<'_>> {
+        self.0.view(index)
+    }
+
+    type Iter<'collection>
+        = <Window<Nulls::Collection<DictionaryKeys<K, V, Storage>, Storage>> as Collection>::Iter<
+        'collection,
+    >
+    where
+        Self: 'collection;
+
+    fn iter_views(&self) -> Self::Iter<'_> {
+        self.0.iter_views()
+    }
+
+    type IntoIter =
+        <Window<Nulls::Collection<DictionaryKeys<K, V, Storage>, Storage>> as Collection>::IntoIter;
+
+    fn into_iter_owned(self) -> Self::IntoIter {
+        self.0.into_iter_owned()
+    }
+}
+
+impl<K: DictionaryKey, V: DictionaryValue, Nulls: Nullability, Storage: Buffer> CollectionAllocIn
+    for DictionaryEncoded<K, V, Nulls, Storage>
+where
+    Nulls::Collection<DictionaryKeys<K, V, Storage>, Storage>: CollectionAllocIn,
+{
+    type Alloc =
+        <Nulls::Collection<DictionaryKeys<K, V, Storage>, Storage> as CollectionAllocIn>::Alloc;
+
+    fn with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Self {
+        Self(Window::with_capacity_in(capacity, alloc))
+    }
+
+    fn from_iter_in<I: IntoIterator<Item = Self::Owned>>(iter: I, alloc: Self::Alloc) -> Self {
+        Self(Window::from_iter_in(iter, alloc))
+    }
+
+    fn try_with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Result<Self, AllocError> {
+        Window::try_with_capacity_in(capacity, alloc).map(Self)
+    }
+
+    fn try_from_iter_in<I: IntoIterator<Item = Self::Owned>>(
+        iter: I,
+        alloc: Self::Alloc,
+    ) -> Result<Self, AllocError> {
+        Window::try_from_iter_in(iter, alloc).map(Self)
+    }
+}
+
+impl<K: DictionaryKey, V: DictionaryValue, Nulls: Nullability, Storage: Buffer> CollectionRealloc
+    for DictionaryEncoded<K, V, Nulls, Storage>
+where
+    Nulls::Collection<DictionaryKeys<K, V, Storage>, Storage>: CollectionRealloc,
+{
+    fn allocator(&self) -> Self::Alloc {
+        self.0.allocator()
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+        self.0.try_reserve(additional)
+    }
+
+    fn try_extend<I: IntoIterator<Item = Self::Owned>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), AllocError> {
+        self.0.try_extend(iter)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.0.truncate(len);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::{string::String, vec, vec::Vec};
+
+    use crate::{array::Array, collection::tests::round_trip, nullability::Nullable};
+
+    use super::*;
+
+    fn items<V>(values: impl IntoIterator<Item = V>) -> Vec<Dictionary<u16, V>> {
+        values.into_iter().map(Dictionary::new).collect()
+    }
+
+    #[test]
+    fn deduplicates() {
+        let keys = items(["a", "b", "a", "a", "c", "b"].map(String::from))
+            .into_iter()
+            .collect::<DictionaryKeys<u16, String>>();
+        assert_eq!(keys.buffer_ref(), &[0, 1, 0, 0, 2, 1]);
+        assert_eq!(
+            keys.child_ref().iter_views().collect::<Vec<_>>(),
+            ["a", "b", "c"]
+        );
+        assert_eq!(keys.view(4), Some("c"));
+    }
+
+    #[test]
+    fn deduplicates_against_parts() {
+        let mut dictionary =
+            DictionaryKeys::<u8, u32>::try_from_parts(vec![1, 0], [4, 3, 4].into_iter().collect())
+                .expect("valid");
+        dictionary.extend([4, 5].map(Dictionary::new));
+        let (keys, values) = dictionary.into_parts();
+        assert_eq!(keys, [1, 0, 0, 3]);
+        assert_eq!(values.into_buffer(), [4, 3, 4, 5]);
+    }
+
+    #[test]
+    fn deduplicates_against_clones() {
+        let source = [1, 2]
+            .map(Dictionary::new)
+            .into_iter()
+            .collect::<DictionaryKeys<u8, u32>>();
+        let mut clone = source.clone();
+        assert!(clone.index.is_empty());
+        clone.extend([2, 3].map(Dictionary::new));
+        let (keys, values) = clone.into_parts();
+        assert_eq!(keys, [0, 1, 1, 2]);
+        assert_eq!(values.into_buffer(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn views_lists_as_slices() {
+        let values = items([b"ab".to_vec(), b"c".to_vec(), b"ab".to_vec()])
+            .into_iter()
+            .collect::<Array<Dictionary<u16, Vec<u8>>>>();
+        let view: Option<&[u8]> = values.view(2);
+        assert_eq!(view, Some(b"ab".as_slice()));
+        assert_eq!(
+            values.iter_views().collect::<Vec<_>>(),
+            [b"ab".as_slice(), b"c", b"ab"]
+        );
+
+        let nullable = items([Some(vec![1_u16]), None, Some(vec![1])])
+            .into_iter()
+            .collect::<DictionaryKeys<u16, Option<Vec<u16>>>>();
+        assert_eq!(nullable.buffer_ref(), &[0, 1, 0]);
+        assert_eq!(nullable.view(2), Some(Some([1].as_slice())));
+    }
+
+    #[test]
+    fn try_push_key_overflow() {
+        let mut keys = (0..256)
+            .map(Dictionary::new)
+            .collect::<DictionaryKeys<u8, u32>>();
+        assert_eq!(keys.try_push(Dictionary::new(255)), Ok(255));
+        assert_eq!(
+            keys.try_push(Dictionary::new(256)),
+            Err(DictionaryKeysError::KeyOverflow { values: 256 })
+        );
+        assert_eq!(keys.len(), 257);
+        assert_eq!(keys.child_ref().len(), 256);
+    }
+
+    #[test]
+    fn try_from_parts_out_of_bounds() {
+        let error =
+            DictionaryKeys::<i8, u32>::try_from_parts(vec![0, -1], [1].into_iter().collect())
+                .expect_err("negative key");
+        assert_eq!(
+            error,
+            DictionaryKeysError::OutOfBounds {
+                index: 1,
+                values: 1
+            }
+        );
+    }
+
+    #[test]
+    fn from_buffer() {
+        let values = items([1_u32, 2, 1])
+            .into_iter()
+            .collect::<DictionaryEncoded<u16, u32>>();
+        let restored = DictionaryEncoded::<u16, u32>::from_buffer(values.into_buffer());
+        assert_eq!(restored.len(), 3);
+        assert_eq!(restored.owned(2), Some(Dictionary::new(1)));
+    }
+
+    #[test]
+    fn collection() {
+        round_trip::<DictionaryEncoded<_, _>, _>(items([1_u32, 2, 1, 1]));
+        round_trip::<DictionaryEncoded<_, _, Nullable>, _>(
+            items([1_u32, 2]).into_iter().map(Some).chain([None]),
+        );
+        round_trip::<DictionaryEncoded<_, _>, _>(items([vec![1_u8], vec![], vec![1]]));
+        round_trip::<DictionaryEncoded<_, _>, _>(items(["a", "a", "b"].map(String::from)));
+        round_trip::<DictionaryEncoded<_, _>, _>(items([Some(1_u8), None, Some(1)]));
+    }
+
+    #[test]
+    fn try_extend() {
+        let mut values = items([1_u32])
+            .into_iter()
+            .collect::<DictionaryEncoded<u16, u32>>();
+        CollectionRealloc::try_extend(&mut values, items([2, 1])).expect("reserved");
+        assert_eq!(values.iter_views().collect::<Vec<_>>(), [1, 2, 1]);
+        CollectionRealloc::truncate(&mut values, 1);
+        assert_eq!(values.len(), 1);
+        assert_eq!(values.buffer_ref().child_ref().len(), 2);
+    }
+
+    #[test]
+    fn try_extend_key_overflow() {
+        let mut values = [1_u32, 2, 1]
+            .map(Dictionary::<u8, _>::new)
+            .into_iter()
+            .collect::<DictionaryEncoded<u8, u32>>();
+        assert_eq!(
+            CollectionRealloc::try_extend(&mut values, (0..300).map(Dictionary::new)),
+            Err(AllocError)
+        );
+        assert_eq!(values.iter_views().collect::<Vec<_>>(), [1, 2, 1]);
+        assert_eq!(
+            values
+                .buffer_ref()
+                .child_ref()
+                .iter_views()
+                .collect::<Vec<_>>(),
+            [1, 2]
+        );
+
+        CollectionRealloc::try_extend(&mut values, [2, 3].map(Dictionary::new)).expect("in range");
+        assert_eq!(values.buffer_ref().buffer_ref(), &[0, 1, 0, 1, 2]);
+
+        let mut nullable = [Some(Dictionary::<u8, _>::new(1_u32)), None]
+            .into_iter()
+            .collect::<DictionaryEncoded<u8, u32, Nullable>>();
+        assert_eq!(
+            CollectionRealloc::try_extend(
+                &mut nullable,
+                (0..300).map(|value| Some(Dictionary::new(value)))
+            ),
+            Err(AllocError)
+        );
+        assert_eq!(nullable.iter_views().collect::<Vec<_>>(), [Some(1), None]);
+    }
+}
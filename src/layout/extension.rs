@@ -0,0 +1,863 @@
+//! Extension types stored in the layout of another type.
+
+extern crate alloc;
+
+use alloc::string::String;
+use core::{
+    fmt::{self, Debug, Display},
+    iter::Map,
+    marker::PhantomData,
+    ops::Deref,
+};
+
+use crate::{
+    buffer::{Buffer, BufferRef, VecBuffer},
+    collection::{
+        AllocError, Collection, CollectionAllocIn, CollectionRealloc, owned::IntoOwned,
+        view::AsView, window::Window,
+    },
+    fixed_size::FixedSizeArray,
+    layout::{Layout, MemoryLayout},
+    length::Length,
+    nullability::{NonNullable, Nullability},
+};
+
+/// A logical item type stored as items of another type.
+///
+/// Arrow extension types give an existing storage type a new meaning, like a
+/// UUID stored as 16 bytes. They are identified by the
+/// `ARROW:extension:name` and `ARROW:extension:metadata` keys in the metadata
+/// of a field, so the physical layout is the layout of the storage type. An
+/// extension item type selects the [`Extension`] layout, which stores its
+/// items in the layout of [`ExtensionType::StorageType`]:
+///
+/// ```text
+/// T: ExtensionType -> Extension<T> -> Layout::Memory of T::StorageType
+/// ```
+///
+/// Null items are stored as `T::default()`, so nullable extension items
+/// implement [`Default`].
+///
+/// The canonical [`Uuid`] (`arrow.uuid`) and [`Json`] (`arrow.json`)
+/// extension types are defined in this module.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{
+///     array::Array,
+///     buffer::Buffer,
+///     collection::Collection,
+///     fixed_size::FixedSizeArray,
+///     layout::{
+///         Layout, NullableLayout,
+///         extension::{Extension, ExtensionType},
+///     },
+///     nullability::Nullability,
+/// };
+///
+/// #[derive(Clone, Copy, Debug, Default, PartialEq)]
+/// struct Point {
+///     x: f64,
+///     y: f64,
+/// }
+///
+/// impl ExtensionType for Point {
+///     type StorageType = FixedSizeArray<f64, 2>;
+///
+///     const NAME: &'static str = "geo.point";
+///     const METADATA: &'static str = r#"{"crs":"EPSG:4326"}"#;
+///
+///     fn from_storage(storage: Self::StorageType) -> Self {
+///         let [x, y] = *storage;
+///         Self { x, y }
+///     }
+///
+///     fn into_storage(self) -> Self::StorageType {
+///         FixedSizeArray::from([self.x, self.y])
+///     }
+/// }
+///
+/// impl Layout for Point {
+///     type Memory<Nulls: Nullability, Storage: Buffer> = Extension<Self, Nulls, Storage>;
+/// }
+///
+/// impl NullableLayout for Point {}
+///
+/// let points = [Some(Point { x: 1.0, y: 2.0 }), None]
+///     .into_iter()
+///     .collect::<Array<Option<Point>>>();
+/// assert_eq!(points.owned(0), Some(Some(Point { x: 1.0, y: 2.0 })));
+/// assert_eq!(points.owned(1), Some(None));
+/// ```
+pub trait ExtensionType: Sized {
+    /// The item type whose layout stores items of this type.
+    type StorageType: Layout;
+
+    /// The name of this extension type, stored in `ARROW:extension:name`.
+    const NAME: &'static str;
+
+    /// The serialized metadata of this extension type, stored in
+    /// `ARROW:extension:metadata`.
+    const METADATA: &'static str = "";
+
+    /// Converts an item of the storage type into an item of this type.
+    fn from_storage(storage: Self::StorageType) -> Self;
+
+    /// Converts this item into an item of the storage type.
+    fn into_storage(self) -> Self::StorageType;
+}
+
+/// The non-nullable layout that stores items of the storage type of `T`.
+type StorageMemory<T, Storage> =
+    <<T as ExtensionType>::StorageType as Layout>::Memory<NonNullable, Storage>;
+
+/// A view of an item of an extension type.
+///
+/// This wraps the view of the storage type, which converts into an owned item
+/// of the extension type.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{
+///     collection::{Collection, owned::IntoOwned},
+///     layout::extension::{Extension, Json},
+/// };
+///
+/// let values = [Json::from("{}")].into_iter().collect::<Extension<Json>>();
+/// let view = values.view(0).unwrap();
+/// assert_eq!(view.storage(), "{}");
+/// let owned: Json = view.into_owned();
+/// assert_eq!(owned, Json::from("{}"));
+/// ```
+pub struct ExtensionView<T, View>(View, PhantomData<fn() -> T>);
+
+impl<T, View> ExtensionView<T, View> {
+    /// Wraps a view of the storage type.
+    const fn new(view: View) -> Self {
+        Self(view, PhantomData)
+    }
+
+    /// Returns the view of the storage type.
+    #[must_use]
+    pub fn storage(self) -> View {
+        self.0
+    }
+}
+
+impl<T, View: Clone> Clone for ExtensionView<T, View> {
+    fn clone(&self) -> Self {
+        Self::new(self.0.clone())
+    }
+}
+
+impl<T, View: Copy> Copy for ExtensionView<T, View> {}
+
+impl<T, View: Debug> Debug for ExtensionView<T, View> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ExtensionView").field(&self.0).finish()
+    }
+}
+
+impl<T: ExtensionType, View: IntoOwned<T::StorageType>> IntoOwned<T> for ExtensionView<T, View> {
+    fn into_owned(self) -> T {
+        T::from_storage(self.0.into_owned())
+    }
+}
+
+/// Items of an extension type, stored in the non-nullable layout of its
+/// storage type.
+///
+/// Items are converted with [`ExtensionType::from_storage`] and
+/// [`ExtensionType::into_storage`].
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{
+///     collection::Collection,
+///     layout::extension::{ExtensionValues, Json},
+/// };
+///
+/// let values = [Json::from("[]")].into_iter().collect::<ExtensionValues<Json>>();
+/// assert_eq!(values.into_buffer().owned(0), Some("[]".to_owned()));
+/// ```
+pub struct ExtensionValues<T: ExtensionType, Storage: Buffer = VecBuffer>(
+    StorageMemory<T, Storage>,
+);
+
+impl<T: ExtensionType, Storage: Buffer> ExtensionValues<T, Storage> {
+    /// Constructs [`ExtensionValues`] from the layout of their storage type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{
+    ///     collection::Collection,
+    ///     layout::{extension::{ExtensionValues, Json}, utf8::Utf8},
+    /// };
+    ///
+    /// let storage = ["[]".to_owned()].into_iter().collect::<Utf8>();
+    /// let values = ExtensionValues::<Json>::from_buffer(storage);
+    /// assert_eq!(values.owned(0), Some(Json::from("[]")));
+    /// ```
+    #[must_use]
+    pub fn from_buffer(buffer: StorageMemory<T, Storage>) -> Self {
+        Self(buffer)
+    }
+
+    /// Returns the layout of the storage type of these [`ExtensionValues`].
+    ///
+    /// This is the inverse of [`ExtensionValues::from_buffer`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{
+    ///     collection::Collection,
+    ///     layout::extension::{ExtensionValues, Json},
+    /// };
+    ///
+    /// let values = [Json::from("1")].into_iter().collect::<ExtensionValues<Json>>();
+    /// assert_eq!(values.into_buffer().owned(0), Some("1".to_owned()));
+    /// ```
+    #[must_use]
+    pub fn into_buffer(self) -> StorageMemory<T, Storage> {
+        self.0
+    }
+}
+
+impl<T: ExtensionType, Storage: Buffer> BufferRef for ExtensionValues<T, Storage> {
+    type Buffer = StorageMemory<T, Storage>;
+
+    fn buffer_ref(&self) -> &Self::Buffer {
+        &self.0
+    }
+}
+
+impl<T: ExtensionType, Storage: Buffer> Debug for ExtensionValues<T, Storage>
+where
+    StorageMemory<T, Storage>: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ExtensionValues").field(&self.0).finish()
+    }
+}
+
+impl<T: ExtensionType, Storage: Buffer> Clone for ExtensionValues<T, Storage>
+where
+    StorageMemory<T, Storage>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: ExtensionType, Storage: Buffer> Default for ExtensionValues<T, Storage>
+where
+    StorageMemory<T, Storage>: Default,
+{
+    fn default() -> Self {
+        Self(Default::default())
+    }
+}
+
+impl<T: ExtensionType, Storage: Buffer> Extend<T> for ExtensionValues<T, Storage>
+where
+    StorageMemory<T, Storage>: Extend<T::StorageType>,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0.extend(iter.into_iter().map(T::into_storage));
+    }
+}
+
+impl<T: ExtensionType, Storage: Buffer> FromIterator<T> for ExtensionValues<T, Storage>
+where
+    StorageMemory<T, Storage>: FromIterator<T::StorageType>,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self(iter.into_iter().map(T::into_storage).collect())
+    }
+}
+
+impl<T: ExtensionType, Storage: Buffer> Length for ExtensionValues<T, Storage> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<T: ExtensionType, Storage: Buffer> Collection for ExtensionValues<T, Storage> {
+    type View<'collection>
+        = ExtensionView<T, <StorageMemory<T, Storage> as Collection>::View<'collection>>
+    where
+        Self: 'collection;
+
+    type Owned = T;
+
+    fn view(&self, index: usize) -> Option<Self::View<'_>> {
+        self.0.view(index).map(ExtensionView::new)
+    }
+
+    type Iter<'collection>
+        = Map<
+        <StorageMemory<T, Storage> as Collection>::Iter<'collection>,
+        fn(<StorageMemory<T, Storage> as Collection>::View<'collection>) -> Self::View<'collection>,
+    >
+    where
+        Self: 'collection;
+
+    fn iter_views(&self) -> Self::Iter<'_> {
+        self.0.iter_views().map(ExtensionView::new)
+    }
+
+    type IntoIter =
+        Map<<StorageMemory<T, Storage> as Collection>::IntoIter, fn(T::StorageType) -> T>;
+
+    fn into_iter_owned(self) -> Self::IntoIter {
+        self.0.into_iter_owned().map(T::from_storage)
+    }
+}
+
+impl<T: ExtensionType, Storage: Buffer> CollectionAllocIn for ExtensionValues<T, Storage>
+where
+    StorageMemory<T, Storage>: CollectionAllocIn,
+{
+    type Alloc = <StorageMemory<T, Storage> as CollectionAllocIn>::Alloc;
+
+    fn with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Self {
+        Self(CollectionAllocIn::with_capacity_in(capacity, alloc))
+    }
+
+    fn from_iter_in<I: IntoIterator<Item = Self::Owned>>(iter: I, alloc: Self::Alloc) -> Self {
+        Self(CollectionAllocIn::from_iter_in(
+            iter.into_iter().map(T::into_storage),
+            alloc,
+        ))
+    }
+
+    fn try_with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Result<Self, AllocError> {
+        CollectionAllocIn::try_with_capacity_in(capacity, alloc).map(Self)
+    }
+
+    fn try_from_iter_in<I: IntoIterator<Item = Self::Owned>>(
+        iter: I,
+        alloc: Self::Alloc,
+    ) -> Result<Self, AllocError> {
+        CollectionAllocIn::try_from_iter_in(iter.into_iter().map(T::into_storage), alloc).map(Self)
+    }
+}
+
+impl<T: ExtensionType, Storage: Buffer> CollectionRealloc for ExtensionValues<T, Storage>
+where
+    StorageMemory<T, Storage>: CollectionRealloc,
+{
+    fn allocator(&self) -> Self::Alloc {
+        self.0.allocator()
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+        self.0.try_reserve(additional)
+    }
+
+    fn try_extend<I: IntoIterator<Item = Self::Owned>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), AllocError> {
+        self.0.try_extend(iter.into_iter().map(T::into_storage))
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.0.truncate(len);
+    }
+}
+
+/// A collection of extension type items.
+///
+/// <https://arrow.apache.org/docs/format/Columnar.html#extension-types>
+///
+/// This is [`ExtensionValues`] with outer nullability. The values are stored
+/// in the layout of [`ExtensionType::StorageType`], and validity applies to
+/// them like it applies to the storage type: null items are stored as
+/// `T::default()`.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{
+///     collection::Collection,
+///     layout::extension::{Extension, Uuid},
+///     nullability::Nullable,
+/// };
+///
+/// let uuid = Uuid::from_bytes([1; 16]);
+/// let values = [Some(uuid), None].into_iter().collect::<Extension<Uuid, Nullable>>();
+/// assert_eq!(values.owned(0), Some(Some(uuid)));
+/// assert_eq!(values.owned(1), Some(None));
+/// ```
+pub struct Extension<
+    T: ExtensionType,
+    Nulls: Nullability = NonNullable,
+    Storage: Buffer = VecBuffer,
+>(Window<Nulls::Collection<ExtensionValues<T, Storage>, Storage>>);
+
+impl<T: ExtensionType, Nulls: Nullability, Storage: Buffer> MemoryLayout
+    for Extension<T, Nulls, Storage>
+{
+    fn slice(self, offset: usize, len: usize) -> Self {
+        Self(self.0.slice(offset, len))
+    }
+
+    fn offset(&self) -> usize {
+        self.0.offset()
+    }
+}
+
+impl<T: ExtensionType, Nulls: Nullability, Storage: Buffer> Extension<T, Nulls, Storage> {
+    /// Constructs an [`Extension`] from its backing collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{
+    ///     collection::Collection,
+    ///     layout::extension::{Extension, ExtensionValues, Json},
+    ///     nullability::NonNullable,
+    /// };
+    ///
+    /// let values = [Json::from("[]")].into_iter().collect::<ExtensionValues<Json>>();
+    /// let values = Extension::<Json, NonNullable>::from_buffer(values);
+    /// assert_eq!(values.owned(0), Some(Json::from("[]")));
+    /// ```
+    #[must_use]
+    pub fn from_buffer(buffer: Nulls::Collection<ExtensionValues<T, Storage>, Storage>) -> Self {
+        Self(Window::new(buffer))
+    }
+
+    /// Returns the backing collection of this [`Extension`].
+    ///
+    /// This is the inverse of [`Extension::from_buffer`]. The backing
+    /// collection of a slice still contains the items outside of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{
+    ///     collection::Collection,
+    ///     layout::extension::{Extension, Json},
+    /// };
+    ///
+    /// let values = [Json::from("1")].into_iter().collect::<Extension<Json>>();
+    /// assert_eq!(values.into_buffer().into_buffer().owned(0), Some("1".to_owned()));
+    /// ```
+    #[must_use]
+    pub fn into_buffer(self) -> Nulls::Collection<ExtensionValues<T, Storage>, Storage> {
+        self.0.into_parts().0
+    }
+}
+
+impl<T: ExtensionType, Nulls: Nullability, Storage: Buffer> BufferRef
+    for Extension<T, Nulls, Storage>
+{
+    type Buffer = Nulls::Collection<ExtensionValues<T, Storage>, Storage>;
+
+    fn buffer_ref(&self) -> &Self::Buffer {
+        self.0.buffer_ref()
+    }
+}
+
+impl<T: ExtensionType, Nulls: Nullability, Storage: Buffer> Debug for Extension<T, Nulls, Storage>
+where
+    Nulls::Collection<ExtensionValues<T, Storage>, Storage>: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Extension").field(&self.0).finish()
+    }
+}
+
+impl<T: ExtensionType, Nulls: Nullability, Storage: Buffer> Clone for Extension<T, Nulls, Storage>
+where
+    Nulls::Collection<ExtensionValues<T, Storage>, Storage>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: ExtensionType, Nulls: Nullability, Storage: Buffer> Default for Extension<T, Nulls, Storage>
+where
+    Nulls::Collection<ExtensionValues<T, Storage>, Storage>: Default,
+{
+    fn default() -> Self {
+        Self(Window::default())
+    }
+}
+
+impl<T: ExtensionType, Nulls: Nullability, Storage: Buffer> Extend<Nulls::Item<T>>
+    for Extension<T, Nulls, Storage>
+where
+    Window<Nulls::Collection<ExtensionValues<T, Storage>, Storage>>: Extend<Nulls::Item<T>>,
+{
+    fn extend<I: IntoIterator<Item = Nulls::Item<T>>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl<T: ExtensionType, Nulls: Nullability, Storage: Buffer> FromIterator<Nulls::Item<T>>
+    for Extension<T, Nulls, Storage>
+where
+    Nulls::Collection<ExtensionValues<T, Storage>, Storage>: FromIterator<Nulls::Item<T>>,
+{
+    fn from_iter<I: IntoIterator<Item = Nulls::Item<T>>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<T: ExtensionType, Nulls: Nullability, Storage: Buffer> Length
+    for Extension<T, Nulls, Storage>
+{
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<T: ExtensionType, Nulls: Nullability, Storage: Buffer> Collection
+    for Extension<T, Nulls, Storage>
+{
+    type View<'collection>
+        = <Nulls::Collection<ExtensionValues<T, Storage>, Storage> as Collection>::View<'collection>
+    where
+        Self: 'collection;
+
+    type Owned = Nulls::Item<T>;
+
+    fn view(&self, index: usize) -> Option<Self::View<'_>> {
+        self.0.view(index)
+    }
+
+    type Iter<'collection>
+        = <Window<Nulls::Collection<ExtensionValues<T, Storage>, Storage>> as Collection>::Iter<
+        'collection,
+    >
+    where
+        Self: 'collection;
+
+    fn iter_views(&self) -> Self::Iter<'_> {
+        self.0.iter_views()
+    }
+
+    type IntoIter =
+        <Window<Nulls::Collection<ExtensionValues<T, Storage>, Storage>> as Collection>::IntoIter;
+
+    fn into_iter_owned(self) -> Self::IntoIter {
+        self.0.into_iter_owned()
+    }
+}
+
+impl<T: ExtensionType, Nulls: Nullability, Storage: Buffer> CollectionAllocIn
+    for Extension<T, Nulls, Storage>
+where
+    Nulls::Collection<ExtensionValues<T, Storage>, Storage>: CollectionAllocIn,
+{
+    type Alloc =
+        <Nulls::Collection<ExtensionValues<T, Storage>, Storage> as CollectionAllocIn>::Alloc;
+
+    fn with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Self {
+        Self(Window::with_capacity_in(capacity, alloc))
+    }
+
+    fn from_iter_in<I: IntoIterator<Item = Self::Owned>>(iter: I, alloc: Self::Alloc) -> Self {
+        Self(Window::from_iter_in(iter, alloc))
+    }
+
+    fn try_with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Result<Self, AllocError> {
+        Window::try_with_capacity_in(capacity, alloc).map(Self)
+    }
+
+    fn try_from_iter_in<I: IntoIterator<Item = Self::Owned>>(
+        iter: I,
+        alloc: Self::Alloc,
+    ) -> Result<Self, AllocError> {
+        Window::try_from_iter_in(iter, alloc).map(Self)
+    }
+}
+
+impl<T: ExtensionType, Nulls: Nullability, Storage: Buffer> CollectionRealloc
+    for Extension<T, Nulls, Storage>
+where
+    Nulls::Collection<ExtensionValues<T, Storage>, Storage>: CollectionRealloc,
+{
+    fn allocator(&self) -> Self::Alloc {
+        self.0.allocator()
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+        self.0.try_reserve(additional)
+    }
+
+    fn try_extend<I: IntoIterator<Item = Self::Owned>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), AllocError> {
+        self.0.try_extend(iter)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.0.truncate(len);
+    }
+}
+
+/// A universally unique identifier, the canonical `arrow.uuid` extension
+/// type.
+///
+/// The 16 bytes of the identifier are stored as a fixed-size binary, in the
+/// order in which they are written in the hyphenated form.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{array::Array, collection::Collection, layout::extension::Uuid};
+///
+/// let uuid = Uuid::from_bytes([0xab; 16]);
+/// assert_eq!(uuid.to_string(), "abababab-abab-abab-abab-abababababab");
+///
+/// let values = [uuid].into_iter().collect::<Array<Uuid>>();
+/// assert_eq!(values.owned(0), Some(uuid));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Uuid([u8; 16]);
+
+impl Uuid {
+    /// Constructs a [`Uuid`] from its bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::layout::extension::Uuid;
+    ///
+    /// let uuid = Uuid::from_bytes([1; 16]);
+    /// assert_eq!(uuid.as_bytes(), &[1; 16]);
+    /// ```
+    #[must_use]
+    pub const fn from_bytes(bytes: [u8; 16]) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns the bytes of this [`Uuid`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::layout::extension::Uuid;
+    ///
+    /// assert_eq!(Uuid::default().as_bytes(), &[0; 16]);
+    /// ```
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8; 16] {
+        &self.0
+    }
+}
+
+impl Display for Uuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, byte) in self.0.iter().enumerate() {
+            if matches!(index, 4 | 6 | 8 | 10) {
+                f.write_str("-")?;
+            }
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+impl From<[u8; 16]> for Uuid {
+    fn from(value: [u8; 16]) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Uuid> for [u8; 16] {
+    fn from(value: Uuid) -> Self {
+        value.0
+    }
+}
+
+impl IntoOwned<Uuid> for Uuid {
+    fn into_owned(self) -> Uuid {
+        self
+    }
+}
+
+impl AsView<'_> for Uuid {
+    type View = Self;
+
+    fn as_view(&self) -> Self::View {
+        *self
+    }
+}
+
+impl ExtensionType for Uuid {
+    type StorageType = FixedSizeArray<u8, 16>;
+
+    const NAME: &'static str = "arrow.uuid";
+
+    fn from_storage(storage: Self::StorageType) -> Self {
+        Self(*storage)
+    }
+
+    fn into_storage(self) -> Self::StorageType {
+        FixedSizeArray::from(self.0)
+    }
+}
+
+/// A JSON text, the canonical `arrow.json` extension type.
+///
+/// The text is stored as a UTF-8 string. It is not parsed, so it is not
+/// validated to be JSON either.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{array::Array, collection::Collection, layout::extension::Json};
+///
+/// let values = [Some(Json::from(r#"{"a":1}"#)), None]
+///     .into_iter()
+///     .collect::<Array<Option<Json>>>();
+/// assert_eq!(values.owned(0), Some(Some(Json::from(r#"{"a":1}"#))));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Json(String);
+
+impl Json {
+    /// Wraps a JSON `text`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::layout::extension::Json;
+    ///
+    /// let json = Json::new("[1, 2]".to_owned());
+    /// assert_eq!(&*json, "[1, 2]");
+    /// ```
+    #[must_use]
+    pub const fn new(text: String) -> Self {
+        Self(text)
+    }
+
+    /// Returns the wrapped text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::layout::extension::Json;
+    ///
+    /// assert_eq!(Json::from("null").into_inner(), "null");
+    /// ```
+    #[must_use]
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl Deref for Json {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<String> for Json {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for Json {
+    fn from(value: &str) -> Self {
+        Self(value.into())
+    }
+}
+
+impl From<Json> for String {
+    fn from(value: Json) -> Self {
+        value.0
+    }
+}
+
+impl<'collection> AsView<'collection> for Json {
+    type View = &'collection str;
+
+    fn as_view(&'collection self) -> Self::View {
+        &self.0
+    }
+}
+
+impl ExtensionType for Json {
+    type StorageType = String;
+
+    const NAME: &'static str = "arrow.json";
+
+    fn from_storage(storage: Self::StorageType) -> Self {
+        Self(storage)
+    }
+
+    fn into_storage(self) -> Self::StorageType {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::ToString, vec::Vec};
+
+    use crate::{collection::tests::round_trip, nullability::Nullable};
+
+    use super::*;
+
+    #[test]
+    fn collection() {
+        let uuid = Uuid::from_bytes([1; 16]);
+        round_trip::<Extension<Uuid>, _>([uuid, Uuid::default()]);
+        round_trip::<Extension<Uuid, Nullable>, _>([None, Some(uuid)]);
+        round_trip::<Extension<Json>, _>([Json::from("{}"), Json::from("[]")]);
+        round_trip::<Extension<Json, Nullable>, _>([Some(Json::from("1")), None]);
+    }
+
+    #[test]
+    fn extend_and_slice() {
+        let mut values = [Json::from("1")].into_iter().collect::<Extension<Json>>();
+        values.extend([Json::from("2")]);
+        CollectionRealloc::try_extend(&mut values, [Json::from("3")]).expect("reserved");
+        let slice = values.slice(1, 2);
+        assert_eq!(
+            slice
+                .iter_views()
+                .map(ExtensionView::storage)
+                .collect::<Vec<_>>(),
+            ["2", "3"]
+        );
+        assert_eq!(slice.offset(), 1);
+        assert_eq!(
+            slice.into_iter_owned().collect::<Vec<_>>(),
+            [Json::from("2"), Json::from("3")]
+        );
+    }
+
+    #[test]
+    fn uuid_display() {
+        let uuid = Uuid::from_bytes([
+            0x67, 0xe5, 0x50, 0x44, 0x10, 0xb1, 0x42, 0x6f, 0x92, 0x47, 0xbb, 0x68, 0x0e, 0x5f,
+            0xe0, 0xc8,
+        ]);
+        assert_eq!(uuid.to_string(), "67e55044-10b1-426f-9247-bb680e5fe0c8");
+    }
+}
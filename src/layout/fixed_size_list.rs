@@ -2,7 +2,10 @@ use core::fmt::Debug;
 
 use crate::{
     buffer::{Buffer, BufferRef, VecBuffer},
-    collection::{AllocError, Collection, CollectionAllocIn, CollectionRealloc, flatten::Flatten},
+    collection::{
+        AllocError, Collection, CollectionAllocIn, CollectionRealloc, flatten::Flatten,
+        window::Window,
+    },
     layout::{ArrayItem, MemoryLayout},
     length::Length,
     nullability::{NonNullable, Nullability},
@@ -30,16 +33,24 @@ use crate::{
 /// let values = [Some([1, 2]), None].into_iter().collect::<FixedSizeList<i32, 2, Nullable>>();
 /// assert_eq!(values.owned(1), Some(None));
 /// ```
+#[expect(clippy::type_complexity, reason = "nullable slice of nested children")]
 pub struct FixedSizeList<
     T: ArrayItem,
     const N: usize,
     Nulls: Nullability = NonNullable,
     Storage: Buffer = VecBuffer,
->(Nulls::Collection<Flatten<T::Memory<Storage>, N>, Storage>);
+>(Window<Nulls::Collection<Flatten<T::Memory<Storage>, N>, Storage>>);
 
 impl<T: ArrayItem, const N: usize, Nulls: Nullability, Storage: Buffer> MemoryLayout
     for FixedSizeList<T, N, Nulls, Storage>
 {
+    fn slice(self, offset: usize, len: usize) -> Self {
+        Self(self.0.slice(offset, len))
+    }
+
+    fn offset(&self) -> usize {
+        self.0.offset()
+    }
 }
 
 impl<T: ArrayItem, const N: usize, Nulls: Nullability, Storage: Buffer>
@@ -58,12 +69,13 @@ impl<T: ArrayItem, const N: usize, Nulls: Nullability, Storage: Buffer>
     /// ```
     #[must_use]
     pub fn from_buffer(buffer: Nulls::Collection<Flatten<T::Memory<Storage>, N>, Storage>) -> Self {
-        Self(buffer)
+        Self(Window::new(buffer))
     }
 
     /// Returns the backing collection of this [`FixedSizeList`].
     ///
-    /// This is the inverse of [`FixedSizeList::from_buffer`].
+    /// This is the inverse of [`FixedSizeList::from_buffer`]. The backing
+    /// collection of a slice still contains the items outside of it.
     ///
     /// # Examples
     ///
@@ -75,7 +87,7 @@ impl<T: ArrayItem, const N: usize, Nulls: Nullability, Storage: Buffer>
     /// ```
     #[must_use]
     pub fn into_buffer(self) -> Nulls::Collection<Flatten<T::Memory<Storage>, N>, Storage> {
-        self.0
+        self.0.into_parts().0
     }
 }
 
@@ -85,7 +97,7 @@ impl<T: ArrayItem, const N: usize, Nulls: Nullability, Storage: Buffer> BufferRe
     type Buffer = Nulls::Collection<Flatten<T::Memory<Storage>, N>, Storage>;
 
     fn buffer_ref(&self) -> &Self::Buffer {
-        &self.0
+        self.0.buffer_ref()
     }
 }
 
@@ -115,14 +127,14 @@ where
     Nulls::Collection<Flatten<T::Memory<Storage>, N>, Storage>: Default,
 {
     fn default() -> Self {
-        Self(Default::default())
+        Self(Window::default())
     }
 }
 
 impl<T: ArrayItem, const N: usize, Nulls: Nullability, Storage: Buffer> Extend<Nulls::Item<[T; N]>>
     for FixedSizeList<T, N, Nulls, Storage>
 where
-    Nulls::Collection<Flatten<T::Memory<Storage>, N>, Storage>: Extend<Nulls::Item<[T; N]>>,
+    Window<Nulls::Collection<Flatten<T::Memory<Storage>, N>, Storage>>: Extend<Nulls::Item<[T; N]>>,
 {
     fn extend<I: IntoIterator<Item = Nulls::Item<[T; N]>>>(&mut self, iter: I) {
         self.0.extend(iter);
@@ -164,7 +176,7 @@ impl<T: ArrayItem, const N: usize, Nulls: Nullability, Storage: Buffer> Collecti
     }
 
     type Iter<'collection>
-        = <Nulls::Collection<Flatten<T::Memory<Storage>, N>, Storage> as Collection>::Iter<
+        = <Window<Nulls::Collection<Flatten<T::Memory<Storage>, N>, Storage>> as Collection>::Iter<
         'collection,
     >
     where
@@ -175,7 +187,7 @@ impl<T: ArrayItem, const N: usize, Nulls: Nullability, Storage: Buffer> Collecti
     }
 
     type IntoIter =
-        <Nulls::Collection<Flatten<T::Memory<Storage>, N>, Storage> as Collection>::IntoIter;
+        <Window<Nulls::Collection<Flatten<T::Memory<Storage>, N>, Storage>> as Collection>::IntoIter;
 
     fn into_iter_owned(self) -> Self::IntoIter {
         self.0.into_iter_owned()
@@ -191,32 +203,22 @@ where
         <Nulls::Collection<Flatten<T::Memory<Storage>, N>, Storage> as CollectionAllocIn>::Alloc;
 
     fn with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Self {
-        Self(
-            Nulls::Collection::<Flatten<T::Memory<Storage>, N>, Storage>::with_capacity_in(
-                capacity, alloc,
-            ),
-        )
+        Self(Window::with_capacity_in(capacity, alloc))
     }
 
     fn from_iter_in<I: IntoIterator<Item = Self::Owned>>(iter: I, alloc: Self::Alloc) -> Self {
-        Self(
-            Nulls::Collection::<Flatten<T::Memory<Storage>, N>, Storage>::from_iter_in(iter, alloc),
-        )
+        Self(Window::from_iter_in(iter, alloc))
     }
 
     fn try_with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Result<Self, AllocError> {
-        Nulls::Collection::<Flatten<T::Memory<Storage>, N>, Storage>::try_with_capacity_in(
-            capacity, alloc,
-        )
-        .map(Self)
+        Window::try_with_capacity_in(capacity, alloc).map(Self)
     }
 
     fn try_from_iter_in<I: IntoIterator<Item = Self::Owned>>(
         iter: I,
         alloc: Self::Alloc,
     ) -> Result<Self, AllocError> {
-        Nulls::Collection::<Flatten<T::Memory<Storage>, N>, Storage>::try_from_iter_in(iter, alloc)
-            .map(Self)
+        Window::try_from_iter_in(iter, alloc).map(Self)
     }
 }
 
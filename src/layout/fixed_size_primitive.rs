@@ -1,8 +1,8 @@
-use core::fmt::Debug;
+use core::{borrow::Borrow, fmt::Debug};
 
 use crate::{
     buffer::{Buffer, BufferRef, VecBuffer},
-    collection::{AllocError, Collection, CollectionAllocIn, CollectionRealloc},
+    collection::{AllocError, Collection, CollectionAllocIn, CollectionRealloc, window::Window},
     fixed_size::FixedSize,
     layout::MemoryLayout,
     length::Length,
@@ -31,11 +31,18 @@ pub struct FixedSizePrimitive<
     T: FixedSize,
     Nulls: Nullability = NonNullable,
     Storage: Buffer = VecBuffer,
->(Nulls::Collection<Storage::For<T>, Storage>);
+>(Window<Nulls::Collection<Storage::For<T>, Storage>>);
 
 impl<T: FixedSize, Nulls: Nullability, Storage: Buffer> MemoryLayout
     for FixedSizePrimitive<T, Nulls, Storage>
 {
+    fn slice(self, offset: usize, len: usize) -> Self {
+        Self(self.0.slice(offset, len))
+    }
+
+    fn offset(&self) -> usize {
+        self.0.offset()
+    }
 }
 
 impl<T: FixedSize, Nulls: Nullability, Storage: Buffer> FixedSizePrimitive<T, Nulls, Storage> {
@@ -51,12 +58,13 @@ impl<T: FixedSize, Nulls: Nullability, Storage: Buffer> FixedSizePrimitive<T, Nu
     /// ```
     #[must_use]
     pub fn from_buffer(buffer: Nulls::Collection<Storage::For<T>, Storage>) -> Self {
-        Self(buffer)
+        Self(Window::new(buffer))
     }
 
     /// Returns the backing collection of this [`FixedSizePrimitive`].
     ///
-    /// This is the inverse of [`FixedSizePrimitive::from_buffer`].
+    /// This is the inverse of [`FixedSizePrimitive::from_buffer`]. The backing
+    /// collection of a slice still contains the items outside of it.
     ///
     /// # Examples
     ///
@@ -68,7 +76,15 @@ impl<T: FixedSize, Nulls: Nullability, Storage: Buffer> FixedSizePrimitive<T, Nu
     /// ```
     #[must_use]
     pub fn into_buffer(self) -> Nulls::Collection<Storage::For<T>, Storage> {
-        self.0
+        self.0.into_parts().0
+    }
+}
+
+impl<T: FixedSize, Storage: Buffer> FixedSizePrimitive<T, NonNullable, Storage> {
+    /// Returns the values of this [`FixedSizePrimitive`] as a slice.
+    pub(crate) fn values(&self) -> &[T] {
+        let values: &[T] = self.buffer_ref().borrow();
+        &values[self.offset()..self.offset().strict_add(self.len())]
     }
 }
 
@@ -78,7 +94,7 @@ impl<T: FixedSize, Nulls: Nullability, Storage: Buffer> BufferRef
     type Buffer = Nulls::Collection<Storage::For<T>, Storage>;
 
     fn buffer_ref(&self) -> &Self::Buffer {
-        &self.0
+        self.0.buffer_ref()
     }
 }
 
@@ -92,13 +108,23 @@ where
     }
 }
 
+impl<T: FixedSize, Nulls: Nullability, Storage: Buffer> Clone
+    for FixedSizePrimitive<T, Nulls, Storage>
+where
+    Nulls::Collection<Storage::For<T>, Storage>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
 impl<T: FixedSize, Nulls: Nullability, Storage: Buffer> Default
     for FixedSizePrimitive<T, Nulls, Storage>
 where
     Nulls::Collection<Storage::For<T>, Storage>: Default,
 {
     fn default() -> Self {
-        Self(Default::default())
+        Self(Window::default())
     }
 }
 
@@ -123,7 +149,7 @@ where
 impl<T: FixedSize, Nulls: Nullability, Storage: Buffer> Extend<Nulls::Item<T>>
     for FixedSizePrimitive<T, Nulls, Storage>
 where
-    Nulls::Collection<Storage::For<T>, Storage>: Extend<Nulls::Item<T>>,
+    Window<Nulls::Collection<Storage::For<T>, Storage>>: Extend<Nulls::Item<T>>,
 {
     fn extend<I: IntoIterator<Item = Nulls::Item<T>>>(&mut self, iter: I) {
         self.0.extend(iter);
@@ -145,7 +171,7 @@ impl<T: FixedSize, Nulls: Nullability, Storage: Buffer> Collection
     }
 
     type Iter<'collection>
-        = <Nulls::Collection<Storage::For<T>, Storage> as Collection>::Iter<'collection>
+        = <Window<Nulls::Collection<Storage::For<T>, Storage>> as Collection>::Iter<'collection>
     where
         Self: 'collection;
 
@@ -153,7 +179,7 @@ impl<T: FixedSize, Nulls: Nullability, Storage: Buffer> Collection
         self.0.iter_views()
     }
 
-    type IntoIter = <Nulls::Collection<Storage::For<T>, Storage> as Collection>::IntoIter;
+    type IntoIter = <Window<Nulls::Collection<Storage::For<T>, Storage>> as Collection>::IntoIter;
 
     fn into_iter_owned(self) -> Self::IntoIter {
         self.0.into_iter_owned()
@@ -168,22 +194,22 @@ where
     type Alloc = <Nulls::Collection<Storage::For<T>, Storage> as CollectionAllocIn>::Alloc;
 
     fn with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Self {
-        Self(Nulls::Collection::with_capacity_in(capacity, alloc))
+        Self(Window::with_capacity_in(capacity, alloc))
     }
 
     fn from_iter_in<I: IntoIterator<Item = Self::Owned>>(iter: I, alloc: Self::Alloc) -> Self {
-        Self(Nulls::Collection::from_iter_in(iter, alloc))
+        Self(Window::from_iter_in(iter, alloc))
     }
 
     fn try_with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Result<Self, AllocError> {
-        Nulls::Collection::try_with_capacity_in(capacity, alloc).map(Self)
+        Window::try_with_capacity_in(capacity, alloc).map(Self)
     }
 
     fn try_from_iter_in<I: IntoIterator<Item = Self::Owned>>(
         iter: I,
         alloc: Self::Alloc,
     ) -> Result<Self, AllocError> {
-        Nulls::Collection::try_from_iter_in(iter, alloc).map(Self)
+        Window::try_from_iter_in(iter, alloc).map(Self)
     }
 }
 
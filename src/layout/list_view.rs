@@ -0,0 +1,1109 @@
+//! Lists stored as views into their values.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::{
+    borrow::Borrow,
+    fmt::{self, Debug},
+    iter::{self, Map, RepeatN, Zip},
+    marker::PhantomData,
+    ops::{Deref, Range},
+};
+
+use crate::{
+    buffer::{Buffer, BufferRef, VecBuffer},
+    collection::{
+        AllocError, ChildRef, Collection, CollectionAllocIn, CollectionRealloc, owned::IntoOwned,
+        window::Window,
+    },
+    layout::{ArrayItem, MemoryLayout},
+    length::Length,
+    nullability::{NonNullable, Nullability},
+    offset::Offset,
+};
+
+/// A list of `T` stored in a list view layout, using offsets and sizes of
+/// type `OffsetItem`.
+///
+/// Arrow stores lists either with monotonic offsets or with an offset and a
+/// size per list. Both represent the same items, so selecting list views is a
+/// storage decision. Wrapping the list selects the [`ListView`] layout, and
+/// `OffsetItem` selects the standard (`i32`) or large (`i64`) format:
+///
+/// ```text
+/// Vec<T>                VariableSizeList
+/// ViewedList<T>         ListView
+/// ViewedList<T, i64>    LargeListView
+/// ```
+///
+/// Views are the [`ListItemView`] of the list values.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{array::Array, collection::Collection, layout::list_view::ViewedList};
+///
+/// let values = [vec![1, 2], vec![3]]
+///     .into_iter()
+///     .map(ViewedList::new)
+///     .collect::<Array<ViewedList<u32>>>();
+/// assert_eq!(values.owned(1), Some(ViewedList::new(vec![3])));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ViewedList<T, OffsetItem: Offset = i32>(Vec<T>, PhantomData<OffsetItem>);
+
+impl<T, OffsetItem: Offset> ViewedList<T, OffsetItem> {
+    /// Wraps `values` for storage in a list view layout.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::layout::list_view::ViewedList;
+    ///
+    /// let list = ViewedList::<_>::new(vec![1, 2]);
+    /// assert_eq!(*list, [1, 2]);
+    /// ```
+    #[must_use]
+    pub const fn new(values: Vec<T>) -> Self {
+        Self(values, PhantomData)
+    }
+
+    /// Returns the wrapped values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::layout::list_view::ViewedList;
+    ///
+    /// let list = ViewedList::<_, i64>::new(vec![1]);
+    /// assert_eq!(list.into_inner(), [1]);
+    /// ```
+    #[must_use]
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T, OffsetItem: Offset> Deref for ViewedList<T, OffsetItem> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, OffsetItem: Offset> FromIterator<T> for ViewedList<T, OffsetItem> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+/// Lists described by an offset and a size into their values.
+///
+/// <https://arrow.apache.org/docs/format/Columnar.html#listview-layout>
+///
+/// Every list has its own offset and size, so lists do not have to be stored
+/// in order and may overlap or share values:
+///
+/// ```text
+/// offsets: [3, 0, 1]
+/// sizes:   [2, 2, 0]
+/// values:  [a, b, c, d, e]
+/// items:   [d, e]  [a, b]  []
+/// ```
+///
+/// Filtering or reordering lists only touches the offsets and sizes, where
+/// [`Offsets`](crate::offset::Offsets) must copy the values to keep its
+/// offsets monotonic.
+///
+/// Extending appends the values of every list and points its offset at them.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{
+///     buffer::BufferRef,
+///     collection::Collection,
+///     layout::list_view::{ListViews, ViewedList},
+/// };
+///
+/// let lists = [vec![1, 2], vec![3]]
+///     .into_iter()
+///     .map(ViewedList::new)
+///     .collect::<ListViews<u8>>();
+/// assert_eq!((lists.buffer_ref(), lists.sizes()), (&vec![0, 2], &vec![2, 1]));
+/// assert_eq!(lists.owned(0), Some(ViewedList::new(vec![1, 2])));
+/// ```
+pub struct ListViews<T: ArrayItem, OffsetItem: Offset = i32, Storage: Buffer = VecBuffer> {
+    /// The values referenced by the lists.
+    values: T::Memory<Storage>,
+    /// The offset of every list in the values.
+    offsets: Storage::For<OffsetItem>,
+    /// The number of values of every list.
+    sizes: Storage::For<OffsetItem>,
+}
+
+/// Error returned by [`ListViews::try_from_parts`].
+///
+/// # Examples
+///
+/// ```
+/// use narrow::layout::list_view::{ListViews, ListViewsError};
+///
+/// let values = [1, 2].into_iter().collect();
+/// let error = ListViews::<u8>::try_from_parts(values, vec![1], vec![2]).unwrap_err();
+/// assert_eq!(error, ListViewsError::OutOfBounds { index: 0 });
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ListViewsError {
+    /// The number of offsets and sizes differ.
+    LengthMismatch {
+        /// The number of offsets.
+        offsets: usize,
+        /// The number of sizes.
+        sizes: usize,
+    },
+    /// The offset or size of the list at `index` is negative.
+    Negative {
+        /// The index of the invalid list.
+        index: usize,
+    },
+    /// The list at `index` ends after the last value.
+    OutOfBounds {
+        /// The index of the invalid list.
+        index: usize,
+    },
+}
+
+impl fmt::Display for ListViewsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::LengthMismatch { offsets, sizes } => write!(
+                f,
+                "number of offsets ({offsets}) does not match number of sizes ({sizes})"
+            ),
+            Self::Negative { index } => {
+                write!(f, "offset or size of list at index {index} is negative")
+            }
+            Self::OutOfBounds { index } => {
+                write!(f, "list at index {index} is out of bounds of its values")
+            }
+        }
+    }
+}
+
+impl core::error::Error for ListViewsError {}
+
+impl<T: ArrayItem, OffsetItem: Offset, Storage: Buffer> ListViews<T, OffsetItem, Storage> {
+    /// Constructs [`ListViews`] from their `values` and the `offsets` and
+    /// `sizes` buffers of the lists.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ListViewsError`] when the number of offsets and sizes
+    /// differ, or when a list has a negative offset or size or ends after the
+    /// last value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{collection::Collection, layout::list_view::{ListViews, ViewedList}};
+    ///
+    /// let values = [1, 2, 3].into_iter().collect();
+    /// let lists = ListViews::<u8>::try_from_parts(values, vec![1, 0], vec![2, 1]).unwrap();
+    /// assert_eq!(lists.owned(0), Some(ViewedList::new(vec![2, 3])));
+    /// assert_eq!(lists.owned(1), Some(ViewedList::new(vec![1])));
+    /// ```
+    pub fn try_from_parts(
+        values: T::Memory<Storage>,
+        offsets: Storage::For<OffsetItem>,
+        sizes: Storage::For<OffsetItem>,
+    ) -> Result<Self, ListViewsError> {
+        let (offsets_len, sizes_len) = (offsets.borrow().len(), sizes.borrow().len());
+        if offsets_len != sizes_len {
+            return Err(ListViewsError::LengthMismatch {
+                offsets: offsets_len,
+                sizes: sizes_len,
+            });
+        }
+        let values_len = values.len();
+        offsets
+            .borrow()
+            .iter()
+            .zip(sizes.borrow())
+            .enumerate()
+            .try_for_each(|(index, (&offset, &size))| {
+                let (Ok(start), Ok(len)) = (offset.try_into(), size.try_into()) else {
+                    return Err(ListViewsError::Negative { index });
+                };
+                match usize::checked_add(start, len) {
+                    Some(end) if end <= values_len => Ok(()),
+                    Some(_) | None => Err(ListViewsError::OutOfBounds { index }),
+                }
+            })?;
+        Ok(Self {
+            values,
+            offsets,
+            sizes,
+        })
+    }
+
+    /// Returns the values, offsets buffer and sizes buffer of these
+    /// [`ListViews`].
+    ///
+    /// This is the inverse of [`ListViews::try_from_parts`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::layout::list_view::{ListViews, ViewedList};
+    ///
+    /// let lists = [ViewedList::new(vec![1])].into_iter().collect::<ListViews<u8>>();
+    /// let (values, offsets, sizes) = lists.into_parts();
+    /// assert_eq!((values.into_buffer(), offsets, sizes), (vec![1], vec![0], vec![1]));
+    /// ```
+    #[must_use]
+    pub fn into_parts(
+        self,
+    ) -> (
+        T::Memory<Storage>,
+        Storage::For<OffsetItem>,
+        Storage::For<OffsetItem>,
+    ) {
+        (self.values, self.offsets, self.sizes)
+    }
+
+    /// Returns the sizes buffer of these [`ListViews`].
+    ///
+    /// The offsets buffer is available through [`BufferRef`] and the values
+    /// through [`ChildRef`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::layout::list_view::{ListViews, ViewedList};
+    ///
+    /// let lists = [vec![1, 2], vec![]]
+    ///     .into_iter()
+    ///     .map(ViewedList::new)
+    ///     .collect::<ListViews<u8>>();
+    /// assert_eq!(lists.sizes(), &[2, 0]);
+    /// ```
+    #[must_use]
+    pub fn sizes(&self) -> &Storage::For<OffsetItem> {
+        &self.sizes
+    }
+
+    /// Returns the range of values of the list at `index`.
+    fn range(&self, index: usize) -> Option<Range<usize>> {
+        let start = self.offsets.borrow().get(index)?.as_usize();
+        let len = self.sizes.borrow().get(index)?.as_usize();
+        Some(start..start.strict_add(len))
+    }
+}
+
+impl<T: ArrayItem, OffsetItem: Offset, Storage: Buffer> ListViews<T, OffsetItem, Storage>
+where
+    T::Memory<Storage>: CollectionRealloc,
+    Storage::For<OffsetItem>: CollectionRealloc,
+{
+    /// Appends the values of `list` and the offset and size that reference
+    /// them.
+    fn try_push(
+        &mut self,
+        list: Vec<T>,
+        push_values: impl FnOnce(&mut T::Memory<Storage>, Vec<T>) -> Result<(), AllocError>,
+        push_offset: impl Fn(&mut Storage::For<OffsetItem>, OffsetItem) -> Result<(), AllocError>,
+    ) -> Result<(), AllocError> {
+        let offset = OffsetItem::try_from(self.values.len()).map_err(|_| AllocError)?;
+        let size = OffsetItem::try_from(list.len()).map_err(|_| AllocError)?;
+        self.values
+            .len()
+            .checked_add(list.len())
+            .and_then(|end| OffsetItem::try_from(end).ok())
+            .ok_or(AllocError)?;
+        push_values(&mut self.values, list)?;
+        push_offset(&mut self.offsets, offset)?;
+        push_offset(&mut self.sizes, size)
+    }
+}
+
+impl<T: ArrayItem, OffsetItem: Offset, Storage: Buffer> BufferRef
+    for ListViews<T, OffsetItem, Storage>
+{
+    type Buffer = Storage::For<OffsetItem>;
+
+    fn buffer_ref(&self) -> &Self::Buffer {
+        &self.offsets
+    }
+}
+
+impl<T: ArrayItem, OffsetItem: Offset, Storage: Buffer> ChildRef
+    for ListViews<T, OffsetItem, Storage>
+{
+    type Child = T::Memory<Storage>;
+
+    fn child_ref(&self) -> &Self::Child {
+        &self.values
+    }
+}
+
+impl<T: ArrayItem, OffsetItem: Offset, Storage: Buffer> Debug for ListViews<T, OffsetItem, Storage>
+where
+    T::Memory<Storage>: Debug,
+    Storage::For<OffsetItem>: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ListViews")
+            .field("values", &self.values)
+            .field("offsets", &self.offsets)
+            .field("sizes", &self.sizes)
+            .finish()
+    }
+}
+
+impl<T: ArrayItem, OffsetItem: Offset, Storage: Buffer> Clone for ListViews<T, OffsetItem, Storage>
+where
+    T::Memory<Storage>: Clone,
+    Storage::For<OffsetItem>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            values: self.values.clone(),
+            offsets: self.offsets.clone(),
+            sizes: self.sizes.clone(),
+        }
+    }
+}
+
+impl<T: ArrayItem, OffsetItem: Offset, Storage: Buffer> Default
+    for ListViews<T, OffsetItem, Storage>
+where
+    T::Memory<Storage>: Default,
+    Storage::For<OffsetItem>: Default,
+{
+    fn default() -> Self {
+        Self {
+            values: T::Memory::<Storage>::default(),
+            offsets: Storage::For::<OffsetItem>::default(),
+            sizes: Storage::For::<OffsetItem>::default(),
+        }
+    }
+}
+
+impl<T: ArrayItem, OffsetItem: Offset, Storage: Buffer> Extend<ViewedList<T, OffsetItem>>
+    for ListViews<T, OffsetItem, Storage>
+where
+    T::Memory<Storage>: CollectionRealloc,
+    Storage::For<OffsetItem>: CollectionRealloc,
+{
+    fn extend<I: IntoIterator<Item = ViewedList<T, OffsetItem>>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|ViewedList(list, _)| {
+            self.try_push(
+                list,
+                |values, new| {
+                    values.extend(new);
+                    Ok(())
+                },
+                |buffer, value| {
+                    buffer.extend(iter::once(value));
+                    Ok(())
+                },
+            )
+            .expect("list offset overflow");
+        });
+    }
+}
+
+impl<T: ArrayItem, OffsetItem: Offset, Storage: Buffer> FromIterator<ViewedList<T, OffsetItem>>
+    for ListViews<T, OffsetItem, Storage>
+where
+    Self: Default + Extend<ViewedList<T, OffsetItem>>,
+{
+    fn from_iter<I: IntoIterator<Item = ViewedList<T, OffsetItem>>>(iter: I) -> Self {
+        let mut lists = Self::default();
+        lists.extend(iter);
+        lists
+    }
+}
+
+impl<T: ArrayItem, OffsetItem: Offset, Storage: Buffer> Length
+    for ListViews<T, OffsetItem, Storage>
+{
+    fn len(&self) -> usize {
+        self.offsets.len()
+    }
+}
+
+impl<T: ArrayItem, OffsetItem: Offset, Storage: Buffer> Collection
+    for ListViews<T, OffsetItem, Storage>
+{
+    type View<'collection>
+        = ListItemView<'collection, T, OffsetItem, Storage>
+    where
+        Self: 'collection;
+
+    type Owned = ViewedList<T, OffsetItem>;
+
+    fn view(&self, index: usize) -> Option<Self::View<'_>> {
+        self.range(index).map(|range| ListItemView {
+            values: &self.values,
+            start: range.start,
+            end: range.end,
+            _offset: PhantomData,
+        })
+    }
+
+    type Iter<'collection>
+        = Map<
+        Zip<Range<usize>, RepeatN<&'collection Self>>,
+        fn((usize, &'collection Self)) -> Self::View<'collection>,
+    >
+    where
+        Self: 'collection;
+
+    fn iter_views(&self) -> Self::Iter<'_> {
+        let len = self.len();
+        (0..len)
+            .zip(iter::repeat_n(self, len))
+            .map(|(index, lists)| lists.view(index).expect("index in range"))
+    }
+
+    type IntoIter = ListViewsIntoIter<T, OffsetItem, Storage>;
+
+    fn into_iter_owned(self) -> Self::IntoIter {
+        ListViewsIntoIter {
+            indices: 0..self.len(),
+            lists: self,
+        }
+    }
+}
+
+impl<T: ArrayItem, OffsetItem: Offset, Storage: Buffer> CollectionAllocIn
+    for ListViews<T, OffsetItem, Storage>
+where
+    Storage::For<OffsetItem>: CollectionRealloc,
+    T::Memory<Storage>:
+        CollectionRealloc<Alloc = <Storage::For<OffsetItem> as CollectionAllocIn>::Alloc>,
+{
+    type Alloc = <Storage::For<OffsetItem> as CollectionAllocIn>::Alloc;
+
+    fn with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Self {
+        Self {
+            values: T::Memory::<Storage>::with_capacity_in(capacity, alloc.clone()),
+            offsets: Storage::For::<OffsetItem>::with_capacity_in(capacity, alloc.clone()),
+            sizes: Storage::For::<OffsetItem>::with_capacity_in(capacity, alloc),
+        }
+    }
+
+    fn from_iter_in<I: IntoIterator<Item = Self::Owned>>(iter: I, alloc: Self::Alloc) -> Self {
+        let items = iter.into_iter();
+        let mut lists = Self::with_capacity_in(items.size_hint().0, alloc);
+        lists.extend(items);
+        lists
+    }
+
+    fn try_with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Result<Self, AllocError> {
+        Ok(Self {
+            values: T::Memory::<Storage>::try_with_capacity_in(capacity, alloc.clone())?,
+            offsets: Storage::For::<OffsetItem>::try_with_capacity_in(capacity, alloc.clone())?,
+            sizes: Storage::For::<OffsetItem>::try_with_capacity_in(capacity, alloc)?,
+        })
+    }
+
+    fn try_from_iter_in<I: IntoIterator<Item = Self::Owned>>(
+        iter: I,
+        alloc: Self::Alloc,
+    ) -> Result<Self, AllocError> {
+        let items = iter.into_iter();
+        let mut lists = Self::try_with_capacity_in(items.size_hint().0, alloc)?;
+        lists.try_extend(items)?;
+        Ok(lists)
+    }
+}
+
+impl<T: ArrayItem, OffsetItem: Offset, Storage: Buffer> CollectionRealloc
+    for ListViews<T, OffsetItem, Storage>
+where
+    Storage::For<OffsetItem>: CollectionRealloc,
+    T::Memory<Storage>:
+        CollectionRealloc<Alloc = <Storage::For<OffsetItem> as CollectionAllocIn>::Alloc>,
+{
+    fn allocator(&self) -> Self::Alloc {
+        self.offsets.allocator()
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+        self.offsets.try_reserve(additional)?;
+        self.sizes.try_reserve(additional)
+    }
+
+    fn try_extend<I: IntoIterator<Item = Self::Owned>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), AllocError> {
+        let (len, values_len) = (self.len(), self.values.len());
+        iter.into_iter()
+            .try_for_each(|ViewedList(list, _)| {
+                self.try_push(list, CollectionRealloc::try_extend, |buffer, value| {
+                    buffer.try_extend(iter::once(value))
+                })
+            })
+            .inspect_err(|_| {
+                self.truncate(len);
+                self.values.truncate(values_len);
+            })
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.offsets.reserve(additional);
+        self.sizes.reserve(additional);
+    }
+
+    /// Drops the lists after `len`. The values are kept, because lists may
+    /// share them.
+    fn truncate(&mut self, len: usize) {
+        self.offsets.truncate(len);
+        self.sizes.truncate(len);
+    }
+}
+
+/// A borrowed view of one list in [`ListViews`].
+///
+/// The view is a range into the values of the lists, which it is a
+/// [`Collection`] of.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{
+///     collection::Collection,
+///     layout::list_view::{ListViews, ViewedList},
+///     length::Length,
+/// };
+///
+/// let lists = [ViewedList::new(vec![1, 2])].into_iter().collect::<ListViews<u8>>();
+/// let view = lists.view(0).unwrap();
+/// assert_eq!(view.len(), 2);
+/// assert_eq!(view.iter_views().collect::<Vec<_>>(), [1, 2]);
+/// ```
+pub struct ListItemView<'collection, T: ArrayItem, OffsetItem: Offset, Storage: Buffer> {
+    /// The values of the lists.
+    values: &'collection T::Memory<Storage>,
+    /// The index of the first value of this list.
+    start: usize,
+    /// The index one past the last value of this list.
+    end: usize,
+    /// The offset type of the lists.
+    _offset: PhantomData<OffsetItem>,
+}
+
+impl<T: ArrayItem, OffsetItem: Offset, Storage: Buffer> Debug
+    for ListItemView<'_, T, OffsetItem, Storage>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ListItemView")
+            .field("values", &(self.start..self.end))
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: ArrayItem, OffsetItem: Offset, Storage: Buffer> Clone
+    for ListItemView<'_, T, OffsetItem, Storage>
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ArrayItem, OffsetItem: Offset, Storage: Buffer> Copy
+    for ListItemView<'_, T, OffsetItem, Storage>
+{
+}
+
+impl<C: Collection, T: ArrayItem, OffsetItem: Offset, Storage: Buffer> PartialEq<C>
+    for ListItemView<'_, T, OffsetItem, Storage>
+where
+    for<'any, 'other> <T::Memory<Storage> as Collection>::View<'any>: PartialEq<C::View<'other>>,
+{
+    fn eq(&self, other: &C) -> bool {
+        self.len() == other.len()
+            && self
+                .iter_views()
+                .zip(other.iter_views())
+                .all(|(a, b)| a == b)
+    }
+}
+
+impl<T: ArrayItem, OffsetItem: Offset, Storage: Buffer> IntoOwned<ViewedList<T, OffsetItem>>
+    for ListItemView<'_, T, OffsetItem, Storage>
+{
+    fn into_owned(self) -> ViewedList<T, OffsetItem> {
+        self.into_iter_owned().collect()
+    }
+}
+
+impl<T: ArrayItem, OffsetItem: Offset, Storage: Buffer> Length
+    for ListItemView<'_, T, OffsetItem, Storage>
+{
+    fn len(&self) -> usize {
+        self.end.strict_sub(self.start)
+    }
+}
+
+impl<T: ArrayItem, OffsetItem: Offset, Storage: Buffer> Collection
+    for ListItemView<'_, T, OffsetItem, Storage>
+{
+    type View<'collection>
+        = <T::Memory<Storage> as Collection>::View<'collection>
+    where
+        Self: 'collection;
+
+    type Owned = T;
+
+    fn view(&self, index: usize) -> Option<Self::View<'_>> {
+        let position = self.start.strict_add(index);
+        if position < self.end {
+            self.values.view(position)
+        } else {
+            None
+        }
+    }
+
+    type Iter<'collection>
+        = Map<
+        Zip<Range<usize>, RepeatN<&'collection Self>>,
+        fn((usize, &'collection Self)) -> Self::View<'collection>,
+    >
+    where
+        Self: 'collection;
+
+    fn iter_views(&self) -> Self::Iter<'_> {
+        (0..self.len())
+            .zip(iter::repeat_n(self, self.len()))
+            .map(|(index, list)| list.view(index).expect("index in range"))
+    }
+
+    type IntoIter = Map<Zip<Range<usize>, RepeatN<Self>>, fn((usize, Self)) -> Self::Owned>;
+
+    fn into_iter_owned(self) -> Self::IntoIter {
+        (0..self.len())
+            .zip(iter::repeat_n(self, self.len()))
+            .map(|(index, list)| list.view(index).expect("index in range").into_owned())
+    }
+}
+
+/// Iterator over owned items of [`ListViews`].
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{collection::Collection, layout::list_view::{ListViews, ViewedList}};
+///
+/// let lists = [ViewedList::new(vec![1])].into_iter().collect::<ListViews<u8>>();
+/// let mut iter = lists.into_iter_owned();
+/// assert_eq!(iter.len(), 1);
+/// assert_eq!(iter.next(), Some(ViewedList::new(vec![1])));
+/// ```
+pub struct ListViewsIntoIter<T: ArrayItem, OffsetItem: Offset, Storage: Buffer> {
+    /// The indices of the remaining lists.
+    indices: Range<usize>,
+    /// The iterated lists.
+    lists: ListViews<T, OffsetItem, Storage>,
+}
+
+impl<T: ArrayItem, OffsetItem: Offset, Storage: Buffer> Debug
+    for ListViewsIntoIter<T, OffsetItem, Storage>
+where
+    ListViews<T, OffsetItem, Storage>: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ListViewsIntoIter")
+            .field("indices", &self.indices)
+            .field("lists", &self.lists)
+            .finish()
+    }
+}
+
+impl<T: ArrayItem, OffsetItem: Offset, Storage: Buffer> Iterator
+    for ListViewsIntoIter<T, OffsetItem, Storage>
+{
+    type Item = ViewedList<T, OffsetItem>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.indices.next()?;
+        self.lists.view(index).map(IntoOwned::into_owned)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let index = self.indices.nth(n)?;
+        self.lists.view(index).map(IntoOwned::into_owned)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+impl<T: ArrayItem, OffsetItem: Offset, Storage: Buffer> ExactSizeIterator
+    for ListViewsIntoIter<T, OffsetItem, Storage>
+{
+}
+
+/// A collection of lists stored as views into their values.
+///
+/// This is the Arrow `ListView` layout, or `LargeListView` with `i64`
+/// offsets: [`ListViews`] with outer nullability. Like
+/// [`VariableSizeList`](super::variable_size_list::VariableSizeList), the
+/// values layout is obtained from [`ArrayItem`] and `Nulls` only controls the
+/// validity of the lists.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{
+///     collection::Collection,
+///     layout::list_view::{ListView, ViewedList},
+///     nullability::Nullable,
+/// };
+///
+/// let lists = [Some(ViewedList::new(vec![1, 2])), None]
+///     .into_iter()
+///     .collect::<ListView<u16, Nullable>>();
+/// assert_eq!(lists.owned(0), Some(Some(ViewedList::new(vec![1, 2]))));
+/// assert_eq!(lists.owned(1), Some(None));
+/// ```
+pub struct ListView<
+    T: ArrayItem,
+    Nulls: Nullability = NonNullable,
+    OffsetItem: Offset = i32,
+    Storage: Buffer = VecBuffer,
+>(Window<Nulls::Collection<ListViews<T, OffsetItem, Storage>, Storage>>);
+
+impl<T: ArrayItem, Nulls: Nullability, OffsetItem: Offset, Storage: Buffer> MemoryLayout
+    for ListView<T, Nulls, OffsetItem, Storage>
+{
+    fn slice(self, offset: usize, len: usize) -> Self {
+        Self(self.0.slice(offset, len))
+    }
+
+    fn offset(&self) -> usize {
+        self.0.offset()
+    }
+}
+
+impl<T: ArrayItem, Nulls: Nullability, OffsetItem: Offset, Storage: Buffer>
+    ListView<T, Nulls, OffsetItem, Storage>
+{
+    /// Constructs a [`ListView`] from its backing collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{
+    ///     collection::Collection,
+    ///     layout::list_view::{ListView, ListViews, ViewedList},
+    /// };
+    ///
+    /// let values = [1, 2, 3].into_iter().collect();
+    /// let lists = ListViews::try_from_parts(values, vec![2, 0], vec![1, 2]).unwrap();
+    /// let lists = ListView::<u8>::from_buffer(lists);
+    /// assert_eq!(lists.owned(0), Some(ViewedList::new(vec![3])));
+    /// ```
+    #[must_use]
+    pub fn from_buffer(
+        buffer: Nulls::Collection<ListViews<T, OffsetItem, Storage>, Storage>,
+    ) -> Self {
+        Self(Window::new(buffer))
+    }
+
+    /// Returns the backing collection of this [`ListView`].
+    ///
+    /// This is the inverse of [`ListView::from_buffer`]. The backing
+    /// collection of a slice still contains the items outside of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{layout::list_view::{ListView, ViewedList}, length::Length};
+    ///
+    /// let lists = [ViewedList::new(vec![1])].into_iter().collect::<ListView<u8>>();
+    /// assert_eq!(lists.into_buffer().len(), 1);
+    /// ```
+    #[must_use]
+    pub fn into_buffer(self) -> Nulls::Collection<ListViews<T, OffsetItem, Storage>, Storage> {
+        self.0.into_parts().0
+    }
+}
+
+impl<T: ArrayItem, Nulls: Nullability, OffsetItem: Offset, Storage: Buffer> BufferRef
+    for ListView<T, Nulls, OffsetItem, Storage>
+{
+    type Buffer = Nulls::Collection<ListViews<T, OffsetItem, Storage>, Storage>;
+
+    fn buffer_ref(&self) -> &Self::Buffer {
+        self.0.buffer_ref()
+    }
+}
+
+impl<T: ArrayItem, Nulls: Nullability, OffsetItem: Offset, Storage: Buffer> Debug
+    for ListView<T, Nulls, OffsetItem, Storage>
+where
+    Nulls::Collection<ListViews<T, OffsetItem, Storage>, Storage>: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ListView").field(&self.0).finish()
+    }
+}
+
+impl<T: ArrayItem, Nulls: Nullability, OffsetItem: Offset, Storage: Buffer> Clone
+    for ListView<T, Nulls, OffsetItem, Storage>
+where
+    Nulls::Collection<ListViews<T, OffsetItem, Storage>, Storage>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: ArrayItem, Nulls: Nullability, OffsetItem: Offset, Storage: Buffer> Default
+    for ListView<T, Nulls, OffsetItem, Storage>
+where
+    Nulls::Collection<ListViews<T, OffsetItem, Storage>, Storage>: Default,
+{
+    fn default() -> Self {
+        Self(Window::default())
+    }
+}
+
+impl<T: ArrayItem, Nulls: Nullability, OffsetItem: Offset, Storage: Buffer>
+    Extend<Nulls::Item<ViewedList<T, OffsetItem>>> for ListView<T, Nulls, OffsetItem, Storage>
+where
+    Window<Nulls::Collection<ListViews<T, OffsetItem, Storage>, Storage>>:
+        Extend<Nulls::Item<ViewedList<T, OffsetItem>>>,
+{
+    fn extend<I: IntoIterator<Item = Nulls::Item<ViewedList<T, OffsetItem>>>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl<T: ArrayItem, Nulls: Nullability, OffsetItem: Offset, Storage: Buffer>
+    FromIterator<Nulls::Item<ViewedList<T, OffsetItem>>> for ListView<T, Nulls, OffsetItem, Storage>
+where
+    Nulls::Collection<ListViews<T, OffsetItem, Storage>, Storage>:
+        FromIterator<Nulls::Item<ViewedList<T, OffsetItem>>>,
+{
+    fn from_iter<I: IntoIterator<Item = Nulls::Item<ViewedList<T, OffsetItem>>>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<T: ArrayItem, Nulls: Nullability, OffsetItem: Offset, Storage: Buffer> Length
+    for ListView<T, Nulls, OffsetItem, Storage>
+{
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<T: ArrayItem, Nulls: Nullability, OffsetItem: Offset, Storage: Buffer> Collection
+    for ListView<T, Nulls, OffsetItem, Storage>
+{
+    type View<'collection>
+        = <Nulls::Collection<ListViews<T, OffsetItem, Storage>, Storage> as Collection>::View<
+        'collection,
+    >
+    where
+        Self: 'collection;
+
+    type Owned = Nulls::Item<ViewedList<T, OffsetItem>>;
+
+    fn view(&self, index: usize) -> Option<Self::View<'_>> {
+        self.0.view(index)
+    }
+
+    type Iter<'collection>
+        =
+        <Window<Nulls::Collection<ListViews<T, OffsetItem, Storage>, Storage>> as Collection>::Iter<
+            'collection,
+        >
+    where
+        Self: 'collection;
+
+    fn iter_views(&self) -> Self::Iter<'_> {
+        self.0.iter_views()
+    }
+
+    type IntoIter =
+        <Window<Nulls::Collection<ListViews<T, OffsetItem, Storage>, Storage>> as Collection>::IntoIter;
+
+    fn into_iter_owned(self) -> Self::IntoIter {
+        self.0.into_iter_owned()
+    }
+}
+
+impl<T: ArrayItem, Nulls: Nullability, OffsetItem: Offset, Storage: Buffer> CollectionAllocIn
+    for ListView<T, Nulls, OffsetItem, Storage>
+where
+    Nulls::Collection<ListViews<T, OffsetItem, Storage>, Storage>: CollectionAllocIn,
+{
+    type Alloc =
+        <Nulls::Collection<ListViews<T, OffsetItem, Storage>, Storage> as CollectionAllocIn>::Alloc;
+
+    fn with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Self {
+        Self(Window::with_capacity_in(capacity, alloc))
+    }
+
+    fn from_iter_in<I: IntoIterator<Item = Self::Owned>>(iter: I, alloc: Self::Alloc) -> Self {
+        Self(Window::from_iter_in(iter, alloc))
+    }
+
+    fn try_with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Result<Self, AllocError> {
+        Window::try_with_capacity_in(capacity, alloc).map(Self)
+    }
+
+    fn try_from_iter_in<I: IntoIterator<Item = Self::Owned>>(
+        iter: I,
+        alloc: Self::Alloc,
+    ) -> Result<Self, AllocError> {
+        Window::try_from_iter_in(iter, alloc).map(Self)
+    }
+}
+
+impl<T: ArrayItem, Nulls: Nullability, OffsetItem: Offset, Storage: Buffer> CollectionRealloc
+    for ListView<T, Nulls, OffsetItem, Storage>
+where
+    Nulls::Collection<ListViews<T, OffsetItem, Storage>, Storage>: CollectionRealloc,
+{
+    fn allocator(&self) -> Self::Alloc {
+        self.0.allocator()
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+        self.0.try_reserve(additional)
+    }
+
+    fn try_extend<I: IntoIterator<Item = Self::Owned>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), AllocError> {
+        self.0.try_extend(iter)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.0.truncate(len);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::vec;
+
+    use crate::nullability::Nullable;
+
+    use super::*;
+
+    fn lists<T, const N: usize>(values: [Vec<T>; N]) -> [ViewedList<T>; N] {
+        values.map(ViewedList::new)
+    }
+
+    #[test]
+    fn try_from_parts() {
+        let values = [1, 2, 3, 4].into_iter().collect();
+        let out_of_order =
+            ListViews::<u8>::try_from_parts(values, vec![2, 0, 1, 4], vec![2, 2, 2, 0])
+                .expect("valid parts");
+        assert_eq!(
+            out_of_order
+                .iter_views()
+                .map(IntoOwned::<ViewedList<u8>>::into_owned)
+                .collect::<Vec<_>>(),
+            lists([vec![3, 4], vec![1, 2], vec![2, 3], vec![]])
+        );
+
+        let pair = || [1, 2].into_iter().collect();
+        assert_eq!(
+            ListViews::<u8>::try_from_parts(pair(), vec![0], vec![]).expect_err("mismatch"),
+            ListViewsError::LengthMismatch {
+                offsets: 1,
+                sizes: 0
+            }
+        );
+        assert_eq!(
+            ListViews::<u8>::try_from_parts(pair(), vec![0, -1], vec![1, 1])
+                .expect_err("negative offset"),
+            ListViewsError::Negative { index: 1 }
+        );
+        assert_eq!(
+            ListViews::<u8>::try_from_parts(pair(), vec![0], vec![-1]).expect_err("negative"),
+            ListViewsError::Negative { index: 0 }
+        );
+        assert_eq!(
+            ListViews::<u8, i64>::try_from_parts(pair(), vec![1, 2], vec![1, 1])
+                .expect_err("out of bounds"),
+            ListViewsError::OutOfBounds { index: 1 }
+        );
+        assert_eq!(
+            ListViews::<u8>::try_from_parts(pair(), vec![i32::MAX], vec![i32::MAX])
+                .expect_err("overflowing end"),
+            ListViewsError::OutOfBounds { index: 0 }
+        );
+    }
+
+    #[test]
+    fn from_buffer() {
+        let values = lists([vec![1, 2], vec![3]])
+            .into_iter()
+            .collect::<ListView<u32>>();
+        let restored = ListView::<u32>::from_buffer(values.into_buffer());
+        assert_eq!(restored.len(), 2);
+        assert_eq!(restored.owned(1), Some(ViewedList::new(vec![3])));
+    }
+
+    #[test]
+    fn collection() {
+        let items = lists([vec![vec![1], vec![]], vec![vec![2, 3]]]);
+        let values = items.clone().into_iter().collect::<ListView<Vec<u8>>>();
+        assert_eq!(values.len(), 2);
+        let view = values.view(1).expect("a list");
+        assert_eq!(view.len(), 1);
+        assert_eq!(
+            IntoOwned::<ViewedList<Vec<u8>>>::into_owned(view),
+            ViewedList::new(vec![vec![2, 3]])
+        );
+        assert_eq!(values.into_iter_owned().collect::<Vec<_>>(), items);
+
+        let nullable = [
+            Some(ViewedList::<u16, i64>::new(vec![1, 2])),
+            None,
+            Some(ViewedList::new(vec![])),
+        ];
+        let nullable_values = nullable
+            .clone()
+            .into_iter()
+            .collect::<ListView<u16, Nullable, i64>>();
+        let iter = nullable_values.into_iter_owned();
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        assert_eq!(iter.collect::<Vec<_>>(), nullable);
+    }
+
+    #[test]
+    fn try_extend_and_truncate() {
+        let mut values = lists([vec![1]]).into_iter().collect::<ListView<u8>>();
+        CollectionRealloc::try_extend(&mut values, lists([vec![2, 3], vec![4]])).expect("reserved");
+        assert_eq!(values.owned(1), Some(ViewedList::new(vec![2, 3])));
+        CollectionRealloc::truncate(&mut values, 1);
+        values.extend(lists([vec![5]]));
+        assert_eq!(
+            values.into_iter_owned().collect::<Vec<_>>(),
+            lists([vec![1], vec![5]])
+        );
+    }
+}
@@ -0,0 +1,880 @@
+//! Maps of keys to values.
+
+extern crate alloc;
+
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::{
+    fmt::{self, Debug},
+    iter::{self, RepeatN, Zip},
+    marker::PhantomData,
+    ops::{Deref, Range},
+};
+
+use crate::{
+    buffer::{Buffer, BufferRef, VecBuffer},
+    collection::{
+        AllocError, ChildRef, Collection, CollectionAllocIn, CollectionRealloc, owned::IntoOwned,
+        window::Window,
+    },
+    layout::{ArrayItem, Layout, MemoryLayout, struct_array::Struct},
+    length::Length,
+    nullability::{NonNullable, Nullability},
+    offset::{OffsetIntoIter, OffsetView, Offsets, OffsetsError},
+};
+
+/// Owned map types of the items in a [`Map`] layout.
+///
+/// Arrow stores a map as a list of key-value entries, so every map type that
+/// can be built from and turned into its entries can be stored. The entries
+/// of maps that iterate in key order are sorted, which Arrow records in the
+/// schema of the map:
+///
+/// ```text
+/// BTreeMap<K, V>   sorted keys
+/// HashMap<K, V>    unordered keys
+/// Entries<K, V>    keys in insertion order
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// use narrow::layout::map::{Entries, MapItem};
+///
+/// assert!(<BTreeMap<u8, bool> as MapItem<_, _>>::KEYS_SORTED);
+/// assert!(!<Entries<u8, bool> as MapItem<_, _>>::KEYS_SORTED);
+/// ```
+pub trait MapItem<K, V>: FromIterator<(K, V)> + IntoIterator<Item = (K, V)> {
+    /// Whether the entries of this map are sorted by key.
+    const KEYS_SORTED: bool;
+}
+
+impl<K: Ord, V> MapItem<K, V> for BTreeMap<K, V> {
+    const KEYS_SORTED: bool = true;
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<K: Eq + core::hash::Hash, V, S: core::hash::BuildHasher + Default> MapItem<K, V>
+    for std::collections::HashMap<K, V, S>
+{
+    const KEYS_SORTED: bool = false;
+}
+
+impl<K, V> MapItem<K, V> for Entries<K, V> {
+    const KEYS_SORTED: bool = false;
+}
+
+/// The entries of a map, stored in a [`Map`] layout.
+///
+/// Arrow maps are lists of entries that may contain duplicate keys, and that
+/// keep their entries in the order they were written. Collecting them into a
+/// `BTreeMap` or a `HashMap` drops both, so this wrapper of a `Vec` of
+/// key-value pairs preserves the entries as stored. A plain `Vec` of pairs
+/// already selects a list of structs.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{array::Array, collection::Collection, layout::map::Entries};
+///
+/// let values = [vec![(1, true), (1, false)], vec![]]
+///     .into_iter()
+///     .map(Entries::new)
+///     .collect::<Array<Entries<u8, bool>>>();
+/// assert_eq!(values.owned(0), Some(Entries::new(vec![(1, true), (1, false)])));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Entries<K, V>(Vec<(K, V)>);
+
+impl<K, V> Entries<K, V> {
+    /// Wraps `entries` for storage in a map layout.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::layout::map::Entries;
+    ///
+    /// let entries = Entries::new(vec![(1, "a")]);
+    /// assert_eq!(*entries, [(1, "a")]);
+    /// ```
+    #[must_use]
+    pub const fn new(entries: Vec<(K, V)>) -> Self {
+        Self(entries)
+    }
+
+    /// Returns the wrapped entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::layout::map::Entries;
+    ///
+    /// let entries = Entries::new(vec![(1, "a")]);
+    /// assert_eq!(entries.into_inner(), [(1, "a")]);
+    /// ```
+    #[must_use]
+    pub fn into_inner(self) -> Vec<(K, V)> {
+        self.0
+    }
+}
+
+impl<K, V> Deref for Entries<K, V> {
+    type Target = Vec<(K, V)>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for Entries<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+impl<K, V> IntoIterator for Entries<K, V> {
+    type Item = (K, V);
+    type IntoIter = alloc::vec::IntoIter<(K, V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/// The key-value entries of maps.
+///
+/// Arrow stores the entries of all maps in one struct child with a
+/// non-nullable key field and a value field.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{collection::Collection, layout::map::MapEntries};
+///
+/// let entries = [(1, Some(true)), (2, None)].into_iter().collect::<MapEntries<u8, Option<bool>>>();
+/// assert_eq!(entries.view(1), Some((2, None)));
+/// ```
+pub type MapEntries<K, V, Storage = VecBuffer> = Struct<(K, V), NonNullable, Storage>;
+
+/// Offsets into the key-value entries of maps.
+///
+/// <https://arrow.apache.org/docs/format/Columnar.html#map-layout>
+///
+/// A map is a list of entries, and the entries are a struct of a key and a
+/// value field. Keys are never null, which is why `K` is a [`Layout`] rather
+/// than an [`ArrayItem`]:
+///
+/// ```text
+/// offsets: [0, 2, 2, 3]
+/// keys:    [a, b, c]
+/// values:  [1, 2, null]
+/// items:   {a: 1, b: 2}  {}  {c: null}
+/// ```
+///
+/// `U` selects the owned map type, see [`MapItem`]. Map offsets are always
+/// `i32`.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// use narrow::{buffer::VecBuffer, collection::Collection, layout::map::MapValues};
+///
+/// let maps = [BTreeMap::from([(1, Some(2))]), BTreeMap::new()]
+///     .into_iter()
+///     .collect::<MapValues<u8, Option<u16>, VecBuffer, BTreeMap<_, _>>>();
+/// assert_eq!(maps.view(0).unwrap().view(0), Some((1, Some(2))));
+/// assert_eq!(maps.owned(1), Some(BTreeMap::new()));
+/// ```
+#[expect(
+    clippy::type_complexity,
+    reason = "offsets into a struct of key and value children"
+)]
+pub struct MapValues<K: Layout, V: ArrayItem, Storage: Buffer = VecBuffer, U = Entries<K, V>>(
+    Offsets<MapEntries<K, V, Storage>, i32, Storage, Vec<(K, V)>>,
+    PhantomData<U>,
+);
+
+impl<K: Layout, V: ArrayItem, Storage: Buffer, U> MapValues<K, V, Storage, U> {
+    /// Constructs [`MapValues`] from the `entries` of the maps and the
+    /// `offsets` buffer into them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`OffsetsError`] when the offsets are invalid, see
+    /// [`Offsets::try_from_parts`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{collection::Collection, layout::map::{Entries, MapValues}};
+    ///
+    /// let entries = [(1, true), (2, false)].into_iter().collect();
+    /// let maps = MapValues::<u8, bool>::try_from_parts(entries, vec![0, 0, 2]).unwrap();
+    /// assert_eq!(maps.owned(1), Some(Entries::new(vec![(1, true), (2, false)])));
+    /// ```
+    pub fn try_from_parts(
+        entries: MapEntries<K, V, Storage>,
+        offsets: Storage::For<i32>,
+    ) -> Result<Self, OffsetsError> {
+        Offsets::try_from_parts(entries, offsets).map(|maps| Self(maps, PhantomData))
+    }
+
+    /// Returns the entries and the offsets buffer of these [`MapValues`].
+    ///
+    /// This is the inverse of [`MapValues::try_from_parts`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{layout::map::MapValues, length::Length};
+    ///
+    /// let entries = [(1, true)].into_iter().collect();
+    /// let maps = MapValues::<u8, bool>::try_from_parts(entries, vec![0, 1]).unwrap();
+    /// let (entries, offsets) = maps.into_parts();
+    /// assert_eq!((entries.len(), offsets), (1, vec![0, 1]));
+    /// ```
+    #[must_use]
+    pub fn into_parts(self) -> (MapEntries<K, V, Storage>, Storage::For<i32>) {
+        self.0.into_parts()
+    }
+}
+
+impl<K: Layout, V: ArrayItem, Storage: Buffer, U> BufferRef for MapValues<K, V, Storage, U> {
+    type Buffer = Storage::For<i32>;
+
+    fn buffer_ref(&self) -> &Self::Buffer {
+        self.0.buffer_ref()
+    }
+}
+
+impl<K: Layout, V: ArrayItem, Storage: Buffer, U> ChildRef for MapValues<K, V, Storage, U> {
+    type Child = MapEntries<K, V, Storage>;
+
+    fn child_ref(&self) -> &Self::Child {
+        self.0.child_ref()
+    }
+}
+
+impl<K: Layout, V: ArrayItem, Storage: Buffer, U> Debug for MapValues<K, V, Storage, U>
+where
+    Offsets<MapEntries<K, V, Storage>, i32, Storage, Vec<(K, V)>>: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("MapValues").field(&self.0).finish()
+    }
+}
+
+impl<K: Layout, V: ArrayItem, Storage: Buffer, U> Clone for MapValues<K, V, Storage, U>
+where
+    Offsets<MapEntries<K, V, Storage>, i32, Storage, Vec<(K, V)>>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<K: Layout, V: ArrayItem, Storage: Buffer, U> Default for MapValues<K, V, Storage, U>
+where
+    Offsets<MapEntries<K, V, Storage>, i32, Storage, Vec<(K, V)>>: Default,
+{
+    fn default() -> Self {
+        Self(Offsets::default(), PhantomData)
+    }
+}
+
+impl<K: Layout, V: ArrayItem, Storage: Buffer, U: MapItem<K, V>> Extend<U>
+    for MapValues<K, V, Storage, U>
+where
+    Offsets<MapEntries<K, V, Storage>, i32, Storage, Vec<(K, V)>>: Extend<Vec<(K, V)>>,
+{
+    fn extend<I: IntoIterator<Item = U>>(&mut self, iter: I) {
+        self.0
+            .extend(iter.into_iter().map(|map| map.into_iter().collect()));
+    }
+}
+
+impl<K: Layout, V: ArrayItem, Storage: Buffer, U: MapItem<K, V>> FromIterator<U>
+    for MapValues<K, V, Storage, U>
+where
+    Offsets<MapEntries<K, V, Storage>, i32, Storage, Vec<(K, V)>>: FromIterator<Vec<(K, V)>>,
+{
+    fn from_iter<I: IntoIterator<Item = U>>(iter: I) -> Self {
+        Self(
+            iter.into_iter()
+                .map(|map| map.into_iter().collect())
+                .collect(),
+            PhantomData,
+        )
+    }
+}
+
+impl<K: Layout, V: ArrayItem, Storage: Buffer, U> Length for MapValues<K, V, Storage, U> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<K: Layout, V: ArrayItem, Storage: Buffer, U: MapItem<K, V>> Collection
+    for MapValues<K, V, Storage, U>
+{
+    type View<'collection>
+        = MapView<'collection, K, V, Storage, U>
+    where
+        Self: 'collection;
+
+    type Owned = U;
+
+    fn view(&self, index: usize) -> Option<Self::View<'_>> {
+        self.0.view(index).map(|entries| MapView {
+            entries,
+            _item: PhantomData,
+        })
+    }
+
+    type Iter<'collection>
+        = iter::Map<
+        Zip<Range<usize>, RepeatN<&'collection Self>>,
+        fn((usize, &'collection Self)) -> Self::View<'collection>,
+    >
+    where
+        Self: 'collection;
+
+    fn iter_views(&self) -> Self::Iter<'_> {
+        let len = self.len();
+        (0..len)
+            .zip(iter::repeat_n(self, len))
+            .map(|(index, maps)| maps.view(index).expect("index in range"))
+    }
+
+    type IntoIter = iter::Map<
+        OffsetIntoIter<MapEntries<K, V, Storage>, i32, Storage, Vec<(K, V)>>,
+        fn(Vec<(K, V)>) -> U,
+    >;
+
+    fn into_iter_owned(self) -> Self::IntoIter {
+        self.0
+            .into_iter_owned()
+            .map(|entries| entries.into_iter().collect())
+    }
+}
+
+impl<K: Layout, V: ArrayItem, Storage: Buffer, U: MapItem<K, V>> CollectionAllocIn
+    for MapValues<K, V, Storage, U>
+where
+    Offsets<MapEntries<K, V, Storage>, i32, Storage, Vec<(K, V)>>:
+        CollectionRealloc<Owned = Vec<(K, V)>>,
+{
+    type Alloc =
+        <Offsets<MapEntries<K, V, Storage>, i32, Storage, Vec<(K, V)>> as CollectionAllocIn>::Alloc;
+
+    fn with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Self {
+        Self(Offsets::with_capacity_in(capacity, alloc), PhantomData)
+    }
+
+    fn from_iter_in<I: IntoIterator<Item = Self::Owned>>(iter: I, alloc: Self::Alloc) -> Self {
+        Self(
+            Offsets::from_iter_in(iter.into_iter().map(|map| map.into_iter().collect()), alloc),
+            PhantomData,
+        )
+    }
+
+    fn try_with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Result<Self, AllocError> {
+        Offsets::try_with_capacity_in(capacity, alloc).map(|maps| Self(maps, PhantomData))
+    }
+
+    fn try_from_iter_in<I: IntoIterator<Item = Self::Owned>>(
+        iter: I,
+        alloc: Self::Alloc,
+    ) -> Result<Self, AllocError> {
+        Offsets::try_from_iter_in(iter.into_iter().map(|map| map.into_iter().collect()), alloc)
+            .map(|maps| Self(maps, PhantomData))
+    }
+}
+
+impl<K: Layout, V: ArrayItem, Storage: Buffer, U: MapItem<K, V>> CollectionRealloc
+    for MapValues<K, V, Storage, U>
+where
+    Offsets<MapEntries<K, V, Storage>, i32, Storage, Vec<(K, V)>>:
+        CollectionRealloc<Owned = Vec<(K, V)>>,
+{
+    fn allocator(&self) -> Self::Alloc {
+        self.0.allocator()
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+        self.0.try_reserve(additional)
+    }
+
+    fn try_extend<I: IntoIterator<Item = Self::Owned>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), AllocError> {
+        self.0
+            .try_extend(iter.into_iter().map(|map| map.into_iter().collect()))
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.0.truncate(len);
+    }
+}
+
+/// A borrowed view of one map in [`MapValues`].
+///
+/// The view is a [`Collection`] of the entries of the map, whose views are
+/// pairs of a key view and a value view. Reading a map through its view does
+/// not build the owned map.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{
+///     collection::Collection,
+///     layout::map::{Entries, MapValues},
+///     length::Length,
+/// };
+///
+/// let maps = [Entries::new(vec![(String::from("a"), 1), (String::from("b"), 2)])]
+///     .into_iter()
+///     .collect::<MapValues<String, u8>>();
+/// let view = maps.view(0).unwrap();
+/// assert_eq!(view.len(), 2);
+/// assert_eq!(view.iter_views().collect::<Vec<_>>(), [("a", 1), ("b", 2)]);
+/// ```
+#[expect(clippy::type_complexity, reason = "range of the entries of one map")]
+pub struct MapView<'collection, K: Layout, V: ArrayItem, Storage: Buffer, U> {
+    /// The entries of this map.
+    entries: OffsetView<'collection, MapEntries<K, V, Storage>, i32, Storage, Vec<(K, V)>>,
+    /// The owned map type.
+    _item: PhantomData<U>,
+}
+
+impl<K: Layout, V: ArrayItem, Storage: Buffer, U> Debug for MapView<'_, K, V, Storage, U>
+where
+    for<'any> OffsetView<'any, MapEntries<K, V, Storage>, i32, Storage, Vec<(K, V)>>: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MapView")
+            .field("entries", &self.entries)
+            .finish()
+    }
+}
+
+impl<K: Layout, V: ArrayItem, Storage: Buffer, U> Clone for MapView<'_, K, V, Storage, U> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K: Layout, V: ArrayItem, Storage: Buffer, U> Copy for MapView<'_, K, V, Storage, U> {}
+
+impl<C: Collection, K: Layout, V: ArrayItem, Storage: Buffer, U> PartialEq<C>
+    for MapView<'_, K, V, Storage, U>
+where
+    for<'any, 'other> (
+        <K::Memory<NonNullable, Storage> as Collection>::View<'any>,
+        <V::Memory<Storage> as Collection>::View<'any>,
+    ): PartialEq<C::View<'other>>,
+{
+    fn eq(&self, other: &C) -> bool {
+        self.len() == other.len()
+            && self
+                .iter_views()
+                .zip(other.iter_views())
+                .all(|(a, b)| a == b)
+    }
+}
+
+impl<K: Layout, V: ArrayItem, Storage: Buffer, U: MapItem<K, V>> IntoOwned<U>
+    for MapView<'_, K, V, Storage, U>
+{
+    fn into_owned(self) -> U {
+        self.entries.into_iter_owned().collect()
+    }
+}
+
+impl<K: Layout, V: ArrayItem, Storage: Buffer, U> Length for MapView<'_, K, V, Storage, U> {
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+}
+
+impl<'view, K: Layout, V: ArrayItem, Storage: Buffer, U> Collection
+    for MapView<'view, K, V, Storage, U>
+{
+    type View<'collection>
+        = <MapEntries<K, V, Storage> as Collection>::View<'collection>
+    where
+        Self: 'collection;
+
+    type Owned = (K, V);
+
+    fn view(&self, index: usize) -> Option<Self::View<'_>> {
+        self.entries.view(index)
+    }
+
+    type Iter<'collection>
+        = <OffsetView<'view, MapEntries<K, V, Storage>, i32, Storage, Vec<(K, V)>> as Collection>::Iter<'collection>
+    where
+        Self: 'collection;
+
+    fn iter_views(&self) -> Self::Iter<'_> {
+        self.entries.iter_views()
+    }
+
+    type IntoIter = <OffsetView<
+        'view,
+        MapEntries<K, V, Storage>,
+        i32,
+        Storage,
+        Vec<(K, V)>,
+    > as Collection>::IntoIter;
+
+    fn into_iter_owned(self) -> Self::IntoIter {
+        self.entries.into_iter_owned()
+    }
+}
+
+/// A collection of maps.
+///
+/// This is the Arrow `Map` layout: [`MapValues`] with outer nullability. The
+/// key and value layouts are obtained from [`Layout`] and [`ArrayItem`], and
+/// `Nulls` only controls the validity of the maps. `U` selects the owned map
+/// type.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// use narrow::{buffer::VecBuffer, collection::Collection, layout::map::Map, nullability::Nullable};
+///
+/// let maps = [Some(BTreeMap::from([(1, true)])), None]
+///     .into_iter()
+///     .collect::<Map<u8, bool, Nullable, VecBuffer, BTreeMap<_, _>>>();
+/// assert_eq!(maps.owned(0), Some(Some(BTreeMap::from([(1, true)]))));
+/// assert_eq!(maps.owned(1), Some(None));
+/// ```
+#[expect(clippy::type_complexity, reason = "nullable slice of map values")]
+pub struct Map<
+    K: Layout,
+    V: ArrayItem,
+    Nulls: Nullability = NonNullable,
+    Storage: Buffer = VecBuffer,
+    U: MapItem<K, V> = Entries<K, V>,
+>(Window<Nulls::Collection<MapValues<K, V, Storage, U>, Storage>>);
+
+impl<K: Layout, V: ArrayItem, Nulls: Nullability, Storage: Buffer, U: MapItem<K, V>> MemoryLayout
+    for Map<K, V, Nulls, Storage, U>
+{
+    fn slice(self, offset: usize, len: usize) -> Self {
+        Self(self.0.slice(offset, len))
+    }
+
+    fn offset(&self) -> usize {
+        self.0.offset()
+    }
+}
+
+impl<K: Layout, V: ArrayItem, Nulls: Nullability, Storage: Buffer, U: MapItem<K, V>>
+    Map<K, V, Nulls, Storage, U>
+{
+    /// Constructs a [`Map`] from its backing collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{
+    ///     collection::Collection,
+    ///     layout::map::{Entries, Map, MapValues},
+    /// };
+    ///
+    /// let entries = [(1, true)].into_iter().collect();
+    /// let maps = MapValues::try_from_parts(entries, vec![0, 1]).unwrap();
+    /// let maps = Map::<u8, bool>::from_buffer(maps);
+    /// assert_eq!(maps.owned(0), Some(Entries::new(vec![(1, true)])));
+    /// ```
+    #[must_use]
+    pub fn from_buffer(buffer: Nulls::Collection<MapValues<K, V, Storage, U>, Storage>) -> Self {
+        Self(Window::new(buffer))
+    }
+
+    /// Returns the backing collection of this [`Map`].
+    ///
+    /// This is the inverse of [`Map::from_buffer`]. The backing collection of
+    /// a slice still contains the items outside of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{layout::map::{Entries, Map}, length::Length};
+    ///
+    /// let maps = [Entries::new(vec![(1, true)])].into_iter().collect::<Map<u8, bool>>();
+    /// assert_eq!(maps.into_buffer().len(), 1);
+    /// ```
+    #[must_use]
+    pub fn into_buffer(self) -> Nulls::Collection<MapValues<K, V, Storage, U>, Storage> {
+        self.0.into_parts().0
+    }
+}
+
+impl<K: Layout, V: ArrayItem, Nulls: Nullability, Storage: Buffer, U: MapItem<K, V>> BufferRef
+    for Map<K, V, Nulls, Storage, U>
+{
+    type Buffer = Nulls::Collection<MapValues<K, V, Storage, U>, Storage>;
+
+    fn buffer_ref(&self) -> &Self::Buffer {
+        self.0.buffer_ref()
+    }
+}
+
+impl<K: Layout, V: ArrayItem, Nulls: Nullability, Storage: Buffer, U: MapItem<K, V>> Debug
+    for Map<K, V, Nulls, Storage, U>
+where
+    Nulls::Collection<MapValues<K, V, Storage, U>, Storage>: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Map").field(&self.0).finish()
+    }
+}
+
+impl<K: Layout, V: ArrayItem, Nulls: Nullability, Storage: Buffer, U: MapItem<K, V>> Clone
+    for Map<K, V, Nulls, Storage, U>
+where
+    Nulls::Collection<MapValues<K, V, Storage, U>, Storage>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<K: Layout, V: ArrayItem, Nulls: Nullability, Storage: Buffer, U: MapItem<K, V>> Default
+    for Map<K, V, Nulls, Storage, U>
+where
+    Nulls::Collection<MapValues<K, V, Storage, U>, Storage>: Default,
+{
+    fn default() -> Self {
+        Self(Window::default())
+    }
+}
+
+impl<K: Layout, V: ArrayItem, Nulls: Nullability, Storage: Buffer, U: MapItem<K, V>>
+    Extend<Nulls::Item<U>> for Map<K, V, Nulls, Storage, U>
+where
+    Window<Nulls::Collection<MapValues<K, V, Storage, U>, Storage>>: Extend<Nulls::Item<U>>,
+{
+    fn extend<I: IntoIterator<Item = Nulls::Item<U>>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl<K: Layout, V: ArrayItem, Nulls: Nullability, Storage: Buffer, U: MapItem<K, V>>
+    FromIterator<Nulls::Item<U>> for Map<K, V, Nulls, Storage, U>
+where
+    Nulls::Collection<MapValues<K, V, Storage, U>, Storage>: FromIterator<Nulls::Item<U>>,
+{
+    fn from_iter<I: IntoIterator<Item = Nulls::Item<U>>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<K: Layout, V: ArrayItem, Nulls: Nullability, Storage: Buffer, U: MapItem<K, V>> Length
+    for Map<K, V, Nulls, Storage, U>
+{
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<K: Layout, V: ArrayItem, Nulls: Nullability, Storage: Buffer, U: MapItem<K, V>> Collection
+    for Map<K, V, Nulls, Storage, U>
+{
+    type View<'collection>
+        = <Nulls::Collection<MapValues<K, V, Storage, U>, Storage> as Collection>::View<'collection>
+    where
+        Self: 'collection;
+
+    type Owned = Nulls::Item<U>;
+
+    fn view(&self, index: usize) -> Option<Self::View<'_>> {
+        self.0.view(index)
+    }
+
+    type Iter<'collection>
+        = <Window<Nulls::Collection<MapValues<K, V, Storage, U>, Storage>> as Collection>::Iter<
+        'collection,
+    >
+    where
+        Self: 'collection;
+
+    fn iter_views(&self) -> Self::Iter<'_> {
+        self.0.iter_views()
+    }
+
+    type IntoIter =
+        <Window<Nulls::Collection<MapValues<K, V, Storage, U>, Storage>> as Collection>::IntoIter;
+
+    fn into_iter_owned(self) -> Self::IntoIter {
+        self.0.into_iter_owned()
+    }
+}
+
+impl<K: Layout, V: ArrayItem, Nulls: Nullability, Storage: Buffer, U: MapItem<K, V>>
+    CollectionAllocIn for Map<K, V, Nulls, Storage, U>
+where
+    Nulls::Collection<MapValues<K, V, Storage, U>, Storage>: CollectionAllocIn,
+{
+    type Alloc =
+        <Nulls::Collection<MapValues<K, V, Storage, U>, Storage> as CollectionAllocIn>::Alloc;
+
+    fn with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Self {
+        Self(Window::with_capacity_in(capacity, alloc))
+    }
+
+    fn from_iter_in<I: IntoIterator<Item = Self::Owned>>(iter: I, alloc: Self::Alloc) -> Self {
+        Self(Window::from_iter_in(iter, alloc))
+    }
+
+    fn try_with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Result<Self, AllocError> {
+        Window::try_with_capacity_in(capacity, alloc).map(Self)
+    }
+
+    fn try_from_iter_in<I: IntoIterator<Item = Self::Owned>>(
+        iter: I,
+        alloc: Self::Alloc,
+    ) -> Result<Self, AllocError> {
+        Window::try_from_iter_in(iter, alloc).map(Self)
+    }
+}
+
+impl<K: Layout, V: ArrayItem, Nulls: Nullability, Storage: Buffer, U: MapItem<K, V>>
+    CollectionRealloc for Map<K, V, Nulls, Storage, U>
+where
+    Nulls::Collection<MapValues<K, V, Storage, U>, Storage>: CollectionRealloc,
+{
+    fn allocator(&self) -> Self::Alloc {
+        self.0.allocator()
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+        self.0.try_reserve(additional)
+    }
+
+    fn try_extend<I: IntoIterator<Item = Self::Owned>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), AllocError> {
+        self.0.try_extend(iter)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.0.truncate(len);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::{string::String, vec};
+
+    use crate::nullability::Nullable;
+
+    use super::*;
+
+    #[test]
+    fn try_from_parts() {
+        let entries = [(1, 2), (3, 4)].into_iter().collect();
+        let maps = MapValues::<u8, u16>::try_from_parts(entries, vec![0, 1, 2]).expect("valid");
+        assert_eq!(maps.owned(1), Some(Entries::new(vec![(3, 4)])));
+
+        let short = [(1, 2)].into_iter().collect();
+        assert_eq!(
+            MapValues::<u8, u16>::try_from_parts(short, vec![0, 2]).expect_err("out of bounds"),
+            OffsetsError::OutOfBounds { last: 2, data: 1 }
+        );
+    }
+
+    #[test]
+    fn views() {
+        let maps = [
+            BTreeMap::from([(String::from("b"), Some(2)), (String::from("a"), None)]),
+            BTreeMap::new(),
+        ]
+        .into_iter()
+        .collect::<Map<String, Option<u32>, NonNullable, VecBuffer, BTreeMap<_, _>>>();
+        let view = maps.view(0).expect("a map");
+        assert_eq!(view.len(), 2);
+        assert_eq!(
+            view.iter_views().collect::<Vec<_>>(),
+            [("a", None), ("b", Some(2))]
+        );
+        assert_eq!(view.view(1), Some(("b", Some(2))));
+        assert_eq!(maps.view(1).expect("a map").len(), 0);
+    }
+
+    #[test]
+    fn collection() {
+        let items = [
+            Entries::new(vec![(1, vec![1]), (1, vec![])]),
+            Entries::new(vec![]),
+        ];
+        let maps = items.clone().into_iter().collect::<Map<u8, Vec<u8>>>();
+        assert_eq!(maps.len(), 2);
+        assert_eq!(maps.owned(0), Some(items[0].clone()));
+        assert_eq!(maps.into_iter_owned().collect::<Vec<_>>(), items);
+
+        let nullable = [Some(BTreeMap::from([(2, true), (1, false)])), None];
+        let nullable_maps =
+            nullable
+                .clone()
+                .into_iter()
+                .collect::<Map<i64, bool, Nullable, VecBuffer, BTreeMap<_, _>>>();
+        let iter = nullable_maps.into_iter_owned();
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        assert_eq!(iter.collect::<Vec<_>>(), nullable);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hash_map() {
+        use std::collections::HashMap;
+
+        let items = [HashMap::from([(1, 2), (3, 4)]), HashMap::new()];
+        let maps = items
+            .clone()
+            .into_iter()
+            .collect::<Map<u8, u8, NonNullable, VecBuffer, HashMap<_, _>>>();
+        assert_eq!(maps.into_iter_owned().collect::<Vec<_>>(), items);
+    }
+
+    #[test]
+    fn try_extend_and_truncate() {
+        let mut maps = [Entries::new(vec![(1, 1)])]
+            .into_iter()
+            .collect::<Map<u8, u8>>();
+        CollectionRealloc::try_extend(
+            &mut maps,
+            [Entries::new(vec![(2, 2), (3, 3)]), Entries::new(vec![])],
+        )
+        .expect("reserved");
+        assert_eq!(maps.owned(1), Some(Entries::new(vec![(2, 2), (3, 3)])));
+        CollectionRealloc::truncate(&mut maps, 1);
+        maps.extend([Entries::new(vec![(4, 4)])]);
+        assert_eq!(
+            maps.into_iter_owned().collect::<Vec<_>>(),
+            [Entries::new(vec![(1, 1)]), Entries::new(vec![(4, 4)])]
+        );
+    }
+}
@@ -2,31 +2,61 @@
 
 extern crate alloc;
 
-use alloc::vec::Vec;
+use alloc::{boxed::Box, collections::BTreeMap, string::String, vec::Vec};
 
 use crate::{
     buffer::Buffer,
     collection::Collection,
     fixed_size::FixedSize,
     layout::{
-        boolean::Boolean, fixed_size_list::FixedSizeList, fixed_size_primitive::FixedSizePrimitive,
+        binary::{Binary, Bytes},
+        binary_view::{BinaryView, Viewed},
+        boolean::Boolean,
+        dictionary::{Dictionary, DictionaryEncoded, DictionaryKey, DictionaryValue},
+        extension::{Extension, Json, Uuid},
+        fixed_size_list::FixedSizeList,
+        fixed_size_primitive::FixedSizePrimitive,
+        list_view::{ListView, ViewedList},
+        map::{Entries, Map},
+        null::NullLayout,
+        run_end_encoded::{Run, RunEnd, RunEndEncoded},
+        utf8::{LargeString, Utf8},
+        utf8_view::Utf8View,
         variable_size_list::VariableSizeList,
     },
     nullability::{NonNullable, Nullability, Nullable},
+    offset::Offset,
 };
 
+pub mod binary;
+pub mod binary_view;
 pub mod boolean;
+pub mod dictionary;
+pub mod extension;
 pub mod fixed_size_list;
 pub mod fixed_size_primitive;
+pub mod list_view;
+pub mod map;
+pub mod null;
+pub mod run_end_encoded;
+pub mod struct_array;
+pub mod union;
+pub mod utf8;
+pub mod utf8_view;
 pub mod variable_size_binary;
 pub mod variable_size_list;
 
 /// A physical memory layout.
 ///
-/// This marker is the endpoint of type-level layout selection. Requiring
+/// This is the endpoint of type-level layout selection. Requiring
 /// [`Collection`] ensures every selected Arrow representation has the same
 /// access and construction vocabulary.
 ///
+/// Every layout can also be sliced without copying. A slice keeps all backing
+/// buffers and records the window of items it covers, like the `offset` of an
+/// Arrow array. Nested children are never sliced: the window applies to the
+/// items of the outermost layout, which index into their children as before.
+///
 /// # Examples
 ///
 /// ```
@@ -35,7 +65,45 @@ pub mod variable_size_list;
 /// fn assert_memory_layout<T: MemoryLayout>() {}
 /// assert_memory_layout::<FixedSizePrimitive<i32>>();
 /// ```
-pub trait MemoryLayout: Collection {}
+pub trait MemoryLayout: Collection {
+    /// Returns a slice of `len` items starting at `offset` in this layout,
+    /// sharing its backing buffers.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `offset + len` exceeds the length of this layout.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{
+    ///     collection::Collection,
+    ///     layout::{MemoryLayout, fixed_size_primitive::FixedSizePrimitive},
+    /// };
+    ///
+    /// let values = [1, 2, 3].into_iter().collect::<FixedSizePrimitive<i32>>();
+    /// let slice = values.slice(1, 2);
+    /// assert_eq!(slice.iter_views().collect::<Vec<_>>(), [2, 3]);
+    /// ```
+    #[must_use]
+    fn slice(self, offset: usize, len: usize) -> Self;
+
+    /// Returns the index of the first item of this layout in its backing
+    /// buffers.
+    ///
+    /// This is zero unless the layout was sliced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::layout::{MemoryLayout, fixed_size_primitive::FixedSizePrimitive};
+    ///
+    /// let values = [1, 2, 3].into_iter().collect::<FixedSizePrimitive<i32>>();
+    /// assert_eq!(values.offset(), 0);
+    /// assert_eq!(values.slice(1, 2).offset(), 1);
+    /// ```
+    fn offset(&self) -> usize;
+}
 
 /// Mapping a base type to its physical memory layout.
 ///
@@ -111,6 +179,17 @@ impl<T: NullableLayout> ArrayItem for Option<T> {
     type Memory<Storage: Buffer> = T::Memory<Nullable, Storage>;
 }
 
+/// `()` selects its layout without a [`Layout`]: every item of an Arrow null
+/// array is null, so there is no validity bitmap to add for `Option<()>`.
+impl ArrayItem for () {
+    type Memory<Storage: Buffer> = NullLayout<NonNullable, Storage>;
+}
+
+/// The items of a nullable [`NullLayout`] are always `None`.
+impl ArrayItem for Option<()> {
+    type Memory<Storage: Buffer> = NullLayout<Nullable, Storage>;
+}
+
 impl Layout for bool {
     type Memory<Nulls: Nullability, Storage: Buffer> = Boolean<Nulls, Storage>;
 }
@@ -123,6 +202,42 @@ impl<T: FixedSize> Layout for T {
 
 impl<T: FixedSize> NullableLayout for T {}
 
+impl Layout for String {
+    type Memory<Nulls: Nullability, Storage: Buffer> = Utf8<Nulls, i32, Storage, String>;
+}
+
+impl NullableLayout for String {}
+
+impl Layout for Box<str> {
+    type Memory<Nulls: Nullability, Storage: Buffer> = Utf8<Nulls, i32, Storage, Box<str>>;
+}
+
+impl NullableLayout for Box<str> {}
+
+impl Layout for LargeString {
+    type Memory<Nulls: Nullability, Storage: Buffer> = Utf8<Nulls, i64, Storage, LargeString>;
+}
+
+impl NullableLayout for LargeString {}
+
+impl Layout for Viewed<String> {
+    type Memory<Nulls: Nullability, Storage: Buffer> = Utf8View<Nulls, Storage>;
+}
+
+impl NullableLayout for Viewed<String> {}
+
+impl<OffsetItem: Offset> Layout for Bytes<OffsetItem> {
+    type Memory<Nulls: Nullability, Storage: Buffer> = Binary<Nulls, OffsetItem, Storage>;
+}
+
+impl<OffsetItem: Offset> NullableLayout for Bytes<OffsetItem> {}
+
+impl Layout for Viewed<Vec<u8>> {
+    type Memory<Nulls: Nullability, Storage: Buffer> = BinaryView<Nulls, Storage>;
+}
+
+impl NullableLayout for Viewed<Vec<u8>> {}
+
 impl<T: ArrayItem, const N: usize> Layout for [T; N] {
     type Memory<Nulls: Nullability, Storage: Buffer> = FixedSizeList<T, N, Nulls, Storage>;
 }
@@ -135,17 +250,95 @@ impl<T: ArrayItem> Layout for Vec<T> {
 
 impl<T: ArrayItem> NullableLayout for Vec<T> {}
 
+impl<T: ArrayItem, OffsetItem: Offset> Layout for ViewedList<T, OffsetItem> {
+    type Memory<Nulls: Nullability, Storage: Buffer> = ListView<T, Nulls, OffsetItem, Storage>;
+}
+
+impl<T: ArrayItem, OffsetItem: Offset> NullableLayout for ViewedList<T, OffsetItem> {}
+
+impl<K: Layout + Ord, V: ArrayItem> Layout for BTreeMap<K, V> {
+    type Memory<Nulls: Nullability, Storage: Buffer> = Map<K, V, Nulls, Storage, Self>;
+}
+
+impl<K: Layout + Ord, V: ArrayItem> NullableLayout for BTreeMap<K, V> {}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<K, V, S> Layout for std::collections::HashMap<K, V, S>
+where
+    K: Layout + Eq + core::hash::Hash,
+    V: ArrayItem,
+    S: core::hash::BuildHasher + Default,
+{
+    type Memory<Nulls: Nullability, Storage: Buffer> = Map<K, V, Nulls, Storage, Self>;
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<K, V, S> NullableLayout for std::collections::HashMap<K, V, S>
+where
+    K: Layout + Eq + core::hash::Hash,
+    V: ArrayItem,
+    S: core::hash::BuildHasher + Default,
+{
+}
+
+impl<K: Layout, V: ArrayItem> Layout for Entries<K, V> {
+    type Memory<Nulls: Nullability, Storage: Buffer> = Map<K, V, Nulls, Storage>;
+}
+
+impl<K: Layout, V: ArrayItem> NullableLayout for Entries<K, V> {}
+
+impl<K: DictionaryKey, V: DictionaryValue> Layout for Dictionary<K, V> {
+    type Memory<Nulls: Nullability, Storage: Buffer> = DictionaryEncoded<K, V, Nulls, Storage>;
+}
+
+impl<K: DictionaryKey, V: DictionaryValue> NullableLayout for Dictionary<K, V> {}
+
+/// [`Run`] selects its layout without a [`Layout`]: Arrow run-end encoded
+/// arrays have no validity bitmap, so there is no nullable variant. Null
+/// items are null run values.
+impl<R: RunEnd, V: ArrayItem + Clone + PartialEq> ArrayItem for Run<R, V> {
+    type Memory<Storage: Buffer> = RunEndEncoded<R, V, Storage>;
+}
+
+impl Layout for Uuid {
+    type Memory<Nulls: Nullability, Storage: Buffer> = Extension<Self, Nulls, Storage>;
+}
+
+impl NullableLayout for Uuid {}
+
+impl Layout for Json {
+    type Memory<Nulls: Nullability, Storage: Buffer> = Extension<Self, Nulls, Storage>;
+}
+
+impl NullableLayout for Json {}
+
 #[cfg(test)]
 mod tests {
     extern crate alloc;
 
-    use alloc::vec::Vec;
+    use alloc::{boxed::Box, collections::BTreeMap, string::String, vec::Vec};
 
     use crate::{
         buffer::VecBuffer,
         layout::{
-            ArrayItem, boolean::Boolean, fixed_size_list::FixedSizeList,
-            fixed_size_primitive::FixedSizePrimitive, variable_size_list::VariableSizeList,
+            ArrayItem,
+            binary::{Binary, Bytes},
+            binary_view::{BinaryView, Viewed},
+            boolean::Boolean,
+            dictionary::{Dictionary, DictionaryEncoded},
+            extension::{Extension, Json, Uuid},
+            fixed_size_list::FixedSizeList,
+            fixed_size_primitive::FixedSizePrimitive,
+            list_view::{ListView, ViewedList},
+            map::{Entries, Map},
+            null::NullLayout,
+            run_end_encoded::{Run, RunEndEncoded},
+            struct_array::Struct,
+            utf8::{LargeString, LargeUtf8, Utf8},
+            utf8_view::Utf8View,
+            variable_size_list::VariableSizeList,
         },
         nullability::{NonNullable, Nullable},
     };
@@ -154,13 +347,37 @@ mod tests {
 
     #[test]
     fn array_item_selects_nullability() {
+        assert_memory::<(), NullLayout>();
+        assert_memory::<Option<()>, NullLayout<Nullable>>();
         assert_memory::<bool, Boolean<NonNullable, VecBuffer>>();
         assert_memory::<Option<bool>, Boolean<Nullable, VecBuffer>>();
         assert_memory::<i32, FixedSizePrimitive<i32, NonNullable, VecBuffer>>();
         assert_memory::<Option<i32>, FixedSizePrimitive<i32, Nullable, VecBuffer>>();
+        assert_memory::<String, Utf8>();
+        assert_memory::<Option<String>, Utf8<Nullable>>();
+        assert_memory::<Box<str>, Utf8<NonNullable, i32, VecBuffer, Box<str>>>();
+        assert_memory::<Option<LargeString>, LargeUtf8<Nullable, VecBuffer, LargeString>>();
+        assert_memory::<Option<Viewed<String>>, Utf8View<Nullable>>();
+        assert_memory::<Bytes, Binary>();
+        assert_memory::<Option<Bytes<i64>>, Binary<Nullable, i64>>();
+        assert_memory::<Viewed<Vec<u8>>, BinaryView>();
         assert_memory::<[Option<i32>; 2], FixedSizeList<Option<i32>, 2>>();
         assert_memory::<Option<[Option<i32>; 2]>, FixedSizeList<Option<i32>, 2, Nullable>>();
         assert_memory::<Vec<Option<i32>>, VariableSizeList<Option<i32>>>();
         assert_memory::<Option<Vec<Option<i32>>>, VariableSizeList<Option<i32>, Nullable>>();
+        assert_memory::<ViewedList<u8>, ListView<u8>>();
+        assert_memory::<Option<ViewedList<u8, i64>>, ListView<u8, Nullable, i64>>();
+        assert_memory::<Entries<u8, Option<i32>>, Map<u8, Option<i32>>>();
+        assert_memory::<
+            Option<BTreeMap<String, u8>>,
+            Map<String, u8, Nullable, VecBuffer, BTreeMap<String, u8>>,
+        >();
+        assert_memory::<(i32, Option<bool>), Struct<(i32, Option<bool>)>>();
+        assert_memory::<Option<(i32, Option<bool>)>, Struct<(i32, Option<bool>), Nullable>>();
+        assert_memory::<Dictionary<u8, String>, DictionaryEncoded<u8, String>>();
+        assert_memory::<Option<Dictionary<u8, String>>, DictionaryEncoded<u8, String, Nullable>>();
+        assert_memory::<Run<i32, Option<u8>>, RunEndEncoded<i32, Option<u8>>>();
+        assert_memory::<Uuid, Extension<Uuid>>();
+        assert_memory::<Option<Json>, Extension<Json, Nullable>>();
     }
 }
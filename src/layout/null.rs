@@ -0,0 +1,280 @@
+//! All-null values.
+
+use core::{
+    fmt::{self, Debug},
+    iter::{self, RepeatN},
+    marker::PhantomData,
+};
+
+use crate::{
+    buffer::{Buffer, VecBuffer},
+    collection::{AllocError, Collection, CollectionAllocIn, CollectionRealloc},
+    layout::MemoryLayout,
+    length::Length,
+    nullability::{NonNullable, Nullability, Nullable},
+};
+
+/// A collection of null items, stored as a count.
+///
+/// <https://arrow.apache.org/docs/format/Columnar.html#null-layout>
+///
+/// Arrow's null type has no buffers: every item is null, so the length is the
+/// only information left to store. `()` selects this layout, and so does
+/// `Option<()>`, whose items are always `None`. Collecting `Some(())` into a
+/// nullable [`NullLayout`] only counts it, and it reads back as `None`.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{collection::Collection, layout::null::NullLayout, length::Length};
+///
+/// let units = [(), ()].into_iter().collect::<NullLayout>();
+/// assert_eq!((units.len(), units.owned(1)), (2, Some(())));
+/// ```
+pub struct NullLayout<Nulls: Nullability = NonNullable, Storage: Buffer = VecBuffer> {
+    /// The index of the first item in the backing count.
+    offset: usize,
+    /// The number of items.
+    len: usize,
+    /// The item nullability and storage, which hold no data.
+    _layout: PhantomData<fn() -> (Nulls, Storage)>,
+}
+
+impl<Nulls: Nullability, Storage: Buffer> NullLayout<Nulls, Storage> {
+    /// Constructs a [`NullLayout`] of `len` items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{collection::Collection, layout::null::NullLayout, nullability::Nullable};
+    ///
+    /// let nulls = NullLayout::<Nullable>::new(3);
+    /// assert_eq!(nulls.owned(2), Some(None));
+    /// ```
+    #[must_use]
+    pub const fn new(len: usize) -> Self {
+        Self {
+            offset: 0,
+            len,
+            _layout: PhantomData,
+        }
+    }
+}
+
+impl<Nulls: Nullability, Storage: Buffer> MemoryLayout for NullLayout<Nulls, Storage>
+where
+    Self: Collection,
+{
+    fn slice(self, offset: usize, len: usize) -> Self {
+        assert!(
+            offset.checked_add(len).is_some_and(|end| end <= self.len),
+            "slice at offset {offset} with length {len} exceeds length {}",
+            self.len
+        );
+        Self {
+            offset: self.offset.strict_add(offset),
+            len,
+            _layout: PhantomData,
+        }
+    }
+
+    fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl<Nulls: Nullability, Storage: Buffer> Debug for NullLayout<Nulls, Storage> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NullLayout")
+            .field("offset", &self.offset)
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+impl<Nulls: Nullability, Storage: Buffer> Clone for NullLayout<Nulls, Storage> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Nulls: Nullability, Storage: Buffer> Copy for NullLayout<Nulls, Storage> {}
+
+impl<Nulls: Nullability, Storage: Buffer> Default for NullLayout<Nulls, Storage> {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl<Nulls: Nullability, Storage: Buffer> Length for NullLayout<Nulls, Storage> {
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<Nulls: Nullability, Storage: Buffer> FromIterator<Nulls::Item<()>>
+    for NullLayout<Nulls, Storage>
+{
+    fn from_iter<I: IntoIterator<Item = Nulls::Item<()>>>(iter: I) -> Self {
+        Self::new(iter.into_iter().count())
+    }
+}
+
+impl<Nulls: Nullability, Storage: Buffer> Extend<Nulls::Item<()>> for NullLayout<Nulls, Storage> {
+    fn extend<I: IntoIterator<Item = Nulls::Item<()>>>(&mut self, iter: I) {
+        self.len = self.len.strict_add(iter.into_iter().count());
+    }
+}
+
+impl<Storage: Buffer> Collection for NullLayout<NonNullable, Storage> {
+    type View<'collection>
+        = ()
+    where
+        Self: 'collection;
+
+    type Owned = ();
+
+    fn view(&self, index: usize) -> Option<Self::View<'_>> {
+        (index < self.len).then_some(())
+    }
+
+    type Iter<'collection>
+        = RepeatN<()>
+    where
+        Self: 'collection;
+
+    fn iter_views(&self) -> Self::Iter<'_> {
+        iter::repeat_n((), self.len)
+    }
+
+    type IntoIter = RepeatN<()>;
+
+    fn into_iter_owned(self) -> Self::IntoIter {
+        iter::repeat_n((), self.len)
+    }
+}
+
+impl<Storage: Buffer> Collection for NullLayout<Nullable, Storage> {
+    type View<'collection>
+        = Option<()>
+    where
+        Self: 'collection;
+
+    type Owned = Option<()>;
+
+    fn view(&self, index: usize) -> Option<Self::View<'_>> {
+        (index < self.len).then_some(None)
+    }
+
+    type Iter<'collection>
+        = RepeatN<Option<()>>
+    where
+        Self: 'collection;
+
+    fn iter_views(&self) -> Self::Iter<'_> {
+        iter::repeat_n(None, self.len)
+    }
+
+    type IntoIter = RepeatN<Option<()>>;
+
+    fn into_iter_owned(self) -> Self::IntoIter {
+        iter::repeat_n(None, self.len)
+    }
+}
+
+/// Null items need no allocation, so the allocator is `()` like the one of
+/// [`VecBuffer`] storage, letting null fields share it in a struct.
+impl<Nulls: Nullability, Storage: Buffer> CollectionAllocIn for NullLayout<Nulls, Storage>
+where
+    Self: Collection,
+{
+    type Alloc = ();
+
+    fn with_capacity_in(_capacity: usize, _alloc: Self::Alloc) -> Self {
+        Self::default()
+    }
+
+    fn from_iter_in<I: IntoIterator<Item = Self::Owned>>(iter: I, _alloc: Self::Alloc) -> Self {
+        Self::new(iter.into_iter().count())
+    }
+
+    fn try_with_capacity_in(_capacity: usize, _alloc: Self::Alloc) -> Result<Self, AllocError> {
+        Ok(Self::default())
+    }
+
+    fn try_from_iter_in<I: IntoIterator<Item = Self::Owned>>(
+        iter: I,
+        _alloc: Self::Alloc,
+    ) -> Result<Self, AllocError> {
+        Ok(Self::new(iter.into_iter().count()))
+    }
+}
+
+impl<Nulls: Nullability, Storage: Buffer> CollectionRealloc for NullLayout<Nulls, Storage>
+where
+    Self: Collection<Owned = Nulls::Item<()>>,
+{
+    fn allocator(&self) -> Self::Alloc {}
+
+    fn try_reserve(&mut self, _additional: usize) -> Result<(), AllocError> {
+        Ok(())
+    }
+
+    fn try_extend<I: IntoIterator<Item = Self::Owned>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), AllocError> {
+        self.extend(iter);
+        Ok(())
+    }
+
+    fn reserve(&mut self, _additional: usize) {}
+
+    fn truncate(&mut self, len: usize) {
+        self.len = self.len.min(len);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::vec::Vec;
+
+    use crate::collection::tests::round_trip;
+
+    use super::*;
+
+    #[test]
+    fn collection() {
+        round_trip::<NullLayout, _>([(), (), ()]);
+        round_trip::<NullLayout<Nullable>, _>([None, None]);
+    }
+
+    #[test]
+    fn nullable_items_are_null() {
+        let nulls = [Some(()), None]
+            .into_iter()
+            .collect::<NullLayout<Nullable>>();
+        assert_eq!(nulls.len(), 2);
+        assert_eq!(nulls.view(0), Some(None));
+        assert_eq!(nulls.view(2), None);
+    }
+
+    #[test]
+    fn slice_extend_and_truncate() {
+        let mut units = NullLayout::<NonNullable>::new(4).slice(1, 2);
+        assert_eq!((units.offset(), units.len()), (1, 2));
+        units.extend([()]);
+        assert_eq!(units.iter_views().count(), 3);
+        units.try_extend([(), ()]).expect("no allocation");
+        units.truncate(1);
+        assert_eq!(units.into_iter_owned().collect::<Vec<_>>(), [()]);
+    }
+
+    #[test]
+    #[should_panic(expected = "slice at offset 1 with length 2 exceeds length 2")]
+    fn slice_out_of_bounds() {
+        let _units = NullLayout::<NonNullable>::new(2).slice(1, 2);
+    }
+}
@@ -0,0 +1,1173 @@
+//! Run-end encoded values.
+
+use core::{
+    borrow::{Borrow, BorrowMut},
+    fmt::{self, Debug},
+    iter,
+    marker::PhantomData,
+    ops::Deref,
+};
+
+use crate::{
+    buffer::{Buffer, BufferRef, VecBuffer},
+    collection::{
+        AllocError, ChildRef, Collection, CollectionAllocIn, CollectionRealloc, owned::IntoOwned,
+        view::AsView, window::Window,
+    },
+    fixed_size::FixedSize,
+    layout::{ArrayItem, MemoryLayout},
+    length::Length,
+};
+
+/// Integer types that can store the run ends of run-end encoded values.
+///
+/// Arrow restricts run ends to signed integers of 16, 32 or 64 bits. The
+/// width bounds the logical length of the array: the last run end is the
+/// number of items.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::layout::run_end_encoded::RunEnd;
+///
+/// fn assert_run_end<R: RunEnd>() {}
+/// assert_run_end::<i16>();
+/// assert_run_end::<i64>();
+/// ```
+pub trait RunEnd: FixedSize + TryFrom<usize> + TryInto<usize> + sealed::Sealed {}
+
+impl RunEnd for i16 {}
+impl RunEnd for i32 {}
+impl RunEnd for i64 {}
+
+/// Private module for [`sealed::Sealed`] trait.
+mod sealed {
+    /// Used to seal [`super::RunEnd`].
+    pub trait Sealed {}
+
+    /// Prevent downstream implementations of [`super::RunEnd`].
+    impl<T> Sealed for T where T: super::RunEnd {}
+}
+
+/// A value of type `V` stored with run-end encoding, using run ends of type
+/// `R`.
+///
+/// Like dictionary encoding, run-end encoding is a storage decision. Wrapping
+/// the value selects the [`RunEndEncoded`] layout, which stores consecutive
+/// equal values once. Views wrap the view of the run value, which they
+/// dereference to.
+///
+/// Arrow run-end encoded arrays have no validity bitmap, so `Run` has no
+/// nullable layout. Null items are stored as null run values instead:
+///
+/// ```text
+/// Run<i32, Option<u8>>  nullable items, nulls in the values child
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{array::Array, collection::Collection, layout::run_end_encoded::Run};
+///
+/// let values = [Some(1), Some(1), None]
+///     .into_iter()
+///     .map(Run::new)
+///     .collect::<Array<Run<i32, Option<u8>>>>();
+/// assert_eq!(values.view(1).as_deref(), Some(&Some(1)));
+/// assert_eq!(values.owned(2).unwrap().into_inner(), None);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Run<R: RunEnd, V>(V, PhantomData<R>);
+
+impl<R: RunEnd, V> Run<R, V> {
+    /// Wraps `value` for run-end encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::layout::run_end_encoded::Run;
+    ///
+    /// let value = Run::<i16, _>::new(1);
+    /// assert_eq!(*value, 1);
+    /// ```
+    #[must_use]
+    pub const fn new(value: V) -> Self {
+        Self(value, PhantomData)
+    }
+
+    /// Returns the wrapped value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::layout::run_end_encoded::Run;
+    ///
+    /// let value = Run::<i16, _>::new(vec![1]);
+    /// assert_eq!(value.into_inner(), [1]);
+    /// ```
+    #[must_use]
+    pub fn into_inner(self) -> V {
+        self.0
+    }
+}
+
+impl<R: RunEnd, V> Deref for Run<R, V> {
+    type Target = V;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<R: RunEnd, V, T: IntoOwned<V>> IntoOwned<Run<R, V>> for Run<R, T> {
+    fn into_owned(self) -> Run<R, V> {
+        Run::new(self.0.into_owned())
+    }
+}
+
+impl<'collection, R: RunEnd, V: AsView<'collection>> AsView<'collection> for Run<R, V> {
+    type View = Run<R, V::View>;
+
+    fn as_view(&'collection self) -> Self::View {
+        Run::new(self.0.as_view())
+    }
+}
+
+/// Returns the item position of a validated run end.
+fn position<R: RunEnd>(end: R) -> usize {
+    end.try_into()
+        .unwrap_or_else(|_| panic!("run ends are positive"))
+}
+
+/// Run ends of the runs of equal values.
+///
+/// <https://arrow.apache.org/docs/format/Columnar.html#run-end-encoded-layout>
+///
+/// Every run of consecutive equal items is stored as one value and the
+/// logical index one past its last item. Columns with long runs shrink to
+/// two entries per run:
+///
+/// ```text
+/// items:    [1, 1, 1, 2, 2, 1]
+/// run ends: [3, 5, 6]
+/// values:   [1, 2, 1]
+/// ```
+///
+/// Indexing binary-searches the run ends, while iteration walks the runs in
+/// order. Extending compares each value with the last run value, which
+/// requires `V: PartialEq`, and extends that run instead of starting a new
+/// one when they are equal.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{buffer::BufferRef, collection::{ChildRef, Collection}, length::Length};
+/// use narrow::layout::run_end_encoded::{Run, RunEnds};
+///
+/// let runs = [1, 1, 2].into_iter().map(Run::new).collect::<RunEnds<i32, u8>>();
+/// assert_eq!(runs.len(), 3);
+/// assert_eq!(runs.buffer_ref(), &[2, 3]);
+/// assert_eq!(runs.child_ref().len(), 2);
+/// assert_eq!(runs.view(1).as_deref(), Some(&1));
+/// ```
+pub struct RunEnds<R: RunEnd, V: ArrayItem, Storage: Buffer = VecBuffer> {
+    /// The run ends.
+    ends: Storage::For<R>,
+    /// The value of every run.
+    values: V::Memory<Storage>,
+    /// The value of the last run, once it has been compared against.
+    last: Option<V>,
+}
+
+/// Error returned by [`RunEnds::try_from_parts`].
+///
+/// # Examples
+///
+/// ```
+/// use narrow::layout::run_end_encoded::{RunEnds, RunEndsError};
+///
+/// let values = [1, 2].into_iter().collect();
+/// let error = RunEnds::<i32, u8>::try_from_parts(vec![2, 2], values).unwrap_err();
+/// assert_eq!(error, RunEndsError::NotIncreasing { index: 1 });
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RunEndsError {
+    /// The number of run ends and run values differ.
+    LengthMismatch {
+        /// The number of run ends.
+        run_ends: usize,
+        /// The number of run values.
+        values: usize,
+    },
+    /// The run end at `index` is not positive or not greater than the run
+    /// end before it.
+    NotIncreasing {
+        /// The index of the invalid run end.
+        index: usize,
+    },
+}
+
+impl fmt::Display for RunEndsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::LengthMismatch { run_ends, values } => write!(
+                f,
+                "number of run ends ({run_ends}) does not match number of values ({values})"
+            ),
+            Self::NotIncreasing { index } => {
+                write!(f, "run end at index {index} is not strictly increasing")
+            }
+        }
+    }
+}
+
+impl core::error::Error for RunEndsError {}
+
+impl<R: RunEnd, V: ArrayItem, Storage: Buffer> RunEnds<R, V, Storage> {
+    /// Constructs [`RunEnds`] from a `run_ends` buffer and the run `values`.
+    ///
+    /// Adjacent runs are not required to have distinct values.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RunEndsError`] when the number of run ends and values
+    /// differ, or when the run ends are not positive and strictly increasing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{collection::Collection, layout::run_end_encoded::RunEnds, length::Length};
+    ///
+    /// let values = [3, 4].into_iter().collect();
+    /// let runs = RunEnds::<i16, u32>::try_from_parts(vec![1, 4], values).unwrap();
+    /// assert_eq!(runs.len(), 4);
+    /// assert_eq!(runs.view(3).as_deref(), Some(&4));
+    /// ```
+    pub fn try_from_parts(
+        run_ends: Storage::For<R>,
+        values: V::Memory<Storage>,
+    ) -> Result<Self, RunEndsError> {
+        let (run_ends_len, values_len) = (run_ends.borrow().len(), values.len());
+        if run_ends_len != values_len {
+            return Err(RunEndsError::LengthMismatch {
+                run_ends: run_ends_len,
+                values: values_len,
+            });
+        }
+        run_ends
+            .borrow()
+            .iter()
+            .enumerate()
+            .try_fold(0, |previous, (index, &end)| match end.try_into() {
+                Ok(end_position) if end_position > previous => Ok(end_position),
+                Ok(_) | Err(_) => Err(RunEndsError::NotIncreasing { index }),
+            })?;
+        Ok(Self {
+            ends: run_ends,
+            values,
+            last: None,
+        })
+    }
+
+    /// Returns the run ends buffer and the run values of these [`RunEnds`].
+    ///
+    /// This is the inverse of [`RunEnds::try_from_parts`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::layout::run_end_encoded::{Run, RunEnds};
+    ///
+    /// let runs = [5, 5, 6].into_iter().map(Run::new).collect::<RunEnds<i64, u32>>();
+    /// let (run_ends, values) = runs.into_parts();
+    /// assert_eq!((run_ends, values.into_buffer()), (vec![2, 3], vec![5, 6]));
+    /// ```
+    #[must_use]
+    pub fn into_parts(self) -> (Storage::For<R>, V::Memory<Storage>) {
+        (self.ends, self.values)
+    }
+}
+
+impl<R: RunEnd, V: ArrayItem + Clone + PartialEq, Storage: Buffer> RunEnds<R, V, Storage>
+where
+    Storage::For<R>: BorrowMut<[R]> + CollectionRealloc,
+{
+    /// Appends `value` to the last run when it holds an equal value, and
+    /// starts a new run with `push` otherwise.
+    ///
+    /// Returns an [`AllocError`] when `R` cannot represent the new run end.
+    fn try_push(
+        &mut self,
+        value: V,
+        push: impl FnOnce(&mut V::Memory<Storage>, V) -> Result<(), AllocError>,
+        push_end: impl FnOnce(&mut Storage::For<R>, R) -> Result<(), AllocError>,
+    ) -> Result<(), AllocError> {
+        let end = self
+            .len()
+            .checked_add(1)
+            .and_then(|len| R::try_from(len).ok())
+            .ok_or(AllocError)?;
+        // Runs added through `try_from_parts` or `truncate` are not cached
+        // yet.
+        let runs = self.ends.borrow().len();
+        if self.last.is_none() {
+            self.last = runs.checked_sub(1).and_then(|run| self.values.owned(run));
+        }
+        if self.last.as_ref() == Some(&value) {
+            if let Some(last_end) = self.ends.borrow_mut().last_mut() {
+                *last_end = end;
+            }
+            Ok(())
+        } else {
+            push(&mut self.values, value.clone())?;
+            push_end(&mut self.ends, end)?;
+            self.last = Some(value);
+            Ok(())
+        }
+    }
+}
+
+impl<R: RunEnd, V: ArrayItem, Storage: Buffer> BufferRef for RunEnds<R, V, Storage> {
+    type Buffer = Storage::For<R>;
+
+    fn buffer_ref(&self) -> &Self::Buffer {
+        &self.ends
+    }
+}
+
+impl<R: RunEnd, V: ArrayItem, Storage: Buffer> ChildRef for RunEnds<R, V, Storage> {
+    type Child = V::Memory<Storage>;
+
+    fn child_ref(&self) -> &Self::Child {
+        &self.values
+    }
+}
+
+impl<R: RunEnd, V: ArrayItem, Storage: Buffer> Debug for RunEnds<R, V, Storage>
+where
+    Storage::For<R>: Debug,
+    V::Memory<Storage>: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RunEnds")
+            .field("ends", &self.ends)
+            .field("values", &self.values)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R: RunEnd, V: ArrayItem + Clone, Storage: Buffer> Clone for RunEnds<R, V, Storage>
+where
+    Storage::For<R>: Clone,
+    V::Memory<Storage>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            ends: self.ends.clone(),
+            values: self.values.clone(),
+            last: self.last.clone(),
+        }
+    }
+}
+
+impl<R: RunEnd, V: ArrayItem, Storage: Buffer> Default for RunEnds<R, V, Storage>
+where
+    Storage::For<R>: Default,
+    V::Memory<Storage>: Default,
+{
+    fn default() -> Self {
+        Self {
+            ends: Storage::For::<R>::default(),
+            values: V::Memory::<Storage>::default(),
+            last: None,
+        }
+    }
+}
+
+impl<R: RunEnd, V: ArrayItem + Clone + PartialEq, Storage: Buffer> Extend<Run<R, V>>
+    for RunEnds<R, V, Storage>
+where
+    Storage::For<R>: BorrowMut<[R]> + CollectionRealloc,
+    V::Memory<Storage>: Extend<V>,
+{
+    /// Extends the runs with the items of `iter`.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `R` cannot represent the end of the last run. Use
+    /// [`CollectionRealloc::try_extend`] to handle this case.
+    fn extend<I: IntoIterator<Item = Run<R, V>>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|Run(value, _)| {
+            self.try_push(
+                value,
+                |values, new| {
+                    values.extend(iter::once(new));
+                    Ok(())
+                },
+                |run_ends, end| {
+                    run_ends.extend(iter::once(end));
+                    Ok(())
+                },
+            )
+            .expect("run end overflow");
+        });
+    }
+}
+
+impl<R: RunEnd, V: ArrayItem + Clone + PartialEq, Storage: Buffer> FromIterator<Run<R, V>>
+    for RunEnds<R, V, Storage>
+where
+    Self: Default + Extend<Run<R, V>>,
+{
+    fn from_iter<I: IntoIterator<Item = Run<R, V>>>(iter: I) -> Self {
+        let mut runs = Self::default();
+        runs.extend(iter);
+        runs
+    }
+}
+
+impl<R: RunEnd, V: ArrayItem, Storage: Buffer> Length for RunEnds<R, V, Storage> {
+    fn len(&self) -> usize {
+        self.ends.borrow().last().map_or(0, |&end| position(end))
+    }
+}
+
+impl<R: RunEnd, V: ArrayItem, Storage: Buffer> Collection for RunEnds<R, V, Storage> {
+    type View<'collection>
+        = Run<R, <V::Memory<Storage> as Collection>::View<'collection>>
+    where
+        Self: 'collection;
+
+    type Owned = Run<R, V>;
+
+    fn view(&self, index: usize) -> Option<Self::View<'_>> {
+        let run = self
+            .ends
+            .borrow()
+            .partition_point(|&end| position(end) <= index);
+        self.values.view(run).map(Run::new)
+    }
+
+    type Iter<'collection>
+        = RunEndsIter<'collection, R, V, Storage>
+    where
+        Self: 'collection;
+
+    fn iter_views(&self) -> Self::Iter<'_> {
+        let run_ends = self.ends.borrow();
+        RunEndsIter {
+            cursor: Cursor::new(run_ends),
+            run_ends,
+            values: &self.values,
+            view: None,
+        }
+    }
+
+    type IntoIter = RunEndsIntoIter<R, V, Storage>;
+
+    fn into_iter_owned(self) -> Self::IntoIter {
+        RunEndsIntoIter {
+            cursor: Cursor::new(self.ends.borrow()),
+            runs: self,
+        }
+    }
+}
+
+impl<R: RunEnd, V: ArrayItem + Clone + PartialEq, Storage: Buffer> CollectionAllocIn
+    for RunEnds<R, V, Storage>
+where
+    Storage::For<R>: BorrowMut<[R]> + CollectionRealloc,
+    V::Memory<Storage>: CollectionRealloc<Alloc = <Storage::For<R> as CollectionAllocIn>::Alloc>,
+{
+    type Alloc = <Storage::For<R> as CollectionAllocIn>::Alloc;
+
+    /// Every item may start a new run, so both children are allocated with
+    /// `capacity`.
+    fn with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Self {
+        Self {
+            ends: Storage::For::<R>::with_capacity_in(capacity, alloc.clone()),
+            values: V::Memory::<Storage>::with_capacity_in(capacity, alloc),
+            last: None,
+        }
+    }
+
+    fn from_iter_in<I: IntoIterator<Item = Self::Owned>>(iter: I, alloc: Self::Alloc) -> Self {
+        let items = iter.into_iter();
+        let mut runs = Self::with_capacity_in(items.size_hint().0, alloc);
+        runs.extend(items);
+        runs
+    }
+
+    fn try_with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Result<Self, AllocError> {
+        Ok(Self {
+            ends: Storage::For::<R>::try_with_capacity_in(capacity, alloc.clone())?,
+            values: V::Memory::<Storage>::try_with_capacity_in(capacity, alloc)?,
+            last: None,
+        })
+    }
+
+    fn try_from_iter_in<I: IntoIterator<Item = Self::Owned>>(
+        iter: I,
+        alloc: Self::Alloc,
+    ) -> Result<Self, AllocError> {
+        let items = iter.into_iter();
+        let mut runs = Self::try_with_capacity_in(items.size_hint().0, alloc)?;
+        runs.try_extend(items)?;
+        Ok(runs)
+    }
+}
+
+impl<R: RunEnd, V: ArrayItem + Clone + PartialEq, Storage: Buffer> CollectionRealloc
+    for RunEnds<R, V, Storage>
+where
+    Storage::For<R>: BorrowMut<[R]> + CollectionRealloc,
+    V::Memory<Storage>: CollectionRealloc<Alloc = <Storage::For<R> as CollectionAllocIn>::Alloc>,
+{
+    fn allocator(&self) -> Self::Alloc {
+        self.ends.allocator()
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+        self.ends.try_reserve(additional)?;
+        self.values.try_reserve(additional)
+    }
+
+    /// Tries to extend the runs with the items of `iter`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AllocError`] when storage cannot be reserved, or when `R`
+    /// cannot represent the end of the last run. The runs are unchanged on
+    /// failure.
+    fn try_extend<I: IntoIterator<Item = Self::Owned>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), AllocError> {
+        let len = self.len();
+        iter.into_iter()
+            .try_for_each(|Run(value, _)| {
+                self.try_push(
+                    value,
+                    |values, new| values.try_extend(iter::once(new)),
+                    |run_ends, end| run_ends.try_extend(iter::once(end)),
+                )
+            })
+            .inspect_err(|_| {
+                self.truncate(len);
+                // A value may be pushed before its run end fails to.
+                let runs = self.ends.borrow().len();
+                self.values.truncate(runs);
+            })
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.ends.reserve(additional);
+        self.values.reserve(additional);
+    }
+
+    /// Drops the runs after the one containing the new last item, and ends
+    /// that run at `len`.
+    fn truncate(&mut self, len: usize) {
+        if len >= self.len() {
+            return;
+        }
+        let run_ends = self.ends.borrow_mut();
+        let runs = run_ends
+            .partition_point(|&end| end.try_into().is_ok_and(|position: usize| position < len));
+        let kept = if len == 0 {
+            0
+        } else {
+            // `len` is less than the end of the run that contains it, so it
+            // fits in `R`.
+            if let (Some(last_end), Ok(end)) = (run_ends.get_mut(runs), R::try_from(len)) {
+                *last_end = end;
+            }
+            runs.saturating_add(1)
+        };
+        self.ends.truncate(kept);
+        self.values.truncate(kept);
+        self.last = None;
+    }
+}
+
+/// Position of an iterator over run-end encoded items.
+///
+/// The current run is tracked next to the item index, so stepping to the next
+/// item only compares against the end of the current run.
+#[derive(Clone, Copy, Debug)]
+struct Cursor {
+    /// The index of the next item.
+    index: usize,
+    /// The number of items.
+    end: usize,
+    /// The run containing the next item.
+    run: usize,
+    /// The end of the current run.
+    run_end: usize,
+}
+
+impl Cursor {
+    /// Constructs a [`Cursor`] at the first item of `run_ends`.
+    fn new<R: RunEnd>(run_ends: &[R]) -> Self {
+        let end = run_ends.last().map_or(0, |&last| position(last));
+        let run_end = run_ends.first().map_or(0, |&first| position(first));
+        Self {
+            index: 0,
+            end,
+            run: 0,
+            run_end,
+        }
+    }
+
+    /// Moves to `run`.
+    fn enter<R: RunEnd>(&mut self, run_ends: &[R], run: usize) {
+        self.run = run;
+        self.run_end = run_ends.get(run).map_or(self.end, |&end| position(end));
+    }
+
+    /// Advances past the next item and returns its run.
+    fn next<R: RunEnd>(&mut self, run_ends: &[R]) -> Option<usize> {
+        if self.index == self.end {
+            return None;
+        }
+        if self.index == self.run_end {
+            self.enter(run_ends, self.run.strict_add(1));
+        }
+        self.index = self.index.strict_add(1);
+        Some(self.run)
+    }
+
+    /// Skips `n` items, binary-searching the run ends when that leaves the
+    /// current run, and advances past the next item.
+    fn nth<R: RunEnd>(&mut self, run_ends: &[R], n: usize) -> Option<usize> {
+        self.index = self.index.saturating_add(n).min(self.end);
+        if self.index > self.run_end {
+            let index = self.index;
+            self.enter(
+                run_ends,
+                run_ends.partition_point(|&end| position(end) <= index),
+            );
+        }
+        self.next(run_ends)
+    }
+
+    /// Returns the number of remaining items.
+    fn remaining(&self) -> usize {
+        self.end.strict_sub(self.index)
+    }
+}
+
+/// The view of a run value.
+type RunView<'collection, R, V, Storage> =
+    Run<R, <<V as ArrayItem>::Memory<Storage> as Collection>::View<'collection>>;
+
+/// Iterator over views of [`RunEnds`].
+///
+/// The view of the current run value is reused for every item of the run.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{collection::Collection, layout::run_end_encoded::{Run, RunEnds}};
+///
+/// let runs = [1, 1, 2].into_iter().map(Run::new).collect::<RunEnds<i32, u8>>();
+/// let views = runs.iter_views().map(|view| *view).collect::<Vec<_>>();
+/// assert_eq!(views, [1, 1, 2]);
+/// ```
+pub struct RunEndsIter<'collection, R: RunEnd, V: ArrayItem, Storage: Buffer> {
+    /// The iterator position.
+    cursor: Cursor,
+    /// The run ends.
+    run_ends: &'collection [R],
+    /// The run values.
+    values: &'collection V::Memory<Storage>,
+    /// The run and view of the last visited run value.
+    view: Option<(usize, RunView<'collection, R, V, Storage>)>,
+}
+
+impl<R: RunEnd + Debug, V: ArrayItem, Storage: Buffer> Debug for RunEndsIter<'_, R, V, Storage>
+where
+    V::Memory<Storage>: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RunEndsIter")
+            .field("cursor", &self.cursor)
+            .field("run_ends", &self.run_ends)
+            .field("values", &self.values)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'collection, R: RunEnd, V: ArrayItem, Storage: Buffer>
+    RunEndsIter<'collection, R, V, Storage>
+{
+    /// Returns the view of the value of `run`.
+    fn run_view(
+        &mut self,
+        run: usize,
+    ) -> Option<Run<R, <V::Memory<Storage> as Collection>::View<'collection>>> {
+        match self.view {
+            Some((visited, view)) if visited == run => Some(view),
+            Some(_) | None => {
+                let view = self.values.view(run).map(Run::new)?;
+                self.view = Some((run, view));
+                Some(view)
+            }
+        }
+    }
+}
+
+impl<'collection, R: RunEnd, V: ArrayItem, Storage: Buffer> Iterator
+    for RunEndsIter<'collection, R, V, Storage>
+{
+    type Item = Run<R, <V::Memory<Storage> as Collection>::View<'collection>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let run = self.cursor.next(self.run_ends)?;
+        self.run_view(run)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let run = self.cursor.nth(self.run_ends, n)?;
+        self.run_view(run)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.cursor.remaining();
+        (remaining, Some(remaining))
+    }
+}
+
+impl<R: RunEnd, V: ArrayItem, Storage: Buffer> ExactSizeIterator
+    for RunEndsIter<'_, R, V, Storage>
+{
+}
+
+/// Iterator over owned items of [`RunEnds`].
+///
+/// Every item is materialized from the value of its run.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{collection::Collection, layout::run_end_encoded::{Run, RunEnds}};
+///
+/// let runs = [1, 1].into_iter().map(Run::new).collect::<RunEnds<i16, u32>>();
+/// let mut iter = runs.into_iter_owned();
+/// assert_eq!(iter.len(), 2);
+/// assert_eq!(iter.next(), Some(Run::new(1)));
+/// ```
+pub struct RunEndsIntoIter<R: RunEnd, V: ArrayItem, Storage: Buffer> {
+    /// The iterator position.
+    cursor: Cursor,
+    /// The iterated runs.
+    runs: RunEnds<R, V, Storage>,
+}
+
+impl<R: RunEnd, V: ArrayItem, Storage: Buffer> Debug for RunEndsIntoIter<R, V, Storage>
+where
+    RunEnds<R, V, Storage>: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RunEndsIntoIter")
+            .field("cursor", &self.cursor)
+            .field("runs", &self.runs)
+            .finish()
+    }
+}
+
+impl<R: RunEnd, V: ArrayItem, Storage: Buffer> Iterator for RunEndsIntoIter<R, V, Storage> {
+    type Item = Run<R, V>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let run = self.cursor.next(self.runs.ends.borrow())?;
+        self.runs.values.owned(run).map(Run::new)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let run = self.cursor.nth(self.runs.ends.borrow(), n)?;
+        self.runs.values.owned(run).map(Run::new)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.cursor.remaining();
+        (remaining, Some(remaining))
+    }
+}
+
+impl<R: RunEnd, V: ArrayItem, Storage: Buffer> ExactSizeIterator
+    for RunEndsIntoIter<R, V, Storage>
+{
+}
+
+/// A collection of run-end encoded values.
+///
+/// This is the Arrow run-end encoded layout: [`RunEnds`] with a window. Arrow
+/// slices run-end encoded arrays by their logical offset and length while
+/// keeping both children complete, which the window records.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{
+///     collection::Collection,
+///     layout::{MemoryLayout, run_end_encoded::{Run, RunEndEncoded}},
+/// };
+///
+/// let values = [1, 1, 1, 2]
+///     .into_iter()
+///     .map(Run::new)
+///     .collect::<RunEndEncoded<i32, u8>>()
+///     .slice(2, 2);
+/// assert_eq!(values.iter_views().map(|view| *view).collect::<Vec<_>>(), [1, 2]);
+/// ```
+pub struct RunEndEncoded<R: RunEnd, V: ArrayItem, Storage: Buffer = VecBuffer>(
+    Window<RunEnds<R, V, Storage>>,
+);
+
+impl<R: RunEnd, V: ArrayItem, Storage: Buffer> MemoryLayout for RunEndEncoded<R, V, Storage> {
+    fn slice(self, offset: usize, len: usize) -> Self {
+        Self(self.0.slice(offset, len))
+    }
+
+    fn offset(&self) -> usize {
+        self.0.offset()
+    }
+}
+
+impl<R: RunEnd, V: ArrayItem, Storage: Buffer> RunEndEncoded<R, V, Storage> {
+    /// Constructs a [`RunEndEncoded`] from its backing collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{
+    ///     collection::Collection,
+    ///     layout::run_end_encoded::{Run, RunEndEncoded, RunEnds},
+    /// };
+    ///
+    /// let runs = RunEnds::<i32, u32>::try_from_parts(vec![2], [7].into_iter().collect());
+    /// let values = RunEndEncoded::from_buffer(runs.unwrap());
+    /// assert_eq!(values.owned(1), Some(Run::new(7)));
+    /// ```
+    #[must_use]
+    pub fn from_buffer(buffer: RunEnds<R, V, Storage>) -> Self {
+        Self(Window::new(buffer))
+    }
+
+    /// Returns the backing collection of this [`RunEndEncoded`].
+    ///
+    /// This is the inverse of [`RunEndEncoded::from_buffer`]. The backing
+    /// collection of a slice still contains the items outside of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{layout::run_end_encoded::{Run, RunEndEncoded}, length::Length};
+    ///
+    /// let values = [Run::new(1)].into_iter().collect::<RunEndEncoded<i16, u32>>();
+    /// assert_eq!(values.into_buffer().len(), 1);
+    /// ```
+    #[must_use]
+    pub fn into_buffer(self) -> RunEnds<R, V, Storage> {
+        self.0.into_parts().0
+    }
+}
+
+impl<R: RunEnd, V: ArrayItem, Storage: Buffer> BufferRef for RunEndEncoded<R, V, Storage> {
+    type Buffer = RunEnds<R, V, Storage>;
+
+    fn buffer_ref(&self) -> &Self::Buffer {
+        self.0.buffer_ref()
+    }
+}
+
+impl<R: RunEnd, V: ArrayItem, Storage: Buffer> Debug for RunEndEncoded<R, V, Storage>
+where
+    RunEnds<R, V, Storage>: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RunEndEncoded").field(&self.0).finish()
+    }
+}
+
+impl<R: RunEnd, V: ArrayItem, Storage: Buffer> Clone for RunEndEncoded<R, V, Storage>
+where
+    RunEnds<R, V, Storage>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<R: RunEnd, V: ArrayItem, Storage: Buffer> Default for RunEndEncoded<R, V, Storage>
+where
+    RunEnds<R, V, Storage>: Default,
+{
+    fn default() -> Self {
+        Self(Window::default())
+    }
+}
+
+impl<R: RunEnd, V: ArrayItem, Storage: Buffer> Extend<Run<R, V>> for RunEndEncoded<R, V, Storage>
+where
+    Window<RunEnds<R, V, Storage>>: Extend<Run<R, V>>,
+{
+    fn extend<I: IntoIterator<Item = Run<R, V>>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl<R: RunEnd, V: ArrayItem, Storage: Buffer> FromIterator<Run<R, V>>
+    for RunEndEncoded<R, V, Storage>
+where
+    RunEnds<R, V, Storage>: FromIterator<Run<R, V>>,
+{
+    fn from_iter<I: IntoIterator<Item = Run<R, V>>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<R: RunEnd, V: ArrayItem, Storage: Buffer> Length for RunEndEncoded<R, V, Storage> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<R: RunEnd, V: ArrayItem, Storage: Buffer> Collection for RunEndEncoded<R, V, Storage> {
+    type View<'collection>
+        = <RunEnds<R, V, Storage> as Collection>::View<'collection>
+    where
+        Self: 'collection;
+
+    type Owned = Run<R, V>;
+
+    fn view(&self, index: usize) -> Option<Self::View<'_>> {
+        self.0.view(index)
+    }
+
+    type Iter<'collection>
+        = <Window<RunEnds<R, V, Storage>> as Collection>::Iter<'collection>
+    where
+        Self: 'collection;
+
+    fn iter_views(&self) -> Self::Iter<'_> {
+        self.0.iter_views()
+    }
+
+    type IntoIter = <Window<RunEnds<R, V, Storage>> as Collection>::IntoIter;
+
+    fn into_iter_owned(self) -> Self::IntoIter {
+        self.0.into_iter_owned()
+    }
+}
+
+impl<R: RunEnd, V: ArrayItem + Clone + PartialEq, Storage: Buffer> CollectionAllocIn
+    for RunEndEncoded<R, V, Storage>
+where
+    Storage::For<R>: BorrowMut<[R]> + CollectionRealloc,
+    V::Memory<Storage>: CollectionRealloc<Alloc = <Storage::For<R> as CollectionAllocIn>::Alloc>,
+{
+    type Alloc = <Storage::For<R> as CollectionAllocIn>::Alloc;
+
+    fn with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Self {
+        Self(Window::with_capacity_in(capacity, alloc))
+    }
+
+    fn from_iter_in<I: IntoIterator<Item = Self::Owned>>(iter: I, alloc: Self::Alloc) -> Self {
+        Self(Window::from_iter_in(iter, alloc))
+    }
+
+    fn try_with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Result<Self, AllocError> {
+        Window::try_with_capacity_in(capacity, alloc).map(Self)
+    }
+
+    fn try_from_iter_in<I: IntoIterator<Item = Self::Owned>>(
+        iter: I,
+        alloc: Self::Alloc,
+    ) -> Result<Self, AllocError> {
+        Window::try_from_iter_in(iter, alloc).map(Self)
+    }
+}
+
+impl<R: RunEnd, V: ArrayItem + Clone + PartialEq, Storage: Buffer> CollectionRealloc
+    for RunEndEncoded<R, V, Storage>
+where
+    Storage::For<R>: BorrowMut<[R]> + CollectionRealloc,
+    V::Memory<Storage>: CollectionRealloc<Alloc = <Storage::For<R> as CollectionAllocIn>::Alloc>,
+{
+    fn allocator(&self) -> Self::Alloc {
+        self.0.allocator()
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+        self.0.try_reserve(additional)
+    }
+
+    fn try_extend<I: IntoIterator<Item = Self::Owned>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), AllocError> {
+        self.0.try_extend(iter)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.0.truncate(len);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::{string::String, vec, vec::Vec};
+
+    use crate::collection::tests::round_trip;
+
+    use super::*;
+
+    fn items<V>(values: impl IntoIterator<Item = V>) -> Vec<Run<i32, V>> {
+        values.into_iter().map(Run::new).collect()
+    }
+
+    #[test]
+    fn coalesces() {
+        let runs = items(["a", "a", "b", "a", "a", "a"].map(String::from))
+            .into_iter()
+            .collect::<RunEnds<i32, String>>();
+        assert_eq!(runs.buffer_ref(), &[2, 3, 6]);
+        assert_eq!(
+            runs.child_ref().iter_views().collect::<Vec<_>>(),
+            ["a", "b", "a"]
+        );
+        assert_eq!(runs.len(), 6);
+        assert_eq!(runs.view(2).as_deref(), Some(&"b"));
+        assert_eq!(runs.view(5).as_deref(), Some(&"a"));
+        assert!(runs.view(6).is_none());
+    }
+
+    #[test]
+    fn coalesces_against_parts() {
+        let mut runs =
+            RunEnds::<i16, u32>::try_from_parts(vec![1, 3], [4, 5].into_iter().collect())
+                .expect("valid");
+        runs.extend([5, 6].map(Run::new));
+        let (run_ends, values) = runs.into_parts();
+        assert_eq!(run_ends, [1, 4, 5]);
+        assert_eq!(values.into_buffer(), [4, 5, 6]);
+    }
+
+    #[test]
+    fn try_from_parts() {
+        assert_eq!(
+            RunEnds::<i32, u32>::try_from_parts(vec![1], [1, 2].into_iter().collect())
+                .expect_err("missing run end"),
+            RunEndsError::LengthMismatch {
+                run_ends: 1,
+                values: 2
+            }
+        );
+        assert_eq!(
+            RunEnds::<i64, u32>::try_from_parts(vec![0], [1].into_iter().collect())
+                .expect_err("empty run"),
+            RunEndsError::NotIncreasing { index: 0 }
+        );
+        assert_eq!(
+            RunEnds::<i16, u32>::try_from_parts(vec![2, -1], [1, 2].into_iter().collect())
+                .expect_err("negative run end"),
+            RunEndsError::NotIncreasing { index: 1 }
+        );
+    }
+
+    #[test]
+    fn iter_views_skips_runs() {
+        let values = items([1_u8, 1, 1, 2, 3, 3, 3, 3])
+            .into_iter()
+            .collect::<RunEndEncoded<i32, u8>>();
+        let mut iter = values.iter_views();
+        assert_eq!(iter.nth(4).as_deref(), Some(&3));
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next().as_deref(), Some(&3));
+        assert!(iter.nth(2).is_none());
+        assert_eq!(
+            values
+                .slice(2, 5)
+                .iter_views()
+                .map(|view| *view)
+                .collect::<Vec<_>>(),
+            [1, 2, 3, 3, 3]
+        );
+    }
+
+    #[test]
+    fn from_buffer() {
+        let values = items([1_u32, 1, 2])
+            .into_iter()
+            .collect::<RunEndEncoded<i32, u32>>();
+        let restored = RunEndEncoded::<i32, u32>::from_buffer(values.into_buffer());
+        assert_eq!(restored.len(), 3);
+        assert_eq!(restored.owned(1), Some(Run::new(1)));
+    }
+
+    #[test]
+    fn collection() {
+        round_trip::<RunEndEncoded<_, _>, _>(items([1_u32, 1, 2, 1, 1]));
+        round_trip::<RunEndEncoded<_, _>, _>(items([Some(1_u8), None, None, Some(1)]));
+        round_trip::<RunEndEncoded<_, _>, _>(items([vec![1_u8], vec![1], vec![]]));
+        round_trip::<RunEndEncoded<_, _>, _>(items(["a", "a", "b"].map(String::from)));
+        round_trip::<RunEndEncoded<_, _>, _>(items::<u8>([]));
+    }
+
+    #[test]
+    fn try_extend_and_truncate() {
+        let mut values = items([1_u32, 1])
+            .into_iter()
+            .collect::<RunEndEncoded<i32, u32>>();
+        CollectionRealloc::try_extend(&mut values, items([1, 2, 2, 3])).expect("reserved");
+        assert_eq!(values.buffer_ref().buffer_ref(), &[3, 5, 6]);
+        CollectionRealloc::truncate(&mut values, 4);
+        assert_eq!(values.buffer_ref().buffer_ref(), &[3, 4]);
+        values.extend(items([2, 1]));
+        assert_eq!(values.buffer_ref().buffer_ref(), &[3, 5, 6]);
+        CollectionRealloc::truncate(&mut values, 0);
+        assert_eq!(values.len(), 0);
+        assert_eq!(values.buffer_ref().child_ref().len(), 0);
+    }
+
+    #[test]
+    fn try_extend_run_end_overflow() {
+        let max = usize::try_from(i16::MAX).expect("positive");
+        let mut values = iter::repeat_n(1_u8, max.strict_sub(1))
+            .chain([2])
+            .map(Run::new)
+            .collect::<RunEndEncoded<i16, u8>>();
+        assert_eq!(values.len(), max);
+
+        assert_eq!(
+            CollectionRealloc::try_extend(&mut values, [Run::new(2)]),
+            Err(AllocError)
+        );
+        assert_eq!(
+            CollectionRealloc::try_extend(&mut values, [3, 4].map(Run::new)),
+            Err(AllocError)
+        );
+        assert_eq!(values.len(), max);
+        assert_eq!(values.buffer_ref().buffer_ref(), &[i16::MAX - 1, i16::MAX]);
+        assert_eq!(
+            values
+                .buffer_ref()
+                .child_ref()
+                .iter_views()
+                .collect::<Vec<_>>(),
+            [1, 2]
+        );
+
+        CollectionRealloc::truncate(&mut values, 1);
+        CollectionRealloc::try_extend(&mut values, [1, 3].map(Run::new)).expect("fits");
+        assert_eq!(values.buffer_ref().buffer_ref(), &[2, 3]);
+    }
+}
@@ -0,0 +1,537 @@
+//! Struct arrays.
+
+use core::fmt::Debug;
+
+use crate::{
+    buffer::{Buffer, BufferRef, VecBuffer},
+    collection::{
+        AllocError, ChildRef, Collection, CollectionAllocIn, CollectionRealloc, window::Window,
+    },
+    layout::{ArrayItem, Layout, MemoryLayout, NullableLayout},
+    length::Length,
+    nullability::{NonNullable, Nullability, Nullable},
+};
+
+/// Mapping a record type to the child collections of its fields.
+///
+/// Arrow stores a struct as one equally long child array per field. The
+/// fields collection zips these children back into rows, which lets
+/// [`Struct`] stay agnostic of the number and names of the fields. Tuples
+/// use a tuple of child layouts; derived structs generate a named fields
+/// collection.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{
+///     buffer::VecBuffer,
+///     layout::{fixed_size_primitive::FixedSizePrimitive, struct_array::StructLayout},
+/// };
+///
+/// fn assert_fields<T: StructLayout<Fields<VecBuffer> = Fields>, Fields>() {}
+/// assert_fields::<(u8, u16), (FixedSizePrimitive<u8>, FixedSizePrimitive<u16>)>();
+/// assert_eq!(<(u8, u16)>::FIELD_NAMES, ["0", "1"]);
+/// ```
+pub trait StructLayout: Sized {
+    /// The names of the fields, in declaration order.
+    ///
+    /// Tuple fields are named by their position.
+    const FIELD_NAMES: &'static [&'static str];
+
+    /// The collection of child memory layouts, one per field.
+    type Fields<Storage: Buffer>: Collection<Owned = Self> + StructFields;
+}
+
+/// Access to all child collections of a fields collection at once.
+///
+/// [`FieldRef`] selects a single field by its index. Code that handles every
+/// field in turn, like a serializer, instead needs the children of structs of
+/// any width. These are exposed as a nested list of pairs ending in `()`, so
+/// a trait implemented for `()` and for `(Head, Tail)` covers every struct:
+///
+/// ```text
+/// (A, B, C) -> (A, (B, (C, ())))
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use narrow::layout::struct_array::StructFields;
+///
+/// let fields = (vec![1, 2], vec![true, false]);
+/// let (numbers, (flags, ())) = fields.children_ref();
+/// assert_eq!((numbers, flags), (&vec![1, 2], &vec![true, false]));
+/// let restored = <(Vec<i32>, Vec<bool>)>::from_children(fields.into_children());
+/// assert_eq!(restored, (vec![1, 2], vec![true, false]));
+/// ```
+pub trait StructFields: Sized {
+    /// The child collections as a nested list of pairs.
+    type Children;
+
+    /// References to the child collections as a nested list of pairs.
+    type ChildrenRef<'fields>
+    where
+        Self: 'fields;
+
+    /// Returns references to the child collections.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::layout::struct_array::StructFields;
+    ///
+    /// let fields = (vec![1],);
+    /// assert_eq!(fields.children_ref(), (&vec![1], ()));
+    /// ```
+    fn children_ref(&self) -> Self::ChildrenRef<'_>;
+
+    /// Returns the child collections.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::layout::struct_array::StructFields;
+    ///
+    /// let fields = (vec![1], vec![true]);
+    /// assert_eq!(fields.into_children(), (vec![1], (vec![true], ())));
+    /// ```
+    fn into_children(self) -> Self::Children;
+
+    /// Constructs the fields collection from its child collections.
+    ///
+    /// The children are expected to have the same length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::layout::struct_array::StructFields;
+    ///
+    /// let fields = <(Vec<i32>, Vec<bool>)>::from_children((vec![1], (vec![true], ())));
+    /// assert_eq!(fields, (vec![1], vec![true]));
+    /// ```
+    fn from_children(children: Self::Children) -> Self;
+}
+
+/// Positional access to the child collection of a field.
+///
+/// This is the per-field counterpart of [`ChildRef`]: a struct has one
+/// physical child per field, so the field is selected with a const index
+/// following declaration order.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::layout::struct_array::FieldRef;
+///
+/// let fields = (vec![1, 2], vec![true, false]);
+/// assert_eq!(FieldRef::<1>::field_ref(&fields), &[true, false]);
+/// ```
+pub trait FieldRef<const INDEX: usize> {
+    /// Child collection of the field.
+    type Field: Collection;
+
+    /// Returns the child collection of the field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::layout::struct_array::FieldRef;
+    ///
+    /// let fields = (vec![1, 2], vec![true, false]);
+    /// assert_eq!(FieldRef::<0>::field_ref(&fields), &[1, 2]);
+    /// ```
+    fn field_ref(&self) -> &Self::Field;
+}
+
+/// A collection of structs.
+///
+/// The fields are stored in separate child collections and a single optional
+/// validity bitmap covers whole rows. Nullability of a field is independent of
+/// the nullability of the struct:
+///
+/// ```text
+/// [Some((1, None)), None] -> bitmap [1, 0]
+///                            child 0 [1, 0]
+///                            child 1 [null, null]
+/// ```
+///
+/// Views project a row by combining the views of its fields, so reading a
+/// row does not copy variable-size field data.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{collection::Collection, layout::struct_array::Struct, nullability::Nullable};
+///
+/// let values = [(1, true), (2, false)].into_iter().collect::<Struct<(u8, bool)>>();
+/// assert_eq!(values.view(1), Some((2, false)));
+/// let values = [Some((1, vec![2])), None]
+///     .into_iter()
+///     .collect::<Struct<(u8, Vec<u8>), Nullable>>();
+/// assert_eq!(values.owned(0), Some(Some((1, vec![2]))));
+/// assert_eq!(values.owned(1), Some(None));
+/// ```
+pub struct Struct<T: StructLayout, Nulls: Nullability = NonNullable, Storage: Buffer = VecBuffer>(
+    Window<Nulls::Collection<T::Fields<Storage>, Storage>>,
+);
+
+impl<T: StructLayout, Nulls: Nullability, Storage: Buffer> MemoryLayout
+    for Struct<T, Nulls, Storage>
+{
+    fn slice(self, offset: usize, len: usize) -> Self {
+        Self(self.0.slice(offset, len))
+    }
+
+    fn offset(&self) -> usize {
+        self.0.offset()
+    }
+}
+
+impl<T: StructLayout, Nulls: Nullability, Storage: Buffer> Struct<T, Nulls, Storage> {
+    /// Constructs a [`Struct`] from its backing collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{collection::Collection, layout::struct_array::Struct};
+    ///
+    /// let original = [(1, true)].into_iter().collect::<Struct<(u8, bool)>>();
+    /// let restored = Struct::<(u8, bool)>::from_buffer(original.into_buffer());
+    /// assert_eq!(restored.owned(0), Some((1, true)));
+    /// ```
+    #[must_use]
+    pub fn from_buffer(buffer: Nulls::Collection<T::Fields<Storage>, Storage>) -> Self {
+        Self(Window::new(buffer))
+    }
+
+    /// Returns the backing collection of this [`Struct`].
+    ///
+    /// This is the inverse of [`Struct::from_buffer`]. The backing
+    /// collection of a slice still contains the items outside of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{layout::struct_array::Struct, length::Length};
+    ///
+    /// let values = [(1, true)].into_iter().collect::<Struct<(u8, bool)>>();
+    /// assert_eq!(values.into_buffer().0.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn into_buffer(self) -> Nulls::Collection<T::Fields<Storage>, Storage> {
+        self.0.into_parts().0
+    }
+}
+
+impl<T: StructLayout, Nulls: Nullability, Storage: Buffer> BufferRef for Struct<T, Nulls, Storage> {
+    type Buffer = Nulls::Collection<T::Fields<Storage>, Storage>;
+
+    fn buffer_ref(&self) -> &Self::Buffer {
+        self.0.buffer_ref()
+    }
+}
+
+impl<T: StructLayout, Storage: Buffer> ChildRef for Struct<T, NonNullable, Storage> {
+    type Child = T::Fields<Storage>;
+
+    fn child_ref(&self) -> &Self::Child {
+        self.0.buffer_ref()
+    }
+}
+
+impl<T: StructLayout, Storage: Buffer> ChildRef for Struct<T, Nullable, Storage> {
+    type Child = T::Fields<Storage>;
+
+    fn child_ref(&self) -> &Self::Child {
+        self.0.buffer_ref().child_ref()
+    }
+}
+
+impl<T: StructLayout, Nulls: Nullability, Storage: Buffer, const INDEX: usize> FieldRef<INDEX>
+    for Struct<T, Nulls, Storage>
+where
+    Self: ChildRef<Child = T::Fields<Storage>>,
+    T::Fields<Storage>: FieldRef<INDEX>,
+{
+    type Field = <T::Fields<Storage> as FieldRef<INDEX>>::Field;
+
+    fn field_ref(&self) -> &Self::Field {
+        self.child_ref().field_ref()
+    }
+}
+
+impl<T: StructLayout, Nulls: Nullability, Storage: Buffer> Debug for Struct<T, Nulls, Storage>
+where
+    Nulls::Collection<T::Fields<Storage>, Storage>: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Struct").field(&self.0).finish()
+    }
+}
+
+impl<T: StructLayout, Nulls: Nullability, Storage: Buffer> Clone for Struct<T, Nulls, Storage>
+where
+    Nulls::Collection<T::Fields<Storage>, Storage>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: StructLayout, Nulls: Nullability, Storage: Buffer> Default for Struct<T, Nulls, Storage>
+where
+    Nulls::Collection<T::Fields<Storage>, Storage>: Default,
+{
+    fn default() -> Self {
+        Self(Window::default())
+    }
+}
+
+impl<T: StructLayout, Nulls: Nullability, Storage: Buffer> Extend<Nulls::Item<T>>
+    for Struct<T, Nulls, Storage>
+where
+    Window<Nulls::Collection<T::Fields<Storage>, Storage>>: Extend<Nulls::Item<T>>,
+{
+    fn extend<I: IntoIterator<Item = Nulls::Item<T>>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl<T: StructLayout, Nulls: Nullability, Storage: Buffer> FromIterator<Nulls::Item<T>>
+    for Struct<T, Nulls, Storage>
+where
+    Nulls::Collection<T::Fields<Storage>, Storage>: FromIterator<Nulls::Item<T>>,
+{
+    fn from_iter<I: IntoIterator<Item = Nulls::Item<T>>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<T: StructLayout, Nulls: Nullability, Storage: Buffer> Length for Struct<T, Nulls, Storage> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<T: StructLayout, Nulls: Nullability, Storage: Buffer> Collection
+    for Struct<T, Nulls, Storage>
+{
+    type View<'collection>
+        = <Nulls::Collection<T::Fields<Storage>, Storage> as Collection>::View<'collection>
+    where
+        Self: 'collection;
+
+    type Owned = Nulls::Item<T>;
+
+    fn view(&self, index: usize) -> Option<Self::View<'_>> {
+        self.0.view(index)
+    }
+
+    type Iter<'collection>
+        = <Window<Nulls::Collection<T::Fields<Storage>, Storage>> as Collection>::Iter<'collection>
+    where
+        Self: 'collection;
+
+    fn iter_views(&self) -> Self::Iter<'_> {
+        self.0.iter_views()
+    }
+
+    type IntoIter =
+        <Window<Nulls::Collection<T::Fields<Storage>, Storage>> as Collection>::IntoIter;
+
+    fn into_iter_owned(self) -> Self::IntoIter {
+        self.0.into_iter_owned()
+    }
+}
+
+impl<T: StructLayout, Nulls: Nullability, Storage: Buffer> CollectionAllocIn
+    for Struct<T, Nulls, Storage>
+where
+    Nulls::Collection<T::Fields<Storage>, Storage>: CollectionAllocIn,
+{
+    type Alloc = <Nulls::Collection<T::Fields<Storage>, Storage> as CollectionAllocIn>::Alloc;
+
+    fn with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Self {
+        Self(Window::with_capacity_in(capacity, alloc))
+    }
+
+    fn from_iter_in<I: IntoIterator<Item = Self::Owned>>(iter: I, alloc: Self::Alloc) -> Self {
+        Self(Window::from_iter_in(iter, alloc))
+    }
+
+    fn try_with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Result<Self, AllocError> {
+        Window::try_with_capacity_in(capacity, alloc).map(Self)
+    }
+
+    fn try_from_iter_in<I: IntoIterator<Item = Self::Owned>>(
+        iter: I,
+        alloc: Self::Alloc,
+    ) -> Result<Self, AllocError> {
+        Window::try_from_iter_in(iter, alloc).map(Self)
+    }
+}
+
+impl<T: StructLayout, Nulls: Nullability, Storage: Buffer> CollectionRealloc
+    for Struct<T, Nulls, Storage>
+where
+    Nulls::Collection<T::Fields<Storage>, Storage>: CollectionRealloc,
+{
+    fn allocator(&self) -> Self::Alloc {
+        self.0.allocator()
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+        self.0.try_reserve(additional)
+    }
+
+    fn try_extend<I: IntoIterator<Item = Self::Owned>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), AllocError> {
+        self.0.try_extend(iter)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.0.truncate(len);
+    }
+}
+
+/// Implements [`FieldRef`] for every field of a tuple of collections.
+macro_rules! field_ref {
+    ($types:tt; $($index:tt $Field:ident),+) => {
+        $(field_ref!(@field $types $index $Field);)+
+    };
+    (@field ($($Type:ident),+) $index:tt $Field:ident) => {
+        impl<$($Type: Collection),+> FieldRef<$index> for ($($Type,)+) {
+            type Field = $Field;
+
+            fn field_ref(&self) -> &Self::Field {
+                &self.$index
+            }
+        }
+    };
+}
+
+/// Nests a list of types, expressions or patterns into pairs ending in `()`.
+macro_rules! nested {
+    (ty;) => { () };
+    (ty; $head:ty $(, $tail:ty)*) => { ($head, nested!(ty; $($tail),*)) };
+    (expr;) => { () };
+    (expr; $head:expr $(, $tail:expr)*) => { ($head, nested!(expr; $($tail),*)) };
+    (pat;) => { () };
+    (pat; $head:pat $(, $tail:pat)*) => { ($head, nested!(pat; $($tail),*)) };
+}
+
+/// Implements the struct layout for a tuple of array items.
+macro_rules! tuple {
+    ($($index:tt $Type:ident $field:ident),+) => {
+        field_ref!(($($Type),+); $($index $Type),+);
+
+        impl<$($Type),+> StructFields for ($($Type,)+) {
+            type Children = nested!(ty; $($Type),+);
+
+            type ChildrenRef<'fields>
+                = nested!(ty; $(&'fields $Type),+)
+            where
+                Self: 'fields;
+
+            fn children_ref(&self) -> Self::ChildrenRef<'_> {
+                nested!(expr; $(&self.$index),+)
+            }
+
+            fn into_children(self) -> Self::Children {
+                nested!(expr; $(self.$index),+)
+            }
+
+            fn from_children(children: Self::Children) -> Self {
+                let nested!(pat; $($field),+) = children;
+                ($($field,)+)
+            }
+        }
+
+        impl<$($Type: ArrayItem),+> StructLayout for ($($Type,)+) {
+            const FIELD_NAMES: &'static [&'static str] = &[$(stringify!($index)),+];
+
+            type Fields<Storage: Buffer> = ($($Type::Memory<Storage>,)+);
+        }
+
+        impl<$($Type: ArrayItem),+> Layout for ($($Type,)+) {
+            type Memory<Nulls: Nullability, Storage: Buffer> = Struct<Self, Nulls, Storage>;
+        }
+
+        impl<$($Type: ArrayItem),+> NullableLayout for ($($Type,)+) {}
+    };
+}
+
+tuple!(0 T0 f0);
+tuple!(0 T0 f0, 1 T1 f1);
+tuple!(0 T0 f0, 1 T1 f1, 2 T2 f2);
+tuple!(0 T0 f0, 1 T1 f1, 2 T2 f2, 3 T3 f3);
+tuple!(0 T0 f0, 1 T1 f1, 2 T2 f2, 3 T3 f3, 4 T4 f4);
+tuple!(0 T0 f0, 1 T1 f1, 2 T2 f2, 3 T3 f3, 4 T4 f4, 5 T5 f5);
+tuple!(0 T0 f0, 1 T1 f1, 2 T2 f2, 3 T3 f3, 4 T4 f4, 5 T5 f5, 6 T6 f6);
+tuple!(0 T0 f0, 1 T1 f1, 2 T2 f2, 3 T3 f3, 4 T4 f4, 5 T5 f5, 6 T6 f6, 7 T7 f7);
+tuple!(0 T0 f0, 1 T1 f1, 2 T2 f2, 3 T3 f3, 4 T4 f4, 5 T5 f5, 6 T6 f6, 7 T7 f7, 8 T8 f8);
+tuple!(0 T0 f0, 1 T1 f1, 2 T2 f2, 3 T3 f3, 4 T4 f4, 5 T5 f5, 6 T6 f6, 7 T7 f7, 8 T8 f8, 9 T9 f9);
+tuple!(0 T0 f0, 1 T1 f1, 2 T2 f2, 3 T3 f3, 4 T4 f4, 5 T5 f5, 6 T6 f6, 7 T7 f7, 8 T8 f8, 9 T9 f9, 10 T10 f10);
+tuple!(0 T0 f0, 1 T1 f1, 2 T2 f2, 3 T3 f3, 4 T4 f4, 5 T5 f5, 6 T6 f6, 7 T7 f7, 8 T8 f8, 9 T9 f9, 10 T10 f10, 11 T11 f11);
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::{vec, vec::Vec};
+
+    use crate::{bitmap::ValidityBitmap, collection::tests::round_trip};
+
+    use super::*;
+
+    #[test]
+    fn from_buffer() {
+        let values = [(1, true), (2, false)]
+            .into_iter()
+            .collect::<Struct<(u8, bool)>>();
+        let restored = Struct::<(u8, bool)>::from_buffer(values.into_buffer());
+        assert_eq!(restored.len(), 2);
+        assert_eq!(restored.owned(0), Some((1, true)));
+        assert_eq!(restored.owned(1), Some((2, false)));
+    }
+
+    #[test]
+    fn field_ref() {
+        let values = [Some((1_u8, Some(2_u16))), None, Some((3, None))]
+            .into_iter()
+            .collect::<Struct<(u8, Option<u16>), Nullable>>();
+        assert_eq!(values.buffer_ref().null_count(), 1);
+        assert_eq!(FieldRef::<0>::field_ref(&values).len(), 3);
+        assert_eq!(FieldRef::<1>::field_ref(&values).owned(0), Some(Some(2)));
+        assert_eq!(
+            FieldRef::<1>::field_ref(&values).buffer_ref().null_count(),
+            2
+        );
+    }
+
+    #[test]
+    fn collection() {
+        round_trip::<Struct<(u8,)>, _>([(1,), (2,)]);
+        round_trip::<Struct<(u8, bool)>, _>([(1, true), (2, false)]);
+        round_trip::<Struct<(u8, Option<bool>), Nullable>, _>([
+            Some((1, Some(true))),
+            None,
+            Some((3, None)),
+        ]);
+        round_trip::<Struct<(Vec<u8>, [u16; 2])>, _>([(vec![1, 2], [3, 4]), (vec![], [5, 6])]);
+        round_trip::<Struct<((u8, bool), Option<(u16,)>)>, _>([
+            ((1, true), Some((2,))),
+            ((3, false), None),
+        ]);
+        round_trip::<Struct<(Vec<(u8, Option<bool>)>,), Nullable>, _>([
+            Some((vec![(1, None), (2, Some(false))],)),
+            None,
+        ]);
+    }
+}
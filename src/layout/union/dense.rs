@@ -0,0 +1,697 @@
+//! Dense unions.
+
+use core::{
+    borrow::Borrow,
+    fmt::{self, Debug},
+    iter::{self, Map, RepeatN, Zip},
+    ops::Range,
+};
+
+use crate::{
+    buffer::{Buffer, BufferRef, VecBuffer},
+    collection::{AllocError, Collection, CollectionAllocIn, CollectionRealloc, window::Window},
+    layout::{
+        MemoryLayout,
+        union::{
+            MAX_VARIANTS, UnionError, UnionIntoIter, UnionLayout, UnionVariants,
+            UnionVariantsRealloc, variant_type_ids,
+        },
+    },
+    length::Length,
+    nullability::{NonNullable, Nullability},
+};
+
+/// The type ids, offsets and children of a dense union.
+///
+/// Every child only stores the values of its own variant. The offset of an
+/// item is the index of its value in the child of its variant:
+///
+/// ```text
+/// [Number(1), Flag(true), Number(2)] -> type ids [0, 1, 0]
+///                                        offsets  [0, 0, 1]
+///                                        child 0  [1, 2]
+///                                        child 1  [true]
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{
+///     buffer::BufferRef, collection::Collection, layout::union::DenseTypeIds, length::Length,
+/// };
+/// use narrow_derive::ArrayType;
+///
+/// #[derive(ArrayType, Clone, Debug, PartialEq)]
+/// enum Value {
+///     Number(u8),
+///     Flag(bool),
+/// }
+///
+/// let values = [Value::Number(1), Value::Flag(true), Value::Number(2)]
+///     .into_iter()
+///     .collect::<DenseTypeIds<Value>>();
+/// assert_eq!(values.buffer_ref(), &[0, 1, 0]);
+/// assert_eq!(values.offsets(), &[0, 0, 1]);
+/// assert_eq!(values.variants().0.len(), 2);
+/// assert_eq!(values.variants().1.len(), 1);
+/// assert!(matches!(values.view(2), Some(ValueView::Number(2))));
+/// ```
+pub struct DenseTypeIds<T: UnionLayout, Storage: Buffer = VecBuffer> {
+    /// The type ids of the items.
+    type_ids: Storage::For<i8>,
+    /// The index of every item in the child of its variant.
+    offsets: Storage::For<i32>,
+    /// The children, one per variant.
+    variants: T::Variants<Storage>,
+}
+
+impl<T: UnionLayout, Storage: Buffer> DenseTypeIds<T, Storage> {
+    /// Constructs [`DenseTypeIds`] from a `type_ids` buffer, an `offsets`
+    /// buffer and the `variants`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UnionError`] when there is not an offset per type id, when
+    /// a type id does not select a variant, or when an offset is out of bounds
+    /// of the child of its variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{collection::Collection, layout::union::DenseTypeIds};
+    /// use narrow_derive::ArrayType;
+    ///
+    /// #[derive(ArrayType, Clone, Debug, PartialEq)]
+    /// enum Value {
+    ///     Number(u8),
+    ///     Flag(bool),
+    /// }
+    ///
+    /// let variants = ValueVariants([1, 2].into_iter().collect(), [true].into_iter().collect());
+    /// let values = DenseTypeIds::<Value>::try_from_parts(vec![0, 1], vec![1, 0], variants);
+    /// assert_eq!(values.unwrap().owned(0), Some(Value::Number(2)));
+    /// ```
+    pub fn try_from_parts(
+        type_ids: Storage::For<i8>,
+        offsets: Storage::For<i32>,
+        variants: T::Variants<Storage>,
+    ) -> Result<Self, UnionError> {
+        let ids: &[i8] = type_ids.borrow();
+        let item_offsets: &[i32] = offsets.borrow();
+        if ids.len() != item_offsets.len() {
+            return Err(UnionError::OffsetsLength {
+                offsets: item_offsets.len(),
+                type_ids: ids.len(),
+            });
+        }
+        ids.iter()
+            .zip(item_offsets)
+            .enumerate()
+            .try_for_each(|(index, (&type_id, &offset))| {
+                let len = variants
+                    .variant_len(type_id)
+                    .ok_or(UnionError::InvalidTypeId { index, type_id })?;
+                match usize::try_from(offset) {
+                    Ok(position) if position < len => Ok(()),
+                    Ok(_) | Err(_) => Err(UnionError::OffsetOutOfBounds { index, offset, len }),
+                }
+            })?;
+        Ok(Self {
+            type_ids,
+            offsets,
+            variants,
+        })
+    }
+
+    /// Returns the type ids buffer, the offsets buffer and the variants of
+    /// these [`DenseTypeIds`].
+    ///
+    /// This is the inverse of [`DenseTypeIds::try_from_parts`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{collection::Collection, layout::union::DenseTypeIds, length::Length};
+    /// use narrow_derive::ArrayType;
+    ///
+    /// #[derive(ArrayType, Clone, Debug, PartialEq)]
+    /// enum Value {
+    ///     Number(u8),
+    ///     Flag(bool),
+    /// }
+    ///
+    /// let values = [Value::Flag(true)].into_iter().collect::<DenseTypeIds<Value>>();
+    /// let (type_ids, offsets, ValueVariants(numbers, flags)) = values.into_parts();
+    /// assert_eq!((type_ids, offsets), (vec![1], vec![0]));
+    /// assert!(numbers.is_empty());
+    /// assert_eq!(flags.owned(0), Some(true));
+    /// ```
+    #[must_use]
+    pub fn into_parts(self) -> (Storage::For<i8>, Storage::For<i32>, T::Variants<Storage>) {
+        (self.type_ids, self.offsets, self.variants)
+    }
+
+    /// Returns the offsets of the items in the children of their variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::layout::union::DenseTypeIds;
+    /// use narrow_derive::ArrayType;
+    ///
+    /// #[derive(ArrayType, Clone, Debug, PartialEq)]
+    /// enum Value {
+    ///     Number(u8),
+    ///     Flag(bool),
+    /// }
+    ///
+    /// let values = [Value::Number(1), Value::Number(2)]
+    ///     .into_iter()
+    ///     .collect::<DenseTypeIds<Value>>();
+    /// assert_eq!(values.offsets(), &[0, 1]);
+    /// ```
+    pub fn offsets(&self) -> &[i32] {
+        self.offsets.borrow()
+    }
+
+    /// Returns the children, one per variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{layout::union::DenseTypeIds, length::Length};
+    /// use narrow_derive::ArrayType;
+    ///
+    /// #[derive(ArrayType, Clone, Debug, PartialEq)]
+    /// enum Value {
+    ///     Number(u8),
+    ///     Flag(bool),
+    /// }
+    ///
+    /// let values = [Value::Number(1)].into_iter().collect::<DenseTypeIds<Value>>();
+    /// assert!(values.variants().1.is_empty());
+    /// ```
+    pub fn variants(&self) -> &T::Variants<Storage> {
+        &self.variants
+    }
+}
+
+impl<T: UnionLayout, Storage: Buffer> BufferRef for DenseTypeIds<T, Storage> {
+    type Buffer = Storage::For<i8>;
+
+    fn buffer_ref(&self) -> &Self::Buffer {
+        &self.type_ids
+    }
+}
+
+impl<T: UnionLayout, Storage: Buffer> Clone for DenseTypeIds<T, Storage>
+where
+    Storage::For<i8>: Clone,
+    Storage::For<i32>: Clone,
+    T::Variants<Storage>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            type_ids: self.type_ids.clone(),
+            offsets: self.offsets.clone(),
+            variants: self.variants.clone(),
+        }
+    }
+}
+
+impl<T: UnionLayout, Storage: Buffer> Debug for DenseTypeIds<T, Storage>
+where
+    Storage::For<i8>: Debug,
+    Storage::For<i32>: Debug,
+    T::Variants<Storage>: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DenseTypeIds")
+            .field("type_ids", &self.type_ids)
+            .field("offsets", &self.offsets)
+            .field("variants", &self.variants)
+            .finish()
+    }
+}
+
+impl<T: UnionLayout, Storage: Buffer> Default for DenseTypeIds<T, Storage>
+where
+    Storage::For<i8>: Default,
+    Storage::For<i32>: Default,
+    T::Variants<Storage>: Default,
+{
+    fn default() -> Self {
+        Self {
+            type_ids: Storage::For::<i8>::default(),
+            offsets: Storage::For::<i32>::default(),
+            variants: T::Variants::<Storage>::default(),
+        }
+    }
+}
+
+impl<T: UnionLayout, Storage: Buffer> DenseTypeIds<T, Storage> {
+    /// Returns the offset of the next value in the child of the variant with
+    /// `type_id`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the child exceeds `i32::MAX` values.
+    fn next_offset(&self, type_id: i8) -> i32 {
+        let len = self
+            .variants
+            .variant_len(type_id)
+            .expect("type id selects a variant");
+        i32::try_from(len).expect("dense union offset overflow")
+    }
+}
+
+impl<T: UnionLayout, Storage: Buffer> Extend<T> for DenseTypeIds<T, Storage>
+where
+    Storage::For<i8>: Extend<i8>,
+    Storage::For<i32>: Extend<i32>,
+    T::Variants<Storage>: UnionVariantsRealloc,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|item| {
+            let type_id = T::Variants::<Storage>::type_id(&item);
+            let offset = self.next_offset(type_id);
+            self.variants.push(item);
+            self.type_ids.extend(iter::once(type_id));
+            self.offsets.extend(iter::once(offset));
+        });
+    }
+}
+
+impl<T: UnionLayout, Storage: Buffer> FromIterator<T> for DenseTypeIds<T, Storage>
+where
+    Self: Default + Extend<T>,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut values = Self::default();
+        values.extend(iter);
+        values
+    }
+}
+
+impl<T: UnionLayout, Storage: Buffer> Length for DenseTypeIds<T, Storage> {
+    fn len(&self) -> usize {
+        self.type_ids.borrow().len()
+    }
+}
+
+impl<T: UnionLayout, Storage: Buffer> Collection for DenseTypeIds<T, Storage> {
+    type View<'collection>
+        = <T::Variants<Storage> as UnionVariants>::View<'collection>
+    where
+        Self: 'collection;
+
+    type Owned = T;
+
+    fn view(&self, index: usize) -> Option<Self::View<'_>> {
+        let type_id = *self.type_ids.borrow().get(index)?;
+        let offset = *self.offsets.borrow().get(index)?;
+        self.variants
+            .variant_view(type_id, usize::try_from(offset).ok()?)
+    }
+
+    type Iter<'collection>
+        = Map<
+        Zip<Range<usize>, RepeatN<&'collection Self>>,
+        fn((usize, &'collection Self)) -> Self::View<'collection>,
+    >
+    where
+        Self: 'collection;
+
+    fn iter_views(&self) -> Self::Iter<'_> {
+        let len = self.len();
+        (0..len)
+            .zip(iter::repeat_n(self, len))
+            .map(|(index, values)| values.view(index).expect("offset selects a value"))
+    }
+
+    type IntoIter = UnionIntoIter<Self>;
+
+    fn into_iter_owned(self) -> Self::IntoIter {
+        UnionIntoIter::new(self)
+    }
+}
+
+impl<T: UnionLayout, Storage: Buffer> CollectionAllocIn for DenseTypeIds<T, Storage>
+where
+    Storage::For<i8>: CollectionRealloc,
+    Storage::For<i32>: CollectionRealloc<Alloc = <Storage::For<i8> as CollectionAllocIn>::Alloc>,
+    T::Variants<Storage>:
+        UnionVariantsRealloc<Alloc = <Storage::For<i8> as CollectionAllocIn>::Alloc>,
+{
+    type Alloc = <Storage::For<i8> as CollectionAllocIn>::Alloc;
+
+    fn with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Self {
+        // The distribution of items over the variants is unknown, so only the
+        // type ids and offsets are preallocated.
+        Self {
+            type_ids: Storage::For::<i8>::with_capacity_in(capacity, alloc.clone()),
+            offsets: Storage::For::<i32>::with_capacity_in(capacity, alloc.clone()),
+            variants: T::Variants::<Storage>::with_capacity_in(0, alloc),
+        }
+    }
+
+    fn from_iter_in<I: IntoIterator<Item = Self::Owned>>(iter: I, alloc: Self::Alloc) -> Self {
+        let items = iter.into_iter();
+        let mut values = Self::with_capacity_in(items.size_hint().0, alloc);
+        values.extend(items);
+        values
+    }
+
+    fn try_with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Result<Self, AllocError> {
+        Ok(Self {
+            type_ids: Storage::For::<i8>::try_with_capacity_in(capacity, alloc.clone())?,
+            offsets: Storage::For::<i32>::try_with_capacity_in(capacity, alloc.clone())?,
+            variants: T::Variants::<Storage>::try_with_capacity_in(0, alloc)?,
+        })
+    }
+
+    fn try_from_iter_in<I: IntoIterator<Item = Self::Owned>>(
+        iter: I,
+        alloc: Self::Alloc,
+    ) -> Result<Self, AllocError> {
+        let items = iter.into_iter();
+        let mut values = Self::try_with_capacity_in(items.size_hint().0, alloc)?;
+        values.try_extend(items)?;
+        Ok(values)
+    }
+}
+
+impl<T: UnionLayout, Storage: Buffer> CollectionRealloc for DenseTypeIds<T, Storage>
+where
+    Storage::For<i8>: CollectionRealloc,
+    Storage::For<i32>: CollectionRealloc<Alloc = <Storage::For<i8> as CollectionAllocIn>::Alloc>,
+    T::Variants<Storage>:
+        UnionVariantsRealloc<Alloc = <Storage::For<i8> as CollectionAllocIn>::Alloc>,
+{
+    fn allocator(&self) -> Self::Alloc {
+        self.type_ids.allocator()
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+        self.type_ids.try_reserve(additional)?;
+        self.offsets.try_reserve(additional)
+    }
+
+    fn try_extend<I: IntoIterator<Item = Self::Owned>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), AllocError> {
+        // An item touches three collections, so a failed reservation can
+        // leave them with different lengths. Truncating restores the items
+        // present before the call.
+        let len = self.len();
+        iter.into_iter()
+            .try_for_each(|item| {
+                let type_id = T::Variants::<Storage>::type_id(&item);
+                let offset = self.next_offset(type_id);
+                self.variants.try_push(item)?;
+                self.type_ids.try_extend(iter::once(type_id))?;
+                self.offsets.try_extend(iter::once(offset))
+            })
+            .inspect_err(|_| self.truncate(len))
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.type_ids.reserve(additional);
+        self.offsets.reserve(additional);
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.type_ids.truncate(len);
+        self.offsets.truncate(len);
+        // Values are appended in item order, so every child keeps the values
+        // up to the largest offset of its variant among the remaining items.
+        let mut variant_lens = [0_usize; MAX_VARIANTS];
+        self.type_ids
+            .borrow()
+            .iter()
+            .zip(self.offsets.borrow())
+            .for_each(|(&type_id, &offset)| {
+                if let (Ok(variant), Ok(position)) =
+                    (usize::try_from(type_id), usize::try_from(offset))
+                    && let Some(variant_len) = variant_lens.get_mut(variant)
+                {
+                    *variant_len = (*variant_len).max(position.strict_add(1));
+                }
+            });
+        variant_type_ids::<T::Variants<Storage>>()
+            .zip(variant_lens)
+            .for_each(|(type_id, variant_len)| self.variants.truncate(type_id, variant_len));
+    }
+}
+
+/// A collection of dense unions.
+///
+/// This is the Arrow dense union layout: [`DenseTypeIds`] with outer
+/// nullability. The validity bitmap sits next to the type ids, so a null item
+/// costs a type id, an offset and a default value in the child of its
+/// variant.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{collection::Collection, layout::union::DenseUnion};
+/// use narrow_derive::ArrayType;
+///
+/// #[derive(ArrayType, Clone, Debug, PartialEq)]
+/// enum Value {
+///     Number(u8),
+///     Text(String),
+/// }
+///
+/// let values = [Value::Number(1), Value::Text(String::from("a"))]
+///     .into_iter()
+///     .collect::<DenseUnion<Value>>();
+/// assert!(matches!(values.view(1), Some(ValueView::Text("a"))));
+/// assert_eq!(values.owned(0), Some(Value::Number(1)));
+/// ```
+pub struct DenseUnion<T: UnionLayout, Nulls: Nullability = NonNullable, Storage: Buffer = VecBuffer>(
+    Window<Nulls::Collection<DenseTypeIds<T, Storage>, Storage>>,
+);
+
+impl<T: UnionLayout, Nulls: Nullability, Storage: Buffer> MemoryLayout
+    for DenseUnion<T, Nulls, Storage>
+{
+    fn slice(self, offset: usize, len: usize) -> Self {
+        Self(self.0.slice(offset, len))
+    }
+
+    fn offset(&self) -> usize {
+        self.0.offset()
+    }
+}
+
+impl<T: UnionLayout, Nulls: Nullability, Storage: Buffer> DenseUnion<T, Nulls, Storage> {
+    /// Constructs a [`DenseUnion`] from its backing collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{
+    ///     collection::Collection,
+    ///     layout::union::{DenseTypeIds, DenseUnion},
+    ///     nullability::NonNullable,
+    /// };
+    /// use narrow_derive::ArrayType;
+    ///
+    /// #[derive(ArrayType, Clone, Debug, PartialEq)]
+    /// enum Value {
+    ///     Number(u8),
+    ///     Flag(bool),
+    /// }
+    ///
+    /// let type_ids = DenseTypeIds::<Value>::try_from_parts(
+    ///     vec![1],
+    ///     vec![0],
+    ///     ValueVariants(Default::default(), [true].into_iter().collect()),
+    /// );
+    /// let values = DenseUnion::<_, NonNullable>::from_buffer(type_ids.unwrap());
+    /// assert_eq!(values.owned(0), Some(Value::Flag(true)));
+    /// ```
+    #[must_use]
+    pub fn from_buffer(buffer: Nulls::Collection<DenseTypeIds<T, Storage>, Storage>) -> Self {
+        Self(Window::new(buffer))
+    }
+
+    /// Returns the backing collection of this [`DenseUnion`].
+    ///
+    /// This is the inverse of [`DenseUnion::from_buffer`]. The backing
+    /// collection of a slice still contains the items outside of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{layout::union::DenseUnion, length::Length};
+    /// use narrow_derive::ArrayType;
+    ///
+    /// #[derive(ArrayType, Clone, Debug, PartialEq)]
+    /// enum Value {
+    ///     Number(u8),
+    ///     Flag(bool),
+    /// }
+    ///
+    /// let values = [Value::Number(1)].into_iter().collect::<DenseUnion<Value>>();
+    /// assert_eq!(values.into_buffer().len(), 1);
+    /// ```
+    #[must_use]
+    pub fn into_buffer(self) -> Nulls::Collection<DenseTypeIds<T, Storage>, Storage> {
+        self.0.into_parts().0
+    }
+}
+
+impl<T: UnionLayout, Nulls: Nullability, Storage: Buffer> BufferRef
+    for DenseUnion<T, Nulls, Storage>
+{
+    type Buffer = Nulls::Collection<DenseTypeIds<T, Storage>, Storage>;
+
+    fn buffer_ref(&self) -> &Self::Buffer {
+        self.0.buffer_ref()
+    }
+}
+
+impl<T: UnionLayout, Nulls: Nullability, Storage: Buffer> Clone for DenseUnion<T, Nulls, Storage>
+where
+    Nulls::Collection<DenseTypeIds<T, Storage>, Storage>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: UnionLayout, Nulls: Nullability, Storage: Buffer> Debug for DenseUnion<T, Nulls, Storage>
+where
+    Nulls::Collection<DenseTypeIds<T, Storage>, Storage>: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("DenseUnion").field(&self.0).finish()
+    }
+}
+
+impl<T: UnionLayout, Nulls: Nullability, Storage: Buffer> Default for DenseUnion<T, Nulls, Storage>
+where
+    Nulls::Collection<DenseTypeIds<T, Storage>, Storage>: Default,
+{
+    fn default() -> Self {
+        Self(Window::default())
+    }
+}
+
+impl<T: UnionLayout, Nulls: Nullability, Storage: Buffer> Extend<Nulls::Item<T>>
+    for DenseUnion<T, Nulls, Storage>
+where
+    Window<Nulls::Collection<DenseTypeIds<T, Storage>, Storage>>: Extend<Nulls::Item<T>>,
+{
+    fn extend<I: IntoIterator<Item = Nulls::Item<T>>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl<T: UnionLayout, Nulls: Nullability, Storage: Buffer> FromIterator<Nulls::Item<T>>
+    for DenseUnion<T, Nulls, Storage>
+where
+    Nulls::Collection<DenseTypeIds<T, Storage>, Storage>: FromIterator<Nulls::Item<T>>,
+{
+    fn from_iter<I: IntoIterator<Item = Nulls::Item<T>>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<T: UnionLayout, Nulls: Nullability, Storage: Buffer> Length for DenseUnion<T, Nulls, Storage> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<T: UnionLayout, Nulls: Nullability, Storage: Buffer> Collection
+    for DenseUnion<T, Nulls, Storage>
+{
+    type View<'collection>
+        = <Nulls::Collection<DenseTypeIds<T, Storage>, Storage> as Collection>::View<'collection>
+    where
+        Self: 'collection;
+
+    type Owned = Nulls::Item<T>;
+
+    fn view(&self, index: usize) -> Option<Self::View<'_>> {
+        self.0.view(index)
+    }
+
+    type Iter<'collection>
+        = <Window<Nulls::Collection<DenseTypeIds<T, Storage>, Storage>> as Collection>::Iter<
+        'collection,
+    >
+    where
+        Self: 'collection;
+
+    fn iter_views(&self) -> Self::Iter<'_> {
+        self.0.iter_views()
+    }
+
+    type IntoIter =
+        <Window<Nulls::Collection<DenseTypeIds<T, Storage>, Storage>> as Collection>::IntoIter;
+
+    fn into_iter_owned(self) -> Self::IntoIter {
+        self.0.into_iter_owned()
+    }
+}
+
+impl<T: UnionLayout, Nulls: Nullability, Storage: Buffer> CollectionAllocIn
+    for DenseUnion<T, Nulls, Storage>
+where
+    Nulls::Collection<DenseTypeIds<T, Storage>, Storage>: CollectionAllocIn,
+{
+    type Alloc = <Nulls::Collection<DenseTypeIds<T, Storage>, Storage> as CollectionAllocIn>::Alloc;
+
+    fn with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Self {
+        Self(Window::with_capacity_in(capacity, alloc))
+    }
+
+    fn from_iter_in<I: IntoIterator<Item = Self::Owned>>(iter: I, alloc: Self::Alloc) -> Self {
+        Self(Window::from_iter_in(iter, alloc))
+    }
+
+    fn try_with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Result<Self, AllocError> {
+        Window::try_with_capacity_in(capacity, alloc).map(Self)
+    }
+
+    fn try_from_iter_in<I: IntoIterator<Item = Self::Owned>>(
+        iter: I,
+        alloc: Self::Alloc,
+    ) -> Result<Self, AllocError> {
+        Window::try_from_iter_in(iter, alloc).map(Self)
+    }
+}
+
+impl<T: UnionLayout, Nulls: Nullability, Storage: Buffer> CollectionRealloc
+    for DenseUnion<T, Nulls, Storage>
+where
+    Nulls::Collection<DenseTypeIds<T, Storage>, Storage>: CollectionRealloc,
+{
+    fn allocator(&self) -> Self::Alloc {
+        self.0.allocator()
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+        self.0.try_reserve(additional)
+    }
+
+    fn try_extend<I: IntoIterator<Item = Self::Owned>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), AllocError> {
+        self.0.try_extend(iter)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.0.truncate(len);
+    }
+}
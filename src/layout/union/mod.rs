@@ -0,0 +1,742 @@
+//! Union arrays.
+//!
+//! Arrow stores a sum type as a union: one child array per variant and an
+//! `i8` type id per item that selects the variant. Sparse unions keep every
+//! child as long as the union, dense unions add an offset per item into the
+//! child of its variant:
+//!
+//! ```text
+//! [Number(1), Text("a"), Number(2)]
+//!
+//! sparse: type ids [0, 1, 0]
+//!         number   [1, _, 2]
+//!         text     [_, "a", _]
+//!
+//! dense:  type ids [0, 1, 0]
+//!         offsets  [0, 0, 1]
+//!         number   [1, 2]
+//!         text     ["a"]
+//! ```
+//!
+//! Enums select a union layout with `#[derive(ArrayType)]`.
+
+use core::{
+    fmt::{self, Debug},
+    ops::Range,
+};
+
+use crate::{
+    buffer::Buffer,
+    collection::{AllocError, Collection, owned::IntoOwned},
+};
+
+mod dense;
+mod sparse;
+
+pub use dense::{DenseTypeIds, DenseUnion};
+pub use sparse::{SparseTypeIds, SparseUnion};
+
+/// The maximum number of variants of a union.
+///
+/// Arrow type ids are non-negative `i8` values.
+const MAX_VARIANTS: usize = 128;
+
+/// Mapping a sum type to the child collections of its variants.
+///
+/// This is the union counterpart of
+/// [`StructLayout`](crate::layout::struct_array::StructLayout). The variants
+/// collection dispatches items to the child of their variant, which lets
+/// [`SparseUnion`] and [`DenseUnion`] stay agnostic of the number and types of
+/// the variants. Derived enums generate a variants collection with one child
+/// per variant.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{
+///     buffer::VecBuffer,
+///     layout::{fixed_size_primitive::FixedSizePrimitive, union::UnionLayout, utf8::Utf8},
+/// };
+/// use narrow_derive::ArrayType;
+///
+/// #[derive(ArrayType, Clone, Debug, PartialEq)]
+/// enum Value {
+///     Number(u8),
+///     Text(String),
+/// }
+///
+/// fn assert_variants<T: UnionLayout<Variants<VecBuffer> = Variants>, Variants>() {}
+/// assert_variants::<Value, ValueVariants<FixedSizePrimitive<u8>, Utf8>>();
+/// ```
+pub trait UnionLayout: Sized {
+    /// The child collections, one per variant.
+    type Variants<Storage: Buffer>: UnionVariants<Owned = Self>;
+}
+
+/// The child collections of the variants of a union.
+///
+/// Variants are identified by their type id, which is their index in
+/// declaration order. The union layouts store the type ids, so the variants
+/// only resolve a type id and an index in the child of that variant.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::layout::union::UnionVariants;
+/// use narrow_derive::ArrayType;
+///
+/// #[derive(ArrayType, Clone, Debug, PartialEq)]
+/// enum Value {
+///     Number(u8),
+///     Flag(bool),
+/// }
+///
+/// let variants = ValueVariants(vec![1, 2], vec![true]);
+/// assert_eq!(ValueVariants::<Vec<u8>, Vec<bool>>::type_id(&Value::Flag(false)), 1);
+/// assert_eq!(variants.variant_len(0), Some(2));
+/// assert!(matches!(variants.variant_view(1, 0), Some(ValueView::Flag(true))));
+/// ```
+pub trait UnionVariants {
+    /// The number of variants, at most 128.
+    const VARIANTS: usize;
+
+    /// Owned items of the union.
+    type Owned;
+
+    /// Borrowed view of an item of the union.
+    type View<'collection>: Copy + IntoOwned<Self::Owned> + 'collection
+    where
+        Self: 'collection;
+
+    /// Returns the type id of the variant of `item`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::layout::union::UnionVariants;
+    /// use narrow_derive::ArrayType;
+    ///
+    /// #[derive(ArrayType, Clone, Debug, PartialEq)]
+    /// enum Value {
+    ///     Number(u8),
+    ///     Flag(bool),
+    /// }
+    ///
+    /// assert_eq!(ValueVariants::<Vec<u8>, Vec<bool>>::type_id(&Value::Number(1)), 0);
+    /// ```
+    fn type_id(item: &Self::Owned) -> i8;
+
+    /// Returns the number of values in the child of the variant with
+    /// `type_id`, or `None` if `type_id` does not select a variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::layout::union::UnionVariants;
+    /// use narrow_derive::ArrayType;
+    ///
+    /// #[derive(ArrayType, Clone, Debug, PartialEq)]
+    /// enum Value {
+    ///     Number(u8),
+    ///     Flag(bool),
+    /// }
+    ///
+    /// let variants = ValueVariants(vec![1], vec![true, false]);
+    /// assert_eq!(variants.variant_len(1), Some(2));
+    /// assert_eq!(variants.variant_len(2), None);
+    /// ```
+    fn variant_len(&self, type_id: i8) -> Option<usize>;
+
+    /// Returns a view of the value at `index` in the child of the variant with
+    /// `type_id`, or `None` if either is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::layout::union::UnionVariants;
+    /// use narrow_derive::ArrayType;
+    ///
+    /// #[derive(ArrayType, Clone, Debug, PartialEq)]
+    /// enum Value {
+    ///     Number(u8),
+    ///     Flag(bool),
+    /// }
+    ///
+    /// let variants = ValueVariants(vec![1], vec![true]);
+    /// assert!(matches!(variants.variant_view(0, 0), Some(ValueView::Number(1))));
+    /// assert!(variants.variant_view(0, 1).is_none());
+    /// ```
+    fn variant_view(&self, type_id: i8, index: usize) -> Option<Self::View<'_>>;
+}
+
+/// Growable child collections of the variants of a union.
+///
+/// Appending an item only touches the child of its variant. The union layouts
+/// add the type ids and offsets, and use [`SparseUnionVariants`] to keep the
+/// other children aligned for sparse unions.
+///
+/// All children share one allocator, in the same way as the fields of a
+/// struct.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::layout::union::{UnionVariants, UnionVariantsRealloc};
+/// use narrow_derive::ArrayType;
+///
+/// #[derive(ArrayType, Clone, Debug, PartialEq)]
+/// enum Value {
+///     Number(u8),
+///     Flag(bool),
+/// }
+///
+/// let mut variants = ValueVariants::<Vec<u8>, Vec<bool>>::with_capacity_in(0, ());
+/// variants.push(Value::Flag(true));
+/// variants.try_push(Value::Number(1)).unwrap();
+/// assert_eq!((&variants.0, &variants.1), (&vec![1], &vec![true]));
+/// variants.truncate(1, 0);
+/// assert_eq!(variants.variant_len(1), Some(0));
+/// ```
+pub trait UnionVariantsRealloc: UnionVariants + Sized {
+    /// Allocator used by the children.
+    type Alloc: Clone;
+
+    /// Constructs empty children with at least the specified capacity each.
+    #[must_use]
+    fn with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Self;
+
+    /// Tries to construct empty children with at least the specified capacity
+    /// each.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AllocError`] when the requested capacity cannot be
+    /// reserved.
+    fn try_with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Result<Self, AllocError>;
+
+    /// Returns the allocator used by the children.
+    fn allocator(&self) -> Self::Alloc;
+
+    /// Appends `item` to the child of its variant.
+    fn push(&mut self, item: Self::Owned);
+
+    /// Tries to append `item` to the child of its variant.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AllocError`] when storage for the item cannot be
+    /// reserved.
+    fn try_push(&mut self, item: Self::Owned) -> Result<(), AllocError>;
+
+    /// Shortens the child of the variant with `type_id` to `len` values.
+    fn truncate(&mut self, type_id: i8, len: usize);
+}
+
+/// Child collections that can be padded, as sparse unions require.
+///
+/// Every child of a sparse union has a value for every item. Items of other
+/// variants are padded with the default value of the child.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::layout::union::{SparseUnionVariants, UnionVariantsRealloc};
+/// use narrow_derive::ArrayType;
+///
+/// #[derive(ArrayType, Clone, Debug, PartialEq)]
+/// #[narrow(sparse)]
+/// enum Value {
+///     Number(u8),
+///     Flag(bool),
+/// }
+///
+/// let mut variants = ValueVariants::<Vec<u8>, Vec<bool>>::with_capacity_in(0, ());
+/// variants.push(Value::Number(1));
+/// variants.push_default(1);
+/// assert_eq!((&variants.0, &variants.1), (&vec![1], &vec![false]));
+/// ```
+pub trait SparseUnionVariants: UnionVariantsRealloc {
+    /// Reserves capacity for at least `additional` more values in every child.
+    fn reserve(&mut self, additional: usize);
+
+    /// Tries to reserve capacity for at least `additional` more values in
+    /// every child.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AllocError`] when the requested capacity cannot be
+    /// reserved.
+    fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError>;
+
+    /// Appends a default value to the child of the variant with `type_id`.
+    fn push_default(&mut self, type_id: i8);
+
+    /// Tries to append a default value to the child of the variant with
+    /// `type_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AllocError`] when storage for the value cannot be
+    /// reserved.
+    fn try_push_default(&mut self, type_id: i8) -> Result<(), AllocError>;
+}
+
+/// Error returned when constructing a union from its parts.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::layout::union::{SparseTypeIds, UnionError};
+/// use narrow_derive::ArrayType;
+///
+/// #[derive(ArrayType, Clone, Debug, PartialEq)]
+/// #[narrow(sparse)]
+/// enum Value {
+///     Number(u8),
+///     Flag(bool),
+/// }
+///
+/// let variants = ValueVariants([1, 2].into_iter().collect(), [true, false].into_iter().collect());
+/// let error = SparseTypeIds::<Value>::try_from_parts(vec![0, 2], variants);
+/// assert_eq!(error.unwrap_err(), UnionError::InvalidTypeId { index: 1, type_id: 2 });
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnionError {
+    /// The type id at `index` does not select a variant.
+    InvalidTypeId {
+        /// The index of the invalid type id.
+        index: usize,
+        /// The invalid type id.
+        type_id: i8,
+    },
+    /// The child of a sparse union variant does not have a value per item.
+    VariantLength {
+        /// The type id of the variant.
+        type_id: i8,
+        /// The number of values in the child.
+        len: usize,
+        /// The number of items.
+        expected: usize,
+    },
+    /// A dense union does not have an offset per type id.
+    OffsetsLength {
+        /// The number of offsets.
+        offsets: usize,
+        /// The number of type ids.
+        type_ids: usize,
+    },
+    /// The offset at `index` is out of bounds of the child of its variant.
+    OffsetOutOfBounds {
+        /// The index of the invalid offset.
+        index: usize,
+        /// The invalid offset.
+        offset: i32,
+        /// The number of values in the child.
+        len: usize,
+    },
+}
+
+impl fmt::Display for UnionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::InvalidTypeId { index, type_id } => write!(
+                f,
+                "type id {type_id} at index {index} does not select a variant"
+            ),
+            Self::VariantLength {
+                type_id,
+                len,
+                expected,
+            } => write!(
+                f,
+                "variant with type id {type_id} has {len} values, expected {expected}"
+            ),
+            Self::OffsetsLength { offsets, type_ids } => {
+                write!(f, "{offsets} offsets for {type_ids} type ids")
+            }
+            Self::OffsetOutOfBounds { index, offset, len } => write!(
+                f,
+                "offset {offset} at index {index} is out of bounds for a variant of {len} values"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for UnionError {}
+
+/// Returns the type ids of all variants of `T`.
+fn variant_type_ids<T: UnionVariants>() -> impl Iterator<Item = i8> {
+    (0..=i8::MAX).take(T::VARIANTS)
+}
+
+/// Iterator over owned items of a union.
+///
+/// Every item is materialized from the child value its type id selects.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{collection::Collection, layout::union::DenseTypeIds};
+/// use narrow_derive::ArrayType;
+///
+/// #[derive(ArrayType, Clone, Debug, PartialEq)]
+/// enum Value {
+///     Number(u8),
+///     Flag(bool),
+/// }
+///
+/// let values = [Value::Number(1), Value::Flag(true)]
+///     .into_iter()
+///     .collect::<DenseTypeIds<Value>>();
+/// let mut iter = values.into_iter_owned();
+/// assert_eq!(iter.len(), 2);
+/// assert_eq!(iter.next(), Some(Value::Number(1)));
+/// ```
+pub struct UnionIntoIter<T> {
+    /// The remaining indices.
+    range: Range<usize>,
+    /// The iterated union.
+    union: T,
+}
+
+impl<T: Debug> Debug for UnionIntoIter<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UnionIntoIter")
+            .field("range", &self.range)
+            .field("union", &self.union)
+            .finish()
+    }
+}
+
+impl<T: Collection> UnionIntoIter<T> {
+    /// Returns an iterator over all owned items of `union`.
+    fn new(union: T) -> Self {
+        Self {
+            range: 0..union.len(),
+            union,
+        }
+    }
+}
+
+impl<T: Collection> Iterator for UnionIntoIter<T> {
+    type Item = T::Owned;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range.next().and_then(|index| self.union.owned(index))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl<T: Collection> ExactSizeIterator for UnionIntoIter<T> {}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::{string::String, vec, vec::Vec};
+    use core::iter;
+
+    use crate::{
+        buffer::BufferRef,
+        collection::{Collection, CollectionRealloc, tests::round_trip, view::AsView},
+        layout::ArrayItem,
+        length::Length,
+    };
+
+    use super::*;
+
+    /// A sum type with two variants, laid out like a derived enum.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    enum Either<L, R> {
+        Left(L),
+        Right(R),
+    }
+
+    /// Child collections of the variants of [`Either`].
+    #[derive(Clone, Debug, Default, PartialEq)]
+    struct EitherVariants<L, R>(L, R);
+
+    impl<L: ArrayItem, R: ArrayItem> UnionLayout for Either<L, R> {
+        type Variants<Storage: Buffer> = EitherVariants<L::Memory<Storage>, R::Memory<Storage>>;
+    }
+
+    impl<'collection, L: AsView<'collection>, R: AsView<'collection>> AsView<'collection>
+        for Either<L, R>
+    {
+        type View = Either<L::View, R::View>;
+
+        fn as_view(&'collection self) -> Self::View {
+            match *self {
+                Self::Left(ref left) => Either::Left(left.as_view()),
+                Self::Right(ref right) => Either::Right(right.as_view()),
+            }
+        }
+    }
+
+    impl<L: IntoOwned<M>, M, R: IntoOwned<S>, S> IntoOwned<Either<M, S>> for Either<L, R> {
+        fn into_owned(self) -> Either<M, S> {
+            match self {
+                Self::Left(left) => Either::Left(left.into_owned()),
+                Self::Right(right) => Either::Right(right.into_owned()),
+            }
+        }
+    }
+
+    impl<L: Collection, R: Collection> UnionVariants for EitherVariants<L, R> {
+        const VARIANTS: usize = 2;
+
+        type Owned = Either<L::Owned, R::Owned>;
+
+        type View<'collection>
+            = Either<L::View<'collection>, R::View<'collection>>
+        where
+            Self: 'collection;
+
+        fn type_id(item: &Self::Owned) -> i8 {
+            match *item {
+                Either::Left(_) => 0,
+                Either::Right(_) => 1,
+            }
+        }
+
+        fn variant_len(&self, type_id: i8) -> Option<usize> {
+            match type_id {
+                0 => Some(self.0.len()),
+                1 => Some(self.1.len()),
+                _ => None,
+            }
+        }
+
+        fn variant_view(&self, type_id: i8, index: usize) -> Option<Self::View<'_>> {
+            match type_id {
+                0 => self.0.view(index).map(Either::Left),
+                1 => self.1.view(index).map(Either::Right),
+                _ => None,
+            }
+        }
+    }
+
+    impl<L, R> UnionVariantsRealloc for EitherVariants<L, R>
+    where
+        L: CollectionRealloc,
+        R: CollectionRealloc<Alloc = L::Alloc>,
+    {
+        type Alloc = L::Alloc;
+
+        fn with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Self {
+            Self(
+                L::with_capacity_in(capacity, alloc.clone()),
+                R::with_capacity_in(capacity, alloc),
+            )
+        }
+
+        fn try_with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Result<Self, AllocError> {
+            Ok(Self(
+                L::try_with_capacity_in(capacity, alloc.clone())?,
+                R::try_with_capacity_in(capacity, alloc)?,
+            ))
+        }
+
+        fn allocator(&self) -> Self::Alloc {
+            self.0.allocator()
+        }
+
+        fn push(&mut self, item: Self::Owned) {
+            match item {
+                Either::Left(left) => self.0.extend(iter::once(left)),
+                Either::Right(right) => self.1.extend(iter::once(right)),
+            }
+        }
+
+        fn try_push(&mut self, item: Self::Owned) -> Result<(), AllocError> {
+            match item {
+                Either::Left(left) => self.0.try_extend(iter::once(left)),
+                Either::Right(right) => self.1.try_extend(iter::once(right)),
+            }
+        }
+
+        fn truncate(&mut self, type_id: i8, len: usize) {
+            match type_id {
+                0 => self.0.truncate(len),
+                1 => self.1.truncate(len),
+                _ => {}
+            }
+        }
+    }
+
+    impl<L, R> SparseUnionVariants for EitherVariants<L, R>
+    where
+        L: CollectionRealloc<Owned: Default>,
+        R: CollectionRealloc<Alloc = L::Alloc, Owned: Default>,
+    {
+        fn reserve(&mut self, additional: usize) {
+            self.0.reserve(additional);
+            self.1.reserve(additional);
+        }
+
+        fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+            self.0.try_reserve(additional)?;
+            self.1.try_reserve(additional)
+        }
+
+        fn push_default(&mut self, type_id: i8) {
+            match type_id {
+                0 => self.0.extend(iter::once(L::Owned::default())),
+                1 => self.1.extend(iter::once(R::Owned::default())),
+                _ => {}
+            }
+        }
+
+        fn try_push_default(&mut self, type_id: i8) -> Result<(), AllocError> {
+            match type_id {
+                0 => self.0.try_extend(iter::once(L::Owned::default())),
+                1 => self.1.try_extend(iter::once(R::Owned::default())),
+                _ => Ok(()),
+            }
+        }
+    }
+
+    use Either::{Left, Right};
+
+    type Sparse = SparseUnion<Either<u32, String>>;
+    type Dense = DenseUnion<Either<u32, String>>;
+
+    fn items() -> Vec<Either<u32, String>> {
+        vec![
+            Left(1),
+            Right(String::from("a")),
+            Left(2),
+            Right(String::new()),
+            Left(3),
+        ]
+    }
+
+    #[test]
+    fn sparse_children_have_union_length() {
+        let values = items().into_iter().collect::<Sparse>();
+        let type_ids = values.buffer_ref();
+        assert_eq!(type_ids.buffer_ref(), &[0, 1, 0, 1, 0]);
+        assert_eq!(type_ids.variants().0.len(), 5);
+        assert_eq!(type_ids.variants().1.len(), 5);
+        assert_eq!(values.view(1), Some(Right("a")));
+    }
+
+    #[test]
+    fn dense_children_have_variant_length() {
+        let values = items().into_iter().collect::<Dense>();
+        let type_ids = values.buffer_ref();
+        assert_eq!(type_ids.buffer_ref(), &[0, 1, 0, 1, 0]);
+        assert_eq!(type_ids.offsets(), &[0, 0, 1, 1, 2]);
+        assert_eq!(type_ids.variants().0.len(), 3);
+        assert_eq!(type_ids.variants().1.len(), 2);
+        assert_eq!(values.view(4), Some(Left(3)));
+    }
+
+    #[test]
+    fn try_from_parts() {
+        let sparse = SparseTypeIds::<Either<u8, bool>>::try_from_parts(
+            vec![0, 1],
+            EitherVariants(
+                [1].into_iter().collect(),
+                [false, true].into_iter().collect(),
+            ),
+        );
+        assert_eq!(
+            sparse.expect_err("short child"),
+            UnionError::VariantLength {
+                type_id: 0,
+                len: 1,
+                expected: 2
+            }
+        );
+
+        let dense = |type_ids, offsets| {
+            DenseTypeIds::<Either<u8, bool>>::try_from_parts(
+                type_ids,
+                offsets,
+                EitherVariants([1, 2].into_iter().collect(), [true].into_iter().collect()),
+            )
+        };
+        assert_eq!(
+            dense(vec![1, 0, 0], vec![0, 1]).expect_err("missing offset"),
+            UnionError::OffsetsLength {
+                offsets: 2,
+                type_ids: 3
+            }
+        );
+        assert_eq!(
+            dense(vec![1, -1], vec![0, 0]).expect_err("negative type id"),
+            UnionError::InvalidTypeId {
+                index: 1,
+                type_id: -1
+            }
+        );
+        assert_eq!(
+            dense(vec![0, 1], vec![1, 1]).expect_err("offset out of bounds"),
+            UnionError::OffsetOutOfBounds {
+                index: 1,
+                offset: 1,
+                len: 1
+            }
+        );
+
+        let values = dense(vec![0, 1, 0], vec![1, 0, 1]).expect("valid parts");
+        assert_eq!(values.owned(0), Some(Left(2)));
+        assert_eq!(values.owned(1), Some(Right(true)));
+    }
+
+    #[test]
+    fn from_buffer() {
+        let values = items().into_iter().collect::<Dense>();
+        let restored = Dense::from_buffer(values.into_buffer());
+        assert_eq!(restored.len(), 5);
+        assert_eq!(restored.owned(1), Some(Right(String::from("a"))));
+    }
+
+    #[test]
+    fn variants() {
+        let values = [Left(1), Right(vec![Some(true), None])]
+            .into_iter()
+            .collect::<SparseTypeIds<Either<u16, Vec<Option<bool>>>>>();
+        let (type_ids, EitherVariants(left, right)) = values.into_parts();
+        assert_eq!(type_ids, [0, 1]);
+        assert_eq!(left.into_buffer(), [1, 0]);
+        assert_eq!(right.owned(1), Some(vec![Some(true), None]));
+    }
+
+    #[test]
+    fn collection() {
+        round_trip::<Sparse, _>(items());
+        round_trip::<Dense, _>(items());
+        round_trip::<SparseUnion<Either<u8, bool>>, _>([Left(1), Right(false), Right(true)]);
+        round_trip::<DenseUnion<Either<Vec<u8>, (u8, bool)>>, _>([
+            Right((1, true)),
+            Left(vec![1, 2]),
+            Left(vec![]),
+        ]);
+    }
+
+    #[test]
+    fn try_extend() {
+        let mut sparse = items().into_iter().take(2).collect::<Sparse>();
+        CollectionRealloc::try_extend(&mut sparse, items()).expect("reserved");
+        assert_eq!(sparse.len(), 7);
+        CollectionRealloc::truncate(&mut sparse, 3);
+        assert_eq!(sparse.buffer_ref().variants().1.len(), 3);
+        assert_eq!(
+            sparse.into_iter_owned().collect::<Vec<_>>(),
+            [Left(1), Right(String::from("a")), Left(1)]
+        );
+
+        let mut dense = items().into_iter().collect::<Dense>();
+        CollectionRealloc::truncate(&mut dense, 2);
+        assert_eq!(dense.buffer_ref().variants().0.len(), 1);
+        assert_eq!(dense.buffer_ref().variants().1.len(), 1);
+        CollectionRealloc::try_extend(&mut dense, items()).expect("reserved");
+        assert_eq!(dense.buffer_ref().offsets(), &[0, 0, 1, 1, 2, 2, 3]);
+        assert_eq!(dense.owned(6), Some(Left(3)));
+    }
+}
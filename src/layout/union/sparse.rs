@@ -0,0 +1,634 @@
+//! Sparse unions.
+
+use core::{
+    borrow::Borrow,
+    fmt::{self, Debug},
+    iter::{self, Map, RepeatN, Zip},
+    ops::Range,
+};
+
+use crate::{
+    buffer::{Buffer, BufferRef, VecBuffer},
+    collection::{AllocError, Collection, CollectionAllocIn, CollectionRealloc, window::Window},
+    layout::{
+        MemoryLayout,
+        union::{
+            SparseUnionVariants, UnionError, UnionIntoIter, UnionLayout, UnionVariants,
+            UnionVariantsRealloc, variant_type_ids,
+        },
+    },
+    length::Length,
+    nullability::{NonNullable, Nullability},
+};
+
+/// The type ids and children of a sparse union.
+///
+/// Every child has a value for every item. Items of other variants are
+/// padded with default values, so the value of an item is at the same index
+/// in the child of its variant:
+///
+/// ```text
+/// [Number(1), Flag(true)] -> type ids [0, 1]
+///                             child 0  [1, 0]
+///                             child 1  [false, true]
+/// ```
+///
+/// Padding makes sparse unions larger than dense unions, but items are
+/// resolved without an offsets buffer.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{buffer::BufferRef, collection::Collection, layout::union::SparseTypeIds};
+/// use narrow_derive::ArrayType;
+///
+/// #[derive(ArrayType, Clone, Debug, PartialEq)]
+/// #[narrow(sparse)]
+/// enum Value {
+///     Number(u8),
+///     Flag(bool),
+/// }
+///
+/// let values = [Value::Number(1), Value::Flag(true)]
+///     .into_iter()
+///     .collect::<SparseTypeIds<Value>>();
+/// assert_eq!(values.buffer_ref(), &[0, 1]);
+/// assert_eq!(values.variants().0.owned(1), Some(0));
+/// assert_eq!(values.variants().1.owned(0), Some(false));
+/// assert!(matches!(values.view(1), Some(ValueView::Flag(true))));
+/// ```
+pub struct SparseTypeIds<T: UnionLayout, Storage: Buffer = VecBuffer> {
+    /// The type ids of the items.
+    type_ids: Storage::For<i8>,
+    /// The children, one per variant.
+    variants: T::Variants<Storage>,
+}
+
+impl<T: UnionLayout, Storage: Buffer> SparseTypeIds<T, Storage> {
+    /// Constructs [`SparseTypeIds`] from a `type_ids` buffer and the
+    /// `variants`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UnionError`] when a type id does not select a variant, or
+    /// when a child does not have a value for every type id.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{collection::Collection, layout::union::SparseTypeIds};
+    /// use narrow_derive::ArrayType;
+    ///
+    /// #[derive(ArrayType, Clone, Debug, PartialEq)]
+    /// #[narrow(sparse)]
+    /// enum Value {
+    ///     Number(u8),
+    ///     Flag(bool),
+    /// }
+    ///
+    /// let variants = ValueVariants([1, 2].into_iter().collect(), [true, false].into_iter().collect());
+    /// let values = SparseTypeIds::<Value>::try_from_parts(vec![1, 0], variants);
+    /// assert_eq!(values.unwrap().owned(1), Some(Value::Number(2)));
+    /// ```
+    pub fn try_from_parts(
+        type_ids: Storage::For<i8>,
+        variants: T::Variants<Storage>,
+    ) -> Result<Self, UnionError> {
+        let expected = type_ids.borrow().len();
+        variant_type_ids::<T::Variants<Storage>>().try_for_each(|type_id| {
+            let len = variants.variant_len(type_id).unwrap_or_default();
+            if len == expected {
+                Ok(())
+            } else {
+                Err(UnionError::VariantLength {
+                    type_id,
+                    len,
+                    expected,
+                })
+            }
+        })?;
+        type_ids
+            .borrow()
+            .iter()
+            .enumerate()
+            .try_for_each(|(index, &type_id)| {
+                variants
+                    .variant_len(type_id)
+                    .map(|_| ())
+                    .ok_or(UnionError::InvalidTypeId { index, type_id })
+            })?;
+        Ok(Self { type_ids, variants })
+    }
+
+    /// Returns the type ids buffer and the variants of these
+    /// [`SparseTypeIds`].
+    ///
+    /// This is the inverse of [`SparseTypeIds::try_from_parts`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{collection::Collection, layout::union::SparseTypeIds};
+    /// use narrow_derive::ArrayType;
+    ///
+    /// #[derive(ArrayType, Clone, Debug, PartialEq)]
+    /// #[narrow(sparse)]
+    /// enum Value {
+    ///     Number(u8),
+    ///     Flag(bool),
+    /// }
+    ///
+    /// let values = [Value::Flag(true)].into_iter().collect::<SparseTypeIds<Value>>();
+    /// let (type_ids, ValueVariants(numbers, flags)) = values.into_parts();
+    /// assert_eq!(type_ids, [1]);
+    /// assert_eq!(numbers.owned(0), Some(0));
+    /// assert_eq!(flags.owned(0), Some(true));
+    /// ```
+    #[must_use]
+    pub fn into_parts(self) -> (Storage::For<i8>, T::Variants<Storage>) {
+        (self.type_ids, self.variants)
+    }
+
+    /// Returns the children, one per variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{collection::Collection, layout::union::SparseTypeIds};
+    /// use narrow_derive::ArrayType;
+    ///
+    /// #[derive(ArrayType, Clone, Debug, PartialEq)]
+    /// #[narrow(sparse)]
+    /// enum Value {
+    ///     Number(u8),
+    ///     Flag(bool),
+    /// }
+    ///
+    /// let values = [Value::Number(1)].into_iter().collect::<SparseTypeIds<Value>>();
+    /// assert_eq!(values.variants().1.owned(0), Some(false));
+    /// ```
+    pub fn variants(&self) -> &T::Variants<Storage> {
+        &self.variants
+    }
+}
+
+impl<T: UnionLayout, Storage: Buffer> BufferRef for SparseTypeIds<T, Storage> {
+    type Buffer = Storage::For<i8>;
+
+    fn buffer_ref(&self) -> &Self::Buffer {
+        &self.type_ids
+    }
+}
+
+impl<T: UnionLayout, Storage: Buffer> Clone for SparseTypeIds<T, Storage>
+where
+    Storage::For<i8>: Clone,
+    T::Variants<Storage>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            type_ids: self.type_ids.clone(),
+            variants: self.variants.clone(),
+        }
+    }
+}
+
+impl<T: UnionLayout, Storage: Buffer> Debug for SparseTypeIds<T, Storage>
+where
+    Storage::For<i8>: Debug,
+    T::Variants<Storage>: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SparseTypeIds")
+            .field("type_ids", &self.type_ids)
+            .field("variants", &self.variants)
+            .finish()
+    }
+}
+
+impl<T: UnionLayout, Storage: Buffer> Default for SparseTypeIds<T, Storage>
+where
+    Storage::For<i8>: Default,
+    T::Variants<Storage>: Default,
+{
+    fn default() -> Self {
+        Self {
+            type_ids: Storage::For::<i8>::default(),
+            variants: T::Variants::<Storage>::default(),
+        }
+    }
+}
+
+impl<T: UnionLayout, Storage: Buffer> Extend<T> for SparseTypeIds<T, Storage>
+where
+    Storage::For<i8>: Extend<i8>,
+    T::Variants<Storage>: SparseUnionVariants,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|item| {
+            let type_id = T::Variants::<Storage>::type_id(&item);
+            self.variants.push(item);
+            variant_type_ids::<T::Variants<Storage>>()
+                .filter(|&other| other != type_id)
+                .for_each(|other| self.variants.push_default(other));
+            self.type_ids.extend(iter::once(type_id));
+        });
+    }
+}
+
+impl<T: UnionLayout, Storage: Buffer> FromIterator<T> for SparseTypeIds<T, Storage>
+where
+    Self: Default + Extend<T>,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut values = Self::default();
+        values.extend(iter);
+        values
+    }
+}
+
+impl<T: UnionLayout, Storage: Buffer> Length for SparseTypeIds<T, Storage> {
+    fn len(&self) -> usize {
+        self.type_ids.borrow().len()
+    }
+}
+
+impl<T: UnionLayout, Storage: Buffer> Collection for SparseTypeIds<T, Storage> {
+    type View<'collection>
+        = <T::Variants<Storage> as UnionVariants>::View<'collection>
+    where
+        Self: 'collection;
+
+    type Owned = T;
+
+    fn view(&self, index: usize) -> Option<Self::View<'_>> {
+        let type_id = *self.type_ids.borrow().get(index)?;
+        self.variants.variant_view(type_id, index)
+    }
+
+    type Iter<'collection>
+        = Map<
+        Zip<Range<usize>, RepeatN<&'collection Self>>,
+        fn((usize, &'collection Self)) -> Self::View<'collection>,
+    >
+    where
+        Self: 'collection;
+
+    fn iter_views(&self) -> Self::Iter<'_> {
+        let len = self.len();
+        (0..len)
+            .zip(iter::repeat_n(self, len))
+            .map(|(index, values)| values.view(index).expect("type id selects a variant"))
+    }
+
+    type IntoIter = UnionIntoIter<Self>;
+
+    fn into_iter_owned(self) -> Self::IntoIter {
+        UnionIntoIter::new(self)
+    }
+}
+
+impl<T: UnionLayout, Storage: Buffer> CollectionAllocIn for SparseTypeIds<T, Storage>
+where
+    Storage::For<i8>: CollectionRealloc,
+    T::Variants<Storage>:
+        SparseUnionVariants<Alloc = <Storage::For<i8> as CollectionAllocIn>::Alloc>,
+{
+    type Alloc = <Storage::For<i8> as CollectionAllocIn>::Alloc;
+
+    fn with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Self {
+        Self {
+            type_ids: Storage::For::<i8>::with_capacity_in(capacity, alloc.clone()),
+            variants: T::Variants::<Storage>::with_capacity_in(capacity, alloc),
+        }
+    }
+
+    fn from_iter_in<I: IntoIterator<Item = Self::Owned>>(iter: I, alloc: Self::Alloc) -> Self {
+        let items = iter.into_iter();
+        let mut values = Self::with_capacity_in(items.size_hint().0, alloc);
+        values.extend(items);
+        values
+    }
+
+    fn try_with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Result<Self, AllocError> {
+        Ok(Self {
+            type_ids: Storage::For::<i8>::try_with_capacity_in(capacity, alloc.clone())?,
+            variants: T::Variants::<Storage>::try_with_capacity_in(capacity, alloc)?,
+        })
+    }
+
+    fn try_from_iter_in<I: IntoIterator<Item = Self::Owned>>(
+        iter: I,
+        alloc: Self::Alloc,
+    ) -> Result<Self, AllocError> {
+        let items = iter.into_iter();
+        let mut values = Self::try_with_capacity_in(items.size_hint().0, alloc)?;
+        values.try_extend(items)?;
+        Ok(values)
+    }
+}
+
+impl<T: UnionLayout, Storage: Buffer> CollectionRealloc for SparseTypeIds<T, Storage>
+where
+    Storage::For<i8>: CollectionRealloc,
+    T::Variants<Storage>:
+        SparseUnionVariants<Alloc = <Storage::For<i8> as CollectionAllocIn>::Alloc>,
+{
+    fn allocator(&self) -> Self::Alloc {
+        self.type_ids.allocator()
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+        self.type_ids.try_reserve(additional)?;
+        self.variants.try_reserve(additional)
+    }
+
+    fn try_extend<I: IntoIterator<Item = Self::Owned>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), AllocError> {
+        // Children are extended one value at a time, so a failed reservation
+        // can leave them with different lengths. Truncating restores the
+        // items present before the call.
+        let len = self.len();
+        iter.into_iter()
+            .try_for_each(|item| {
+                let type_id = T::Variants::<Storage>::type_id(&item);
+                self.variants.try_push(item)?;
+                variant_type_ids::<T::Variants<Storage>>()
+                    .filter(|&other| other != type_id)
+                    .try_for_each(|other| self.variants.try_push_default(other))?;
+                self.type_ids.try_extend(iter::once(type_id))
+            })
+            .inspect_err(|_| self.truncate(len))
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.type_ids.reserve(additional);
+        self.variants.reserve(additional);
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.type_ids.truncate(len);
+        variant_type_ids::<T::Variants<Storage>>()
+            .for_each(|type_id| self.variants.truncate(type_id, len));
+    }
+}
+
+/// A collection of sparse unions.
+///
+/// This is the Arrow sparse union layout: [`SparseTypeIds`] with outer
+/// nullability. Arrow unions have no validity bitmap of their own, so a
+/// nullable union keeps one next to the type ids; null items are stored as
+/// `T::default()`.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{collection::Collection, layout::union::SparseUnion};
+/// use narrow_derive::ArrayType;
+///
+/// #[derive(ArrayType, Clone, Debug, PartialEq)]
+/// #[narrow(sparse)]
+/// enum Value {
+///     Number(u8),
+///     Text(String),
+/// }
+///
+/// let values = [Value::Number(1), Value::Text(String::from("a"))]
+///     .into_iter()
+///     .collect::<SparseUnion<Value>>();
+/// assert!(matches!(values.view(1), Some(ValueView::Text("a"))));
+/// assert_eq!(values.owned(0), Some(Value::Number(1)));
+/// ```
+pub struct SparseUnion<
+    T: UnionLayout,
+    Nulls: Nullability = NonNullable,
+    Storage: Buffer = VecBuffer,
+>(Window<Nulls::Collection<SparseTypeIds<T, Storage>, Storage>>);
+
+impl<T: UnionLayout, Nulls: Nullability, Storage: Buffer> MemoryLayout
+    for SparseUnion<T, Nulls, Storage>
+{
+    fn slice(self, offset: usize, len: usize) -> Self {
+        Self(self.0.slice(offset, len))
+    }
+
+    fn offset(&self) -> usize {
+        self.0.offset()
+    }
+}
+
+impl<T: UnionLayout, Nulls: Nullability, Storage: Buffer> SparseUnion<T, Nulls, Storage> {
+    /// Constructs a [`SparseUnion`] from its backing collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{
+    ///     collection::Collection,
+    ///     layout::union::{SparseTypeIds, SparseUnion},
+    ///     nullability::NonNullable,
+    /// };
+    /// use narrow_derive::ArrayType;
+    ///
+    /// #[derive(ArrayType, Clone, Debug, PartialEq)]
+    /// #[narrow(sparse)]
+    /// enum Value {
+    ///     Number(u8),
+    ///     Flag(bool),
+    /// }
+    ///
+    /// let type_ids = SparseTypeIds::<Value>::try_from_parts(
+    ///     vec![1],
+    ///     ValueVariants([0].into_iter().collect(), [true].into_iter().collect()),
+    /// );
+    /// let values = SparseUnion::<_, NonNullable>::from_buffer(type_ids.unwrap());
+    /// assert_eq!(values.owned(0), Some(Value::Flag(true)));
+    /// ```
+    #[must_use]
+    pub fn from_buffer(buffer: Nulls::Collection<SparseTypeIds<T, Storage>, Storage>) -> Self {
+        Self(Window::new(buffer))
+    }
+
+    /// Returns the backing collection of this [`SparseUnion`].
+    ///
+    /// This is the inverse of [`SparseUnion::from_buffer`]. The backing
+    /// collection of a slice still contains the items outside of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{layout::union::SparseUnion, length::Length};
+    /// use narrow_derive::ArrayType;
+    ///
+    /// #[derive(ArrayType, Clone, Debug, PartialEq)]
+    /// #[narrow(sparse)]
+    /// enum Value {
+    ///     Number(u8),
+    ///     Flag(bool),
+    /// }
+    ///
+    /// let values = [Value::Number(1)].into_iter().collect::<SparseUnion<Value>>();
+    /// assert_eq!(values.into_buffer().len(), 1);
+    /// ```
+    #[must_use]
+    pub fn into_buffer(self) -> Nulls::Collection<SparseTypeIds<T, Storage>, Storage> {
+        self.0.into_parts().0
+    }
+}
+
+impl<T: UnionLayout, Nulls: Nullability, Storage: Buffer> BufferRef
+    for SparseUnion<T, Nulls, Storage>
+{
+    type Buffer = Nulls::Collection<SparseTypeIds<T, Storage>, Storage>;
+
+    fn buffer_ref(&self) -> &Self::Buffer {
+        self.0.buffer_ref()
+    }
+}
+
+impl<T: UnionLayout, Nulls: Nullability, Storage: Buffer> Clone for SparseUnion<T, Nulls, Storage>
+where
+    Nulls::Collection<SparseTypeIds<T, Storage>, Storage>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: UnionLayout, Nulls: Nullability, Storage: Buffer> Debug for SparseUnion<T, Nulls, Storage>
+where
+    Nulls::Collection<SparseTypeIds<T, Storage>, Storage>: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SparseUnion").field(&self.0).finish()
+    }
+}
+
+impl<T: UnionLayout, Nulls: Nullability, Storage: Buffer> Default for SparseUnion<T, Nulls, Storage>
+where
+    Nulls::Collection<SparseTypeIds<T, Storage>, Storage>: Default,
+{
+    fn default() -> Self {
+        Self(Window::default())
+    }
+}
+
+impl<T: UnionLayout, Nulls: Nullability, Storage: Buffer> Extend<Nulls::Item<T>>
+    for SparseUnion<T, Nulls, Storage>
+where
+    Window<Nulls::Collection<SparseTypeIds<T, Storage>, Storage>>: Extend<Nulls::Item<T>>,
+{
+    fn extend<I: IntoIterator<Item = Nulls::Item<T>>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl<T: UnionLayout, Nulls: Nullability, Storage: Buffer> FromIterator<Nulls::Item<T>>
+    for SparseUnion<T, Nulls, Storage>
+where
+    Nulls::Collection<SparseTypeIds<T, Storage>, Storage>: FromIterator<Nulls::Item<T>>,
+{
+    fn from_iter<I: IntoIterator<Item = Nulls::Item<T>>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<T: UnionLayout, Nulls: Nullability, Storage: Buffer> Length
+    for SparseUnion<T, Nulls, Storage>
+{
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<T: UnionLayout, Nulls: Nullability, Storage: Buffer> Collection
+    for SparseUnion<T, Nulls, Storage>
+{
+    type View<'collection>
+        = <Nulls::Collection<SparseTypeIds<T, Storage>, Storage> as Collection>::View<'collection>
+    where
+        Self: 'collection;
+
+    type Owned = Nulls::Item<T>;
+
+    fn view(&self, index: usize) -> Option<Self::View<'_>> {
+        self.0.view(index)
+    }
+
+    type Iter<'collection>
+        = <Window<Nulls::Collection<SparseTypeIds<T, Storage>, Storage>> as Collection>::Iter<
+        'collection,
+    >
+    where
+        Self: 'collection;
+
+    fn iter_views(&self) -> Self::Iter<'_> {
+        self.0.iter_views()
+    }
+
+    type IntoIter =
+        <Window<Nulls::Collection<SparseTypeIds<T, Storage>, Storage>> as Collection>::IntoIter;
+
+    fn into_iter_owned(self) -> Self::IntoIter {
+        self.0.into_iter_owned()
+    }
+}
+
+impl<T: UnionLayout, Nulls: Nullability, Storage: Buffer> CollectionAllocIn
+    for SparseUnion<T, Nulls, Storage>
+where
+    Nulls::Collection<SparseTypeIds<T, Storage>, Storage>: CollectionAllocIn,
+{
+    type Alloc =
+        <Nulls::Collection<SparseTypeIds<T, Storage>, Storage> as CollectionAllocIn>::Alloc;
+
+    fn with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Self {
+        Self(Window::with_capacity_in(capacity, alloc))
+    }
+
+    fn from_iter_in<I: IntoIterator<Item = Self::Owned>>(iter: I, alloc: Self::Alloc) -> Self {
+        Self(Window::from_iter_in(iter, alloc))
+    }
+
+    fn try_with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Result<Self, AllocError> {
+        Window::try_with_capacity_in(capacity, alloc).map(Self)
+    }
+
+    fn try_from_iter_in<I: IntoIterator<Item = Self::Owned>>(
+        iter: I,
+        alloc: Self::Alloc,
+    ) -> Result<Self, AllocError> {
+        Window::try_from_iter_in(iter, alloc).map(Self)
+    }
+}
+
+impl<T: UnionLayout, Nulls: Nullability, Storage: Buffer> CollectionRealloc
+    for SparseUnion<T, Nulls, Storage>
+where
+    Nulls::Collection<SparseTypeIds<T, Storage>, Storage>: CollectionRealloc,
+{
+    fn allocator(&self) -> Self::Alloc {
+        self.0.allocator()
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+        self.0.try_reserve(additional)
+    }
+
+    fn try_extend<I: IntoIterator<Item = Self::Owned>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), AllocError> {
+        self.0.try_extend(iter)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.0.truncate(len);
+    }
+}
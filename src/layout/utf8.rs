@@ -0,0 +1,775 @@
+//! UTF-8 encoded strings.
+
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+use core::{
+    borrow::Borrow,
+    fmt::{self, Debug},
+    iter::{self, Map, RepeatN, Zip},
+    marker::PhantomData,
+    ops::{Deref, Range},
+    str,
+};
+
+use crate::{
+    buffer::{Buffer, BufferRef, VecBuffer},
+    collection::{
+        AllocError, ChildRef, Collection, CollectionAllocIn, CollectionRealloc, view::AsView,
+        window::Window,
+    },
+    layout::MemoryLayout,
+    length::Length,
+    nullability::{NonNullable, Nullability},
+    offset::{Offset, OffsetIntoIter, Offsets, OffsetsError},
+};
+
+/// Owned string types of the items in a [`Utf8`] layout.
+///
+/// Items are converted to and from [`String`] to move their bytes in and out of
+/// the data buffer without copying, while views are converted from `&str`.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::layout::utf8::Utf8Item;
+///
+/// fn assert_item<T: Utf8Item>() {}
+/// assert_item::<String>();
+/// assert_item::<Box<str>>();
+/// ```
+pub trait Utf8Item: for<'any> From<&'any str> + From<String> + Into<String> {}
+
+impl<T: for<'any> From<&'any str> + From<String> + Into<String>> Utf8Item for T {}
+
+/// A [`String`] stored with 64-bit offsets.
+///
+/// [`String`] items select the [`Utf8`] layout with `i32` offsets. Wrapping
+/// the string selects the [`LargeUtf8`] layout instead, for string data that
+/// can exceed `i32::MAX` bytes. Views are `&str` for both:
+///
+/// ```text
+/// String        Utf8
+/// LargeString   LargeUtf8
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{array::Array, collection::Collection, layout::utf8::LargeString};
+///
+/// let values = [LargeString::from("ab"), LargeString::new(String::new())]
+///     .into_iter()
+///     .collect::<Array<LargeString>>();
+/// assert_eq!(values.view(0), Some("ab"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LargeString(String);
+
+impl LargeString {
+    /// Wraps `string` for storage in a [`LargeUtf8`] layout.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::layout::utf8::LargeString;
+    ///
+    /// let string = LargeString::new(String::from("a"));
+    /// assert_eq!(&*string, "a");
+    /// ```
+    #[must_use]
+    pub const fn new(string: String) -> Self {
+        Self(string)
+    }
+
+    /// Returns the wrapped string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::layout::utf8::LargeString;
+    ///
+    /// assert_eq!(LargeString::from("a").into_inner(), "a");
+    /// ```
+    #[must_use]
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl Deref for LargeString {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<String> for LargeString {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<&str> for LargeString {
+    fn from(value: &str) -> Self {
+        Self::new(String::from(value))
+    }
+}
+
+impl From<LargeString> for String {
+    fn from(value: LargeString) -> Self {
+        value.0
+    }
+}
+
+impl<'collection> AsView<'collection> for LargeString {
+    type View = &'collection str;
+
+    fn as_view(&'collection self) -> Self::View {
+        &self.0
+    }
+}
+
+/// Offsets into UTF-8 encoded bytes.
+///
+/// Arrow strings share the physical layout of binary data, with the added
+/// guarantee that every item is valid UTF-8. Validating once, when the values
+/// are constructed, lets views hand out `&str` slices of the data buffer:
+///
+/// ```text
+/// offsets: [0, 2, 2, 5]
+/// data:    b"hiabc"
+/// items:   ["hi", "", "abc"]
+/// ```
+///
+/// `U` selects the owned string type, e.g. [`String`] or `Box<str>`.
+///
+/// Views check the UTF-8 encoding of the item again, because converting the
+/// validated bytes to `&str` without the check requires `unsafe`, which this
+/// crate forbids. Viewing an item therefore takes time linear in its length.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{collection::Collection, layout::utf8::Utf8Values};
+///
+/// let values = ["hi", "", "abc"].map(String::from).into_iter().collect::<Utf8Values>();
+/// assert_eq!(values.view(2), Some("abc"));
+/// ```
+pub struct Utf8Values<OffsetItem: Offset = i32, Storage: Buffer = VecBuffer, U = String>(
+    Offsets<Storage::For<u8>, OffsetItem, Storage, Vec<u8>>,
+    PhantomData<U>,
+);
+
+/// Error returned by [`Utf8Values::try_from_parts`].
+///
+/// Both the offsets and the bytes they address are checked, so a successfully
+/// constructed collection never exposes a string slice that is not valid
+/// UTF-8.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{layout::utf8::{Utf8Values, Utf8ValuesError}, offset::OffsetsError};
+///
+/// let error = Utf8Values::<i32>::try_from_parts(vec![], vec![1]).unwrap_err();
+/// assert_eq!(error, Utf8ValuesError::Offsets(OffsetsError::OutOfBounds { last: 1, data: 0 }));
+/// let error = Utf8Values::<i32>::try_from_parts(vec![b'a', 0xff], vec![0, 1, 2]).unwrap_err();
+/// assert_eq!(error, Utf8ValuesError::InvalidUtf8 { index: 1 });
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Utf8ValuesError {
+    /// The offsets are invalid.
+    Offsets(OffsetsError),
+    /// The item at `index` is not valid UTF-8.
+    InvalidUtf8 {
+        /// The index of the invalid item.
+        index: usize,
+    },
+}
+
+impl fmt::Display for Utf8ValuesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Offsets(ref error) => write!(f, "invalid offsets: {error}"),
+            Self::InvalidUtf8 { index } => write!(f, "item at index {index} is not valid utf-8"),
+        }
+    }
+}
+
+impl core::error::Error for Utf8ValuesError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match *self {
+            Self::Offsets(ref error) => Some(error),
+            Self::InvalidUtf8 { .. } => None,
+        }
+    }
+}
+
+impl From<OffsetsError> for Utf8ValuesError {
+    fn from(error: OffsetsError) -> Self {
+        Self::Offsets(error)
+    }
+}
+
+impl<OffsetItem: Offset, Storage: Buffer, U> Utf8Values<OffsetItem, Storage, U> {
+    /// Constructs [`Utf8Values`] from a `data` buffer and its `offsets`
+    /// buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Utf8ValuesError`] when the offsets are invalid (see
+    /// [`Offsets::try_from_parts`]) or when an item is not valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{collection::Collection, layout::utf8::Utf8Values};
+    ///
+    /// let values = Utf8Values::<i32>::try_from_parts(b"hiabc".to_vec(), vec![0, 2, 5]).unwrap();
+    /// assert_eq!(values.view(1), Some("abc"));
+    /// ```
+    pub fn try_from_parts(
+        data: Storage::For<u8>,
+        offsets: Storage::For<OffsetItem>,
+    ) -> Result<Self, Utf8ValuesError> {
+        let values = Self(Offsets::try_from_parts(data, offsets)?, PhantomData);
+        (0..values.len()).try_for_each(|index| match values.bytes(index).map(str::from_utf8) {
+            Some(Ok(_)) => Ok(()),
+            Some(Err(_)) | None => Err(Utf8ValuesError::InvalidUtf8 { index }),
+        })?;
+        Ok(values)
+    }
+
+    /// Returns the data buffer and offsets buffer of these [`Utf8Values`].
+    ///
+    /// This is the inverse of [`Utf8Values::try_from_parts`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::layout::utf8::Utf8Values;
+    ///
+    /// let values = Utf8Values::<i32>::try_from_parts(b"hi".to_vec(), vec![0, 2]).unwrap();
+    /// assert_eq!(values.into_parts(), (b"hi".to_vec(), vec![0, 2]));
+    /// ```
+    #[must_use]
+    pub fn into_parts(self) -> (Storage::For<u8>, Storage::For<OffsetItem>) {
+        self.0.into_parts()
+    }
+
+    /// Returns the encoded bytes of the item at `index`.
+    fn bytes(&self, index: usize) -> Option<&[u8]> {
+        let offsets = self.0.buffer_ref().borrow();
+        let start = offsets.get(index)?.as_usize();
+        let end = offsets.get(index.checked_add(1)?)?.as_usize();
+        self.0.child_ref().borrow().get(start..end)
+    }
+}
+
+impl<OffsetItem: Offset, Storage: Buffer, U> BufferRef for Utf8Values<OffsetItem, Storage, U> {
+    type Buffer = Offsets<Storage::For<u8>, OffsetItem, Storage, Vec<u8>>;
+
+    fn buffer_ref(&self) -> &Self::Buffer {
+        &self.0
+    }
+}
+
+impl<OffsetItem: Offset, Storage: Buffer, U> Debug for Utf8Values<OffsetItem, Storage, U>
+where
+    Offsets<Storage::For<u8>, OffsetItem, Storage, Vec<u8>>: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Utf8Values").field(&self.0).finish()
+    }
+}
+
+impl<OffsetItem: Offset, Storage: Buffer, U> Clone for Utf8Values<OffsetItem, Storage, U>
+where
+    Offsets<Storage::For<u8>, OffsetItem, Storage, Vec<u8>>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<OffsetItem: Offset, Storage: Buffer, U> Default for Utf8Values<OffsetItem, Storage, U>
+where
+    Offsets<Storage::For<u8>, OffsetItem, Storage, Vec<u8>>: Default,
+{
+    fn default() -> Self {
+        Self(Offsets::default(), PhantomData)
+    }
+}
+
+impl<OffsetItem: Offset, Storage: Buffer, U: Utf8Item> Extend<U>
+    for Utf8Values<OffsetItem, Storage, U>
+where
+    Offsets<Storage::For<u8>, OffsetItem, Storage, Vec<u8>>: Extend<Vec<u8>>,
+{
+    fn extend<I: IntoIterator<Item = U>>(&mut self, iter: I) {
+        self.0
+            .extend(iter.into_iter().map(|value| value.into().into_bytes()));
+    }
+}
+
+impl<OffsetItem: Offset, Storage: Buffer, U: Utf8Item> FromIterator<U>
+    for Utf8Values<OffsetItem, Storage, U>
+where
+    Offsets<Storage::For<u8>, OffsetItem, Storage, Vec<u8>>: FromIterator<Vec<u8>>,
+{
+    fn from_iter<I: IntoIterator<Item = U>>(iter: I) -> Self {
+        Self(
+            iter.into_iter()
+                .map(|value| value.into().into_bytes())
+                .collect(),
+            PhantomData,
+        )
+    }
+}
+
+impl<OffsetItem: Offset, Storage: Buffer, U> Length for Utf8Values<OffsetItem, Storage, U> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<OffsetItem: Offset, Storage: Buffer, U: Utf8Item> Collection
+    for Utf8Values<OffsetItem, Storage, U>
+{
+    type View<'collection>
+        = &'collection str
+    where
+        Self: 'collection;
+
+    type Owned = U;
+
+    fn view(&self, index: usize) -> Option<Self::View<'_>> {
+        // The bytes are validated on construction, but skipping the check
+        // here would require `unsafe`, which this crate forbids. This makes
+        // views O(len) instead of O(1).
+        self.bytes(index)
+            .map(|bytes| str::from_utf8(bytes).expect("validated utf-8"))
+    }
+
+    type Iter<'collection>
+        = Map<
+        Zip<Range<usize>, RepeatN<&'collection Self>>,
+        fn((usize, &'collection Self)) -> Self::View<'collection>,
+    >
+    where
+        Self: 'collection;
+
+    fn iter_views(&self) -> Self::Iter<'_> {
+        let len = self.len();
+        (0..len)
+            .zip(iter::repeat_n(self, len))
+            .map(|(index, values)| values.view(index).expect("index in range"))
+    }
+
+    type IntoIter =
+        Map<OffsetIntoIter<Storage::For<u8>, OffsetItem, Storage, Vec<u8>>, fn(Vec<u8>) -> U>;
+
+    fn into_iter_owned(self) -> Self::IntoIter {
+        self.0
+            .into_iter_owned()
+            .map(|bytes| String::from_utf8(bytes).expect("validated utf-8").into())
+    }
+}
+
+impl<OffsetItem: Offset, Storage: Buffer, U: Utf8Item> CollectionAllocIn
+    for Utf8Values<OffsetItem, Storage, U>
+where
+    Offsets<Storage::For<u8>, OffsetItem, Storage, Vec<u8>>: CollectionRealloc<Owned = Vec<u8>>,
+{
+    type Alloc =
+        <Offsets<Storage::For<u8>, OffsetItem, Storage, Vec<u8>> as CollectionAllocIn>::Alloc;
+
+    fn with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Self {
+        Self(Offsets::with_capacity_in(capacity, alloc), PhantomData)
+    }
+
+    fn from_iter_in<I: IntoIterator<Item = Self::Owned>>(iter: I, alloc: Self::Alloc) -> Self {
+        Self(
+            Offsets::from_iter_in(
+                iter.into_iter().map(|value| value.into().into_bytes()),
+                alloc,
+            ),
+            PhantomData,
+        )
+    }
+
+    fn try_with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Result<Self, AllocError> {
+        Offsets::try_with_capacity_in(capacity, alloc).map(|offsets| Self(offsets, PhantomData))
+    }
+
+    fn try_from_iter_in<I: IntoIterator<Item = Self::Owned>>(
+        iter: I,
+        alloc: Self::Alloc,
+    ) -> Result<Self, AllocError> {
+        Offsets::try_from_iter_in(
+            iter.into_iter().map(|value| value.into().into_bytes()),
+            alloc,
+        )
+        .map(|offsets| Self(offsets, PhantomData))
+    }
+}
+
+impl<OffsetItem: Offset, Storage: Buffer, U: Utf8Item> CollectionRealloc
+    for Utf8Values<OffsetItem, Storage, U>
+where
+    Offsets<Storage::For<u8>, OffsetItem, Storage, Vec<u8>>: CollectionRealloc<Owned = Vec<u8>>,
+{
+    fn allocator(&self) -> Self::Alloc {
+        self.0.allocator()
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+        self.0.try_reserve(additional)
+    }
+
+    fn try_extend<I: IntoIterator<Item = Self::Owned>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), AllocError> {
+        self.0
+            .try_extend(iter.into_iter().map(|value| value.into().into_bytes()))
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.0.truncate(len);
+    }
+}
+
+/// A collection of UTF-8 encoded strings.
+///
+/// This is the Arrow `Utf8` layout: [`Utf8Values`] with outer nullability.
+/// `OffsetItem` selects the standard (`i32`) or large (`i64`, see
+/// [`LargeUtf8`]) Arrow string format. Views take time linear in the length
+/// of the item, see [`Utf8Values`].
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{collection::Collection, layout::utf8::Utf8, nullability::Nullable};
+///
+/// let values = ["a", "bc"].map(String::from).into_iter().collect::<Utf8>();
+/// assert_eq!(values.view(1), Some("bc"));
+/// let values = [Some(String::from("a")), None].into_iter().collect::<Utf8<Nullable>>();
+/// assert_eq!(values.view(1), Some(None));
+/// ```
+pub struct Utf8<
+    Nulls: Nullability = NonNullable,
+    OffsetItem: Offset = i32,
+    Storage: Buffer = VecBuffer,
+    U: Utf8Item = String,
+>(Window<Nulls::Collection<Utf8Values<OffsetItem, Storage, U>, Storage>>);
+
+/// A collection of UTF-8 encoded strings with 64-bit offsets.
+///
+/// This is the Arrow `LargeUtf8` layout, for string data that can exceed
+/// `i32::MAX` bytes. [`LargeString`] items select it.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{collection::Collection, layout::utf8::LargeUtf8};
+///
+/// let values = [String::from("a")].into_iter().collect::<LargeUtf8>();
+/// assert_eq!(values.view(0), Some("a"));
+/// ```
+pub type LargeUtf8<Nulls = NonNullable, Storage = VecBuffer, U = String> =
+    Utf8<Nulls, i64, Storage, U>;
+
+impl<Nulls: Nullability, OffsetItem: Offset, Storage: Buffer, U: Utf8Item> MemoryLayout
+    for Utf8<Nulls, OffsetItem, Storage, U>
+{
+    fn slice(self, offset: usize, len: usize) -> Self {
+        Self(self.0.slice(offset, len))
+    }
+
+    fn offset(&self) -> usize {
+        self.0.offset()
+    }
+}
+
+impl<Nulls: Nullability, OffsetItem: Offset, Storage: Buffer, U: Utf8Item>
+    Utf8<Nulls, OffsetItem, Storage, U>
+{
+    /// Constructs a [`Utf8`] from its backing collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{
+    ///     collection::Collection,
+    ///     layout::utf8::{Utf8, Utf8Values},
+    ///     nullability::NonNullable,
+    /// };
+    ///
+    /// let values = Utf8Values::<i32>::try_from_parts(b"hi".to_vec(), vec![0, 2]).unwrap();
+    /// let values = Utf8::<NonNullable>::from_buffer(values);
+    /// assert_eq!(values.view(0), Some("hi"));
+    /// ```
+    #[must_use]
+    pub fn from_buffer(
+        buffer: Nulls::Collection<Utf8Values<OffsetItem, Storage, U>, Storage>,
+    ) -> Self {
+        Self(Window::new(buffer))
+    }
+
+    /// Returns the backing collection of this [`Utf8`].
+    ///
+    /// This is the inverse of [`Utf8::from_buffer`]. The backing
+    /// collection of a slice still contains the items outside of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{layout::utf8::Utf8, length::Length};
+    ///
+    /// let values = [String::from("hi")].into_iter().collect::<Utf8>();
+    /// assert_eq!(values.into_buffer().len(), 1);
+    /// ```
+    #[must_use]
+    pub fn into_buffer(self) -> Nulls::Collection<Utf8Values<OffsetItem, Storage, U>, Storage> {
+        self.0.into_parts().0
+    }
+}
+
+impl<Nulls: Nullability, OffsetItem: Offset, Storage: Buffer, U: Utf8Item> BufferRef
+    for Utf8<Nulls, OffsetItem, Storage, U>
+{
+    type Buffer = Nulls::Collection<Utf8Values<OffsetItem, Storage, U>, Storage>;
+
+    fn buffer_ref(&self) -> &Self::Buffer {
+        self.0.buffer_ref()
+    }
+}
+
+impl<Nulls: Nullability, OffsetItem: Offset, Storage: Buffer, U: Utf8Item> Debug
+    for Utf8<Nulls, OffsetItem, Storage, U>
+where
+    Nulls::Collection<Utf8Values<OffsetItem, Storage, U>, Storage>: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Utf8").field(&self.0).finish()
+    }
+}
+
+impl<Nulls: Nullability, OffsetItem: Offset, Storage: Buffer, U: Utf8Item> Clone
+    for Utf8<Nulls, OffsetItem, Storage, U>
+where
+    Nulls::Collection<Utf8Values<OffsetItem, Storage, U>, Storage>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<Nulls: Nullability, OffsetItem: Offset, Storage: Buffer, U: Utf8Item> Default
+    for Utf8<Nulls, OffsetItem, Storage, U>
+where
+    Nulls::Collection<Utf8Values<OffsetItem, Storage, U>, Storage>: Default,
+{
+    fn default() -> Self {
+        Self(Window::default())
+    }
+}
+
+impl<Nulls: Nullability, OffsetItem: Offset, Storage: Buffer, U: Utf8Item> Extend<Nulls::Item<U>>
+    for Utf8<Nulls, OffsetItem, Storage, U>
+where
+    Window<Nulls::Collection<Utf8Values<OffsetItem, Storage, U>, Storage>>: Extend<Nulls::Item<U>>,
+{
+    fn extend<I: IntoIterator<Item = Nulls::Item<U>>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl<Nulls: Nullability, OffsetItem: Offset, Storage: Buffer, U: Utf8Item>
+    FromIterator<Nulls::Item<U>> for Utf8<Nulls, OffsetItem, Storage, U>
+where
+    Nulls::Collection<Utf8Values<OffsetItem, Storage, U>, Storage>: FromIterator<Nulls::Item<U>>,
+{
+    fn from_iter<I: IntoIterator<Item = Nulls::Item<U>>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<Nulls: Nullability, OffsetItem: Offset, Storage: Buffer, U: Utf8Item> Length
+    for Utf8<Nulls, OffsetItem, Storage, U>
+{
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<Nulls: Nullability, OffsetItem: Offset, Storage: Buffer, U: Utf8Item> Collection
+    for Utf8<Nulls, OffsetItem, Storage, U>
+{
+    type View<'collection>
+        = <Nulls::Collection<Utf8Values<OffsetItem, Storage, U>, Storage> as Collection>::View<
+        'collection,
+    >
+    where
+        Self: 'collection;
+
+    type Owned = Nulls::Item<U>;
+
+    fn view(&self, index: usize) -> Option<Self::View<'_>> {
+        self.0.view(index)
+    }
+
+    type Iter<'collection>
+        =
+        <Window<Nulls::Collection<Utf8Values<OffsetItem, Storage, U>, Storage>> as Collection>::Iter<
+            'collection,
+        >
+    where
+        Self: 'collection;
+
+    fn iter_views(&self) -> Self::Iter<'_> {
+        self.0.iter_views()
+    }
+
+    type IntoIter =
+        <Window<Nulls::Collection<Utf8Values<OffsetItem, Storage, U>, Storage>> as Collection>::IntoIter;
+
+    fn into_iter_owned(self) -> Self::IntoIter {
+        self.0.into_iter_owned()
+    }
+}
+
+impl<Nulls: Nullability, OffsetItem: Offset, Storage: Buffer, U: Utf8Item> CollectionAllocIn
+    for Utf8<Nulls, OffsetItem, Storage, U>
+where
+    Nulls::Collection<Utf8Values<OffsetItem, Storage, U>, Storage>: CollectionAllocIn,
+{
+    type Alloc =
+        <Nulls::Collection<Utf8Values<OffsetItem, Storage, U>, Storage> as CollectionAllocIn>::Alloc;
+
+    fn with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Self {
+        Self(Window::with_capacity_in(capacity, alloc))
+    }
+
+    fn from_iter_in<I: IntoIterator<Item = Self::Owned>>(iter: I, alloc: Self::Alloc) -> Self {
+        Self(Window::from_iter_in(iter, alloc))
+    }
+
+    fn try_with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Result<Self, AllocError> {
+        Window::try_with_capacity_in(capacity, alloc).map(Self)
+    }
+
+    fn try_from_iter_in<I: IntoIterator<Item = Self::Owned>>(
+        iter: I,
+        alloc: Self::Alloc,
+    ) -> Result<Self, AllocError> {
+        Window::try_from_iter_in(iter, alloc).map(Self)
+    }
+}
+
+impl<Nulls: Nullability, OffsetItem: Offset, Storage: Buffer, U: Utf8Item> CollectionRealloc
+    for Utf8<Nulls, OffsetItem, Storage, U>
+where
+    Nulls::Collection<Utf8Values<OffsetItem, Storage, U>, Storage>: CollectionRealloc,
+{
+    fn allocator(&self) -> Self::Alloc {
+        self.0.allocator()
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+        self.0.try_reserve(additional)
+    }
+
+    fn try_extend<I: IntoIterator<Item = Self::Owned>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), AllocError> {
+        self.0.try_extend(iter)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.0.truncate(len);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::{boxed::Box, vec};
+
+    use crate::{collection::tests::round_trip, nullability::Nullable};
+
+    use super::*;
+
+    #[test]
+    fn try_from_parts() {
+        let values = Utf8Values::<i32>::try_from_parts("héllo".as_bytes().to_vec(), vec![0, 1, 6])
+            .expect("valid");
+        assert_eq!(values.view(0), Some("h"));
+        assert_eq!(values.view(1), Some("éllo"));
+        assert_eq!(values.view(2), None);
+    }
+
+    #[test]
+    fn try_from_parts_invalid_utf8() {
+        // Splitting a multi-byte character yields two invalid items.
+        let error = Utf8Values::<i32>::try_from_parts("é".as_bytes().to_vec(), vec![0, 1, 2])
+            .expect_err("split character");
+        assert_eq!(error, Utf8ValuesError::InvalidUtf8 { index: 0 });
+    }
+
+    #[test]
+    fn try_from_parts_invalid_offsets() {
+        let error = Utf8Values::<i64>::try_from_parts(b"a".to_vec(), vec![0, 2])
+            .expect_err("out of bounds");
+        assert_eq!(
+            error,
+            Utf8ValuesError::Offsets(OffsetsError::OutOfBounds { last: 2, data: 1 })
+        );
+    }
+
+    #[test]
+    fn from_buffer() {
+        let values = [String::from("a"), String::from("bc")]
+            .into_iter()
+            .collect::<Utf8>();
+        let restored = Utf8::<NonNullable>::from_buffer(values.into_buffer());
+        assert_eq!(restored.len(), 2);
+        assert_eq!(restored.owned(1), Some(String::from("bc")));
+    }
+
+    #[test]
+    fn collection() {
+        round_trip::<Utf8, _>(["", "a", "bcd", "€"].map(String::from));
+        round_trip::<Utf8<Nullable>, _>([Some(String::from("a")), None, Some(String::new())]);
+        round_trip::<LargeUtf8, _>(["a", "bc"].map(String::from));
+        round_trip::<LargeUtf8<Nullable>, _>([None, Some(String::from("a"))]);
+        round_trip::<Utf8<NonNullable, i32, VecBuffer, Box<str>>, _>(["a", "bc"].map(Box::from));
+        round_trip::<LargeUtf8<Nullable, VecBuffer, LargeString>, _>([
+            Some(LargeString::from("a")),
+            None,
+        ]);
+    }
+
+    #[test]
+    fn extend() {
+        let mut values = [String::from("a")].into_iter().collect::<Utf8>();
+        values.extend([String::from("bc")]);
+        CollectionRealloc::try_extend(&mut values, [String::from("d")]).expect("reserved");
+        assert_eq!(values.iter_views().collect::<Vec<_>>(), ["a", "bc", "d"]);
+        CollectionRealloc::truncate(&mut values, 1);
+        assert_eq!(values.len(), 1);
+    }
+}
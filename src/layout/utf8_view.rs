@@ -0,0 +1,587 @@
+//! UTF-8 encoded strings stored in views.
+
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+use core::{
+    fmt::{self, Debug},
+    iter::{self, Map, RepeatN, Zip},
+    ops::Range,
+    str,
+};
+
+use crate::{
+    buffer::{Buffer, BufferRef, VecBuffer},
+    collection::{AllocError, Collection, CollectionAllocIn, CollectionRealloc, window::Window},
+    layout::{
+        MemoryLayout,
+        binary_view::{BinaryViews, BinaryViewsError, BinaryViewsIntoIter, ByteView, Viewed},
+    },
+    length::Length,
+    nullability::{NonNullable, Nullability},
+};
+
+/// Views of UTF-8 encoded strings.
+///
+/// Arrow string views share the physical layout of [`BinaryViews`], with the
+/// added guarantee that every item is valid UTF-8. The check runs when the
+/// views are constructed from their parts, so views hand out `&str` slices of
+/// the inline data or the data buffers.
+///
+/// Views check the UTF-8 encoding of the item again, because converting the
+/// validated bytes to `&str` without the check requires `unsafe`, which this
+/// crate forbids. Viewing an item therefore takes time linear in its length.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{
+///     collection::Collection,
+///     layout::{binary_view::Viewed, utf8_view::Utf8Views},
+/// };
+///
+/// let values = ["hi", "a much longer string"]
+///     .into_iter()
+///     .map(Viewed::from)
+///     .collect::<Utf8Views>();
+/// assert_eq!(values.view(1), Some("a much longer string"));
+/// ```
+pub struct Utf8Views<Storage: Buffer = VecBuffer>(BinaryViews<Storage>);
+
+/// Error returned by [`Utf8Views::try_from_parts`].
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{
+///     buffer::VecBuffer,
+///     layout::{
+///         binary_view::{BinaryViews, Viewed},
+///         utf8_view::{Utf8Views, Utf8ViewsError},
+///     },
+/// };
+///
+/// let values = [Viewed::new(vec![0xff])].into_iter().collect::<BinaryViews>();
+/// let (views, buffers) = values.into_parts();
+/// let error = Utf8Views::<VecBuffer>::try_from_parts(views, buffers).unwrap_err();
+/// assert_eq!(error, Utf8ViewsError::InvalidUtf8 { index: 0 });
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Utf8ViewsError {
+    /// The views are invalid.
+    Views(BinaryViewsError),
+    /// The item at `index` is not valid UTF-8.
+    InvalidUtf8 {
+        /// The index of the invalid item.
+        index: usize,
+    },
+}
+
+impl fmt::Display for Utf8ViewsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Views(ref error) => write!(f, "invalid views: {error}"),
+            Self::InvalidUtf8 { index } => write!(f, "item at index {index} is not valid utf-8"),
+        }
+    }
+}
+
+impl core::error::Error for Utf8ViewsError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match *self {
+            Self::Views(ref error) => Some(error),
+            Self::InvalidUtf8 { .. } => None,
+        }
+    }
+}
+
+impl From<BinaryViewsError> for Utf8ViewsError {
+    fn from(error: BinaryViewsError) -> Self {
+        Self::Views(error)
+    }
+}
+
+/// Returns the string of an owned item of validated [`BinaryViews`].
+fn into_string(bytes: Viewed<Vec<u8>>) -> Viewed<String> {
+    Viewed::new(String::from_utf8(bytes.into_inner()).expect("validated utf-8"))
+}
+
+/// Returns the bytes of a string item.
+fn into_bytes(value: Viewed<String>) -> Viewed<Vec<u8>> {
+    Viewed::new(value.into_inner().into_bytes())
+}
+
+impl<Storage: Buffer> Utf8Views<Storage> {
+    /// Constructs [`Utf8Views`] from a `views` buffer and the data `buffers`
+    /// they reference.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Utf8ViewsError`] when the views are invalid (see
+    /// [`BinaryViews::try_from_parts`]) or when an item is not valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{
+    ///     collection::Collection,
+    ///     layout::{binary_view::{BinaryViews, Viewed}, utf8_view::Utf8Views},
+    /// };
+    ///
+    /// let values = [Viewed::new(b"hi".to_vec())].into_iter().collect::<BinaryViews>();
+    /// let (views, buffers) = values.into_parts();
+    /// let values: Utf8Views = Utf8Views::try_from_parts(views, buffers).unwrap();
+    /// assert_eq!(values.view(0), Some("hi"));
+    /// ```
+    pub fn try_from_parts(
+        views: Storage::For<ByteView>,
+        buffers: Vec<Storage::For<u8>>,
+    ) -> Result<Self, Utf8ViewsError> {
+        let values = BinaryViews::try_from_parts(views, buffers)?;
+        values
+            .iter_views()
+            .enumerate()
+            .try_for_each(|(index, bytes)| match str::from_utf8(bytes) {
+                Ok(_) => Ok(()),
+                Err(_) => Err(Utf8ViewsError::InvalidUtf8 { index }),
+            })?;
+        Ok(Self(values))
+    }
+
+    /// Returns the views buffer and data buffers of these [`Utf8Views`].
+    ///
+    /// This is the inverse of [`Utf8Views::try_from_parts`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::layout::{binary_view::Viewed, utf8_view::Utf8Views};
+    ///
+    /// let values = [Viewed::from("a")].into_iter().collect::<Utf8Views>();
+    /// let (views, buffers) = values.into_parts();
+    /// assert_eq!((views.len(), buffers.len()), (1, 0));
+    /// ```
+    #[must_use]
+    pub fn into_parts(self) -> (Storage::For<ByteView>, Vec<Storage::For<u8>>) {
+        self.0.into_parts()
+    }
+}
+
+impl<Storage: Buffer> BufferRef for Utf8Views<Storage> {
+    type Buffer = BinaryViews<Storage>;
+
+    fn buffer_ref(&self) -> &Self::Buffer {
+        &self.0
+    }
+}
+
+impl<Storage: Buffer> Debug for Utf8Views<Storage>
+where
+    BinaryViews<Storage>: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Utf8Views").field(&self.0).finish()
+    }
+}
+
+impl<Storage: Buffer> Clone for Utf8Views<Storage>
+where
+    BinaryViews<Storage>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<Storage: Buffer> Default for Utf8Views<Storage>
+where
+    BinaryViews<Storage>: Default,
+{
+    fn default() -> Self {
+        Self(BinaryViews::default())
+    }
+}
+
+impl<Storage: Buffer> Extend<Viewed<String>> for Utf8Views<Storage>
+where
+    BinaryViews<Storage>: Extend<Viewed<Vec<u8>>>,
+{
+    fn extend<I: IntoIterator<Item = Viewed<String>>>(&mut self, iter: I) {
+        self.0.extend(iter.into_iter().map(into_bytes));
+    }
+}
+
+impl<Storage: Buffer> FromIterator<Viewed<String>> for Utf8Views<Storage>
+where
+    BinaryViews<Storage>: FromIterator<Viewed<Vec<u8>>>,
+{
+    fn from_iter<I: IntoIterator<Item = Viewed<String>>>(iter: I) -> Self {
+        Self(iter.into_iter().map(into_bytes).collect())
+    }
+}
+
+impl<Storage: Buffer> Length for Utf8Views<Storage> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<Storage: Buffer> Collection for Utf8Views<Storage> {
+    type View<'collection>
+        = &'collection str
+    where
+        Self: 'collection;
+
+    type Owned = Viewed<String>;
+
+    fn view(&self, index: usize) -> Option<Self::View<'_>> {
+        // The bytes are validated on construction, but skipping the check
+        // here would require `unsafe`, which this crate forbids. This makes
+        // views O(len) instead of O(1).
+        self.0
+            .view(index)
+            .map(|bytes| str::from_utf8(bytes).expect("validated utf-8"))
+    }
+
+    type Iter<'collection>
+        = Map<
+        Zip<Range<usize>, RepeatN<&'collection Self>>,
+        fn((usize, &'collection Self)) -> Self::View<'collection>,
+    >
+    where
+        Self: 'collection;
+
+    fn iter_views(&self) -> Self::Iter<'_> {
+        let len = self.len();
+        (0..len)
+            .zip(iter::repeat_n(self, len))
+            .map(|(index, values)| values.view(index).expect("index in range"))
+    }
+
+    type IntoIter = Map<BinaryViewsIntoIter<Storage>, fn(Viewed<Vec<u8>>) -> Viewed<String>>;
+
+    fn into_iter_owned(self) -> Self::IntoIter {
+        self.0.into_iter_owned().map(into_string)
+    }
+}
+
+impl<Storage: Buffer> CollectionAllocIn for Utf8Views<Storage>
+where
+    Storage::For<ByteView>: CollectionRealloc,
+    Storage::For<u8>:
+        CollectionRealloc<Alloc = <Storage::For<ByteView> as CollectionAllocIn>::Alloc>,
+{
+    type Alloc = <BinaryViews<Storage> as CollectionAllocIn>::Alloc;
+
+    fn with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Self {
+        Self(BinaryViews::with_capacity_in(capacity, alloc))
+    }
+
+    fn from_iter_in<I: IntoIterator<Item = Self::Owned>>(iter: I, alloc: Self::Alloc) -> Self {
+        Self(BinaryViews::from_iter_in(
+            iter.into_iter().map(into_bytes),
+            alloc,
+        ))
+    }
+
+    fn try_with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Result<Self, AllocError> {
+        BinaryViews::try_with_capacity_in(capacity, alloc).map(Self)
+    }
+
+    fn try_from_iter_in<I: IntoIterator<Item = Self::Owned>>(
+        iter: I,
+        alloc: Self::Alloc,
+    ) -> Result<Self, AllocError> {
+        BinaryViews::try_from_iter_in(iter.into_iter().map(into_bytes), alloc).map(Self)
+    }
+}
+
+impl<Storage: Buffer> CollectionRealloc for Utf8Views<Storage>
+where
+    Storage::For<ByteView>: CollectionRealloc,
+    Storage::For<u8>:
+        CollectionRealloc<Alloc = <Storage::For<ByteView> as CollectionAllocIn>::Alloc>,
+{
+    fn allocator(&self) -> Self::Alloc {
+        self.0.allocator()
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+        self.0.try_reserve(additional)
+    }
+
+    fn try_extend<I: IntoIterator<Item = Self::Owned>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), AllocError> {
+        self.0.try_extend(iter.into_iter().map(into_bytes))
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.0.truncate(len);
+    }
+}
+
+/// A collection of UTF-8 encoded strings stored in views.
+///
+/// This is the Arrow `Utf8View` layout: [`Utf8Views`] with outer nullability.
+/// Views take time linear in the length of the item, see [`Utf8Views`].
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{
+///     collection::Collection,
+///     layout::{binary_view::Viewed, utf8_view::Utf8View},
+///     nullability::Nullable,
+/// };
+///
+/// let values = [Some(Viewed::from("a")), None].into_iter().collect::<Utf8View<Nullable>>();
+/// assert_eq!(values.view(0), Some(Some("a")));
+/// assert_eq!(values.view(1), Some(None));
+/// ```
+pub struct Utf8View<Nulls: Nullability = NonNullable, Storage: Buffer = VecBuffer>(
+    Window<Nulls::Collection<Utf8Views<Storage>, Storage>>,
+);
+
+impl<Nulls: Nullability, Storage: Buffer> MemoryLayout for Utf8View<Nulls, Storage> {
+    fn slice(self, offset: usize, len: usize) -> Self {
+        Self(self.0.slice(offset, len))
+    }
+
+    fn offset(&self) -> usize {
+        self.0.offset()
+    }
+}
+
+impl<Nulls: Nullability, Storage: Buffer> Utf8View<Nulls, Storage> {
+    /// Constructs a [`Utf8View`] from its backing collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{
+    ///     collection::Collection,
+    ///     layout::{binary_view::Viewed, utf8_view::{Utf8View, Utf8Views}},
+    ///     nullability::NonNullable,
+    /// };
+    ///
+    /// let values = [Viewed::from("hi")].into_iter().collect::<Utf8Views>();
+    /// let values = Utf8View::<NonNullable>::from_buffer(values);
+    /// assert_eq!(values.view(0), Some("hi"));
+    /// ```
+    #[must_use]
+    pub fn from_buffer(buffer: Nulls::Collection<Utf8Views<Storage>, Storage>) -> Self {
+        Self(Window::new(buffer))
+    }
+
+    /// Returns the backing collection of this [`Utf8View`].
+    ///
+    /// This is the inverse of [`Utf8View::from_buffer`]. The backing
+    /// collection of a slice still contains the items outside of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use narrow::{layout::{binary_view::Viewed, utf8_view::Utf8View}, length::Length};
+    ///
+    /// let values = [Viewed::from("hi")].into_iter().collect::<Utf8View>();
+    /// assert_eq!(values.into_buffer().len(), 1);
+    /// ```
+    #[must_use]
+    pub fn into_buffer(self) -> Nulls::Collection<Utf8Views<Storage>, Storage> {
+        self.0.into_parts().0
+    }
+}
+
+impl<Nulls: Nullability, Storage: Buffer> BufferRef for Utf8View<Nulls, Storage> {
+    type Buffer = Nulls::Collection<Utf8Views<Storage>, Storage>;
+
+    fn buffer_ref(&self) -> &Self::Buffer {
+        self.0.buffer_ref()
+    }
+}
+
+impl<Nulls: Nullability, Storage: Buffer> Debug for Utf8View<Nulls, Storage>
+where
+    Nulls::Collection<Utf8Views<Storage>, Storage>: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Utf8View").field(&self.0).finish()
+    }
+}
+
+impl<Nulls: Nullability, Storage: Buffer> Clone for Utf8View<Nulls, Storage>
+where
+    Nulls::Collection<Utf8Views<Storage>, Storage>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<Nulls: Nullability, Storage: Buffer> Default for Utf8View<Nulls, Storage>
+where
+    Nulls::Collection<Utf8Views<Storage>, Storage>: Default,
+{
+    fn default() -> Self {
+        Self(Window::default())
+    }
+}
+
+impl<Nulls: Nullability, Storage: Buffer> Extend<Nulls::Item<Viewed<String>>>
+    for Utf8View<Nulls, Storage>
+where
+    Window<Nulls::Collection<Utf8Views<Storage>, Storage>>: Extend<Nulls::Item<Viewed<String>>>,
+{
+    fn extend<I: IntoIterator<Item = Nulls::Item<Viewed<String>>>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl<Nulls: Nullability, Storage: Buffer> FromIterator<Nulls::Item<Viewed<String>>>
+    for Utf8View<Nulls, Storage>
+where
+    Nulls::Collection<Utf8Views<Storage>, Storage>: FromIterator<Nulls::Item<Viewed<String>>>,
+{
+    fn from_iter<I: IntoIterator<Item = Nulls::Item<Viewed<String>>>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<Nulls: Nullability, Storage: Buffer> Length for Utf8View<Nulls, Storage> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<Nulls: Nullability, Storage: Buffer> Collection for Utf8View<Nulls, Storage> {
+    type View<'collection>
+        = <Nulls::Collection<Utf8Views<Storage>, Storage> as Collection>::View<'collection>
+    where
+        Self: 'collection;
+
+    type Owned = Nulls::Item<Viewed<String>>;
+
+    fn view(&self, index: usize) -> Option<Self::View<'_>> {
+        self.0.view(index)
+    }
+
+    type Iter<'collection>
+        = <Window<Nulls::Collection<Utf8Views<Storage>, Storage>> as Collection>::Iter<'collection>
+    where
+        Self: 'collection;
+
+    fn iter_views(&self) -> Self::Iter<'_> {
+        self.0.iter_views()
+    }
+
+    type IntoIter =
+        <Window<Nulls::Collection<Utf8Views<Storage>, Storage>> as Collection>::IntoIter;
+
+    fn into_iter_owned(self) -> Self::IntoIter {
+        self.0.into_iter_owned()
+    }
+}
+
+impl<Nulls: Nullability, Storage: Buffer> CollectionAllocIn for Utf8View<Nulls, Storage>
+where
+    Nulls::Collection<Utf8Views<Storage>, Storage>: CollectionAllocIn,
+{
+    type Alloc = <Nulls::Collection<Utf8Views<Storage>, Storage> as CollectionAllocIn>::Alloc;
+
+    fn with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Self {
+        Self(Window::with_capacity_in(capacity, alloc))
+    }
+
+    fn from_iter_in<I: IntoIterator<Item = Self::Owned>>(iter: I, alloc: Self::Alloc) -> Self {
+        Self(Window::from_iter_in(iter, alloc))
+    }
+
+    fn try_with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Result<Self, AllocError> {
+        Window::try_with_capacity_in(capacity, alloc).map(Self)
+    }
+
+    fn try_from_iter_in<I: IntoIterator<Item = Self::Owned>>(
+        iter: I,
+        alloc: Self::Alloc,
+    ) -> Result<Self, AllocError> {
+        Window::try_from_iter_in(iter, alloc).map(Self)
+    }
+}
+
+impl<Nulls: Nullability, Storage: Buffer> CollectionRealloc for Utf8View<Nulls, Storage>
+where
+    Nulls::Collection<Utf8Views<Storage>, Storage>: CollectionRealloc,
+{
+    fn allocator(&self) -> Self::Alloc {
+        self.0.allocator()
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), AllocError> {
+        self.0.try_reserve(additional)
+    }
+
+    fn try_extend<I: IntoIterator<Item = Self::Owned>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), AllocError> {
+        self.0.try_extend(iter)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.0.truncate(len);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{collection::tests::round_trip, layout::MemoryLayout, nullability::Nullable};
+
+    use super::*;
+
+    #[test]
+    fn try_from_parts_invalid_utf8() {
+        let (views, buffers) = [
+            b"valid".to_vec(),
+            "not valid utf-8: é".as_bytes()[..18].to_vec(),
+        ]
+        .into_iter()
+        .map(Viewed::new)
+        .collect::<BinaryViews>()
+        .into_parts();
+        let error = Utf8Views::<VecBuffer>::try_from_parts(views, buffers).expect_err("invalid");
+        assert_eq!(error, Utf8ViewsError::InvalidUtf8 { index: 1 });
+    }
+
+    #[test]
+    fn slice() {
+        let values = ["a", "a string that is not inlined", "b"]
+            .map(Viewed::from)
+            .into_iter()
+            .collect::<Utf8View>()
+            .slice(1, 2);
+        assert_eq!(
+            values.iter_views().collect::<Vec<_>>(),
+            ["a string that is not inlined", "b"]
+        );
+    }
+
+    #[test]
+    fn collection() {
+        round_trip::<Utf8View, _>(["", "a", "a string that is not inlined", "€"].map(Viewed::from));
+        round_trip::<Utf8View<Nullable>, _>([
+            Some(Viewed::from("a string that is not inlined")),
+            None,
+            Some(Viewed::from("")),
+        ]);
+    }
+}
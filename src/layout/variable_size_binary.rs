@@ -8,6 +8,11 @@ use crate::{
 /// bytes. This alias makes that equivalence explicit and reuses the list's
 /// offsets, storage, nullability, and collection behavior.
 ///
+/// The items are still lists of `u8`, which Arrow consumers see as
+/// `list<uint8>`. Use [`Bytes`](crate::layout::binary::Bytes) items, stored in
+/// a [`Binary`](crate::layout::binary::Binary) layout, for the Arrow binary
+/// type.
+///
 /// # Examples
 ///
 /// ```
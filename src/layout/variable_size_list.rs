@@ -5,7 +5,7 @@ use core::fmt::Debug;
 
 use crate::{
     buffer::{Buffer, BufferRef, VecBuffer},
-    collection::{AllocError, Collection, CollectionAllocIn, CollectionRealloc},
+    collection::{AllocError, Collection, CollectionAllocIn, CollectionRealloc, window::Window},
     layout::{ArrayItem, MemoryLayout},
     length::Length,
     nullability::{NonNullable, Nullability},
@@ -29,16 +29,24 @@ use crate::{
 /// let values = [Some(vec![1]), None].into_iter().collect::<VariableSizeList<i32, Nullable>>();
 /// assert_eq!(values.owned(1), Some(None));
 /// ```
+#[expect(clippy::type_complexity, reason = "nullable slice of nested children")]
 pub struct VariableSizeList<
     T: ArrayItem,
     Nulls: Nullability = NonNullable,
     OffsetItem: Offset = i32,
     Storage: Buffer = VecBuffer,
->(Nulls::Collection<Offsets<T::Memory<Storage>, OffsetItem, Storage>, Storage>);
+>(Window<Nulls::Collection<Offsets<T::Memory<Storage>, OffsetItem, Storage>, Storage>>);
 
 impl<T: ArrayItem, Nulls: Nullability, OffsetItem: Offset, Storage: Buffer> MemoryLayout
     for VariableSizeList<T, Nulls, OffsetItem, Storage>
 {
+    fn slice(self, offset: usize, len: usize) -> Self {
+        Self(self.0.slice(offset, len))
+    }
+
+    fn offset(&self) -> usize {
+        self.0.offset()
+    }
 }
 
 impl<T: ArrayItem, Nulls: Nullability, OffsetItem: Offset, Storage: Buffer>
@@ -59,12 +67,13 @@ impl<T: ArrayItem, Nulls: Nullability, OffsetItem: Offset, Storage: Buffer>
     pub fn from_buffer(
         buffer: Nulls::Collection<Offsets<T::Memory<Storage>, OffsetItem, Storage>, Storage>,
     ) -> Self {
-        Self(buffer)
+        Self(Window::new(buffer))
     }
 
     /// Returns the backing collection of this [`VariableSizeList`].
     ///
-    /// This is the inverse of [`VariableSizeList::from_buffer`].
+    /// This is the inverse of [`VariableSizeList::from_buffer`]. The backing
+    /// collection of a slice still contains the items outside of it.
     ///
     /// # Examples
     ///
@@ -78,7 +87,7 @@ impl<T: ArrayItem, Nulls: Nullability, OffsetItem: Offset, Storage: Buffer>
     pub fn into_buffer(
         self,
     ) -> Nulls::Collection<Offsets<T::Memory<Storage>, OffsetItem, Storage>, Storage> {
-        self.0
+        self.0.into_parts().0
     }
 }
 
@@ -88,7 +97,7 @@ impl<T: ArrayItem, Nulls: Nullability, OffsetItem: Offset, Storage: Buffer> Buff
     type Buffer = Nulls::Collection<Offsets<T::Memory<Storage>, OffsetItem, Storage>, Storage>;
 
     fn buffer_ref(&self) -> &Self::Buffer {
-        &self.0
+        self.0.buffer_ref()
     }
 }
 
@@ -102,20 +111,30 @@ where
     }
 }
 
+impl<T: ArrayItem, Nulls: Nullability, OffsetItem: Offset, Storage: Buffer> Clone
+    for VariableSizeList<T, Nulls, OffsetItem, Storage>
+where
+    Nulls::Collection<Offsets<T::Memory<Storage>, OffsetItem, Storage>, Storage>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
 impl<T: ArrayItem, Nulls: Nullability, OffsetItem: Offset, Storage: Buffer> Default
     for VariableSizeList<T, Nulls, OffsetItem, Storage>
 where
     Nulls::Collection<Offsets<T::Memory<Storage>, OffsetItem, Storage>, Storage>: Default,
 {
     fn default() -> Self {
-        Self(Default::default())
+        Self(Window::default())
     }
 }
 
 impl<T: ArrayItem, Nulls: Nullability, OffsetItem: Offset, Storage: Buffer>
     Extend<Nulls::Item<Vec<T>>> for VariableSizeList<T, Nulls, OffsetItem, Storage>
 where
-    Nulls::Collection<Offsets<T::Memory<Storage>, OffsetItem, Storage>, Storage>:
+    Window<Nulls::Collection<Offsets<T::Memory<Storage>, OffsetItem, Storage>, Storage>>:
         Extend<Nulls::Item<Vec<T>>>,
 {
     fn extend<I: IntoIterator<Item = Nulls::Item<Vec<T>>>>(&mut self, iter: I) {
@@ -155,7 +174,7 @@ impl<T: ArrayItem, Nulls: Nullability, OffsetItem: Offset, Storage: Buffer> Coll
         self.0.view(index)
     }
 
-    type Iter<'collection> = <Nulls::Collection<Offsets<T::Memory<Storage>, OffsetItem, Storage>, Storage> as Collection>::Iter<'collection>
+    type Iter<'collection> = <Window<Nulls::Collection<Offsets<T::Memory<Storage>, OffsetItem, Storage>, Storage>> as Collection>::Iter<'collection>
     where
         Self: 'collection;
 
@@ -163,7 +182,9 @@ impl<T: ArrayItem, Nulls: Nullability, OffsetItem: Offset, Storage: Buffer> Coll
         self.0.iter_views()
     }
 
-    type IntoIter = <Nulls::Collection<Offsets<T::Memory<Storage>, OffsetItem, Storage>, Storage> as Collection>::IntoIter;
+    type IntoIter = <Window<
+        Nulls::Collection<Offsets<T::Memory<Storage>, OffsetItem, Storage>, Storage>,
+    > as Collection>::IntoIter;
 
     fn into_iter_owned(self) -> Self::IntoIter {
         self.0.into_iter_owned()
@@ -178,36 +199,22 @@ where
     type Alloc = <Nulls::Collection<Offsets<T::Memory<Storage>, OffsetItem, Storage>, Storage> as CollectionAllocIn>::Alloc;
 
     fn with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Self {
-        Self(Nulls::Collection::<
-            Offsets<T::Memory<Storage>, OffsetItem, Storage>,
-            Storage,
-        >::with_capacity_in(capacity, alloc))
+        Self(Window::with_capacity_in(capacity, alloc))
     }
 
     fn from_iter_in<I: IntoIterator<Item = Self::Owned>>(iter: I, alloc: Self::Alloc) -> Self {
-        Self(Nulls::Collection::<
-            Offsets<T::Memory<Storage>, OffsetItem, Storage>,
-            Storage,
-        >::from_iter_in(iter, alloc))
+        Self(Window::from_iter_in(iter, alloc))
     }
 
     fn try_with_capacity_in(capacity: usize, alloc: Self::Alloc) -> Result<Self, AllocError> {
-        Nulls::Collection::<
-            Offsets<T::Memory<Storage>, OffsetItem, Storage>,
-            Storage,
-        >::try_with_capacity_in(capacity, alloc)
-        .map(Self)
+        Window::try_with_capacity_in(capacity, alloc).map(Self)
     }
 
     fn try_from_iter_in<I: IntoIterator<Item = Self::Owned>>(
         iter: I,
         alloc: Self::Alloc,
     ) -> Result<Self, AllocError> {
-        Nulls::Collection::<
-            Offsets<T::Memory<Storage>, OffsetItem, Storage>,
-            Storage,
-        >::try_from_iter_in(iter, alloc)
-        .map(Self)
+        Window::try_from_iter_in(iter, alloc).map(Self)
     }
 }
 
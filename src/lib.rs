@@ -62,9 +62,14 @@
     unsafe_op_in_unsafe_fn
 )]
 
+#[cfg(feature = "std")]
+extern crate std;
+
 pub mod collection;
+pub mod decimal;
 pub mod fixed_size;
 pub mod length;
+pub mod temporal;
 
 pub mod buffer;
 
@@ -78,3 +83,6 @@ pub mod offset;
 pub mod layout;
 
 pub mod array;
+
+#[cfg(feature = "derive")]
+pub use narrow_derive::ArrayType;
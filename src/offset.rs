@@ -21,7 +21,9 @@ use crate::{
         owned::IntoOwned,
     },
     fixed_size::FixedSize,
+    layout::fixed_size_primitive::FixedSizePrimitive,
     length::Length,
+    nullability::NonNullable,
 };
 
 /// An integer type used to index the values in an [`Offsets`] collection.
@@ -132,9 +134,11 @@ pub struct Offsets<
 
 /// Error returned by [`Offsets::try_from_parts`].
 ///
-/// Arrow relies on an initial zero and monotonically increasing, in-bounds
-/// offsets. Validating those rules once makes every adjacent pair a safe range
-/// into the flat child collection.
+/// Arrow relies on non-negative, monotonically increasing, in-bounds offsets.
+/// Validating those rules once makes every adjacent pair a safe range into the
+/// flat child collection. The first offset may be non-zero, which is how
+/// producers such as arrow-rs represent sliced lists without rewriting their
+/// offsets.
 ///
 /// # Examples
 ///
@@ -143,8 +147,6 @@ pub struct Offsets<
 ///
 /// let error = Offsets::<Vec<i32>>::try_from_parts(vec![], vec![]).unwrap_err();
 /// assert_eq!(error, OffsetsError::Empty);
-/// let error = Offsets::<Vec<i32>>::try_from_parts(vec![], vec![1]).unwrap_err();
-/// assert_eq!(error, OffsetsError::NonZeroFirst { first: 1 });
 /// let error = Offsets::<Vec<i32>>::try_from_parts(vec![], vec![0, -1]).unwrap_err();
 /// assert_eq!(error, OffsetsError::Negative { index: 1 });
 /// let error = Offsets::<Vec<i32>>::try_from_parts(vec![0], vec![0, 1, 0]).unwrap_err();
@@ -156,12 +158,6 @@ pub struct Offsets<
 pub enum OffsetsError {
     /// The offsets buffer is empty; it must contain at least one offset.
     Empty,
-    /// The first offset is not zero (required by the Arrow `n + 1`
-    /// representation).
-    NonZeroFirst {
-        /// The value of the first offset.
-        first: usize,
-    },
     /// The offset at `index` is negative.
     Negative {
         /// The index of the negative offset.
@@ -185,7 +181,6 @@ impl fmt::Display for OffsetsError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             Self::Empty => write!(f, "offsets buffer must contain at least one offset"),
-            Self::NonZeroFirst { first } => write!(f, "first offset ({first}) is not zero"),
             Self::Negative { index } => write!(f, "offset at index {index} is negative"),
             Self::NonMonotonic { index } => {
                 write!(f, "offset at index {index} is not monotonically increasing")
@@ -206,10 +201,9 @@ impl<T: Collection, OffsetItem: Offset, Storage: Buffer, U> Offsets<T, OffsetIte
     ///
     /// # Errors
     ///
-    /// Returns an [`OffsetsError`] when the offsets buffer is empty, its first
-    /// offset is not zero, it contains a negative offset, it is not
-    /// monotonically increasing, or when its last offset exceeds the length of
-    /// the data.
+    /// Returns an [`OffsetsError`] when the offsets buffer is empty, it
+    /// contains a negative offset, it is not monotonically increasing, or when
+    /// its last offset exceeds the length of the data.
     ///
     /// # Examples
     ///
@@ -228,9 +222,6 @@ impl<T: Collection, OffsetItem: Offset, Storage: Buffer, U> Offsets<T, OffsetIte
         let mut previous: usize = (*first)
             .try_into()
             .map_err(|_| OffsetsError::Negative { index: 0 })?;
-        if previous != 0 {
-            return Err(OffsetsError::NonZeroFirst { first: previous });
-        }
         for (index, offset) in iter {
             let current: usize = (*offset)
                 .try_into()
@@ -303,6 +294,18 @@ impl<T: Collection + Debug, OffsetItem: Offset, Storage: Buffer<For<OffsetItem>:
     }
 }
 
+impl<T: Collection + Clone, OffsetItem: Offset, Storage: Buffer<For<OffsetItem>: Clone>, U> Clone
+    for Offsets<T, OffsetItem, Storage, U>
+{
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+            offsets: self.offsets.clone(),
+            _collection: PhantomData,
+        }
+    }
+}
+
 impl<T: Collection, OffsetItem: Offset, Storage: Buffer, U> Default
     for Offsets<T, OffsetItem, Storage, U>
 where
@@ -431,8 +434,8 @@ impl<T: Collection, OffsetItem: Offset, Storage: Buffer, U> Length
     }
 }
 
-impl<T: Collection, OffsetItem: Offset, Storage: Buffer, U: FromIterator<T::Owned>> Collection
-    for Offsets<T, OffsetItem, Storage, U>
+impl<T: Collection, OffsetItem: Offset, Storage: Buffer, U: FromIterator<T::Owned> + Length>
+    Collection for Offsets<T, OffsetItem, Storage, U>
 {
     type View<'collection>
         = OffsetView<'collection, T, OffsetItem, Storage, U>
@@ -628,7 +631,8 @@ impl<T: Collection, OffsetItem: Offset, Storage: Buffer, U: FromIterator<T::Owne
 /// let view: OffsetView<'_, Vec<i32>, i32, VecBuffer, Vec<i32>> = values.view(0).unwrap();
 /// assert_eq!(view.len(), 2);
 /// assert!(<_ as PartialEq<Vec<_>>>::eq(&view, &vec![1, 2]));
-/// assert_eq!(view.into_owned(), vec![1, 2]);
+/// let owned: Vec<_> = view.into_owned();
+/// assert_eq!(owned, vec![1, 2]);
 /// ```
 pub struct OffsetView<'collection, T: Collection, OffsetItem: Offset, Storage: Buffer, U> {
     collection: &'collection Offsets<T, OffsetItem, Storage, U>,
@@ -636,6 +640,15 @@ pub struct OffsetView<'collection, T: Collection, OffsetItem: Offset, Storage: B
     end: usize,
 }
 
+impl<'collection, T: FixedSize, OffsetItem: Offset, Storage: Buffer, U>
+    OffsetView<'collection, FixedSizePrimitive<T, NonNullable, Storage>, OffsetItem, Storage, U>
+{
+    /// Returns the items of this view as a slice of the flat data.
+    pub(crate) fn as_slice(&self) -> &'collection [T] {
+        &self.collection.data.values()[self.start..self.end]
+    }
+}
+
 impl<T: for<'any> Collection<View<'any>: Debug>, OffsetItem: Offset, Storage: Buffer, U> Debug
     for OffsetView<'_, T, OffsetItem, Storage, U>
 {
@@ -673,8 +686,8 @@ where
     }
 }
 
-impl<T: Collection, OffsetItem: Offset, Storage: Buffer, U: FromIterator<T::Owned>> IntoOwned<U>
-    for OffsetView<'_, T, OffsetItem, Storage, U>
+impl<T: Collection, OffsetItem: Offset, Storage: Buffer, U: FromIterator<T::Owned> + Length>
+    IntoOwned<U> for OffsetView<'_, T, OffsetItem, Storage, U>
 {
     fn into_owned(self) -> U {
         self.into_iter_owned().collect()
@@ -802,9 +815,14 @@ mod tests {
 
     #[test]
     fn try_from_parts_non_zero_first() {
-        let error = Offsets::<Vec<i32>>::try_from_parts(vec![1, 2], vec![1, 2])
-            .expect_err("non-zero first offset");
-        assert_eq!(error, OffsetsError::NonZeroFirst { first: 1 });
+        let offsets = Offsets::<Vec<i32>>::try_from_parts(vec![1, 2, 3], vec![1, 2, 3])
+            .expect("non-zero first offset");
+        assert_eq!(offsets.len(), 2);
+        assert_eq!(offsets.owned(0), Some(vec![2]));
+        assert_eq!(
+            offsets.into_iter_owned().collect::<Vec<_>>(),
+            [vec![2], vec![3]]
+        );
     }
 
     #[test]
@@ -876,7 +894,7 @@ mod tests {
         assert_eq!(slice.view(2), None);
 
         let views = offsets.iter_views().collect::<Vec<_>>();
-        assert_eq!(views[0].into_owned(), vec![1, 2]);
+        assert_eq!(IntoOwned::<Vec<_>>::into_owned(views[0]), vec![1, 2]);
         assert_eq!(views[1].into_iter_owned().collect::<Vec<_>>(), vec![3]);
         assert_eq!(views[2].iter_views().collect::<Vec<_>>(), vec![4]);
     }
@@ -0,0 +1,450 @@
+//! Dates, times, timestamps, durations and intervals.
+//!
+//! Arrow stores temporal values as plain integers, and the meaning of those
+//! integers (the epoch, the unit and the time zone) belongs to the type. The
+//! types in this module wrap the integers so the meaning is kept:
+//!
+//! ```text
+//! Date32                         -> days since the UNIX epoch
+//! Timestamp<Millisecond, Utc>    -> milliseconds since the UNIX epoch, in UTC
+//! Duration<Nanosecond>           -> a number of nanoseconds
+//! ```
+//!
+//! All of them are [`FixedSize`], so arrays of them use the fixed-size
+//! primitive layout.
+//!
+//! # Examples
+//!
+//! ```
+//! use narrow::{
+//!     array::Array,
+//!     collection::Collection,
+//!     temporal::{Millisecond, Timestamp, Utc},
+//! };
+//!
+//! let events = [Some(1_700_000_000_000), None]
+//!     .into_iter()
+//!     .map(|millis| millis.map(Timestamp::<Millisecond, Utc>::new))
+//!     .collect::<Array<Option<Timestamp<Millisecond, Utc>>>>();
+//! assert_eq!(
+//!     events.owned(0).flatten().map(Timestamp::value),
+//!     Some(1_700_000_000_000)
+//! );
+//! ```
+
+use core::{fmt::Debug, hash::Hash, marker::PhantomData};
+
+use crate::fixed_size::FixedSize;
+
+/// The unit of a time, timestamp or duration.
+///
+/// The units are zero-sized types, so the unit of a value is part of its type
+/// and never stored. This trait is sealed.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::temporal::{Microsecond, TimeUnit};
+///
+/// assert_eq!(Microsecond::PER_SECOND, 1_000_000);
+/// ```
+pub trait TimeUnit:
+    Copy + Debug + Default + Eq + Ord + Hash + Send + Sync + 'static + sealed::Sealed
+{
+    /// The number of units in one second.
+    const PER_SECOND: i64;
+}
+
+/// A [`TimeUnit`] of a [`Time32`].
+pub trait Time32Unit: TimeUnit {}
+
+/// A [`TimeUnit`] of a [`Time64`].
+pub trait Time64Unit: TimeUnit {}
+
+/// Seconds.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Second;
+
+impl TimeUnit for Second {
+    const PER_SECOND: i64 = 1;
+}
+impl Time32Unit for Second {}
+
+/// Milliseconds.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Millisecond;
+
+impl TimeUnit for Millisecond {
+    const PER_SECOND: i64 = 1_000;
+}
+impl Time32Unit for Millisecond {}
+
+/// Microseconds.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Microsecond;
+
+impl TimeUnit for Microsecond {
+    const PER_SECOND: i64 = 1_000_000;
+}
+impl Time64Unit for Microsecond {}
+
+/// Nanoseconds.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Nanosecond;
+
+impl TimeUnit for Nanosecond {
+    const PER_SECOND: i64 = 1_000_000_000;
+}
+impl Time64Unit for Nanosecond {}
+
+/// The time zone of a [`Timestamp`].
+///
+/// Like units, time zones are types, so values with different time zones
+/// cannot be mixed up. [`Naive`] and [`Utc`] are provided, and other zones
+/// are declared by implementing this trait.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::temporal::{Second, TimeZone, Timestamp};
+///
+/// #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// struct Amsterdam;
+///
+/// impl TimeZone for Amsterdam {
+///     const NAME: Option<&'static str> = Some("Europe/Amsterdam");
+/// }
+///
+/// let noon = Timestamp::<Second, Amsterdam>::new(43_200);
+/// assert_eq!(noon.value(), 43_200);
+/// ```
+pub trait TimeZone: Copy + Debug + Default + Eq + Ord + Hash + Send + Sync + 'static {
+    /// The name of the time zone, an Olson database name or a fixed offset
+    /// like `+07:30`, or `None` for timestamps without a time zone.
+    const NAME: Option<&'static str>;
+}
+
+/// The absence of a time zone: timestamps are wall-clock times.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Naive;
+
+impl TimeZone for Naive {
+    const NAME: Option<&'static str> = None;
+}
+
+/// Coordinated Universal Time.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Utc;
+
+impl TimeZone for Utc {
+    const NAME: Option<&'static str> = Some("UTC");
+}
+
+/// A date, stored as the number of days since the UNIX epoch in 32 bits.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::temporal::Date32;
+///
+/// assert_eq!(Date32::new(19_723).value(), 19_723);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Date32(i32);
+
+impl Date32 {
+    /// Constructs a [`Date32`] from the number of days since the UNIX epoch.
+    #[must_use]
+    pub const fn new(days: i32) -> Self {
+        Self(days)
+    }
+
+    /// Returns the number of days since the UNIX epoch.
+    #[must_use]
+    pub const fn value(self) -> i32 {
+        self.0
+    }
+}
+
+impl FixedSize for Date32 {}
+
+/// A date, stored as the number of milliseconds since the UNIX epoch in 64
+/// bits.
+///
+/// Arrow expects the value to be a whole number of days, which is not
+/// checked here.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::temporal::Date64;
+///
+/// assert_eq!(Date64::new(86_400_000).value(), 86_400_000);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Date64(i64);
+
+impl Date64 {
+    /// Constructs a [`Date64`] from the number of milliseconds since the UNIX
+    /// epoch.
+    #[must_use]
+    pub const fn new(milliseconds: i64) -> Self {
+        Self(milliseconds)
+    }
+
+    /// Returns the number of milliseconds since the UNIX epoch.
+    #[must_use]
+    pub const fn value(self) -> i64 {
+        self.0
+    }
+}
+
+impl FixedSize for Date64 {}
+
+/// A time of day in seconds or milliseconds since midnight, stored in 32
+/// bits.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::temporal::{Second, Time32};
+///
+/// assert_eq!(Time32::<Second>::new(3_600).value(), 3_600);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Time32<Unit: Time32Unit>(i32, PhantomData<Unit>);
+
+impl<Unit: Time32Unit> Time32<Unit> {
+    /// Constructs a [`Time32`] from the number of units since midnight.
+    #[must_use]
+    pub const fn new(value: i32) -> Self {
+        Self(value, PhantomData)
+    }
+
+    /// Returns the number of units since midnight.
+    #[must_use]
+    pub const fn value(self) -> i32 {
+        self.0
+    }
+}
+
+impl<Unit: Time32Unit> FixedSize for Time32<Unit> {}
+
+/// A time of day in microseconds or nanoseconds since midnight, stored in 64
+/// bits.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::temporal::{Nanosecond, Time64};
+///
+/// assert_eq!(Time64::<Nanosecond>::new(1).value(), 1);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Time64<Unit: Time64Unit>(i64, PhantomData<Unit>);
+
+impl<Unit: Time64Unit> Time64<Unit> {
+    /// Constructs a [`Time64`] from the number of units since midnight.
+    #[must_use]
+    pub const fn new(value: i64) -> Self {
+        Self(value, PhantomData)
+    }
+
+    /// Returns the number of units since midnight.
+    #[must_use]
+    pub const fn value(self) -> i64 {
+        self.0
+    }
+}
+
+impl<Unit: Time64Unit> FixedSize for Time64<Unit> {}
+
+/// A point in time, stored as the number of units since the UNIX epoch in 64
+/// bits.
+///
+/// With a time zone, the value counts from the epoch in UTC and the zone is
+/// used for display. Without one ([`Naive`]), the value is a wall-clock time
+/// in an unknown zone.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::temporal::{Microsecond, Naive, Timestamp};
+///
+/// let timestamp = Timestamp::<Microsecond, Naive>::new(-1);
+/// assert_eq!(timestamp.value(), -1);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Timestamp<Unit: TimeUnit, Tz: TimeZone = Naive>(i64, PhantomData<(Unit, Tz)>);
+
+impl<Unit: TimeUnit, Tz: TimeZone> Timestamp<Unit, Tz> {
+    /// Constructs a [`Timestamp`] from the number of units since the UNIX
+    /// epoch.
+    #[must_use]
+    pub const fn new(value: i64) -> Self {
+        Self(value, PhantomData)
+    }
+
+    /// Returns the number of units since the UNIX epoch.
+    #[must_use]
+    pub const fn value(self) -> i64 {
+        self.0
+    }
+}
+
+impl<Unit: TimeUnit, Tz: TimeZone> FixedSize for Timestamp<Unit, Tz> {}
+
+/// An elapsed time, stored as a number of units in 64 bits.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::temporal::{Duration, Millisecond};
+///
+/// assert_eq!(Duration::<Millisecond>::new(1_500).value(), 1_500);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Duration<Unit: TimeUnit>(i64, PhantomData<Unit>);
+
+impl<Unit: TimeUnit> Duration<Unit> {
+    /// Constructs a [`Duration`] from a number of units.
+    #[must_use]
+    pub const fn new(value: i64) -> Self {
+        Self(value, PhantomData)
+    }
+
+    /// Returns the number of units.
+    #[must_use]
+    pub const fn value(self) -> i64 {
+        self.0
+    }
+}
+
+impl<Unit: TimeUnit> FixedSize for Duration<Unit> {}
+
+/// A calendar interval in months.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::temporal::IntervalYearMonth;
+///
+/// let year = IntervalYearMonth { months: 12 };
+/// assert_eq!(year.months, 12);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct IntervalYearMonth {
+    /// The number of months.
+    pub months: i32,
+}
+
+impl FixedSize for IntervalYearMonth {}
+
+/// A calendar interval in days and milliseconds.
+///
+/// The two fields are independent, because a day is not always 86,400
+/// seconds long.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::temporal::IntervalDayTime;
+///
+/// let interval = IntervalDayTime { days: 1, milliseconds: 500 };
+/// assert_eq!((interval.days, interval.milliseconds), (1, 500));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct IntervalDayTime {
+    /// The number of days.
+    pub days: i32,
+    /// The number of milliseconds.
+    pub milliseconds: i32,
+}
+
+impl FixedSize for IntervalDayTime {}
+
+/// A calendar interval in months, days and nanoseconds, stored in 16 bytes.
+///
+/// The fields are independent, because months and days differ in length.
+///
+/// # Examples
+///
+/// ```
+/// use narrow::{fixed_size::FixedSize, temporal::IntervalMonthDayNano};
+///
+/// let interval = IntervalMonthDayNano { months: 1, days: -2, nanoseconds: 3 };
+/// assert_eq!(interval.days, -2);
+/// assert_eq!(IntervalMonthDayNano::SIZE, 16);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct IntervalMonthDayNano {
+    /// The number of months.
+    pub months: i32,
+    /// The number of days.
+    pub days: i32,
+    /// The number of nanoseconds.
+    pub nanoseconds: i64,
+}
+
+impl FixedSize for IntervalMonthDayNano {}
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for super::Second {}
+    impl Sealed for super::Millisecond {}
+    impl Sealed for super::Microsecond {}
+    impl Sealed for super::Nanosecond {}
+}
+
+#[cfg(test)]
+mod tests {
+    use core::mem;
+
+    use crate::{array::Array, collection::Collection};
+
+    use super::*;
+
+    #[test]
+    fn sizes_match_arrow() {
+        assert_eq!(Date32::SIZE, 4);
+        assert_eq!(Date64::SIZE, 8);
+        assert_eq!(Time32::<Millisecond>::SIZE, 4);
+        assert_eq!(Time64::<Microsecond>::SIZE, 8);
+        assert_eq!(Timestamp::<Second, Utc>::SIZE, 8);
+        assert_eq!(Duration::<Nanosecond>::SIZE, 8);
+        assert_eq!(IntervalYearMonth::SIZE, 4);
+        assert_eq!(IntervalDayTime::SIZE, 8);
+        assert_eq!(IntervalMonthDayNano::SIZE, 16);
+        assert_eq!(mem::align_of::<IntervalMonthDayNano>(), 8);
+    }
+
+    #[test]
+    fn arrays() {
+        let times = [Some(Time32::<Second>::new(1)), None]
+            .into_iter()
+            .collect::<Array<Option<Time32<Second>>>>();
+        assert_eq!(times.owned(0), Some(Some(Time32::new(1))));
+        assert_eq!(times.owned(1), Some(None));
+
+        let intervals = [IntervalMonthDayNano {
+            months: 1,
+            days: 2,
+            nanoseconds: 3,
+        }]
+        .into_iter()
+        .collect::<Array<IntervalMonthDayNano>>();
+        assert_eq!(
+            intervals.owned(0).map(|interval| interval.nanoseconds),
+            Some(3)
+        );
+    }
+}
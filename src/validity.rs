@@ -194,6 +194,18 @@ where
     }
 }
 
+impl<T: Collection + Clone, Storage: Buffer> Clone for Validity<T, Storage>
+where
+    Bitmap<Storage>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            collection: self.collection.clone(),
+            bitmap: self.bitmap.clone(),
+        }
+    }
+}
+
 impl<T: Default + Collection, Storage: Buffer> Default for Validity<T, Storage> {
     fn default() -> Self {
         Self {
@@ -0,0 +1,293 @@
+use narrow::{
+    array::Array,
+    bitmap::ValidityBitmap,
+    buffer::BufferRef,
+    collection::{Collection, CollectionRealloc},
+    layout::struct_array::{FieldRef, StructFields, StructLayout},
+    length::Length,
+};
+use narrow_derive::ArrayType;
+
+#[derive(ArrayType, Clone, Debug, Default, PartialEq)]
+struct Row {
+    id: u32,
+    flag: bool,
+    tags: Option<Vec<u8>>,
+}
+
+#[derive(ArrayType, Clone, Debug, Default, PartialEq)]
+struct Nested {
+    row: Option<Row>,
+    pair: [i16; 2],
+}
+
+#[derive(ArrayType, Clone, Debug, Default, PartialEq)]
+struct Wrapper {
+    value: i64,
+}
+
+#[derive(ArrayType, Clone, Debug, Default, PartialEq)]
+struct Keyword {
+    r#type: u8,
+}
+
+#[derive(ArrayType, Clone, Debug, PartialEq)]
+enum Event {
+    Click { x: u32, y: u32 },
+    Key(String),
+    Scroll(Option<i16>),
+}
+
+impl Default for Event {
+    fn default() -> Self {
+        Self::Scroll(None)
+    }
+}
+
+#[derive(ArrayType, Clone, Debug, PartialEq)]
+#[narrow(sparse)]
+enum Shape {
+    Circle(f32),
+    Rect { width: u8, height: u8 },
+}
+
+fn rows() -> Vec<Row> {
+    vec![
+        Row {
+            id: 1,
+            flag: true,
+            tags: Some(vec![1, 2]),
+        },
+        Row {
+            id: 2,
+            flag: false,
+            tags: None,
+        },
+        Row {
+            id: 3,
+            flag: true,
+            tags: Some(vec![]),
+        },
+    ]
+}
+
+#[test]
+fn stores_fields_as_children() {
+    let array = rows().into_iter().collect::<Array<Row>>();
+
+    let fields = array.buffer_ref().buffer_ref();
+    assert_eq!(fields.id.buffer_ref().as_slice(), &[1, 2, 3]);
+    assert_eq!(fields.flag.len(), 3);
+    assert_eq!(fields.tags.buffer_ref().null_count(), 1);
+}
+
+#[test]
+fn accesses_fields_by_index() {
+    let array = rows()
+        .into_iter()
+        .map(Some)
+        .chain([None])
+        .collect::<Array<Option<Row>>>();
+
+    let rows = array.buffer_ref();
+    assert_eq!(FieldRef::<0>::field_ref(rows).len(), 4);
+    assert_eq!(FieldRef::<1>::field_ref(rows).owned(1), Some(false));
+    assert_eq!(FieldRef::<2>::field_ref(rows).buffer_ref().null_count(), 2);
+}
+
+#[test]
+fn exposes_field_names_and_children() {
+    assert_eq!(Row::FIELD_NAMES, ["id", "flag", "tags"]);
+    assert_eq!(Keyword::FIELD_NAMES, ["type"]);
+
+    let array = rows().into_iter().collect::<Array<Row>>();
+    let (ids, (flags, (tags, ()))) = array.buffer_ref().buffer_ref().children_ref();
+    assert_eq!(
+        (ids.len(), flags.owned(1), tags.owned(1)),
+        (3, Some(false), Some(None))
+    );
+
+    let fields = array.into_buffer().into_buffer();
+    let restored = <Row as StructLayout>::Fields::from_children(fields.into_children());
+    assert_eq!(restored.into_iter_owned().collect::<Vec<_>>(), rows());
+}
+
+#[test]
+fn views_project_rows() {
+    let array = rows().into_iter().collect::<Array<Row>>();
+
+    let view = array.view(0).expect("in bounds");
+    assert_eq!(view.id, 1);
+    assert!(view.flag);
+    assert_eq!(view.tags.map(|tags| tags.owned(1)), Some(Some(2)));
+    assert!(array.view(3).is_none());
+    assert_eq!(
+        array.iter_views().map(|row| row.id).collect::<Vec<_>>(),
+        [1, 2, 3]
+    );
+    assert_eq!(array.owned(1), Some(rows().remove(1)));
+}
+
+#[test]
+fn round_trips_rows() {
+    let array = rows().into_iter().collect::<Array<Row>>();
+    let into_iter = array.into_iter_owned();
+    assert_eq!(into_iter.len(), 3);
+    assert_eq!(into_iter.collect::<Vec<_>>(), rows());
+}
+
+#[test]
+fn round_trips_nullable_rows() {
+    let input = rows()
+        .into_iter()
+        .enumerate()
+        .map(|(index, row)| (index != 1).then_some(row))
+        .collect::<Vec<_>>();
+    let array = input.clone().into_iter().collect::<Array<Option<Row>>>();
+
+    assert_eq!(array.buffer_ref().buffer_ref().null_count(), 1);
+    assert!(array.view(1).expect("in bounds").is_none());
+    assert_eq!(array.into_iter_owned().collect::<Vec<_>>(), input);
+}
+
+#[test]
+fn round_trips_nested_rows() {
+    let input = vec![
+        Nested {
+            row: rows().pop(),
+            pair: [1, 2],
+        },
+        Nested {
+            row: None,
+            pair: [3, 4],
+        },
+    ];
+    let array = input.clone().into_iter().collect::<Array<Nested>>();
+    assert_eq!(
+        array.view(0).expect("in bounds").row.map(|row| row.id),
+        Some(3)
+    );
+    assert_eq!(array.into_iter_owned().collect::<Vec<_>>(), input);
+}
+
+#[test]
+fn extends_rows() {
+    let mut array = rows().into_iter().take(1).collect::<Array<Row>>();
+    array.extend(rows().into_iter().skip(1));
+    assert_eq!(array.len(), 3);
+
+    CollectionRealloc::try_extend(&mut array, rows()).expect("reserved");
+    assert_eq!(array.len(), 6);
+    CollectionRealloc::truncate(&mut array, 2);
+    assert_eq!(array.into_iter_owned().collect::<Vec<_>>(), rows()[..2]);
+}
+
+#[test]
+fn formats_views() {
+    let array = [Wrapper { value: 1 }, Wrapper { value: 2 }]
+        .into_iter()
+        .collect::<Array<Wrapper>>();
+    assert_eq!(
+        format!("{:?}", array.view(1).expect("in bounds")),
+        "WrapperView { value: 2 }"
+    );
+}
+
+fn events() -> Vec<Event> {
+    vec![
+        Event::Key(String::from("a")),
+        Event::Click { x: 1, y: 2 },
+        Event::Scroll(Some(-1)),
+        Event::Key(String::new()),
+        Event::Scroll(None),
+    ]
+}
+
+#[test]
+fn stores_variants_as_dense_children() {
+    let array = events().into_iter().collect::<Array<Event>>();
+
+    let type_ids = array.buffer_ref().buffer_ref();
+    assert_eq!(type_ids.buffer_ref(), &[1, 0, 2, 1, 2]);
+    assert_eq!(type_ids.offsets(), &[0, 0, 0, 1, 1]);
+    let EventVariants(clicks, keys, scrolls) = type_ids.variants();
+    assert_eq!(clicks.len(), 1);
+    assert_eq!(keys.len(), 2);
+    assert_eq!(scrolls.buffer_ref().null_count(), 1);
+}
+
+#[test]
+fn stores_variants_as_sparse_children() {
+    let shapes = [
+        Shape::Circle(1.5),
+        Shape::Rect {
+            width: 2,
+            height: 3,
+        },
+    ];
+    let array = shapes.clone().into_iter().collect::<Array<Shape>>();
+
+    let type_ids = array.buffer_ref().buffer_ref();
+    assert_eq!(type_ids.buffer_ref(), &[0, 1]);
+    assert_eq!(type_ids.variants().0.owned(1), Some(0.0));
+    assert_eq!(type_ids.variants().1.owned(0), Some((0, 0)));
+    assert_eq!(array.into_iter_owned().collect::<Vec<_>>(), shapes);
+}
+
+#[test]
+fn views_select_variants() {
+    let array = events().into_iter().collect::<Array<Event>>();
+
+    assert!(matches!(array.view(0), Some(EventView::Key("a"))));
+    assert!(matches!(array.view(1), Some(EventView::Click((1, 2)))));
+    assert!(matches!(array.view(4), Some(EventView::Scroll(None))));
+    assert!(array.view(5).is_none());
+    assert_eq!(
+        format!("{:?}", array.view(2).expect("in bounds")),
+        "Scroll(Some(-1))"
+    );
+    assert_eq!(array.owned(1), Some(Event::Click { x: 1, y: 2 }));
+}
+
+#[test]
+fn round_trips_events() {
+    let array = events().into_iter().collect::<Array<Event>>();
+    let into_iter = array.into_iter_owned();
+    assert_eq!(into_iter.len(), 5);
+    assert_eq!(into_iter.collect::<Vec<_>>(), events());
+}
+
+#[test]
+fn slices_events() {
+    let array = events().into_iter().collect::<Array<Event>>();
+    let slice = array.slice(1, 3);
+    assert_eq!(slice.len(), 3);
+    assert!(matches!(slice.view(0), Some(EventView::Click((1, 2)))));
+    assert_eq!(slice.into_iter_owned().collect::<Vec<_>>(), events()[1..4]);
+}
+
+#[test]
+fn round_trips_nullable_events() {
+    let input = events()
+        .into_iter()
+        .enumerate()
+        .map(|(index, event)| (index != 1).then_some(event))
+        .collect::<Vec<_>>();
+    let array = input.clone().into_iter().collect::<Array<Option<Event>>>();
+
+    assert_eq!(array.buffer_ref().buffer_ref().null_count(), 1);
+    assert!(array.view(1).expect("in bounds").is_none());
+    assert_eq!(array.into_iter_owned().collect::<Vec<_>>(), input);
+}
+
+#[test]
+fn extends_events() {
+    let mut array = events().into_iter().take(2).collect::<Array<Event>>();
+    array.extend(events().into_iter().skip(2));
+    assert_eq!(array.len(), 5);
+
+    CollectionRealloc::try_extend(&mut array, events()).expect("reserved");
+    assert_eq!(array.len(), 10);
+    CollectionRealloc::truncate(&mut array, 3);
+    assert_eq!(array.into_iter_owned().collect::<Vec<_>>(), events()[..3]);
+}